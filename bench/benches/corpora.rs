@@ -0,0 +1,101 @@
+//! Parser, HTML writer and chunker throughput on representative corpora.
+//!
+//! Every corpus is measured in three groups, so a regression shows up in the
+//! stage that caused it. Compare the `simd` feature on and off with criterion
+//! baselines:
+//!
+//! ```bash
+//! cargo bench -p pulldown-cmark-bench --bench corpora -- --save-baseline scalar
+//! cargo bench -p pulldown-cmark-bench --bench corpora --features simd -- --baseline scalar
+//! ```
+
+use criterion::{criterion_group, criterion_main, BenchmarkGroup, Criterion, Throughput};
+use pulldown_cmark::{chunk::chunk_markdown, html, Options, Parser};
+
+static COMMONMARK_SPEC: &str = include_str!("../../pulldown-cmark/third_party/CommonMark/spec.txt");
+static GFM_SPEC: &str = include_str!("../../pulldown-cmark/third_party/GitHub/spec.txt");
+
+fn cjk() -> String {
+    "## 第一章\n\n中文段落，包含**强调**、*斜体*和[链接](https://example.com)。\
+     日本語の「文章」と`コード`、한국어 문장입니다。\n\n- 列表项\n- 리스트\n\n"
+        .repeat(300)
+}
+
+fn math_heavy() -> String {
+    "Inline $a^2 + b^2 = c^2$ and $\\sum_{i=1}^n i = \\frac{n(n+1)}{2}$ in prose.\n\n\
+     $$\n\\int_0^\\infty e^{-x^2} dx = \\frac{\\sqrt{\\pi}}{2}\n$$\n\n"
+        .repeat(300)
+}
+
+fn pathological() -> String {
+    let mut input = String::new();
+    input.push_str(&"[".repeat(5000));
+    input.push_str("\n\n");
+    input.push_str(&"*a _b ".repeat(2000));
+    input.push_str("\n\n");
+    input.push_str(&"`".repeat(3000));
+    input.push_str("\n\n");
+    input.push_str(&"> ".repeat(500));
+    input.push_str("quote\n\n");
+    input.push_str(&"|x".repeat(500));
+    input.push('\n');
+    input.push_str(&"|-".repeat(500));
+    input.push('\n');
+    input.push_str(&"|x\n".repeat(500));
+    input
+}
+
+fn corpora() -> Vec<(&'static str, String, Options)> {
+    let gfm = Options::ENABLE_TABLES
+        | Options::ENABLE_STRIKETHROUGH
+        | Options::ENABLE_TASKLISTS
+        | Options::ENABLE_FOOTNOTES
+        | Options::ENABLE_GFM;
+    vec![
+        (
+            "commonmark_spec",
+            COMMONMARK_SPEC.to_owned(),
+            Options::empty(),
+        ),
+        ("gfm_spec", GFM_SPEC.to_owned(), gfm),
+        ("cjk", cjk(), gfm),
+        ("math_heavy", math_heavy(), Options::ENABLE_MATH),
+        ("pathological", pathological(), gfm),
+    ]
+}
+
+fn bench_each<F>(c: &mut Criterion, group: &str, mut routine: F)
+where
+    F: FnMut(&mut BenchmarkGroup<'_, criterion::measurement::WallTime>, &str, &str, Options),
+{
+    let mut group = c.benchmark_group(group);
+    for (name, input, options) in corpora() {
+        group.throughput(Throughput::Bytes(input.len() as u64));
+        routine(&mut group, name, &input, options);
+    }
+    group.finish();
+}
+
+fn criterion_benchmark(c: &mut Criterion) {
+    bench_each(c, "corpora_parse", |group, name, input, options| {
+        group.bench_function(name, |b| b.iter(|| Parser::new_ext(input, options).count()));
+    });
+
+    bench_each(c, "corpora_html", |group, name, input, options| {
+        let events: Vec<_> = Parser::new_ext(input, options).collect();
+        let mut buf = String::with_capacity(input.len() * 3 / 2);
+        group.bench_function(name, |b| {
+            b.iter(|| {
+                buf.clear();
+                html::push_html(&mut buf, events.iter().cloned());
+            })
+        });
+    });
+
+    bench_each(c, "corpora_chunk", |group, name, input, options| {
+        group.bench_function(name, |b| b.iter(|| chunk_markdown(input, options)));
+    });
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
@@ -1,6 +1,6 @@
 use criterion::{criterion_group, criterion_main, Criterion};
-use pulldown_cmark::{html, Options, Parser};
-use std::str::from_utf8;
+use pulldown_cmark::{html, Alignment, Event, Options, Parser, Tag, TagEnd};
+use std::{io, iter, str::from_utf8};
 
 static CRDT_BYTES: &[u8] = include_bytes!("../third_party/xi-editor/crdt.md");
 
@@ -33,6 +33,48 @@ fn criterion_benchmark(c: &mut Criterion) {
         })
     });
 
+    c.bench_function("plain_text_html", |b| {
+        let text = "Plain prose without any markup, like most paragraphs. ".repeat(2000);
+        let events = [
+            Event::Start(Tag::Paragraph(None)),
+            Event::Text(text.as_str().into()),
+            Event::End(TagEnd::Paragraph),
+        ];
+        let mut buf = String::with_capacity(text.len() * 3 / 2);
+
+        b.iter(|| {
+            buf.clear();
+            html::push_html(&mut buf, events.clone().into_iter());
+        })
+    });
+
+    c.bench_function("streaming_table_html", |b| {
+        // Events are produced on demand and the output is discarded, so the
+        // renderer runs in constant memory regardless of the table size.
+        let row = [
+            Event::Start(Tag::TableRow),
+            Event::Start(Tag::TableCell(None)),
+            Event::Text("left".into()),
+            Event::End(TagEnd::TableCell),
+            Event::Start(Tag::TableCell(None)),
+            Event::Text("right".into()),
+            Event::End(TagEnd::TableCell),
+            Event::End(TagEnd::TableRow),
+        ];
+
+        b.iter(|| {
+            let events = iter::once(Event::Start(Tag::Table(vec![
+                Alignment::Left,
+                Alignment::Right,
+            ])))
+            .chain(iter::once(Event::Start(Tag::TableHead)))
+            .chain(iter::once(Event::End(TagEnd::TableHead)))
+            .chain((0..10_000).flat_map(|_| row.clone()))
+            .chain(iter::once(Event::End(TagEnd::Table)));
+            html::write_html_io(io::sink(), events).unwrap();
+        })
+    });
+
     c.bench_function("crdt_all_options_parse", |b| {
         let input = from_utf8(CRDT_BYTES).unwrap();
 
@@ -0,0 +1,44 @@
+//! Cost of taking ownership of parsed events, which copies every borrowed
+//! string into an `InlineStr` or a heap allocation. Run once with and once
+//! without the `large-inline-str` feature to compare inline capacities.
+
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+use pulldown_cmark::{CowStr, Event, InlineStr, Options, Parser};
+
+fn english() -> String {
+    "A *short* paragraph with [a link](https://example.com), `code` and some plain words.\n\n"
+        .repeat(500)
+}
+
+fn cjk() -> String {
+    "中文段落，包含**强调**和[链接](https://example.com)。日本語の`コード`と文章。\n\n".repeat(500)
+}
+
+fn criterion_benchmark(c: &mut Criterion) {
+    let mut group = c.benchmark_group(format!("inline_str_{}", InlineStr::CAPACITY));
+    for (name, input) in [("english", english()), ("cjk", cjk())] {
+        let events: Vec<_> = Parser::new_ext(&input, Options::empty()).collect();
+        let inlined = events
+            .iter()
+            .cloned()
+            .map(Event::into_static)
+            .filter(|event| matches!(event, Event::Text(CowStr::Inlined(_))))
+            .count();
+        println!("{name}: {inlined} inlined text events");
+
+        group.throughput(Throughput::Bytes(input.len() as u64));
+        group.bench_function(name, |b| {
+            b.iter(|| {
+                events
+                    .iter()
+                    .cloned()
+                    .map(Event::into_static)
+                    .collect::<Vec<_>>()
+            })
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
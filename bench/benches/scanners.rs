@@ -0,0 +1,42 @@
+//! Parser throughput on inputs that stress the punctuation tables and the
+//! special-byte dispatch of the first pass.
+
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+use pulldown_cmark::{Options, Parser};
+
+fn emphasis_heavy() -> String {
+    "Some *emphasis*, some **strong**, _under_scored_ words, \"*quoted*\" and *(nested **runs**)*. "
+        .repeat(500)
+}
+
+fn cjk_heavy() -> String {
+    "中文段落，包含**强调**和*斜体*。日本語の「文章」と、**強調**。한국어 *문장*입니다。\n"
+        .repeat(500)
+}
+
+fn table_heavy() -> String {
+    let mut table = String::from("| name | value | note |\n| :--- | ---: | :---: |\n");
+    for i in 0..2000 {
+        table.push_str(&format!("| row {} | {} | *note* `code` |\n", i, i * 7));
+    }
+    table
+}
+
+fn criterion_benchmark(c: &mut Criterion) {
+    let mut group = c.benchmark_group("scanners");
+    let options = Options::ENABLE_TABLES | Options::ENABLE_STRIKETHROUGH;
+    for (name, input) in [
+        ("emphasis_heavy", emphasis_heavy()),
+        ("cjk_heavy", cjk_heavy()),
+        ("table_heavy", table_heavy()),
+    ] {
+        group.throughput(Throughput::Bytes(input.len() as u64));
+        group.bench_function(name, |b| {
+            b.iter(|| Parser::new_ext(&input, options).count())
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
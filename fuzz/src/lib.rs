@@ -13,7 +13,9 @@ use mozjs::rooted;
 use mozjs::rust::wrappers::JS_CallFunctionName;
 use mozjs::rust::SIMPLE_GLOBAL_CLASS;
 use mozjs::rust::{JSEngine, RealmOptions, Runtime};
-use pulldown_cmark::{CodeBlockKind, Event, LinkType, Parser, Tag, TagEnd};
+use pulldown_cmark::{
+    CodeBlockKind, CodeDelimiter, Event, HardBreakKind, ImageExtras, LinkType, Parser, Tag, TagEnd,
+};
 use quick_xml::escape::unescape;
 use quick_xml::events::Event as XmlEvent;
 use quick_xml::reader::Reader;
@@ -132,7 +134,7 @@ pub fn xml_to_events(xml: &str) -> anyhow::Result<Vec<Event<'_>>> {
                 {
                     continue;
                 }
-                b"paragraph" => events.push(Event::Start(Tag::Paragraph)),
+                b"paragraph" => events.push(Event::Start(Tag::Paragraph(None))),
                 b"heading" => match tag.try_get_attribute("level")? {
                     Some(level) => {
                         let level = level
@@ -144,8 +146,7 @@ pub fn xml_to_events(xml: &str) -> anyhow::Result<Vec<Event<'_>>> {
                         events.push(Event::Start(Tag::Heading {
                             level,
                             id: None,
-                            classes: Vec::new(),
-                            attrs: Vec::new(),
+                            attributes: None,
                         }));
                     }
                     None => anyhow::bail!("Missing level in heading"),
@@ -185,13 +186,14 @@ pub fn xml_to_events(xml: &str) -> anyhow::Result<Vec<Event<'_>>> {
                     };
                     block_container_stack.push((start.is_some(), tight));
                 }
-                b"item" => events.push(Event::Start(Tag::Item)),
+                b"item" => events.push(Event::Start(Tag::Item(None, None))),
                 b"strong" => events.push(Event::Start(Tag::Strong)),
                 b"emph" => events.push(Event::Start(Tag::Emphasis)),
                 b"code" => events.push(Event::Code(
                     unescape(&reader.read_text(tag.to_end().name())?)?
                         .into_owned()
                         .into(),
+                    CodeDelimiter::default(),
                 )),
                 name @ (b"link" | b"image") => {
                     let dest_url = tag
@@ -218,13 +220,17 @@ pub fn xml_to_events(xml: &str) -> anyhow::Result<Vec<Event<'_>>> {
                             link_type,
                             dest_url,
                             title,
-                            id,
+                            extras: ImageExtras::boxed(id, None),
                         }
                     }));
                 }
                 b"block_quote" => {
                     block_container_stack.push((true, false));
-                    events.push(Event::Start(Tag::BlockQuote(None)))
+                    events.push(Event::Start(Tag::BlockQuote {
+                        kind: None,
+                        folded: None,
+                        title: None,
+                    }))
                 }
                 b"html_block" => {
                     events.push(Event::Start(Tag::HtmlBlock));
@@ -279,7 +285,7 @@ pub fn xml_to_events(xml: &str) -> anyhow::Result<Vec<Event<'_>>> {
             XmlEvent::Empty(tag) => match tag.name().as_ref() {
                 b"thematic_break" => events.push(Event::Rule),
                 b"softbreak" => events.push(Event::SoftBreak),
-                b"linebreak" => events.push(Event::HardBreak),
+                b"linebreak" => events.push(Event::HardBreak(HardBreakKind::Spaces)),
                 name => anyhow::bail!("empty tag: {}", String::from_utf8_lossy(name)),
             },
             event => anyhow::bail!("event {event:?}"),
@@ -310,14 +316,16 @@ pub fn normalize(events: Vec<Event<'_>>) -> Vec<Event<'_>> {
             (Some(Event::Html(prev)), Event::Html(next)) => *prev = format!("{prev}{next}").into(),
 
             // commonmark.js wraps non-empty list items in a paragraph.
-            (Some(Event::Start(Tag::Item)), next)
-                if next != &Event::Start(Tag::Paragraph) && next != &Event::End(TagEnd::Item) =>
+            (Some(Event::Start(Tag::Item(..))), next)
+                if next != &Event::Start(Tag::Paragraph(None))
+                    && next != &Event::End(TagEnd::Item) =>
             {
-                normalized.push(Event::Start(Tag::Paragraph));
+                normalized.push(Event::Start(Tag::Paragraph(None)));
                 normalized.push(event);
             }
             (Some(prev), Event::End(TagEnd::Item))
-                if prev != &Event::End(TagEnd::Paragraph) && prev != &Event::Start(Tag::Item) =>
+                if prev != &Event::End(TagEnd::Paragraph)
+                    && !matches!(prev, Event::Start(Tag::Item(..))) =>
             {
                 normalized.push(Event::End(TagEnd::Paragraph));
                 normalized.push(event);
@@ -339,6 +347,12 @@ pub fn normalize(events: Vec<Event<'_>>) -> Vec<Event<'_>> {
     normalized
         .into_iter()
         .filter_map(|event| match event {
+            // commonmark.js does not record item numbers.
+            Event::Start(Tag::Item(..)) => Some(Event::Start(Tag::Item(None, None))),
+            // commonmark.js does not record how a hard break was written.
+            Event::HardBreak(_) => Some(Event::HardBreak(HardBreakKind::Spaces)),
+            // commonmark.js does not record code span delimiters.
+            Event::Code(text, _) => Some(Event::Code(text, CodeDelimiter::default())),
             // commonmark.js does not record the link type.
             Event::Start(Tag::Link {
                 link_type: LinkType::Email,
@@ -363,13 +377,15 @@ pub fn normalize(events: Vec<Event<'_>>) -> Vec<Event<'_>> {
             Event::Start(Tag::Image {
                 dest_url,
                 title,
-                id,
+                extras,
                 ..
             }) => Some(Event::Start(Tag::Image {
                 link_type: LinkType::Inline,
                 dest_url: urldecode(&dest_url).into(),
                 title: title.clone(),
-                id: id.clone(),
+                extras: extras
+                    .as_ref()
+                    .and_then(|extras| ImageExtras::boxed(extras.id.clone(), None)),
             })),
             // commonmark.js does not distinguish between fenced code
             // blocks with a "" info string and indented code blocks.
@@ -452,15 +468,15 @@ mod tests {
         assert_eq!(
             normalize(vec![
                 Event::Start(Tag::List(None)),
-                Event::Start(Tag::Item),
+                Event::Start(Tag::Item(None, None)),
                 Event::Text("foo".into()),
                 Event::End(TagEnd::Item),
                 Event::End(TagEnd::List(false)),
             ]),
             vec![
                 Event::Start(Tag::List(None)),
-                Event::Start(Tag::Item),
-                Event::Start(Tag::Paragraph),
+                Event::Start(Tag::Item(None, None)),
+                Event::Start(Tag::Paragraph(None)),
                 Event::Text("foo".into()),
                 Event::End(TagEnd::Paragraph),
                 Event::End(TagEnd::Item),
@@ -474,13 +490,13 @@ mod tests {
         assert_eq!(
             normalize(vec![
                 Event::Start(Tag::List(None)),
-                Event::Start(Tag::Item),
+                Event::Start(Tag::Item(None, None)),
                 Event::End(TagEnd::Item),
                 Event::End(TagEnd::List(false)),
             ]),
             vec![
                 Event::Start(Tag::List(None)),
-                Event::Start(Tag::Item),
+                Event::Start(Tag::Item(None, None)),
                 Event::End(TagEnd::Item),
                 Event::End(TagEnd::List(false)),
             ]
@@ -35,6 +35,7 @@ use alloc::string::String;
 
 use core::fmt::{self, Arguments};
 use core::str::from_utf8;
+use memchr::{memchr2, memchr3};
 #[cfg(feature = "std")]
 use std::io::{self, Write};
 
@@ -262,22 +263,27 @@ fn escape_html_scalar<W: StrWrite>(
     table: &'static [u8; 256],
 ) -> Result<(), W::Error> {
     let bytes = s.as_bytes();
+    let quotes = table[b'"' as usize] != 0;
     let mut mark = 0;
-    let mut i = 0;
-    while i < s.len() {
-        match bytes[i..].iter().position(|&c| table[c as usize] != 0) {
-            Some(pos) => {
-                i += pos;
-            }
-            None => break,
-        }
-        let c = bytes[i];
-        let escape = table[c as usize];
-        let escape_seq = HTML_ESCAPES[escape as usize];
+    // Clean runs are found with `memchr`, which is vectorized on most targets.
+    // The next `&<>` and the next quote are searched separately and only again
+    // once they have been escaped, so every byte is scanned at most twice.
+    let mut next_special = memchr3(b'&', b'<', b'>', bytes);
+    let mut next_quote = if quotes {
+        memchr2(b'"', b'\'', bytes)
+    } else {
+        None
+    };
+    while let Some(i) = next_special.into_iter().chain(next_quote).min() {
+        let escape = table[bytes[i] as usize];
         w.write_str(&s[mark..i])?;
-        w.write_str(escape_seq)?;
-        i += 1;
-        mark = i; // all escaped characters are ASCII
+        w.write_str(HTML_ESCAPES[escape as usize])?;
+        mark = i + 1; // all escaped characters are ASCII
+        if next_special == Some(i) {
+            next_special = memchr3(b'&', b'<', b'>', &bytes[mark..]).map(|pos| mark + pos);
+        } else {
+            next_quote = memchr2(b'"', b'\'', &bytes[mark..]).map(|pos| mark + pos);
+        }
     }
     w.write_str(&s[mark..])
 }
@@ -285,8 +291,8 @@ fn escape_html_scalar<W: StrWrite>(
 #[cfg(all(target_arch = "x86_64", feature = "simd"))]
 mod simd {
     use super::StrWrite;
-    use std::arch::x86_64::*;
-    use std::mem::size_of;
+    use core::arch::x86_64::*;
+    use core::mem::size_of;
 
     const VECTOR_SIZE: usize = size_of::<__m128i>();
 
@@ -484,4 +490,45 @@ mod test {
         escape_html_body_text(&mut s, r##"&^"'_"##).unwrap();
         assert_eq!(s.as_str(), r##"&amp;^"'_"##);
     }
+
+    #[test]
+    fn check_long_runs() {
+        use alloc::format;
+
+        let text = "plain text é ".repeat(20);
+        let input = format!("{text}<{text}\"'{text}&>");
+        let mut s = String::new();
+        escape_html(&mut s, &input).unwrap();
+        assert_eq!(s, format!("{text}&lt;{text}&quot;&#39;{text}&amp;&gt;"));
+        s.clear();
+        escape_html_body_text(&mut s, &input).unwrap();
+        assert_eq!(s, format!("{text}&lt;{text}\"'{text}&amp;&gt;"));
+    }
+
+    // The SIMD and scalar paths must produce identical bytes, so output does
+    // not depend on the `simd` feature or the target.
+    #[test]
+    fn matches_scalar() {
+        use super::{escape_html_scalar, HTML_BODY_TEXT_ESCAPE_TABLE, HTML_ESCAPE_TABLE};
+        use alloc::format;
+
+        let ascii: String = (0u8..128).map(char::from).collect();
+        for len in 0..80 {
+            for offset in [0, 1, 15, 31] {
+                let input: String = ascii.chars().cycle().skip(offset).take(len).collect();
+                let input = format!("é{input}<");
+                let mut s = String::new();
+                let mut expected = String::new();
+                escape_html(&mut s, &input).unwrap();
+                escape_html_scalar(&mut expected, &input, &HTML_ESCAPE_TABLE).unwrap();
+                assert_eq!(s, expected);
+
+                s.clear();
+                expected.clear();
+                escape_html_body_text(&mut s, &input).unwrap();
+                escape_html_scalar(&mut expected, &input, &HTML_BODY_TEXT_ESCAPE_TABLE).unwrap();
+                assert_eq!(s, expected);
+            }
+        }
+    }
 }
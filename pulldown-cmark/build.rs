@@ -7,6 +7,15 @@ fn main() {
 #[cfg(any(not(feature = "gen-tests"), not(feature = "std")))]
 fn generate_tests_from_spec() {}
 
+// The spec parser is shared with the `testing` module of the library
+#[cfg(all(feature = "gen-tests", feature = "std"))]
+extern crate alloc;
+
+#[cfg(all(feature = "gen-tests", feature = "std"))]
+#[allow(dead_code)]
+#[path = "src/testing/spec.rs"]
+mod spec;
+
 // If the feature is present, generate tests
 // from any .txt file present in the specs/ directory
 //
@@ -64,7 +73,6 @@ fn generate_tests_from_spec() {
 
         let spec_name = file_path.file_stem().unwrap().to_str().unwrap();
 
-        let spec = Spec::new(&raw_spec);
         let mut n_tests = 0;
 
         spec_rs
@@ -77,7 +85,7 @@ fn generate_tests_from_spec() {
             .write_all(b"\nuse super::test_markdown_html;\n")
             .unwrap();
 
-        for (i, testcase) in spec.enumerate() {
+        for testcase in spec::parse_spec(&raw_spec) {
             spec_rs
                 .write_fmt(format_args!(
                     r###"
@@ -86,20 +94,14 @@ fn {}_test_{i}() {{
     let original = r##"{original}"##;
     let expected = r##"{expected}"##;
 
-    test_markdown_html(original, expected, {smart_punct}, {metadata_blocks}, {old_footnotes}, {subscript}, {wikilinks}, {deflists}, {container_extensions});
+    test_markdown_html(original, expected, "{variant}");
 }}
 "###,
                     spec_name,
-                    i = i + 1,
-                    original = testcase.original,
-                    expected = testcase.expected,
-                    smart_punct = testcase.smart_punct,
-                    metadata_blocks = testcase.metadata_blocks,
-                    old_footnotes = testcase.old_footnotes,
-                    subscript = testcase.subscript,
-                    wikilinks = testcase.wikilinks,
-                    deflists = testcase.deflists,
-                    container_extensions = testcase.container_extensions,
+                    i = testcase.number,
+                    original = testcase.markdown,
+                    expected = testcase.html,
+                    variant = testcase.variant,
                 ))
                 .unwrap();
 
@@ -135,172 +137,3 @@ fn {}_test_{i}() {{
         mod_rs.write_all(b";\n").unwrap();
     }
 }
-
-#[cfg(feature = "gen-tests")]
-pub struct Spec<'a> {
-    spec: &'a str,
-}
-
-#[cfg(feature = "gen-tests")]
-impl<'a> Spec<'a> {
-    pub fn new(spec: &'a str) -> Self {
-        Spec { spec }
-    }
-}
-
-#[cfg(feature = "gen-tests")]
-pub struct TestCase {
-    pub original: String,
-    pub expected: String,
-    pub smart_punct: bool,
-    pub metadata_blocks: bool,
-    pub old_footnotes: bool,
-    pub subscript: bool,
-    pub wikilinks: bool,
-    pub deflists: bool,
-    pub container_extensions: bool,
-}
-
-#[cfg(feature = "gen-tests")]
-impl<'a> Iterator for Spec<'a> {
-    type Item = TestCase;
-
-    fn next(&mut self) -> Option<TestCase> {
-        let spec = self.spec;
-        let prefix = "```````````````````````````````` example";
-
-        let (
-            i_start,
-            smart_punct,
-            metadata_blocks,
-            old_footnotes,
-            subscript,
-            wikilinks,
-            deflists,
-            container_extensions,
-        ) = self.spec.find(prefix).and_then(|pos| {
-            let smartpunct_suffix = "_smartpunct\n";
-            let metadata_blocks_suffix = "_metadata_blocks\n";
-            let old_footnotes_suffix = "_old_footnotes\n";
-            let super_sub_suffix = "_super_sub\n";
-            let wikilinks_suffix = "_wikilinks\n";
-            let deflists_suffix = "_deflists\n";
-            let container_extensions_suffix = "_container_extensions\n";
-            if spec[(pos + prefix.len())..].starts_with(smartpunct_suffix) {
-                Some((
-                    pos + prefix.len() + smartpunct_suffix.len(),
-                    true,
-                    false,
-                    false,
-                    false,
-                    false,
-                    false,
-                    false,
-                ))
-            } else if spec[(pos + prefix.len())..].starts_with(metadata_blocks_suffix) {
-                Some((
-                    pos + prefix.len() + metadata_blocks_suffix.len(),
-                    false,
-                    true,
-                    false,
-                    false,
-                    false,
-                    false,
-                    false,
-                ))
-            } else if spec[(pos + prefix.len())..].starts_with(old_footnotes_suffix) {
-                Some((
-                    pos + prefix.len() + old_footnotes_suffix.len(),
-                    false,
-                    false,
-                    true,
-                    false,
-                    false,
-                    false,
-                    false,
-                ))
-            } else if spec[(pos + prefix.len())..].starts_with(super_sub_suffix) {
-                Some((
-                    pos + prefix.len() + super_sub_suffix.len(),
-                    false,
-                    false,
-                    false,
-                    true,
-                    false,
-                    false,
-                    false,
-                ))
-            } else if spec[(pos + prefix.len())..].starts_with(wikilinks_suffix) {
-                Some((
-                    pos + prefix.len() + wikilinks_suffix.len(),
-                    false,
-                    false,
-                    false,
-                    false,
-                    true,
-                    false,
-                    false,
-                ))
-            } else if spec[(pos + prefix.len())..].starts_with(deflists_suffix) {
-                Some((
-                    pos + prefix.len() + deflists_suffix.len(),
-                    false,
-                    false,
-                    false,
-                    false,
-                    false,
-                    true,
-                    false,
-                ))
-            } else if spec[(pos + prefix.len())..].starts_with(container_extensions_suffix) {
-                Some((
-                    pos + prefix.len() + container_extensions_suffix.len(),
-                    false,
-                    false,
-                    false,
-                    false,
-                    false,
-                    false,
-                    true,
-                ))
-            } else if spec[(pos + prefix.len())..].starts_with('\n') {
-                Some((
-                    pos + prefix.len() + 1,
-                    false,
-                    false,
-                    false,
-                    false,
-                    false,
-                    false,
-                    false,
-                ))
-            } else {
-                None
-            }
-        })?;
-
-        let i_end = self.spec[i_start..]
-            .find("\n.\n")
-            .map(|pos| (pos + 1) + i_start)?;
-
-        let e_end = self.spec[i_end + 2..]
-            .find("````````````````````````````````\n")
-            .map(|pos| pos + i_end + 2)?;
-
-        self.spec = &self.spec[e_end + 33..];
-
-        let test_case = TestCase {
-            original: spec[i_start..i_end].to_string().replace("→", "\t"),
-            expected: spec[i_end + 2..e_end].to_string().replace("→", "\t"),
-            smart_punct,
-            metadata_blocks,
-            old_footnotes,
-            subscript,
-            wikilinks,
-            deflists,
-            container_extensions,
-        };
-
-        Some(test_case)
-    }
-}
@@ -18,13 +18,14 @@ fn main() {
             Event::Html(s) => println!("Html: {:?}", s),
             Event::InlineHtml(s) => println!("InlineHtml: {:?}", s),
             Event::Text(s) => println!("Text: {:?}", s),
-            Event::Code(s) => println!("Code: {:?}", s),
-            Event::DisplayMath(s) => println!("DisplayMath: {:?}", s),
-            Event::InlineMath(s) => println!("Math: {:?}", s),
+            Event::Code(s, delimiter) => println!("Code: {:?} {:?}", s, delimiter),
+            Event::DisplayMath(s, syntax) => println!("DisplayMath ({:?}): {:?}", syntax, s),
+            Event::InlineMath(s, syntax) => println!("Math ({:?}): {:?}", syntax, s),
             Event::FootnoteReference(s) => println!("FootnoteReference: {:?}", s),
             Event::TaskListMarker(b) => println!("TaskListMarker: {:?}", b),
+            Event::Hashtag(s) => println!("Hashtag: {:?}", s),
             Event::SoftBreak => println!("SoftBreak"),
-            Event::HardBreak => println!("HardBreak"),
+            Event::HardBreak(kind) => println!("HardBreak: {:?}", kind),
             Event::Rule => println!("Rule"),
         };
         event
@@ -46,16 +46,14 @@ fn main() {
         match &event {
             Event::Start(tag) => match tag {
                 Tag::HtmlBlock => println!("HtmlBlock"),
-                Tag::Heading {
+                Tag::Heading { level, id, .. } => println!(
+                    "Heading heading_level: {} fragment identifier: {:?} classes: {:?} attrs: {:?}",
                     level,
                     id,
-                    classes,
-                    attrs,
-                } => println!(
-                    "Heading heading_level: {} fragment identifier: {:?} classes: {:?} attrs: {:?}",
-                    level, id, classes, attrs
+                    tag.heading_classes(),
+                    tag.heading_attrs()
                 ),
-                Tag::Paragraph => println!("Paragraph"),
+                Tag::Paragraph(_) => println!("Paragraph"),
                 Tag::List(ordered_list_first_item_number) => println!(
                     "List ordered_list_first_item_number: {:?}",
                     ordered_list_first_item_number
@@ -63,18 +61,21 @@ fn main() {
                 Tag::DefinitionList => println!("Definition list"),
                 Tag::DefinitionListTitle => println!("Definition title (definition list item)"),
                 Tag::DefinitionListDefinition => println!("Definition (definition list item)"),
-                Tag::Item => println!("Item (this is a list item)"),
+                Tag::Item(number, _) => println!("Item (this is a list item) number: {:?}", number),
                 Tag::Emphasis => println!("Emphasis (this is a span tag)"),
                 Tag::Superscript => println!("Superscript (this is a span tag)"),
                 Tag::Subscript => println!("Subscript (this is a span tag)"),
                 Tag::Strong => println!("Strong (this is a span tag)"),
                 Tag::Strikethrough => println!("Strikethrough (this is a span tag)"),
-                Tag::BlockQuote(kind) => println!("BlockQuote ({:?})", kind),
+                Tag::BlockQuote { kind, .. } => println!("BlockQuote ({:?})", kind),
                 Tag::CodeBlock(code_block_kind) => {
                     println!("CodeBlock code_block_kind: {:?}", code_block_kind)
                 }
-                Tag::ContainerBlock(kind, summary) => {
-                    println!("ContainerBlock ({:?}) summary: {:?}", kind, summary)
+                Tag::ContainerBlock(kind, summary, attributes) => {
+                    println!(
+                        "ContainerBlock ({:?}) summary: {:?} attributes: {:?}",
+                        kind, summary, attributes
+                    )
                 }
                 Tag::Link {
                     link_type,
@@ -89,18 +90,28 @@ fn main() {
                     link_type,
                     dest_url,
                     title,
-                    id,
+                    ..
                 } => println!(
-                    "Image link_type: {:?} url: {} title: {} id: {}",
-                    link_type, dest_url, title, id
+                    "Image link_type: {:?} url: {} title: {} id: {} attributes: {:?}",
+                    link_type,
+                    dest_url,
+                    title,
+                    tag.image_id(),
+                    tag.image_attributes()
                 ),
                 Tag::Table(column_text_alignment_list) => println!(
                     "Table column_text_alignment_list: {:?}",
                     column_text_alignment_list
                 ),
                 Tag::TableHead => println!("TableHead (contains TableRow tags"),
+                Tag::TableFoot => println!("TableFoot (contains TableRow tags)"),
                 Tag::TableRow => println!("TableRow (contains TableCell tags)"),
-                Tag::TableCell => println!("TableCell (contains inline tags)"),
+                Tag::TableCell(alignment) => {
+                    println!(
+                        "TableCell alignment: {:?} (contains inline tags)",
+                        alignment
+                    )
+                }
                 Tag::FootnoteDefinition(label) => println!("FootnoteDefinition label: {}", label),
                 Tag::MetadataBlock(kind) => println!("MetadataBlock: {:?}", kind),
             },
@@ -0,0 +1,314 @@
+//! 抽象语法树（AST）
+//!
+//! 将 [`Event`] 流构建为拥有所有权的 [`Node`] 树，支持随机访问和结构化修改，
+//! 之后可以通过 [`Ast::into_events`] 重新生成平衡的事件流。
+//!
+//! ```rust
+//! use pulldown_cmark::{ast::Ast, Event, Parser, Tag};
+//!
+//! let mut ast = Ast::from_events(Parser::new("# Title\n\nBody *text*"));
+//!
+//! // 删除所有标题
+//! ast.retain(&mut |node| !matches!(node.tag(), Some(Tag::Heading { .. })));
+//!
+//! let events: Vec<Event> = ast.into_events().collect();
+//! assert_eq!(events.len(), 6);
+//! ```
+
+use alloc::{string::String, vec, vec::Vec};
+use core::ops::Range;
+
+use crate::{Event, Tag, TagEnd};
+
+/// 节点内容。
+#[derive(Clone, Debug, PartialEq)]
+pub enum NodeKind<'a> {
+    /// 带有子节点的标签。
+    Tag(Tag<'a>),
+    /// 叶子事件，永远不是 `Event::Start` 或 `Event::End`。
+    Leaf(Event<'a>),
+}
+
+/// AST 节点。
+#[derive(Clone, Debug, PartialEq)]
+pub struct Node<'a> {
+    pub kind: NodeKind<'a>,
+    /// 节点在源文本中的范围；由 [`Ast::from_events`] 构建时为 `0..0`。
+    pub span: Range<usize>,
+    /// 子节点；叶子节点总是为空。
+    pub children: Vec<Node<'a>>,
+}
+
+impl<'a> Node<'a> {
+    /// 创建一个没有子节点的标签节点。
+    pub fn new(tag: Tag<'a>) -> Self {
+        Self {
+            kind: NodeKind::Tag(tag),
+            span: 0..0,
+            children: Vec::new(),
+        }
+    }
+
+    /// 创建一个叶子节点。
+    ///
+    /// # Panics
+    ///
+    /// 如果 `event` 是 `Event::Start` 或 `Event::End`。
+    pub fn leaf(event: Event<'a>) -> Self {
+        assert!(
+            !matches!(event, Event::Start(_) | Event::End(_)),
+            "leaf node cannot hold a start or end event"
+        );
+        Self {
+            kind: NodeKind::Leaf(event),
+            span: 0..0,
+            children: Vec::new(),
+        }
+    }
+
+    /// 设置节点的源文本范围。
+    pub fn with_span(mut self, span: Range<usize>) -> Self {
+        self.span = span;
+        self
+    }
+
+    /// 如果是标签节点，返回其标签。
+    pub fn tag(&self) -> Option<&Tag<'a>> {
+        match &self.kind {
+            NodeKind::Tag(tag) => Some(tag),
+            NodeKind::Leaf(_) => None,
+        }
+    }
+
+    /// 如果是叶子节点，返回其事件。
+    pub fn event(&self) -> Option<&Event<'a>> {
+        match &self.kind {
+            NodeKind::Leaf(event) => Some(event),
+            NodeKind::Tag(_) => None,
+        }
+    }
+
+    /// 追加一个子节点。
+    pub fn push(&mut self, child: Node<'a>) {
+        self.children.push(child);
+    }
+
+    /// 拼接节点内所有 `Text` 和 `Code` 的文本内容。
+    pub fn text(&self) -> String {
+        let mut buf = String::new();
+        self.collect_text(&mut buf);
+        buf
+    }
+
+    fn collect_text(&self, buf: &mut String) {
+        match &self.kind {
+            NodeKind::Leaf(Event::Text(text) | Event::Code(text, _)) => buf.push_str(text),
+            NodeKind::Leaf(_) => {}
+            NodeKind::Tag(_) => {
+                for child in &self.children {
+                    child.collect_text(buf);
+                }
+            }
+        }
+    }
+
+    /// 深度优先地递归保留满足 `f` 的子节点。
+    pub fn retain(&mut self, f: &mut impl FnMut(&Node<'a>) -> bool) {
+        retain_nodes(&mut self.children, f);
+    }
+
+    /// 深度优先（先序）地访问自身及所有后代节点。
+    pub fn for_each_mut(&mut self, f: &mut impl FnMut(&mut Node<'a>)) {
+        f(self);
+        for child in &mut self.children {
+            child.for_each_mut(f);
+        }
+    }
+
+    /// 先序查找第一个满足 `f` 的节点（包括自身）。
+    pub fn find(&self, f: &mut impl FnMut(&Node<'a>) -> bool) -> Option<&Node<'a>> {
+        if f(self) {
+            return Some(self);
+        }
+        self.children.iter().find_map(|child| child.find(f))
+    }
+}
+
+fn retain_nodes<'a>(nodes: &mut Vec<Node<'a>>, f: &mut impl FnMut(&Node<'a>) -> bool) {
+    nodes.retain(|node| f(node));
+    for node in nodes {
+        retain_nodes(&mut node.children, f);
+    }
+}
+
+/// 文档的根，包含顶层节点。
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Ast<'a> {
+    pub children: Vec<Node<'a>>,
+}
+
+impl<'a> Ast<'a> {
+    /// 从事件流构建 AST。
+    ///
+    /// 不平衡的输入会被修复：多余的结束标签被忽略，未关闭的标签在末尾关闭。
+    pub fn from_events<I>(events: I) -> Self
+    where
+        I: IntoIterator<Item = Event<'a>>,
+    {
+        Self::from_offset_events(events.into_iter().map(|event| (event, 0..0)))
+    }
+
+    /// 从带偏移的事件流（例如 [`OffsetIter`](crate::OffsetIter)）构建 AST，并记录每个节点的范围。
+    pub fn from_offset_events<I>(events: I) -> Self
+    where
+        I: IntoIterator<Item = (Event<'a>, Range<usize>)>,
+    {
+        let mut root = Vec::new();
+        let mut stack: Vec<Node<'a>> = Vec::new();
+        for (event, range) in events {
+            match event {
+                Event::Start(tag) => stack.push(Node::new(tag).with_span(range)),
+                Event::End(_) => {
+                    if let Some(node) = stack.pop() {
+                        match stack.last_mut() {
+                            Some(parent) => parent.push(node),
+                            None => root.push(node),
+                        }
+                    }
+                }
+                event => {
+                    let node = Node::leaf(event).with_span(range);
+                    match stack.last_mut() {
+                        Some(parent) => parent.push(node),
+                        None => root.push(node),
+                    }
+                }
+            }
+        }
+        while let Some(node) = stack.pop() {
+            match stack.last_mut() {
+                Some(parent) => parent.push(node),
+                None => root.push(node),
+            }
+        }
+        Self { children: root }
+    }
+
+    /// 将 AST 重新转换为平衡的事件流。
+    pub fn into_events(self) -> IntoEvents<'a> {
+        IntoEvents {
+            stack: vec![(self.children.into_iter(), None)],
+        }
+    }
+
+    /// 深度优先地递归保留满足 `f` 的节点。
+    pub fn retain(&mut self, f: &mut impl FnMut(&Node<'a>) -> bool) {
+        retain_nodes(&mut self.children, f);
+    }
+
+    /// 深度优先（先序）地访问所有节点。
+    pub fn for_each_mut(&mut self, f: &mut impl FnMut(&mut Node<'a>)) {
+        for child in &mut self.children {
+            child.for_each_mut(f);
+        }
+    }
+
+    /// 先序查找第一个满足 `f` 的节点。
+    pub fn find(&self, f: &mut impl FnMut(&Node<'a>) -> bool) -> Option<&Node<'a>> {
+        self.children.iter().find_map(|child| child.find(f))
+    }
+}
+
+/// 由 [`Ast::into_events`] 创建的事件迭代器。
+#[derive(Debug)]
+pub struct IntoEvents<'a> {
+    stack: Vec<(vec::IntoIter<Node<'a>>, Option<TagEnd>)>,
+}
+
+impl<'a> Iterator for IntoEvents<'a> {
+    type Item = Event<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let (children, end) = self.stack.last_mut()?;
+            match children.next() {
+                Some(Node {
+                    kind: NodeKind::Tag(tag),
+                    children,
+                    ..
+                }) => {
+                    let end = tag.to_end();
+                    self.stack.push((children.into_iter(), Some(end)));
+                    return Some(Event::Start(tag));
+                }
+                Some(Node {
+                    kind: NodeKind::Leaf(event),
+                    ..
+                }) => return Some(event),
+                None => {
+                    let end = end.take();
+                    self.stack.pop();
+                    if let Some(end) = end {
+                        return Some(Event::End(end));
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Options, Parser};
+
+    #[test]
+    fn test_roundtrip() {
+        let text =
+            "# Heading\n\n> quote with *emphasis*\n\n- a\n- b\n\n| a | b |\n|---|---|\n| c | d |\n";
+        let events: Vec<_> = Parser::new_ext(text, Options::ENABLE_TABLES).collect();
+        let ast = Ast::from_events(events.clone());
+        assert_eq!(ast.children.len(), 4);
+        let roundtrip: Vec<_> = ast.into_events().collect();
+        assert_eq!(events, roundtrip);
+    }
+
+    #[test]
+    fn test_spans() {
+        let text = "para\n\n*em*";
+        let ast = Ast::from_offset_events(Parser::new(text).into_offset_iter());
+        assert_eq!(ast.children[0].span, 0..5);
+        assert_eq!(&text[ast.children[1].children[0].span.clone()], "*em*");
+    }
+
+    #[test]
+    fn test_unbalanced_input() {
+        let ast = Ast::from_events(vec![
+            Event::End(TagEnd::Paragraph),
+            Event::Start(Tag::Paragraph(None)),
+            Event::Start(Tag::Emphasis),
+            Event::Text("x".into()),
+        ]);
+        assert_eq!(ast.children.len(), 1);
+        assert_eq!(ast.children[0].text(), "x");
+        let events: Vec<_> = ast.into_events().collect();
+        assert_eq!(events.last(), Some(&Event::End(TagEnd::Paragraph)));
+        assert_eq!(events.len(), 5);
+    }
+
+    #[test]
+    fn test_mutation() {
+        let mut ast = Ast::from_events(Parser::new("a *b* `c`"));
+        ast.for_each_mut(&mut |node| {
+            if let NodeKind::Tag(tag @ Tag::Emphasis) = &mut node.kind {
+                *tag = Tag::Strong;
+            }
+        });
+        let strong = ast.find(&mut |node| node.tag() == Some(&Tag::Strong));
+        assert_eq!(strong.map(Node::text).as_deref(), Some("b"));
+        ast.retain(&mut |node| !matches!(node.event(), Some(Event::Code(_, _))));
+        assert_eq!(ast.children[0].text(), "a b ");
+        let events: Vec<_> = ast.into_events().collect();
+        assert_eq!(events[4], Event::End(TagEnd::Strong));
+    }
+}
@@ -0,0 +1,216 @@
+//! `pulldown` command line tool.
+//!
+//! ```text
+//! pulldown <html|events|chunk|toc|fmt> [--options tables,gfm,...] [FILE...]
+//! ```
+//!
+//! Input is read from the given files (concatenated, separated by a blank line)
+//! or from standard input.
+
+#![forbid(unsafe_code)]
+
+use std::{
+    env,
+    fs::File,
+    io::{self, BufWriter, Read, Write},
+    ops::Range,
+    process,
+};
+
+use pulldown_cmark::{
+    chunk::{ChunkConfig, Chunker},
+    html,
+    utils::Slugger,
+    Event, Options, Parser, Tag, TagEnd,
+};
+
+const COMMANDS: &str = "\
+Commands:
+    html    render the input as HTML
+    events  print the event stream as JSON lines
+    chunk   print top-level blocks as JSON lines
+    toc     print the heading outline
+    fmt     normalize block spacing and trailing whitespace";
+
+fn brief(program: &str) -> String {
+    format!(
+        "Usage: {} <COMMAND> [options] [FILE...]\n\n{}",
+        program, COMMANDS
+    )
+}
+
+fn fail(message: &str, usage: &str) -> ! {
+    eprintln!("{}\n{}", message, usage);
+    process::exit(1);
+}
+
+fn print_html(text: &str, opts: Options, out: &mut dyn Write) -> io::Result<()> {
+    html::write_html_io(out, Parser::new_ext(text, opts))
+}
+
+fn print_events(text: &str, opts: Options, out: &mut dyn Write) -> io::Result<()> {
+    for (event, range) in Parser::new_ext(text, opts).into_offset_iter() {
+        let line = serde_json::json!({
+            "event": event,
+            "span": [range.start, range.end],
+        });
+        writeln!(out, "{}", line)?;
+    }
+    Ok(())
+}
+
+fn print_chunks(text: &str, opts: Options, out: &mut dyn Write) -> io::Result<()> {
+    for chunk in Chunker::new(text, opts, ChunkConfig::default()) {
+        serde_json::to_writer(&mut *out, &chunk)?;
+        writeln!(out)?;
+    }
+    Ok(())
+}
+
+fn print_toc(text: &str, opts: Options, out: &mut dyn Write) -> io::Result<()> {
+    let mut headings = Vec::new();
+    let mut current = None;
+    for event in Parser::new_ext(text, opts) {
+        match event {
+            Event::Start(Tag::Heading { level, id, .. }) => {
+                current = Some((level, id, String::new()));
+            }
+            Event::End(TagEnd::Heading(_)) => headings.extend(current.take()),
+            Event::Text(s) | Event::Code(s, _) => {
+                if let Some((_, _, title)) = &mut current {
+                    title.push_str(&s);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let min_level = headings.iter().map(|(level, ..)| *level as usize).min();
+    let mut slugger = Slugger::new();
+    for (level, id, title) in &headings {
+        let indent = (*level as usize - min_level.unwrap_or(1)) * 2;
+        let anchor = match id {
+            Some(id) => slugger.unique(id),
+            None => slugger.slug(title),
+        };
+        writeln!(out, "{:indent$}- [{}](#{})", "", title, anchor)?;
+    }
+    Ok(())
+}
+
+/// Source ranges of the top-level blocks, extended back to the start of their line
+/// so indentation is kept.
+fn top_level_blocks(text: &str, opts: Options) -> Vec<Range<usize>> {
+    let mut blocks = Vec::new();
+    let mut depth = 0usize;
+    for (event, range) in Parser::new_ext(text, opts).into_offset_iter() {
+        match event {
+            Event::Start(_) => {
+                if depth == 0 {
+                    blocks.push(range);
+                }
+                depth += 1;
+            }
+            Event::End(_) => depth -= 1,
+            _ if depth == 0 => blocks.push(range),
+            _ => {}
+        }
+    }
+    for block in &mut blocks {
+        let line_start = text[..block.start].rfind('\n').map_or(0, |ix| ix + 1);
+        if text[line_start..block.start].trim().is_empty() {
+            block.start = line_start;
+        }
+    }
+    blocks
+}
+
+/// Re-emits the source with exactly one blank line between top-level blocks,
+/// no trailing whitespace after a block and `\n` line endings. Text that produces
+/// no events, such as link reference definitions, is kept as is.
+fn print_fmt(text: &str, opts: Options, out: &mut dyn Write) -> io::Result<()> {
+    let mut pieces = Vec::new();
+    let mut cursor = 0;
+    for block in top_level_blocks(text, opts) {
+        if block.start < cursor {
+            continue;
+        }
+        pieces.push(&text[cursor..block.start]);
+        pieces.push(&text[block.clone()]);
+        cursor = block.end;
+    }
+    pieces.push(&text[cursor..]);
+
+    let mut first = true;
+    for piece in pieces {
+        // Drop leading blank lines but keep the indentation of the first line.
+        let Some(content_start) = piece.find(|c: char| !c.is_whitespace()) else {
+            continue;
+        };
+        let line_start = piece[..content_start].rfind('\n').map_or(0, |ix| ix + 1);
+        let piece = piece[line_start..].trim_end();
+        if !first {
+            writeln!(out)?;
+        }
+        first = false;
+        writeln!(out, "{}", piece.replace("\r\n", "\n"))?;
+    }
+    Ok(())
+}
+
+pub fn main() -> io::Result<()> {
+    let args: Vec<_> = env::args().collect();
+    let mut opts = getopts::Options::new();
+    opts.optflag("h", "help", "this help message");
+    opts.optopt(
+        "o",
+        "options",
+        "comma-separated parser options, e.g. `tables,gfm`",
+        "LIST",
+    );
+
+    let usage = opts.usage(&brief(&args[0]));
+    let matches = match opts.parse(&args[1..]) {
+        Ok(m) => m,
+        Err(f) => fail(&f.to_string(), &usage),
+    };
+    if matches.opt_present("help") {
+        println!("{}", usage);
+        return Ok(());
+    }
+    let parser_options = match matches.opt_str("options") {
+        Some(list) => list
+            .parse()
+            .unwrap_or_else(|e| fail(&format!("{}", e), &usage)),
+        None => Options::empty(),
+    };
+    let (command, files) = match matches.free.split_first() {
+        Some((command, files)) => (command.as_str(), files),
+        None => fail("missing command", &usage),
+    };
+    let run: fn(&str, Options, &mut dyn Write) -> io::Result<()> = match command {
+        "html" => print_html,
+        "events" => print_events,
+        "chunk" => print_chunks,
+        "toc" => print_toc,
+        "fmt" => print_fmt,
+        _ => fail(&format!("unknown command `{}`", command), &usage),
+    };
+
+    let mut input = String::new();
+    if files.is_empty() {
+        io::stdin().lock().read_to_string(&mut input)?;
+    } else {
+        for filename in files {
+            // a blank line keeps the last block of a file from running into the next file
+            if !input.is_empty() {
+                input.push_str(if input.ends_with('\n') { "\n" } else { "\n\n" });
+            }
+            File::open(filename)?.read_to_string(&mut input)?;
+        }
+    }
+
+    let mut out = BufWriter::new(io::stdout().lock());
+    run(&input, parser_options, &mut out)?;
+    out.flush()
+}
@@ -133,6 +133,8 @@ impl<'a> Chunker<'a> {
     fn extract_chunks(text: &str, options: Options) -> Vec<ChunkInfo> {
         use crate::Parser;
 
+        #[cfg(feature = "tracing")]
+        let _span = tracing::debug_span!("chunk_extraction", bytes = text.len()).entered();
         let parser = Parser::new_ext(text, options);
         let mut chunks = Vec::new();
         let mut depth = 0;
@@ -173,6 +175,8 @@ impl<'a> Chunker<'a> {
             }
         }
 
+        #[cfg(feature = "tracing")]
+        tracing::debug!(chunks = chunks.len(), "chunks extracted");
         chunks
     }
 
@@ -180,9 +184,9 @@ impl<'a> Chunker<'a> {
     fn is_block_tag(tag: &Tag) -> bool {
         matches!(
             tag,
-            Tag::Paragraph
+            Tag::Paragraph(_)
             | Tag::Heading { .. }
-            | Tag::BlockQuote(_)
+            | Tag::BlockQuote { .. }
             | Tag::CodeBlock(_)
             | Tag::HtmlBlock
             | Tag::List(_)
@@ -190,7 +194,7 @@ impl<'a> Chunker<'a> {
             | Tag::MetadataBlock(_)
             | Tag::Table(_)
             | Tag::DefinitionList
-            | Tag::ContainerBlock(_, _)
+            | Tag::ContainerBlock(..)
         )
     }
 
@@ -198,9 +202,9 @@ impl<'a> Chunker<'a> {
     fn tags_match(start: &Tag, end: &TagEnd) -> bool {
         matches!(
             (start, end),
-            (Tag::Paragraph, TagEnd::Paragraph)
+            (Tag::Paragraph(_), TagEnd::Paragraph)
             | (Tag::Heading { .. }, TagEnd::Heading(_))
-            | (Tag::BlockQuote(_), TagEnd::BlockQuote(_))
+            | (Tag::BlockQuote { .. }, TagEnd::BlockQuote(_))
             | (Tag::CodeBlock(_), TagEnd::CodeBlock)
             | (Tag::HtmlBlock, TagEnd::HtmlBlock)
             | (Tag::List(_), TagEnd::List(_))
@@ -208,7 +212,7 @@ impl<'a> Chunker<'a> {
             | (Tag::MetadataBlock(_), TagEnd::MetadataBlock(_))
             | (Tag::Table(_), TagEnd::Table)
             | (Tag::DefinitionList, TagEnd::DefinitionList)
-            | (Tag::ContainerBlock(_, _), TagEnd::ContainerBlock(_))
+            | (Tag::ContainerBlock(..), TagEnd::ContainerBlock(_))
         )
     }
 
@@ -216,8 +220,8 @@ impl<'a> Chunker<'a> {
     fn tag_to_kind(tag: &Tag) -> ChunkKind {
         match tag {
             Tag::Heading { level, .. } => ChunkKind::Heading(*level),
-            Tag::Paragraph => ChunkKind::Paragraph,
-            Tag::BlockQuote(_) => ChunkKind::BlockQuote,
+            Tag::Paragraph(_) => ChunkKind::Paragraph,
+            Tag::BlockQuote { .. } => ChunkKind::BlockQuote,
             Tag::CodeBlock(_) => ChunkKind::CodeBlock,
             Tag::HtmlBlock => ChunkKind::Other,
             Tag::List(_) => ChunkKind::List,
@@ -225,7 +229,7 @@ impl<'a> Chunker<'a> {
             Tag::MetadataBlock(_) => ChunkKind::Metadata,
             Tag::Table(_) => ChunkKind::Table,
             Tag::DefinitionList => ChunkKind::DefinitionList,
-            Tag::ContainerBlock(_, _) => ChunkKind::Other,
+            Tag::ContainerBlock(..) => ChunkKind::Other,
             _ => ChunkKind::Other,
         }
     }
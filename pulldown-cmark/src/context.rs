@@ -0,0 +1,249 @@
+//! 站点级共享解析上下文
+//!
+//! 静态站点生成器往往在一次构建中解析成千上万个页面，而每个页面都要重复相同的簿记工作。
+//! [`ParseContext`] 将这些状态集中起来，在多个文档之间复用：
+//!
+//! - 字符串驻留（[`ParseContext::intern`]）；
+//! - 共享的引用定义，例如站点级的链接表；
+//! - 共享的脚注命名空间；
+//! - 标题锚点去重（[`Slugger`]）。
+//!
+//! ```rust
+//! use pulldown_cmark::{context::ParseContext, Event, Options, Parser, Tag};
+//!
+//! let mut context = ParseContext::new();
+//! let links = Parser::new("[home]: https://example.com");
+//! context.add_reference_definitions(links.reference_definitions());
+//!
+//! let mut parser = context.parser("Go [home]", Options::empty());
+//! assert!(matches!(
+//!     parser.nth(2),
+//!     Some(Event::Start(Tag::Link { dest_url, .. })) if dest_url.as_ref() == "https://example.com"
+//! ));
+//! ```
+
+use alloc::{borrow::ToOwned, string::String};
+#[cfg(all(feature = "std", not(feature = "hashbrown")))]
+use std::collections::{HashMap, HashSet};
+
+#[cfg(feature = "hashbrown")]
+use hashbrown::{HashMap, HashSet};
+use unicase::UniCase;
+
+use crate::{
+    linklabel::{FootnoteLabel, LinkLabel},
+    parse::LinkDef,
+    utils::StringInterner,
+    BrokenFootnote, BrokenLink, CowStr, FootnoteDefs, Options, Parser, ParserCallbacks, RefDefs,
+    Slugger,
+};
+
+/// 驻留字符串的句柄，由 [`ParseContext::intern`] 返回。
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Symbol(pub(crate) u32);
+
+/// 可在多个文档之间复用的解析状态。
+#[derive(Clone, Debug, Default)]
+pub struct ParseContext {
+    strings: StringInterner,
+    refdefs: RefDefs<'static>,
+    footnotes: HashSet<FootnoteLabel<'static>>,
+    slugger: Slugger,
+}
+
+impl ParseContext {
+    /// 创建空的上下文。
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 驻留字符串，相同内容总是返回相同的 [`Symbol`]。
+    pub fn intern(&mut self, s: &str) -> Symbol {
+        self.strings.insert(s)
+    }
+
+    /// 返回驻留的字符串。
+    ///
+    /// # Panics
+    ///
+    /// 如果 `symbol` 不是由这个上下文创建的。
+    pub fn resolve(&self, symbol: Symbol) -> &str {
+        self.strings.resolve(symbol)
+    }
+
+    /// 返回驻留的全部字符串，例如用 [`StringInterner::intern_event`] 让事件借用它们。
+    pub fn strings(&self) -> &StringInterner {
+        &self.strings
+    }
+
+    /// 定义共享的引用定义。与 CommonMark 一致，已存在的标签保持不变。
+    pub fn define_reference(&mut self, label: &str, dest: &str, title: Option<&str>) {
+        let label = UniCase::new(CowStr::from(label.to_owned()));
+        self.refdefs.0.entry(label).or_insert_with(|| LinkDef {
+            dest: dest.to_owned().into(),
+            title: title.map(|title| title.to_owned().into()),
+            span: 0..0,
+        });
+    }
+
+    /// 复制某个文档的全部引用定义，例如站点级的链接表。
+    pub fn add_reference_definitions(&mut self, defs: &RefDefs<'_>) {
+        for (label, def) in defs.iter() {
+            let label = UniCase::new(CowStr::from(label.to_owned()));
+            self.refdefs
+                .0
+                .entry(label)
+                .or_insert_with(|| def.clone().into_static());
+        }
+    }
+
+    /// 按标签（忽略大小写）查找共享的引用定义。
+    pub fn reference<'a>(&'a self, label: &'a str) -> Option<&'a LinkDef<'static>> {
+        self.refdefs.get(label)
+    }
+
+    /// 将脚注标签加入共享命名空间，使其他文档可以引用它。
+    pub fn define_footnote(&mut self, label: &str) {
+        self.footnotes
+            .insert(UniCase::new(CowStr::from(label.to_owned())));
+    }
+
+    /// 将某个文档定义的全部脚注标签加入共享命名空间。
+    pub fn add_footnote_definitions(&mut self, defs: &FootnoteDefs<'_>) {
+        for (label, _) in defs.iter() {
+            self.define_footnote(label);
+        }
+    }
+
+    /// 检查脚注标签是否在共享命名空间中（忽略大小写）。
+    pub fn has_footnote(&self, label: &str) -> bool {
+        self.footnotes.contains(&UniCase::new(label.into()))
+    }
+
+    /// 返回标题文本在整个上下文中唯一的锚点，见 [`Slugger::slug`]。
+    pub fn slug(&mut self, text: &str) -> String {
+        self.slugger.slug(text)
+    }
+
+    /// 返回在整个上下文中唯一的锚点：重复的 `foo` 依次变为 `foo-1`、`foo-2`。
+    pub fn unique_slug(&mut self, slug: &str) -> String {
+        self.slugger.unique(slug)
+    }
+
+    /// 清空已分配的锚点，例如在每个页面开始时调用。
+    pub fn clear_slugs(&mut self) {
+        self.slugger.reset();
+    }
+
+    /// 返回用共享定义解析缺失引用和脚注的回调。
+    pub fn callbacks(&self) -> ContextCallbacks<'_> {
+        ContextCallbacks { context: self }
+    }
+
+    /// 创建使用 [`ParseContext::callbacks`] 的解析器。
+    pub fn parser<'c: 'input, 'input>(
+        &'c self,
+        text: &'input str,
+        options: Options,
+    ) -> Parser<'input, ContextCallbacks<'c>> {
+        Parser::new_with_callbacks(text, options, self.callbacks())
+    }
+}
+
+/// 由 [`ParseContext::callbacks`] 创建的回调。
+///
+/// 文档自身的定义总是优先；只有缺失的引用和脚注才会在上下文中查找。
+#[derive(Clone, Copy, Debug)]
+pub struct ContextCallbacks<'c> {
+    context: &'c ParseContext,
+}
+
+impl<'c: 'input, 'input> ParserCallbacks<'input> for ContextCallbacks<'c> {
+    fn handle_broken_link(
+        &mut self,
+        link: BrokenLink<'input>,
+    ) -> Option<(CowStr<'input>, CowStr<'input>)> {
+        // Shorten the map's lifetime so the label can be looked up without copying it.
+        let refdefs: &'input HashMap<LinkLabel<'input>, LinkDef<'input>> = &self.context.refdefs.0;
+        let def = refdefs.get(&UniCase::new(link.reference))?;
+        let title = def.title.as_deref().unwrap_or("");
+        Some((CowStr::Borrowed(&def.dest), CowStr::Borrowed(title)))
+    }
+
+    fn handle_broken_footnote(
+        &mut self,
+        footnote: BrokenFootnote<'input>,
+    ) -> Option<(CowStr<'input>, Option<CowStr<'input>>)> {
+        // The definition lives in another document, so only the label is supplied.
+        self.context
+            .has_footnote(&footnote.reference)
+            .then_some((footnote.reference, None))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use super::*;
+    use crate::{Event, Tag};
+
+    #[test]
+    fn test_intern() {
+        let mut context = ParseContext::new();
+        let a = context.intern("a");
+        let b = context.intern("b");
+        assert_ne!(a, b);
+        assert_eq!(context.intern("a"), a);
+        assert_eq!(context.resolve(b), "b");
+    }
+
+    #[test]
+    fn test_shared_references() {
+        let mut context = ParseContext::new();
+        context.define_reference("Site", "/site", Some("Site title"));
+        context.define_reference("site", "/ignored", None);
+        let text = "[site] [local]\n\n[local]: /local";
+        let links: Vec<_> = context
+            .parser(text, Options::empty())
+            .filter_map(|event| match event {
+                Event::Start(Tag::Link {
+                    dest_url, title, ..
+                }) => Some((dest_url, title)),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(
+            links,
+            [
+                ("/site".into(), "Site title".into()),
+                ("/local".into(), "".into())
+            ]
+        );
+    }
+
+    #[test]
+    fn test_shared_footnotes() {
+        let mut context = ParseContext::new();
+        let notes = Parser::new_ext("[^shared]: Defined elsewhere", Options::ENABLE_FOOTNOTES);
+        context.add_footnote_definitions(notes.footnote_definitions());
+        let text = "[^shared] [^missing]";
+        let refs: Vec<_> = context
+            .parser(text, Options::ENABLE_FOOTNOTES)
+            .filter(|event| matches!(event, Event::FootnoteReference(_)))
+            .collect();
+        assert_eq!(refs, [Event::FootnoteReference("shared".into())]);
+    }
+
+    #[test]
+    fn test_unique_slug() {
+        let mut context = ParseContext::new();
+        assert_eq!(context.unique_slug("foo"), "foo");
+        assert_eq!(context.unique_slug("foo-1"), "foo-1");
+        assert_eq!(context.unique_slug("foo"), "foo-2");
+        assert_eq!(context.unique_slug("foo"), "foo-3");
+        assert_eq!(context.slug("Foo"), "foo-4");
+        context.clear_slugs();
+        assert_eq!(context.unique_slug("foo"), "foo");
+    }
+}
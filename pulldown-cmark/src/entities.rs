@@ -19,2140 +19,3167 @@
 // THE SOFTWARE.
 
 //! Expansions of HTML5 entities
+//!
+//! Entities are found through a minimal perfect hash, generated together with
+//! the table, so a lookup hashes the name once and compares a single entry.
+//! Names and expansions are stored as two concatenated strings indexed by
+//! 16-bit offsets, which keeps the table small and free of relocations.
+//!
+//! With the `minimal-entities` feature only the XML entities and the most
+//! common typographic ones are recognized.
 
 // Autogenerated by mk_entities.py
 
-static ENTITIES: [(&[u8], &str); 2125] = [
-    (b"AElig", "\u{00C6}"),
-    (b"AMP", "\u{0026}"),
-    (b"Aacute", "\u{00C1}"),
-    (b"Abreve", "\u{0102}"),
-    (b"Acirc", "\u{00C2}"),
-    (b"Acy", "\u{0410}"),
-    (b"Afr", "\u{1D504}"),
-    (b"Agrave", "\u{00C0}"),
-    (b"Alpha", "\u{0391}"),
-    (b"Amacr", "\u{0100}"),
-    (b"And", "\u{2A53}"),
-    (b"Aogon", "\u{0104}"),
-    (b"Aopf", "\u{1D538}"),
-    (b"ApplyFunction", "\u{2061}"),
-    (b"Aring", "\u{00C5}"),
-    (b"Ascr", "\u{1D49C}"),
-    (b"Assign", "\u{2254}"),
-    (b"Atilde", "\u{00C3}"),
-    (b"Auml", "\u{00C4}"),
-    (b"Backslash", "\u{2216}"),
-    (b"Barv", "\u{2AE7}"),
-    (b"Barwed", "\u{2306}"),
-    (b"Bcy", "\u{0411}"),
-    (b"Because", "\u{2235}"),
-    (b"Bernoullis", "\u{212C}"),
-    (b"Beta", "\u{0392}"),
-    (b"Bfr", "\u{1D505}"),
-    (b"Bopf", "\u{1D539}"),
-    (b"Breve", "\u{02D8}"),
-    (b"Bscr", "\u{212C}"),
-    (b"Bumpeq", "\u{224E}"),
-    (b"CHcy", "\u{0427}"),
-    (b"COPY", "\u{00A9}"),
-    (b"Cacute", "\u{0106}"),
-    (b"Cap", "\u{22D2}"),
-    (b"CapitalDifferentialD", "\u{2145}"),
-    (b"Cayleys", "\u{212D}"),
-    (b"Ccaron", "\u{010C}"),
-    (b"Ccedil", "\u{00C7}"),
-    (b"Ccirc", "\u{0108}"),
-    (b"Cconint", "\u{2230}"),
-    (b"Cdot", "\u{010A}"),
-    (b"Cedilla", "\u{00B8}"),
-    (b"CenterDot", "\u{00B7}"),
-    (b"Cfr", "\u{212D}"),
-    (b"Chi", "\u{03A7}"),
-    (b"CircleDot", "\u{2299}"),
-    (b"CircleMinus", "\u{2296}"),
-    (b"CirclePlus", "\u{2295}"),
-    (b"CircleTimes", "\u{2297}"),
-    (b"ClockwiseContourIntegral", "\u{2232}"),
-    (b"CloseCurlyDoubleQuote", "\u{201D}"),
-    (b"CloseCurlyQuote", "\u{2019}"),
-    (b"Colon", "\u{2237}"),
-    (b"Colone", "\u{2A74}"),
-    (b"Congruent", "\u{2261}"),
-    (b"Conint", "\u{222F}"),
-    (b"ContourIntegral", "\u{222E}"),
-    (b"Copf", "\u{2102}"),
-    (b"Coproduct", "\u{2210}"),
-    (b"CounterClockwiseContourIntegral", "\u{2233}"),
-    (b"Cross", "\u{2A2F}"),
-    (b"Cscr", "\u{1D49E}"),
-    (b"Cup", "\u{22D3}"),
-    (b"CupCap", "\u{224D}"),
-    (b"DD", "\u{2145}"),
-    (b"DDotrahd", "\u{2911}"),
-    (b"DJcy", "\u{0402}"),
-    (b"DScy", "\u{0405}"),
-    (b"DZcy", "\u{040F}"),
-    (b"Dagger", "\u{2021}"),
-    (b"Darr", "\u{21A1}"),
-    (b"Dashv", "\u{2AE4}"),
-    (b"Dcaron", "\u{010E}"),
-    (b"Dcy", "\u{0414}"),
-    (b"Del", "\u{2207}"),
-    (b"Delta", "\u{0394}"),
-    (b"Dfr", "\u{1D507}"),
-    (b"DiacriticalAcute", "\u{00B4}"),
-    (b"DiacriticalDot", "\u{02D9}"),
-    (b"DiacriticalDoubleAcute", "\u{02DD}"),
-    (b"DiacriticalGrave", "\u{0060}"),
-    (b"DiacriticalTilde", "\u{02DC}"),
-    (b"Diamond", "\u{22C4}"),
-    (b"DifferentialD", "\u{2146}"),
-    (b"Dopf", "\u{1D53B}"),
-    (b"Dot", "\u{00A8}"),
-    (b"DotDot", "\u{20DC}"),
-    (b"DotEqual", "\u{2250}"),
-    (b"DoubleContourIntegral", "\u{222F}"),
-    (b"DoubleDot", "\u{00A8}"),
-    (b"DoubleDownArrow", "\u{21D3}"),
-    (b"DoubleLeftArrow", "\u{21D0}"),
-    (b"DoubleLeftRightArrow", "\u{21D4}"),
-    (b"DoubleLeftTee", "\u{2AE4}"),
-    (b"DoubleLongLeftArrow", "\u{27F8}"),
-    (b"DoubleLongLeftRightArrow", "\u{27FA}"),
-    (b"DoubleLongRightArrow", "\u{27F9}"),
-    (b"DoubleRightArrow", "\u{21D2}"),
-    (b"DoubleRightTee", "\u{22A8}"),
-    (b"DoubleUpArrow", "\u{21D1}"),
-    (b"DoubleUpDownArrow", "\u{21D5}"),
-    (b"DoubleVerticalBar", "\u{2225}"),
-    (b"DownArrow", "\u{2193}"),
-    (b"DownArrowBar", "\u{2913}"),
-    (b"DownArrowUpArrow", "\u{21F5}"),
-    (b"DownBreve", "\u{0311}"),
-    (b"DownLeftRightVector", "\u{2950}"),
-    (b"DownLeftTeeVector", "\u{295E}"),
-    (b"DownLeftVector", "\u{21BD}"),
-    (b"DownLeftVectorBar", "\u{2956}"),
-    (b"DownRightTeeVector", "\u{295F}"),
-    (b"DownRightVector", "\u{21C1}"),
-    (b"DownRightVectorBar", "\u{2957}"),
-    (b"DownTee", "\u{22A4}"),
-    (b"DownTeeArrow", "\u{21A7}"),
-    (b"Downarrow", "\u{21D3}"),
-    (b"Dscr", "\u{1D49F}"),
-    (b"Dstrok", "\u{0110}"),
-    (b"ENG", "\u{014A}"),
-    (b"ETH", "\u{00D0}"),
-    (b"Eacute", "\u{00C9}"),
-    (b"Ecaron", "\u{011A}"),
-    (b"Ecirc", "\u{00CA}"),
-    (b"Ecy", "\u{042D}"),
-    (b"Edot", "\u{0116}"),
-    (b"Efr", "\u{1D508}"),
-    (b"Egrave", "\u{00C8}"),
-    (b"Element", "\u{2208}"),
-    (b"Emacr", "\u{0112}"),
-    (b"EmptySmallSquare", "\u{25FB}"),
-    (b"EmptyVerySmallSquare", "\u{25AB}"),
-    (b"Eogon", "\u{0118}"),
-    (b"Eopf", "\u{1D53C}"),
-    (b"Epsilon", "\u{0395}"),
-    (b"Equal", "\u{2A75}"),
-    (b"EqualTilde", "\u{2242}"),
-    (b"Equilibrium", "\u{21CC}"),
-    (b"Escr", "\u{2130}"),
-    (b"Esim", "\u{2A73}"),
-    (b"Eta", "\u{0397}"),
-    (b"Euml", "\u{00CB}"),
-    (b"Exists", "\u{2203}"),
-    (b"ExponentialE", "\u{2147}"),
-    (b"Fcy", "\u{0424}"),
-    (b"Ffr", "\u{1D509}"),
-    (b"FilledSmallSquare", "\u{25FC}"),
-    (b"FilledVerySmallSquare", "\u{25AA}"),
-    (b"Fopf", "\u{1D53D}"),
-    (b"ForAll", "\u{2200}"),
-    (b"Fouriertrf", "\u{2131}"),
-    (b"Fscr", "\u{2131}"),
-    (b"GJcy", "\u{0403}"),
-    (b"GT", "\u{003E}"),
-    (b"Gamma", "\u{0393}"),
-    (b"Gammad", "\u{03DC}"),
-    (b"Gbreve", "\u{011E}"),
-    (b"Gcedil", "\u{0122}"),
-    (b"Gcirc", "\u{011C}"),
-    (b"Gcy", "\u{0413}"),
-    (b"Gdot", "\u{0120}"),
-    (b"Gfr", "\u{1D50A}"),
-    (b"Gg", "\u{22D9}"),
-    (b"Gopf", "\u{1D53E}"),
-    (b"GreaterEqual", "\u{2265}"),
-    (b"GreaterEqualLess", "\u{22DB}"),
-    (b"GreaterFullEqual", "\u{2267}"),
-    (b"GreaterGreater", "\u{2AA2}"),
-    (b"GreaterLess", "\u{2277}"),
-    (b"GreaterSlantEqual", "\u{2A7E}"),
-    (b"GreaterTilde", "\u{2273}"),
-    (b"Gscr", "\u{1D4A2}"),
-    (b"Gt", "\u{226B}"),
-    (b"HARDcy", "\u{042A}"),
-    (b"Hacek", "\u{02C7}"),
-    (b"Hat", "\u{005E}"),
-    (b"Hcirc", "\u{0124}"),
-    (b"Hfr", "\u{210C}"),
-    (b"HilbertSpace", "\u{210B}"),
-    (b"Hopf", "\u{210D}"),
-    (b"HorizontalLine", "\u{2500}"),
-    (b"Hscr", "\u{210B}"),
-    (b"Hstrok", "\u{0126}"),
-    (b"HumpDownHump", "\u{224E}"),
-    (b"HumpEqual", "\u{224F}"),
-    (b"IEcy", "\u{0415}"),
-    (b"IJlig", "\u{0132}"),
-    (b"IOcy", "\u{0401}"),
-    (b"Iacute", "\u{00CD}"),
-    (b"Icirc", "\u{00CE}"),
-    (b"Icy", "\u{0418}"),
-    (b"Idot", "\u{0130}"),
-    (b"Ifr", "\u{2111}"),
-    (b"Igrave", "\u{00CC}"),
-    (b"Im", "\u{2111}"),
-    (b"Imacr", "\u{012A}"),
-    (b"ImaginaryI", "\u{2148}"),
-    (b"Implies", "\u{21D2}"),
-    (b"Int", "\u{222C}"),
-    (b"Integral", "\u{222B}"),
-    (b"Intersection", "\u{22C2}"),
-    (b"InvisibleComma", "\u{2063}"),
-    (b"InvisibleTimes", "\u{2062}"),
-    (b"Iogon", "\u{012E}"),
-    (b"Iopf", "\u{1D540}"),
-    (b"Iota", "\u{0399}"),
-    (b"Iscr", "\u{2110}"),
-    (b"Itilde", "\u{0128}"),
-    (b"Iukcy", "\u{0406}"),
-    (b"Iuml", "\u{00CF}"),
-    (b"Jcirc", "\u{0134}"),
-    (b"Jcy", "\u{0419}"),
-    (b"Jfr", "\u{1D50D}"),
-    (b"Jopf", "\u{1D541}"),
-    (b"Jscr", "\u{1D4A5}"),
-    (b"Jsercy", "\u{0408}"),
-    (b"Jukcy", "\u{0404}"),
-    (b"KHcy", "\u{0425}"),
-    (b"KJcy", "\u{040C}"),
-    (b"Kappa", "\u{039A}"),
-    (b"Kcedil", "\u{0136}"),
-    (b"Kcy", "\u{041A}"),
-    (b"Kfr", "\u{1D50E}"),
-    (b"Kopf", "\u{1D542}"),
-    (b"Kscr", "\u{1D4A6}"),
-    (b"LJcy", "\u{0409}"),
-    (b"LT", "\u{003C}"),
-    (b"Lacute", "\u{0139}"),
-    (b"Lambda", "\u{039B}"),
-    (b"Lang", "\u{27EA}"),
-    (b"Laplacetrf", "\u{2112}"),
-    (b"Larr", "\u{219E}"),
-    (b"Lcaron", "\u{013D}"),
-    (b"Lcedil", "\u{013B}"),
-    (b"Lcy", "\u{041B}"),
-    (b"LeftAngleBracket", "\u{27E8}"),
-    (b"LeftArrow", "\u{2190}"),
-    (b"LeftArrowBar", "\u{21E4}"),
-    (b"LeftArrowRightArrow", "\u{21C6}"),
-    (b"LeftCeiling", "\u{2308}"),
-    (b"LeftDoubleBracket", "\u{27E6}"),
-    (b"LeftDownTeeVector", "\u{2961}"),
-    (b"LeftDownVector", "\u{21C3}"),
-    (b"LeftDownVectorBar", "\u{2959}"),
-    (b"LeftFloor", "\u{230A}"),
-    (b"LeftRightArrow", "\u{2194}"),
-    (b"LeftRightVector", "\u{294E}"),
-    (b"LeftTee", "\u{22A3}"),
-    (b"LeftTeeArrow", "\u{21A4}"),
-    (b"LeftTeeVector", "\u{295A}"),
-    (b"LeftTriangle", "\u{22B2}"),
-    (b"LeftTriangleBar", "\u{29CF}"),
-    (b"LeftTriangleEqual", "\u{22B4}"),
-    (b"LeftUpDownVector", "\u{2951}"),
-    (b"LeftUpTeeVector", "\u{2960}"),
-    (b"LeftUpVector", "\u{21BF}"),
-    (b"LeftUpVectorBar", "\u{2958}"),
-    (b"LeftVector", "\u{21BC}"),
-    (b"LeftVectorBar", "\u{2952}"),
-    (b"Leftarrow", "\u{21D0}"),
-    (b"Leftrightarrow", "\u{21D4}"),
-    (b"LessEqualGreater", "\u{22DA}"),
-    (b"LessFullEqual", "\u{2266}"),
-    (b"LessGreater", "\u{2276}"),
-    (b"LessLess", "\u{2AA1}"),
-    (b"LessSlantEqual", "\u{2A7D}"),
-    (b"LessTilde", "\u{2272}"),
-    (b"Lfr", "\u{1D50F}"),
-    (b"Ll", "\u{22D8}"),
-    (b"Lleftarrow", "\u{21DA}"),
-    (b"Lmidot", "\u{013F}"),
-    (b"LongLeftArrow", "\u{27F5}"),
-    (b"LongLeftRightArrow", "\u{27F7}"),
-    (b"LongRightArrow", "\u{27F6}"),
-    (b"Longleftarrow", "\u{27F8}"),
-    (b"Longleftrightarrow", "\u{27FA}"),
-    (b"Longrightarrow", "\u{27F9}"),
-    (b"Lopf", "\u{1D543}"),
-    (b"LowerLeftArrow", "\u{2199}"),
-    (b"LowerRightArrow", "\u{2198}"),
-    (b"Lscr", "\u{2112}"),
-    (b"Lsh", "\u{21B0}"),
-    (b"Lstrok", "\u{0141}"),
-    (b"Lt", "\u{226A}"),
-    (b"Map", "\u{2905}"),
-    (b"Mcy", "\u{041C}"),
-    (b"MediumSpace", "\u{205F}"),
-    (b"Mellintrf", "\u{2133}"),
-    (b"Mfr", "\u{1D510}"),
-    (b"MinusPlus", "\u{2213}"),
-    (b"Mopf", "\u{1D544}"),
-    (b"Mscr", "\u{2133}"),
-    (b"Mu", "\u{039C}"),
-    (b"NJcy", "\u{040A}"),
-    (b"Nacute", "\u{0143}"),
-    (b"Ncaron", "\u{0147}"),
-    (b"Ncedil", "\u{0145}"),
-    (b"Ncy", "\u{041D}"),
-    (b"NegativeMediumSpace", "\u{200B}"),
-    (b"NegativeThickSpace", "\u{200B}"),
-    (b"NegativeThinSpace", "\u{200B}"),
-    (b"NegativeVeryThinSpace", "\u{200B}"),
-    (b"NestedGreaterGreater", "\u{226B}"),
-    (b"NestedLessLess", "\u{226A}"),
-    (b"NewLine", "\u{000A}"),
-    (b"Nfr", "\u{1D511}"),
-    (b"NoBreak", "\u{2060}"),
-    (b"NonBreakingSpace", "\u{00A0}"),
-    (b"Nopf", "\u{2115}"),
-    (b"Not", "\u{2AEC}"),
-    (b"NotCongruent", "\u{2262}"),
-    (b"NotCupCap", "\u{226D}"),
-    (b"NotDoubleVerticalBar", "\u{2226}"),
-    (b"NotElement", "\u{2209}"),
-    (b"NotEqual", "\u{2260}"),
-    (b"NotEqualTilde", "\u{2242}\u{0338}"),
-    (b"NotExists", "\u{2204}"),
-    (b"NotGreater", "\u{226F}"),
-    (b"NotGreaterEqual", "\u{2271}"),
-    (b"NotGreaterFullEqual", "\u{2267}\u{0338}"),
-    (b"NotGreaterGreater", "\u{226B}\u{0338}"),
-    (b"NotGreaterLess", "\u{2279}"),
-    (b"NotGreaterSlantEqual", "\u{2A7E}\u{0338}"),
-    (b"NotGreaterTilde", "\u{2275}"),
-    (b"NotHumpDownHump", "\u{224E}\u{0338}"),
-    (b"NotHumpEqual", "\u{224F}\u{0338}"),
-    (b"NotLeftTriangle", "\u{22EA}"),
-    (b"NotLeftTriangleBar", "\u{29CF}\u{0338}"),
-    (b"NotLeftTriangleEqual", "\u{22EC}"),
-    (b"NotLess", "\u{226E}"),
-    (b"NotLessEqual", "\u{2270}"),
-    (b"NotLessGreater", "\u{2278}"),
-    (b"NotLessLess", "\u{226A}\u{0338}"),
-    (b"NotLessSlantEqual", "\u{2A7D}\u{0338}"),
-    (b"NotLessTilde", "\u{2274}"),
-    (b"NotNestedGreaterGreater", "\u{2AA2}\u{0338}"),
-    (b"NotNestedLessLess", "\u{2AA1}\u{0338}"),
-    (b"NotPrecedes", "\u{2280}"),
-    (b"NotPrecedesEqual", "\u{2AAF}\u{0338}"),
-    (b"NotPrecedesSlantEqual", "\u{22E0}"),
-    (b"NotReverseElement", "\u{220C}"),
-    (b"NotRightTriangle", "\u{22EB}"),
-    (b"NotRightTriangleBar", "\u{29D0}\u{0338}"),
-    (b"NotRightTriangleEqual", "\u{22ED}"),
-    (b"NotSquareSubset", "\u{228F}\u{0338}"),
-    (b"NotSquareSubsetEqual", "\u{22E2}"),
-    (b"NotSquareSuperset", "\u{2290}\u{0338}"),
-    (b"NotSquareSupersetEqual", "\u{22E3}"),
-    (b"NotSubset", "\u{2282}\u{20D2}"),
-    (b"NotSubsetEqual", "\u{2288}"),
-    (b"NotSucceeds", "\u{2281}"),
-    (b"NotSucceedsEqual", "\u{2AB0}\u{0338}"),
-    (b"NotSucceedsSlantEqual", "\u{22E1}"),
-    (b"NotSucceedsTilde", "\u{227F}\u{0338}"),
-    (b"NotSuperset", "\u{2283}\u{20D2}"),
-    (b"NotSupersetEqual", "\u{2289}"),
-    (b"NotTilde", "\u{2241}"),
-    (b"NotTildeEqual", "\u{2244}"),
-    (b"NotTildeFullEqual", "\u{2247}"),
-    (b"NotTildeTilde", "\u{2249}"),
-    (b"NotVerticalBar", "\u{2224}"),
-    (b"Nscr", "\u{1D4A9}"),
-    (b"Ntilde", "\u{00D1}"),
-    (b"Nu", "\u{039D}"),
-    (b"OElig", "\u{0152}"),
-    (b"Oacute", "\u{00D3}"),
-    (b"Ocirc", "\u{00D4}"),
-    (b"Ocy", "\u{041E}"),
-    (b"Odblac", "\u{0150}"),
-    (b"Ofr", "\u{1D512}"),
-    (b"Ograve", "\u{00D2}"),
-    (b"Omacr", "\u{014C}"),
-    (b"Omega", "\u{03A9}"),
-    (b"Omicron", "\u{039F}"),
-    (b"Oopf", "\u{1D546}"),
-    (b"OpenCurlyDoubleQuote", "\u{201C}"),
-    (b"OpenCurlyQuote", "\u{2018}"),
-    (b"Or", "\u{2A54}"),
-    (b"Oscr", "\u{1D4AA}"),
-    (b"Oslash", "\u{00D8}"),
-    (b"Otilde", "\u{00D5}"),
-    (b"Otimes", "\u{2A37}"),
-    (b"Ouml", "\u{00D6}"),
-    (b"OverBar", "\u{203E}"),
-    (b"OverBrace", "\u{23DE}"),
-    (b"OverBracket", "\u{23B4}"),
-    (b"OverParenthesis", "\u{23DC}"),
-    (b"PartialD", "\u{2202}"),
-    (b"Pcy", "\u{041F}"),
-    (b"Pfr", "\u{1D513}"),
-    (b"Phi", "\u{03A6}"),
-    (b"Pi", "\u{03A0}"),
-    (b"PlusMinus", "\u{00B1}"),
-    (b"Poincareplane", "\u{210C}"),
-    (b"Popf", "\u{2119}"),
-    (b"Pr", "\u{2ABB}"),
-    (b"Precedes", "\u{227A}"),
-    (b"PrecedesEqual", "\u{2AAF}"),
-    (b"PrecedesSlantEqual", "\u{227C}"),
-    (b"PrecedesTilde", "\u{227E}"),
-    (b"Prime", "\u{2033}"),
-    (b"Product", "\u{220F}"),
-    (b"Proportion", "\u{2237}"),
-    (b"Proportional", "\u{221D}"),
-    (b"Pscr", "\u{1D4AB}"),
-    (b"Psi", "\u{03A8}"),
-    (b"QUOT", "\u{0022}"),
-    (b"Qfr", "\u{1D514}"),
-    (b"Qopf", "\u{211A}"),
-    (b"Qscr", "\u{1D4AC}"),
-    (b"RBarr", "\u{2910}"),
-    (b"REG", "\u{00AE}"),
-    (b"Racute", "\u{0154}"),
-    (b"Rang", "\u{27EB}"),
-    (b"Rarr", "\u{21A0}"),
-    (b"Rarrtl", "\u{2916}"),
-    (b"Rcaron", "\u{0158}"),
-    (b"Rcedil", "\u{0156}"),
-    (b"Rcy", "\u{0420}"),
-    (b"Re", "\u{211C}"),
-    (b"ReverseElement", "\u{220B}"),
-    (b"ReverseEquilibrium", "\u{21CB}"),
-    (b"ReverseUpEquilibrium", "\u{296F}"),
-    (b"Rfr", "\u{211C}"),
-    (b"Rho", "\u{03A1}"),
-    (b"RightAngleBracket", "\u{27E9}"),
-    (b"RightArrow", "\u{2192}"),
-    (b"RightArrowBar", "\u{21E5}"),
-    (b"RightArrowLeftArrow", "\u{21C4}"),
-    (b"RightCeiling", "\u{2309}"),
-    (b"RightDoubleBracket", "\u{27E7}"),
-    (b"RightDownTeeVector", "\u{295D}"),
-    (b"RightDownVector", "\u{21C2}"),
-    (b"RightDownVectorBar", "\u{2955}"),
-    (b"RightFloor", "\u{230B}"),
-    (b"RightTee", "\u{22A2}"),
-    (b"RightTeeArrow", "\u{21A6}"),
-    (b"RightTeeVector", "\u{295B}"),
-    (b"RightTriangle", "\u{22B3}"),
-    (b"RightTriangleBar", "\u{29D0}"),
-    (b"RightTriangleEqual", "\u{22B5}"),
-    (b"RightUpDownVector", "\u{294F}"),
-    (b"RightUpTeeVector", "\u{295C}"),
-    (b"RightUpVector", "\u{21BE}"),
-    (b"RightUpVectorBar", "\u{2954}"),
-    (b"RightVector", "\u{21C0}"),
-    (b"RightVectorBar", "\u{2953}"),
-    (b"Rightarrow", "\u{21D2}"),
-    (b"Ropf", "\u{211D}"),
-    (b"RoundImplies", "\u{2970}"),
-    (b"Rrightarrow", "\u{21DB}"),
-    (b"Rscr", "\u{211B}"),
-    (b"Rsh", "\u{21B1}"),
-    (b"RuleDelayed", "\u{29F4}"),
-    (b"SHCHcy", "\u{0429}"),
-    (b"SHcy", "\u{0428}"),
-    (b"SOFTcy", "\u{042C}"),
-    (b"Sacute", "\u{015A}"),
-    (b"Sc", "\u{2ABC}"),
-    (b"Scaron", "\u{0160}"),
-    (b"Scedil", "\u{015E}"),
-    (b"Scirc", "\u{015C}"),
-    (b"Scy", "\u{0421}"),
-    (b"Sfr", "\u{1D516}"),
-    (b"ShortDownArrow", "\u{2193}"),
-    (b"ShortLeftArrow", "\u{2190}"),
-    (b"ShortRightArrow", "\u{2192}"),
-    (b"ShortUpArrow", "\u{2191}"),
-    (b"Sigma", "\u{03A3}"),
-    (b"SmallCircle", "\u{2218}"),
-    (b"Sopf", "\u{1D54A}"),
-    (b"Sqrt", "\u{221A}"),
-    (b"Square", "\u{25A1}"),
-    (b"SquareIntersection", "\u{2293}"),
-    (b"SquareSubset", "\u{228F}"),
-    (b"SquareSubsetEqual", "\u{2291}"),
-    (b"SquareSuperset", "\u{2290}"),
-    (b"SquareSupersetEqual", "\u{2292}"),
-    (b"SquareUnion", "\u{2294}"),
-    (b"Sscr", "\u{1D4AE}"),
-    (b"Star", "\u{22C6}"),
-    (b"Sub", "\u{22D0}"),
-    (b"Subset", "\u{22D0}"),
-    (b"SubsetEqual", "\u{2286}"),
-    (b"Succeeds", "\u{227B}"),
-    (b"SucceedsEqual", "\u{2AB0}"),
-    (b"SucceedsSlantEqual", "\u{227D}"),
-    (b"SucceedsTilde", "\u{227F}"),
-    (b"SuchThat", "\u{220B}"),
-    (b"Sum", "\u{2211}"),
-    (b"Sup", "\u{22D1}"),
-    (b"Superset", "\u{2283}"),
-    (b"SupersetEqual", "\u{2287}"),
-    (b"Supset", "\u{22D1}"),
-    (b"THORN", "\u{00DE}"),
-    (b"TRADE", "\u{2122}"),
-    (b"TSHcy", "\u{040B}"),
-    (b"TScy", "\u{0426}"),
-    (b"Tab", "\u{0009}"),
-    (b"Tau", "\u{03A4}"),
-    (b"Tcaron", "\u{0164}"),
-    (b"Tcedil", "\u{0162}"),
-    (b"Tcy", "\u{0422}"),
-    (b"Tfr", "\u{1D517}"),
-    (b"Therefore", "\u{2234}"),
-    (b"Theta", "\u{0398}"),
-    (b"ThickSpace", "\u{205F}\u{200A}"),
-    (b"ThinSpace", "\u{2009}"),
-    (b"Tilde", "\u{223C}"),
-    (b"TildeEqual", "\u{2243}"),
-    (b"TildeFullEqual", "\u{2245}"),
-    (b"TildeTilde", "\u{2248}"),
-    (b"Topf", "\u{1D54B}"),
-    (b"TripleDot", "\u{20DB}"),
-    (b"Tscr", "\u{1D4AF}"),
-    (b"Tstrok", "\u{0166}"),
-    (b"Uacute", "\u{00DA}"),
-    (b"Uarr", "\u{219F}"),
-    (b"Uarrocir", "\u{2949}"),
-    (b"Ubrcy", "\u{040E}"),
-    (b"Ubreve", "\u{016C}"),
-    (b"Ucirc", "\u{00DB}"),
-    (b"Ucy", "\u{0423}"),
-    (b"Udblac", "\u{0170}"),
-    (b"Ufr", "\u{1D518}"),
-    (b"Ugrave", "\u{00D9}"),
-    (b"Umacr", "\u{016A}"),
-    (b"UnderBar", "\u{005F}"),
-    (b"UnderBrace", "\u{23DF}"),
-    (b"UnderBracket", "\u{23B5}"),
-    (b"UnderParenthesis", "\u{23DD}"),
-    (b"Union", "\u{22C3}"),
-    (b"UnionPlus", "\u{228E}"),
-    (b"Uogon", "\u{0172}"),
-    (b"Uopf", "\u{1D54C}"),
-    (b"UpArrow", "\u{2191}"),
-    (b"UpArrowBar", "\u{2912}"),
-    (b"UpArrowDownArrow", "\u{21C5}"),
-    (b"UpDownArrow", "\u{2195}"),
-    (b"UpEquilibrium", "\u{296E}"),
-    (b"UpTee", "\u{22A5}"),
-    (b"UpTeeArrow", "\u{21A5}"),
-    (b"Uparrow", "\u{21D1}"),
-    (b"Updownarrow", "\u{21D5}"),
-    (b"UpperLeftArrow", "\u{2196}"),
-    (b"UpperRightArrow", "\u{2197}"),
-    (b"Upsi", "\u{03D2}"),
-    (b"Upsilon", "\u{03A5}"),
-    (b"Uring", "\u{016E}"),
-    (b"Uscr", "\u{1D4B0}"),
-    (b"Utilde", "\u{0168}"),
-    (b"Uuml", "\u{00DC}"),
-    (b"VDash", "\u{22AB}"),
-    (b"Vbar", "\u{2AEB}"),
-    (b"Vcy", "\u{0412}"),
-    (b"Vdash", "\u{22A9}"),
-    (b"Vdashl", "\u{2AE6}"),
-    (b"Vee", "\u{22C1}"),
-    (b"Verbar", "\u{2016}"),
-    (b"Vert", "\u{2016}"),
-    (b"VerticalBar", "\u{2223}"),
-    (b"VerticalLine", "\u{007C}"),
-    (b"VerticalSeparator", "\u{2758}"),
-    (b"VerticalTilde", "\u{2240}"),
-    (b"VeryThinSpace", "\u{200A}"),
-    (b"Vfr", "\u{1D519}"),
-    (b"Vopf", "\u{1D54D}"),
-    (b"Vscr", "\u{1D4B1}"),
-    (b"Vvdash", "\u{22AA}"),
-    (b"Wcirc", "\u{0174}"),
-    (b"Wedge", "\u{22C0}"),
-    (b"Wfr", "\u{1D51A}"),
-    (b"Wopf", "\u{1D54E}"),
-    (b"Wscr", "\u{1D4B2}"),
-    (b"Xfr", "\u{1D51B}"),
-    (b"Xi", "\u{039E}"),
-    (b"Xopf", "\u{1D54F}"),
-    (b"Xscr", "\u{1D4B3}"),
-    (b"YAcy", "\u{042F}"),
-    (b"YIcy", "\u{0407}"),
-    (b"YUcy", "\u{042E}"),
-    (b"Yacute", "\u{00DD}"),
-    (b"Ycirc", "\u{0176}"),
-    (b"Ycy", "\u{042B}"),
-    (b"Yfr", "\u{1D51C}"),
-    (b"Yopf", "\u{1D550}"),
-    (b"Yscr", "\u{1D4B4}"),
-    (b"Yuml", "\u{0178}"),
-    (b"ZHcy", "\u{0416}"),
-    (b"Zacute", "\u{0179}"),
-    (b"Zcaron", "\u{017D}"),
-    (b"Zcy", "\u{0417}"),
-    (b"Zdot", "\u{017B}"),
-    (b"ZeroWidthSpace", "\u{200B}"),
-    (b"Zeta", "\u{0396}"),
-    (b"Zfr", "\u{2128}"),
-    (b"Zopf", "\u{2124}"),
-    (b"Zscr", "\u{1D4B5}"),
-    (b"aacute", "\u{00E1}"),
-    (b"abreve", "\u{0103}"),
-    (b"ac", "\u{223E}"),
-    (b"acE", "\u{223E}\u{0333}"),
-    (b"acd", "\u{223F}"),
-    (b"acirc", "\u{00E2}"),
-    (b"acute", "\u{00B4}"),
-    (b"acy", "\u{0430}"),
-    (b"aelig", "\u{00E6}"),
-    (b"af", "\u{2061}"),
-    (b"afr", "\u{1D51E}"),
-    (b"agrave", "\u{00E0}"),
-    (b"alefsym", "\u{2135}"),
-    (b"aleph", "\u{2135}"),
-    (b"alpha", "\u{03B1}"),
-    (b"amacr", "\u{0101}"),
-    (b"amalg", "\u{2A3F}"),
-    (b"amp", "\u{0026}"),
-    (b"and", "\u{2227}"),
-    (b"andand", "\u{2A55}"),
-    (b"andd", "\u{2A5C}"),
-    (b"andslope", "\u{2A58}"),
-    (b"andv", "\u{2A5A}"),
-    (b"ang", "\u{2220}"),
-    (b"ange", "\u{29A4}"),
-    (b"angle", "\u{2220}"),
-    (b"angmsd", "\u{2221}"),
-    (b"angmsdaa", "\u{29A8}"),
-    (b"angmsdab", "\u{29A9}"),
-    (b"angmsdac", "\u{29AA}"),
-    (b"angmsdad", "\u{29AB}"),
-    (b"angmsdae", "\u{29AC}"),
-    (b"angmsdaf", "\u{29AD}"),
-    (b"angmsdag", "\u{29AE}"),
-    (b"angmsdah", "\u{29AF}"),
-    (b"angrt", "\u{221F}"),
-    (b"angrtvb", "\u{22BE}"),
-    (b"angrtvbd", "\u{299D}"),
-    (b"angsph", "\u{2222}"),
-    (b"angst", "\u{00C5}"),
-    (b"angzarr", "\u{237C}"),
-    (b"aogon", "\u{0105}"),
-    (b"aopf", "\u{1D552}"),
-    (b"ap", "\u{2248}"),
-    (b"apE", "\u{2A70}"),
-    (b"apacir", "\u{2A6F}"),
-    (b"ape", "\u{224A}"),
-    (b"apid", "\u{224B}"),
-    (b"apos", "\u{0027}"),
-    (b"approx", "\u{2248}"),
-    (b"approxeq", "\u{224A}"),
-    (b"aring", "\u{00E5}"),
-    (b"ascr", "\u{1D4B6}"),
-    (b"ast", "\u{002A}"),
-    (b"asymp", "\u{2248}"),
-    (b"asympeq", "\u{224D}"),
-    (b"atilde", "\u{00E3}"),
-    (b"auml", "\u{00E4}"),
-    (b"awconint", "\u{2233}"),
-    (b"awint", "\u{2A11}"),
-    (b"bNot", "\u{2AED}"),
-    (b"backcong", "\u{224C}"),
-    (b"backepsilon", "\u{03F6}"),
-    (b"backprime", "\u{2035}"),
-    (b"backsim", "\u{223D}"),
-    (b"backsimeq", "\u{22CD}"),
-    (b"barvee", "\u{22BD}"),
-    (b"barwed", "\u{2305}"),
-    (b"barwedge", "\u{2305}"),
-    (b"bbrk", "\u{23B5}"),
-    (b"bbrktbrk", "\u{23B6}"),
-    (b"bcong", "\u{224C}"),
-    (b"bcy", "\u{0431}"),
-    (b"bdquo", "\u{201E}"),
-    (b"becaus", "\u{2235}"),
-    (b"because", "\u{2235}"),
-    (b"bemptyv", "\u{29B0}"),
-    (b"bepsi", "\u{03F6}"),
-    (b"bernou", "\u{212C}"),
-    (b"beta", "\u{03B2}"),
-    (b"beth", "\u{2136}"),
-    (b"between", "\u{226C}"),
-    (b"bfr", "\u{1D51F}"),
-    (b"bigcap", "\u{22C2}"),
-    (b"bigcirc", "\u{25EF}"),
-    (b"bigcup", "\u{22C3}"),
-    (b"bigodot", "\u{2A00}"),
-    (b"bigoplus", "\u{2A01}"),
-    (b"bigotimes", "\u{2A02}"),
-    (b"bigsqcup", "\u{2A06}"),
-    (b"bigstar", "\u{2605}"),
-    (b"bigtriangledown", "\u{25BD}"),
-    (b"bigtriangleup", "\u{25B3}"),
-    (b"biguplus", "\u{2A04}"),
-    (b"bigvee", "\u{22C1}"),
-    (b"bigwedge", "\u{22C0}"),
-    (b"bkarow", "\u{290D}"),
-    (b"blacklozenge", "\u{29EB}"),
-    (b"blacksquare", "\u{25AA}"),
-    (b"blacktriangle", "\u{25B4}"),
-    (b"blacktriangledown", "\u{25BE}"),
-    (b"blacktriangleleft", "\u{25C2}"),
-    (b"blacktriangleright", "\u{25B8}"),
-    (b"blank", "\u{2423}"),
-    (b"blk12", "\u{2592}"),
-    (b"blk14", "\u{2591}"),
-    (b"blk34", "\u{2593}"),
-    (b"block", "\u{2588}"),
-    (b"bne", "\u{003D}\u{20E5}"),
-    (b"bnequiv", "\u{2261}\u{20E5}"),
-    (b"bnot", "\u{2310}"),
-    (b"bopf", "\u{1D553}"),
-    (b"bot", "\u{22A5}"),
-    (b"bottom", "\u{22A5}"),
-    (b"bowtie", "\u{22C8}"),
-    (b"boxDL", "\u{2557}"),
-    (b"boxDR", "\u{2554}"),
-    (b"boxDl", "\u{2556}"),
-    (b"boxDr", "\u{2553}"),
-    (b"boxH", "\u{2550}"),
-    (b"boxHD", "\u{2566}"),
-    (b"boxHU", "\u{2569}"),
-    (b"boxHd", "\u{2564}"),
-    (b"boxHu", "\u{2567}"),
-    (b"boxUL", "\u{255D}"),
-    (b"boxUR", "\u{255A}"),
-    (b"boxUl", "\u{255C}"),
-    (b"boxUr", "\u{2559}"),
-    (b"boxV", "\u{2551}"),
-    (b"boxVH", "\u{256C}"),
-    (b"boxVL", "\u{2563}"),
-    (b"boxVR", "\u{2560}"),
-    (b"boxVh", "\u{256B}"),
-    (b"boxVl", "\u{2562}"),
-    (b"boxVr", "\u{255F}"),
-    (b"boxbox", "\u{29C9}"),
-    (b"boxdL", "\u{2555}"),
-    (b"boxdR", "\u{2552}"),
-    (b"boxdl", "\u{2510}"),
-    (b"boxdr", "\u{250C}"),
-    (b"boxh", "\u{2500}"),
-    (b"boxhD", "\u{2565}"),
-    (b"boxhU", "\u{2568}"),
-    (b"boxhd", "\u{252C}"),
-    (b"boxhu", "\u{2534}"),
-    (b"boxminus", "\u{229F}"),
-    (b"boxplus", "\u{229E}"),
-    (b"boxtimes", "\u{22A0}"),
-    (b"boxuL", "\u{255B}"),
-    (b"boxuR", "\u{2558}"),
-    (b"boxul", "\u{2518}"),
-    (b"boxur", "\u{2514}"),
-    (b"boxv", "\u{2502}"),
-    (b"boxvH", "\u{256A}"),
-    (b"boxvL", "\u{2561}"),
-    (b"boxvR", "\u{255E}"),
-    (b"boxvh", "\u{253C}"),
-    (b"boxvl", "\u{2524}"),
-    (b"boxvr", "\u{251C}"),
-    (b"bprime", "\u{2035}"),
-    (b"breve", "\u{02D8}"),
-    (b"brvbar", "\u{00A6}"),
-    (b"bscr", "\u{1D4B7}"),
-    (b"bsemi", "\u{204F}"),
-    (b"bsim", "\u{223D}"),
-    (b"bsime", "\u{22CD}"),
-    (b"bsol", "\u{005C}"),
-    (b"bsolb", "\u{29C5}"),
-    (b"bsolhsub", "\u{27C8}"),
-    (b"bull", "\u{2022}"),
-    (b"bullet", "\u{2022}"),
-    (b"bump", "\u{224E}"),
-    (b"bumpE", "\u{2AAE}"),
-    (b"bumpe", "\u{224F}"),
-    (b"bumpeq", "\u{224F}"),
-    (b"cacute", "\u{0107}"),
-    (b"cap", "\u{2229}"),
-    (b"capand", "\u{2A44}"),
-    (b"capbrcup", "\u{2A49}"),
-    (b"capcap", "\u{2A4B}"),
-    (b"capcup", "\u{2A47}"),
-    (b"capdot", "\u{2A40}"),
-    (b"caps", "\u{2229}\u{FE00}"),
-    (b"caret", "\u{2041}"),
-    (b"caron", "\u{02C7}"),
-    (b"ccaps", "\u{2A4D}"),
-    (b"ccaron", "\u{010D}"),
-    (b"ccedil", "\u{00E7}"),
-    (b"ccirc", "\u{0109}"),
-    (b"ccups", "\u{2A4C}"),
-    (b"ccupssm", "\u{2A50}"),
-    (b"cdot", "\u{010B}"),
-    (b"cedil", "\u{00B8}"),
-    (b"cemptyv", "\u{29B2}"),
-    (b"cent", "\u{00A2}"),
-    (b"centerdot", "\u{00B7}"),
-    (b"cfr", "\u{1D520}"),
-    (b"chcy", "\u{0447}"),
-    (b"check", "\u{2713}"),
-    (b"checkmark", "\u{2713}"),
-    (b"chi", "\u{03C7}"),
-    (b"cir", "\u{25CB}"),
-    (b"cirE", "\u{29C3}"),
-    (b"circ", "\u{02C6}"),
-    (b"circeq", "\u{2257}"),
-    (b"circlearrowleft", "\u{21BA}"),
-    (b"circlearrowright", "\u{21BB}"),
-    (b"circledR", "\u{00AE}"),
-    (b"circledS", "\u{24C8}"),
-    (b"circledast", "\u{229B}"),
-    (b"circledcirc", "\u{229A}"),
-    (b"circleddash", "\u{229D}"),
-    (b"cire", "\u{2257}"),
-    (b"cirfnint", "\u{2A10}"),
-    (b"cirmid", "\u{2AEF}"),
-    (b"cirscir", "\u{29C2}"),
-    (b"clubs", "\u{2663}"),
-    (b"clubsuit", "\u{2663}"),
-    (b"colon", "\u{003A}"),
-    (b"colone", "\u{2254}"),
-    (b"coloneq", "\u{2254}"),
-    (b"comma", "\u{002C}"),
-    (b"commat", "\u{0040}"),
-    (b"comp", "\u{2201}"),
-    (b"compfn", "\u{2218}"),
-    (b"complement", "\u{2201}"),
-    (b"complexes", "\u{2102}"),
-    (b"cong", "\u{2245}"),
-    (b"congdot", "\u{2A6D}"),
-    (b"conint", "\u{222E}"),
-    (b"copf", "\u{1D554}"),
-    (b"coprod", "\u{2210}"),
-    (b"copy", "\u{00A9}"),
-    (b"copysr", "\u{2117}"),
-    (b"crarr", "\u{21B5}"),
-    (b"cross", "\u{2717}"),
-    (b"cscr", "\u{1D4B8}"),
-    (b"csub", "\u{2ACF}"),
-    (b"csube", "\u{2AD1}"),
-    (b"csup", "\u{2AD0}"),
-    (b"csupe", "\u{2AD2}"),
-    (b"ctdot", "\u{22EF}"),
-    (b"cudarrl", "\u{2938}"),
-    (b"cudarrr", "\u{2935}"),
-    (b"cuepr", "\u{22DE}"),
-    (b"cuesc", "\u{22DF}"),
-    (b"cularr", "\u{21B6}"),
-    (b"cularrp", "\u{293D}"),
-    (b"cup", "\u{222A}"),
-    (b"cupbrcap", "\u{2A48}"),
-    (b"cupcap", "\u{2A46}"),
-    (b"cupcup", "\u{2A4A}"),
-    (b"cupdot", "\u{228D}"),
-    (b"cupor", "\u{2A45}"),
-    (b"cups", "\u{222A}\u{FE00}"),
-    (b"curarr", "\u{21B7}"),
-    (b"curarrm", "\u{293C}"),
-    (b"curlyeqprec", "\u{22DE}"),
-    (b"curlyeqsucc", "\u{22DF}"),
-    (b"curlyvee", "\u{22CE}"),
-    (b"curlywedge", "\u{22CF}"),
-    (b"curren", "\u{00A4}"),
-    (b"curvearrowleft", "\u{21B6}"),
-    (b"curvearrowright", "\u{21B7}"),
-    (b"cuvee", "\u{22CE}"),
-    (b"cuwed", "\u{22CF}"),
-    (b"cwconint", "\u{2232}"),
-    (b"cwint", "\u{2231}"),
-    (b"cylcty", "\u{232D}"),
-    (b"dArr", "\u{21D3}"),
-    (b"dHar", "\u{2965}"),
-    (b"dagger", "\u{2020}"),
-    (b"daleth", "\u{2138}"),
-    (b"darr", "\u{2193}"),
-    (b"dash", "\u{2010}"),
-    (b"dashv", "\u{22A3}"),
-    (b"dbkarow", "\u{290F}"),
-    (b"dblac", "\u{02DD}"),
-    (b"dcaron", "\u{010F}"),
-    (b"dcy", "\u{0434}"),
-    (b"dd", "\u{2146}"),
-    (b"ddagger", "\u{2021}"),
-    (b"ddarr", "\u{21CA}"),
-    (b"ddotseq", "\u{2A77}"),
-    (b"deg", "\u{00B0}"),
-    (b"delta", "\u{03B4}"),
-    (b"demptyv", "\u{29B1}"),
-    (b"dfisht", "\u{297F}"),
-    (b"dfr", "\u{1D521}"),
-    (b"dharl", "\u{21C3}"),
-    (b"dharr", "\u{21C2}"),
-    (b"diam", "\u{22C4}"),
-    (b"diamond", "\u{22C4}"),
-    (b"diamondsuit", "\u{2666}"),
-    (b"diams", "\u{2666}"),
-    (b"die", "\u{00A8}"),
-    (b"digamma", "\u{03DD}"),
-    (b"disin", "\u{22F2}"),
-    (b"div", "\u{00F7}"),
-    (b"divide", "\u{00F7}"),
-    (b"divideontimes", "\u{22C7}"),
-    (b"divonx", "\u{22C7}"),
-    (b"djcy", "\u{0452}"),
-    (b"dlcorn", "\u{231E}"),
-    (b"dlcrop", "\u{230D}"),
-    (b"dollar", "\u{0024}"),
-    (b"dopf", "\u{1D555}"),
-    (b"dot", "\u{02D9}"),
-    (b"doteq", "\u{2250}"),
-    (b"doteqdot", "\u{2251}"),
-    (b"dotminus", "\u{2238}"),
-    (b"dotplus", "\u{2214}"),
-    (b"dotsquare", "\u{22A1}"),
-    (b"doublebarwedge", "\u{2306}"),
-    (b"downarrow", "\u{2193}"),
-    (b"downdownarrows", "\u{21CA}"),
-    (b"downharpoonleft", "\u{21C3}"),
-    (b"downharpoonright", "\u{21C2}"),
-    (b"drbkarow", "\u{2910}"),
-    (b"drcorn", "\u{231F}"),
-    (b"drcrop", "\u{230C}"),
-    (b"dscr", "\u{1D4B9}"),
-    (b"dscy", "\u{0455}"),
-    (b"dsol", "\u{29F6}"),
-    (b"dstrok", "\u{0111}"),
-    (b"dtdot", "\u{22F1}"),
-    (b"dtri", "\u{25BF}"),
-    (b"dtrif", "\u{25BE}"),
-    (b"duarr", "\u{21F5}"),
-    (b"duhar", "\u{296F}"),
-    (b"dwangle", "\u{29A6}"),
-    (b"dzcy", "\u{045F}"),
-    (b"dzigrarr", "\u{27FF}"),
-    (b"eDDot", "\u{2A77}"),
-    (b"eDot", "\u{2251}"),
-    (b"eacute", "\u{00E9}"),
-    (b"easter", "\u{2A6E}"),
-    (b"ecaron", "\u{011B}"),
-    (b"ecir", "\u{2256}"),
-    (b"ecirc", "\u{00EA}"),
-    (b"ecolon", "\u{2255}"),
-    (b"ecy", "\u{044D}"),
-    (b"edot", "\u{0117}"),
-    (b"ee", "\u{2147}"),
-    (b"efDot", "\u{2252}"),
-    (b"efr", "\u{1D522}"),
-    (b"eg", "\u{2A9A}"),
-    (b"egrave", "\u{00E8}"),
-    (b"egs", "\u{2A96}"),
-    (b"egsdot", "\u{2A98}"),
-    (b"el", "\u{2A99}"),
-    (b"elinters", "\u{23E7}"),
-    (b"ell", "\u{2113}"),
-    (b"els", "\u{2A95}"),
-    (b"elsdot", "\u{2A97}"),
-    (b"emacr", "\u{0113}"),
-    (b"empty", "\u{2205}"),
-    (b"emptyset", "\u{2205}"),
-    (b"emptyv", "\u{2205}"),
-    (b"emsp", "\u{2003}"),
-    (b"emsp13", "\u{2004}"),
-    (b"emsp14", "\u{2005}"),
-    (b"eng", "\u{014B}"),
-    (b"ensp", "\u{2002}"),
-    (b"eogon", "\u{0119}"),
-    (b"eopf", "\u{1D556}"),
-    (b"epar", "\u{22D5}"),
-    (b"eparsl", "\u{29E3}"),
-    (b"eplus", "\u{2A71}"),
-    (b"epsi", "\u{03B5}"),
-    (b"epsilon", "\u{03B5}"),
-    (b"epsiv", "\u{03F5}"),
-    (b"eqcirc", "\u{2256}"),
-    (b"eqcolon", "\u{2255}"),
-    (b"eqsim", "\u{2242}"),
-    (b"eqslantgtr", "\u{2A96}"),
-    (b"eqslantless", "\u{2A95}"),
-    (b"equals", "\u{003D}"),
-    (b"equest", "\u{225F}"),
-    (b"equiv", "\u{2261}"),
-    (b"equivDD", "\u{2A78}"),
-    (b"eqvparsl", "\u{29E5}"),
-    (b"erDot", "\u{2253}"),
-    (b"erarr", "\u{2971}"),
-    (b"escr", "\u{212F}"),
-    (b"esdot", "\u{2250}"),
-    (b"esim", "\u{2242}"),
-    (b"eta", "\u{03B7}"),
-    (b"eth", "\u{00F0}"),
-    (b"euml", "\u{00EB}"),
-    (b"euro", "\u{20AC}"),
-    (b"excl", "\u{0021}"),
-    (b"exist", "\u{2203}"),
-    (b"expectation", "\u{2130}"),
-    (b"exponentiale", "\u{2147}"),
-    (b"fallingdotseq", "\u{2252}"),
-    (b"fcy", "\u{0444}"),
-    (b"female", "\u{2640}"),
-    (b"ffilig", "\u{FB03}"),
-    (b"fflig", "\u{FB00}"),
-    (b"ffllig", "\u{FB04}"),
-    (b"ffr", "\u{1D523}"),
-    (b"filig", "\u{FB01}"),
-    (b"fjlig", "\u{0066}\u{006A}"),
-    (b"flat", "\u{266D}"),
-    (b"fllig", "\u{FB02}"),
-    (b"fltns", "\u{25B1}"),
-    (b"fnof", "\u{0192}"),
-    (b"fopf", "\u{1D557}"),
-    (b"forall", "\u{2200}"),
-    (b"fork", "\u{22D4}"),
-    (b"forkv", "\u{2AD9}"),
-    (b"fpartint", "\u{2A0D}"),
-    (b"frac12", "\u{00BD}"),
-    (b"frac13", "\u{2153}"),
-    (b"frac14", "\u{00BC}"),
-    (b"frac15", "\u{2155}"),
-    (b"frac16", "\u{2159}"),
-    (b"frac18", "\u{215B}"),
-    (b"frac23", "\u{2154}"),
-    (b"frac25", "\u{2156}"),
-    (b"frac34", "\u{00BE}"),
-    (b"frac35", "\u{2157}"),
-    (b"frac38", "\u{215C}"),
-    (b"frac45", "\u{2158}"),
-    (b"frac56", "\u{215A}"),
-    (b"frac58", "\u{215D}"),
-    (b"frac78", "\u{215E}"),
-    (b"frasl", "\u{2044}"),
-    (b"frown", "\u{2322}"),
-    (b"fscr", "\u{1D4BB}"),
-    (b"gE", "\u{2267}"),
-    (b"gEl", "\u{2A8C}"),
-    (b"gacute", "\u{01F5}"),
-    (b"gamma", "\u{03B3}"),
-    (b"gammad", "\u{03DD}"),
-    (b"gap", "\u{2A86}"),
-    (b"gbreve", "\u{011F}"),
-    (b"gcirc", "\u{011D}"),
-    (b"gcy", "\u{0433}"),
-    (b"gdot", "\u{0121}"),
-    (b"ge", "\u{2265}"),
-    (b"gel", "\u{22DB}"),
-    (b"geq", "\u{2265}"),
-    (b"geqq", "\u{2267}"),
-    (b"geqslant", "\u{2A7E}"),
-    (b"ges", "\u{2A7E}"),
-    (b"gescc", "\u{2AA9}"),
-    (b"gesdot", "\u{2A80}"),
-    (b"gesdoto", "\u{2A82}"),
-    (b"gesdotol", "\u{2A84}"),
-    (b"gesl", "\u{22DB}\u{FE00}"),
-    (b"gesles", "\u{2A94}"),
-    (b"gfr", "\u{1D524}"),
-    (b"gg", "\u{226B}"),
-    (b"ggg", "\u{22D9}"),
-    (b"gimel", "\u{2137}"),
-    (b"gjcy", "\u{0453}"),
-    (b"gl", "\u{2277}"),
-    (b"glE", "\u{2A92}"),
-    (b"gla", "\u{2AA5}"),
-    (b"glj", "\u{2AA4}"),
-    (b"gnE", "\u{2269}"),
-    (b"gnap", "\u{2A8A}"),
-    (b"gnapprox", "\u{2A8A}"),
-    (b"gne", "\u{2A88}"),
-    (b"gneq", "\u{2A88}"),
-    (b"gneqq", "\u{2269}"),
-    (b"gnsim", "\u{22E7}"),
-    (b"gopf", "\u{1D558}"),
-    (b"grave", "\u{0060}"),
-    (b"gscr", "\u{210A}"),
-    (b"gsim", "\u{2273}"),
-    (b"gsime", "\u{2A8E}"),
-    (b"gsiml", "\u{2A90}"),
-    (b"gt", "\u{003E}"),
-    (b"gtcc", "\u{2AA7}"),
-    (b"gtcir", "\u{2A7A}"),
-    (b"gtdot", "\u{22D7}"),
-    (b"gtlPar", "\u{2995}"),
-    (b"gtquest", "\u{2A7C}"),
-    (b"gtrapprox", "\u{2A86}"),
-    (b"gtrarr", "\u{2978}"),
-    (b"gtrdot", "\u{22D7}"),
-    (b"gtreqless", "\u{22DB}"),
-    (b"gtreqqless", "\u{2A8C}"),
-    (b"gtrless", "\u{2277}"),
-    (b"gtrsim", "\u{2273}"),
-    (b"gvertneqq", "\u{2269}\u{FE00}"),
-    (b"gvnE", "\u{2269}\u{FE00}"),
-    (b"hArr", "\u{21D4}"),
-    (b"hairsp", "\u{200A}"),
-    (b"half", "\u{00BD}"),
-    (b"hamilt", "\u{210B}"),
-    (b"hardcy", "\u{044A}"),
-    (b"harr", "\u{2194}"),
-    (b"harrcir", "\u{2948}"),
-    (b"harrw", "\u{21AD}"),
-    (b"hbar", "\u{210F}"),
-    (b"hcirc", "\u{0125}"),
-    (b"hearts", "\u{2665}"),
-    (b"heartsuit", "\u{2665}"),
-    (b"hellip", "\u{2026}"),
-    (b"hercon", "\u{22B9}"),
-    (b"hfr", "\u{1D525}"),
-    (b"hksearow", "\u{2925}"),
-    (b"hkswarow", "\u{2926}"),
-    (b"hoarr", "\u{21FF}"),
-    (b"homtht", "\u{223B}"),
-    (b"hookleftarrow", "\u{21A9}"),
-    (b"hookrightarrow", "\u{21AA}"),
-    (b"hopf", "\u{1D559}"),
-    (b"horbar", "\u{2015}"),
-    (b"hscr", "\u{1D4BD}"),
-    (b"hslash", "\u{210F}"),
-    (b"hstrok", "\u{0127}"),
-    (b"hybull", "\u{2043}"),
-    (b"hyphen", "\u{2010}"),
-    (b"iacute", "\u{00ED}"),
-    (b"ic", "\u{2063}"),
-    (b"icirc", "\u{00EE}"),
-    (b"icy", "\u{0438}"),
-    (b"iecy", "\u{0435}"),
-    (b"iexcl", "\u{00A1}"),
-    (b"iff", "\u{21D4}"),
-    (b"ifr", "\u{1D526}"),
-    (b"igrave", "\u{00EC}"),
-    (b"ii", "\u{2148}"),
-    (b"iiiint", "\u{2A0C}"),
-    (b"iiint", "\u{222D}"),
-    (b"iinfin", "\u{29DC}"),
-    (b"iiota", "\u{2129}"),
-    (b"ijlig", "\u{0133}"),
-    (b"imacr", "\u{012B}"),
-    (b"image", "\u{2111}"),
-    (b"imagline", "\u{2110}"),
-    (b"imagpart", "\u{2111}"),
-    (b"imath", "\u{0131}"),
-    (b"imof", "\u{22B7}"),
-    (b"imped", "\u{01B5}"),
-    (b"in", "\u{2208}"),
-    (b"incare", "\u{2105}"),
-    (b"infin", "\u{221E}"),
-    (b"infintie", "\u{29DD}"),
-    (b"inodot", "\u{0131}"),
-    (b"int", "\u{222B}"),
-    (b"intcal", "\u{22BA}"),
-    (b"integers", "\u{2124}"),
-    (b"intercal", "\u{22BA}"),
-    (b"intlarhk", "\u{2A17}"),
-    (b"intprod", "\u{2A3C}"),
-    (b"iocy", "\u{0451}"),
-    (b"iogon", "\u{012F}"),
-    (b"iopf", "\u{1D55A}"),
-    (b"iota", "\u{03B9}"),
-    (b"iprod", "\u{2A3C}"),
-    (b"iquest", "\u{00BF}"),
-    (b"iscr", "\u{1D4BE}"),
-    (b"isin", "\u{2208}"),
-    (b"isinE", "\u{22F9}"),
-    (b"isindot", "\u{22F5}"),
-    (b"isins", "\u{22F4}"),
-    (b"isinsv", "\u{22F3}"),
-    (b"isinv", "\u{2208}"),
-    (b"it", "\u{2062}"),
-    (b"itilde", "\u{0129}"),
-    (b"iukcy", "\u{0456}"),
-    (b"iuml", "\u{00EF}"),
-    (b"jcirc", "\u{0135}"),
-    (b"jcy", "\u{0439}"),
-    (b"jfr", "\u{1D527}"),
-    (b"jmath", "\u{0237}"),
-    (b"jopf", "\u{1D55B}"),
-    (b"jscr", "\u{1D4BF}"),
-    (b"jsercy", "\u{0458}"),
-    (b"jukcy", "\u{0454}"),
-    (b"kappa", "\u{03BA}"),
-    (b"kappav", "\u{03F0}"),
-    (b"kcedil", "\u{0137}"),
-    (b"kcy", "\u{043A}"),
-    (b"kfr", "\u{1D528}"),
-    (b"kgreen", "\u{0138}"),
-    (b"khcy", "\u{0445}"),
-    (b"kjcy", "\u{045C}"),
-    (b"kopf", "\u{1D55C}"),
-    (b"kscr", "\u{1D4C0}"),
-    (b"lAarr", "\u{21DA}"),
-    (b"lArr", "\u{21D0}"),
-    (b"lAtail", "\u{291B}"),
-    (b"lBarr", "\u{290E}"),
-    (b"lE", "\u{2266}"),
-    (b"lEg", "\u{2A8B}"),
-    (b"lHar", "\u{2962}"),
-    (b"lacute", "\u{013A}"),
-    (b"laemptyv", "\u{29B4}"),
-    (b"lagran", "\u{2112}"),
-    (b"lambda", "\u{03BB}"),
-    (b"lang", "\u{27E8}"),
-    (b"langd", "\u{2991}"),
-    (b"langle", "\u{27E8}"),
-    (b"lap", "\u{2A85}"),
-    (b"laquo", "\u{00AB}"),
-    (b"larr", "\u{2190}"),
-    (b"larrb", "\u{21E4}"),
-    (b"larrbfs", "\u{291F}"),
-    (b"larrfs", "\u{291D}"),
-    (b"larrhk", "\u{21A9}"),
-    (b"larrlp", "\u{21AB}"),
-    (b"larrpl", "\u{2939}"),
-    (b"larrsim", "\u{2973}"),
-    (b"larrtl", "\u{21A2}"),
-    (b"lat", "\u{2AAB}"),
-    (b"latail", "\u{2919}"),
-    (b"late", "\u{2AAD}"),
-    (b"lates", "\u{2AAD}\u{FE00}"),
-    (b"lbarr", "\u{290C}"),
-    (b"lbbrk", "\u{2772}"),
-    (b"lbrace", "\u{007B}"),
-    (b"lbrack", "\u{005B}"),
-    (b"lbrke", "\u{298B}"),
-    (b"lbrksld", "\u{298F}"),
-    (b"lbrkslu", "\u{298D}"),
-    (b"lcaron", "\u{013E}"),
-    (b"lcedil", "\u{013C}"),
-    (b"lceil", "\u{2308}"),
-    (b"lcub", "\u{007B}"),
-    (b"lcy", "\u{043B}"),
-    (b"ldca", "\u{2936}"),
-    (b"ldquo", "\u{201C}"),
-    (b"ldquor", "\u{201E}"),
-    (b"ldrdhar", "\u{2967}"),
-    (b"ldrushar", "\u{294B}"),
-    (b"ldsh", "\u{21B2}"),
-    (b"le", "\u{2264}"),
-    (b"leftarrow", "\u{2190}"),
-    (b"leftarrowtail", "\u{21A2}"),
-    (b"leftharpoondown", "\u{21BD}"),
-    (b"leftharpoonup", "\u{21BC}"),
-    (b"leftleftarrows", "\u{21C7}"),
-    (b"leftrightarrow", "\u{2194}"),
-    (b"leftrightarrows", "\u{21C6}"),
-    (b"leftrightharpoons", "\u{21CB}"),
-    (b"leftrightsquigarrow", "\u{21AD}"),
-    (b"leftthreetimes", "\u{22CB}"),
-    (b"leg", "\u{22DA}"),
-    (b"leq", "\u{2264}"),
-    (b"leqq", "\u{2266}"),
-    (b"leqslant", "\u{2A7D}"),
-    (b"les", "\u{2A7D}"),
-    (b"lescc", "\u{2AA8}"),
-    (b"lesdot", "\u{2A7F}"),
-    (b"lesdoto", "\u{2A81}"),
-    (b"lesdotor", "\u{2A83}"),
-    (b"lesg", "\u{22DA}\u{FE00}"),
-    (b"lesges", "\u{2A93}"),
-    (b"lessapprox", "\u{2A85}"),
-    (b"lessdot", "\u{22D6}"),
-    (b"lesseqgtr", "\u{22DA}"),
-    (b"lesseqqgtr", "\u{2A8B}"),
-    (b"lessgtr", "\u{2276}"),
-    (b"lesssim", "\u{2272}"),
-    (b"lfisht", "\u{297C}"),
-    (b"lfloor", "\u{230A}"),
-    (b"lfr", "\u{1D529}"),
-    (b"lg", "\u{2276}"),
-    (b"lgE", "\u{2A91}"),
-    (b"lhard", "\u{21BD}"),
-    (b"lharu", "\u{21BC}"),
-    (b"lharul", "\u{296A}"),
-    (b"lhblk", "\u{2584}"),
-    (b"ljcy", "\u{0459}"),
-    (b"ll", "\u{226A}"),
-    (b"llarr", "\u{21C7}"),
-    (b"llcorner", "\u{231E}"),
-    (b"llhard", "\u{296B}"),
-    (b"lltri", "\u{25FA}"),
-    (b"lmidot", "\u{0140}"),
-    (b"lmoust", "\u{23B0}"),
-    (b"lmoustache", "\u{23B0}"),
-    (b"lnE", "\u{2268}"),
-    (b"lnap", "\u{2A89}"),
-    (b"lnapprox", "\u{2A89}"),
-    (b"lne", "\u{2A87}"),
-    (b"lneq", "\u{2A87}"),
-    (b"lneqq", "\u{2268}"),
-    (b"lnsim", "\u{22E6}"),
-    (b"loang", "\u{27EC}"),
-    (b"loarr", "\u{21FD}"),
-    (b"lobrk", "\u{27E6}"),
-    (b"longleftarrow", "\u{27F5}"),
-    (b"longleftrightarrow", "\u{27F7}"),
-    (b"longmapsto", "\u{27FC}"),
-    (b"longrightarrow", "\u{27F6}"),
-    (b"looparrowleft", "\u{21AB}"),
-    (b"looparrowright", "\u{21AC}"),
-    (b"lopar", "\u{2985}"),
-    (b"lopf", "\u{1D55D}"),
-    (b"loplus", "\u{2A2D}"),
-    (b"lotimes", "\u{2A34}"),
-    (b"lowast", "\u{2217}"),
-    (b"lowbar", "\u{005F}"),
-    (b"loz", "\u{25CA}"),
-    (b"lozenge", "\u{25CA}"),
-    (b"lozf", "\u{29EB}"),
-    (b"lpar", "\u{0028}"),
-    (b"lparlt", "\u{2993}"),
-    (b"lrarr", "\u{21C6}"),
-    (b"lrcorner", "\u{231F}"),
-    (b"lrhar", "\u{21CB}"),
-    (b"lrhard", "\u{296D}"),
-    (b"lrm", "\u{200E}"),
-    (b"lrtri", "\u{22BF}"),
-    (b"lsaquo", "\u{2039}"),
-    (b"lscr", "\u{1D4C1}"),
-    (b"lsh", "\u{21B0}"),
-    (b"lsim", "\u{2272}"),
-    (b"lsime", "\u{2A8D}"),
-    (b"lsimg", "\u{2A8F}"),
-    (b"lsqb", "\u{005B}"),
-    (b"lsquo", "\u{2018}"),
-    (b"lsquor", "\u{201A}"),
-    (b"lstrok", "\u{0142}"),
-    (b"lt", "\u{003C}"),
-    (b"ltcc", "\u{2AA6}"),
-    (b"ltcir", "\u{2A79}"),
-    (b"ltdot", "\u{22D6}"),
-    (b"lthree", "\u{22CB}"),
-    (b"ltimes", "\u{22C9}"),
-    (b"ltlarr", "\u{2976}"),
-    (b"ltquest", "\u{2A7B}"),
-    (b"ltrPar", "\u{2996}"),
-    (b"ltri", "\u{25C3}"),
-    (b"ltrie", "\u{22B4}"),
-    (b"ltrif", "\u{25C2}"),
-    (b"lurdshar", "\u{294A}"),
-    (b"luruhar", "\u{2966}"),
-    (b"lvertneqq", "\u{2268}\u{FE00}"),
-    (b"lvnE", "\u{2268}\u{FE00}"),
-    (b"mDDot", "\u{223A}"),
-    (b"macr", "\u{00AF}"),
-    (b"male", "\u{2642}"),
-    (b"malt", "\u{2720}"),
-    (b"maltese", "\u{2720}"),
-    (b"map", "\u{21A6}"),
-    (b"mapsto", "\u{21A6}"),
-    (b"mapstodown", "\u{21A7}"),
-    (b"mapstoleft", "\u{21A4}"),
-    (b"mapstoup", "\u{21A5}"),
-    (b"marker", "\u{25AE}"),
-    (b"mcomma", "\u{2A29}"),
-    (b"mcy", "\u{043C}"),
-    (b"mdash", "\u{2014}"),
-    (b"measuredangle", "\u{2221}"),
-    (b"mfr", "\u{1D52A}"),
-    (b"mho", "\u{2127}"),
-    (b"micro", "\u{00B5}"),
-    (b"mid", "\u{2223}"),
-    (b"midast", "\u{002A}"),
-    (b"midcir", "\u{2AF0}"),
-    (b"middot", "\u{00B7}"),
-    (b"minus", "\u{2212}"),
-    (b"minusb", "\u{229F}"),
-    (b"minusd", "\u{2238}"),
-    (b"minusdu", "\u{2A2A}"),
-    (b"mlcp", "\u{2ADB}"),
-    (b"mldr", "\u{2026}"),
-    (b"mnplus", "\u{2213}"),
-    (b"models", "\u{22A7}"),
-    (b"mopf", "\u{1D55E}"),
-    (b"mp", "\u{2213}"),
-    (b"mscr", "\u{1D4C2}"),
-    (b"mstpos", "\u{223E}"),
-    (b"mu", "\u{03BC}"),
-    (b"multimap", "\u{22B8}"),
-    (b"mumap", "\u{22B8}"),
-    (b"nGg", "\u{22D9}\u{0338}"),
-    (b"nGt", "\u{226B}\u{20D2}"),
-    (b"nGtv", "\u{226B}\u{0338}"),
-    (b"nLeftarrow", "\u{21CD}"),
-    (b"nLeftrightarrow", "\u{21CE}"),
-    (b"nLl", "\u{22D8}\u{0338}"),
-    (b"nLt", "\u{226A}\u{20D2}"),
-    (b"nLtv", "\u{226A}\u{0338}"),
-    (b"nRightarrow", "\u{21CF}"),
-    (b"nVDash", "\u{22AF}"),
-    (b"nVdash", "\u{22AE}"),
-    (b"nabla", "\u{2207}"),
-    (b"nacute", "\u{0144}"),
-    (b"nang", "\u{2220}\u{20D2}"),
-    (b"nap", "\u{2249}"),
-    (b"napE", "\u{2A70}\u{0338}"),
-    (b"napid", "\u{224B}\u{0338}"),
-    (b"napos", "\u{0149}"),
-    (b"napprox", "\u{2249}"),
-    (b"natur", "\u{266E}"),
-    (b"natural", "\u{266E}"),
-    (b"naturals", "\u{2115}"),
-    (b"nbsp", "\u{00A0}"),
-    (b"nbump", "\u{224E}\u{0338}"),
-    (b"nbumpe", "\u{224F}\u{0338}"),
-    (b"ncap", "\u{2A43}"),
-    (b"ncaron", "\u{0148}"),
-    (b"ncedil", "\u{0146}"),
-    (b"ncong", "\u{2247}"),
-    (b"ncongdot", "\u{2A6D}\u{0338}"),
-    (b"ncup", "\u{2A42}"),
-    (b"ncy", "\u{043D}"),
-    (b"ndash", "\u{2013}"),
-    (b"ne", "\u{2260}"),
-    (b"neArr", "\u{21D7}"),
-    (b"nearhk", "\u{2924}"),
-    (b"nearr", "\u{2197}"),
-    (b"nearrow", "\u{2197}"),
-    (b"nedot", "\u{2250}\u{0338}"),
-    (b"nequiv", "\u{2262}"),
-    (b"nesear", "\u{2928}"),
-    (b"nesim", "\u{2242}\u{0338}"),
-    (b"nexist", "\u{2204}"),
-    (b"nexists", "\u{2204}"),
-    (b"nfr", "\u{1D52B}"),
-    (b"ngE", "\u{2267}\u{0338}"),
-    (b"nge", "\u{2271}"),
-    (b"ngeq", "\u{2271}"),
-    (b"ngeqq", "\u{2267}\u{0338}"),
-    (b"ngeqslant", "\u{2A7E}\u{0338}"),
-    (b"nges", "\u{2A7E}\u{0338}"),
-    (b"ngsim", "\u{2275}"),
-    (b"ngt", "\u{226F}"),
-    (b"ngtr", "\u{226F}"),
-    (b"nhArr", "\u{21CE}"),
-    (b"nharr", "\u{21AE}"),
-    (b"nhpar", "\u{2AF2}"),
-    (b"ni", "\u{220B}"),
-    (b"nis", "\u{22FC}"),
-    (b"nisd", "\u{22FA}"),
-    (b"niv", "\u{220B}"),
-    (b"njcy", "\u{045A}"),
-    (b"nlArr", "\u{21CD}"),
-    (b"nlE", "\u{2266}\u{0338}"),
-    (b"nlarr", "\u{219A}"),
-    (b"nldr", "\u{2025}"),
-    (b"nle", "\u{2270}"),
-    (b"nleftarrow", "\u{219A}"),
-    (b"nleftrightarrow", "\u{21AE}"),
-    (b"nleq", "\u{2270}"),
-    (b"nleqq", "\u{2266}\u{0338}"),
-    (b"nleqslant", "\u{2A7D}\u{0338}"),
-    (b"nles", "\u{2A7D}\u{0338}"),
-    (b"nless", "\u{226E}"),
-    (b"nlsim", "\u{2274}"),
-    (b"nlt", "\u{226E}"),
-    (b"nltri", "\u{22EA}"),
-    (b"nltrie", "\u{22EC}"),
-    (b"nmid", "\u{2224}"),
-    (b"nopf", "\u{1D55F}"),
-    (b"not", "\u{00AC}"),
-    (b"notin", "\u{2209}"),
-    (b"notinE", "\u{22F9}\u{0338}"),
-    (b"notindot", "\u{22F5}\u{0338}"),
-    (b"notinva", "\u{2209}"),
-    (b"notinvb", "\u{22F7}"),
-    (b"notinvc", "\u{22F6}"),
-    (b"notni", "\u{220C}"),
-    (b"notniva", "\u{220C}"),
-    (b"notnivb", "\u{22FE}"),
-    (b"notnivc", "\u{22FD}"),
-    (b"npar", "\u{2226}"),
-    (b"nparallel", "\u{2226}"),
-    (b"nparsl", "\u{2AFD}\u{20E5}"),
-    (b"npart", "\u{2202}\u{0338}"),
-    (b"npolint", "\u{2A14}"),
-    (b"npr", "\u{2280}"),
-    (b"nprcue", "\u{22E0}"),
-    (b"npre", "\u{2AAF}\u{0338}"),
-    (b"nprec", "\u{2280}"),
-    (b"npreceq", "\u{2AAF}\u{0338}"),
-    (b"nrArr", "\u{21CF}"),
-    (b"nrarr", "\u{219B}"),
-    (b"nrarrc", "\u{2933}\u{0338}"),
-    (b"nrarrw", "\u{219D}\u{0338}"),
-    (b"nrightarrow", "\u{219B}"),
-    (b"nrtri", "\u{22EB}"),
-    (b"nrtrie", "\u{22ED}"),
-    (b"nsc", "\u{2281}"),
-    (b"nsccue", "\u{22E1}"),
-    (b"nsce", "\u{2AB0}\u{0338}"),
-    (b"nscr", "\u{1D4C3}"),
-    (b"nshortmid", "\u{2224}"),
-    (b"nshortparallel", "\u{2226}"),
-    (b"nsim", "\u{2241}"),
-    (b"nsime", "\u{2244}"),
-    (b"nsimeq", "\u{2244}"),
-    (b"nsmid", "\u{2224}"),
-    (b"nspar", "\u{2226}"),
-    (b"nsqsube", "\u{22E2}"),
-    (b"nsqsupe", "\u{22E3}"),
-    (b"nsub", "\u{2284}"),
-    (b"nsubE", "\u{2AC5}\u{0338}"),
-    (b"nsube", "\u{2288}"),
-    (b"nsubset", "\u{2282}\u{20D2}"),
-    (b"nsubseteq", "\u{2288}"),
-    (b"nsubseteqq", "\u{2AC5}\u{0338}"),
-    (b"nsucc", "\u{2281}"),
-    (b"nsucceq", "\u{2AB0}\u{0338}"),
-    (b"nsup", "\u{2285}"),
-    (b"nsupE", "\u{2AC6}\u{0338}"),
-    (b"nsupe", "\u{2289}"),
-    (b"nsupset", "\u{2283}\u{20D2}"),
-    (b"nsupseteq", "\u{2289}"),
-    (b"nsupseteqq", "\u{2AC6}\u{0338}"),
-    (b"ntgl", "\u{2279}"),
-    (b"ntilde", "\u{00F1}"),
-    (b"ntlg", "\u{2278}"),
-    (b"ntriangleleft", "\u{22EA}"),
-    (b"ntrianglelefteq", "\u{22EC}"),
-    (b"ntriangleright", "\u{22EB}"),
-    (b"ntrianglerighteq", "\u{22ED}"),
-    (b"nu", "\u{03BD}"),
-    (b"num", "\u{0023}"),
-    (b"numero", "\u{2116}"),
-    (b"numsp", "\u{2007}"),
-    (b"nvDash", "\u{22AD}"),
-    (b"nvHarr", "\u{2904}"),
-    (b"nvap", "\u{224D}\u{20D2}"),
-    (b"nvdash", "\u{22AC}"),
-    (b"nvge", "\u{2265}\u{20D2}"),
-    (b"nvgt", "\u{003E}\u{20D2}"),
-    (b"nvinfin", "\u{29DE}"),
-    (b"nvlArr", "\u{2902}"),
-    (b"nvle", "\u{2264}\u{20D2}"),
-    (b"nvlt", "\u{003C}\u{20D2}"),
-    (b"nvltrie", "\u{22B4}\u{20D2}"),
-    (b"nvrArr", "\u{2903}"),
-    (b"nvrtrie", "\u{22B5}\u{20D2}"),
-    (b"nvsim", "\u{223C}\u{20D2}"),
-    (b"nwArr", "\u{21D6}"),
-    (b"nwarhk", "\u{2923}"),
-    (b"nwarr", "\u{2196}"),
-    (b"nwarrow", "\u{2196}"),
-    (b"nwnear", "\u{2927}"),
-    (b"oS", "\u{24C8}"),
-    (b"oacute", "\u{00F3}"),
-    (b"oast", "\u{229B}"),
-    (b"ocir", "\u{229A}"),
-    (b"ocirc", "\u{00F4}"),
-    (b"ocy", "\u{043E}"),
-    (b"odash", "\u{229D}"),
-    (b"odblac", "\u{0151}"),
-    (b"odiv", "\u{2A38}"),
-    (b"odot", "\u{2299}"),
-    (b"odsold", "\u{29BC}"),
-    (b"oelig", "\u{0153}"),
-    (b"ofcir", "\u{29BF}"),
-    (b"ofr", "\u{1D52C}"),
-    (b"ogon", "\u{02DB}"),
-    (b"ograve", "\u{00F2}"),
-    (b"ogt", "\u{29C1}"),
-    (b"ohbar", "\u{29B5}"),
-    (b"ohm", "\u{03A9}"),
-    (b"oint", "\u{222E}"),
-    (b"olarr", "\u{21BA}"),
-    (b"olcir", "\u{29BE}"),
-    (b"olcross", "\u{29BB}"),
-    (b"oline", "\u{203E}"),
-    (b"olt", "\u{29C0}"),
-    (b"omacr", "\u{014D}"),
-    (b"omega", "\u{03C9}"),
-    (b"omicron", "\u{03BF}"),
-    (b"omid", "\u{29B6}"),
-    (b"ominus", "\u{2296}"),
-    (b"oopf", "\u{1D560}"),
-    (b"opar", "\u{29B7}"),
-    (b"operp", "\u{29B9}"),
-    (b"oplus", "\u{2295}"),
-    (b"or", "\u{2228}"),
-    (b"orarr", "\u{21BB}"),
-    (b"ord", "\u{2A5D}"),
-    (b"order", "\u{2134}"),
-    (b"orderof", "\u{2134}"),
-    (b"ordf", "\u{00AA}"),
-    (b"ordm", "\u{00BA}"),
-    (b"origof", "\u{22B6}"),
-    (b"oror", "\u{2A56}"),
-    (b"orslope", "\u{2A57}"),
-    (b"orv", "\u{2A5B}"),
-    (b"oscr", "\u{2134}"),
-    (b"oslash", "\u{00F8}"),
-    (b"osol", "\u{2298}"),
-    (b"otilde", "\u{00F5}"),
-    (b"otimes", "\u{2297}"),
-    (b"otimesas", "\u{2A36}"),
-    (b"ouml", "\u{00F6}"),
-    (b"ovbar", "\u{233D}"),
-    (b"par", "\u{2225}"),
-    (b"para", "\u{00B6}"),
-    (b"parallel", "\u{2225}"),
-    (b"parsim", "\u{2AF3}"),
-    (b"parsl", "\u{2AFD}"),
-    (b"part", "\u{2202}"),
-    (b"pcy", "\u{043F}"),
-    (b"percnt", "\u{0025}"),
-    (b"period", "\u{002E}"),
-    (b"permil", "\u{2030}"),
-    (b"perp", "\u{22A5}"),
-    (b"pertenk", "\u{2031}"),
-    (b"pfr", "\u{1D52D}"),
-    (b"phi", "\u{03C6}"),
-    (b"phiv", "\u{03D5}"),
-    (b"phmmat", "\u{2133}"),
-    (b"phone", "\u{260E}"),
-    (b"pi", "\u{03C0}"),
-    (b"pitchfork", "\u{22D4}"),
-    (b"piv", "\u{03D6}"),
-    (b"planck", "\u{210F}"),
-    (b"planckh", "\u{210E}"),
-    (b"plankv", "\u{210F}"),
-    (b"plus", "\u{002B}"),
-    (b"plusacir", "\u{2A23}"),
-    (b"plusb", "\u{229E}"),
-    (b"pluscir", "\u{2A22}"),
-    (b"plusdo", "\u{2214}"),
-    (b"plusdu", "\u{2A25}"),
-    (b"pluse", "\u{2A72}"),
-    (b"plusmn", "\u{00B1}"),
-    (b"plussim", "\u{2A26}"),
-    (b"plustwo", "\u{2A27}"),
-    (b"pm", "\u{00B1}"),
-    (b"pointint", "\u{2A15}"),
-    (b"popf", "\u{1D561}"),
-    (b"pound", "\u{00A3}"),
-    (b"pr", "\u{227A}"),
-    (b"prE", "\u{2AB3}"),
-    (b"prap", "\u{2AB7}"),
-    (b"prcue", "\u{227C}"),
-    (b"pre", "\u{2AAF}"),
-    (b"prec", "\u{227A}"),
-    (b"precapprox", "\u{2AB7}"),
-    (b"preccurlyeq", "\u{227C}"),
-    (b"preceq", "\u{2AAF}"),
-    (b"precnapprox", "\u{2AB9}"),
-    (b"precneqq", "\u{2AB5}"),
-    (b"precnsim", "\u{22E8}"),
-    (b"precsim", "\u{227E}"),
-    (b"prime", "\u{2032}"),
-    (b"primes", "\u{2119}"),
-    (b"prnE", "\u{2AB5}"),
-    (b"prnap", "\u{2AB9}"),
-    (b"prnsim", "\u{22E8}"),
-    (b"prod", "\u{220F}"),
-    (b"profalar", "\u{232E}"),
-    (b"profline", "\u{2312}"),
-    (b"profsurf", "\u{2313}"),
-    (b"prop", "\u{221D}"),
-    (b"propto", "\u{221D}"),
-    (b"prsim", "\u{227E}"),
-    (b"prurel", "\u{22B0}"),
-    (b"pscr", "\u{1D4C5}"),
-    (b"psi", "\u{03C8}"),
-    (b"puncsp", "\u{2008}"),
-    (b"qfr", "\u{1D52E}"),
-    (b"qint", "\u{2A0C}"),
-    (b"qopf", "\u{1D562}"),
-    (b"qprime", "\u{2057}"),
-    (b"qscr", "\u{1D4C6}"),
-    (b"quaternions", "\u{210D}"),
-    (b"quatint", "\u{2A16}"),
-    (b"quest", "\u{003F}"),
-    (b"questeq", "\u{225F}"),
-    (b"quot", "\u{0022}"),
-    (b"rAarr", "\u{21DB}"),
-    (b"rArr", "\u{21D2}"),
-    (b"rAtail", "\u{291C}"),
-    (b"rBarr", "\u{290F}"),
-    (b"rHar", "\u{2964}"),
-    (b"race", "\u{223D}\u{0331}"),
-    (b"racute", "\u{0155}"),
-    (b"radic", "\u{221A}"),
-    (b"raemptyv", "\u{29B3}"),
-    (b"rang", "\u{27E9}"),
-    (b"rangd", "\u{2992}"),
-    (b"range", "\u{29A5}"),
-    (b"rangle", "\u{27E9}"),
-    (b"raquo", "\u{00BB}"),
-    (b"rarr", "\u{2192}"),
-    (b"rarrap", "\u{2975}"),
-    (b"rarrb", "\u{21E5}"),
-    (b"rarrbfs", "\u{2920}"),
-    (b"rarrc", "\u{2933}"),
-    (b"rarrfs", "\u{291E}"),
-    (b"rarrhk", "\u{21AA}"),
-    (b"rarrlp", "\u{21AC}"),
-    (b"rarrpl", "\u{2945}"),
-    (b"rarrsim", "\u{2974}"),
-    (b"rarrtl", "\u{21A3}"),
-    (b"rarrw", "\u{219D}"),
-    (b"ratail", "\u{291A}"),
-    (b"ratio", "\u{2236}"),
-    (b"rationals", "\u{211A}"),
-    (b"rbarr", "\u{290D}"),
-    (b"rbbrk", "\u{2773}"),
-    (b"rbrace", "\u{007D}"),
-    (b"rbrack", "\u{005D}"),
-    (b"rbrke", "\u{298C}"),
-    (b"rbrksld", "\u{298E}"),
-    (b"rbrkslu", "\u{2990}"),
-    (b"rcaron", "\u{0159}"),
-    (b"rcedil", "\u{0157}"),
-    (b"rceil", "\u{2309}"),
-    (b"rcub", "\u{007D}"),
-    (b"rcy", "\u{0440}"),
-    (b"rdca", "\u{2937}"),
-    (b"rdldhar", "\u{2969}"),
-    (b"rdquo", "\u{201D}"),
-    (b"rdquor", "\u{201D}"),
-    (b"rdsh", "\u{21B3}"),
-    (b"real", "\u{211C}"),
-    (b"realine", "\u{211B}"),
-    (b"realpart", "\u{211C}"),
-    (b"reals", "\u{211D}"),
-    (b"rect", "\u{25AD}"),
-    (b"reg", "\u{00AE}"),
-    (b"rfisht", "\u{297D}"),
-    (b"rfloor", "\u{230B}"),
-    (b"rfr", "\u{1D52F}"),
-    (b"rhard", "\u{21C1}"),
-    (b"rharu", "\u{21C0}"),
-    (b"rharul", "\u{296C}"),
-    (b"rho", "\u{03C1}"),
-    (b"rhov", "\u{03F1}"),
-    (b"rightarrow", "\u{2192}"),
-    (b"rightarrowtail", "\u{21A3}"),
-    (b"rightharpoondown", "\u{21C1}"),
-    (b"rightharpoonup", "\u{21C0}"),
-    (b"rightleftarrows", "\u{21C4}"),
-    (b"rightleftharpoons", "\u{21CC}"),
-    (b"rightrightarrows", "\u{21C9}"),
-    (b"rightsquigarrow", "\u{219D}"),
-    (b"rightthreetimes", "\u{22CC}"),
-    (b"ring", "\u{02DA}"),
-    (b"risingdotseq", "\u{2253}"),
-    (b"rlarr", "\u{21C4}"),
-    (b"rlhar", "\u{21CC}"),
-    (b"rlm", "\u{200F}"),
-    (b"rmoust", "\u{23B1}"),
-    (b"rmoustache", "\u{23B1}"),
-    (b"rnmid", "\u{2AEE}"),
-    (b"roang", "\u{27ED}"),
-    (b"roarr", "\u{21FE}"),
-    (b"robrk", "\u{27E7}"),
-    (b"ropar", "\u{2986}"),
-    (b"ropf", "\u{1D563}"),
-    (b"roplus", "\u{2A2E}"),
-    (b"rotimes", "\u{2A35}"),
-    (b"rpar", "\u{0029}"),
-    (b"rpargt", "\u{2994}"),
-    (b"rppolint", "\u{2A12}"),
-    (b"rrarr", "\u{21C9}"),
-    (b"rsaquo", "\u{203A}"),
-    (b"rscr", "\u{1D4C7}"),
-    (b"rsh", "\u{21B1}"),
-    (b"rsqb", "\u{005D}"),
-    (b"rsquo", "\u{2019}"),
-    (b"rsquor", "\u{2019}"),
-    (b"rthree", "\u{22CC}"),
-    (b"rtimes", "\u{22CA}"),
-    (b"rtri", "\u{25B9}"),
-    (b"rtrie", "\u{22B5}"),
-    (b"rtrif", "\u{25B8}"),
-    (b"rtriltri", "\u{29CE}"),
-    (b"ruluhar", "\u{2968}"),
-    (b"rx", "\u{211E}"),
-    (b"sacute", "\u{015B}"),
-    (b"sbquo", "\u{201A}"),
-    (b"sc", "\u{227B}"),
-    (b"scE", "\u{2AB4}"),
-    (b"scap", "\u{2AB8}"),
-    (b"scaron", "\u{0161}"),
-    (b"sccue", "\u{227D}"),
-    (b"sce", "\u{2AB0}"),
-    (b"scedil", "\u{015F}"),
-    (b"scirc", "\u{015D}"),
-    (b"scnE", "\u{2AB6}"),
-    (b"scnap", "\u{2ABA}"),
-    (b"scnsim", "\u{22E9}"),
-    (b"scpolint", "\u{2A13}"),
-    (b"scsim", "\u{227F}"),
-    (b"scy", "\u{0441}"),
-    (b"sdot", "\u{22C5}"),
-    (b"sdotb", "\u{22A1}"),
-    (b"sdote", "\u{2A66}"),
-    (b"seArr", "\u{21D8}"),
-    (b"searhk", "\u{2925}"),
-    (b"searr", "\u{2198}"),
-    (b"searrow", "\u{2198}"),
-    (b"sect", "\u{00A7}"),
-    (b"semi", "\u{003B}"),
-    (b"seswar", "\u{2929}"),
-    (b"setminus", "\u{2216}"),
-    (b"setmn", "\u{2216}"),
-    (b"sext", "\u{2736}"),
-    (b"sfr", "\u{1D530}"),
-    (b"sfrown", "\u{2322}"),
-    (b"sharp", "\u{266F}"),
-    (b"shchcy", "\u{0449}"),
-    (b"shcy", "\u{0448}"),
-    (b"shortmid", "\u{2223}"),
-    (b"shortparallel", "\u{2225}"),
-    (b"shy", "\u{00AD}"),
-    (b"sigma", "\u{03C3}"),
-    (b"sigmaf", "\u{03C2}"),
-    (b"sigmav", "\u{03C2}"),
-    (b"sim", "\u{223C}"),
-    (b"simdot", "\u{2A6A}"),
-    (b"sime", "\u{2243}"),
-    (b"simeq", "\u{2243}"),
-    (b"simg", "\u{2A9E}"),
-    (b"simgE", "\u{2AA0}"),
-    (b"siml", "\u{2A9D}"),
-    (b"simlE", "\u{2A9F}"),
-    (b"simne", "\u{2246}"),
-    (b"simplus", "\u{2A24}"),
-    (b"simrarr", "\u{2972}"),
-    (b"slarr", "\u{2190}"),
-    (b"smallsetminus", "\u{2216}"),
-    (b"smashp", "\u{2A33}"),
-    (b"smeparsl", "\u{29E4}"),
-    (b"smid", "\u{2223}"),
-    (b"smile", "\u{2323}"),
-    (b"smt", "\u{2AAA}"),
-    (b"smte", "\u{2AAC}"),
-    (b"smtes", "\u{2AAC}\u{FE00}"),
-    (b"softcy", "\u{044C}"),
-    (b"sol", "\u{002F}"),
-    (b"solb", "\u{29C4}"),
-    (b"solbar", "\u{233F}"),
-    (b"sopf", "\u{1D564}"),
-    (b"spades", "\u{2660}"),
-    (b"spadesuit", "\u{2660}"),
-    (b"spar", "\u{2225}"),
-    (b"sqcap", "\u{2293}"),
-    (b"sqcaps", "\u{2293}\u{FE00}"),
-    (b"sqcup", "\u{2294}"),
-    (b"sqcups", "\u{2294}\u{FE00}"),
-    (b"sqsub", "\u{228F}"),
-    (b"sqsube", "\u{2291}"),
-    (b"sqsubset", "\u{228F}"),
-    (b"sqsubseteq", "\u{2291}"),
-    (b"sqsup", "\u{2290}"),
-    (b"sqsupe", "\u{2292}"),
-    (b"sqsupset", "\u{2290}"),
-    (b"sqsupseteq", "\u{2292}"),
-    (b"squ", "\u{25A1}"),
-    (b"square", "\u{25A1}"),
-    (b"squarf", "\u{25AA}"),
-    (b"squf", "\u{25AA}"),
-    (b"srarr", "\u{2192}"),
-    (b"sscr", "\u{1D4C8}"),
-    (b"ssetmn", "\u{2216}"),
-    (b"ssmile", "\u{2323}"),
-    (b"sstarf", "\u{22C6}"),
-    (b"star", "\u{2606}"),
-    (b"starf", "\u{2605}"),
-    (b"straightepsilon", "\u{03F5}"),
-    (b"straightphi", "\u{03D5}"),
-    (b"strns", "\u{00AF}"),
-    (b"sub", "\u{2282}"),
-    (b"subE", "\u{2AC5}"),
-    (b"subdot", "\u{2ABD}"),
-    (b"sube", "\u{2286}"),
-    (b"subedot", "\u{2AC3}"),
-    (b"submult", "\u{2AC1}"),
-    (b"subnE", "\u{2ACB}"),
-    (b"subne", "\u{228A}"),
-    (b"subplus", "\u{2ABF}"),
-    (b"subrarr", "\u{2979}"),
-    (b"subset", "\u{2282}"),
-    (b"subseteq", "\u{2286}"),
-    (b"subseteqq", "\u{2AC5}"),
-    (b"subsetneq", "\u{228A}"),
-    (b"subsetneqq", "\u{2ACB}"),
-    (b"subsim", "\u{2AC7}"),
-    (b"subsub", "\u{2AD5}"),
-    (b"subsup", "\u{2AD3}"),
-    (b"succ", "\u{227B}"),
-    (b"succapprox", "\u{2AB8}"),
-    (b"succcurlyeq", "\u{227D}"),
-    (b"succeq", "\u{2AB0}"),
-    (b"succnapprox", "\u{2ABA}"),
-    (b"succneqq", "\u{2AB6}"),
-    (b"succnsim", "\u{22E9}"),
-    (b"succsim", "\u{227F}"),
-    (b"sum", "\u{2211}"),
-    (b"sung", "\u{266A}"),
-    (b"sup", "\u{2283}"),
-    (b"sup1", "\u{00B9}"),
-    (b"sup2", "\u{00B2}"),
-    (b"sup3", "\u{00B3}"),
-    (b"supE", "\u{2AC6}"),
-    (b"supdot", "\u{2ABE}"),
-    (b"supdsub", "\u{2AD8}"),
-    (b"supe", "\u{2287}"),
-    (b"supedot", "\u{2AC4}"),
-    (b"suphsol", "\u{27C9}"),
-    (b"suphsub", "\u{2AD7}"),
-    (b"suplarr", "\u{297B}"),
-    (b"supmult", "\u{2AC2}"),
-    (b"supnE", "\u{2ACC}"),
-    (b"supne", "\u{228B}"),
-    (b"supplus", "\u{2AC0}"),
-    (b"supset", "\u{2283}"),
-    (b"supseteq", "\u{2287}"),
-    (b"supseteqq", "\u{2AC6}"),
-    (b"supsetneq", "\u{228B}"),
-    (b"supsetneqq", "\u{2ACC}"),
-    (b"supsim", "\u{2AC8}"),
-    (b"supsub", "\u{2AD4}"),
-    (b"supsup", "\u{2AD6}"),
-    (b"swArr", "\u{21D9}"),
-    (b"swarhk", "\u{2926}"),
-    (b"swarr", "\u{2199}"),
-    (b"swarrow", "\u{2199}"),
-    (b"swnwar", "\u{292A}"),
-    (b"szlig", "\u{00DF}"),
-    (b"target", "\u{2316}"),
-    (b"tau", "\u{03C4}"),
-    (b"tbrk", "\u{23B4}"),
-    (b"tcaron", "\u{0165}"),
-    (b"tcedil", "\u{0163}"),
-    (b"tcy", "\u{0442}"),
-    (b"tdot", "\u{20DB}"),
-    (b"telrec", "\u{2315}"),
-    (b"tfr", "\u{1D531}"),
-    (b"there4", "\u{2234}"),
-    (b"therefore", "\u{2234}"),
-    (b"theta", "\u{03B8}"),
-    (b"thetasym", "\u{03D1}"),
-    (b"thetav", "\u{03D1}"),
-    (b"thickapprox", "\u{2248}"),
-    (b"thicksim", "\u{223C}"),
-    (b"thinsp", "\u{2009}"),
-    (b"thkap", "\u{2248}"),
-    (b"thksim", "\u{223C}"),
-    (b"thorn", "\u{00FE}"),
-    (b"tilde", "\u{02DC}"),
-    (b"times", "\u{00D7}"),
-    (b"timesb", "\u{22A0}"),
-    (b"timesbar", "\u{2A31}"),
-    (b"timesd", "\u{2A30}"),
-    (b"tint", "\u{222D}"),
-    (b"toea", "\u{2928}"),
-    (b"top", "\u{22A4}"),
-    (b"topbot", "\u{2336}"),
-    (b"topcir", "\u{2AF1}"),
-    (b"topf", "\u{1D565}"),
-    (b"topfork", "\u{2ADA}"),
-    (b"tosa", "\u{2929}"),
-    (b"tprime", "\u{2034}"),
-    (b"trade", "\u{2122}"),
-    (b"triangle", "\u{25B5}"),
-    (b"triangledown", "\u{25BF}"),
-    (b"triangleleft", "\u{25C3}"),
-    (b"trianglelefteq", "\u{22B4}"),
-    (b"triangleq", "\u{225C}"),
-    (b"triangleright", "\u{25B9}"),
-    (b"trianglerighteq", "\u{22B5}"),
-    (b"tridot", "\u{25EC}"),
-    (b"trie", "\u{225C}"),
-    (b"triminus", "\u{2A3A}"),
-    (b"triplus", "\u{2A39}"),
-    (b"trisb", "\u{29CD}"),
-    (b"tritime", "\u{2A3B}"),
-    (b"trpezium", "\u{23E2}"),
-    (b"tscr", "\u{1D4C9}"),
-    (b"tscy", "\u{0446}"),
-    (b"tshcy", "\u{045B}"),
-    (b"tstrok", "\u{0167}"),
-    (b"twixt", "\u{226C}"),
-    (b"twoheadleftarrow", "\u{219E}"),
-    (b"twoheadrightarrow", "\u{21A0}"),
-    (b"uArr", "\u{21D1}"),
-    (b"uHar", "\u{2963}"),
-    (b"uacute", "\u{00FA}"),
-    (b"uarr", "\u{2191}"),
-    (b"ubrcy", "\u{045E}"),
-    (b"ubreve", "\u{016D}"),
-    (b"ucirc", "\u{00FB}"),
-    (b"ucy", "\u{0443}"),
-    (b"udarr", "\u{21C5}"),
-    (b"udblac", "\u{0171}"),
-    (b"udhar", "\u{296E}"),
-    (b"ufisht", "\u{297E}"),
-    (b"ufr", "\u{1D532}"),
-    (b"ugrave", "\u{00F9}"),
-    (b"uharl", "\u{21BF}"),
-    (b"uharr", "\u{21BE}"),
-    (b"uhblk", "\u{2580}"),
-    (b"ulcorn", "\u{231C}"),
-    (b"ulcorner", "\u{231C}"),
-    (b"ulcrop", "\u{230F}"),
-    (b"ultri", "\u{25F8}"),
-    (b"umacr", "\u{016B}"),
-    (b"uml", "\u{00A8}"),
-    (b"uogon", "\u{0173}"),
-    (b"uopf", "\u{1D566}"),
-    (b"uparrow", "\u{2191}"),
-    (b"updownarrow", "\u{2195}"),
-    (b"upharpoonleft", "\u{21BF}"),
-    (b"upharpoonright", "\u{21BE}"),
-    (b"uplus", "\u{228E}"),
-    (b"upsi", "\u{03C5}"),
-    (b"upsih", "\u{03D2}"),
-    (b"upsilon", "\u{03C5}"),
-    (b"upuparrows", "\u{21C8}"),
-    (b"urcorn", "\u{231D}"),
-    (b"urcorner", "\u{231D}"),
-    (b"urcrop", "\u{230E}"),
-    (b"uring", "\u{016F}"),
-    (b"urtri", "\u{25F9}"),
-    (b"uscr", "\u{1D4CA}"),
-    (b"utdot", "\u{22F0}"),
-    (b"utilde", "\u{0169}"),
-    (b"utri", "\u{25B5}"),
-    (b"utrif", "\u{25B4}"),
-    (b"uuarr", "\u{21C8}"),
-    (b"uuml", "\u{00FC}"),
-    (b"uwangle", "\u{29A7}"),
-    (b"vArr", "\u{21D5}"),
-    (b"vBar", "\u{2AE8}"),
-    (b"vBarv", "\u{2AE9}"),
-    (b"vDash", "\u{22A8}"),
-    (b"vangrt", "\u{299C}"),
-    (b"varepsilon", "\u{03F5}"),
-    (b"varkappa", "\u{03F0}"),
-    (b"varnothing", "\u{2205}"),
-    (b"varphi", "\u{03D5}"),
-    (b"varpi", "\u{03D6}"),
-    (b"varpropto", "\u{221D}"),
-    (b"varr", "\u{2195}"),
-    (b"varrho", "\u{03F1}"),
-    (b"varsigma", "\u{03C2}"),
-    (b"varsubsetneq", "\u{228A}\u{FE00}"),
-    (b"varsubsetneqq", "\u{2ACB}\u{FE00}"),
-    (b"varsupsetneq", "\u{228B}\u{FE00}"),
-    (b"varsupsetneqq", "\u{2ACC}\u{FE00}"),
-    (b"vartheta", "\u{03D1}"),
-    (b"vartriangleleft", "\u{22B2}"),
-    (b"vartriangleright", "\u{22B3}"),
-    (b"vcy", "\u{0432}"),
-    (b"vdash", "\u{22A2}"),
-    (b"vee", "\u{2228}"),
-    (b"veebar", "\u{22BB}"),
-    (b"veeeq", "\u{225A}"),
-    (b"vellip", "\u{22EE}"),
-    (b"verbar", "\u{007C}"),
-    (b"vert", "\u{007C}"),
-    (b"vfr", "\u{1D533}"),
-    (b"vltri", "\u{22B2}"),
-    (b"vnsub", "\u{2282}\u{20D2}"),
-    (b"vnsup", "\u{2283}\u{20D2}"),
-    (b"vopf", "\u{1D567}"),
-    (b"vprop", "\u{221D}"),
-    (b"vrtri", "\u{22B3}"),
-    (b"vscr", "\u{1D4CB}"),
-    (b"vsubnE", "\u{2ACB}\u{FE00}"),
-    (b"vsubne", "\u{228A}\u{FE00}"),
-    (b"vsupnE", "\u{2ACC}\u{FE00}"),
-    (b"vsupne", "\u{228B}\u{FE00}"),
-    (b"vzigzag", "\u{299A}"),
-    (b"wcirc", "\u{0175}"),
-    (b"wedbar", "\u{2A5F}"),
-    (b"wedge", "\u{2227}"),
-    (b"wedgeq", "\u{2259}"),
-    (b"weierp", "\u{2118}"),
-    (b"wfr", "\u{1D534}"),
-    (b"wopf", "\u{1D568}"),
-    (b"wp", "\u{2118}"),
-    (b"wr", "\u{2240}"),
-    (b"wreath", "\u{2240}"),
-    (b"wscr", "\u{1D4CC}"),
-    (b"xcap", "\u{22C2}"),
-    (b"xcirc", "\u{25EF}"),
-    (b"xcup", "\u{22C3}"),
-    (b"xdtri", "\u{25BD}"),
-    (b"xfr", "\u{1D535}"),
-    (b"xhArr", "\u{27FA}"),
-    (b"xharr", "\u{27F7}"),
-    (b"xi", "\u{03BE}"),
-    (b"xlArr", "\u{27F8}"),
-    (b"xlarr", "\u{27F5}"),
-    (b"xmap", "\u{27FC}"),
-    (b"xnis", "\u{22FB}"),
-    (b"xodot", "\u{2A00}"),
-    (b"xopf", "\u{1D569}"),
-    (b"xoplus", "\u{2A01}"),
-    (b"xotime", "\u{2A02}"),
-    (b"xrArr", "\u{27F9}"),
-    (b"xrarr", "\u{27F6}"),
-    (b"xscr", "\u{1D4CD}"),
-    (b"xsqcup", "\u{2A06}"),
-    (b"xuplus", "\u{2A04}"),
-    (b"xutri", "\u{25B3}"),
-    (b"xvee", "\u{22C1}"),
-    (b"xwedge", "\u{22C0}"),
-    (b"yacute", "\u{00FD}"),
-    (b"yacy", "\u{044F}"),
-    (b"ycirc", "\u{0177}"),
-    (b"ycy", "\u{044B}"),
-    (b"yen", "\u{00A5}"),
-    (b"yfr", "\u{1D536}"),
-    (b"yicy", "\u{0457}"),
-    (b"yopf", "\u{1D56A}"),
-    (b"yscr", "\u{1D4CE}"),
-    (b"yucy", "\u{044E}"),
-    (b"yuml", "\u{00FF}"),
-    (b"zacute", "\u{017A}"),
-    (b"zcaron", "\u{017E}"),
-    (b"zcy", "\u{0437}"),
-    (b"zdot", "\u{017C}"),
-    (b"zeetrf", "\u{2128}"),
-    (b"zeta", "\u{03B6}"),
-    (b"zfr", "\u{1D537}"),
-    (b"zhcy", "\u{0436}"),
-    (b"zigrarr", "\u{21DD}"),
-    (b"zopf", "\u{1D56B}"),
-    (b"zscr", "\u{1D4CF}"),
-    (b"zwj", "\u{200D}"),
-    (b"zwnj", "\u{200C}"),
-];
+#[cfg(not(feature = "minimal-entities"))]
+mod table {
+    /// Mixed into the hash, chosen so that every entity gets its own slot.
+    pub(super) const KEY: u64 = 0;
+
+    /// Entity names in slot order, concatenated.
+    pub(super) static NAMES: &[u8] = b"\
+        nfrfscrsubagraveLeftDoubleBracketutdothalfRightUpVectorbigveesupsubeDotDownArrow\
+        XiSmallCirclecenterdotgtreqlessLeftTrianglewedbarNotNestedGreaterGreatercupcaplm\
+        idotprEcrarrbcongboxULfrac16GopfJscrleftleftarrowsTopfloangtrpeziumplusegersquor\
+        NestedGreaterGreatermcyhstrokRightTeeVectorUacuteoltbprimeFopfxodotDscrecolonprc\
+        upslesssimccupssmvarproptoiexclminusoopfrceildownharpoonrightrarrbigtriangleuppl\
+        usbduharescrboxplusdjcynlEgtccxipitchforkLeftTeeArrowpluscirHorizontalLinenotniv\
+        clrtriLeftAngleBracketemptysetrightarrowtailNotPrecedesSlantEqualneArrBarwedcare\
+        tnaposbumpenvDashcircledSRopfShortDownArrowReverseUpEquilibriumsimplusgdotufromi\
+        cronOrTildeEquallgzacutengEacuteedotcirscirSquareUnionblk34rcubtscyrharulolarrem\
+        sp13boxVBfrcurvearrowleftGbrevenotnisparnsucceqNotGreaterLessjcyisinsvSquareSubs\
+        etNotTildeFullEqualssmilengsimNotSucceedsTildemaltdtriringmpOumlamalglsqbIopfupa\
+        rrowuogonintprodRightarrowrightrightarrowsRightVectorBarNotHumpEqualrAtailorderu\
+        umlEacutenhparnedotimaglinetridotntildeffiligdrbkarownlesbigcupcsupnwarhksuphsol\
+        rbrackUpdownarrowTRADEPopfomiddtrifRfrCoproductVerbartimesbNcywedgeqfflligLessFu\
+        llEqualnvgenlessbumpCayleysRscrQopfHstrokAMPRightArrowBarangmsdabcwconintrationa\
+        lsvartrianglerighteasterbetapscrDownArrowBarnsubseteqcurlyeqprecacysucccurlyeqbb\
+        rkcircleddashhybullJcyfemalenotinEracuteQfreparslfltnspermilcongcolonGcircnpolin\
+        tdlcropInvisibleCommavangrtrectjcircafrawconintZHcyldshkgreenOacuteordfnspareopf\
+        IumlYcypuncspsumiinfinTscrnsimNotTildeTildeasymplgENotExistsogonfrac38bbrktbrklo\
+        wastIEcyYacutesolNotVerticalBarzeetrfIOcynsmidchiBackslashmarkernvltriehamiltswa\
+        rrswarrowtriplusimpedboxdLboxDrScircRacutegelNJcyodivrarrfsUarrocirzwnjerDotsucc\
+        approxsearrowjsercySquareIntersectionangleeacuteNotNestedLessLessordmLeftarrowca\
+        pbrcupfiligangmsdaeUpTeeArrowcirfnintgtlParRshuringtbrkvarsupsetneqqcurvearrowri\
+        ghtzscrDcaronOopfigraveangmsdrbrkenotinplanckCircleMinusBernoullisIgraveSHcysopf\
+        succgesdotNotSucceedsDcyointbopffraslCloseCurlyDoubleQuotexrarrLeftTriangleEqual\
+        nisdnpreBarvlcaronmaleRoundImpliesRightUpVectorBarsubsimSumnsubsetlacutengeqslan\
+        tsuccneqquscrratioltdotnVDashnpreceqangstVvdashmfrnLeftarrowcscrImaginaryIwreath\
+        sqsubLeftUpDownVectorfrac34glHacekplusjukcydfratildesmallsetminusbigcircnearrCop\
+        frangeparslnsubdiamsltENGUcircominusvopfboxHularrhkUpperLeftArrowcomplexesContou\
+        rIntegralRrightarrowrightharpoondownlrmNotLessLessisinEhbarNotSucceedsSlantEqual\
+        frac58simgvBarvthorniffSupsetlscrboxDRvarrhofrac15LcaronXfrlcubEscrapidelsdotrds\
+        hcirclearrowleftmdashREGkjcyblacktrianglerightgscrabreveyfrimacrnesimnLtduarrrha\
+        rdorarrDownRightVectorBarVerticalLineBcyllarrnsceMfrluruharboxHveeeqbigstarecirc\
+        GreaterGreaterlarrbfslatailsup1equivDDggmiddotecarondownharpoonlefttritimelELarr\
+        gneqsqcupnsimeIotacurlywedgeUpArrowDownArrowgsimlwcircrHarCfrGscrLongleftarrowan\
+        grtucircsigmavlmoustachenvlegtdotcuescsubEfrac45QUOTdrcornororNotRightTriangleBa\
+        rLangbkarowastfpartintswArrPscrTcaronubrevehellipLmidottimesdboxvHrcedillcedilnw\
+        nearawintnsubEfrac12xoplusboxUrrealleftharpoondowntwixtAmacrncyinfintieZscrFfrss\
+        crharrcirsqufpoundleftrightharpoonsrdcastarenspNegativeVeryThinSpaceHumpEqualbla\
+        cktrianglefrac56OtimesuHarMediumSpaceisinvepsilonDaggergggnacuteSquareSupersetma\
+        lteseUnderBracketfrac78kscrntlgnvinfinniclubssubseteqqNotGreaterTildeImacrCcircC\
+        hisupdotraceDownLeftRightVectorvBarleqslantdscyPrSucceedsSlantEqualleqqDarrwedge\
+        diamondsuitpmutrinaturupharpoonleftHARDcyoplussubsubmodelsboxVHsetminusUdblacima\
+        thtprimegcircrtrifPrecedesSlantEqualPrimeelinterssupseteqblankAopfkfrlharuOverPa\
+        renthesisUpArrowBarnapEqfrbscrleftarrowtaildivideontimesacErightleftarrowsLeftVe\
+        ctorBarutrifscpolintnotltrihookleftarrownparslthkapprecnsimvartriangleleftyicybo\
+        xulnLeftrightarrowNtildetriangleLessLessdzcylesccNotRightTrianglelatqopfpreWopfr\
+        angdGdotnrtribneNotLeftTriangleNonBreakingSpacegraveUparrowparabulllarrmeasureda\
+        ngleparsimJsercyboxVrVopfiogonxscrtriehfrHfrwopfgacutesupehcircangmsdacImNotLess\
+        TildeuhblkUpTeebsimrhoquatintdtdotseArrefDotRightTriangleEqualRightVectornangNot\
+        LessmicroFscrsubedotlaquoeurolbrksluleqcheckLessTildedblaclneiquesttaufcymopfmap\
+        stoleftnumeroofrroangudharDownArrowUpArrowelcommatitUmacrstraightphilArrlarrtlFi\
+        lledVerySmallSquareSqrtAgraveTcedilnumGreaterFullEqualDstrokQscrtwoheadrightarro\
+        wRightUpDownVectorplankvLlNestedLessLessldcabethGreaterTildeeqcircgammadDoubleVe\
+        rticalBarboxhukappaleftarrowlesseqqgtrNotPrecedesEqualtriangledownquestLongLeftR\
+        ightArrowiiocircuharrProportionIntegralroarruplusrbarrnsccueKappacsupeTauwscrlne\
+        qqrscrultriRangrshacDownRightVectorlesdotortopforkdArrdoublebarwedgevDashiscrnot\
+        nivbtshcyiprodLtrealpartboxvliukcygnefjligaumlscircvfrkhcyboxuREquilibriumtosaRa\
+        rrtlotimesgbreveNotthetasupseteqqgElharrseswarkappavBopfsqsupseteqmidcirAndcolon\
+        erthreeiocyllccupsntrianglelefteqRuleDelayedinGreaterLessYIcynotinvbbigoplusraqu\
+        otrisblAtailhArrnRightarrowlozengeRightTrianglensqsupevarsigmarangleequivdcaronm\
+        umapZetayacuteDZcyiacuteNcaronpointintoscrshortmidltquestulcropxlArrUscrlooparro\
+        wrightIntersectionsmtesohbaricintegersNopfNewLineVertlurdsharVdashtstrokyucyntri\
+        angleleftprnsimepsivlbrksldsqcapRightArrowcircledRzigrarrLscrhkswarowPartialDSfr\
+        LleftarrownablaropluslongleftarrowaeligLstrokboxdrsungddotseqDotPfrdharlrlarrsdo\
+        tbsubnenotindotexponentialeRightTeepreceqotildedashhookrightarrowforkvoumlscapLa\
+        mbdaboxtimesNotTildeEqualnrightarrowPsiEmptySmallSquarenpartdigammabecausebernou\
+        yacyIcyPrecedesEqualLessEqualGreaterUnionPlusPlusMinuseDDotbigcapxlarrodotqprime\
+        supsimlrcornerGreaterEqualLessrbrksldepsiTripleDotNegativeThinSpacedoteqdotNacut\
+        etriangleqbsemidemptyvrarrtlNfrNotElementsearrgtrarrgvertneqqEcaronscEsmidlbarrb\
+        lacktriangledownszligesimupsilonprimesoftcycurlyveebarwednleftrightarrowuopfetao\
+        rigofSacutedowndownarrowslvertneqqLcediljfrlparthicksimmcommaNegativeThickSpaceC\
+        onintminusduGgnrarrwcularrampSucceedsTildeequestmhomacrrsaquodrcropepartildeNotG\
+        reaterNegativeMediumSpacegvnEzdotbulletdHarYcircprecnapproxtcaronheartsrsqbnvltR\
+        ightDownVectorBarSquareSupersetEqualiotaintcalpfrshchcyvellipLessGreaternsupEDou\
+        bleLeftArrowaposncongorslopeVerticalBarZeroWidthSpacerotimeshslashhomthtdashvVsc\
+        rcacutendashphmmatTScylarrplgtrdotalphasqsupsetacircodashnlenshortmidbackprimeUp\
+        perRightArrowSubsetEqualJcircumlcomplementCongruentupsiDoubleLeftRightArrowLeftU\
+        pVectorudarrDoubleUpArrowshyCrossCedillabumpEexistocirrarrhktimesHscrogtsubsetne\
+        qqTstrokprecsimccirczetabsimeaopfvltriprofsurfnGgDDycirccapcapvscrEmptyVerySmall\
+        SquareNscrvsubnegtquestzhcyxopfclubsuitDeltaNoBreakdiamondllhardlnEgeqslantnshor\
+        tparallelsqsubsetNotLessEqualDiacriticalDoubleAcuteangTabKcynapproxemspScedilNot\
+        EqualYopflotimesGtquesteqColonBumpeqodsoldNotLeftTriangleEqualleftrightarrowodbl\
+        acolinengeqqsharpEtabiguplusbotbigtriangledownintNotGreaterGreatervsupneBecausef\
+        rac35cirgEsetmnVerticalTildemidastllcornertintisindotShortLeftArrowVeeexcldeggsi\
+        measympeqnsupseteqprnapnparallellesdotodlcornnaturalbsolbrightthreetimesIukcyFor\
+        AllEogonUopfLeftArrowInvisibleTimescirEegsuwanglepartbarveeofcirzfrUnderParenthe\
+        sisangrtvblangdRightFloorRarrnvHarrrxubrcyLeftCeilingnsubseteqqTSHcyEopffrac25Ko\
+        pfLeftTriangleBargtrlessDownLeftVectorxrArrHatgtrsimcwintplanckhThickSpacesquinc\
+        arerobrkboxhUemacrboxHDflatRightArrowLeftArrowrlhargesccboxvrElementLTSscrrbbrkn\
+        VdashnumsplarrsimwpdsolNotDoubleVerticalBarBreveOdblacIntcommaMinusPlusdotplusco\
+        loneqhardcychcypercntAfrboxvLvnsubProductnsimeqApplyFunctionlsquorcuporAbrevethi\
+        ckapproxTildeurcropCOPYsimedeltaeqvparslboxVlfrac13rfishtblk14raemptyvCapitalDif\
+        ferentialDcurrenJopfovbarhyphenlneqandvdotminusbepsibetweenRightCeilingsqsubsete\
+        qUnderBracesupnElhblkAssignufishtOmicronsup3hscrrarrwellSuchThatcsubnapminusbsma\
+        shpbsolZfrvcymapstoOmegabumpeqRBarrLfrlongmapstogesapntrianglerighteqcircledastf\
+        rac23EgraveoSdaggerlrharxutriDelrightleftharpoonsDiacriticalGraveZacuteproflinec\
+        entvarepsilonrlmtcedilplusacircylctypisearhkstrnsOpenCurlyDoubleQuotecdotimagpar\
+        tvarkappaUpDownArrowboxhdphiNotRightTriangleEqualcheckmarkcopyfallingdotseqlesdo\
+        tGammaegsdotsimrarroeligEdotmlcpurcornddarrsstarfcopysrscsimnvdashLeftDownVector\
+        coprodnlArrlangIcircblk12muLeftUpTeeVectorxwedgeFilledSmallSquarecongdotegnltril\
+        dquoiiintrmoustImpliesLeftRightVectorlozfLopfDScyapelbrkeeumlPhishortparallelSho\
+        rtUpArrowrharurhovUumlSubsetUbrevenensubeStarsqcupsboxhDcirceqnsqsubeDashvxmapps\
+        iinodotdiesmtrarrbdollarngeslthreenleqqOcircroparfnofccapssuccnsimdotfopflagranv\
+        eebarxdtriiopfblacklozengenLlcireyopfsquarfuarrgsimnvsimocynleftarrowprurelCconi\
+        ntprcueSopfdarralefsymboxvhsfrownlesgesecyyenpivsupsetneqgeslesogravelharulsimlE\
+        cueprcompLshPoincareplaneYumlETHCcedilquaternionsOverBraceaogonnprecReverseEleme\
+        ntslarrsacutelapregcurlyeqsuccWfrffrtrianglelefteqparexpectationlbraceLongLeftAr\
+        rowDoubleLongLeftRightArrowLongrightarrowlsimeEcyvarsubsetneqscorderofScyNcedila\
+        ngzarrThereforeNotSquareSubsetEqualxsqcupFcyDiacriticalDotboxURLeftUpVectorBarDJ\
+        cylarrlpNotCupCapNotTildeDownRightTeeVectortscrltlarrLcynotinvcgimelrarrplnrarrl\
+        obrkzwjrdquoboxDLprecnsupseteqqZcaronplusdoveetrianglerightOverBartelrecnesearxc\
+        upsucceqlrhardlessdotlessubrarrupsihddltrifboxvlanglesubsupSupersetEqualtcylesga\
+        ngmsdafgammaUpsiSquareSubsetEqualNotGreaterFullEqualMapEcirchopfrtriltriOpenCurl\
+        yQuotepcycompfndivonxisinssubmultAcyglEOfrstarfrealsItildeuuarrblacksquarecularr\
+        pWcircgesltriminusbrvbardbkarowLowerRightArrowangmsdadsolbarprofalarvertdzigrarr\
+        conintolcirNotSquareSubsetsigmafYfrYAcyShortRightArrowAacuteEpsilonNotSquareSupe\
+        rsetboxDlBetaboxuLbottombigodotdstrokkcyleftrightarrowstrianglerighteqsimgErarrs\
+        imtopbotsimntrianglerightandslopelfrimagenotnivalozlvnEsuphsubUnionncedilEsimthe\
+        reforeRenwArrCacuteNotSucceedsEqualnurBarrplustwolowbarifrsuccnapproxcuwedYscrNo\
+        tPrecedesaacutetriangleleftmscrltcirnsuccbowtieEfrXscritildethetavnparnexistsrnm\
+        idlfishtPcynlarrnleqslantnsupecfrlarrfsupuparrowskcedilSigmaTcyforallsfrmnplusNo\
+        tSupersetEqualOmacrucyafAringlstrokgfrNotGreaterSlantEqualcupNotLessGreaternlsim\
+        bigwedgeDownBreveRcedilblockweierplBarrffligoperpnequivycybackcongiiiintvarpiWed\
+        gelltribfrrealinerrarrlsquoAlphansupsqsupebigotimeselssqsupmstpossupdsubmidCscrn\
+        vrtriedcyIfrCupCapbigsqcupgesdotoproptogtsmeparslomacrulcornervsupnEpreccurlyeqD\
+        otEqualJukcyrarrapsupmultrightharpoonupnvrArrDoubleContourIntegralboxvRlshsmilee\
+        ngRightAngleBracketlhardgopfperiodUbrcyxharrUpArrowUcysqcapsgnapproxNotLessSlant\
+        EqualProportionalrbraceIdotugraveXopfVdashlzcyeqsimbcyerarrharrwthere4doteqicirc\
+        prodctdotNotGreaterEqualcapcupLJcyIJligrightsquigarrowcupcupSucceedsleftharpoonu\
+        pVeryThinSpacesexttimesbarequalslsimmapstodownThinSpaceorvudblacgtcirRcaronsupla\
+        rrscedildiamleftrightsquigarrowsupsetuArrboxminussubseteqomegaZopfMopfyscrOscrSu\
+        cceedsEqualshcyoacutengtralephheartsuitcopflsaquotopfDownarrowurcornercudarrlDow\
+        nLeftTeeVectorUtildebecausblacktriangleleftZcyspadesuitnjcybemptyvDoubleDotboxdR\
+        ltccOEligstraightepsilonGJcyvpropBscrxfrlEgUfrscaronLeftrightarrowemptyvlessgtrt\
+        hksimIacutenldrboxHdrtimesprnEPrecedeslHarnrArregravenGtvdotsquarermoustachepara\
+        llelHilbertSpacesectswarhkrarrbfsangevsubnEngtDoubleUpDownArrowsubnEvarsubsetneq\
+        qtargetintlarhkWscrlnaprsquosupedotLeftFloorbdquoLeftTeeVectorUnderBarphivnsupse\
+        tCcarontwoheadleftarrowangsphScaronRightDownTeeVectoresdotgesdotollcysdoteuharlc\
+        irmidmapKfrHcircangmsdaaAtildeyumlglalateperpetheqslantlessolcrossPiUpEquilibriu\
+        mbnequivruluharOverBracketDotDotloarrxotimeMellintrfOgraveEumlvArrgtrapproxcempt\
+        yvDoubleDownArrownscnharrvarsupsetneqrangdopfGreaterEquallsimgnleqrpargtAumlprap\
+        wfrDiacriticalTildecupbrcapboxurgcyiiotaLongleftrightarrowlooparrowleftLeftRight\
+        ArrowRightTriangleBarupdownarrowdownarrowkopfDownTeeArrowjscrboxVhnrtriecedilvns\
+        updivCounterClockwiseContourIntegraldfishtCirclePlusleSubNotSubsetEqualCuplambda\
+        circledcircvarrplussimMuvdashsubsetandandCenterDotColoneSuperseteqslantgtrosolbo\
+        xUlbrevecupdothksearowLacutetoeatoplegDownTeedscrVbarsup2angrtvbdLaplacetrfprime\
+        snhArrIscrNotSquareSupersetEqualapproxRightDoubleBracketqscrltrParforkltrieDiamo\
+        ndlopflaemptyvlbbrklbrackSHCHcyScgnsimNotCongruentKHcypertenkTfrleftthreetimesba\
+        ckepsilonbacksimcaronnearrowLeftArrowRightArrowfrac14emsp14intercalsuccsimncapNo\
+        tEqualTildenltriexcapGammadrparsigmaboxboxJfrefrKJcyswnwarwrflligisinGcyVcygnaps\
+        setmnDoubleRightArrowupharpoonrightrbrksluinfinlmoustCHcyHumpDownHumpsupsupboxdl\
+        popfmapstoupGreaterSlantEqualquotplusmnuacutedividescyGfrhorbarimofnscrlceilDoub\
+        leLeftTeeNotSubsetbsolhsubLeftDownVectorBaremptyverbarotimesasIogoncurarrmrarrlp\
+        KscrddaggerurtrinopfNotHumpDownHumpiumlAogonprecneqqKcedilNotSupersetnapidhairsp\
+        eplussubeMscrsupEscengeminusdLeftArrowBaricyYUcysimlClockwiseContourIntegralFour\
+        iertrfZdotdharrboxhncupReverseEquilibriumgapngeqldquorspadesDfrlparltscnapgeqDDo\
+        trahdLongRightArrownbspboxHUnwarrPrecedesTildeTildeFullEqualijligRightDownVector\
+        andCloseCurlyQuoterfloorNotReverseElementncongdotboxVRmultimapgeqqordnwarrowEqua\
+        lTildeLowerLeftArrowVfrHopfulcornlnsimUogonnrarrcsimdotrppolintDownLeftVectorBar\
+        iecyscnsimCapGTnprcueGcedilEmacroastrcaronumacrThetaradiccapdotvarphicapsUpsilon\
+        hoarrVerticalSeparatorUgraveeogonTildeTildetdotacdRightTeeArrowsolbOslashnbumpec\
+        apOcynvlArrljcycuveeLessSlantEqualsemirisingdotseqrcysupEqualcapandvzigzagxcircN\
+        otLeftTriangleBardalethphonerAarrcsubeboxVLrarrcsimeqloparropfsupsetneqqlatesOti\
+        ldeUringangmsdahnexisteesupplusAEligbacksimeqxhArrcurarrnLtvprecapproxthetasymxn\
+        isnivsubdotDopfnearhkmDDotlnapproxxuplusMcycircaringsupneAscrncaronscnElessappro\
+        xccedilrfrvarnothingsquareascrntglrtrisdotDoubleRightTeetradenbumpsccuegjcyrdquo\
+        rVDashgnEgneqqsbquonvgtccaronfrownfrac18zopfTHORNrightarrowNuCircleDotsimneprsim\
+        lAarramacrlongleftrightarrowrtrieoparnprRightUpTeeVectorCircleTimesbnotlarrbDoub\
+        leLongRightArrowRcyrataillfloorsubsetneqrdldharRhozcaronapESquareapproxeqDoubleL\
+        ongLeftArrowangmsdagtfrvrtrimldrnotinvathinspjmathLeftVectoranddpropSupapacirglj\
+        nlteqcolonldrusharUarrvarthetalrarrcirclearrowrighttopciroslashldrdharDiacritica\
+        lAcuteecirDifferentialDsubplusCdotnGtExistsnvaprArrsqsubeqintnaturalsohmnisdwang\
+        lecudarrrdisinplusduLeftDownTeeVectorAcirccrossbNotxveeLeftTeeutildegtreqqlesslo\
+        ngrightarrowSOFTcybarwedgesmtejopfExponentialEltimesnmidherconsrarrloplusorlesse\
+        qgtr";
+
+    /// Entity expansions, in the same order as the names.
+    pub(super) static VALUES: &str = "\
+        \u{1D52B}\u{1D4BB}\u{2282}\u{00E0}\u{27E6}\u{22F0}\u{00BD}\u{21BE}\
+        \u{22C1}\u{2AD4}\u{2251}\u{2193}\u{039E}\u{2218}\u{00B7}\u{22DB}\
+        \u{22B2}\u{2A5F}\u{2AA2}\u{0338}\u{2A46}\u{0140}\u{2AB3}\u{21B5}\u{224C}\
+        \u{255D}\u{2159}\u{1D53E}\u{1D4A5}\u{21C7}\u{1D54B}\u{27EC}\u{23E2}\
+        \u{2A72}\u{2265}\u{2019}\u{226B}\u{043C}\u{0127}\u{295B}\u{00DA}\
+        \u{29C0}\u{2035}\u{1D53D}\u{2A00}\u{1D49F}\u{2255}\u{227A}\u{222A}\u{FE00}\
+        \u{2272}\u{2A50}\u{221D}\u{00A1}\u{2212}\u{1D560}\u{2309}\u{21C2}\
+        \u{2192}\u{25B3}\u{229E}\u{296F}\u{212F}\u{229E}\u{0452}\u{2266}\u{0338}\
+        \u{2AA7}\u{03BE}\u{22D4}\u{21A4}\u{2A22}\u{2500}\u{22FD}\u{22BF}\
+        \u{27E8}\u{2205}\u{21A3}\u{22E0}\u{21D7}\u{2306}\u{2041}\u{0149}\
+        \u{224F}\u{22AD}\u{24C8}\u{211D}\u{2193}\u{296F}\u{2A24}\u{0121}\
+        \u{1D532}\u{03BF}\u{2A54}\u{2243}\u{2276}\u{017A}\u{2267}\u{0338}\u{00B4}\
+        \u{0117}\u{29C2}\u{2294}\u{2593}\u{007D}\u{0446}\u{296C}\u{21BA}\
+        \u{2004}\u{2551}\u{1D505}\u{21B6}\u{011E}\u{220C}\u{2225}\u{2AB0}\u{0338}\
+        \u{2279}\u{0439}\u{22F3}\u{228F}\u{2247}\u{2323}\u{2275}\u{227F}\u{0338}\
+        \u{2720}\u{25BF}\u{02DA}\u{2213}\u{00D6}\u{2A3F}\u{005B}\u{1D540}\
+        \u{2191}\u{0173}\u{2A3C}\u{21D2}\u{21C9}\u{2953}\u{224F}\u{0338}\u{291C}\
+        \u{2134}\u{00FC}\u{00C9}\u{2AF2}\u{2250}\u{0338}\u{2110}\u{25EC}\u{00F1}\
+        \u{FB03}\u{2910}\u{2A7D}\u{0338}\u{22C3}\u{2AD0}\u{2923}\u{27C9}\u{005D}\
+        \u{21D5}\u{2122}\u{2119}\u{29B6}\u{25BE}\u{211C}\u{2210}\u{2016}\
+        \u{22A0}\u{041D}\u{2259}\u{FB04}\u{2266}\u{2265}\u{20D2}\u{226E}\u{224E}\
+        \u{212D}\u{211B}\u{211A}\u{0126}\u{0026}\u{21E5}\u{29A9}\u{2232}\
+        \u{211A}\u{22B3}\u{2A6E}\u{03B2}\u{1D4C5}\u{2913}\u{2288}\u{22DE}\
+        \u{0430}\u{227D}\u{23B5}\u{229D}\u{2043}\u{0419}\u{2640}\u{22F9}\u{0338}\
+        \u{0155}\u{1D514}\u{29E3}\u{25B1}\u{2030}\u{2245}\u{003A}\u{011C}\
+        \u{2A14}\u{230D}\u{2063}\u{299C}\u{25AD}\u{0135}\u{1D51E}\u{2233}\
+        \u{0416}\u{21B2}\u{0138}\u{00D3}\u{00AA}\u{2226}\u{1D556}\u{00CF}\
+        \u{042B}\u{2008}\u{2211}\u{29DC}\u{1D4AF}\u{2241}\u{2249}\u{2248}\
+        \u{2A91}\u{2204}\u{02DB}\u{215C}\u{23B6}\u{2217}\u{0415}\u{00DD}\
+        \u{002F}\u{2224}\u{2128}\u{0401}\u{2224}\u{03C7}\u{2216}\u{25AE}\
+        \u{22B4}\u{20D2}\u{210B}\u{2199}\u{2199}\u{2A39}\u{01B5}\u{2555}\u{2553}\
+        \u{015C}\u{0154}\u{22DB}\u{040A}\u{2A38}\u{291E}\u{2949}\u{200C}\
+        \u{2253}\u{2AB8}\u{2198}\u{0458}\u{2293}\u{2220}\u{00E9}\u{2AA1}\u{0338}\
+        \u{00BA}\u{21D0}\u{2A49}\u{FB01}\u{29AC}\u{21A5}\u{2A10}\u{2995}\
+        \u{21B1}\u{016F}\u{23B4}\u{2ACC}\u{FE00}\u{21B7}\u{1D4CF}\u{010E}\u{1D546}\
+        \u{00EC}\u{2221}\u{298C}\u{2209}\u{210F}\u{2296}\u{212C}\u{00CC}\
+        \u{0428}\u{1D564}\u{227B}\u{2A80}\u{2281}\u{0414}\u{222E}\u{1D553}\
+        \u{2044}\u{201D}\u{27F6}\u{22B4}\u{22FA}\u{2AAF}\u{0338}\u{2AE7}\u{013E}\
+        \u{2642}\u{2970}\u{2954}\u{2AC7}\u{2211}\u{2282}\u{20D2}\u{013A}\u{2A7E}\u{0338}\
+        \u{2AB6}\u{1D4CA}\u{2236}\u{22D6}\u{22AF}\u{2AAF}\u{0338}\u{00C5}\u{22AA}\
+        \u{1D52A}\u{21CD}\u{1D4B8}\u{2148}\u{2240}\u{228F}\u{2951}\u{00BE}\
+        \u{2277}\u{02C7}\u{002B}\u{0454}\u{1D521}\u{00E3}\u{2216}\u{25EF}\
+        \u{2197}\u{2102}\u{29A5}\u{2AFD}\u{2284}\u{2666}\u{003C}\u{014A}\
+        \u{00DB}\u{2296}\u{1D567}\u{2567}\u{21A9}\u{2196}\u{2102}\u{222E}\
+        \u{21DB}\u{21C1}\u{200E}\u{226A}\u{0338}\u{22F9}\u{210F}\u{22E1}\u{215D}\
+        \u{2A9E}\u{2AE9}\u{00FE}\u{21D4}\u{22D1}\u{1D4C1}\u{2554}\u{03F1}\
+        \u{2155}\u{013D}\u{1D51B}\u{007B}\u{2130}\u{224B}\u{2A97}\u{21B3}\
+        \u{21BA}\u{2014}\u{00AE}\u{045C}\u{25B8}\u{210A}\u{0103}\u{1D536}\
+        \u{012B}\u{2242}\u{0338}\u{226A}\u{20D2}\u{21F5}\u{21C1}\u{21BB}\u{2957}\u{007C}\
+        \u{0411}\u{21C7}\u{2AB0}\u{0338}\u{1D510}\u{2966}\u{2550}\u{225A}\u{2605}\
+        \u{00EA}\u{2AA2}\u{291F}\u{2919}\u{00B9}\u{2A78}\u{226B}\u{00B7}\
+        \u{011B}\u{21C3}\u{2A3B}\u{2266}\u{219E}\u{2A88}\u{2294}\u{2244}\
+        \u{0399}\u{22CF}\u{21C5}\u{2A90}\u{0175}\u{2964}\u{212D}\u{1D4A2}\
+        \u{27F8}\u{221F}\u{00FB}\u{03C2}\u{23B0}\u{2264}\u{20D2}\u{22D7}\u{22DF}\
+        \u{2AC5}\u{2158}\u{0022}\u{231F}\u{2A56}\u{29D0}\u{0338}\u{27EA}\u{290D}\
+        \u{002A}\u{2A0D}\u{21D9}\u{1D4AB}\u{0164}\u{016D}\u{2026}\u{013F}\
+        \u{2A30}\u{256A}\u{0157}\u{013C}\u{2927}\u{2A11}\u{2AC5}\u{0338}\u{00BD}\
+        \u{2A01}\u{2559}\u{211C}\u{21BD}\u{226C}\u{0100}\u{043D}\u{29DD}\
+        \u{1D4B5}\u{1D509}\u{1D4C8}\u{2948}\u{25AA}\u{00A3}\u{21CB}\u{2937}\
+        \u{2606}\u{2002}\u{200B}\u{224F}\u{25B4}\u{215A}\u{2A37}\u{2963}\
+        \u{205F}\u{2208}\u{03B5}\u{2021}\u{22D9}\u{0144}\u{2290}\u{2720}\
+        \u{23B5}\u{215E}\u{1D4C0}\u{2278}\u{29DE}\u{220B}\u{2663}\u{2AC5}\
+        \u{2275}\u{012A}\u{0108}\u{03A7}\u{2ABE}\u{223D}\u{0331}\u{2950}\u{2AE8}\
+        \u{2A7D}\u{0455}\u{2ABB}\u{227D}\u{2266}\u{21A1}\u{2227}\u{2666}\
+        \u{00B1}\u{25B5}\u{266E}\u{21BF}\u{042A}\u{2295}\u{2AD5}\u{22A7}\
+        \u{256C}\u{2216}\u{0170}\u{0131}\u{2034}\u{011D}\u{25B8}\u{227C}\
+        \u{2033}\u{23E7}\u{2287}\u{2423}\u{1D538}\u{1D528}\u{21BC}\u{23DC}\
+        \u{2912}\u{2A70}\u{0338}\u{1D52E}\u{1D4B7}\u{21A2}\u{22C7}\u{223E}\u{0333}\u{21C4}\
+        \u{2952}\u{25B4}\u{2A13}\u{00AC}\u{25C3}\u{21A9}\u{2AFD}\u{20E5}\u{2248}\
+        \u{22E8}\u{22B2}\u{0457}\u{2518}\u{21CE}\u{00D1}\u{25B5}\u{2AA1}\
+        \u{045F}\u{2AA8}\u{22EB}\u{2AAB}\u{1D562}\u{2AAF}\u{1D54E}\u{2992}\
+        \u{0120}\u{22EB}\u{003D}\u{20E5}\u{22EA}\u{00A0}\u{0060}\u{21D1}\u{00B6}\
+        \u{2022}\u{2190}\u{2221}\u{2AF3}\u{0408}\u{255F}\u{1D54D}\u{012F}\
+        \u{1D4CD}\u{225C}\u{1D525}\u{210C}\u{1D568}\u{01F5}\u{2287}\u{0125}\
+        \u{29AA}\u{2111}\u{2274}\u{2580}\u{22A5}\u{223D}\u{03C1}\u{2A16}\
+        \u{22F1}\u{21D8}\u{2252}\u{22B5}\u{21C0}\u{2220}\u{20D2}\u{226E}\u{00B5}\
+        \u{2131}\u{2AC3}\u{00AB}\u{20AC}\u{298D}\u{2264}\u{2713}\u{2272}\
+        \u{02DD}\u{2A87}\u{00BF}\u{03C4}\u{0444}\u{1D55E}\u{21A4}\u{2116}\
+        \u{1D52C}\u{27ED}\u{296E}\u{21F5}\u{2A99}\u{0040}\u{2062}\u{016A}\
+        \u{03D5}\u{21D0}\u{21A2}\u{25AA}\u{221A}\u{00C0}\u{0162}\u{0023}\
+        \u{2267}\u{0110}\u{1D4AC}\u{21A0}\u{294F}\u{210F}\u{22D8}\u{226A}\
+        \u{2936}\u{2136}\u{2273}\u{2256}\u{03DD}\u{2225}\u{2534}\u{03BA}\
+        \u{2190}\u{2A8B}\u{2AAF}\u{0338}\u{25BF}\u{003F}\u{27F7}\u{2148}\u{00F4}\
+        \u{21BE}\u{2237}\u{222B}\u{21FE}\u{228E}\u{290D}\u{22E1}\u{039A}\
+        \u{2AD2}\u{03A4}\u{1D4CC}\u{2268}\u{1D4C7}\u{25F8}\u{27EB}\u{21B1}\
+        \u{223E}\u{21C1}\u{2A83}\u{2ADA}\u{21D3}\u{2306}\u{22A8}\u{1D4BE}\
+        \u{22FE}\u{045B}\u{2A3C}\u{226A}\u{211C}\u{2524}\u{0456}\u{2A88}\
+        \u{0066}\u{006A}\u{00E4}\u{015D}\u{1D533}\u{0445}\u{2558}\u{21CC}\u{2929}\
+        \u{2916}\u{2297}\u{011F}\u{2AEC}\u{03B8}\u{2AC6}\u{2A8C}\u{2194}\
+        \u{2929}\u{03F0}\u{1D539}\u{2292}\u{2AF0}\u{2A53}\u{2254}\u{22CC}\
+        \u{0451}\u{226A}\u{2A4C}\u{22EC}\u{29F4}\u{2208}\u{2277}\u{0407}\
+        \u{22F7}\u{2A01}\u{00BB}\u{29CD}\u{291B}\u{21D4}\u{21CF}\u{25CA}\
+        \u{22B3}\u{22E3}\u{03C2}\u{27E9}\u{2261}\u{010F}\u{22B8}\u{0396}\
+        \u{00FD}\u{040F}\u{00ED}\u{0147}\u{2A15}\u{2134}\u{2223}\u{2A7B}\
+        \u{230F}\u{27F8}\u{1D4B0}\u{21AC}\u{22C2}\u{2AAC}\u{FE00}\u{29B5}\u{2063}\
+        \u{2124}\u{2115}\u{000A}\u{2016}\u{294A}\u{22A9}\u{0167}\u{044E}\
+        \u{22EA}\u{22E8}\u{03F5}\u{298F}\u{2293}\u{2192}\u{00AE}\u{21DD}\
+        \u{2112}\u{2926}\u{2202}\u{1D516}\u{21DA}\u{2207}\u{2A2E}\u{27F5}\
+        \u{00E6}\u{0141}\u{250C}\u{266A}\u{2A77}\u{00A8}\u{1D513}\u{21C3}\
+        \u{21C4}\u{22A1}\u{228A}\u{22F5}\u{0338}\u{2147}\u{22A2}\u{2AAF}\u{00F5}\
+        \u{2010}\u{21AA}\u{2AD9}\u{00F6}\u{2AB8}\u{039B}\u{22A0}\u{2244}\
+        \u{219B}\u{03A8}\u{25FB}\u{2202}\u{0338}\u{03DD}\u{2235}\u{212C}\u{044F}\
+        \u{0418}\u{2AAF}\u{22DA}\u{228E}\u{00B1}\u{2A77}\u{22C2}\u{27F5}\
+        \u{2299}\u{2057}\u{2AC8}\u{231F}\u{22DB}\u{298E}\u{03B5}\u{20DB}\
+        \u{200B}\u{2251}\u{0143}\u{225C}\u{204F}\u{29B1}\u{21A3}\u{1D511}\
+        \u{2209}\u{2198}\u{2978}\u{2269}\u{FE00}\u{011A}\u{2AB4}\u{2223}\u{290C}\
+        \u{25BE}\u{00DF}\u{2242}\u{03C5}\u{2032}\u{044C}\u{22CE}\u{2305}\
+        \u{21AE}\u{1D566}\u{03B7}\u{22B6}\u{015A}\u{21CA}\u{2268}\u{FE00}\u{013B}\
+        \u{1D527}\u{0028}\u{223C}\u{2A29}\u{200B}\u{222F}\u{2A2A}\u{22D9}\
+        \u{219D}\u{0338}\u{21B6}\u{0026}\u{227F}\u{225F}\u{2127}\u{00AF}\u{203A}\
+        \u{230C}\u{22D5}\u{02DC}\u{226F}\u{200B}\u{2269}\u{FE00}\u{017C}\u{2022}\
+        \u{2965}\u{0176}\u{2AB9}\u{0165}\u{2665}\u{005D}\u{003C}\u{20D2}\u{2955}\
+        \u{2292}\u{03B9}\u{22BA}\u{1D52D}\u{0449}\u{22EE}\u{2276}\u{2AC6}\u{0338}\
+        \u{21D0}\u{0027}\u{2247}\u{2A57}\u{2223}\u{200B}\u{2A35}\u{210F}\
+        \u{223B}\u{22A3}\u{1D4B1}\u{0107}\u{2013}\u{2133}\u{0426}\u{2939}\
+        \u{22D7}\u{03B1}\u{2290}\u{00E2}\u{229D}\u{2270}\u{2224}\u{2035}\
+        \u{2197}\u{2286}\u{0134}\u{00A8}\u{2201}\u{2261}\u{03C5}\u{21D4}\
+        \u{21BF}\u{21C5}\u{21D1}\u{00AD}\u{2A2F}\u{00B8}\u{2AAE}\u{2203}\
+        \u{229A}\u{21AA}\u{00D7}\u{210B}\u{29C1}\u{2ACB}\u{0166}\u{227E}\
+        \u{0109}\u{03B6}\u{22CD}\u{1D552}\u{22B2}\u{2313}\u{22D9}\u{0338}\u{2145}\
+        \u{0177}\u{2A4B}\u{1D4CB}\u{25AB}\u{1D4A9}\u{228A}\u{FE00}\u{2A7C}\u{0436}\
+        \u{1D569}\u{2663}\u{0394}\u{2060}\u{22C4}\u{296B}\u{2268}\u{2A7E}\
+        \u{2226}\u{228F}\u{2270}\u{02DD}\u{2220}\u{0009}\u{041A}\u{2249}\
+        \u{2003}\u{015E}\u{2260}\u{1D550}\u{2A34}\u{226B}\u{225F}\u{2237}\
+        \u{224E}\u{29BC}\u{22EC}\u{2194}\u{0151}\u{203E}\u{2267}\u{0338}\u{266F}\
+        \u{0397}\u{2A04}\u{22A5}\u{25BD}\u{222B}\u{226B}\u{0338}\u{228B}\u{FE00}\u{2235}\
+        \u{2157}\u{25CB}\u{2267}\u{2216}\u{2240}\u{002A}\u{231E}\u{222D}\
+        \u{22F5}\u{2190}\u{22C1}\u{0021}\u{00B0}\u{2A8E}\u{224D}\u{2289}\
+        \u{2AB9}\u{2226}\u{2A81}\u{231E}\u{266E}\u{29C5}\u{22CC}\u{0406}\
+        \u{2200}\u{0118}\u{1D54C}\u{2190}\u{2062}\u{29C3}\u{2A96}\u{29A7}\
+        \u{2202}\u{22BD}\u{29BF}\u{1D537}\u{23DD}\u{22BE}\u{2991}\u{230B}\
+        \u{21A0}\u{2904}\u{211E}\u{045E}\u{2308}\u{2AC5}\u{0338}\u{040B}\u{1D53C}\
+        \u{2156}\u{1D542}\u{29CF}\u{2277}\u{21BD}\u{27F9}\u{005E}\u{2273}\
+        \u{2231}\u{210E}\u{205F}\u{200A}\u{25A1}\u{2105}\u{27E7}\u{2568}\u{0113}\
+        \u{2566}\u{266D}\u{21C4}\u{21CC}\u{2AA9}\u{251C}\u{2208}\u{003C}\
+        \u{1D4AE}\u{2773}\u{22AE}\u{2007}\u{2973}\u{2118}\u{29F6}\u{2226}\
+        \u{02D8}\u{0150}\u{222C}\u{002C}\u{2213}\u{2214}\u{2254}\u{044A}\
+        \u{0447}\u{0025}\u{1D504}\u{2561}\u{2282}\u{20D2}\u{220F}\u{2244}\u{2061}\
+        \u{201A}\u{2A45}\u{0102}\u{2248}\u{223C}\u{230E}\u{00A9}\u{2243}\
+        \u{03B4}\u{29E5}\u{2562}\u{2153}\u{297D}\u{2591}\u{29B3}\u{2145}\
+        \u{00A4}\u{1D541}\u{233D}\u{2010}\u{2A87}\u{2A5A}\u{2238}\u{03F6}\
+        \u{226C}\u{2309}\u{2291}\u{23DF}\u{2ACC}\u{2584}\u{2254}\u{297E}\
+        \u{039F}\u{00B3}\u{1D4BD}\u{219D}\u{2113}\u{220B}\u{2ACF}\u{2249}\
+        \u{229F}\u{2A33}\u{005C}\u{2128}\u{0432}\u{21A6}\u{03A9}\u{224F}\
+        \u{2910}\u{1D50F}\u{27FC}\u{2A7E}\u{2248}\u{22ED}\u{229B}\u{2154}\
+        \u{00C8}\u{24C8}\u{2020}\u{21CB}\u{25B3}\u{2207}\u{21CC}\u{0060}\
+        \u{0179}\u{2312}\u{00A2}\u{03F5}\u{200F}\u{0163}\u{2A23}\u{232D}\
+        \u{03C0}\u{2925}\u{00AF}\u{201C}\u{010B}\u{2111}\u{03F0}\u{2195}\
+        \u{252C}\u{03C6}\u{22ED}\u{2713}\u{00A9}\u{2252}\u{2A7F}\u{0393}\
+        \u{2A98}\u{2972}\u{0153}\u{0116}\u{2ADB}\u{231D}\u{21CA}\u{22C6}\
+        \u{2117}\u{227F}\u{22AC}\u{21C3}\u{2210}\u{21CD}\u{27E8}\u{00CE}\
+        \u{2592}\u{03BC}\u{2960}\u{22C0}\u{25FC}\u{2A6D}\u{2A9A}\u{22EA}\
+        \u{201C}\u{222D}\u{23B1}\u{21D2}\u{294E}\u{29EB}\u{1D543}\u{0405}\
+        \u{224A}\u{298B}\u{00EB}\u{03A6}\u{2225}\u{2191}\u{21C0}\u{03F1}\
+        \u{00DC}\u{22D0}\u{016C}\u{2260}\u{2288}\u{22C6}\u{2294}\u{FE00}\u{2565}\
+        \u{2257}\u{22E2}\u{2AE4}\u{27FC}\u{03C8}\u{0131}\u{00A8}\u{2AAA}\
+        \u{21E5}\u{0024}\u{2A7E}\u{0338}\u{22CB}\u{2266}\u{0338}\u{00D4}\u{2986}\u{0192}\
+        \u{2A4D}\u{22E9}\u{02D9}\u{1D557}\u{2112}\u{22BB}\u{25BD}\u{1D55A}\
+        \u{29EB}\u{22D8}\u{0338}\u{2257}\u{1D56A}\u{25AA}\u{2191}\u{2273}\u{223C}\u{20D2}\
+        \u{043E}\u{219A}\u{22B0}\u{2230}\u{227C}\u{1D54A}\u{2193}\u{2135}\
+        \u{253C}\u{2322}\u{2A93}\u{044D}\u{00A5}\u{03D6}\u{228B}\u{2A94}\
+        \u{00F2}\u{296A}\u{2A9F}\u{22DE}\u{2201}\u{21B0}\u{210C}\u{0178}\
+        \u{00D0}\u{00C7}\u{210D}\u{23DE}\u{0105}\u{2280}\u{220B}\u{2190}\
+        \u{015B}\u{2A85}\u{00AE}\u{22DF}\u{1D51A}\u{1D523}\u{22B4}\u{2225}\
+        \u{2130}\u{007B}\u{27F5}\u{27FA}\u{27F9}\u{2A8D}\u{042D}\u{228A}\u{FE00}\
+        \u{227B}\u{2134}\u{0421}\u{0145}\u{237C}\u{2234}\u{22E2}\u{2A06}\
+        \u{0424}\u{02D9}\u{255A}\u{2958}\u{0402}\u{21AB}\u{226D}\u{2241}\
+        \u{295F}\u{1D4C9}\u{2976}\u{041B}\u{22F6}\u{2137}\u{2945}\u{219B}\
+        \u{27E6}\u{200D}\u{201D}\u{2557}\u{227A}\u{2AC6}\u{0338}\u{017D}\u{2214}\
+        \u{2228}\u{25B9}\u{203E}\u{2315}\u{2928}\u{22C3}\u{2AB0}\u{296D}\
+        \u{22D6}\u{2A7D}\u{2979}\u{03D2}\u{2146}\u{25C2}\u{2502}\u{27E8}\
+        \u{2AD3}\u{2287}\u{0442}\u{22DA}\u{FE00}\u{29AD}\u{03B3}\u{03D2}\u{2291}\
+        \u{2267}\u{0338}\u{2905}\u{00CA}\u{1D559}\u{29CE}\u{2018}\u{043F}\u{2218}\
+        \u{22C7}\u{22F4}\u{2AC1}\u{0410}\u{2A92}\u{1D512}\u{2605}\u{211D}\
+        \u{0128}\u{21C8}\u{25AA}\u{293D}\u{0174}\u{22DB}\u{FE00}\u{2A3A}\u{00A6}\
+        \u{290F}\u{2198}\u{29AB}\u{233F}\u{232E}\u{007C}\u{27FF}\u{222E}\
+        \u{29BE}\u{228F}\u{0338}\u{03C2}\u{1D51C}\u{042F}\u{2192}\u{00C1}\u{0395}\
+        \u{2290}\u{0338}\u{2556}\u{0392}\u{255B}\u{22A5}\u{2A00}\u{0111}\u{043A}\
+        \u{21C6}\u{22B5}\u{2AA0}\u{2974}\u{2336}\u{223C}\u{22EB}\u{2A58}\
+        \u{1D529}\u{2111}\u{220C}\u{25CA}\u{2268}\u{FE00}\u{2AD7}\u{22C3}\u{0146}\
+        \u{2A73}\u{2234}\u{211C}\u{21D6}\u{0106}\u{2AB0}\u{0338}\u{03BD}\u{290F}\
+        \u{2A27}\u{005F}\u{1D526}\u{2ABA}\u{22CF}\u{1D4B4}\u{2280}\u{00E1}\
+        \u{25C3}\u{1D4C2}\u{2A79}\u{2281}\u{22C8}\u{1D508}\u{1D4B3}\u{0129}\
+        \u{03D1}\u{2226}\u{2204}\u{2AEE}\u{297C}\u{041F}\u{219A}\u{2A7D}\u{0338}\
+        \u{2289}\u{1D520}\u{291D}\u{21C8}\u{0137}\u{03A3}\u{0422}\u{2200}\
+        \u{1D530}\u{2213}\u{2289}\u{014C}\u{0443}\u{2061}\u{00C5}\u{0142}\
+        \u{1D524}\u{2A7E}\u{0338}\u{222A}\u{2278}\u{2274}\u{22C0}\u{0311}\u{0156}\
+        \u{2588}\u{2118}\u{290E}\u{FB00}\u{29B9}\u{2262}\u{044B}\u{224C}\
+        \u{2A0C}\u{03D6}\u{22C0}\u{25FA}\u{1D51F}\u{211B}\u{21C9}\u{2018}\
+        \u{0391}\u{2285}\u{2292}\u{2A02}\u{2A95}\u{2290}\u{223E}\u{2AD8}\
+        \u{2223}\u{1D49E}\u{22B5}\u{20D2}\u{0434}\u{2111}\u{224D}\u{2A06}\u{2A82}\
+        \u{221D}\u{003E}\u{29E4}\u{014D}\u{231C}\u{2ACC}\u{FE00}\u{227C}\u{2250}\
+        \u{0404}\u{2975}\u{2AC2}\u{21C0}\u{2903}\u{222F}\u{255E}\u{21B0}\
+        \u{2323}\u{014B}\u{27E9}\u{21BD}\u{1D558}\u{002E}\u{040E}\u{27F7}\
+        \u{2191}\u{0423}\u{2293}\u{FE00}\u{2A8A}\u{2A7D}\u{0338}\u{221D}\u{007D}\u{0130}\
+        \u{00F9}\u{1D54F}\u{2AE6}\u{0437}\u{2242}\u{0431}\u{2971}\u{21AD}\
+        \u{2234}\u{2250}\u{00EE}\u{220F}\u{22EF}\u{2271}\u{2A47}\u{0409}\
+        \u{0132}\u{219D}\u{2A4A}\u{227B}\u{21BC}\u{200A}\u{2736}\u{2A31}\
+        \u{003D}\u{2272}\u{21A7}\u{2009}\u{2A5B}\u{0171}\u{2A7A}\u{0158}\
+        \u{297B}\u{015F}\u{22C4}\u{21AD}\u{2283}\u{21D1}\u{229F}\u{2286}\
+        \u{03C9}\u{2124}\u{1D544}\u{1D4CE}\u{1D4AA}\u{2AB0}\u{0448}\u{00F3}\
+        \u{226F}\u{2135}\u{2665}\u{1D554}\u{2039}\u{1D565}\u{21D3}\u{231D}\
+        \u{2938}\u{295E}\u{0168}\u{2235}\u{25C2}\u{0417}\u{2660}\u{045A}\
+        \u{29B0}\u{00A8}\u{2552}\u{2AA6}\u{0152}\u{03F5}\u{0403}\u{221D}\
+        \u{212C}\u{1D535}\u{2A8B}\u{1D518}\u{0161}\u{21D4}\u{2205}\u{2276}\
+        \u{223C}\u{00CD}\u{2025}\u{2564}\u{22CA}\u{2AB5}\u{227A}\u{2962}\
+        \u{21CF}\u{00E8}\u{226B}\u{0338}\u{22A1}\u{23B1}\u{2225}\u{210B}\u{00A7}\
+        \u{2926}\u{2920}\u{29A4}\u{2ACB}\u{FE00}\u{226F}\u{21D5}\u{2ACB}\u{2ACB}\u{FE00}\
+        \u{2316}\u{2A17}\u{1D4B2}\u{2A89}\u{2019}\u{2AC4}\u{230A}\u{201E}\
+        \u{295A}\u{005F}\u{03D5}\u{2283}\u{20D2}\u{010C}\u{219E}\u{2222}\u{0160}\
+        \u{295D}\u{2250}\u{2A84}\u{043B}\u{2A66}\u{21BF}\u{2AEF}\u{21A6}\
+        \u{1D50E}\u{0124}\u{29A8}\u{00C3}\u{00FF}\u{2AA5}\u{2AAD}\u{22A5}\
+        \u{00F0}\u{2A95}\u{29BB}\u{03A0}\u{296E}\u{2261}\u{20E5}\u{2968}\u{23B4}\
+        \u{20DC}\u{21FD}\u{2A02}\u{2133}\u{00D2}\u{00CB}\u{21D5}\u{2A86}\
+        \u{29B2}\u{21D3}\u{2281}\u{21AE}\u{228B}\u{FE00}\u{27E9}\u{1D555}\u{2265}\
+        \u{2A8F}\u{2270}\u{2994}\u{00C4}\u{2AB7}\u{1D534}\u{02DC}\u{2A48}\
+        \u{2514}\u{0433}\u{2129}\u{27FA}\u{21AB}\u{2194}\u{29D0}\u{2195}\
+        \u{2193}\u{1D55C}\u{21A7}\u{1D4BF}\u{256B}\u{22ED}\u{00B8}\u{2283}\u{20D2}\
+        \u{00F7}\u{2233}\u{297F}\u{2295}\u{2264}\u{22D0}\u{2288}\u{22D3}\
+        \u{03BB}\u{229A}\u{2195}\u{2A26}\u{039C}\u{22A2}\u{2282}\u{2A55}\
+        \u{00B7}\u{2A74}\u{2283}\u{2A96}\u{2298}\u{255C}\u{02D8}\u{228D}\
+        \u{2925}\u{0139}\u{2928}\u{22A4}\u{22DA}\u{22A4}\u{1D4B9}\u{2AEB}\
+        \u{00B2}\u{299D}\u{2112}\u{2119}\u{21CE}\u{2110}\u{22E3}\u{2248}\
+        \u{27E7}\u{1D4C6}\u{2996}\u{22D4}\u{22B4}\u{22C4}\u{1D55D}\u{29B4}\
+        \u{2772}\u{005B}\u{0429}\u{2ABC}\u{22E7}\u{2262}\u{0425}\u{2031}\
+        \u{1D517}\u{22CB}\u{03F6}\u{223D}\u{02C7}\u{2197}\u{21C6}\u{00BC}\
+        \u{2005}\u{22BA}\u{227F}\u{2A43}\u{2242}\u{0338}\u{22EC}\u{22C2}\u{03DC}\
+        \u{0029}\u{03C3}\u{29C9}\u{1D50D}\u{1D522}\u{040C}\u{292A}\u{2240}\
+        \u{FB02}\u{2208}\u{0413}\u{0412}\u{2A8A}\u{2216}\u{21D2}\u{21BE}\
+        \u{2990}\u{221E}\u{23B0}\u{0427}\u{224E}\u{2AD6}\u{2510}\u{1D561}\
+        \u{21A5}\u{2A7E}\u{0022}\u{00B1}\u{00FA}\u{00F7}\u{0441}\u{1D50A}\
+        \u{2015}\u{22B7}\u{1D4C3}\u{2308}\u{2AE4}\u{2282}\u{20D2}\u{27C8}\u{2959}\
+        \u{2205}\u{007C}\u{2A36}\u{012E}\u{293C}\u{21AC}\u{1D4A6}\u{2021}\
+        \u{25F9}\u{1D55F}\u{224E}\u{0338}\u{00EF}\u{0104}\u{2AB5}\u{0136}\u{2283}\u{20D2}\
+        \u{224B}\u{0338}\u{200A}\u{2A71}\u{2286}\u{2133}\u{2AC6}\u{2AB0}\u{2271}\
+        \u{2238}\u{21E4}\u{0438}\u{042E}\u{2A9D}\u{2232}\u{2131}\u{017B}\
+        \u{21C2}\u{2500}\u{2A42}\u{21CB}\u{2A86}\u{2271}\u{201E}\u{2660}\
+        \u{1D507}\u{2993}\u{2ABA}\u{2265}\u{2911}\u{27F6}\u{00A0}\u{2569}\
+        \u{2196}\u{227E}\u{2245}\u{0133}\u{21C2}\u{2227}\u{2019}\u{230B}\
+        \u{220C}\u{2A6D}\u{0338}\u{2560}\u{22B8}\u{2267}\u{2A5D}\u{2196}\u{2242}\
+        \u{2199}\u{1D519}\u{210D}\u{231C}\u{22E6}\u{0172}\u{2933}\u{0338}\u{2A6A}\
+        \u{2A12}\u{2956}\u{0435}\u{22E9}\u{22D2}\u{003E}\u{22E0}\u{0122}\
+        \u{0112}\u{229B}\u{0159}\u{016B}\u{0398}\u{221A}\u{2A40}\u{03D5}\
+        \u{2229}\u{FE00}\u{03A5}\u{21FF}\u{2758}\u{00D9}\u{0119}\u{2248}\u{20DB}\
+        \u{223F}\u{21A6}\u{29C4}\u{00D8}\u{224F}\u{0338}\u{2229}\u{041E}\u{2902}\
+        \u{0459}\u{22CE}\u{2A7D}\u{003B}\u{2253}\u{0440}\u{2283}\u{2A75}\
+        \u{2A44}\u{299A}\u{25EF}\u{29CF}\u{0338}\u{2138}\u{260E}\u{21DB}\u{2AD1}\
+        \u{2563}\u{2933}\u{2243}\u{2985}\u{1D563}\u{2ACC}\u{2AAD}\u{FE00}\u{00D5}\
+        \u{016E}\u{29AF}\u{2204}\u{2147}\u{2AC0}\u{00C6}\u{22CD}\u{27FA}\
+        \u{21B7}\u{226A}\u{0338}\u{2AB7}\u{03D1}\u{22FB}\u{220B}\u{2ABD}\u{1D53B}\
+        \u{2924}\u{223A}\u{2A89}\u{2A04}\u{041C}\u{02C6}\u{00E5}\u{228B}\
+        \u{1D49C}\u{0148}\u{2AB6}\u{2A85}\u{00E7}\u{1D52F}\u{2205}\u{25A1}\
+        \u{1D4B6}\u{2279}\u{25B9}\u{22C5}\u{22A8}\u{2122}\u{224E}\u{0338}\u{227D}\
+        \u{0453}\u{201D}\u{22AB}\u{2269}\u{2269}\u{201A}\u{003E}\u{20D2}\u{010D}\
+        \u{2322}\u{215B}\u{1D56B}\u{00DE}\u{2192}\u{039D}\u{2299}\u{2246}\
+        \u{227E}\u{21DA}\u{0101}\u{27F7}\u{22B5}\u{29B7}\u{2280}\u{295C}\
+        \u{2297}\u{2310}\u{21E4}\u{27F9}\u{0420}\u{291A}\u{230A}\u{228A}\
+        \u{2969}\u{03A1}\u{017E}\u{2A70}\u{25A1}\u{224A}\u{27F8}\u{29AE}\
+        \u{1D531}\u{22B3}\u{2026}\u{2209}\u{2009}\u{0237}\u{21BC}\u{2A5C}\
+        \u{221D}\u{22D1}\u{2A6F}\u{2AA4}\u{226E}\u{2255}\u{294B}\u{219F}\
+        \u{03D1}\u{21C6}\u{21BB}\u{2AF1}\u{00F8}\u{2967}\u{00B4}\u{2256}\
+        \u{2146}\u{2ABF}\u{010A}\u{226B}\u{20D2}\u{2203}\u{224D}\u{20D2}\u{21D2}\u{2291}\
+        \u{2A0C}\u{2115}\u{03A9}\u{22FC}\u{29A6}\u{2935}\u{22F2}\u{2A25}\
+        \u{2961}\u{00C2}\u{2717}\u{2AED}\u{22C1}\u{22A3}\u{0169}\u{2A8C}\
+        \u{27F6}\u{042C}\u{2305}\u{2AAC}\u{1D55B}\u{2147}\u{22C9}\u{2224}\
+        \u{22B9}\u{2192}\u{2A2D}\u{2228}\u{22DA}";
+
+    /// End offsets of each entity in `NAMES` and `VALUES`; the start offsets are
+    /// the end offsets of the previous entity.
+    pub(super) static ENTRIES: [(u16, u16); 2125] = [
+        (3, 4),        // nfr
+        (7, 8),        // fscr
+        (10, 11),      // sub
+        (16, 13),      // agrave
+        (33, 16),      // LeftDoubleBracket
+        (38, 19),      // utdot
+        (42, 21),      // half
+        (55, 24),      // RightUpVector
+        (61, 27),      // bigvee
+        (67, 30),      // supsub
+        (71, 33),      // eDot
+        (80, 36),      // DownArrow
+        (82, 38),      // Xi
+        (93, 41),      // SmallCircle
+        (102, 43),     // centerdot
+        (111, 46),     // gtreqless
+        (123, 49),     // LeftTriangle
+        (129, 52),     // wedbar
+        (152, 57),     // NotNestedGreaterGreater
+        (158, 60),     // cupcap
+        (164, 62),     // lmidot
+        (167, 65),     // prE
+        (172, 68),     // crarr
+        (177, 71),     // bcong
+        (182, 74),     // boxUL
+        (188, 77),     // frac16
+        (192, 81),     // Gopf
+        (196, 85),     // Jscr
+        (210, 88),     // leftleftarrows
+        (214, 92),     // Topf
+        (219, 95),     // loang
+        (227, 98),     // trpezium
+        (232, 101),    // pluse
+        (234, 104),    // ge
+        (240, 107),    // rsquor
+        (260, 110),    // NestedGreaterGreater
+        (263, 112),    // mcy
+        (269, 114),    // hstrok
+        (283, 117),    // RightTeeVector
+        (289, 119),    // Uacute
+        (292, 122),    // olt
+        (298, 125),    // bprime
+        (302, 129),    // Fopf
+        (307, 132),    // xodot
+        (311, 136),    // Dscr
+        (317, 139),    // ecolon
+        (319, 142),    // pr
+        (323, 148),    // cups
+        (330, 151),    // lesssim
+        (337, 154),    // ccupssm
+        (346, 157),    // varpropto
+        (351, 159),    // iexcl
+        (356, 162),    // minus
+        (360, 166),    // oopf
+        (365, 169),    // rceil
+        (381, 172),    // downharpoonright
+        (385, 175),    // rarr
+        (398, 178),    // bigtriangleup
+        (403, 181),    // plusb
+        (408, 184),    // duhar
+        (412, 187),    // escr
+        (419, 190),    // boxplus
+        (423, 192),    // djcy
+        (426, 197),    // nlE
+        (430, 200),    // gtcc
+        (432, 202),    // xi
+        (441, 205),    // pitchfork
+        (453, 208),    // LeftTeeArrow
+        (460, 211),    // pluscir
+        (474, 214),    // HorizontalLine
+        (481, 217),    // notnivc
+        (486, 220),    // lrtri
+        (502, 223),    // LeftAngleBracket
+        (510, 226),    // emptyset
+        (524, 229),    // rightarrowtail
+        (545, 232),    // NotPrecedesSlantEqual
+        (550, 235),    // neArr
+        (556, 238),    // Barwed
+        (561, 241),    // caret
+        (566, 243),    // napos
+        (571, 246),    // bumpe
+        (577, 249),    // nvDash
+        (585, 252),    // circledS
+        (589, 255),    // Ropf
+        (603, 258),    // ShortDownArrow
+        (623, 261),    // ReverseUpEquilibrium
+        (630, 264),    // simplus
+        (634, 266),    // gdot
+        (637, 270),    // ufr
+        (644, 272),    // omicron
+        (646, 275),    // Or
+        (656, 278),    // TildeEqual
+        (658, 281),    // lg
+        (664, 283),    // zacute
+        (667, 288),    // ngE
+        (672, 290),    // acute
+        (676, 292),    // edot
+        (683, 295),    // cirscir
+        (694, 298),    // SquareUnion
+        (699, 301),    // blk34
+        (703, 302),    // rcub
+        (707, 304),    // tscy
+        (713, 307),    // rharul
+        (718, 310),    // olarr
+        (724, 313),    // emsp13
+        (728, 316),    // boxV
+        (731, 320),    // Bfr
+        (745, 323),    // curvearrowleft
+        (751, 325),    // Gbreve
+        (756, 328),    // notni
+        (760, 331),    // spar
+        (767, 336),    // nsucceq
+        (781, 339),    // NotGreaterLess
+        (784, 341),    // jcy
+        (790, 344),    // isinsv
+        (802, 347),    // SquareSubset
+        (819, 350),    // NotTildeFullEqual
+        (825, 353),    // ssmile
+        (830, 356),    // ngsim
+        (846, 361),    // NotSucceedsTilde
+        (850, 364),    // malt
+        (854, 367),    // dtri
+        (858, 369),    // ring
+        (860, 372),    // mp
+        (864, 374),    // Ouml
+        (869, 377),    // amalg
+        (873, 378),    // lsqb
+        (877, 382),    // Iopf
+        (884, 385),    // uparrow
+        (889, 387),    // uogon
+        (896, 390),    // intprod
+        (906, 393),    // Rightarrow
+        (922, 396),    // rightrightarrows
+        (936, 399),    // RightVectorBar
+        (948, 404),    // NotHumpEqual
+        (954, 407),    // rAtail
+        (959, 410),    // order
+        (963, 412),    // uuml
+        (969, 414),    // Eacute
+        (974, 417),    // nhpar
+        (979, 422),    // nedot
+        (987, 425),    // imagline
+        (993, 428),    // tridot
+        (999, 430),    // ntilde
+        (1005, 433),   // ffilig
+        (1013, 436),   // drbkarow
+        (1017, 441),   // nles
+        (1023, 444),   // bigcup
+        (1027, 447),   // csup
+        (1033, 450),   // nwarhk
+        (1040, 453),   // suphsol
+        (1046, 454),   // rbrack
+        (1057, 457),   // Updownarrow
+        (1062, 460),   // TRADE
+        (1066, 463),   // Popf
+        (1070, 466),   // omid
+        (1075, 469),   // dtrif
+        (1078, 472),   // Rfr
+        (1087, 475),   // Coproduct
+        (1093, 478),   // Verbar
+        (1099, 481),   // timesb
+        (1102, 483),   // Ncy
+        (1108, 486),   // wedgeq
+        (1114, 489),   // ffllig
+        (1127, 492),   // LessFullEqual
+        (1131, 498),   // nvge
+        (1136, 501),   // nless
+        (1140, 504),   // bump
+        (1147, 507),   // Cayleys
+        (1151, 510),   // Rscr
+        (1155, 513),   // Qopf
+        (1161, 515),   // Hstrok
+        (1164, 516),   // AMP
+        (1177, 519),   // RightArrowBar
+        (1185, 522),   // angmsdab
+        (1193, 525),   // cwconint
+        (1202, 528),   // rationals
+        (1218, 531),   // vartriangleright
+        (1224, 534),   // easter
+        (1228, 536),   // beta
+        (1232, 540),   // pscr
+        (1244, 543),   // DownArrowBar
+        (1253, 546),   // nsubseteq
+        (1264, 549),   // curlyeqprec
+        (1267, 551),   // acy
+        (1278, 554),   // succcurlyeq
+        (1282, 557),   // bbrk
+        (1293, 560),   // circleddash
+        (1299, 563),   // hybull
+        (1302, 565),   // Jcy
+        (1308, 568),   // female
+        (1314, 573),   // notinE
+        (1320, 575),   // racute
+        (1323, 579),   // Qfr
+        (1329, 582),   // eparsl
+        (1334, 585),   // fltns
+        (1340, 588),   // permil
+        (1344, 591),   // cong
+        (1349, 592),   // colon
+        (1354, 594),   // Gcirc
+        (1361, 597),   // npolint
+        (1367, 600),   // dlcrop
+        (1381, 603),   // InvisibleComma
+        (1387, 606),   // vangrt
+        (1391, 609),   // rect
+        (1396, 611),   // jcirc
+        (1399, 615),   // afr
+        (1407, 618),   // awconint
+        (1411, 620),   // ZHcy
+        (1415, 623),   // ldsh
+        (1421, 625),   // kgreen
+        (1427, 627),   // Oacute
+        (1431, 629),   // ordf
+        (1436, 632),   // nspar
+        (1440, 636),   // eopf
+        (1444, 638),   // Iuml
+        (1447, 640),   // Ycy
+        (1453, 643),   // puncsp
+        (1456, 646),   // sum
+        (1462, 649),   // iinfin
+        (1466, 653),   // Tscr
+        (1470, 656),   // nsim
+        (1483, 659),   // NotTildeTilde
+        (1488, 662),   // asymp
+        (1491, 665),   // lgE
+        (1500, 668),   // NotExists
+        (1504, 670),   // ogon
+        (1510, 673),   // frac38
+        (1518, 676),   // bbrktbrk
+        (1524, 679),   // lowast
+        (1528, 681),   // IEcy
+        (1534, 683),   // Yacute
+        (1537, 684),   // sol
+        (1551, 687),   // NotVerticalBar
+        (1557, 690),   // zeetrf
+        (1561, 692),   // IOcy
+        (1566, 695),   // nsmid
+        (1569, 697),   // chi
+        (1578, 700),   // Backslash
+        (1584, 703),   // marker
+        (1591, 709),   // nvltrie
+        (1597, 712),   // hamilt
+        (1602, 715),   // swarr
+        (1609, 718),   // swarrow
+        (1616, 721),   // triplus
+        (1621, 723),   // imped
+        (1626, 726),   // boxdL
+        (1631, 729),   // boxDr
+        (1636, 731),   // Scirc
+        (1642, 733),   // Racute
+        (1645, 736),   // gel
+        (1649, 738),   // NJcy
+        (1653, 741),   // odiv
+        (1659, 744),   // rarrfs
+        (1667, 747),   // Uarrocir
+        (1671, 750),   // zwnj
+        (1676, 753),   // erDot
+        (1686, 756),   // succapprox
+        (1693, 759),   // searrow
+        (1699, 761),   // jsercy
+        (1717, 764),   // SquareIntersection
+        (1722, 767),   // angle
+        (1728, 769),   // eacute
+        (1745, 774),   // NotNestedLessLess
+        (1749, 776),   // ordm
+        (1758, 779),   // Leftarrow
+        (1766, 782),   // capbrcup
+        (1771, 785),   // filig
+        (1779, 788),   // angmsdae
+        (1789, 791),   // UpTeeArrow
+        (1797, 794),   // cirfnint
+        (1803, 797),   // gtlPar
+        (1806, 800),   // Rsh
+        (1811, 802),   // uring
+        (1815, 805),   // tbrk
+        (1828, 811),   // varsupsetneqq
+        (1843, 814),   // curvearrowright
+        (1847, 818),   // zscr
+        (1853, 820),   // Dcaron
+        (1857, 824),   // Oopf
+        (1863, 826),   // igrave
+        (1869, 829),   // angmsd
+        (1874, 832),   // rbrke
+        (1879, 835),   // notin
+        (1885, 838),   // planck
+        (1896, 841),   // CircleMinus
+        (1906, 844),   // Bernoullis
+        (1912, 846),   // Igrave
+        (1916, 848),   // SHcy
+        (1920, 852),   // sopf
+        (1924, 855),   // succ
+        (1930, 858),   // gesdot
+        (1941, 861),   // NotSucceeds
+        (1944, 863),   // Dcy
+        (1948, 866),   // oint
+        (1952, 870),   // bopf
+        (1957, 873),   // frasl
+        (1978, 876),   // CloseCurlyDoubleQuote
+        (1983, 879),   // xrarr
+        (2000, 882),   // LeftTriangleEqual
+        (2004, 885),   // nisd
+        (2008, 890),   // npre
+        (2012, 893),   // Barv
+        (2018, 895),   // lcaron
+        (2022, 898),   // male
+        (2034, 901),   // RoundImplies
+        (2050, 904),   // RightUpVectorBar
+        (2056, 907),   // subsim
+        (2059, 910),   // Sum
+        (2066, 916),   // nsubset
+        (2072, 918),   // lacute
+        (2081, 923),   // ngeqslant
+        (2089, 926),   // succneqq
+        (2093, 930),   // uscr
+        (2098, 933),   // ratio
+        (2103, 936),   // ltdot
+        (2109, 939),   // nVDash
+        (2116, 944),   // npreceq
+        (2121, 946),   // angst
+        (2127, 949),   // Vvdash
+        (2130, 953),   // mfr
+        (2140, 956),   // nLeftarrow
+        (2144, 960),   // cscr
+        (2154, 963),   // ImaginaryI
+        (2160, 966),   // wreath
+        (2165, 969),   // sqsub
+        (2181, 972),   // LeftUpDownVector
+        (2187, 974),   // frac34
+        (2189, 977),   // gl
+        (2194, 979),   // Hacek
+        (2198, 980),   // plus
+        (2203, 982),   // jukcy
+        (2206, 986),   // dfr
+        (2212, 988),   // atilde
+        (2225, 991),   // smallsetminus
+        (2232, 994),   // bigcirc
+        (2237, 997),   // nearr
+        (2241, 1000),  // Copf
+        (2246, 1003),  // range
+        (2251, 1006),  // parsl
+        (2255, 1009),  // nsub
+        (2260, 1012),  // diams
+        (2262, 1013),  // lt
+        (2265, 1015),  // ENG
+        (2270, 1017),  // Ucirc
+        (2276, 1020),  // ominus
+        (2280, 1024),  // vopf
+        (2285, 1027),  // boxHu
+        (2291, 1030),  // larrhk
+        (2305, 1033),  // UpperLeftArrow
+        (2314, 1036),  // complexes
+        (2329, 1039),  // ContourIntegral
+        (2340, 1042),  // Rrightarrow
+        (2356, 1045),  // rightharpoondown
+        (2359, 1048),  // lrm
+        (2370, 1053),  // NotLessLess
+        (2375, 1056),  // isinE
+        (2379, 1059),  // hbar
+        (2400, 1062),  // NotSucceedsSlantEqual
+        (2406, 1065),  // frac58
+        (2410, 1068),  // simg
+        (2415, 1071),  // vBarv
+        (2420, 1073),  // thorn
+        (2423, 1076),  // iff
+        (2429, 1079),  // Supset
+        (2433, 1083),  // lscr
+        (2438, 1086),  // boxDR
+        (2444, 1088),  // varrho
+        (2450, 1091),  // frac15
+        (2456, 1093),  // Lcaron
+        (2459, 1097),  // Xfr
+        (2463, 1098),  // lcub
+        (2467, 1101),  // Escr
+        (2471, 1104),  // apid
+        (2477, 1107),  // elsdot
+        (2481, 1110),  // rdsh
+        (2496, 1113),  // circlearrowleft
+        (2501, 1116),  // mdash
+        (2504, 1118),  // REG
+        (2508, 1120),  // kjcy
+        (2526, 1123),  // blacktriangleright
+        (2530, 1126),  // gscr
+        (2536, 1128),  // abreve
+        (2539, 1132),  // yfr
+        (2544, 1134),  // imacr
+        (2549, 1139),  // nesim
+        (2552, 1145),  // nLt
+        (2557, 1148),  // duarr
+        (2562, 1151),  // rhard
+        (2567, 1154),  // orarr
+        (2585, 1157),  // DownRightVectorBar
+        (2597, 1158),  // VerticalLine
+        (2600, 1160),  // Bcy
+        (2605, 1163),  // llarr
+        (2609, 1168),  // nsce
+        (2612, 1172),  // Mfr
+        (2619, 1175),  // luruhar
+        (2623, 1178),  // boxH
+        (2628, 1181),  // veeeq
+        (2635, 1184),  // bigstar
+        (2640, 1186),  // ecirc
+        (2654, 1189),  // GreaterGreater
+        (2661, 1192),  // larrbfs
+        (2667, 1195),  // latail
+        (2671, 1197),  // sup1
+        (2678, 1200),  // equivDD
+        (2680, 1203),  // gg
+        (2686, 1205),  // middot
+        (2692, 1207),  // ecaron
+        (2707, 1210),  // downharpoonleft
+        (2714, 1213),  // tritime
+        (2716, 1216),  // lE
+        (2720, 1219),  // Larr
+        (2724, 1222),  // gneq
+        (2729, 1225),  // sqcup
+        (2734, 1228),  // nsime
+        (2738, 1230),  // Iota
+        (2748, 1233),  // curlywedge
+        (2764, 1236),  // UpArrowDownArrow
+        (2769, 1239),  // gsiml
+        (2774, 1241),  // wcirc
+        (2778, 1244),  // rHar
+        (2781, 1247),  // Cfr
+        (2785, 1251),  // Gscr
+        (2798, 1254),  // Longleftarrow
+        (2803, 1257),  // angrt
+        (2808, 1259),  // ucirc
+        (2814, 1261),  // sigmav
+        (2824, 1264),  // lmoustache
+        (2828, 1270),  // nvle
+        (2833, 1273),  // gtdot
+        (2838, 1276),  // cuesc
+        (2842, 1279),  // subE
+        (2848, 1282),  // frac45
+        (2852, 1283),  // QUOT
+        (2858, 1286),  // drcorn
+        (2862, 1289),  // oror
+        (2881, 1294),  // NotRightTriangleBar
+        (2885, 1297),  // Lang
+        (2891, 1300),  // bkarow
+        (2894, 1301),  // ast
+        (2902, 1304),  // fpartint
+        (2907, 1307),  // swArr
+        (2911, 1311),  // Pscr
+        (2917, 1313),  // Tcaron
+        (2923, 1315),  // ubreve
+        (2929, 1318),  // hellip
+        (2935, 1320),  // Lmidot
+        (2941, 1323),  // timesd
+        (2946, 1326),  // boxvH
+        (2952, 1328),  // rcedil
+        (2958, 1330),  // lcedil
+        (2964, 1333),  // nwnear
+        (2969, 1336),  // awint
+        (2974, 1341),  // nsubE
+        (2980, 1343),  // frac12
+        (2986, 1346),  // xoplus
+        (2991, 1349),  // boxUr
+        (2995, 1352),  // real
+        (3010, 1355),  // leftharpoondown
+        (3015, 1358),  // twixt
+        (3020, 1360),  // Amacr
+        (3023, 1362),  // ncy
+        (3031, 1365),  // infintie
+        (3035, 1369),  // Zscr
+        (3038, 1373),  // Ffr
+        (3042, 1377),  // sscr
+        (3049, 1380),  // harrcir
+        (3053, 1383),  // squf
+        (3058, 1385),  // pound
+        (3075, 1388),  // leftrightharpoons
+        (3079, 1391),  // rdca
+        (3083, 1394),  // star
+        (3087, 1397),  // ensp
+        (3108, 1400),  // NegativeVeryThinSpace
+        (3117, 1403),  // HumpEqual
+        (3130, 1406),  // blacktriangle
+        (3136, 1409),  // frac56
+        (3142, 1412),  // Otimes
+        (3146, 1415),  // uHar
+        (3157, 1418),  // MediumSpace
+        (3162, 1421),  // isinv
+        (3169, 1423),  // epsilon
+        (3175, 1426),  // Dagger
+        (3178, 1429),  // ggg
+        (3184, 1431),  // nacute
+        (3198, 1434),  // SquareSuperset
+        (3205, 1437),  // maltese
+        (3217, 1440),  // UnderBracket
+        (3223, 1443),  // frac78
+        (3227, 1447),  // kscr
+        (3231, 1450),  // ntlg
+        (3238, 1453),  // nvinfin
+        (3240, 1456),  // ni
+        (3245, 1459),  // clubs
+        (3254, 1462),  // subseteqq
+        (3269, 1465),  // NotGreaterTilde
+        (3274, 1467),  // Imacr
+        (3279, 1469),  // Ccirc
+        (3282, 1471),  // Chi
+        (3288, 1474),  // supdot
+        (3292, 1479),  // race
+        (3311, 1482),  // DownLeftRightVector
+        (3315, 1485),  // vBar
+        (3323, 1488),  // leqslant
+        (3327, 1490),  // dscy
+        (3329, 1493),  // Pr
+        (3347, 1496),  // SucceedsSlantEqual
+        (3351, 1499),  // leqq
+        (3355, 1502),  // Darr
+        (3360, 1505),  // wedge
+        (3371, 1508),  // diamondsuit
+        (3373, 1510),  // pm
+        (3377, 1513),  // utri
+        (3382, 1516),  // natur
+        (3395, 1519),  // upharpoonleft
+        (3401, 1521),  // HARDcy
+        (3406, 1524),  // oplus
+        (3412, 1527),  // subsub
+        (3418, 1530),  // models
+        (3423, 1533),  // boxVH
+        (3431, 1536),  // setminus
+        (3437, 1538),  // Udblac
+        (3442, 1540),  // imath
+        (3448, 1543),  // tprime
+        (3453, 1545),  // gcirc
+        (3458, 1548),  // rtrif
+        (3476, 1551),  // PrecedesSlantEqual
+        (3481, 1554),  // Prime
+        (3489, 1557),  // elinters
+        (3497, 1560),  // supseteq
+        (3502, 1563),  // blank
+        (3506, 1567),  // Aopf
+        (3509, 1571),  // kfr
+        (3514, 1574),  // lharu
+        (3529, 1577),  // OverParenthesis
+        (3539, 1580),  // UpArrowBar
+        (3543, 1585),  // napE
+        (3546, 1589),  // qfr
+        (3550, 1593),  // bscr
+        (3563, 1596),  // leftarrowtail
+        (3576, 1599),  // divideontimes
+        (3579, 1604),  // acE
+        (3594, 1607),  // rightleftarrows
+        (3607, 1610),  // LeftVectorBar
+        (3612, 1613),  // utrif
+        (3620, 1616),  // scpolint
+        (3623, 1618),  // not
+        (3627, 1621),  // ltri
+        (3640, 1624),  // hookleftarrow
+        (3646, 1630),  // nparsl
+        (3651, 1633),  // thkap
+        (3659, 1636),  // precnsim
+        (3674, 1639),  // vartriangleleft
+        (3678, 1641),  // yicy
+        (3683, 1644),  // boxul
+        (3698, 1647),  // nLeftrightarrow
+        (3704, 1649),  // Ntilde
+        (3712, 1652),  // triangle
+        (3720, 1655),  // LessLess
+        (3724, 1657),  // dzcy
+        (3729, 1660),  // lescc
+        (3745, 1663),  // NotRightTriangle
+        (3748, 1666),  // lat
+        (3752, 1670),  // qopf
+        (3755, 1673),  // pre
+        (3759, 1677),  // Wopf
+        (3764, 1680),  // rangd
+        (3768, 1682),  // Gdot
+        (3773, 1685),  // nrtri
+        (3776, 1689),  // bne
+        (3791, 1692),  // NotLeftTriangle
+        (3807, 1694),  // NonBreakingSpace
+        (3812, 1695),  // grave
+        (3819, 1698),  // Uparrow
+        (3823, 1700),  // para
+        (3827, 1703),  // bull
+        (3831, 1706),  // larr
+        (3844, 1709),  // measuredangle
+        (3850, 1712),  // parsim
+        (3856, 1714),  // Jsercy
+        (3861, 1717),  // boxVr
+        (3865, 1721),  // Vopf
+        (3870, 1723),  // iogon
+        (3874, 1727),  // xscr
+        (3878, 1730),  // trie
+        (3881, 1734),  // hfr
+        (3884, 1737),  // Hfr
+        (3888, 1741),  // wopf
+        (3894, 1743),  // gacute
+        (3898, 1746),  // supe
+        (3903, 1748),  // hcirc
+        (3911, 1751),  // angmsdac
+        (3913, 1754),  // Im
+        (3925, 1757),  // NotLessTilde
+        (3930, 1760),  // uhblk
+        (3935, 1763),  // UpTee
+        (3939, 1766),  // bsim
+        (3942, 1768),  // rho
+        (3949, 1771),  // quatint
+        (3954, 1774),  // dtdot
+        (3959, 1777),  // seArr
+        (3964, 1780),  // efDot
+        (3982, 1783),  // RightTriangleEqual
+        (3993, 1786),  // RightVector
+        (3997, 1792),  // nang
+        (4004, 1795),  // NotLess
+        (4009, 1797),  // micro
+        (4013, 1800),  // Fscr
+        (4020, 1803),  // subedot
+        (4025, 1805),  // laquo
+        (4029, 1808),  // euro
+        (4036, 1811),  // lbrkslu
+        (4039, 1814),  // leq
+        (4044, 1817),  // check
+        (4053, 1820),  // LessTilde
+        (4058, 1822),  // dblac
+        (4061, 1825),  // lne
+        (4067, 1827),  // iquest
+        (4070, 1829),  // tau
+        (4073, 1831),  // fcy
+        (4077, 1835),  // mopf
+        (4087, 1838),  // mapstoleft
+        (4093, 1841),  // numero
+        (4096, 1845),  // ofr
+        (4101, 1848),  // roang
+        (4106, 1851),  // udhar
+        (4122, 1854),  // DownArrowUpArrow
+        (4124, 1857),  // el
+        (4130, 1858),  // commat
+        (4132, 1861),  // it
+        (4137, 1863),  // Umacr
+        (4148, 1865),  // straightphi
+        (4152, 1868),  // lArr
+        (4158, 1871),  // larrtl
+        (4179, 1874),  // FilledVerySmallSquare
+        (4183, 1877),  // Sqrt
+        (4189, 1879),  // Agrave
+        (4195, 1881),  // Tcedil
+        (4198, 1882),  // num
+        (4214, 1885),  // GreaterFullEqual
+        (4220, 1887),  // Dstrok
+        (4224, 1891),  // Qscr
+        (4241, 1894),  // twoheadrightarrow
+        (4258, 1897),  // RightUpDownVector
+        (4264, 1900),  // plankv
+        (4266, 1903),  // Ll
+        (4280, 1906),  // NestedLessLess
+        (4284, 1909),  // ldca
+        (4288, 1912),  // beth
+        (4300, 1915),  // GreaterTilde
+        (4306, 1918),  // eqcirc
+        (4312, 1920),  // gammad
+        (4329, 1923),  // DoubleVerticalBar
+        (4334, 1926),  // boxhu
+        (4339, 1928),  // kappa
+        (4348, 1931),  // leftarrow
+        (4358, 1934),  // lesseqqgtr
+        (4374, 1939),  // NotPrecedesEqual
+        (4386, 1942),  // triangledown
+        (4391, 1943),  // quest
+        (4409, 1946),  // LongLeftRightArrow
+        (4411, 1949),  // ii
+        (4416, 1951),  // ocirc
+        (4421, 1954),  // uharr
+        (4431, 1957),  // Proportion
+        (4439, 1960),  // Integral
+        (4444, 1963),  // roarr
+        (4449, 1966),  // uplus
+        (4454, 1969),  // rbarr
+        (4460, 1972),  // nsccue
+        (4465, 1974),  // Kappa
+        (4470, 1977),  // csupe
+        (4473, 1979),  // Tau
+        (4477, 1983),  // wscr
+        (4482, 1986),  // lneqq
+        (4486, 1990),  // rscr
+        (4491, 1993),  // ultri
+        (4495, 1996),  // Rang
+        (4498, 1999),  // rsh
+        (4500, 2002),  // ac
+        (4515, 2005),  // DownRightVector
+        (4523, 2008),  // lesdotor
+        (4530, 2011),  // topfork
+        (4534, 2014),  // dArr
+        (4548, 2017),  // doublebarwedge
+        (4553, 2020),  // vDash
+        (4557, 2024),  // iscr
+        (4564, 2027),  // notnivb
+        (4569, 2029),  // tshcy
+        (4574, 2032),  // iprod
+        (4576, 2035),  // Lt
+        (4584, 2038),  // realpart
+        (4589, 2041),  // boxvl
+        (4594, 2043),  // iukcy
+        (4597, 2046),  // gne
+        (4602, 2048),  // fjlig
+        (4606, 2050),  // auml
+        (4611, 2052),  // scirc
+        (4614, 2056),  // vfr
+        (4618, 2058),  // khcy
+        (4623, 2061),  // boxuR
+        (4634, 2064),  // Equilibrium
+        (4638, 2067),  // tosa
+        (4644, 2070),  // Rarrtl
+        (4650, 2073),  // otimes
+        (4656, 2075),  // gbreve
+        (4659, 2078),  // Not
+        (4664, 2080),  // theta
+        (4673, 2083),  // supseteqq
+        (4676, 2086),  // gEl
+        (4680, 2089),  // harr
+        (4686, 2092),  // seswar
+        (4692, 2094),  // kappav
+        (4696, 2098),  // Bopf
+        (4706, 2101),  // sqsupseteq
+        (4712, 2104),  // midcir
+        (4715, 2107),  // And
+        (4721, 2110),  // colone
+        (4727, 2113),  // rthree
+        (4731, 2115),  // iocy
+        (4733, 2118),  // ll
+        (4738, 2121),  // ccups
+        (4753, 2124),  // ntrianglelefteq
+        (4764, 2127),  // RuleDelayed
+        (4766, 2130),  // in
+        (4777, 2133),  // GreaterLess
+        (4781, 2135),  // YIcy
+        (4788, 2138),  // notinvb
+        (4796, 2141),  // bigoplus
+        (4801, 2143),  // raquo
+        (4806, 2146),  // trisb
+        (4812, 2149),  // lAtail
+        (4816, 2152),  // hArr
+        (4827, 2155),  // nRightarrow
+        (4834, 2158),  // lozenge
+        (4847, 2161),  // RightTriangle
+        (4854, 2164),  // nsqsupe
+        (4862, 2166),  // varsigma
+        (4868, 2169),  // rangle
+        (4873, 2172),  // equiv
+        (4879, 2174),  // dcaron
+        (4884, 2177),  // mumap
+        (4888, 2179),  // Zeta
+        (4894, 2181),  // yacute
+        (4898, 2183),  // DZcy
+        (4904, 2185),  // iacute
+        (4910, 2187),  // Ncaron
+        (4918, 2190),  // pointint
+        (4922, 2193),  // oscr
+        (4930, 2196),  // shortmid
+        (4937, 2199),  // ltquest
+        (4943, 2202),  // ulcrop
+        (4948, 2205),  // xlArr
+        (4952, 2209),  // Uscr
+        (4966, 2212),  // looparrowright
+        (4978, 2215),  // Intersection
+        (4983, 2221),  // smtes
+        (4988, 2224),  // ohbar
+        (4990, 2227),  // ic
+        (4998, 2230),  // integers
+        (5002, 2233),  // Nopf
+        (5009, 2234),  // NewLine
+        (5013, 2237),  // Vert
+        (5021, 2240),  // lurdshar
+        (5026, 2243),  // Vdash
+        (5032, 2245),  // tstrok
+        (5036, 2247),  // yucy
+        (5049, 2250),  // ntriangleleft
+        (5055, 2253),  // prnsim
+        (5060, 2255),  // epsiv
+        (5067, 2258),  // lbrksld
+        (5072, 2261),  // sqcap
+        (5082, 2264),  // RightArrow
+        (5090, 2266),  // circledR
+        (5097, 2269),  // zigrarr
+        (5101, 2272),  // Lscr
+        (5109, 2275),  // hkswarow
+        (5117, 2278),  // PartialD
+        (5120, 2282),  // Sfr
+        (5130, 2285),  // Lleftarrow
+        (5135, 2288),  // nabla
+        (5141, 2291),  // roplus
+        (5154, 2294),  // longleftarrow
+        (5159, 2296),  // aelig
+        (5165, 2298),  // Lstrok
+        (5170, 2301),  // boxdr
+        (5174, 2304),  // sung
+        (5181, 2307),  // ddotseq
+        (5184, 2309),  // Dot
+        (5187, 2313),  // Pfr
+        (5192, 2316),  // dharl
+        (5197, 2319),  // rlarr
+        (5202, 2322),  // sdotb
+        (5207, 2325),  // subne
+        (5215, 2330),  // notindot
+        (5227, 2333),  // exponentiale
+        (5235, 2336),  // RightTee
+        (5241, 2339),  // preceq
+        (5247, 2341),  // otilde
+        (5251, 2344),  // dash
+        (5265, 2347),  // hookrightarrow
+        (5270, 2350),  // forkv
+        (5274, 2352),  // ouml
+        (5278, 2355),  // scap
+        (5284, 2357),  // Lambda
+        (5292, 2360),  // boxtimes
+        (5305, 2363),  // NotTildeEqual
+        (5316, 2366),  // nrightarrow
+        (5319, 2368),  // Psi
+        (5335, 2371),  // EmptySmallSquare
+        (5340, 2376),  // npart
+        (5347, 2378),  // digamma
+        (5354, 2381),  // because
+        (5360, 2384),  // bernou
+        (5364, 2386),  // yacy
+        (5367, 2388),  // Icy
+        (5380, 2391),  // PrecedesEqual
+        (5396, 2394),  // LessEqualGreater
+        (5405, 2397),  // UnionPlus
+        (5414, 2399),  // PlusMinus
+        (5419, 2402),  // eDDot
+        (5425, 2405),  // bigcap
+        (5430, 2408),  // xlarr
+        (5434, 2411),  // odot
+        (5440, 2414),  // qprime
+        (5446, 2417),  // supsim
+        (5454, 2420),  // lrcorner
+        (5470, 2423),  // GreaterEqualLess
+        (5477, 2426),  // rbrksld
+        (5481, 2428),  // epsi
+        (5490, 2431),  // TripleDot
+        (5507, 2434),  // NegativeThinSpace
+        (5515, 2437),  // doteqdot
+        (5521, 2439),  // Nacute
+        (5530, 2442),  // triangleq
+        (5535, 2445),  // bsemi
+        (5542, 2448),  // demptyv
+        (5548, 2451),  // rarrtl
+        (5551, 2455),  // Nfr
+        (5561, 2458),  // NotElement
+        (5566, 2461),  // searr
+        (5572, 2464),  // gtrarr
+        (5581, 2470),  // gvertneqq
+        (5587, 2472),  // Ecaron
+        (5590, 2475),  // scE
+        (5594, 2478),  // smid
+        (5599, 2481),  // lbarr
+        (5616, 2484),  // blacktriangledown
+        (5621, 2486),  // szlig
+        (5625, 2489),  // esim
+        (5632, 2491),  // upsilon
+        (5637, 2494),  // prime
+        (5643, 2496),  // softcy
+        (5651, 2499),  // curlyvee
+        (5657, 2502),  // barwed
+        (5672, 2505),  // nleftrightarrow
+        (5676, 2509),  // uopf
+        (5679, 2511),  // eta
+        (5685, 2514),  // origof
+        (5691, 2516),  // Sacute
+        (5705, 2519),  // downdownarrows
+        (5714, 2525),  // lvertneqq
+        (5720, 2527),  // Lcedil
+        (5723, 2531),  // jfr
+        (5727, 2532),  // lpar
+        (5735, 2535),  // thicksim
+        (5741, 2538),  // mcomma
+        (5759, 2541),  // NegativeThickSpace
+        (5765, 2544),  // Conint
+        (5772, 2547),  // minusdu
+        (5774, 2550),  // Gg
+        (5780, 2555),  // nrarrw
+        (5786, 2558),  // cularr
+        (5789, 2559),  // amp
+        (5802, 2562),  // SucceedsTilde
+        (5808, 2565),  // equest
+        (5811, 2568),  // mho
+        (5815, 2570),  // macr
+        (5821, 2573),  // rsaquo
+        (5827, 2576),  // drcrop
+        (5831, 2579),  // epar
+        (5836, 2581),  // tilde
+        (5846, 2584),  // NotGreater
+        (5865, 2587),  // NegativeMediumSpace
+        (5869, 2593),  // gvnE
+        (5873, 2595),  // zdot
+        (5879, 2598),  // bullet
+        (5883, 2601),  // dHar
+        (5888, 2603),  // Ycirc
+        (5899, 2606),  // precnapprox
+        (5905, 2608),  // tcaron
+        (5911, 2611),  // hearts
+        (5915, 2612),  // rsqb
+        (5919, 2616),  // nvlt
+        (5937, 2619),  // RightDownVectorBar
+        (5956, 2622),  // SquareSupersetEqual
+        (5960, 2624),  // iota
+        (5966, 2627),  // intcal
+        (5969, 2631),  // pfr
+        (5975, 2633),  // shchcy
+        (5981, 2636),  // vellip
+        (5992, 2639),  // LessGreater
+        (5997, 2644),  // nsupE
+        (6012, 2647),  // DoubleLeftArrow
+        (6016, 2648),  // apos
+        (6021, 2651),  // ncong
+        (6028, 2654),  // orslope
+        (6039, 2657),  // VerticalBar
+        (6053, 2660),  // ZeroWidthSpace
+        (6060, 2663),  // rotimes
+        (6066, 2666),  // hslash
+        (6072, 2669),  // homtht
+        (6077, 2672),  // dashv
+        (6081, 2676),  // Vscr
+        (6087, 2678),  // cacute
+        (6092, 2681),  // ndash
+        (6098, 2684),  // phmmat
+        (6102, 2686),  // TScy
+        (6108, 2689),  // larrpl
+        (6114, 2692),  // gtrdot
+        (6119, 2694),  // alpha
+        (6127, 2697),  // sqsupset
+        (6132, 2699),  // acirc
+        (6137, 2702),  // odash
+        (6140, 2705),  // nle
+        (6149, 2708),  // nshortmid
+        (6158, 2711),  // backprime
+        (6173, 2714),  // UpperRightArrow
+        (6184, 2717),  // SubsetEqual
+        (6189, 2719),  // Jcirc
+        (6192, 2721),  // uml
+        (6202, 2724),  // complement
+        (6211, 2727),  // Congruent
+        (6215, 2729),  // upsi
+        (6235, 2732),  // DoubleLeftRightArrow
+        (6247, 2735),  // LeftUpVector
+        (6252, 2738),  // udarr
+        (6265, 2741),  // DoubleUpArrow
+        (6268, 2743),  // shy
+        (6273, 2746),  // Cross
+        (6280, 2748),  // Cedilla
+        (6285, 2751),  // bumpE
+        (6290, 2754),  // exist
+        (6294, 2757),  // ocir
+        (6300, 2760),  // rarrhk
+        (6305, 2762),  // times
+        (6309, 2765),  // Hscr
+        (6312, 2768),  // ogt
+        (6322, 2771),  // subsetneqq
+        (6328, 2773),  // Tstrok
+        (6335, 2776),  // precsim
+        (6340, 2778),  // ccirc
+        (6344, 2780),  // zeta
+        (6349, 2783),  // bsime
+        (6353, 2787),  // aopf
+        (6358, 2790),  // vltri
+        (6366, 2793),  // profsurf
+        (6369, 2798),  // nGg
+        (6371, 2801),  // DD
+        (6376, 2803),  // ycirc
+        (6382, 2806),  // capcap
+        (6386, 2810),  // vscr
+        (6406, 2813),  // EmptyVerySmallSquare
+        (6410, 2817),  // Nscr
+        (6416, 2823),  // vsubne
+        (6423, 2826),  // gtquest
+        (6427, 2828),  // zhcy
+        (6431, 2832),  // xopf
+        (6439, 2835),  // clubsuit
+        (6444, 2837),  // Delta
+        (6451, 2840),  // NoBreak
+        (6458, 2843),  // diamond
+        (6464, 2846),  // llhard
+        (6467, 2849),  // lnE
+        (6475, 2852),  // geqslant
+        (6489, 2855),  // nshortparallel
+        (6497, 2858),  // sqsubset
+        (6509, 2861),  // NotLessEqual
+        (6531, 2863),  // DiacriticalDoubleAcute
+        (6534, 2866),  // ang
+        (6537, 2867),  // Tab
+        (6540, 2869),  // Kcy
+        (6547, 2872),  // napprox
+        (6551, 2875),  // emsp
+        (6557, 2877),  // Scedil
+        (6565, 2880),  // NotEqual
+        (6569, 2884),  // Yopf
+        (6576, 2887),  // lotimes
+        (6578, 2890),  // Gt
+        (6585, 2893),  // questeq
+        (6590, 2896),  // Colon
+        (6596, 2899),  // Bumpeq
+        (6602, 2902),  // odsold
+        (6622, 2905),  // NotLeftTriangleEqual
+        (6636, 2908),  // leftrightarrow
+        (6642, 2910),  // odblac
+        (6647, 2913),  // oline
+        (6652, 2918),  // ngeqq
+        (6657, 2921),  // sharp
+        (6660, 2923),  // Eta
+        (6668, 2926),  // biguplus
+        (6671, 2929),  // bot
+        (6686, 2932),  // bigtriangledown
+        (6689, 2935),  // int
+        (6706, 2940),  // NotGreaterGreater
+        (6712, 2946),  // vsupne
+        (6719, 2949),  // Because
+        (6725, 2952),  // frac35
+        (6728, 2955),  // cir
+        (6730, 2958),  // gE
+        (6735, 2961),  // setmn
+        (6748, 2964),  // VerticalTilde
+        (6754, 2965),  // midast
+        (6762, 2968),  // llcorner
+        (6766, 2971),  // tint
+        (6773, 2974),  // isindot
+        (6787, 2977),  // ShortLeftArrow
+        (6790, 2980),  // Vee
+        (6794, 2981),  // excl
+        (6797, 2983),  // deg
+        (6802, 2986),  // gsime
+        (6809, 2989),  // asympeq
+        (6818, 2992),  // nsupseteq
+        (6823, 2995),  // prnap
+        (6832, 2998),  // nparallel
+        (6839, 3001),  // lesdoto
+        (6845, 3004),  // dlcorn
+        (6852, 3007),  // natural
+        (6857, 3010),  // bsolb
+        (6872, 3013),  // rightthreetimes
+        (6877, 3015),  // Iukcy
+        (6883, 3018),  // ForAll
+        (6888, 3020),  // Eogon
+        (6892, 3024),  // Uopf
+        (6901, 3027),  // LeftArrow
+        (6915, 3030),  // InvisibleTimes
+        (6919, 3033),  // cirE
+        (6922, 3036),  // egs
+        (6929, 3039),  // uwangle
+        (6933, 3042),  // part
+        (6939, 3045),  // barvee
+        (6944, 3048),  // ofcir
+        (6947, 3052),  // zfr
+        (6963, 3055),  // UnderParenthesis
+        (6970, 3058),  // angrtvb
+        (6975, 3061),  // langd
+        (6985, 3064),  // RightFloor
+        (6989, 3067),  // Rarr
+        (6995, 3070),  // nvHarr
+        (6997, 3073),  // rx
+        (7002, 3075),  // ubrcy
+        (7013, 3078),  // LeftCeiling
+        (7023, 3083),  // nsubseteqq
+        (7028, 3085),  // TSHcy
+        (7032, 3089),  // Eopf
+        (7038, 3092),  // frac25
+        (7042, 3096),  // Kopf
+        (7057, 3099),  // LeftTriangleBar
+        (7064, 3102),  // gtrless
+        (7078, 3105),  // DownLeftVector
+        (7083, 3108),  // xrArr
+        (7086, 3109),  // Hat
+        (7092, 3112),  // gtrsim
+        (7097, 3115),  // cwint
+        (7104, 3118),  // planckh
+        (7114, 3124),  // ThickSpace
+        (7117, 3127),  // squ
+        (7123, 3130),  // incare
+        (7128, 3133),  // robrk
+        (7133, 3136),  // boxhU
+        (7138, 3138),  // emacr
+        (7143, 3141),  // boxHD
+        (7147, 3144),  // flat
+        (7166, 3147),  // RightArrowLeftArrow
+        (7171, 3150),  // rlhar
+        (7176, 3153),  // gescc
+        (7181, 3156),  // boxvr
+        (7188, 3159),  // Element
+        (7190, 3160),  // LT
+        (7194, 3164),  // Sscr
+        (7199, 3167),  // rbbrk
+        (7205, 3170),  // nVdash
+        (7210, 3173),  // numsp
+        (7217, 3176),  // larrsim
+        (7219, 3179),  // wp
+        (7223, 3182),  // dsol
+        (7243, 3185),  // NotDoubleVerticalBar
+        (7248, 3187),  // Breve
+        (7254, 3189),  // Odblac
+        (7257, 3192),  // Int
+        (7262, 3193),  // comma
+        (7271, 3196),  // MinusPlus
+        (7278, 3199),  // dotplus
+        (7285, 3202),  // coloneq
+        (7291, 3204),  // hardcy
+        (7295, 3206),  // chcy
+        (7301, 3207),  // percnt
+        (7304, 3211),  // Afr
+        (7309, 3214),  // boxvL
+        (7314, 3220),  // vnsub
+        (7321, 3223),  // Product
+        (7327, 3226),  // nsimeq
+        (7340, 3229),  // ApplyFunction
+        (7346, 3232),  // lsquor
+        (7351, 3235),  // cupor
+        (7357, 3237),  // Abreve
+        (7368, 3240),  // thickapprox
+        (7373, 3243),  // Tilde
+        (7379, 3246),  // urcrop
+        (7383, 3248),  // COPY
+        (7387, 3251),  // sime
+        (7392, 3253),  // delta
+        (7400, 3256),  // eqvparsl
+        (7405, 3259),  // boxVl
+        (7411, 3262),  // frac13
+        (7417, 3265),  // rfisht
+        (7422, 3268),  // blk14
+        (7430, 3271),  // raemptyv
+        (7450, 3274),  // CapitalDifferentialD
+        (7456, 3276),  // curren
+        (7460, 3280),  // Jopf
+        (7465, 3283),  // ovbar
+        (7471, 3286),  // hyphen
+        (7475, 3289),  // lneq
+        (7479, 3292),  // andv
+        (7487, 3295),  // dotminus
+        (7492, 3297),  // bepsi
+        (7499, 3300),  // between
+        (7511, 3303),  // RightCeiling
+        (7521, 3306),  // sqsubseteq
+        (7531, 3309),  // UnderBrace
+        (7536, 3312),  // supnE
+        (7541, 3315),  // lhblk
+        (7547, 3318),  // Assign
+        (7553, 3321),  // ufisht
+        (7560, 3323),  // Omicron
+        (7564, 3325),  // sup3
+        (7568, 3329),  // hscr
+        (7573, 3332),  // rarrw
+        (7576, 3335),  // ell
+        (7584, 3338),  // SuchThat
+        (7588, 3341),  // csub
+        (7591, 3344),  // nap
+        (7597, 3347),  // minusb
+        (7603, 3350),  // smashp
+        (7607, 3351),  // bsol
+        (7610, 3354),  // Zfr
+        (7613, 3356),  // vcy
+        (7619, 3359),  // mapsto
+        (7624, 3361),  // Omega
+        (7630, 3364),  // bumpeq
+        (7635, 3367),  // RBarr
+        (7638, 3371),  // Lfr
+        (7648, 3374),  // longmapsto
+        (7651, 3377),  // ges
+        (7653, 3380),  // ap
+        (7669, 3383),  // ntrianglerighteq
+        (7679, 3386),  // circledast
+        (7685, 3389),  // frac23
+        (7691, 3391),  // Egrave
+        (7693, 3394),  // oS
+        (7699, 3397),  // dagger
+        (7704, 3400),  // lrhar
+        (7709, 3403),  // xutri
+        (7712, 3406),  // Del
+        (7729, 3409),  // rightleftharpoons
+        (7745, 3410),  // DiacriticalGrave
+        (7751, 3412),  // Zacute
+        (7759, 3415),  // profline
+        (7763, 3417),  // cent
+        (7773, 3419),  // varepsilon
+        (7776, 3422),  // rlm
+        (7782, 3424),  // tcedil
+        (7790, 3427),  // plusacir
+        (7796, 3430),  // cylcty
+        (7798, 3432),  // pi
+        (7804, 3435),  // searhk
+        (7809, 3437),  // strns
+        (7829, 3440),  // OpenCurlyDoubleQuote
+        (7833, 3442),  // cdot
+        (7841, 3445),  // imagpart
+        (7849, 3447),  // varkappa
+        (7860, 3450),  // UpDownArrow
+        (7865, 3453),  // boxhd
+        (7868, 3455),  // phi
+        (7889, 3458),  // NotRightTriangleEqual
+        (7898, 3461),  // checkmark
+        (7902, 3463),  // copy
+        (7915, 3466),  // fallingdotseq
+        (7921, 3469),  // lesdot
+        (7926, 3471),  // Gamma
+        (7932, 3474),  // egsdot
+        (7939, 3477),  // simrarr
+        (7944, 3479),  // oelig
+        (7948, 3481),  // Edot
+        (7952, 3484),  // mlcp
+        (7958, 3487),  // urcorn
+        (7963, 3490),  // ddarr
+        (7969, 3493),  // sstarf
+        (7975, 3496),  // copysr
+        (7980, 3499),  // scsim
+        (7986, 3502),  // nvdash
+        (8000, 3505),  // LeftDownVector
+        (8006, 3508),  // coprod
+        (8011, 3511),  // nlArr
+        (8015, 3514),  // lang
+        (8020, 3516),  // Icirc
+        (8025, 3519),  // blk12
+        (8027, 3521),  // mu
+        (8042, 3524),  // LeftUpTeeVector
+        (8048, 3527),  // xwedge
+        (8065, 3530),  // FilledSmallSquare
+        (8072, 3533),  // congdot
+        (8074, 3536),  // eg
+        (8079, 3539),  // nltri
+        (8084, 3542),  // ldquo
+        (8089, 3545),  // iiint
+        (8095, 3548),  // rmoust
+        (8102, 3551),  // Implies
+        (8117, 3554),  // LeftRightVector
+        (8121, 3557),  // lozf
+        (8125, 3561),  // Lopf
+        (8129, 3563),  // DScy
+        (8132, 3566),  // ape
+        (8137, 3569),  // lbrke
+        (8141, 3571),  // euml
+        (8144, 3573),  // Phi
+        (8157, 3576),  // shortparallel
+        (8169, 3579),  // ShortUpArrow
+        (8174, 3582),  // rharu
+        (8178, 3584),  // rhov
+        (8182, 3586),  // Uuml
+        (8188, 3589),  // Subset
+        (8194, 3591),  // Ubreve
+        (8196, 3594),  // ne
+        (8201, 3597),  // nsube
+        (8205, 3600),  // Star
+        (8211, 3606),  // sqcups
+        (8216, 3609),  // boxhD
+        (8222, 3612),  // circeq
+        (8229, 3615),  // nsqsube
+        (8234, 3618),  // Dashv
+        (8238, 3621),  // xmap
+        (8241, 3623),  // psi
+        (8247, 3625),  // inodot
+        (8250, 3627),  // die
+        (8253, 3630),  // smt
+        (8258, 3633),  // rarrb
+        (8264, 3634),  // dollar
+        (8268, 3639),  // nges
+        (8274, 3642),  // lthree
+        (8279, 3647),  // nleqq
+        (8284, 3649),  // Ocirc
+        (8289, 3652),  // ropar
+        (8293, 3654),  // fnof
+        (8298, 3657),  // ccaps
+        (8306, 3660),  // succnsim
+        (8309, 3662),  // dot
+        (8313, 3666),  // fopf
+        (8319, 3669),  // lagran
+        (8325, 3672),  // veebar
+        (8330, 3675),  // xdtri
+        (8334, 3679),  // iopf
+        (8346, 3682),  // blacklozenge
+        (8349, 3687),  // nLl
+        (8353, 3690),  // cire
+        (8357, 3694),  // yopf
+        (8363, 3697),  // squarf
+        (8367, 3700),  // uarr
+        (8371, 3703),  // gsim
+        (8376, 3709),  // nvsim
+        (8379, 3711),  // ocy
+        (8389, 3714),  // nleftarrow
+        (8395, 3717),  // prurel
+        (8402, 3720),  // Cconint
+        (8407, 3723),  // prcue
+        (8411, 3727),  // Sopf
+        (8415, 3730),  // darr
+        (8422, 3733),  // alefsym
+        (8427, 3736),  // boxvh
+        (8433, 3739),  // sfrown
+        (8439, 3742),  // lesges
+        (8442, 3744),  // ecy
+        (8445, 3746),  // yen
+        (8448, 3748),  // piv
+        (8457, 3751),  // supsetneq
+        (8463, 3754),  // gesles
+        (8469, 3756),  // ograve
+        (8475, 3759),  // lharul
+        (8480, 3762),  // simlE
+        (8485, 3765),  // cuepr
+        (8489, 3768),  // comp
+        (8492, 3771),  // Lsh
+        (8505, 3774),  // Poincareplane
+        (8509, 3776),  // Yuml
+        (8512, 3778),  // ETH
+        (8518, 3780),  // Ccedil
+        (8529, 3783),  // quaternions
+        (8538, 3786),  // OverBrace
+        (8543, 3788),  // aogon
+        (8548, 3791),  // nprec
+        (8562, 3794),  // ReverseElement
+        (8567, 3797),  // slarr
+        (8573, 3799),  // sacute
+        (8576, 3802),  // lap
+        (8579, 3804),  // reg
+        (8590, 3807),  // curlyeqsucc
+        (8593, 3811),  // Wfr
+        (8596, 3815),  // ffr
+        (8610, 3818),  // trianglelefteq
+        (8613, 3821),  // par
+        (8624, 3824),  // expectation
+        (8630, 3825),  // lbrace
+        (8643, 3828),  // LongLeftArrow
+        (8667, 3831),  // DoubleLongLeftRightArrow
+        (8681, 3834),  // Longrightarrow
+        (8686, 3837),  // lsime
+        (8689, 3839),  // Ecy
+        (8701, 3845),  // varsubsetneq
+        (8703, 3848),  // sc
+        (8710, 3851),  // orderof
+        (8713, 3853),  // Scy
+        (8719, 3855),  // Ncedil
+        (8726, 3858),  // angzarr
+        (8735, 3861),  // Therefore
+        (8755, 3864),  // NotSquareSubsetEqual
+        (8761, 3867),  // xsqcup
+        (8764, 3869),  // Fcy
+        (8778, 3871),  // DiacriticalDot
+        (8783, 3874),  // boxUR
+        (8798, 3877),  // LeftUpVectorBar
+        (8802, 3879),  // DJcy
+        (8808, 3882),  // larrlp
+        (8817, 3885),  // NotCupCap
+        (8825, 3888),  // NotTilde
+        (8843, 3891),  // DownRightTeeVector
+        (8847, 3895),  // tscr
+        (8853, 3898),  // ltlarr
+        (8856, 3900),  // Lcy
+        (8863, 3903),  // notinvc
+        (8868, 3906),  // gimel
+        (8874, 3909),  // rarrpl
+        (8879, 3912),  // nrarr
+        (8884, 3915),  // lobrk
+        (8887, 3918),  // zwj
+        (8892, 3921),  // rdquo
+        (8897, 3924),  // boxDL
+        (8901, 3927),  // prec
+        (8911, 3932),  // nsupseteqq
+        (8917, 3934),  // Zcaron
+        (8923, 3937),  // plusdo
+        (8926, 3940),  // vee
+        (8939, 3943),  // triangleright
+        (8946, 3946),  // OverBar
+        (8952, 3949),  // telrec
+        (8958, 3952),  // nesear
+        (8962, 3955),  // xcup
+        (8968, 3958),  // succeq
+        (8974, 3961),  // lrhard
+        (8981, 3964),  // lessdot
+        (8984, 3967),  // les
+        (8991, 3970),  // subrarr
+        (8996, 3972),  // upsih
+        (8998, 3975),  // dd
+        (9003, 3978),  // ltrif
+        (9007, 3981),  // boxv
+        (9013, 3984),  // langle
+        (9019, 3987),  // subsup
+        (9032, 3990),  // SupersetEqual
+        (9035, 3992),  // tcy
+        (9039, 3998),  // lesg
+        (9047, 4001),  // angmsdaf
+        (9052, 4003),  // gamma
+        (9056, 4005),  // Upsi
+        (9073, 4008),  // SquareSubsetEqual
+        (9092, 4013),  // NotGreaterFullEqual
+        (9095, 4016),  // Map
+        (9100, 4018),  // Ecirc
+        (9104, 4022),  // hopf
+        (9112, 4025),  // rtriltri
+        (9126, 4028),  // OpenCurlyQuote
+        (9129, 4030),  // pcy
+        (9135, 4033),  // compfn
+        (9141, 4036),  // divonx
+        (9146, 4039),  // isins
+        (9153, 4042),  // submult
+        (9156, 4044),  // Acy
+        (9159, 4047),  // glE
+        (9162, 4051),  // Ofr
+        (9167, 4054),  // starf
+        (9172, 4057),  // reals
+        (9178, 4059),  // Itilde
+        (9183, 4062),  // uuarr
+        (9194, 4065),  // blacksquare
+        (9201, 4068),  // cularrp
+        (9206, 4070),  // Wcirc
+        (9210, 4076),  // gesl
+        (9218, 4079),  // triminus
+        (9224, 4081),  // brvbar
+        (9231, 4084),  // dbkarow
+        (9246, 4087),  // LowerRightArrow
+        (9254, 4090),  // angmsdad
+        (9260, 4093),  // solbar
+        (9268, 4096),  // profalar
+        (9272, 4097),  // vert
+        (9280, 4100),  // dzigrarr
+        (9286, 4103),  // conint
+        (9291, 4106),  // olcir
+        (9306, 4111),  // NotSquareSubset
+        (9312, 4113),  // sigmaf
+        (9315, 4117),  // Yfr
+        (9319, 4119),  // YAcy
+        (9334, 4122),  // ShortRightArrow
+        (9340, 4124),  // Aacute
+        (9347, 4126),  // Epsilon
+        (9364, 4131),  // NotSquareSuperset
+        (9369, 4134),  // boxDl
+        (9373, 4136),  // Beta
+        (9378, 4139),  // boxuL
+        (9384, 4142),  // bottom
+        (9391, 4145),  // bigodot
+        (9397, 4147),  // dstrok
+        (9400, 4149),  // kcy
+        (9415, 4152),  // leftrightarrows
+        (9430, 4155),  // trianglerighteq
+        (9435, 4158),  // simgE
+        (9442, 4161),  // rarrsim
+        (9448, 4164),  // topbot
+        (9451, 4167),  // sim
+        (9465, 4170),  // ntriangleright
+        (9473, 4173),  // andslope
+        (9476, 4177),  // lfr
+        (9481, 4180),  // image
+        (9488, 4183),  // notniva
+        (9491, 4186),  // loz
+        (9495, 4192),  // lvnE
+        (9502, 4195),  // suphsub
+        (9507, 4198),  // Union
+        (9513, 4200),  // ncedil
+        (9517, 4203),  // Esim
+        (9526, 4206),  // therefore
+        (9528, 4209),  // Re
+        (9533, 4212),  // nwArr
+        (9539, 4214),  // Cacute
+        (9555, 4219),  // NotSucceedsEqual
+        (9557, 4221),  // nu
+        (9562, 4224),  // rBarr
+        (9569, 4227),  // plustwo
+        (9575, 4228),  // lowbar
+        (9578, 4232),  // ifr
+        (9589, 4235),  // succnapprox
+        (9594, 4238),  // cuwed
+        (9598, 4242),  // Yscr
+        (9609, 4245),  // NotPrecedes
+        (9615, 4247),  // aacute
+        (9627, 4250),  // triangleleft
+        (9631, 4254),  // mscr
+        (9636, 4257),  // ltcir
+        (9641, 4260),  // nsucc
+        (9647, 4263),  // bowtie
+        (9650, 4267),  // Efr
+        (9654, 4271),  // Xscr
+        (9660, 4273),  // itilde
+        (9666, 4275),  // thetav
+        (9670, 4278),  // npar
+        (9677, 4281),  // nexists
+        (9682, 4284),  // rnmid
+        (9688, 4287),  // lfisht
+        (9691, 4289),  // Pcy
+        (9696, 4292),  // nlarr
+        (9705, 4297),  // nleqslant
+        (9710, 4300),  // nsupe
+        (9713, 4304),  // cfr
+        (9719, 4307),  // larrfs
+        (9729, 4310),  // upuparrows
+        (9735, 4312),  // kcedil
+        (9740, 4314),  // Sigma
+        (9743, 4316),  // Tcy
+        (9749, 4319),  // forall
+        (9752, 4323),  // sfr
+        (9758, 4326),  // mnplus
+        (9774, 4329),  // NotSupersetEqual
+        (9779, 4331),  // Omacr
+        (9782, 4333),  // ucy
+        (9784, 4336),  // af
+        (9789, 4338),  // Aring
+        (9795, 4340),  // lstrok
+        (9798, 4344),  // gfr
+        (9818, 4349),  // NotGreaterSlantEqual
+        (9821, 4352),  // cup
+        (9835, 4355),  // NotLessGreater
+        (9840, 4358),  // nlsim
+        (9848, 4361),  // bigwedge
+        (9857, 4363),  // DownBreve
+        (9863, 4365),  // Rcedil
+        (9868, 4368),  // block
+        (9874, 4371),  // weierp
+        (9879, 4374),  // lBarr
+        (9884, 4377),  // fflig
+        (9889, 4380),  // operp
+        (9895, 4383),  // nequiv
+        (9898, 4385),  // ycy
+        (9906, 4388),  // backcong
+        (9912, 4391),  // iiiint
+        (9917, 4393),  // varpi
+        (9922, 4396),  // Wedge
+        (9927, 4399),  // lltri
+        (9930, 4403),  // bfr
+        (9937, 4406),  // realine
+        (9942, 4409),  // rrarr
+        (9947, 4412),  // lsquo
+        (9952, 4414),  // Alpha
+        (9956, 4417),  // nsup
+        (9962, 4420),  // sqsupe
+        (9971, 4423),  // bigotimes
+        (9974, 4426),  // els
+        (9979, 4429),  // sqsup
+        (9985, 4432),  // mstpos
+        (9992, 4435),  // supdsub
+        (9995, 4438),  // mid
+        (9999, 4442),  // Cscr
+        (10006, 4448), // nvrtrie
+        (10009, 4450), // dcy
+        (10012, 4453), // Ifr
+        (10018, 4456), // CupCap
+        (10026, 4459), // bigsqcup
+        (10033, 4462), // gesdoto
+        (10039, 4465), // propto
+        (10041, 4466), // gt
+        (10049, 4469), // smeparsl
+        (10054, 4471), // omacr
+        (10062, 4474), // ulcorner
+        (10068, 4480), // vsupnE
+        (10079, 4483), // preccurlyeq
+        (10087, 4486), // DotEqual
+        (10092, 4488), // Jukcy
+        (10098, 4491), // rarrap
+        (10105, 4494), // supmult
+        (10119, 4497), // rightharpoonup
+        (10125, 4500), // nvrArr
+        (10146, 4503), // DoubleContourIntegral
+        (10151, 4506), // boxvR
+        (10154, 4509), // lsh
+        (10159, 4512), // smile
+        (10162, 4514), // eng
+        (10179, 4517), // RightAngleBracket
+        (10184, 4520), // lhard
+        (10188, 4524), // gopf
+        (10194, 4525), // period
+        (10199, 4527), // Ubrcy
+        (10204, 4530), // xharr
+        (10211, 4533), // UpArrow
+        (10214, 4535), // Ucy
+        (10220, 4541), // sqcaps
+        (10228, 4544), // gnapprox
+        (10245, 4549), // NotLessSlantEqual
+        (10257, 4552), // Proportional
+        (10263, 4553), // rbrace
+        (10267, 4555), // Idot
+        (10273, 4557), // ugrave
+        (10277, 4561), // Xopf
+        (10283, 4564), // Vdashl
+        (10286, 4566), // zcy
+        (10291, 4569), // eqsim
+        (10294, 4571), // bcy
+        (10299, 4574), // erarr
+        (10304, 4577), // harrw
+        (10310, 4580), // there4
+        (10315, 4583), // doteq
+        (10320, 4585), // icirc
+        (10324, 4588), // prod
+        (10329, 4591), // ctdot
+        (10344, 4594), // NotGreaterEqual
+        (10350, 4597), // capcup
+        (10354, 4599), // LJcy
+        (10359, 4601), // IJlig
+        (10374, 4604), // rightsquigarrow
+        (10380, 4607), // cupcup
+        (10388, 4610), // Succeeds
+        (10401, 4613), // leftharpoonup
+        (10414, 4616), // VeryThinSpace
+        (10418, 4619), // sext
+        (10426, 4622), // timesbar
+        (10432, 4623), // equals
+        (10436, 4626), // lsim
+        (10446, 4629), // mapstodown
+        (10455, 4632), // ThinSpace
+        (10458, 4635), // orv
+        (10464, 4637), // udblac
+        (10469, 4640), // gtcir
+        (10475, 4642), // Rcaron
+        (10482, 4645), // suplarr
+        (10488, 4647), // scedil
+        (10492, 4650), // diam
+        (10511, 4653), // leftrightsquigarrow
+        (10517, 4656), // supset
+        (10521, 4659), // uArr
+        (10529, 4662), // boxminus
+        (10537, 4665), // subseteq
+        (10542, 4667), // omega
+        (10546, 4670), // Zopf
+        (10550, 4674), // Mopf
+        (10554, 4678), // yscr
+        (10558, 4682), // Oscr
+        (10571, 4685), // SucceedsEqual
+        (10575, 4687), // shcy
+        (10581, 4689), // oacute
+        (10585, 4692), // ngtr
+        (10590, 4695), // aleph
+        (10599, 4698), // heartsuit
+        (10603, 4702), // copf
+        (10609, 4705), // lsaquo
+        (10613, 4709), // topf
+        (10622, 4712), // Downarrow
+        (10630, 4715), // urcorner
+        (10637, 4718), // cudarrl
+        (10654, 4721), // DownLeftTeeVector
+        (10660, 4723), // Utilde
+        (10666, 4726), // becaus
+        (10683, 4729), // blacktriangleleft
+        (10686, 4731), // Zcy
+        (10695, 4734), // spadesuit
+        (10699, 4736), // njcy
+        (10706, 4739), // bemptyv
+        (10715, 4741), // DoubleDot
+        (10720, 4744), // boxdR
+        (10724, 4747), // ltcc
+        (10729, 4749), // OElig
+        (10744, 4751), // straightepsilon
+        (10748, 4753), // GJcy
+        (10753, 4756), // vprop
+        (10757, 4759), // Bscr
+        (10760, 4763), // xfr
+        (10763, 4766), // lEg
+        (10766, 4770), // Ufr
+        (10772, 4772), // scaron
+        (10786, 4775), // Leftrightarrow
+        (10792, 4778), // emptyv
+        (10799, 4781), // lessgtr
+        (10805, 4784), // thksim
+        (10811, 4786), // Iacute
+        (10815, 4789), // nldr
+        (10820, 4792), // boxHd
+        (10826, 4795), // rtimes
+        (10830, 4798), // prnE
+        (10838, 4801), // Precedes
+        (10842, 4804), // lHar
+        (10847, 4807), // nrArr
+        (10853, 4809), // egrave
+        (10857, 4814), // nGtv
+        (10866, 4817), // dotsquare
+        (10876, 4820), // rmoustache
+        (10884, 4823), // parallel
+        (10896, 4826), // HilbertSpace
+        (10900, 4828), // sect
+        (10906, 4831), // swarhk
+        (10913, 4834), // rarrbfs
+        (10917, 4837), // ange
+        (10923, 4843), // vsubnE
+        (10926, 4846), // ngt
+        (10943, 4849), // DoubleUpDownArrow
+        (10948, 4852), // subnE
+        (10961, 4858), // varsubsetneqq
+        (10967, 4861), // target
+        (10975, 4864), // intlarhk
+        (10979, 4868), // Wscr
+        (10983, 4871), // lnap
+        (10988, 4874), // rsquo
+        (10995, 4877), // supedot
+        (11004, 4880), // LeftFloor
+        (11009, 4883), // bdquo
+        (11022, 4886), // LeftTeeVector
+        (11030, 4887), // UnderBar
+        (11034, 4889), // phiv
+        (11041, 4895), // nsupset
+        (11047, 4897), // Ccaron
+        (11063, 4900), // twoheadleftarrow
+        (11069, 4903), // angsph
+        (11075, 4905), // Scaron
+        (11093, 4908), // RightDownTeeVector
+        (11098, 4911), // esdot
+        (11106, 4914), // gesdotol
+        (11109, 4916), // lcy
+        (11114, 4919), // sdote
+        (11119, 4922), // uharl
+        (11125, 4925), // cirmid
+        (11128, 4928), // map
+        (11131, 4932), // Kfr
+        (11136, 4934), // Hcirc
+        (11144, 4937), // angmsdaa
+        (11150, 4939), // Atilde
+        (11154, 4941), // yuml
+        (11157, 4944), // gla
+        (11161, 4947), // late
+        (11165, 4950), // perp
+        (11168, 4952), // eth
+        (11179, 4955), // eqslantless
+        (11186, 4958), // olcross
+        (11188, 4960), // Pi
+        (11201, 4963), // UpEquilibrium
+        (11208, 4969), // bnequiv
+        (11215, 4972), // ruluhar
+        (11226, 4975), // OverBracket
+        (11232, 4978), // DotDot
+        (11237, 4981), // loarr
+        (11243, 4984), // xotime
+        (11252, 4987), // Mellintrf
+        (11258, 4989), // Ograve
+        (11262, 4991), // Euml
+        (11266, 4994), // vArr
+        (11275, 4997), // gtrapprox
+        (11282, 5000), // cemptyv
+        (11297, 5003), // DoubleDownArrow
+        (11300, 5006), // nsc
+        (11305, 5009), // nharr
+        (11317, 5015), // varsupsetneq
+        (11321, 5018), // rang
+        (11325, 5022), // dopf
+        (11337, 5025), // GreaterEqual
+        (11342, 5028), // lsimg
+        (11346, 5031), // nleq
+        (11352, 5034), // rpargt
+        (11356, 5036), // Auml
+        (11360, 5039), // prap
+        (11363, 5043), // wfr
+        (11379, 5045), // DiacriticalTilde
+        (11387, 5048), // cupbrcap
+        (11392, 5051), // boxur
+        (11395, 5053), // gcy
+        (11400, 5056), // iiota
+        (11418, 5059), // Longleftrightarrow
+        (11431, 5062), // looparrowleft
+        (11445, 5065), // LeftRightArrow
+        (11461, 5068), // RightTriangleBar
+        (11472, 5071), // updownarrow
+        (11481, 5074), // downarrow
+        (11485, 5078), // kopf
+        (11497, 5081), // DownTeeArrow
+        (11501, 5085), // jscr
+        (11506, 5088), // boxVh
+        (11512, 5091), // nrtrie
+        (11517, 5093), // cedil
+        (11522, 5099), // vnsup
+        (11525, 5101), // div
+        (11556, 5104), // CounterClockwiseContourIntegral
+        (11562, 5107), // dfisht
+        (11572, 5110), // CirclePlus
+        (11574, 5113), // le
+        (11577, 5116), // Sub
+        (11591, 5119), // NotSubsetEqual
+        (11594, 5122), // Cup
+        (11600, 5124), // lambda
+        (11611, 5127), // circledcirc
+        (11615, 5130), // varr
+        (11622, 5133), // plussim
+        (11624, 5135), // Mu
+        (11629, 5138), // vdash
+        (11635, 5141), // subset
+        (11641, 5144), // andand
+        (11650, 5146), // CenterDot
+        (11656, 5149), // Colone
+        (11664, 5152), // Superset
+        (11674, 5155), // eqslantgtr
+        (11678, 5158), // osol
+        (11683, 5161), // boxUl
+        (11688, 5163), // breve
+        (11694, 5166), // cupdot
+        (11702, 5169), // hksearow
+        (11708, 5171), // Lacute
+        (11712, 5174), // toea
+        (11715, 5177), // top
+        (11718, 5180), // leg
+        (11725, 5183), // DownTee
+        (11729, 5187), // dscr
+        (11733, 5190), // Vbar
+        (11737, 5192), // sup2
+        (11745, 5195), // angrtvbd
+        (11755, 5198), // Laplacetrf
+        (11761, 5201), // primes
+        (11766, 5204), // nhArr
+        (11770, 5207), // Iscr
+        (11792, 5210), // NotSquareSupersetEqual
+        (11798, 5213), // approx
+        (11816, 5216), // RightDoubleBracket
+        (11820, 5220), // qscr
+        (11826, 5223), // ltrPar
+        (11830, 5226), // fork
+        (11835, 5229), // ltrie
+        (11842, 5232), // Diamond
+        (11846, 5236), // lopf
+        (11854, 5239), // laemptyv
+        (11859, 5242), // lbbrk
+        (11865, 5243), // lbrack
+        (11871, 5245), // SHCHcy
+        (11873, 5248), // Sc
+        (11878, 5251), // gnsim
+        (11890, 5254), // NotCongruent
+        (11894, 5256), // KHcy
+        (11901, 5259), // pertenk
+        (11904, 5263), // Tfr
+        (11918, 5266), // leftthreetimes
+        (11929, 5268), // backepsilon
+        (11936, 5271), // backsim
+        (11941, 5273), // caron
+        (11948, 5276), // nearrow
+        (11967, 5279), // LeftArrowRightArrow
+        (11973, 5281), // frac14
+        (11979, 5284), // emsp14
+        (11987, 5287), // intercal
+        (11994, 5290), // succsim
+        (11998, 5293), // ncap
+        (12011, 5298), // NotEqualTilde
+        (12017, 5301), // nltrie
+        (12021, 5304), // xcap
+        (12027, 5306), // Gammad
+        (12031, 5307), // rpar
+        (12036, 5309), // sigma
+        (12042, 5312), // boxbox
+        (12045, 5316), // Jfr
+        (12048, 5320), // efr
+        (12052, 5322), // KJcy
+        (12058, 5325), // swnwar
+        (12060, 5328), // wr
+        (12065, 5331), // fllig
+        (12069, 5334), // isin
+        (12072, 5336), // Gcy
+        (12075, 5338), // Vcy
+        (12079, 5341), // gnap
+        (12085, 5344), // ssetmn
+        (12101, 5347), // DoubleRightArrow
+        (12115, 5350), // upharpoonright
+        (12122, 5353), // rbrkslu
+        (12127, 5356), // infin
+        (12133, 5359), // lmoust
+        (12137, 5361), // CHcy
+        (12149, 5364), // HumpDownHump
+        (12155, 5367), // supsup
+        (12160, 5370), // boxdl
+        (12164, 5374), // popf
+        (12172, 5377), // mapstoup
+        (12189, 5380), // GreaterSlantEqual
+        (12193, 5381), // quot
+        (12199, 5383), // plusmn
+        (12205, 5385), // uacute
+        (12211, 5387), // divide
+        (12214, 5389), // scy
+        (12217, 5393), // Gfr
+        (12223, 5396), // horbar
+        (12227, 5399), // imof
+        (12231, 5403), // nscr
+        (12236, 5406), // lceil
+        (12249, 5409), // DoubleLeftTee
+        (12258, 5415), // NotSubset
+        (12266, 5418), // bsolhsub
+        (12283, 5421), // LeftDownVectorBar
+        (12288, 5424), // empty
+        (12294, 5425), // verbar
+        (12302, 5428), // otimesas
+        (12307, 5430), // Iogon
+        (12314, 5433), // curarrm
+        (12320, 5436), // rarrlp
+        (12324, 5440), // Kscr
+        (12331, 5443), // ddagger
+        (12336, 5446), // urtri
+        (12340, 5450), // nopf
+        (12355, 5455), // NotHumpDownHump
+        (12359, 5457), // iuml
+        (12364, 5459), // Aogon
+        (12372, 5462), // precneqq
+        (12378, 5464), // Kcedil
+        (12389, 5470), // NotSuperset
+        (12394, 5475), // napid
+        (12400, 5478), // hairsp
+        (12405, 5481), // eplus
+        (12409, 5484), // sube
+        (12413, 5487), // Mscr
+        (12417, 5490), // supE
+        (12420, 5493), // sce
+        (12423, 5496), // nge
+        (12429, 5499), // minusd
+        (12441, 5502), // LeftArrowBar
+        (12444, 5504), // icy
+        (12448, 5506), // YUcy
+        (12452, 5509), // siml
+        (12476, 5512), // ClockwiseContourIntegral
+        (12486, 5515), // Fouriertrf
+        (12490, 5517), // Zdot
+        (12495, 5520), // dharr
+        (12499, 5523), // boxh
+        (12503, 5526), // ncup
+        (12521, 5529), // ReverseEquilibrium
+        (12524, 5532), // gap
+        (12528, 5535), // ngeq
+        (12534, 5538), // ldquor
+        (12540, 5541), // spades
+        (12543, 5545), // Dfr
+        (12549, 5548), // lparlt
+        (12554, 5551), // scnap
+        (12557, 5554), // geq
+        (12565, 5557), // DDotrahd
+        (12579, 5560), // LongRightArrow
+        (12583, 5562), // nbsp
+        (12588, 5565), // boxHU
+        (12593, 5568), // nwarr
+        (12606, 5571), // PrecedesTilde
+        (12620, 5574), // TildeFullEqual
+        (12625, 5576), // ijlig
+        (12640, 5579), // RightDownVector
+        (12643, 5582), // and
+        (12658, 5585), // CloseCurlyQuote
+        (12664, 5588), // rfloor
+        (12681, 5591), // NotReverseElement
+        (12689, 5596), // ncongdot
+        (12694, 5599), // boxVR
+        (12702, 5602), // multimap
+        (12706, 5605), // geqq
+        (12709, 5608), // ord
+        (12716, 5611), // nwarrow
+        (12726, 5614), // EqualTilde
+        (12740, 5617), // LowerLeftArrow
+        (12743, 5621), // Vfr
+        (12747, 5624), // Hopf
+        (12753, 5627), // ulcorn
+        (12758, 5630), // lnsim
+        (12763, 5632), // Uogon
+        (12769, 5637), // nrarrc
+        (12775, 5640), // simdot
+        (12783, 5643), // rppolint
+        (12800, 5646), // DownLeftVectorBar
+        (12804, 5648), // iecy
+        (12810, 5651), // scnsim
+        (12813, 5654), // Cap
+        (12815, 5655), // GT
+        (12821, 5658), // nprcue
+        (12827, 5660), // Gcedil
+        (12832, 5662), // Emacr
+        (12836, 5665), // oast
+        (12842, 5667), // rcaron
+        (12847, 5669), // umacr
+        (12852, 5671), // Theta
+        (12857, 5674), // radic
+        (12863, 5677), // capdot
+        (12869, 5679), // varphi
+        (12873, 5685), // caps
+        (12880, 5687), // Upsilon
+        (12885, 5690), // hoarr
+        (12902, 5693), // VerticalSeparator
+        (12908, 5695), // Ugrave
+        (12913, 5697), // eogon
+        (12923, 5700), // TildeTilde
+        (12927, 5703), // tdot
+        (12930, 5706), // acd
+        (12943, 5709), // RightTeeArrow
+        (12947, 5712), // solb
+        (12953, 5714), // Oslash
+        (12959, 5719), // nbumpe
+        (12962, 5722), // cap
+        (12965, 5724), // Ocy
+        (12971, 5727), // nvlArr
+        (12975, 5729), // ljcy
+        (12980, 5732), // cuvee
+        (12994, 5735), // LessSlantEqual
+        (12998, 5736), // semi
+        (13010, 5739), // risingdotseq
+        (13013, 5741), // rcy
+        (13016, 5744), // sup
+        (13021, 5747), // Equal
+        (13027, 5750), // capand
+        (13034, 5753), // vzigzag
+        (13039, 5756), // xcirc
+        (13057, 5761), // NotLeftTriangleBar
+        (13063, 5764), // daleth
+        (13068, 5767), // phone
+        (13073, 5770), // rAarr
+        (13078, 5773), // csube
+        (13083, 5776), // boxVL
+        (13088, 5779), // rarrc
+        (13093, 5782), // simeq
+        (13098, 5785), // lopar
+        (13102, 5789), // ropf
+        (13112, 5792), // supsetneqq
+        (13117, 5798), // lates
+        (13123, 5800), // Otilde
+        (13128, 5802), // Uring
+        (13136, 5805), // angmsdah
+        (13142, 5808), // nexist
+        (13144, 5811), // ee
+        (13151, 5814), // supplus
+        (13156, 5816), // AElig
+        (13165, 5819), // backsimeq
+        (13170, 5822), // xhArr
+        (13176, 5825), // curarr
+        (13180, 5830), // nLtv
+        (13190, 5833), // precapprox
+        (13198, 5835), // thetasym
+        (13202, 5838), // xnis
+        (13205, 5841), // niv
+        (13211, 5844), // subdot
+        (13215, 5848), // Dopf
+        (13221, 5851), // nearhk
+        (13226, 5854), // mDDot
+        (13234, 5857), // lnapprox
+        (13240, 5860), // xuplus
+        (13243, 5862), // Mcy
+        (13247, 5864), // circ
+        (13252, 5866), // aring
+        (13257, 5869), // supne
+        (13261, 5873), // Ascr
+        (13267, 5875), // ncaron
+        (13271, 5878), // scnE
+        (13281, 5881), // lessapprox
+        (13287, 5883), // ccedil
+        (13290, 5887), // rfr
+        (13300, 5890), // varnothing
+        (13306, 5893), // square
+        (13310, 5897), // ascr
+        (13314, 5900), // ntgl
+        (13318, 5903), // rtri
+        (13322, 5906), // sdot
+        (13336, 5909), // DoubleRightTee
+        (13341, 5912), // trade
+        (13346, 5917), // nbump
+        (13351, 5920), // sccue
+        (13355, 5922), // gjcy
+        (13361, 5925), // rdquor
+        (13366, 5928), // VDash
+        (13369, 5931), // gnE
+        (13374, 5934), // gneqq
+        (13379, 5937), // sbquo
+        (13383, 5941), // nvgt
+        (13389, 5943), // ccaron
+        (13394, 5946), // frown
+        (13400, 5949), // frac18
+        (13404, 5953), // zopf
+        (13409, 5955), // THORN
+        (13419, 5958), // rightarrow
+        (13421, 5960), // Nu
+        (13430, 5963), // CircleDot
+        (13435, 5966), // simne
+        (13440, 5969), // prsim
+        (13445, 5972), // lAarr
+        (13450, 5974), // amacr
+        (13468, 5977), // longleftrightarrow
+        (13473, 5980), // rtrie
+        (13477, 5983), // opar
+        (13480, 5986), // npr
+        (13496, 5989), // RightUpTeeVector
+        (13507, 5992), // CircleTimes
+        (13511, 5995), // bnot
+        (13516, 5998), // larrb
+        (13536, 6001), // DoubleLongRightArrow
+        (13539, 6003), // Rcy
+        (13545, 6006), // ratail
+        (13551, 6009), // lfloor
+        (13560, 6012), // subsetneq
+        (13567, 6015), // rdldhar
+        (13570, 6017), // Rho
+        (13576, 6019), // zcaron
+        (13579, 6022), // apE
+        (13585, 6025), // Square
+        (13593, 6028), // approxeq
+        (13612, 6031), // DoubleLongLeftArrow
+        (13620, 6034), // angmsdag
+        (13623, 6038), // tfr
+        (13628, 6041), // vrtri
+        (13632, 6044), // mldr
+        (13639, 6047), // notinva
+        (13645, 6050), // thinsp
+        (13650, 6052), // jmath
+        (13660, 6055), // LeftVector
+        (13664, 6058), // andd
+        (13668, 6061), // prop
+        (13671, 6064), // Sup
+        (13677, 6067), // apacir
+        (13680, 6070), // glj
+        (13683, 6073), // nlt
+        (13690, 6076), // eqcolon
+        (13698, 6079), // ldrushar
+        (13702, 6082), // Uarr
+        (13710, 6084), // vartheta
+        (13715, 6087), // lrarr
+        (13731, 6090), // circlearrowright
+        (13737, 6093), // topcir
+        (13743, 6095), // oslash
+        (13750, 6098), // ldrdhar
+        (13766, 6100), // DiacriticalAcute
+        (13770, 6103), // ecir
+        (13783, 6106), // DifferentialD
+        (13790, 6109), // subplus
+        (13794, 6111), // Cdot
+        (13797, 6117), // nGt
+        (13803, 6120), // Exists
+        (13807, 6126), // nvap
+        (13811, 6129), // rArr
+        (13817, 6132), // sqsube
+        (13821, 6135), // qint
+        (13829, 6138), // naturals
+        (13832, 6140), // ohm
+        (13835, 6143), // nis
+        (13842, 6146), // dwangle
+        (13849, 6149), // cudarrr
+        (13854, 6152), // disin
+        (13860, 6155), // plusdu
+        (13877, 6158), // LeftDownTeeVector
+        (13882, 6160), // Acirc
+        (13887, 6163), // cross
+        (13891, 6166), // bNot
+        (13895, 6169), // xvee
+        (13902, 6172), // LeftTee
+        (13908, 6174), // utilde
+        (13918, 6177), // gtreqqless
+        (13932, 6180), // longrightarrow
+        (13938, 6182), // SOFTcy
+        (13946, 6185), // barwedge
+        (13950, 6188), // smte
+        (13954, 6192), // jopf
+        (13966, 6195), // ExponentialE
+        (13972, 6198), // ltimes
+        (13976, 6201), // nmid
+        (13982, 6204), // hercon
+        (13987, 6207), // srarr
+        (13993, 6210), // loplus
+        (13995, 6213), // or
+        (14004, 6216), // lesseqgtr
+    ];
+
+    /// Displacements of each bucket of the perfect hash.
+    pub(super) static DISPLACEMENTS: [(u16, u16); 425] = [
+        (0, 1077),
+        (1, 53),
+        (1, 7),
+        (1, 0),
+        (1, 2),
+        (1, 2),
+        (1, 39),
+        (1, 92),
+        (0, 786),
+        (1, 8),
+        (0, 221),
+        (0, 0),
+        (1, 230),
+        (1, 151),
+        (1, 2),
+        (1, 36),
+        (1, 4),
+        (1, 72),
+        (0, 132),
+        (0, 828),
+        (1, 700),
+        (1, 64),
+        (1, 36),
+        (0, 813),
+        (1, 0),
+        (1, 703),
+        (1, 35),
+        (1, 35),
+        (1, 9),
+        (1, 5),
+        (1, 1),
+        (1, 253),
+        (1, 2),
+        (1, 135),
+        (0, 202),
+        (1, 4),
+        (1, 14),
+        (1, 0),
+        (1, 345),
+        (0, 381),
+        (1, 5),
+        (1, 0),
+        (1, 1),
+        (1, 0),
+        (1, 818),
+        (1, 122),
+        (0, 265),
+        (1, 21),
+        (0, 67),
+        (1, 291),
+        (1, 17),
+        (1, 594),
+        (1, 803),
+        (1, 72),
+        (1, 58),
+        (0, 944),
+        (1, 225),
+        (1, 13),
+        (1, 1546),
+        (1, 305),
+        (1, 1),
+        (0, 37),
+        (1, 667),
+        (1, 7),
+        (1, 3),
+        (1, 218),
+        (1, 0),
+        (1, 1),
+        (1, 24),
+        (1, 0),
+        (1, 210),
+        (1, 92),
+        (1, 4),
+        (1, 164),
+        (1, 304),
+        (0, 76),
+        (1, 26),
+        (1, 10),
+        (1, 115),
+        (1, 4),
+        (1, 117),
+        (0, 1123),
+        (1, 2),
+        (1, 8),
+        (1, 18),
+        (1, 271),
+        (1, 32),
+        (1, 13),
+        (1, 247),
+        (2, 16),
+        (1, 4),
+        (1, 747),
+        (1, 46),
+        (1, 3),
+        (1, 2),
+        (1, 41),
+        (1, 856),
+        (1, 399),
+        (1, 528),
+        (1, 20),
+        (1, 87),
+        (1, 709),
+        (0, 4),
+        (0, 707),
+        (1, 1),
+        (2, 2118),
+        (2, 42),
+        (1, 136),
+        (1, 1594),
+        (1, 995),
+        (1, 98),
+        (1, 13),
+        (1, 42),
+        (2, 500),
+        (1, 1048),
+        (1, 150),
+        (1, 0),
+        (1, 86),
+        (1, 11),
+        (1, 4),
+        (1, 757),
+        (0, 0),
+        (1, 0),
+        (1, 7),
+        (1, 17),
+        (0, 1277),
+        (1, 453),
+        (1, 6),
+        (1, 262),
+        (1, 1),
+        (1, 370),
+        (0, 1241),
+        (1, 676),
+        (1, 455),
+        (1, 1118),
+        (1, 9),
+        (1, 318),
+        (1, 1184),
+        (1, 123),
+        (1, 335),
+        (1, 28),
+        (1, 471),
+        (1, 5),
+        (1, 73),
+        (1, 4),
+        (1, 1),
+        (1, 1361),
+        (0, 141),
+        (1, 303),
+        (1, 326),
+        (1, 11),
+        (1, 0),
+        (1, 52),
+        (1, 752),
+        (1, 33),
+        (1, 44),
+        (1, 5),
+        (1, 342),
+        (1, 0),
+        (1, 7),
+        (1, 291),
+        (1, 8),
+        (1, 392),
+        (1, 126),
+        (1, 42),
+        (1, 1876),
+        (1, 0),
+        (1, 87),
+        (1, 0),
+        (1, 1122),
+        (1, 15),
+        (1, 0),
+        (1, 30),
+        (1, 0),
+        (1, 12),
+        (1, 57),
+        (1, 895),
+        (1, 1338),
+        (1, 1467),
+        (1, 28),
+        (1, 18),
+        (1, 283),
+        (1, 397),
+        (1, 17),
+        (1, 3),
+        (1, 1282),
+        (1, 17),
+        (1, 21),
+        (1, 50),
+        (1, 10),
+        (1, 858),
+        (1, 0),
+        (1, 0),
+        (1, 4),
+        (1, 23),
+        (1, 0),
+        (1, 435),
+        (1, 238),
+        (1, 268),
+        (1, 1944),
+        (1, 331),
+        (1, 4),
+        (0, 39),
+        (1, 1727),
+        (1, 35),
+        (1, 3),
+        (1, 27),
+        (1, 17),
+        (0, 826),
+        (1, 22),
+        (1, 197),
+        (1, 1784),
+        (1, 0),
+        (1, 1909),
+        (1, 67),
+        (1, 2),
+        (1, 11),
+        (1, 15),
+        (1, 678),
+        (1, 8),
+        (1, 2),
+        (2, 2094),
+        (1, 78),
+        (1, 0),
+        (1, 0),
+        (1, 512),
+        (1, 7),
+        (1, 523),
+        (1, 249),
+        (1, 1090),
+        (1, 86),
+        (1, 578),
+        (1, 155),
+        (1, 10),
+        (1, 49),
+        (1, 17),
+        (1, 74),
+        (1, 1),
+        (1, 1475),
+        (1, 0),
+        (1, 7),
+        (1, 58),
+        (1, 561),
+        (1, 325),
+        (1, 478),
+        (1, 588),
+        (1, 0),
+        (1, 485),
+        (1, 35),
+        (1, 484),
+        (1, 684),
+        (1, 0),
+        (2, 833),
+        (1, 201),
+        (0, 76),
+        (1, 121),
+        (1, 1336),
+        (1, 11),
+        (1, 16),
+        (1, 1323),
+        (1, 29),
+        (1, 1507),
+        (1, 32),
+        (1, 1227),
+        (2, 878),
+        (1, 976),
+        (1, 107),
+        (1, 112),
+        (1, 1832),
+        (1, 17),
+        (1, 0),
+        (4, 599),
+        (1, 450),
+        (1, 178),
+        (1, 779),
+        (1, 390),
+        (1, 102),
+        (1, 113),
+        (1, 1324),
+        (1, 7),
+        (0, 0),
+        (1, 138),
+        (1, 1),
+        (0, 892),
+        (1, 0),
+        (1, 43),
+        (1, 459),
+        (0, 982),
+        (1, 9),
+        (1, 248),
+        (1, 331),
+        (1, 5),
+        (1, 133),
+        (1, 61),
+        (1, 95),
+        (1, 190),
+        (1, 214),
+        (1, 0),
+        (1, 1359),
+        (1, 18),
+        (1, 22),
+        (1, 438),
+        (1, 30),
+        (1, 10),
+        (1, 633),
+        (2, 1132),
+        (1, 74),
+        (1, 8),
+        (1, 162),
+        (2, 699),
+        (1, 214),
+        (1, 221),
+        (5, 1766),
+        (0, 0),
+        (0, 1953),
+        (1, 153),
+        (1, 26),
+        (1, 59),
+        (1, 4),
+        (1, 491),
+        (2, 1499),
+        (1, 15),
+        (1, 1948),
+        (1, 56),
+        (1, 716),
+        (2, 1902),
+        (1, 1208),
+        (1, 16),
+        (1, 215),
+        (2, 1788),
+        (1, 34),
+        (1, 220),
+        (1, 326),
+        (1, 0),
+        (1, 0),
+        (1, 11),
+        (1, 1611),
+        (1, 1376),
+        (1, 893),
+        (1, 333),
+        (1, 475),
+        (1, 1316),
+        (8, 1562),
+        (1, 217),
+        (4, 1507),
+        (1, 27),
+        (1, 15),
+        (1, 924),
+        (7, 1083),
+        (0, 1219),
+        (1, 10),
+        (1, 8),
+        (5, 1711),
+        (1, 753),
+        (1, 1741),
+        (1, 815),
+        (1, 35),
+        (1, 208),
+        (1, 977),
+        (2, 152),
+        (3, 470),
+        (1, 32),
+        (1, 2053),
+        (1, 386),
+        (1, 45),
+        (1, 103),
+        (1, 430),
+        (1, 1087),
+        (2, 818),
+        (1, 662),
+        (1, 235),
+        (1, 0),
+        (4, 903),
+        (1, 47),
+        (4, 2053),
+        (5, 71),
+        (1, 734),
+        (1, 22),
+        (1, 1091),
+        (1, 2),
+        (1, 478),
+        (4, 470),
+        (1, 1822),
+        (1, 35),
+        (1, 186),
+        (4, 894),
+        (1, 288),
+        (2, 266),
+        (11, 1085),
+        (1, 47),
+        (1, 4),
+        (1, 343),
+        (1, 0),
+        (5, 913),
+        (2, 368),
+        (1, 56),
+        (1, 6),
+        (1, 11),
+        (1, 637),
+        (3, 608),
+        (7, 847),
+        (18, 1525),
+        (1, 9),
+        (12, 243),
+        (1, 76),
+        (1, 0),
+        (1, 132),
+        (1, 2),
+        (1, 14),
+        (1, 312),
+        (3, 1193),
+        (1, 2),
+        (3, 1692),
+        (1, 2014),
+        (1, 8),
+        (1, 1400),
+        (8, 186),
+        (1, 161),
+        (1, 653),
+        (1, 13),
+        (1, 60),
+        (11, 1528),
+        (1, 1),
+        (1, 5),
+        (1, 57),
+    ];
+}
+
+#[cfg(feature = "minimal-entities")]
+mod table {
+    /// Mixed into the hash, chosen so that every entity gets its own slot.
+    pub(super) const KEY: u64 = 3;
+
+    /// Entity names in slot order, concatenated.
+    pub(super) static NAMES: &[u8] = b"\
+        gtzwnjnbsptraderegampmdashlaquoldquouarrLTndashaposdividerarrenspeurolsquoPrimes\
+        ectmicroquotzwjparaprimedegrdquoshyharrminuspoundsbquobdquodarrdaggerthinspGTrsq\
+        uocentQUOTbulllsaquotimespermilraquoDaggerinfinyenltgeplusmnhellipneasympcopyems\
+        pmiddotAMPlelarrcurrenrsaquo";
+
+    /// Entity expansions, in the same order as the names.
+    pub(super) static VALUES: &str = "\
+        \u{003E}\u{200C}\u{00A0}\u{2122}\u{00AE}\u{0026}\u{2014}\u{00AB}\
+        \u{201C}\u{2191}\u{003C}\u{2013}\u{0027}\u{00F7}\u{2192}\u{2002}\
+        \u{20AC}\u{2018}\u{2033}\u{00A7}\u{00B5}\u{0022}\u{200D}\u{00B6}\
+        \u{2032}\u{00B0}\u{201D}\u{00AD}\u{2194}\u{2212}\u{00A3}\u{201A}\
+        \u{201E}\u{2193}\u{2020}\u{2009}\u{003E}\u{2019}\u{00A2}\u{0022}\
+        \u{2022}\u{2039}\u{00D7}\u{2030}\u{00BB}\u{2021}\u{221E}\u{00A5}\
+        \u{003C}\u{2265}\u{00B1}\u{2026}\u{2260}\u{2248}\u{00A9}\u{2003}\
+        \u{00B7}\u{0026}\u{2264}\u{2190}\u{00A4}\u{203A}";
+
+    /// End offsets of each entity in `NAMES` and `VALUES`; the start offsets are
+    /// the end offsets of the previous entity.
+    pub(super) static ENTRIES: [(u16, u16); 62] = [
+        (2, 1),     // gt
+        (6, 4),     // zwnj
+        (10, 6),    // nbsp
+        (15, 9),    // trade
+        (18, 11),   // reg
+        (21, 12),   // amp
+        (26, 15),   // mdash
+        (31, 17),   // laquo
+        (36, 20),   // ldquo
+        (40, 23),   // uarr
+        (42, 24),   // LT
+        (47, 27),   // ndash
+        (51, 28),   // apos
+        (57, 30),   // divide
+        (61, 33),   // rarr
+        (65, 36),   // ensp
+        (69, 39),   // euro
+        (74, 42),   // lsquo
+        (79, 45),   // Prime
+        (83, 47),   // sect
+        (88, 49),   // micro
+        (92, 50),   // quot
+        (95, 53),   // zwj
+        (99, 55),   // para
+        (104, 58),  // prime
+        (107, 60),  // deg
+        (112, 63),  // rdquo
+        (115, 65),  // shy
+        (119, 68),  // harr
+        (124, 71),  // minus
+        (129, 73),  // pound
+        (134, 76),  // sbquo
+        (139, 79),  // bdquo
+        (143, 82),  // darr
+        (149, 85),  // dagger
+        (155, 88),  // thinsp
+        (157, 89),  // GT
+        (162, 92),  // rsquo
+        (166, 94),  // cent
+        (170, 95),  // QUOT
+        (174, 98),  // bull
+        (180, 101), // lsaquo
+        (185, 103), // times
+        (191, 106), // permil
+        (196, 108), // raquo
+        (202, 111), // Dagger
+        (207, 114), // infin
+        (210, 116), // yen
+        (212, 117), // lt
+        (214, 120), // ge
+        (220, 122), // plusmn
+        (226, 125), // hellip
+        (228, 128), // ne
+        (233, 131), // asymp
+        (237, 133), // copy
+        (241, 136), // emsp
+        (247, 138), // middot
+        (250, 139), // AMP
+        (252, 142), // le
+        (256, 145), // larr
+        (262, 147), // curren
+        (268, 150), // rsaquo
+    ];
+
+    /// Displacements of each bucket of the perfect hash.
+    pub(super) static DISPLACEMENTS: [(u16, u16); 13] = [
+        (2, 32),
+        (1, 6),
+        (0, 0),
+        (0, 7),
+        (38, 49),
+        (8, 45),
+        (45, 11),
+        (0, 21),
+        (0, 3),
+        (6, 19),
+        (3, 2),
+        (0, 2),
+        (21, 26),
+    ];
+}
+
+use table::{DISPLACEMENTS, ENTRIES, KEY, NAMES, VALUES};
+
+fn entry(i: usize) -> (&'static [u8], &'static str) {
+    let (name_start, value_start) = match i.checked_sub(1) {
+        Some(prev) => ENTRIES[prev],
+        None => (0, 0),
+    };
+    let (name_end, value_end) = ENTRIES[i];
+    (
+        &NAMES[name_start as usize..name_end as usize],
+        &VALUES[value_start as usize..value_end as usize],
+    )
+}
+
+/// 64-bit FNV-1a with `KEY` mixed into the offset basis, as in the generator.
+fn hash(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325 ^ KEY, |h, &b| {
+        (h ^ u64::from(b)).wrapping_mul(0x0000_0100_0000_01b3)
+    })
+}
 
 pub(crate) fn get_entity(bytes: &[u8]) -> Option<&'static str> {
-    ENTITIES
-        .binary_search_by_key(&bytes, |&(key, _value)| key)
-        .ok()
-        .map(|i| ENTITIES[i].1)
+    let h = hash(bytes);
+    let (f1, f2) = (h as u32, (h >> 32) as u32);
+    let (d1, d2) = DISPLACEMENTS[f2 as usize % DISPLACEMENTS.len()];
+    let slot = u32::from(d2)
+        .wrapping_add(f1.wrapping_mul(u32::from(d1)))
+        .wrapping_add(f2) as usize
+        % ENTRIES.len();
+    let (name, value) = entry(slot);
+    (name == bytes).then_some(value)
 }
@@ -9,33 +9,62 @@ use unicase::UniCase;
 use crate::{
     linklabel::{scan_link_label_rest, LinkLabel},
     parse::{
-        scan_containers, Allocations, FootnoteDef, HeadingAttributes, Item, ItemBody, LinkDef,
-        LINK_MAX_NESTED_PARENS,
+        scan_containers, Allocations, AutolinkConfig, Diagnostic, DiagnosticKind, FootnoteDef,
+        HeadingAttributes, Item, ItemBody, LinkDef, LINK_MAX_NESTED_PARENS,
     },
     scanners::*,
     strings::CowStr,
     tree::{Tree, TreeIndex},
-    ContainerKind, HeadingLevel, MetadataBlockKind, Options,
+    Alignment, AutolinkKinds, BulletMarkers, ContainerKind, DisabledFeatures, HardBreakKind,
+    HeadingLevel, LinkType, MathDelimiters, MathSyntax, MetadataBlockKind, Options,
+    SmartPunctuation,
 };
 
-/// 运行第一遍解析，解决文档的块级结构，
-/// 并返回生成的树。
-pub(crate) fn run_first_pass(text: &str, options: Options) -> (Tree<Item>, Allocations<'_>) {
+/// 为第一遍解析创建空树。
+pub(crate) fn first_pass_tree(text: &str) -> Tree<Item> {
     // 这是对我们需要的节点数的一个非常简单的启发式估计。
-    let start_capacity = max(128, text.len() / 32);
-    let lookup_table = &create_lut(&options);
-    let first_pass = FirstPass {
-        text,
-        tree: Tree::with_capacity(start_capacity),
-        begin_list_item: None,
-        last_line_blank: false,
-        allocs: Allocations::new(),
-        options,
-        lookup_table,
-        brace_context_next: 0,
-        brace_context_stack: Vec::new(),
-    };
-    first_pass.run()
+    Tree::with_capacity(max(128, text.len() / 32))
+}
+
+/// 第一遍解析中[`Options`]之外的设置，由[`ParserBuilder`](crate::ParserBuilder)提供。
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct FirstPassConfig {
+    /// 按普通文本解析的核心语法。
+    pub disabled: DisabledFeatures,
+    /// 开始无序列表项的标记字符。
+    pub bullets: BulletMarkers,
+    /// 启用智能标点时的替换。
+    pub smart: SmartPunctuation,
+    /// 识别的自动链接字面量和去掉末尾字符的规则。
+    pub autolink: AutolinkConfig,
+    /// 数学公式的定界符。
+    pub math_delimiters: MathDelimiters,
+}
+
+impl Default for FirstPassConfig {
+    fn default() -> Self {
+        FirstPassConfig {
+            disabled: DisabledFeatures::empty(),
+            bullets: BulletMarkers::all(),
+            smart: SmartPunctuation::standard(),
+            autolink: AutolinkConfig::default(),
+            math_delimiters: MathDelimiters::default(),
+        }
+    }
+}
+
+/// 运行第一遍解析，解决文档的块级结构，并返回生成的树。
+///
+/// 使用给定的空树，复用它已分配的容量。
+pub(crate) fn run_first_pass_in(
+    text: &str,
+    options: Options,
+    config: FirstPassConfig,
+    tree: Tree<Item>,
+) -> (Tree<Item>, Allocations<'_>) {
+    #[cfg(feature = "tracing")]
+    let _span = tracing::debug_span!("first_pass", bytes = text.len()).entered();
+    FirstPass::new(text, options, config, tree).run()
 }
 
 // 每一级大括号嵌套都会向哈希表添加另一个条目。
@@ -47,32 +76,200 @@ pub(crate) fn run_first_pass(text: &str, options: Options) -> (Tree<Item>, Alloc
 // 这是更好的行为。
 const MATH_BRACE_CONTEXT_MAX_NESTING: usize = 25;
 
+/// 返回数学定界符所在的大括号上下文，必要时创建根上下文。
+fn math_brace_context(stack: &mut Vec<u8>, next: usize) -> u8 {
+    // 0xFFFF_FFFF... represents the root brace context. Using None would require
+    // storing Option<u8>, which is bigger than u8.
+    //
+    // These shouldn't conflict unless you have 255 levels of nesting, which is
+    // past the intended limit anyway.
+    //
+    // Unbalanced braces will cause the root to be changed, which is why it gets
+    // stored here.
+    if stack.len() > MATH_BRACE_CONTEXT_MAX_NESTING {
+        next as u8
+    } else {
+        stack.last().copied().unwrap_or_else(|| {
+            stack.push(!0);
+            !0
+        })
+    }
+}
+
 /// 第一遍解析的状态。
-struct FirstPass<'a, 'b> {
+pub(crate) struct FirstPass<'a> {
     text: &'a str,
+    /// 下一个块开始的偏移量。
+    ix: usize,
     tree: Tree<Item>,
     begin_list_item: Option<usize>,
     last_line_blank: bool,
     allocs: Allocations<'a>,
     options: Options,
-    lookup_table: &'b LookupTable,
+    /// 按普通文本解析的核心语法。
+    disabled: DisabledFeatures,
+    /// 开始无序列表项的标记字符。
+    bullets: BulletMarkers,
+    /// 启用的智能标点替换，未启用智能标点时为空。
+    smart: SmartPunctuation,
+    /// 识别的自动链接字面量和去掉末尾字符的规则。
+    autolink: AutolinkConfig,
+    /// 数学公式的定界符。
+    math_delimiters: MathDelimiters,
+    lookup_table: LookupTable,
     /// 数学环境的大括号嵌套。
     brace_context_stack: Vec<u8>,
     brace_context_next: usize,
+    /// 以可能的块标识符结尾的段落，记录为`(父节点, 段落, 块标识符)`。
+    /// 段落是否属于紧凑列表要到整个列表结束才知道，所以在最后才处理。
+    block_id_candidates: Vec<(Option<TreeIndex>, TreeIndex, TreeIndex)>,
 }
 
-impl<'a, 'b> FirstPass<'a, 'b> {
+impl<'a> FirstPass<'a> {
+    pub(crate) fn new(
+        text: &'a str,
+        options: Options,
+        config: FirstPassConfig,
+        tree: Tree<Item>,
+    ) -> Self {
+        let FirstPassConfig {
+            disabled,
+            bullets,
+            smart,
+            autolink,
+            math_delimiters,
+        } = config;
+        FirstPass {
+            text,
+            ix: 0,
+            tree,
+            begin_list_item: None,
+            last_line_blank: false,
+            allocs: Allocations::new(),
+            options,
+            disabled,
+            bullets,
+            smart: if options.contains(Options::ENABLE_SMART_PUNCTUATION) {
+                smart
+            } else {
+                SmartPunctuation::empty()
+            },
+            autolink,
+            math_delimiters,
+            lookup_table: create_lut(&options),
+            brace_context_next: 0,
+            brace_context_stack: Vec::new(),
+            block_id_candidates: Vec::new(),
+        }
+    }
+
     fn run(mut self) -> (Tree<Item>, Allocations<'a>) {
-        let mut ix = 0;
-        while ix < self.text.len() {
-            ix = self.parse_block(ix);
+        self.parse_blocks(usize::MAX);
+        self.finish()
+    }
+
+    /// 解析块，直到处理了至少`fuel`字节或到达文本末尾，返回是否已到达末尾。
+    ///
+    /// 每次至少解析一个块，一个块总是整体解析，所以处理的字节数可能超过`fuel`。
+    pub(crate) fn parse_blocks(&mut self, fuel: usize) -> bool {
+        let stop = self.ix.saturating_add(fuel);
+        while self.ix < self.text.len() {
+            self.ix = self.parse_block(self.ix);
+            if self.ix >= stop {
+                break;
+            }
         }
+        self.ix >= self.text.len()
+    }
+
+    /// 关闭所有仍然打开的块，返回生成的树。
+    pub(crate) fn finish(mut self) -> (Tree<Item>, Allocations<'a>) {
         while self.tree.spine_len() > 0 {
-            self.pop(ix);
+            self.pop(self.ix);
         }
+        self.attach_block_ids();
+        #[cfg(feature = "tracing")]
+        tracing::debug!(nodes = self.tree.node_count(), "block tree built");
         (self.tree, self.allocs)
     }
 
+    /// Moves the block ids that end paragraphs onto the tags of their blocks.
+    ///
+    /// The id of a tight paragraph goes to its list item, unless an earlier
+    /// paragraph of the item already has one. Ids that cannot be attached are
+    /// left in place and rendered as text.
+    fn attach_block_ids(&mut self) {
+        for (parent_ix, para_ix, id_ix) in core::mem::take(&mut self.block_id_candidates) {
+            let block_start = match (self.tree[para_ix].item.body, parent_ix) {
+                (ItemBody::Paragraph, _) => self.tree[para_ix].item.start,
+                (ItemBody::TightParagraph, Some(item_ix))
+                    if matches!(self.tree[item_ix].item.body, ItemBody::ListItem(_)) =>
+                {
+                    self.tree[item_ix].item.start
+                }
+                _ => continue,
+            };
+            if self.allocs.has_block_id(block_start) {
+                continue;
+            }
+            let id = &self.text[self.tree[id_ix].item.start + 1..self.tree[id_ix].item.end];
+            self.allocs.allocate_block_id(block_start, id.into());
+
+            // 从段落中移除块标识符及其前面的空白
+            let mut prev = None;
+            let mut node = self.tree[para_ix].child;
+            while let Some(node_ix) = node.filter(|&node_ix| node_ix != id_ix) {
+                prev = Some(node_ix);
+                node = self.tree[node_ix].next;
+            }
+            match prev {
+                Some(prev_ix) => {
+                    self.tree[prev_ix].next = None;
+                    if let ItemBody::Text { .. } = self.tree[prev_ix].item.body {
+                        let item = &mut self.tree[prev_ix].item;
+                        item.end = item.start + self.text[item.start..item.end].trim_end().len();
+                    }
+                }
+                None => self.tree[para_ix].child = None,
+            }
+        }
+    }
+
+    /// Scans a closing fence for an open container extension block, looking
+    /// past the lists that end at this line and inner blocks with longer fences.
+    ///
+    /// Returns the number of levels to pop from the tree.
+    fn scan_closing_container_fence(&self, line_start: &mut LineStart) -> Option<usize> {
+        for (i, &node_ix) in self.tree.walk_spine().rev().enumerate() {
+            match self.tree[node_ix].item.body {
+                ItemBody::Container(length, ..) => {
+                    if line_start.scan_closing_container_extensions_fence(length) {
+                        return Some(i + 1);
+                    }
+                }
+                // 未延续的列表会在这一行结束，不妨碍关闭外层容器
+                ItemBody::List(..) | ItemBody::DefinitionList(..) => (),
+                _ => return None,
+            }
+        }
+        None
+    }
+
+    /// Scans a setext heading underline, unless headings are disabled.
+    fn scan_setext_heading(&self, data: &[u8]) -> Option<(usize, HeadingLevel)> {
+        if self.disabled.contains(DisabledFeatures::HEADINGS) {
+            return None;
+        }
+        scan_setext_heading(data)
+    }
+
+    /// Records a diagnostic when they are enabled.
+    fn diagnose(&mut self, kind: DiagnosticKind, span: Range<usize>) {
+        if self.options.contains(Options::ENABLE_DIAGNOSTICS) {
+            self.allocs.diagnostics.push(Diagnostic { kind, span });
+        }
+    }
+
     /// 返回块之后的偏移量。
     fn parse_block(&mut self, mut start_ix: usize) -> usize {
         let bytes = self.text.as_bytes();
@@ -121,7 +318,14 @@ impl<'a, 'b> FirstPass<'a, 'b> {
                 }
             }
             let container_start = start_ix + line_start.bytes_scanned();
-            if let Some((ch, index, indent)) = line_start.scan_list_marker_with_indent(outer_indent)
+            // a closing container fence is not a definition marker
+            let definition_list = self.options.contains(Options::ENABLE_DEFINITION_LIST)
+                && !(self.options.contains(Options::ENABLE_CONTAINER_EXTENSIONS)
+                    && self
+                        .scan_closing_container_fence(&mut line_start.clone())
+                        .is_some());
+            if let Some((ch, index, indent)) =
+                line_start.scan_list_marker_with_indent(outer_indent, self.bullets)
             {
                 let after_marker_index = start_ix + line_start.bytes_scanned();
                 self.continue_list(container_start - outer_indent, ch, index);
@@ -154,9 +358,7 @@ impl<'a, 'b> FirstPass<'a, 'b> {
                         }
                     }
                 }
-            } else if let Some((indent, child, item)) = self
-                .options
-                .contains(Options::ENABLE_DEFINITION_LIST)
+            } else if let Some((indent, child, item)) = definition_list
                 .then(|| {
                     self.tree
                         .cur()
@@ -224,16 +426,23 @@ impl<'a, 'b> FirstPass<'a, 'b> {
                     return after_marker_index + n;
                 }
             } else if line_start.scan_blockquote_marker() {
-                let kind = if self.options.contains(Options::ENABLE_GFM) {
+                let tag = if self.options.contains(Options::ENABLE_GFM) {
                     line_start.scan_blockquote_tag()
                 } else {
                     None
                 };
+                let kind = tag.as_ref().map(|(kind, ..)| *kind);
+                let callout = tag.and_then(|(_, folded, title)| {
+                    let title = (!title.is_empty())
+                        .then(|| self.text[start_ix + title.start..start_ix + title.end].into());
+                    (folded.is_some() || title.is_some())
+                        .then(|| self.allocs.allocate_callout(folded, title))
+                });
                 self.finish_list(start_ix);
                 self.tree.append(Item {
                     start: container_start,
                     end: 0, // will get set later
-                    body: ItemBody::BlockQuote(kind),
+                    body: ItemBody::BlockQuote(kind, callout),
                 });
                 self.tree.push();
                 if kind.is_some() {
@@ -293,37 +502,42 @@ impl<'a, 'b> FirstPass<'a, 'b> {
                         let mut summary_start = kind_start + kind_length;
                         summary_start += scan_whitespace_no_nl(&bytes[summary_start..]);
                         let line_end = summary_start + scan_nextline(&bytes[summary_start..]);
-                        let summary_end = line_end
+                        let mut summary_end = line_end
                             - scan_rev_while(&bytes[summary_start..line_end], is_ascii_whitespace);
-                        if kind.eq_ignore_ascii_case("spoiler") {
+                        // 信息字符串末尾的属性块，与标题属性的写法相同
+                        let (content_len, attr_block) =
+                            extract_attribute_block_content_from_header_text(
+                                &bytes[summary_start..summary_end],
+                            );
+                        let attributes = attr_block.and_then(|r| {
+                            parse_inside_attribute_block(
+                                &self.text[(summary_start + r.start)..(summary_start + r.end)],
+                            )
+                        });
+                        summary_end = summary_start + content_len;
+                        summary_end -=
+                            scan_rev_while(&bytes[summary_start..summary_end], is_ascii_whitespace);
+                        let (container_kind, cow_ix) = if kind.eq_ignore_ascii_case("spoiler") {
                             let summary = unescape(
                                 &self.text[summary_start..summary_end],
                                 self.tree.is_in_table(),
                             );
-                            let summary_cow_ix = self.allocs.allocate_cow(summary);
-                            self.tree.append(Item {
-                                start: container_start,
-                                end: 0,
-                                body: ItemBody::Container(
-                                    fence_length as u8,
-                                    ContainerKind::Spoiler,
-                                    summary_cow_ix,
-                                ),
-                            });
+                            (ContainerKind::Spoiler, self.allocs.allocate_cow(summary))
                         } else {
-                            let kind_cow_ix = self.allocs.allocate_cow(kind);
-                            self.tree.append(Item {
-                                start: container_start,
-                                end: 0,
-                                body: ItemBody::Container(
-                                    fence_length as u8,
-                                    ContainerKind::Default,
-                                    kind_cow_ix,
-                                ),
-                            });
+                            (ContainerKind::Default, self.allocs.allocate_cow(kind))
+                        };
+                        if let Some(attributes) = attributes {
+                            self.allocs
+                                .allocate_container_attributes(cow_ix, attributes);
                         }
+                        self.finish_list(start_ix);
+                        self.tree.append(Item {
+                            start: container_start,
+                            end: 0,
+                            body: ItemBody::Container(fence_length as u8, container_kind, cow_ix),
+                        });
                         self.tree.push();
-                        return summary_end + 1;
+                        return line_end;
                     }
                 }
             } else {
@@ -333,24 +547,14 @@ impl<'a, 'b> FirstPass<'a, 'b> {
         }
 
         if self.options.contains(Options::ENABLE_CONTAINER_EXTENSIONS) {
-            let mut pop_count = None;
-            for (i, &node_ix) in self.tree.walk_spine().rev().enumerate() {
-                match self.tree[node_ix].item.body {
-                    ItemBody::Container(length, ..) => {
-                        if line_start.scan_closing_container_extensions_fence(length) {
-                            pop_count = Some(i + 1);
-                            break;
+            if let Some(c) = self.scan_closing_container_fence(&mut line_start) {
+                for _ in 0..c {
+                    match self.tree.peek_up().map(|ix| &self.tree[ix].item.body) {
+                        Some(ItemBody::List(..) | ItemBody::DefinitionList(..)) => {
+                            self.finish_list(start_ix)
                         }
+                        _ => self.pop(start_ix),
                     }
-                    _ => {
-                        break;
-                    }
-                }
-            }
-
-            if let Some(c) = pop_count {
-                for _ in 0..c {
-                    self.pop(start_ix);
                 }
             }
         }
@@ -409,7 +613,7 @@ impl<'a, 'b> FirstPass<'a, 'b> {
         }
 
         // HTML Blocks
-        if bytes[ix] == b'<' {
+        if bytes[ix] == b'<' && !self.disabled.contains(DisabledFeatures::RAW_HTML) {
             // Types 1-5 are all detected by one function and all end with the same
             // pattern
             if let Some(html_end_tag) = get_html_end_tag(&bytes[(ix + 1)..]) {
@@ -440,7 +644,9 @@ impl<'a, 'b> FirstPass<'a, 'b> {
             return self.parse_hrule(n, ix);
         }
 
-        if let Some(atx_size) = scan_atx_heading(&bytes[ix..]) {
+        if let Some(atx_size) = scan_atx_heading(&bytes[ix..])
+            .filter(|_| !self.disabled.contains(DisabledFeatures::HEADINGS))
+        {
             self.finish_list(start_ix);
             return self.parse_atx_heading(ix, atx_size);
         }
@@ -454,8 +660,16 @@ impl<'a, 'b> FirstPass<'a, 'b> {
         while let Some((bytecount, label, link_def)) =
             self.parse_refdef_total(start_ix + line_start.bytes_scanned())
         {
+            let refdef_count = self.allocs.refdefs.0.len();
             self.allocs.refdefs.0.entry(label).or_insert(link_def);
             let container_start = start_ix + line_start.bytes_scanned();
+            if self.allocs.refdefs.0.len() == refdef_count {
+                // the first definition of a label wins
+                self.diagnose(
+                    DiagnosticKind::DuplicateReferenceDefinition,
+                    container_start..container_start + bytecount,
+                );
+            }
             let mut ix = container_start + bytecount;
             // Refdefs act as if they were contained within a paragraph, for purposes of lazy
             // continuations. For example:
@@ -532,30 +746,136 @@ impl<'a, 'b> FirstPass<'a, 'b> {
         // https://github.com/raphlinus/pulldown-cmark/issues/832
         let mut missing_empty_cells = 0;
         // parse header. this shouldn't fail because we made sure the table header is ok
-        let (_sep_start, thead_ix) =
+        let (_sep_start, thead_ix, _) =
             self.parse_table_row_inner(head_start, table_cols, &mut missing_empty_cells)?;
         self.tree[thead_ix].item.body = ItemBody::TableHead;
 
         // parse body
         let mut ix = body_start;
-        while let Some((next_ix, _row_ix)) =
-            self.parse_table_row(ix, table_cols, &mut missing_empty_cells)
-        {
+        let mut foot = None;
+        loop {
+            if foot.is_none() && self.options.contains(Options::ENABLE_TABLE_FOOTERS) {
+                if let Some(next_ix) = self.scan_table_foot(ix, table_cols) {
+                    // the rows after a second delimiter row make up the footer
+                    let prev_ix = self.tree.cur();
+                    let foot_ix = self.tree.append(Item {
+                        start: ix,
+                        end: 0, // set when the table ends
+                        body: ItemBody::TableFoot,
+                    });
+                    self.tree.push();
+                    foot = Some((prev_ix, foot_ix));
+                    ix = next_ix;
+                    continue;
+                }
+            }
+            let prev_ix = self.tree.cur();
+            let Some((next_ix, mut row_ix, mut continuation)) =
+                self.parse_table_row(ix, table_cols, &mut missing_empty_cells)
+            else {
+                break;
+            };
             ix = next_ix;
+            // a row ending in a backslash continues on the next line
+            while let Some(marker_ix) = continuation {
+                if self.scan_table_delimiter_row(ix).is_some() {
+                    // a delimiter row is never part of a cell
+                    break;
+                }
+                let Some((next_ix, next_row_ix, next_continuation)) =
+                    self.parse_table_row(ix, table_cols, &mut missing_empty_cells)
+                else {
+                    break;
+                };
+                self.merge_table_rows(prev_ix, row_ix, next_row_ix, marker_ix);
+                (ix, row_ix, continuation) = (next_ix, next_row_ix, next_continuation);
+            }
         }
 
+        if let Some((prev_ix, foot_ix)) = foot {
+            self.pop(ix);
+            if let (Some(prev_ix), None) = (prev_ix, self.tree[foot_ix].child) {
+                // no rows after the delimiter row, drop the empty footer
+                self.tree[prev_ix].next = None;
+            }
+        }
         self.pop(ix);
         Some(ix)
     }
 
+    /// Merges the cells of `row_ix` into the cells of the following row
+    /// `next_ix`, separated by a hard break, and removes `row_ix`.
+    /// See [`Options::ENABLE_MULTILINE_TABLE_CELLS`].
+    fn merge_table_rows(
+        &mut self,
+        prev_ix: Option<TreeIndex>,
+        row_ix: TreeIndex,
+        next_ix: TreeIndex,
+        marker_ix: usize,
+    ) {
+        let mut cell = self.tree[row_ix].child;
+        let mut next_cell = self.tree[next_ix].child;
+        while let (Some(cell_ix), Some(next_cell_ix)) = (cell, next_cell) {
+            if let Some(first_ix) = self.tree[cell_ix].child {
+                let mut last_ix = first_ix;
+                while let Some(ix) = self.tree[last_ix].next {
+                    last_ix = ix;
+                }
+                if let Some(next_first_ix) = self.tree[next_cell_ix].child {
+                    let break_ix = self.tree.create_node(Item {
+                        start: marker_ix,
+                        end: marker_ix + 1,
+                        body: ItemBody::HardBreak(HardBreakKind::Backslash),
+                    });
+                    self.tree[last_ix].next = Some(break_ix);
+                    self.tree[break_ix].next = Some(next_first_ix);
+                }
+                self.tree[next_cell_ix].child = Some(first_ix);
+            }
+            self.tree[next_cell_ix].item.start = self.tree[cell_ix].item.start;
+            cell = self.tree[cell_ix].next;
+            next_cell = self.tree[next_cell_ix].next;
+        }
+        self.tree[next_ix].item.start = self.tree[row_ix].item.start;
+        match prev_ix {
+            Some(prev_ix) => self.tree[prev_ix].next = Some(next_ix),
+            None => {
+                let parent_ix = self.tree.peek_up().unwrap();
+                self.tree[parent_ix].child = Some(next_ix);
+            }
+        }
+    }
+
+    /// Returns the offset after a delimiter row that starts a table footer,
+    /// see [`Options::ENABLE_TABLE_FOOTERS`].
+    fn scan_table_foot(&self, ix: usize, table_cols: usize) -> Option<usize> {
+        self.scan_table_delimiter_row(ix)
+            .and_then(|(end, cols)| (cols == table_cols).then_some(end))
+    }
+
+    /// Checks whether the line starting at `ix` is a table delimiter row,
+    /// returning the offset after it and its number of columns.
+    fn scan_table_delimiter_row(&self, ix: usize) -> Option<(usize, usize)> {
+        let bytes = self.text.as_bytes();
+        let mut line_start = LineStart::new(&bytes[ix..]);
+        let tree_position = scan_containers(&self.tree, &mut line_start, self.options);
+        if tree_position != self.tree.spine_len() {
+            return None;
+        }
+        let start = ix + line_start.bytes_scanned();
+        let (bytes_scanned, alignments) = scan_table_head(&bytes[start..]);
+        (bytes_scanned > 0).then_some((start + bytes_scanned, alignments.len()))
+    }
+
     /// Call this when containers are taken care of.
-    /// Returns bytes scanned, row_ix
+    /// Returns bytes scanned, row_ix, and the offset of the backslash if the
+    /// row continues on the next line.
     fn parse_table_row_inner(
         &mut self,
         mut ix: usize,
         row_cells: usize,
         missing_empty_cells: &mut usize,
-    ) -> Option<(usize, TreeIndex)> {
+    ) -> Option<(usize, TreeIndex, Option<usize>)> {
         // Limit to prevent a malicious input from causing a denial of service.
         const MAX_AUTOCOMPLETED_CELLS: usize = 1 << 18; // = 0x40000
 
@@ -571,9 +891,15 @@ impl<'a, 'b> FirstPass<'a, 'b> {
         });
         self.tree.push();
 
+        let cell_alignment = self.options.contains(Options::ENABLE_CELL_ALIGNMENT);
+        let multiline = self.options.contains(Options::ENABLE_MULTILINE_TABLE_CELLS);
+        let mut continuation = None;
         loop {
-            ix += scan_ch(&bytes[ix..], b'|');
+            let pipe = scan_ch(&bytes[ix..], b'|');
+            ix += pipe;
             let start_ix = ix;
+            // a colon right after the pipe aligns the cell to the left
+            let leading_colon = cell_alignment && pipe == 1 && bytes.get(ix) == Some(&b':');
             ix += scan_whitespace_no_nl(&bytes[ix..]);
 
             if let Some(eol_bytes) = scan_eol(&bytes[ix..]) {
@@ -581,17 +907,44 @@ impl<'a, 'b> FirstPass<'a, 'b> {
                 break;
             }
 
+            if multiline && pipe == 1 && cells > 0 && bytes[ix] == b'\\' {
+                let after = ix + 1 + scan_whitespace_no_nl(&bytes[ix + 1..]);
+                if let Some(eol_bytes) = scan_eol(&bytes[after..]) {
+                    continuation = Some(ix);
+                    ix = after + eol_bytes;
+                    break;
+                }
+            }
+
             let cell_ix = self.tree.append(Item {
                 start: start_ix,
                 end: ix,
-                body: ItemBody::TableCell,
+                body: ItemBody::TableCell(None),
             });
             self.tree.push();
+            if leading_colon {
+                ix += 1;
+            }
             let (next_ix, _brk) = self.parse_line(ix, None, TableParseMode::Active);
 
             self.tree[cell_ix].item.end = next_ix;
             self.tree.pop();
 
+            // and a colon right before the pipe to the right
+            let trailing_colon = cell_alignment
+                && next_ix > ix
+                && bytes.get(next_ix) == Some(&b'|')
+                && bytes[next_ix - 1] == b':'
+                && !bytes[..next_ix - 1].ends_with(b"\\")
+                && self.trim_cell_colon(cell_ix, next_ix);
+            let alignment = match (leading_colon, trailing_colon) {
+                (true, true) => Some(Alignment::Center),
+                (true, false) => Some(Alignment::Left),
+                (false, true) => Some(Alignment::Right),
+                (false, false) => None,
+            };
+            self.tree[cell_ix].item.body = ItemBody::TableCell(alignment);
+
             ix = next_ix;
             cells += 1;
 
@@ -617,27 +970,65 @@ impl<'a, 'b> FirstPass<'a, 'b> {
             self.tree.append(Item {
                 start: ix,
                 end: ix,
-                body: ItemBody::TableCell,
+                body: ItemBody::TableCell(None),
             });
         }
 
         // drop excess cells
         if let Some(cell_ix) = final_cell_ix {
+            if cells > row_cells {
+                self.diagnose(
+                    DiagnosticKind::ExcessTableCells {
+                        expected: row_cells,
+                        found: cells,
+                    },
+                    self.tree[row_ix].item.start..ix,
+                );
+            }
             self.tree[cell_ix].next = None;
         }
 
         self.pop(ix);
 
-        Some((ix, row_ix))
+        Some((ix, row_ix, continuation))
+    }
+
+    /// Removes the alignment colon ending at `colon_end` from the last text
+    /// of a table cell, along with the whitespace before it. Returns false
+    /// if the colon is not part of a text item.
+    fn trim_cell_colon(&mut self, cell_ix: TreeIndex, colon_end: usize) -> bool {
+        let bytes = self.text.as_bytes();
+        let mut prev_ix = None;
+        let Some(mut last_ix) = self.tree[cell_ix].child else {
+            return false;
+        };
+        while let Some(next_ix) = self.tree[last_ix].next {
+            prev_ix = Some(last_ix);
+            last_ix = next_ix;
+        }
+        let item = &mut self.tree[last_ix].item;
+        if !matches!(item.body, ItemBody::Text { .. }) || item.end != colon_end {
+            return false;
+        }
+        let content = &bytes[item.start..colon_end - 1];
+        item.end = colon_end - 1 - scan_rev_while(content, is_ascii_whitespace_no_nl);
+        if item.start == item.end {
+            match prev_ix {
+                Some(prev_ix) => self.tree[prev_ix].next = None,
+                None => self.tree[cell_ix].child = None,
+            }
+        }
+        true
     }
 
-    /// Returns first offset after the row and the tree index of the row.
+    /// Returns first offset after the row, the tree index of the row, and
+    /// the offset of the backslash if the row continues on the next line.
     fn parse_table_row(
         &mut self,
         mut ix: usize,
         row_cells: usize,
         missing_empty_cells: &mut usize,
-    ) -> Option<(usize, TreeIndex)> {
+    ) -> Option<(usize, TreeIndex, Option<usize>)> {
         let bytes = self.text.as_bytes();
         let mut line_start = LineStart::new(&bytes[ix..]);
         let tree_position = scan_containers(&self.tree, &mut line_start, self.options);
@@ -650,16 +1041,16 @@ impl<'a, 'b> FirstPass<'a, 'b> {
         if scan_paragraph_interrupt_no_table(
             &bytes[ix..],
             current_container,
-            self.options.contains(Options::ENABLE_FOOTNOTES),
-            self.options.contains(Options::ENABLE_DEFINITION_LIST),
+            self.options,
+            self.disabled,
+            self.bullets,
             &self.tree,
             tree_position,
         ) {
             return None;
         }
 
-        let (ix, row_ix) = self.parse_table_row_inner(ix, row_cells, missing_empty_cells)?;
-        Some((ix, row_ix))
+        self.parse_table_row_inner(ix, row_cells, missing_empty_cells)
     }
 
     /// Returns offset of line start after paragraph.
@@ -734,7 +1125,7 @@ impl<'a, 'b> FirstPass<'a, 'b> {
             let trailing_backslash_pos = match brk {
                 Some(Item {
                     start,
-                    body: ItemBody::HardBreak(true),
+                    body: ItemBody::HardBreak(HardBreakKind::Backslash),
                     ..
                 }) if bytes[start] == b'\\' => Some(start),
                 _ => None,
@@ -815,7 +1206,7 @@ impl<'a, 'b> FirstPass<'a, 'b> {
         has_trailing_content: bool,
     ) -> Option<usize> {
         let bytes = self.text.as_bytes();
-        let (n, level) = scan_setext_heading(&bytes[ix..])?;
+        let (n, level) = self.scan_setext_heading(&bytes[ix..])?;
         let mut attrs = None;
 
         if let Some(cur_ix) = self.tree.cur() {
@@ -877,6 +1268,29 @@ impl<'a, 'b> FirstPass<'a, 'b> {
         Some(ix + n)
     }
 
+    /// Appends an autolink literal spanning `range`, without borrowing `self`.
+    fn append_autolink_literal(
+        tree: &mut Tree<Item>,
+        allocs: &mut Allocations<'a>,
+        text: &'a str,
+        range: Range<usize>,
+    ) {
+        let text = &text[range.clone()];
+        let (link_type, dest_url) = if text.starts_with("www.") {
+            (LinkType::AutolinkLiteral, format!("http://{}", text).into())
+        } else if text.contains(':') {
+            (LinkType::AutolinkLiteral, text.into())
+        } else {
+            (LinkType::EmailLiteral, text.into())
+        };
+        let link_ix = allocs.allocate_link(link_type, dest_url, "".into(), "".into());
+        tree.append(Item {
+            start: range.start,
+            end: range.end,
+            body: ItemBody::MaybeAutolinkLiteral(link_ix),
+        });
+    }
+
     /// Parse a line of input, appending text and items to tree.
     ///
     /// Returns: index after line and an item representing the break.
@@ -897,7 +1311,35 @@ impl<'a, 'b> FirstPass<'a, 'b> {
         let mut begin_text = start;
         let mut backslash_escaped = false;
 
-        let (final_ix, brk) = iterate_special_bytes(self.lookup_table, bytes, start, |ix, byte| {
+        let lookup_table = &self.lookup_table;
+        let (final_ix, brk) = iterate_special_bytes(lookup_table, bytes, start, |ix, byte| {
+            if matches!(byte, b':' | b'.' | b'@')
+                && self.options.contains(Options::ENABLE_AUTOLINK_LITERALS)
+            {
+                let link = if byte == b'@' {
+                    self.autolink
+                        .kinds
+                        .contains(AutolinkKinds::EMAIL)
+                        .then(|| scan_email_literal(bytes, ix, start, begin_text))
+                        .flatten()
+                } else {
+                    let AutolinkConfig { kinds, trimming } = self.autolink;
+                    scan_autolink_literal(bytes, ix, start, begin_text, kinds, trimming)
+                };
+                if let Some(link) = link {
+                    self.tree
+                        .append_text(begin_text, link.start, backslash_escaped);
+                    backslash_escaped = false;
+                    Self::append_autolink_literal(
+                        &mut self.tree,
+                        &mut self.allocs,
+                        self.text,
+                        link.clone(),
+                    );
+                    begin_text = link.end;
+                    return LoopInstruction::ContinueAndSkip(link.end - ix - 1);
+                }
+            }
             match byte {
                 b'\n' | b'\r' => {
                     if let TableParseMode::Active = mode {
@@ -918,7 +1360,7 @@ impl<'a, 'b> FirstPass<'a, 'b> {
                             Some(Item {
                                 start: i,
                                 end: end_ix,
-                                body: ItemBody::HardBreak(true),
+                                body: ItemBody::HardBreak(HardBreakKind::Backslash),
                             }),
                         );
                     }
@@ -967,7 +1409,7 @@ impl<'a, 'b> FirstPass<'a, 'b> {
                             Some(Item {
                                 start: i,
                                 end: end_ix,
-                                body: ItemBody::HardBreak(false),
+                                body: ItemBody::HardBreak(HardBreakKind::Spaces),
                             }),
                         );
                     }
@@ -992,7 +1434,26 @@ impl<'a, 'b> FirstPass<'a, 'b> {
                         .is_some_and(is_ascii_punctuation) =>
                 {
                     self.tree.append_text(begin_text, ix, backslash_escaped);
-                    if bytes[ix + 1] == b'`' {
+                    if self.math_delimiters == MathDelimiters::Brackets
+                        && self.options.contains(Options::ENABLE_MATH)
+                        && matches!(bytes[ix + 1], b'(' | b')' | b'[' | b']')
+                    {
+                        // Each delimiter is a single item; the inline pass tells `\[` and
+                        // `\]` apart from `\(` and `\)` by their second byte.
+                        let opens = matches!(bytes[ix + 1], b'(' | b'[');
+                        let brace_context = math_brace_context(
+                            &mut self.brace_context_stack,
+                            self.brace_context_next,
+                        );
+                        self.tree.append(Item {
+                            start: ix,
+                            end: ix + 2,
+                            body: ItemBody::MaybeMath(opens, !opens, brace_context),
+                        });
+                        begin_text = ix + 2;
+                        backslash_escaped = false;
+                        LoopInstruction::ContinueAndSkip(1)
+                    } else if bytes[ix + 1] == b'`' {
                         let count = 1 + scan_ch_repeat(&bytes[(ix + 2)..], b'`');
                         self.tree.append(Item {
                             start: ix + 1,
@@ -1027,6 +1488,25 @@ impl<'a, 'b> FirstPass<'a, 'b> {
                         LoopInstruction::ContinueAndSkip(1)
                     }
                 }
+                b'^' if self.options.contains(Options::ENABLE_BLOCK_IDS)
+                    && ix > start
+                    && is_ascii_whitespace(bytes[ix - 1])
+                    && scan_block_id(&bytes[ix..bytes_len]).is_some() =>
+                {
+                    let n = scan_block_id(&bytes[ix..bytes_len]).unwrap();
+                    self.tree.append_text(begin_text, ix, backslash_escaped);
+                    backslash_escaped = false;
+                    self.tree.append(Item {
+                        start: ix,
+                        end: ix + n,
+                        body: ItemBody::MaybeBlockId,
+                    });
+                    begin_text = ix + n;
+                    LoopInstruction::ContinueAndSkip(n - 1)
+                }
+                b'^' if !self.options.contains(Options::ENABLE_SUPERSCRIPT) => {
+                    LoopInstruction::ContinueAndSkip(0)
+                }
                 c @ b'*' | c @ b'_' | c @ b'~' | c @ b'^' => {
                     let string_suffix = &self.text[ix..];
                     let count = 1 + scan_ch_repeat(&string_suffix.as_bytes()[1..], c);
@@ -1062,7 +1542,7 @@ impl<'a, 'b> FirstPass<'a, 'b> {
                     }
                     LoopInstruction::ContinueAndSkip(count - 1)
                 }
-                b'$' => {
+                b'$' if self.math_delimiters == MathDelimiters::Dollars => {
                     let byte_suffix = &bytes[ix..];
                     let can_open = !byte_suffix[1..]
                         .first()
@@ -1073,24 +1553,8 @@ impl<'a, 'b> FirstPass<'a, 'b> {
                             .last()
                             .copied()
                             .map_or(true, is_ascii_whitespace);
-
-                    // 0xFFFF_FFFF... represents the root brace context. Using None would require
-                    // storing Option<u8>, which is bigger than u8.
-                    //
-                    // These shouldn't conflict unless you have 255 levels of nesting, which is
-                    // past the intended limit anyway.
-                    //
-                    // Unbalanced braces will cause the root to be changed, which is why it gets
-                    // stored here.
                     let brace_context =
-                        if self.brace_context_stack.len() > MATH_BRACE_CONTEXT_MAX_NESTING {
-                            self.brace_context_next as u8
-                        } else {
-                            self.brace_context_stack.last().copied().unwrap_or_else(|| {
-                                self.brace_context_stack.push(!0);
-                                !0
-                            })
-                        };
+                        math_brace_context(&mut self.brace_context_stack, self.brace_context_next);
 
                     self.tree.append_text(begin_text, ix, backslash_escaped);
                     self.tree.append(Item {
@@ -1172,7 +1636,25 @@ impl<'a, 'b> FirstPass<'a, 'b> {
                     begin_text = ix + 1;
                     LoopInstruction::ContinueAndSkip(0)
                 }
-                b'!' if bytes.get(ix + 1) == Some(&b'[') => {
+                b'#' if ix == start || is_ascii_whitespace(bytes[ix - 1]) => {
+                    match scan_hashtag(&self.text[ix..bytes_len]) {
+                        Some(n) => {
+                            self.tree.append_text(begin_text, ix, backslash_escaped);
+                            backslash_escaped = false;
+                            self.tree.append(Item {
+                                start: ix,
+                                end: ix + n,
+                                body: ItemBody::Hashtag,
+                            });
+                            begin_text = ix + n;
+                            LoopInstruction::ContinueAndSkip(n - 1)
+                        }
+                        None => LoopInstruction::ContinueAndSkip(0),
+                    }
+                }
+                b'!' if bytes.get(ix + 1) == Some(&b'[')
+                    && !self.disabled.contains(DisabledFeatures::IMAGES) =>
+                {
                     self.tree.append_text(begin_text, ix, backslash_escaped);
                     backslash_escaped = false;
                     self.tree.append(Item {
@@ -1230,7 +1712,9 @@ impl<'a, 'b> FirstPass<'a, 'b> {
                         LoopInstruction::ContinueAndSkip(0)
                     }
                 }
-                b'.' if matches!(bytes.get(ix + 1..), Some(&[b'.', b'.', ..])) => {
+                b'.' if self.smart.contains(SmartPunctuation::ELLIPSES)
+                    && matches!(bytes.get(ix + 1..), Some(&[b'.', b'.', ..])) =>
+                {
                     self.tree.append_text(begin_text, ix, backslash_escaped);
                     backslash_escaped = false;
                     self.tree.append(Item {
@@ -1241,7 +1725,7 @@ impl<'a, 'b> FirstPass<'a, 'b> {
                     begin_text = ix + 3;
                     LoopInstruction::ContinueAndSkip(2)
                 }
-                b'-' => {
+                b'-' if self.smart.contains(SmartPunctuation::DASHES) => {
                     let count = 1 + scan_ch_repeat(&bytes[(ix + 1)..], b'-');
                     if count == 1 {
                         LoopInstruction::ContinueAndSkip(0)
@@ -1279,7 +1763,20 @@ impl<'a, 'b> FirstPass<'a, 'b> {
                         LoopInstruction::ContinueAndSkip(count - 1)
                     }
                 }
-                c @ b'\'' | c @ b'"' => {
+                b'\''
+                    if self.smart.contains(SmartPunctuation::STRAIGHT_APOSTROPHES)
+                        && self.text[start..ix]
+                            .chars()
+                            .next_back()
+                            .is_some_and(char::is_alphanumeric)
+                        && self.text[ix + 1..bytes_len]
+                            .chars()
+                            .next()
+                            .is_some_and(char::is_alphanumeric) =>
+                {
+                    LoopInstruction::ContinueAndSkip(0)
+                }
+                c @ b'\'' | c @ b'"' if self.smart.contains(SmartPunctuation::QUOTES) => {
                     let string_suffix = &self.text[ix..];
                     let can_open = delim_run_can_open(
                         &self.text[start..],
@@ -1309,6 +1806,37 @@ impl<'a, 'b> FirstPass<'a, 'b> {
 
                     LoopInstruction::ContinueAndSkip(0)
                 }
+                b'/' if self.smart.contains(SmartPunctuation::FRACTIONS)
+                    && ix > start
+                    && begin_text < ix
+                    && scan_fraction(&bytes[start..bytes_len], ix - start).is_some() =>
+                {
+                    let fraction = scan_fraction(&bytes[start..bytes_len], ix - start).unwrap();
+                    self.tree.append_text(begin_text, ix - 1, backslash_escaped);
+                    backslash_escaped = false;
+                    self.tree.append(Item {
+                        start: ix - 1,
+                        end: ix + 2,
+                        body: ItemBody::SynthesizeChar(fraction),
+                    });
+                    begin_text = ix + 2;
+                    LoopInstruction::ContinueAndSkip(1)
+                }
+                b'(' if self.smart.contains(SmartPunctuation::SYMBOLS)
+                    && (ix == start || bytes[ix - 1] != b']')
+                    && scan_symbol(&bytes[ix..bytes_len]).is_some() =>
+                {
+                    let (symbol, n) = scan_symbol(&bytes[ix..bytes_len]).unwrap();
+                    self.tree.append_text(begin_text, ix, backslash_escaped);
+                    backslash_escaped = false;
+                    self.tree.append(Item {
+                        start: ix,
+                        end: ix + n,
+                        body: ItemBody::SynthesizeChar(symbol),
+                    });
+                    begin_text = ix + n;
+                    LoopInstruction::ContinueAndSkip(n - 1)
+                }
                 _ => LoopInstruction::ContinueAndSkip(0),
             }
         });
@@ -1489,7 +2017,19 @@ impl<'a, 'b> FirstPass<'a, 'b> {
         let mut ix = info_start + scan_nextline(&bytes[info_start..]);
         let info_end = ix - scan_rev_while(&bytes[info_start..ix], is_ascii_whitespace);
         let info_string = unescape(&self.text[info_start..info_end], self.tree.is_in_table());
-        self.tree.append(Item {
+        let math_syntax = if self
+            .options
+            .contains(Options::ENABLE_MATH | Options::ENABLE_ASCIIMATH)
+        {
+            match &*info_string {
+                "math" => Some(MathSyntax::Tex),
+                "asciimath" => Some(MathSyntax::AsciiMath),
+                _ => None,
+            }
+        } else {
+            None
+        };
+        let node_ix = self.tree.append(Item {
             start: start_ix,
             end: 0, // will get set later
             body: ItemBody::FencedCodeBlock(self.allocs.allocate_cow(info_string)),
@@ -1499,9 +2039,13 @@ impl<'a, 'b> FirstPass<'a, 'b> {
             let mut line_start = LineStart::new(&bytes[ix..]);
             let n_containers = scan_containers(&self.tree, &mut line_start, self.options);
             if n_containers < self.tree.spine_len() {
+                self.diagnose(DiagnosticKind::UnclosedCodeFence, start_ix..ix);
                 // this line will get parsed again as not being part of the code
                 // if it's blank, it should be parsed as a blank line
                 self.pop(ix);
+                if let Some(syntax) = math_syntax {
+                    self.make_math_block(node_ix, syntax);
+                }
                 return ix;
             }
             line_start.scan_space(indent);
@@ -1510,8 +2054,14 @@ impl<'a, 'b> FirstPass<'a, 'b> {
                 let close_ix = ix + close_line_start.bytes_scanned();
                 if let Some(n) = scan_closing_code_fence(&bytes[close_ix..], fence_ch, n_fence_char)
                 {
+                    if close_ix == bytes.len() {
+                        self.diagnose(DiagnosticKind::UnclosedCodeFence, start_ix..close_ix);
+                    }
                     ix = close_ix + n;
                     self.pop(ix);
+                    if let Some(syntax) = math_syntax {
+                        self.make_math_block(node_ix, syntax);
+                    }
                     // try to read trailing whitespace or it will register as a completely blank line
                     return ix + scan_blank_line(&bytes[ix..]).unwrap_or(0);
                 }
@@ -1524,6 +2074,38 @@ impl<'a, 'b> FirstPass<'a, 'b> {
         }
     }
 
+    /// Turns a fenced code block into display math, see
+    /// [`Options::ENABLE_ASCIIMATH`].
+    fn make_math_block(&mut self, node_ix: TreeIndex, syntax: MathSyntax) {
+        let child = self.tree[node_ix].child.take();
+        let math: CowStr<'a> = match child.map(|child_ix| self.tree[child_ix]) {
+            // a single line is borrowed from the source
+            Some(node)
+                if node.next.is_none() && matches!(node.item.body, ItemBody::Text { .. }) =>
+            {
+                self.text[node.item.start..node.item.end]
+                    .trim_end_matches('\n')
+                    .into()
+            }
+            _ => {
+                let mut math = String::new();
+                let mut child = child;
+                while let Some(child_ix) = child {
+                    let item = self.tree[child_ix].item;
+                    match item.body {
+                        ItemBody::SynthesizeText(cow_ix) => math.push_str(&self.allocs[cow_ix]),
+                        _ => math.push_str(&self.text[item.start..item.end]),
+                    }
+                    child = self.tree[child_ix].next;
+                }
+                math.truncate(math.trim_end_matches('\n').len());
+                math.into()
+            }
+        };
+        let cow_ix = self.allocs.allocate_cow(math);
+        self.tree[node_ix].item.body = ItemBody::Math(cow_ix, true, syntax);
+    }
+
     fn parse_metadata_block(&mut self, start_ix: usize, metadata_block_ch: u8) -> usize {
         let bytes = self.text.as_bytes();
         let metadata_block_kind = match metadata_block_ch {
@@ -1615,8 +2197,23 @@ impl<'a, 'b> FirstPass<'a, 'b> {
 
     /// Pop a container, setting its end.
     fn pop(&mut self, ix: usize) {
+        let last_child = self.tree.cur();
         let cur_ix = self.tree.pop().unwrap();
         self.tree[cur_ix].item.end = ix;
+        if let (ItemBody::Paragraph, Some(child_ix)) = (self.tree[cur_ix].item.body, last_child) {
+            if let ItemBody::MaybeBlockId = self.tree[child_ix].item.body {
+                self.block_id_candidates
+                    .push((self.tree.peek_up(), cur_ix, child_ix));
+            }
+        }
+        if let ItemBody::FootnoteDefinition(label_ix) = self.tree[cur_ix].item.body {
+            let label = self.allocs[label_ix].clone();
+            if let Some(def) = self.allocs.footdefs.get_mut(label) {
+                let body = self.text[def.span.end..ix].trim_end();
+                def.span.end += body.len();
+                def.body = body.into();
+            }
+        }
         if let ItemBody::DefinitionList(_) = self.tree[cur_ix].item.body {
             fixup_end_of_definition_list(&mut self.tree, cur_ix);
             self.begin_list_item = None;
@@ -1749,7 +2346,7 @@ impl<'a, 'b> FirstPass<'a, 'b> {
             if let Some(Item {
                 start,
                 end,
-                body: ItemBody::HardBreak(true),
+                body: ItemBody::HardBreak(HardBreakKind::Backslash),
             }) = line_brk
             {
                 self.tree.append_text(start, end, false);
@@ -1835,10 +2432,15 @@ impl<'a, 'b> FirstPass<'a, 'b> {
         if self.options.has_gfm_footnotes() {
             i += scan_whitespace_no_nl(&bytes[i..]);
         }
-        self.allocs
-            .footdefs
-            .0
-            .insert(UniCase::new(label.clone()), FootnoteDef { use_count: 0 });
+        self.allocs.footdefs.0.insert(
+            UniCase::new(label.clone()),
+            FootnoteDef {
+                use_count: 0,
+                // the span ends at the body for now, `pop` sets the real end
+                span: start..start + i,
+                body: "".into(),
+            },
+        );
         self.tree.append(Item {
             start,
             end: 0, // will get set later
@@ -1864,7 +2466,7 @@ impl<'a, 'b> FirstPass<'a, 'b> {
                 let bytes_scanned = line_start.bytes_scanned();
                 let suffix = &bytes[bytes_scanned..];
                 if self.scan_paragraph_interrupt(suffix, current_container, tree_position)
-                    || (current_container && scan_setext_heading(suffix).is_some())
+                    || (current_container && self.scan_setext_heading(suffix).is_some())
                 {
                     None
                 } else {
@@ -1912,7 +2514,7 @@ impl<'a, 'b> FirstPass<'a, 'b> {
             if !line_start.scan_space(4) {
                 let suffix = &bytes[i + line_start.bytes_scanned()..];
                 if self.scan_paragraph_interrupt(suffix, current_container, tree_position)
-                    || scan_setext_heading(suffix).is_some()
+                    || self.scan_setext_heading(suffix).is_some()
                 {
                     return None;
                 }
@@ -1971,7 +2573,7 @@ impl<'a, 'b> FirstPass<'a, 'b> {
                     if !line_start.scan_space(4) {
                         let suffix = &bytes[bytecount + line_start.bytes_scanned()..];
                         if self.scan_paragraph_interrupt(suffix, current_container, tree_position)
-                            || scan_setext_heading(suffix).is_some()
+                            || self.scan_setext_heading(suffix).is_some()
                         {
                             return None;
                         }
@@ -2080,8 +2682,9 @@ impl<'a, 'b> FirstPass<'a, 'b> {
         if scan_paragraph_interrupt_no_table(
             bytes,
             current_container,
-            self.options.contains(Options::ENABLE_FOOTNOTES),
-            self.options.contains(Options::ENABLE_DEFINITION_LIST),
+            self.options,
+            self.disabled,
+            self.bullets,
             &self.tree,
             tree_position,
         ) {
@@ -2185,6 +2788,14 @@ impl<'a, 'b> FirstPass<'a, 'b> {
         let (content_len, attr_block_range_rel) =
             extract_attribute_block_content_from_header_text(header_bytes);
         let content_end = header_start + content_len;
+        if let Some(span) =
+            find_malformed_attribute_block(header_bytes, attr_block_range_rel.clone())
+        {
+            self.diagnose(
+                DiagnosticKind::MalformedHeadingAttributes,
+                (header_start + span.start)..(header_start + span.end),
+            );
+        }
         let attrs = attr_block_range_rel.and_then(|r| {
             parse_inside_attribute_block(
                 &self.text[(header_start + r.start)..(header_start + r.end)],
@@ -2234,26 +2845,32 @@ fn count_header_cols(
 fn scan_paragraph_interrupt_no_table(
     bytes: &[u8],
     current_container: bool,
-    has_footnote: bool,
-    definition_list: bool,
+    options: Options,
+    disabled: DisabledFeatures,
+    bullets: BulletMarkers,
     tree: &Tree<Item>,
     tree_position: usize,
 ) -> bool {
+    let has_footnote = options.contains(Options::ENABLE_FOOTNOTES);
+    let definition_list = options.contains(Options::ENABLE_DEFINITION_LIST);
     scan_eol(bytes).is_some()
         || scan_hrule(bytes).is_ok()
-        || scan_atx_heading(bytes).is_some()
+        || !disabled.contains(DisabledFeatures::HEADINGS) && scan_atx_heading(bytes).is_some()
         || scan_code_fence(bytes).is_some()
         || scan_interrupting_container_extensions_fence(bytes)
         || scan_blockquote_start(bytes).is_some()
-        || scan_listitem(bytes).is_some_and(|(ix, delim, index, _)| {
-            ! current_container ||
-            tree.is_in_table() ||
-            // we don't allow interruption by either empty lists or
-            // numbered lists starting at an index other than 1
-            (delim == b'*' || delim == b'-' || delim == b'+' || index == 1)
-                && (scan_blank_line(&bytes[ix..]).is_none())
-        })
+        || scan_listitem(bytes)
+            .filter(|&(_, delim, _, _)| matches!(delim, b'.' | b')') || bullets.allows(delim))
+            .is_some_and(|(ix, delim, index, _)| {
+                ! current_container ||
+                tree.is_in_table() ||
+                // we don't allow interruption by either empty lists or
+                // numbered lists starting at an index other than 1
+                (bullets.allows(delim) || index == 1)
+                    && (scan_blank_line(&bytes[ix..]).is_none())
+            })
         || bytes.starts_with(b"<")
+            && !disabled.contains(DisabledFeatures::RAW_HTML)
             && (get_html_end_tag(&bytes[1..]).is_some() || starts_html_block_type_6(&bytes[1..]))
         || definition_list
             && ((current_container
@@ -2488,16 +3105,26 @@ fn special_bytes(options: &Options) -> [bool; 256] {
     {
         bytes[b'~' as usize] = true;
     }
-    if options.contains(Options::ENABLE_SUPERSCRIPT) {
+    if options.contains(Options::ENABLE_SUPERSCRIPT) || options.contains(Options::ENABLE_BLOCK_IDS)
+    {
         bytes[b'^' as usize] = true;
     }
+    if options.contains(Options::ENABLE_HASHTAGS) {
+        bytes[b'#' as usize] = true;
+    }
     if options.contains(Options::ENABLE_MATH) {
         bytes[b'$' as usize] = true;
         bytes[b'{' as usize] = true;
         bytes[b'}' as usize] = true;
     }
+    if options.contains(Options::ENABLE_AUTOLINK_LITERALS) {
+        bytes[b':' as usize] = true;
+        bytes[b'.' as usize] = true;
+        bytes[b'@' as usize] = true;
+    }
     if options.contains(Options::ENABLE_SMART_PUNCTUATION) {
-        for &byte in b".-\"'" {
+        // `/`和`(`只用于可选的分数和符号替换
+        for &byte in b".-\"'/(" {
             bytes[byte as usize] = true;
         }
     }
@@ -2513,6 +3140,7 @@ enum LoopInstruction<T> {
 }
 
 #[cfg(all(target_arch = "x86_64", feature = "simd"))]
+#[derive(Clone, Copy)]
 struct LookupTable {
     simd: [u8; 16],
     scalar: [bool; 256],
@@ -2639,6 +3267,38 @@ fn extract_attribute_block_content_from_header_text(
     (attr_block_open, Some(ix..attr_block_close))
 }
 
+/// Looks for an attribute block that is probably a typo, given the result of
+/// [`extract_attribute_block_content_from_header_text`].
+///
+/// Returns the range of the block within `heading`, including its braces, if
+/// the block is never closed but starts like one (`{#` or `{.` after a
+/// space), or if it holds a lone `#` or `.` or an attribute with an empty name.
+fn find_malformed_attribute_block(
+    heading: &[u8],
+    attr_block: Option<Range<usize>>,
+) -> Option<Range<usize>> {
+    match attr_block {
+        Some(r) => {
+            let inside = &heading[r.clone()];
+            inside
+                .split(|b| b.is_ascii_whitespace())
+                .any(|attr| matches!(attr, [b'#'] | [b'.'] | [b'=', ..]))
+                .then(|| (r.start - 1)..(r.end + 1))
+        }
+        None => {
+            let end = heading.len()
+                - scan_rev_while(heading, |b| {
+                    b == b'\n' || b == b'\r' || b == b' ' || b == b'\t'
+                });
+            let open = heading[..end].iter().rposition(|&b| b == b'{')?;
+            let unclosed = !heading[open..end].contains(&b'}');
+            let starts_like_block = matches!(heading.get(open + 1), Some(b'#' | b'.'))
+                && (open == 0 || matches!(heading[open - 1], b' ' | b'\t'));
+            (unclosed && starts_like_block).then_some(open..end)
+        }
+    }
+}
+
 /// Parses an attribute block content, such as `.class1 #id .class2`.
 ///
 /// Returns `(id, classes)`.
@@ -2734,16 +3394,26 @@ mod simd {
         {
             add_lookup_byte(&mut lookup, b'~');
         }
-        if options.contains(Options::ENABLE_SUPERSCRIPT) {
+        if options.contains(Options::ENABLE_SUPERSCRIPT)
+            || options.contains(Options::ENABLE_BLOCK_IDS)
+        {
             add_lookup_byte(&mut lookup, b'^');
         }
+        if options.contains(Options::ENABLE_HASHTAGS) {
+            add_lookup_byte(&mut lookup, b'#');
+        }
         if options.contains(Options::ENABLE_MATH) {
             add_lookup_byte(&mut lookup, b'$');
             add_lookup_byte(&mut lookup, b'{');
             add_lookup_byte(&mut lookup, b'}');
         }
+        if options.contains(Options::ENABLE_AUTOLINK_LITERALS) {
+            add_lookup_byte(&mut lookup, b':');
+            add_lookup_byte(&mut lookup, b'.');
+            add_lookup_byte(&mut lookup, b'@');
+        }
         if options.contains(Options::ENABLE_SMART_PUNCTUATION) {
-            for &byte in b".-\"'" {
+            for &byte in b".-\"'/(" {
                 add_lookup_byte(&mut lookup, byte);
             }
         }
@@ -19,8 +19,23 @@
 // THE SOFTWARE.
 
 //! HTML 渲染器，接收事件迭代器作为输入。
+//!
+//! 渲染器逐个拉取事件并立即写出，不做任何前瞻：表格按单元格、
+//! 脚注定义按出现位置输出，不会缓冲整张表格或整篇文档。
+//! 除写入器本身外，额外内存只有当前表格的列对齐方式，
+//! 以及脚注名称到编号的映射，即 O(表格列数 + 不同脚注数)。
+//! 与按需产生事件的迭代器配合时，渲染任意长的文档只需要有界的内存。
+//! 例外是配置了 [`HtmlConfig::code_block_hook`] 时，每个代码块会被整体缓冲；
+//! 以及 [`HtmlConfig::footnote_placement`] 不是 [`FootnotePlacement::InPlace`] 时，
+//! 脚注定义渲染后会被缓冲到输出位置。
 
-use alloc::{string::String, vec::Vec};
+#[cfg(feature = "rayon")]
+use alloc::sync::Arc;
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::{cell::Cell, ops::Range};
 #[cfg(all(feature = "std", not(feature = "hashbrown")))]
 use std::collections::HashMap;
 
@@ -32,15 +47,448 @@ use pulldown_cmark_escape::{escape_href, escape_html, escape_html_body_text, Fmt
 
 use crate::{
     strings::CowStr,
-    Alignment, BlockQuoteKind, CodeBlockKind,
+    utils::{is_inline_tag, sanitize_url, url_scheme, used_extensions, UrlPolicy},
+    Alignment, Attributes, BlockQuoteKind, CodeBlockKind,
     ContainerKind::*,
     Event::{self, *},
-    LinkType, Tag, TagEnd,
+    LinkType, MathSyntax, Options, Parser, Tag, TagEnd,
 };
 
+/// HTML 渲染配置，见 [`push_html_with_config`]。
+#[derive(Clone, Debug, Default)]
+pub struct HtmlConfig {
+    /// 链接和图片地址的清理策略（None 表示原样输出）。
+    ///
+    /// 被 [`sanitize_url`] 拒绝的地址输出为空的 `href`/`src`。
+    pub url_policy: Option<UrlPolicy>,
+    /// 代码块预处理钩子（None 表示原样输出）。
+    ///
+    /// 只影响渲染结果，解析器产生的事件仍包含代码块的全部文本。
+    /// 例如 [`hide_rust_lines`] 按 mdBook/rustdoc 的约定隐藏 Rust 代码中的辅助行。
+    pub code_block_hook: Option<CodeBlockHook>,
+    /// 脚注定义的输出位置。
+    ///
+    /// 移动位置的脚注定义在 [`push_html_with_source_map`] 的映射中输出范围为空，
+    /// [`push_html_parallel`] 在这种情况下按顺序渲染。
+    pub footnote_placement: FootnotePlacement,
+    /// 脚注引用和定义中显示的标签（None 表示显示编号）。
+    ///
+    /// 例如 [`footnote_label_name`] 显示原始标签，[`footnote_label_symbol`] 显示 `*`、`†` 等符号。
+    /// 只影响显示的文本，链接目标和 `id` 仍然是原始标签。
+    pub footnote_label: Option<FootnoteLabelHook>,
+    /// 按链接类别添加的 `rel` 属性，默认不添加。
+    pub link_rel: LinkRelPolicy,
+    /// 原始 HTML（[`Event::Html`] 和 [`Event::InlineHtml`]）的输出策略，默认原样输出。
+    ///
+    /// 与解析器是否识别 HTML 无关。只有 [`RawHtmlPolicy::EscapeAll`] 和 [`RawHtmlPolicy::Drop`]
+    /// 能阻止原始 HTML 生效；[`RawHtmlPolicy::Passthrough`] 和 [`RawHtmlPolicy::Tagfilter`]
+    /// 仍会输出 `<img onerror=...>` 这样的事件处理属性。渲染不受信任的输入时，
+    /// 还需要关闭属性扩展，或通过 [`image_attributes`](Self::image_attributes) 等白名单限制属性。
+    pub raw_html: RawHtmlPolicy,
+    /// 允许输出到 `<img>` 的图片属性名称（不区分大小写），默认不输出任何属性。
+    ///
+    /// 属性来自 [`Options::ENABLE_IMAGE_ATTRIBUTES`]，类在允许 `class` 时输出。
+    /// [`RESPONSIVE_IMAGE_ATTRIBUTES`] 列出了响应式图片的常用属性。
+    /// `src`、`alt` 和图片自带的 `title` 不会被属性覆盖，`srcset` 中的地址同样经过 [`url_policy`](Self::url_policy)。
+    ///
+    /// ```
+    /// use pulldown_cmark::{html, Options, Parser};
+    ///
+    /// let markdown = r#"![Chart](chart.png){srcset="chart@2x.png 2x" onload=x}"#;
+    /// let config = html::HtmlConfig {
+    ///     image_attributes: html::RESPONSIVE_IMAGE_ATTRIBUTES.map(String::from).to_vec(),
+    ///     ..Default::default()
+    /// };
+    /// let parser = Parser::new_ext(markdown, Options::ENABLE_IMAGE_ATTRIBUTES);
+    /// let mut output = String::new();
+    /// html::push_html_with_config(&mut output, parser, config);
+    /// assert_eq!(
+    ///     output,
+    ///     "<p><img src=\"chart.png\" alt=\"Chart\" srcset=\"chart@2x.png 2x\" /></p>\n"
+    /// );
+    /// ```
+    pub image_attributes: Vec<String>,
+    /// 除 `id`、类和 `data-*` 之外，允许输出到容器块的属性名称（不区分大小写），默认为空。
+    ///
+    /// 属性来自 `::: note {#id .class key=value}` 的属性块，其余属性（例如 `onclick`）被丢弃。
+    ///
+    /// ```
+    /// use pulldown_cmark::{html, Options, Parser};
+    ///
+    /// let markdown = "::: note {#tip data-level=2 title=Tip onclick=x}\ntext\n:::\n";
+    /// let config = html::HtmlConfig {
+    ///     container_attributes: vec!["title".into()],
+    ///     ..Default::default()
+    /// };
+    /// let parser = Parser::new_ext(markdown, Options::ENABLE_CONTAINER_EXTENSIONS);
+    /// let mut output = String::new();
+    /// html::push_html_with_config(&mut output, parser, config);
+    /// assert!(output.starts_with("<div class=\"note\" id=\"tip\" data-level=\"2\" title=\"Tip\">"));
+    /// ```
+    pub container_attributes: Vec<String>,
+    /// 添加到标题、容器块、脚注定义和块 ID 的 `id` 之前的前缀，默认为空。
+    ///
+    /// 脚注引用和以 `#` 开头的链接同样加上前缀，因此多个文档渲染到同一页面时锚点不会冲突。
+    ///
+    /// ```
+    /// use pulldown_cmark::{html, Options, Parser};
+    ///
+    /// let markdown = "See[^1] and [top](#top).\n\n[^1]: Note\n";
+    /// let config = html::HtmlConfig {
+    ///     id_prefix: "post-42-".into(),
+    ///     ..Default::default()
+    /// };
+    /// let mut output = String::new();
+    /// html::push_html_with_config(&mut output, Parser::new_ext(markdown, Options::ENABLE_FOOTNOTES), config);
+    /// assert!(output.contains("<a href=\"#post-42-1\">1</a>"));
+    /// assert!(output.contains("href=\"#post-42-top\""));
+    /// assert!(output.contains("id=\"post-42-1\""));
+    /// ```
+    pub id_prefix: String,
+    /// 是否为有序列表项输出书写的编号作为 `value` 属性，默认不输出。
+    ///
+    /// 浏览器默认按顺序重新编号，输出 `value` 后不连续的编号（例如法律条文或更新日志）得以保留。
+    ///
+    /// ```
+    /// use pulldown_cmark::{html, Parser};
+    ///
+    /// let config = html::HtmlConfig {
+    ///     list_item_values: true,
+    ///     ..Default::default()
+    /// };
+    /// let mut output = String::new();
+    /// html::push_html_with_config(&mut output, Parser::new("1. a\n5. b\n"), config);
+    /// assert!(output.contains("<li value=\"1\">a</li>\n<li value=\"5\">b</li>"));
+    /// ```
+    pub list_item_values: bool,
+    /// 规范化输出模式，默认关闭。
+    ///
+    /// 相同的事件在任何平台和特性组合（`simd`、`std`/`no_std`）下都渲染为逐字节相同的 HTML。
+    /// 规范化模式还将文本、代码、数学公式和原始 HTML 中的 `\r\n` 与单独的 `\r` 统一为 `\n`，
+    /// 使以 CRLF 换行签出的文档与 LF 换行的文档渲染结果相同，便于可重现构建比较输出。
+    ///
+    /// ```
+    /// use pulldown_cmark::{html, Parser};
+    ///
+    /// let render = |markdown| {
+    ///     let config = html::HtmlConfig {
+    ///         canonical: true,
+    ///         ..Default::default()
+    ///     };
+    ///     let mut output = String::new();
+    ///     html::push_html_with_config(&mut output, Parser::new(markdown), config);
+    ///     output
+    /// };
+    /// assert_eq!(render("<b\r\nx>\r\n"), render("<b\nx>\n"));
+    /// ```
+    pub canonical: bool,
+    /// 是否在自动链接的文本中省略协议（例如 `https://`、`mailto:`），默认显示。
+    ///
+    /// 只影响显示的文本，`href` 仍是完整的地址。
+    ///
+    /// ```
+    /// use pulldown_cmark::{html, Parser};
+    ///
+    /// let config = html::HtmlConfig {
+    ///     hide_autolink_scheme: true,
+    ///     ..Default::default()
+    /// };
+    /// let mut output = String::new();
+    /// html::push_html_with_config(&mut output, Parser::new("<https://example.com/a>"), config);
+    /// assert!(output.contains("href=\"https://example.com/a\">example.com/a</a>"));
+    /// ```
+    pub hide_autolink_scheme: bool,
+}
+
+/// 响应式图片的常用属性，可用于 [`HtmlConfig::image_attributes`]。
+pub const RESPONSIVE_IMAGE_ATTRIBUTES: [&str; 5] =
+    ["srcset", "sizes", "width", "height", "loading"];
+
+/// 原始 HTML 的输出策略，见 [`HtmlConfig::raw_html`]。
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RawHtmlPolicy {
+    /// 原样输出。
+    #[default]
+    Passthrough,
+    /// 按 GFM 的 tagfilter 扩展处理，见 [`tagfilter`]。
+    Tagfilter,
+    /// 转义后作为文本输出。
+    EscapeAll,
+    /// 不输出。
+    Drop,
+}
+
+/// GFM tagfilter 扩展过滤的标签。
+pub const TAGFILTER_TAGS: [&str; 9] = [
+    "title",
+    "textarea",
+    "style",
+    "xmp",
+    "iframe",
+    "noembed",
+    "noframes",
+    "script",
+    "plaintext",
+];
+
+/// 按 GFM 的 tagfilter 扩展，将 [`TAGFILTER_TAGS`] 中标签的开始和结束标签的 `<`
+/// 替换为 `&lt;`，其余内容原样返回。标签名不区分大小写。
+///
+/// ```
+/// use pulldown_cmark::html::tagfilter;
+///
+/// assert_eq!(
+///     tagfilter("<strong><SCRIPT>alert(1)</script></strong>"),
+///     "<strong>&lt;SCRIPT>alert(1)&lt;/script></strong>".into()
+/// );
+/// assert_eq!(tagfilter("<scripts>"), "<scripts>".into());
+/// ```
+pub fn tagfilter(html: &str) -> CowStr<'_> {
+    let bytes = html.as_bytes();
+    let mut output = String::new();
+    let mut last = 0;
+    for (ix, _) in html.match_indices('<') {
+        let name_start = ix + 1 + usize::from(bytes.get(ix + 1) == Some(&b'/'));
+        let filtered = TAGFILTER_TAGS.iter().any(|tag| {
+            let name_end = name_start + tag.len();
+            bytes
+                .get(name_start..name_end)
+                .is_some_and(|name| name.eq_ignore_ascii_case(tag.as_bytes()))
+                && matches!(
+                    bytes.get(name_end),
+                    Some(b' ' | b'\t' | b'\n' | b'\r' | b'\x0c' | b'>' | b'/')
+                )
+        });
+        if filtered {
+            output.push_str(&html[last..ix]);
+            output.push_str("&lt;");
+            last = ix + 1;
+        }
+    }
+    if last == 0 {
+        return html.into();
+    }
+    output.push_str(&html[last..]);
+    output.into()
+}
+
+/// 脚注定义的输出位置，见 [`HtmlConfig::footnote_placement`]。
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum FootnotePlacement {
+    /// 在定义出现的位置输出。
+    #[default]
+    InPlace,
+    /// 在包含定义的块（例如引用块或列表项）的末尾、结束标签之前输出，
+    /// 顶层的定义在文档末尾输出。
+    EndOfParentBlock,
+    /// 全部收集到文档末尾的 `<section class="footnotes">` 中输出。
+    EndOfDocument,
+}
+
+/// 脚注标签钩子，见 [`HtmlConfig::footnote_label`]。
+///
+/// 参数为脚注的编号（按首次出现的顺序，从 1 开始）和原始标签，返回要显示的文本，
+/// 输出时会进行 HTML 转义。
+pub type FootnoteLabelHook = fn(number: usize, name: &str) -> String;
+
+/// 显示脚注的原始标签，可用作 [`HtmlConfig::footnote_label`]。
+pub fn footnote_label_name(_number: usize, name: &str) -> String {
+    name.into()
+}
+
+/// 按 `*`、`†`、`‡`、`§`、`‖`、`¶` 的顺序显示符号，可用作 [`HtmlConfig::footnote_label`]。
+///
+/// 超过六个脚注后符号加倍，例如第七个为 `**`。
+///
+/// ```
+/// use pulldown_cmark::{html, Options, Parser};
+///
+/// let markdown = "a[^x] b[^y]\n\n[^x]: one\n\n[^y]: two\n";
+/// let config = html::HtmlConfig {
+///     footnote_label: Some(html::footnote_label_symbol),
+///     ..Default::default()
+/// };
+/// let mut output = String::new();
+/// html::push_html_with_config(&mut output, Parser::new_ext(markdown, Options::ENABLE_FOOTNOTES), config);
+/// assert!(output.contains("<a href=\"#x\">*</a>"));
+/// assert!(output.contains("<a href=\"#y\">†</a>"));
+/// assert_eq!(html::footnote_label_symbol(8, "z"), "††");
+/// ```
+pub fn footnote_label_symbol(number: usize, _name: &str) -> String {
+    const SYMBOLS: [char; 6] = ['*', '†', '‡', '§', '‖', '¶'];
+    let ix = number.saturating_sub(1);
+    core::iter::repeat(SYMBOLS[ix % SYMBOLS.len()])
+        .take(ix / SYMBOLS.len() + 1)
+        .collect()
+}
+
+/// 将 `\r\n` 和单独的 `\r` 换行统一为 `\n`，见 [`HtmlConfig::canonical`]。
+fn canonical_line_endings(text: CowStr<'_>) -> CowStr<'_> {
+    if text.contains('\r') {
+        text.replace("\r\n", "\n").replace('\r', "\n").into()
+    } else {
+        text
+    }
+}
+
+/// 去掉地址开头的协议和随后的 `//`，见 [`HtmlConfig::hide_autolink_scheme`]。
+fn strip_scheme(url: &str) -> &str {
+    match url_scheme(url) {
+        Some(scheme) => {
+            let rest = &url[scheme.len() + 1..];
+            rest.strip_prefix("//").unwrap_or(rest)
+        }
+        None => url,
+    }
+}
+
+/// 代码块预处理钩子，见 [`HtmlConfig::code_block_hook`]。
+///
+/// 参数为代码块的语言（信息字符串的第一个词，缩进代码块为空字符串）和代码块的全部文本，
+/// 返回要输出的文本。
+pub type CodeBlockHook = for<'s> fn(lang: &str, code: &'s str) -> CowStr<'s>;
+
+/// 隐藏 Rust 代码块中以 `# ` 开头的行，可用作 [`HtmlConfig::code_block_hook`]。
+///
+/// 遵循 mdBook/rustdoc 的约定：只处理语言为 `rust` 的代码块（包括 `rust,ignore`
+/// 这样的写法），单独的 `#` 行同样隐藏，以 `##` 开头的行去掉一个 `#` 后输出。
+///
+/// ```
+/// use pulldown_cmark::{html, Parser};
+///
+/// let markdown = "```rust\n# fn main() {\nprintln!(\"hi\");\n## not hidden\n# }\n```\n";
+/// let config = html::HtmlConfig {
+///     code_block_hook: Some(html::hide_rust_lines),
+///     ..Default::default()
+/// };
+/// let mut output = String::new();
+/// html::push_html_with_config(&mut output, Parser::new(markdown), config);
+/// assert_eq!(
+///     output,
+///     "<pre><code class=\"language-rust\">println!(\"hi\");\n# not hidden\n</code></pre>\n"
+/// );
+/// ```
+pub fn hide_rust_lines<'s>(lang: &str, code: &'s str) -> CowStr<'s> {
+    if lang.split(',').next() != Some("rust") {
+        return code.into();
+    }
+    let mut output = String::with_capacity(code.len());
+    for line in code.split_inclusive('\n') {
+        let trimmed = line.trim_start();
+        let indent = &line[..line.len() - trimmed.len()];
+        if trimmed.starts_with("##") {
+            output.push_str(indent);
+            output.push_str(&trimmed[1..]);
+        } else if trimmed.trim_end() == "#"
+            || trimmed.starts_with("# ")
+            || trimmed.starts_with("#\t")
+        {
+            continue;
+        } else {
+            output.push_str(line);
+        }
+    }
+    if output.len() == code.len() {
+        code.into()
+    } else {
+        output.into()
+    }
+}
+
+/// 按链接类别设置的 `rel` 属性值，见 [`HtmlConfig::link_rel`]。
+///
+/// 每个类别的值原样输出（会进行 HTML 转义），例如 `"nofollow ugc"`，
+/// None 表示该类链接不添加 `rel`。图片不受影响。
+///
+/// ```
+/// use pulldown_cmark::{html, Parser};
+///
+/// let markdown = "[a](/docs) [b](https://example.com) <https://spam.example>";
+/// let config = html::HtmlConfig {
+///     link_rel: html::LinkRelPolicy {
+///         autolink: Some("nofollow ugc noopener".into()),
+///         ..html::LinkRelPolicy::ugc()
+///     },
+///     ..Default::default()
+/// };
+/// let mut output = String::new();
+/// html::push_html_with_config(&mut output, Parser::new(markdown), config);
+/// assert!(output.contains(r#"href="/docs">a"#));
+/// assert!(output.contains(r#"href="https://example.com" rel="nofollow ugc">b"#));
+/// assert!(output.contains(r#"href="https://spam.example" rel="nofollow ugc noopener">"#));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct LinkRelPolicy {
+    /// 内部链接：相对地址、锚点，以及主机在 `internal_hosts` 中的地址。
+    pub internal: Option<String>,
+    /// 外部链接：其他带协议（如 `https:`）或以 `//` 开头的地址。
+    pub external: Option<String>,
+    /// `mailto:` 链接，包括电子邮件自动链接。
+    pub mailto: Option<String>,
+    /// 自动链接（`<https://...>` 和自动链接字面量）。None 时按地址归入内部或外部链接。
+    pub autolink: Option<String>,
+    /// 视为内部链接的主机名，不区分大小写，不包括子域名。
+    pub internal_hosts: Vec<String>,
+}
+
+impl LinkRelPolicy {
+    /// 用户生成内容的常用配置：外部链接和自动链接添加 `nofollow ugc`。
+    pub fn ugc() -> Self {
+        LinkRelPolicy {
+            external: Some("nofollow ugc".into()),
+            autolink: Some("nofollow ugc".into()),
+            ..Self::default()
+        }
+    }
+
+    /// 返回给定链接应添加的 `rel` 属性值。
+    pub fn rel_for(&self, link_type: LinkType, dest_url: &str) -> Option<&str> {
+        let rel = match link_type {
+            LinkType::Email | LinkType::EmailLiteral => &self.mailto,
+            LinkType::Autolink | LinkType::AutolinkLiteral if self.autolink.is_some() => {
+                &self.autolink
+            }
+            _ if url_scheme(dest_url).is_some_and(|s| s.eq_ignore_ascii_case("mailto")) => {
+                &self.mailto
+            }
+            _ if self.is_external(dest_url) => &self.external,
+            _ => &self.internal,
+        };
+        rel.as_deref()
+    }
+
+    /// 地址是否指向 `internal_hosts` 以外的位置。
+    fn is_external(&self, url: &str) -> bool {
+        let rest = match url_scheme(url) {
+            Some(scheme) => match url[scheme.len() + 1..].strip_prefix("//") {
+                Some(rest) => rest,
+                // 没有主机的地址，例如 `tel:`
+                None => return true,
+            },
+            None => match url.strip_prefix("//") {
+                Some(rest) => rest,
+                None => return false,
+            },
+        };
+        let authority = &rest[..rest.find(['/', '?', '#']).unwrap_or(rest.len())];
+        let host = authority.rsplit('@').next().unwrap_or(authority);
+        let host = match host.rfind(':') {
+            Some(ix) if !host.ends_with(']') => &host[..ix],
+            _ => host,
+        };
+        !self
+            .internal_hosts
+            .iter()
+            .any(|internal| internal.eq_ignore_ascii_case(host))
+    }
+}
+
+#[derive(PartialEq)]
 enum TableState {
     Head,
     Body,
+    Foot,
 }
 
 struct HtmlWriter<'a, I, W> {
@@ -60,6 +508,20 @@ struct HtmlWriter<'a, I, W> {
     table_alignments: Vec<Alignment>,
     table_cell_index: usize,
     numbers: HashMap<CowStr<'a>, usize>,
+    /// 每个打开的引用块是否渲染为`<details>`，按嵌套顺序排列。
+    folded_quotes: Vec<bool>,
+    /// 配置了代码块钩子时，当前代码块的语言和已缓冲的文本。
+    code_block: Option<(String, String)>,
+    /// 当前打开的块级元素数量。
+    block_depth: usize,
+    /// 等待输出的脚注定义，以及定义出现时的块级嵌套深度。
+    deferred_footnotes: Vec<(usize, String)>,
+    /// 下一个文本事件是自动链接的文本，按配置去掉协议。
+    strip_scheme: bool,
+    /// 预先分配的脚注编号，并行渲染时在各块之间共享。
+    #[cfg(feature = "rayon")]
+    resolved_numbers: Option<Arc<HashMap<CowStr<'a>, usize>>>,
+    config: HtmlConfig,
 }
 
 impl<'a, I, W> HtmlWriter<'a, I, W>
@@ -67,7 +529,7 @@ where
     I: Iterator<Item = Event<'a>>,
     W: StrWrite,
 {
-    fn new(iter: I, writer: W) -> Self {
+    fn new(iter: I, writer: W, config: HtmlConfig) -> Self {
         Self {
             iter,
             writer,
@@ -77,7 +539,86 @@ where
             table_alignments: vec![],
             table_cell_index: 0,
             numbers: HashMap::new(),
+            folded_quotes: Vec::new(),
+            code_block: None,
+            block_depth: 0,
+            deferred_footnotes: Vec::new(),
+            strip_scheme: false,
+            #[cfg(feature = "rayon")]
+            resolved_numbers: None,
+            config,
+        }
+    }
+
+    /// 返回脚注的编号，首次出现时分配新编号。
+    fn footnote_number(&mut self, name: CowStr<'a>) -> usize {
+        #[cfg(feature = "rayon")]
+        if let Some(&number) = self.resolved_numbers.as_ref().and_then(|n| n.get(&name)) {
+            return number;
+        }
+        let len = self.numbers.len() + 1;
+        *self.numbers.entry(name).or_insert(len)
+    }
+
+    /// 写入脚注的标签，配置了标签钩子时使用钩子的结果。
+    fn write_footnote_label(&mut self, name: CowStr<'a>) -> Result<(), W::Error> {
+        match self.config.footnote_label {
+            Some(label) => {
+                let number = self.footnote_number(name.clone());
+                escape_html_body_text(&mut self.writer, &label(number, &name))
+            }
+            None => {
+                let number = self.footnote_number(name);
+                write!(&mut self.writer, "{}", number)
+            }
+        }
+    }
+
+    /// 将脚注定义渲染到单独的缓冲区，等到输出位置再写出。
+    fn defer_footnote(&mut self, name: CowStr<'a>) {
+        let mut events = vec![Start(Tag::FootnoteDefinition(name))];
+        let mut nest = 0;
+        for event in self.iter.by_ref() {
+            match event {
+                Start(_) => nest += 1,
+                End(_) if nest == 0 => {
+                    events.push(event);
+                    break;
+                }
+                End(_) => nest -= 1,
+                _ => {}
+            }
+            events.push(event);
         }
+
+        let mut html = String::new();
+        let mut config = self.config.clone();
+        config.footnote_placement = FootnotePlacement::InPlace;
+        let mut writer = HtmlWriter::new(events.into_iter(), FmtWriter(&mut html), config);
+        writer.numbers = core::mem::take(&mut self.numbers);
+        // 写入 String 不会失败
+        writer.render().unwrap();
+        self.numbers = writer.numbers;
+        self.deferred_footnotes.push((self.block_depth, html));
+    }
+
+    /// 写出嵌套深度不小于 `depth` 的脚注定义。
+    fn flush_footnotes(&mut self, depth: usize) -> Result<(), W::Error> {
+        let split = self
+            .deferred_footnotes
+            .iter()
+            .position(|&(level, _)| level >= depth)
+            .unwrap_or(self.deferred_footnotes.len());
+        if split == self.deferred_footnotes.len() {
+            return Ok(());
+        }
+        if !self.end_newline {
+            self.write_newline()?;
+        }
+        for (_, html) in self.deferred_footnotes.split_off(split) {
+            self.write(&html)?;
+        }
+        Ok(())
     }
 
     /// 写入新行。
@@ -98,69 +639,196 @@ where
         Ok(())
     }
 
+    /// 写入链接的 `rel` 属性（包括前面的 `" rel="`），没有配置时不写入。
+    fn write_link_rel(&mut self, link_type: LinkType, dest_url: &str) -> Result<(), W::Error> {
+        if let Some(rel) = self.config.link_rel.rel_for(link_type, dest_url) {
+            self.writer.write_str("\" rel=\"")?;
+            escape_html(&mut self.writer, rel)?;
+        }
+        Ok(())
+    }
+
+    /// 写入加上 `id_prefix` 的 `id`。
+    fn write_id(&mut self, id: &str) -> Result<(), W::Error> {
+        escape_html(&mut self.writer, &self.config.id_prefix)?;
+        escape_html(&mut self.writer, id)
+    }
+
+    /// 写入块标识符对应的 `id` 属性，`[[页面#^标识符]]` 链接到这里。
+    fn write_block_id(&mut self, block_id: &str) -> Result<(), W::Error> {
+        self.write(" id=\"")?;
+        self.write_id("^")?;
+        escape_html(&mut self.writer, block_id)?;
+        self.write("\"")
+    }
+
+    /// 写入链接或图片地址，配置了 URL 策略时先进行清理。
+    fn write_url(&mut self, url: &str) -> Result<(), W::Error> {
+        match &self.config.url_policy {
+            Some(policy) => match sanitize_url(url, policy) {
+                Some(url) => escape_href(&mut self.writer, &url),
+                None => Ok(()),
+            },
+            None => escape_href(&mut self.writer, url),
+        }
+    }
+
+    /// 取出下一个事件，规范化模式下统一其中的换行符。
+    fn next_event(&mut self) -> Option<Event<'a>> {
+        let event = self.iter.next()?;
+        if !self.config.canonical {
+            return Some(event);
+        }
+        Some(match event {
+            Text(text) => Text(canonical_line_endings(text)),
+            Code(text, delimiter) => Code(canonical_line_endings(text), delimiter),
+            InlineMath(text, syntax) => InlineMath(canonical_line_endings(text), syntax),
+            DisplayMath(text, syntax) => DisplayMath(canonical_line_endings(text), syntax),
+            Html(html) => Html(canonical_line_endings(html)),
+            InlineHtml(html) => InlineHtml(canonical_line_endings(html)),
+            event => event,
+        })
+    }
+
     fn run(mut self) -> Result<(), W::Error> {
-        while let Some(event) = self.iter.next() {
-            match event {
-                Start(tag) => {
-                    self.start_tag(tag)?;
-                }
-                End(tag) => {
-                    self.end_tag(tag)?;
+        self.render()
+    }
+
+    fn render(&mut self) -> Result<(), W::Error> {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::debug_span!("html_render").entered();
+        #[cfg(feature = "tracing")]
+        let mut events = 0usize;
+        while let Some(event) = self.next_event() {
+            #[cfg(feature = "tracing")]
+            {
+                events += 1;
+            }
+            self.render_event(event)?;
+        }
+        self.finish()?;
+        #[cfg(feature = "tracing")]
+        tracing::debug!(events, "html rendered");
+        Ok(())
+    }
+
+    /// 渲染一个事件。
+    fn render_event(&mut self, event: Event<'a>) -> Result<(), W::Error> {
+        match event {
+            Start(Tag::FootnoteDefinition(name))
+                if self.config.footnote_placement != FootnotePlacement::InPlace =>
+            {
+                self.defer_footnote(name);
+            }
+            Start(tag) => {
+                if !is_inline_tag(&tag.to_end()) {
+                    self.block_depth += 1;
                 }
-                Text(text) => {
-                    if !self.in_non_writing_block {
-                        escape_html_body_text(&mut self.writer, &text)?;
-                        self.end_newline = text.ends_with('\n');
+                self.start_tag(tag)?;
+            }
+            End(tag) => {
+                if !is_inline_tag(&tag) {
+                    if self.config.footnote_placement == FootnotePlacement::EndOfParentBlock {
+                        self.flush_footnotes(self.block_depth)?;
                     }
+                    self.block_depth = self.block_depth.saturating_sub(1);
                 }
-                // 行内代码
-                Code(text) => {
-                    // self.write("<code>")?;
-                    self.write("<kbd class=\"kbd\">")?;
-                    escape_html_body_text(&mut self.writer, &text)?;
-                    self.write("</kbd>")?;
-                    // self.write("</code>")?;
-                }
-                InlineMath(text) => {
-                    self.write(r#"<span class="math math-inline">"#)?;
-                    escape_html(&mut self.writer, &text)?;
-                    self.write("</span>")?;
-                }
-                DisplayMath(text) => {
-                    self.write(r#"<span class="math math-display">"#)?;
-                    escape_html(&mut self.writer, &text)?;
-                    self.write("</span>")?;
-                }
-                Html(html) | InlineHtml(html) => {
-                    self.write(&html)?;
-                }
-                SoftBreak => {
-                    self.write_newline()?;
-                }
-                HardBreak => {
-                    self.write("<br />\n")?;
-                }
-                Rule => {
-                    if self.end_newline {
-                        self.write("<hr />\n")?;
+                self.end_tag(tag)?;
+            }
+            Text(text) => {
+                if let Some((_, code)) = &mut self.code_block {
+                    code.push_str(&text);
+                } else if !self.in_non_writing_block {
+                    let text = if core::mem::take(&mut self.strip_scheme) {
+                        strip_scheme(&text)
                     } else {
-                        self.write("\n<hr />\n")?;
-                    }
+                        &text
+                    };
+                    escape_html_body_text(&mut self.writer, text)?;
+                    self.end_newline = text.ends_with('\n');
                 }
-                FootnoteReference(name) => {
-                    let len = self.numbers.len() + 1;
-                    self.write("<sup class=\"footnote-reference\"><a href=\"#")?;
-                    escape_html(&mut self.writer, &name)?;
-                    self.write("\">")?;
-                    let number = *self.numbers.entry(name).or_insert(len);
-                    write!(&mut self.writer, "{}", number)?;
-                    self.write("</a></sup>")?;
+            }
+            // 行内代码
+            Code(text, _) => {
+                // self.write("<code>")?;
+                self.write("<kbd class=\"kbd\">")?;
+                escape_html_body_text(&mut self.writer, &text)?;
+                self.write("</kbd>")?;
+                // self.write("</code>")?;
+            }
+            InlineMath(text, syntax) => {
+                self.write(match syntax {
+                    MathSyntax::Tex => r#"<span class="math math-inline">"#,
+                    MathSyntax::AsciiMath => r#"<span class="math math-inline asciimath">"#,
+                })?;
+                escape_html(&mut self.writer, &text)?;
+                self.write("</span>")?;
+            }
+            DisplayMath(text, syntax) => {
+                self.write(match syntax {
+                    MathSyntax::Tex => r#"<span class="math math-display">"#,
+                    MathSyntax::AsciiMath => r#"<span class="math math-display asciimath">"#,
+                })?;
+                escape_html(&mut self.writer, &text)?;
+                self.write("</span>")?;
+            }
+            Hashtag(tag) => {
+                self.write("<span class=\"hashtag\">#")?;
+                escape_html_body_text(&mut self.writer, &tag)?;
+                self.write("</span>")?;
+            }
+            Html(html) | InlineHtml(html) => match self.config.raw_html {
+                RawHtmlPolicy::Passthrough => self.write(&html)?,
+                RawHtmlPolicy::Tagfilter => self.write(&tagfilter(&html))?,
+                RawHtmlPolicy::EscapeAll => {
+                    escape_html_body_text(&mut self.writer, &html)?;
+                    self.end_newline = html.ends_with('\n');
                 }
-                TaskListMarker(true) => {
-                    self.write("<input disabled=\"\" type=\"checkbox\" checked=\"\"/>\n")?;
+                RawHtmlPolicy::Drop => {}
+            },
+            SoftBreak => {
+                self.write_newline()?;
+            }
+            HardBreak(_) => {
+                self.write("<br />\n")?;
+            }
+            Rule => {
+                if self.end_newline {
+                    self.write("<hr />\n")?;
+                } else {
+                    self.write("\n<hr />\n")?;
                 }
-                TaskListMarker(false) => {
-                    self.write("<input disabled=\"\" type=\"checkbox\"/>\n")?;
+            }
+            FootnoteReference(name) => {
+                self.write("<sup class=\"footnote-reference\"><a href=\"#")?;
+                self.write_id(&name)?;
+                self.write("\">")?;
+                self.write_footnote_label(name)?;
+                self.write("</a></sup>")?;
+            }
+            TaskListMarker(true) => {
+                self.write("<input disabled=\"\" type=\"checkbox\" checked=\"\"/>\n")?;
+            }
+            TaskListMarker(false) => {
+                self.write("<input disabled=\"\" type=\"checkbox\"/>\n")?;
+            }
+        }
+        Ok(())
+    }
+
+    /// 所有事件都渲染后，写出仍在等待的脚注定义。
+    fn finish(&mut self) -> Result<(), W::Error> {
+        match self.config.footnote_placement {
+            FootnotePlacement::InPlace => {}
+            FootnotePlacement::EndOfParentBlock => self.flush_footnotes(0)?,
+            FootnotePlacement::EndOfDocument => {
+                if !self.deferred_footnotes.is_empty() {
+                    if !self.end_newline {
+                        self.write_newline()?;
+                    }
+                    self.write("<section class=\"footnotes\">\n")?;
+                    self.flush_footnotes(0)?;
+                    self.write("</section>\n")?;
                 }
             }
         }
@@ -171,18 +839,21 @@ where
     fn start_tag(&mut self, tag: Tag<'a>) -> Result<(), W::Error> {
         match tag {
             Tag::HtmlBlock => Ok(()),
-            Tag::Paragraph => {
+            Tag::Paragraph(block_id) => {
                 if self.end_newline {
-                    self.write("<p>")
+                    self.write("<p")?;
                 } else {
-                    self.write("\n<p>")
+                    self.write("\n<p")?;
+                }
+                if let Some(block_id) = block_id {
+                    self.write_block_id(&block_id)?;
                 }
+                self.write(">")
             }
             Tag::Heading {
                 level,
                 id,
-                classes,
-                attrs,
+                attributes,
             } => {
                 if self.end_newline {
                     self.write("<")?;
@@ -192,9 +863,13 @@ where
                 write!(&mut self.writer, "{}", level)?;
                 if let Some(id) = id {
                     self.write(" id=\"")?;
-                    escape_html(&mut self.writer, &id)?;
+                    self.write_id(&id)?;
                     self.write("\"")?;
                 }
+                let (classes, attrs) = match &attributes {
+                    Some(attributes) => (&attributes.classes[..], &attributes.attrs[..]),
+                    None => (&[][..], &[][..]),
+                };
                 let mut classes = classes.iter();
                 if let Some(class) = classes.next() {
                     self.write(" class=\"")?;
@@ -207,10 +882,10 @@ where
                 }
                 for (attr, value) in attrs {
                     self.write(" ")?;
-                    escape_html(&mut self.writer, &attr)?;
+                    escape_html(&mut self.writer, attr)?;
                     if let Some(val) = value {
                         self.write("=\"")?;
-                        escape_html(&mut self.writer, &val)?;
+                        escape_html(&mut self.writer, val)?;
                         self.write("\"")?;
                     } else {
                         self.write("=\"\"")?;
@@ -227,27 +902,37 @@ where
                 self.table_cell_index = 0;
                 self.write("<thead><tr>")
             }
+            Tag::TableFoot => {
+                self.table_state = TableState::Foot;
+                self.write("</tbody><tfoot>\n")
+            }
             Tag::TableRow => {
                 self.table_cell_index = 0;
                 self.write("<tr>")
             }
-            Tag::TableCell => {
+            Tag::TableCell(alignment) => {
                 match self.table_state {
                     TableState::Head => {
                         self.write("<th")?;
                     }
-                    TableState::Body => {
+                    TableState::Body | TableState::Foot => {
                         self.write("<td")?;
                     }
                 }
-                match self.table_alignments.get(self.table_cell_index) {
-                    Some(&Alignment::Left) => self.write(" style=\"text-align: left\">"),
-                    Some(&Alignment::Center) => self.write(" style=\"text-align: center\">"),
-                    Some(&Alignment::Right) => self.write(" style=\"text-align: right\">"),
+                let alignment =
+                    alignment.or_else(|| self.table_alignments.get(self.table_cell_index).copied());
+                match alignment {
+                    Some(Alignment::Left) => self.write(" style=\"text-align: left\">"),
+                    Some(Alignment::Center) => self.write(" style=\"text-align: center\">"),
+                    Some(Alignment::Right) => self.write(" style=\"text-align: right\">"),
                     _ => self.write(">"),
                 }
             }
-            Tag::BlockQuote(kind) => {
+            Tag::BlockQuote {
+                kind,
+                folded,
+                title,
+            } => {
                 let (class_str, icon) = match kind {
                     None => ("", ""),
                     Some(kind) => match kind {
@@ -266,16 +951,51 @@ where
                         "#),
                     },
                 };
-                if self.end_newline {
-                    self.write(&format!("<blockquote{}>\n{}", class_str, icon))
-                } else {
-                    self.write(&format!("\n<blockquote{}>\n{}", class_str, icon))
+                if !self.end_newline {
+                    self.write_newline()?;
+                }
+                self.folded_quotes.push(folded.is_some());
+                match folded {
+                    Some(folded) => {
+                        let open = if folded { "" } else { " open" };
+                        self.write(&format!(
+                            "<details{}{}>\n<summary>{}",
+                            class_str, open, icon
+                        ))?;
+                        match title {
+                            Some(title) => escape_html_body_text(&mut self.writer, &title)?,
+                            None => self.write(match kind {
+                                Some(BlockQuoteKind::Tip) => "Tip",
+                                Some(BlockQuoteKind::Important) => "Important",
+                                Some(BlockQuoteKind::Warning) => "Warning",
+                                Some(BlockQuoteKind::Caution) => "Caution",
+                                Some(BlockQuoteKind::Note) | None => "Note",
+                            })?,
+                        }
+                        self.write("</summary>\n")
+                    }
+                    None => {
+                        self.write(&format!("<blockquote{}>\n{}", class_str, icon))?;
+                        if let Some(title) = title {
+                            self.write("<p class=\"markdown-alert-title\">")?;
+                            escape_html_body_text(&mut self.writer, &title)?;
+                            self.write("</p>\n")?;
+                        }
+                        Ok(())
+                    }
                 }
             }
             Tag::CodeBlock(info) => {
                 if !self.end_newline {
                     self.write_newline()?;
                 }
+                if self.config.code_block_hook.is_some() {
+                    let lang = match &info {
+                        CodeBlockKind::Fenced(info) => info.split(' ').next().unwrap(),
+                        CodeBlockKind::Indented => "",
+                    };
+                    self.code_block = Some((lang.into(), String::new()));
+                }
                 match info {
                     CodeBlockKind::Fenced(info) => {
                         let lang = info.split(' ').next().unwrap();
@@ -290,25 +1010,28 @@ where
                     CodeBlockKind::Indented => self.write("<pre><code>"),
                 }
             }
-            Tag::ContainerBlock(Default, kind) => {
+            Tag::ContainerBlock(Default, kind, attributes) => {
                 if !self.end_newline {
                     self.write_newline()?;
                 }
                 self.write("<div class=\"")?;
                 escape_html(&mut self.writer, &kind)?;
-                self.write("\">")
+                self.write_container_attributes(attributes.as_deref())?;
+                self.write(">")
             }
-            Tag::ContainerBlock(Spoiler, summary) => {
+            Tag::ContainerBlock(Spoiler, summary, attributes) => {
                 if !self.end_newline {
                     self.write_newline()?;
                 }
-                if summary.is_empty() {
-                    self.write("<details class=\"collapse collapse-plus border\">")
-                } else {
-                    self.write("<details class=\"collapse collapse-plus border\"><summary class=\"collapse-title font-semibold\">")?;
+                self.write("<details class=\"collapse collapse-plus border")?;
+                self.write_container_attributes(attributes.as_deref())?;
+                self.write(">")?;
+                if !summary.is_empty() {
+                    self.write("<summary class=\"collapse-title font-semibold\">")?;
                     escape_html(&mut self.writer, summary.as_ref())?;
-                    self.write("</summary><div class=\"collapse-content text-sm\">")
+                    self.write("</summary>")?;
                 }
+                self.write("<div class=\"collapse-content text-sm\">")
             }
             Tag::List(Some(1)) => {
                 if self.end_newline {
@@ -333,11 +1056,21 @@ where
                     self.write("\n<ul class=\"list-disc list-inside list\">\n")
                 }
             }
-            Tag::Item => {
-                if self.end_newline {
-                    self.write("<li>")
-                } else {
-                    self.write("\n<li>")
+            Tag::Item(number, block_id) => {
+                if !self.end_newline {
+                    self.write_newline()?;
+                }
+                self.write("<li")?;
+                if let Some(block_id) = block_id {
+                    self.write_block_id(&block_id)?;
+                }
+                match number {
+                    Some(number) if self.config.list_item_values => {
+                        self.write(" value=\"")?;
+                        write!(&mut self.writer, "{}", number)?;
+                        self.write("\">")
+                    }
+                    _ => self.write(">"),
                 }
             }
             Tag::DefinitionList => {
@@ -367,13 +1100,14 @@ where
             Tag::Strong => self.write("<strong>"),
             Tag::Strikethrough => self.write("<del>"),
             Tag::Link {
-                link_type: LinkType::Email,
+                link_type: link_type @ (LinkType::Email | LinkType::EmailLiteral),
                 dest_url,
                 title,
                 id: _,
             } => {
-                self.write("<a class=\"link link-warning\" href=\"mailto:")?;
-                escape_href(&mut self.writer, &dest_url)?;
+                self.write("<a class=\"link link-warning\" href=\"")?;
+                self.write_url(&format!("mailto:{}", dest_url))?;
+                self.write_link_rel(link_type, &dest_url)?;
                 if !title.is_empty() {
                     self.write("\" title=\"")?;
                     escape_html(&mut self.writer, &title)?;
@@ -381,33 +1115,43 @@ where
                 self.write("\">")
             }
             Tag::Link {
-                link_type: _,
+                link_type,
                 dest_url,
                 title,
                 id: _,
             } => {
                 self.write("<a class=\"link\" href=\"")?;
-                escape_href(&mut self.writer, &dest_url)?;
+                match dest_url.strip_prefix('#') {
+                    Some(fragment) if !self.config.id_prefix.is_empty() => {
+                        self.write_url(&format!("#{}{}", self.config.id_prefix, fragment))?
+                    }
+                    _ => self.write_url(&dest_url)?,
+                }
+                self.write_link_rel(link_type, &dest_url)?;
                 if !title.is_empty() {
                     self.write("\" title=\"")?;
                     escape_html(&mut self.writer, &title)?;
                 }
+                self.strip_scheme = self.config.hide_autolink_scheme && link_type.is_autolink();
                 self.write("\">")
             }
             Tag::Image {
                 link_type: _,
                 dest_url,
                 title,
-                id: _,
+                extras,
             } => {
                 self.write("<img src=\"")?;
-                escape_href(&mut self.writer, &dest_url)?;
+                self.write_url(&dest_url)?;
                 self.write("\" alt=\"")?;
                 self.raw_text()?;
                 if !title.is_empty() {
                     self.write("\" title=\"")?;
                     escape_html(&mut self.writer, &title)?;
                 }
+                if let Some(extras) = extras {
+                    self.write_image_attributes(&extras.attributes, !title.is_empty())?;
+                }
                 self.write("\" />")
             }
             Tag::FootnoteDefinition(name) => {
@@ -416,11 +1160,9 @@ where
                 } else {
                     self.write("\n<div class=\"footnote-definition\" id=\"")?;
                 }
-                escape_html(&mut self.writer, &name)?;
+                self.write_id(&name)?;
                 self.write("\"><sup class=\"footnote-definition-label\">")?;
-                let len = self.numbers.len() + 1;
-                let number = *self.numbers.entry(name).or_insert(len);
-                write!(&mut self.writer, "{}", number)?;
+                self.write_footnote_label(name)?;
                 self.write("</sup>")
             }
             Tag::MetadataBlock(_) => {
@@ -430,6 +1172,127 @@ where
         }
     }
 
+    /// 在已打开的`class`属性值后追加容器块的类，闭合引号后输出允许的其余属性，`id`加上`id_prefix`。
+    ///
+    /// 只输出`id`、`data-*`和`container_attributes`中的属性。
+    fn write_container_attributes(
+        &mut self,
+        attributes: Option<&Attributes<'_>>,
+    ) -> Result<(), W::Error> {
+        let Some(attributes) = attributes else {
+            return self.write("\"");
+        };
+        for class in &attributes.classes {
+            self.write(" ")?;
+            escape_html(&mut self.writer, class)?;
+        }
+        self.write("\"")?;
+        for (attr, value) in &attributes.attrs {
+            let is_allowed = &**attr == "id"
+                || attr
+                    .get(..5)
+                    .is_some_and(|prefix| prefix.eq_ignore_ascii_case("data-"))
+                || self
+                    .config
+                    .container_attributes
+                    .iter()
+                    .any(|allowed| allowed.eq_ignore_ascii_case(attr));
+            if !is_allowed {
+                continue;
+            }
+            self.write(" ")?;
+            escape_html(&mut self.writer, attr)?;
+            self.write("=\"")?;
+            match value {
+                Some(value) if &**attr == "id" => self.write_id(value)?,
+                Some(value) => escape_html(&mut self.writer, value)?,
+                None => {}
+            }
+            self.write("\"")?;
+        }
+        Ok(())
+    }
+
+    /// 输出 `image_attributes` 允许的图片属性，每个属性之前先闭合上一个值的引号。
+    ///
+    /// 已经输出的属性（`src`、`alt`，以及 `has_title` 时的 `title`）不会重复输出，
+    /// 同名属性只输出第一个。
+    fn write_image_attributes(
+        &mut self,
+        attributes: &Attributes<'_>,
+        has_title: bool,
+    ) -> Result<(), W::Error> {
+        let is_allowed = |config: &HtmlConfig, name: &str| {
+            config
+                .image_attributes
+                .iter()
+                .any(|allowed| allowed.eq_ignore_ascii_case(name))
+        };
+        let mut written = vec!["src", "alt"];
+        if has_title {
+            written.push("title");
+        }
+        if !attributes.classes.is_empty() && is_allowed(&self.config, "class") {
+            self.write("\" class=\"")?;
+            for (i, class) in attributes.classes.iter().enumerate() {
+                if i > 0 {
+                    self.write(" ")?;
+                }
+                escape_html(&mut self.writer, class)?;
+            }
+            written.push("class");
+        }
+        for (name, value) in &attributes.attrs {
+            if !is_allowed(&self.config, name)
+                || written
+                    .iter()
+                    .any(|written| written.eq_ignore_ascii_case(name))
+            {
+                continue;
+            }
+            written.push(name);
+            self.write("\" ")?;
+            escape_html(&mut self.writer, name)?;
+            self.write("=\"")?;
+            match value {
+                Some(value) if name.eq_ignore_ascii_case("srcset") => self.write_srcset(value)?,
+                Some(value) => escape_html(&mut self.writer, value)?,
+                None => {}
+            }
+        }
+        Ok(())
+    }
+
+    /// 输出 `srcset` 的值，每个候选地址都和 `src` 一样经过 `url_policy` 清理，
+    /// 被拒绝的候选项整个丢弃。
+    fn write_srcset(&mut self, srcset: &str) -> Result<(), W::Error> {
+        let mut first = true;
+        for candidate in srcset.split(',') {
+            let candidate = candidate.trim();
+            let (url, descriptor) = candidate
+                .split_once(|c: char| c.is_ascii_whitespace())
+                .unwrap_or((candidate, ""));
+            let rejected = match &self.config.url_policy {
+                Some(policy) => sanitize_url(url, policy).is_none(),
+                None => false,
+            };
+            if url.is_empty() || rejected {
+                continue;
+            }
+            if !first {
+                self.write(", ")?;
+            }
+            first = false;
+            self.write_url(url)?;
+            let descriptor = descriptor.trim();
+            if !descriptor.is_empty() {
+                self.write(" ")?;
+                escape_html(&mut self.writer, descriptor)?;
+            }
+        }
+        Ok(())
+    }
+
     fn end_tag(&mut self, tag: TagEnd) -> Result<(), W::Error> {
         match tag {
             TagEnd::HtmlBlock => {}
@@ -442,12 +1305,19 @@ where
                 self.write(">\n")?;
             }
             TagEnd::Table => {
-                self.write("</tbody></table>\n")?;
+                if self.table_state == TableState::Foot {
+                    self.write("</table>\n")?;
+                } else {
+                    self.write("</tbody></table>\n")?;
+                }
             }
             TagEnd::TableHead => {
                 self.write("</tr></thead><tbody>\n")?;
                 self.table_state = TableState::Body;
             }
+            TagEnd::TableFoot => {
+                self.write("</tfoot>\n")?;
+            }
             TagEnd::TableRow => {
                 self.write("</tr>\n")?;
             }
@@ -456,20 +1326,28 @@ where
                     TableState::Head => {
                         self.write("</th>")?;
                     }
-                    TableState::Body => {
+                    TableState::Body | TableState::Foot => {
                         self.write("</td>")?;
                     }
                 }
                 self.table_cell_index += 1;
             }
             TagEnd::BlockQuote(_) => {
-                self.write("</blockquote>\n")?;
+                if self.folded_quotes.pop() == Some(true) {
+                    self.write("</details>\n")?;
+                } else {
+                    self.write("</blockquote>\n")?;
+                }
             }
             TagEnd::CodeBlock => {
+                if let (Some((lang, code)), Some(hook)) =
+                    (self.code_block.take(), self.config.code_block_hook)
+                {
+                    escape_html_body_text(&mut self.writer, &hook(&lang, &code))?;
+                }
                 self.write("</code></pre>\n")?;
             }
             TagEnd::ContainerBlock(Spoiler) => {
-                
                 self.write("</div></details>\n")?;
             }
             TagEnd::ContainerBlock(Default) => {
@@ -509,6 +1387,7 @@ where
                 self.write("</del>")?;
             }
             TagEnd::Link => {
+                self.strip_scheme = false;
                 self.write("</a>")?;
             }
             TagEnd::Image => (), // 不应该发生，在start处理
@@ -525,7 +1404,7 @@ where
     // 运行原始文本，消费结束标签
     fn raw_text(&mut self) -> Result<(), W::Error> {
         let mut nest = 0;
-        while let Some(event) = self.iter.next() {
+        while let Some(event) = self.next_event() {
             match event {
                 Start(_) => nest += 1,
                 End(_) => {
@@ -535,29 +1414,33 @@ where
                     nest -= 1;
                 }
                 Html(_) => {}
-                InlineHtml(text) | Code(text) | Text(text) => {
+                InlineHtml(text) | Code(text, _) | Text(text) => {
                     // 这里不要使用 escape_html_body_text。
                     // 此函数的输出用于 `alt` 属性。
                     escape_html(&mut self.writer, &text)?;
                     self.end_newline = text.ends_with('\n');
                 }
-                InlineMath(text) => {
+                InlineMath(text, _) => {
                     self.write("$")?;
                     escape_html(&mut self.writer, &text)?;
                     self.write("$")?;
                 }
-                DisplayMath(text) => {
+                DisplayMath(text, _) => {
                     self.write("$$")?;
                     escape_html(&mut self.writer, &text)?;
                     self.write("$$")?;
                 }
-                SoftBreak | HardBreak | Rule => {
+                Hashtag(tag) => {
+                    self.write("#")?;
+                    escape_html(&mut self.writer, &tag)?;
+                }
+                SoftBreak | HardBreak(_) | Rule => {
                     self.write(" ")?;
                 }
                 FootnoteReference(name) => {
-                    let len = self.numbers.len() + 1;
-                    let number = *self.numbers.entry(name).or_insert(len);
-                    write!(&mut self.writer, "[{}]", number)?;
+                    self.write("[")?;
+                    self.write_footnote_label(name)?;
+                    self.write("]")?;
                 }
                 TaskListMarker(true) => self.write("[x]")?,
                 TaskListMarker(false) => self.write("[ ]")?,
@@ -601,6 +1484,185 @@ where
     write_html_fmt(s, iter).unwrap()
 }
 
+/// 与 [`push_html`] 相同，但使用给定的渲染配置。
+///
+/// # 示例
+///
+/// ```
+/// use pulldown_cmark::{html::{self, HtmlConfig}, utils::UrlPolicy, Parser};
+///
+/// let parser = Parser::new("[click](javascript:alert(1)) [ok](/a%2fb)");
+/// let config = HtmlConfig {
+///     url_policy: Some(UrlPolicy::default()),
+///     ..Default::default()
+/// };
+///
+/// let mut html_buf = String::new();
+/// html::push_html_with_config(&mut html_buf, parser, config);
+///
+/// assert_eq!(html_buf, "<p><a class=\"link\" href=\"\">click</a> <a class=\"link\" href=\"/a%2Fb\">ok</a></p>\n");
+/// ```
+pub fn push_html_with_config<'a, I>(s: &mut String, iter: I, config: HtmlConfig)
+where
+    I: Iterator<Item = Event<'a>>,
+{
+    write_html_fmt_with_config(s, iter, config).unwrap()
+}
+
+/// 源码与输出 HTML 之间的位置映射，由 [`push_html_with_source_map`] 生成。
+///
+/// 每个元素和每个叶子事件对应一项，元素的输出范围包含开始标签到结束标签。
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SourceMap {
+    entries: Vec<SourceMapEntry>,
+}
+
+/// [`SourceMap`] 中的一项。
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SourceMapEntry {
+    /// 源码中的字节范围。
+    pub source: Range<usize>,
+    /// 输出中的字节范围。
+    pub output: Range<usize>,
+}
+
+impl SourceMap {
+    /// 所有映射项，按在输出中的位置排序。
+    pub fn entries(&self) -> &[SourceMapEntry] {
+        &self.entries
+    }
+
+    /// 返回包含源码偏移量的最内层元素在输出中的范围。
+    pub fn output_range(&self, source_offset: usize) -> Option<Range<usize>> {
+        self.entries
+            .iter()
+            .filter(|entry| entry.source.contains(&source_offset))
+            .min_by_key(|entry| entry.source.len())
+            .map(|entry| entry.output.clone())
+    }
+
+    /// 返回生成了输出偏移量处内容的最内层元素在源码中的范围。
+    pub fn source_range(&self, output_offset: usize) -> Option<Range<usize>> {
+        self.entries
+            .iter()
+            .filter(|entry| entry.output.contains(&output_offset))
+            .min_by_key(|entry| entry.output.len())
+            .map(|entry| entry.source.clone())
+    }
+}
+
+/// 统计写入字节数的写入器。
+struct CountingWriter<'w> {
+    inner: &'w mut String,
+    len: &'w Cell<usize>,
+}
+
+impl core::fmt::Write for CountingWriter<'_> {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        self.inner.push_str(s);
+        self.len.set(self.inner.len());
+        Ok(())
+    }
+}
+
+/// 与 [`push_html_with_config`] 相同，但接收带偏移量的事件
+/// （见 [`Parser::into_offset_iter`](crate::Parser::into_offset_iter)），
+/// 并返回源码与输出之间的位置映射。
+///
+/// 映射作为单独的表返回，不会在标记中加入 `data-sourcepos` 之类的属性，
+/// 可用于预览窗格的点击跳转和滚动同步。输出范围是 `s` 中的字节偏移量。
+///
+/// 配置了 [`HtmlConfig::code_block_hook`] 时，代码块的文本在结束时整体输出，
+/// 块内每个文本事件的输出范围都是钩子输出的全部代码。
+/// [`HtmlConfig::footnote_placement`] 移动了位置的脚注定义不在原位输出，
+/// 其中各项的输出范围为空。
+///
+/// # 示例
+///
+/// ```
+/// use pulldown_cmark::{html, Parser};
+///
+/// let markdown_str = "# Title\n\nSome *text*.\n";
+/// let mut html_buf = String::new();
+/// let map = html::push_html_with_source_map(
+///     &mut html_buf,
+///     Parser::new(markdown_str).into_offset_iter(),
+///     Default::default(),
+/// );
+///
+/// let emphasis = markdown_str.find('*').unwrap();
+/// let output = map.output_range(emphasis).unwrap();
+/// assert_eq!(&html_buf[output.clone()], "<em>text</em>");
+/// assert_eq!(map.source_range(output.start), Some(emphasis..emphasis + 6));
+/// ```
+pub fn push_html_with_source_map<'a, I>(s: &mut String, iter: I, config: HtmlConfig) -> SourceMap
+where
+    I: Iterator<Item = (Event<'a>, Range<usize>)>,
+{
+    let len = Cell::new(s.len());
+    let mut entries: Vec<SourceMapEntry> = Vec::new();
+    let mut open = Vec::new();
+    // 上一个事件对应的项，在拉取下一个事件时其输出已经全部写出。
+    let mut pending: Option<usize> = None;
+    let has_code_block_hook = config.code_block_hook.is_some();
+    let mut in_code_block = false;
+    // 被钩子缓冲的代码块文本对应的项，以及代码块结束时缓冲输出的起点
+    let mut buffered: Vec<usize> = Vec::new();
+    let mut flush_start: Option<usize> = None;
+    let mut iter = iter.fuse();
+    let events = core::iter::from_fn(|| {
+        let now = len.get();
+        if let Some(ix) = pending.take() {
+            entries[ix].output.end = now;
+        }
+        if let Some(start) = flush_start.take() {
+            // 结束标签紧跟在钩子的输出之后
+            let end = now - "</code></pre>\n".len();
+            for ix in buffered.drain(..) {
+                entries[ix].output = start..end;
+            }
+        }
+        let (event, range) = iter.next()?;
+        match &event {
+            Start(tag) => {
+                in_code_block = has_code_block_hook && matches!(tag, Tag::CodeBlock(_));
+                open.push(entries.len());
+                entries.push(SourceMapEntry {
+                    source: range,
+                    output: now..now,
+                });
+            }
+            // 结束标签的输出计入对应的开始项
+            End(tag) => {
+                if in_code_block && *tag == TagEnd::CodeBlock {
+                    in_code_block = false;
+                    flush_start = Some(now);
+                }
+                pending = open.pop();
+            }
+            _ => {
+                if in_code_block {
+                    buffered.push(entries.len());
+                }
+                pending = Some(entries.len());
+                entries.push(SourceMapEntry {
+                    source: range,
+                    output: now..now,
+                });
+            }
+        }
+        Some(event)
+    });
+    let writer = CountingWriter {
+        inner: s,
+        len: &len,
+    };
+    HtmlWriter::new(events, FmtWriter(writer), config)
+        .run()
+        .unwrap();
+    SourceMap { entries }
+}
+
 /// 遍历 `Event` 迭代器，为每个 `Event` 生成HTML，
 /// 并将其写入 I/O 流。
 ///
@@ -640,7 +1702,21 @@ where
     I: Iterator<Item = Event<'a>>,
     W: std::io::Write,
 {
-    HtmlWriter::new(iter, IoWriter(writer)).run()
+    write_html_io_with_config(writer, iter, HtmlConfig::default())
+}
+
+/// 与 [`write_html_io`] 相同，但使用给定的渲染配置。
+#[cfg(feature = "std")]
+pub fn write_html_io_with_config<'a, I, W>(
+    writer: W,
+    iter: I,
+    config: HtmlConfig,
+) -> std::io::Result<()>
+where
+    I: Iterator<Item = Event<'a>>,
+    W: std::io::Write,
+{
+    HtmlWriter::new(iter, IoWriter(writer), config).run()
 }
 
 /// 遍历 `Event` 迭代器，为每个 `Event` 生成HTML，
@@ -675,5 +1751,470 @@ where
     I: Iterator<Item = Event<'a>>,
     W: core::fmt::Write,
 {
-    HtmlWriter::new(iter, FmtWriter(writer)).run()
+    write_html_fmt_with_config(writer, iter, HtmlConfig::default())
+}
+
+/// 与 [`write_html_fmt`] 相同，但使用给定的渲染配置。
+pub fn write_html_fmt_with_config<'a, I, W>(
+    writer: W,
+    iter: I,
+    config: HtmlConfig,
+) -> core::fmt::Result
+where
+    I: Iterator<Item = Event<'a>>,
+    W: core::fmt::Write,
+{
+    HtmlWriter::new(iter, FmtWriter(writer), config).run()
+}
+
+/// 逐块产生 HTML 的迭代器，见 [`HtmlStream::new`]。
+///
+/// 每次拉取时渲染事件，直到缓冲的输出达到块大小或事件耗尽，然后把这部分
+/// 输出作为一个 `String` 返回。调用方按自己的节奏拉取，就能把渲染和写出
+/// 交错进行，例如写入异步的响应体时由写入端提供背压，内存占用由块大小决定。
+///
+/// # 示例
+///
+/// ```
+/// use pulldown_cmark::{html::{self, HtmlStream}, Parser};
+///
+/// let markdown_str = "# Title\n\nSome *text*.\n".repeat(100);
+/// let chunks: Vec<String> =
+///     HtmlStream::new(Parser::new(&markdown_str), Default::default())
+///         .chunk_size(1024)
+///         .collect();
+/// assert!(chunks.len() > 1);
+///
+/// let mut html_buf = String::new();
+/// html::push_html(&mut html_buf, Parser::new(&markdown_str));
+/// assert_eq!(chunks.concat(), html_buf);
+/// ```
+pub struct HtmlStream<'a, I> {
+    writer: HtmlWriter<'a, I, String>,
+    chunk_size: usize,
+    done: bool,
+}
+
+impl<'a, I> HtmlStream<'a, I>
+where
+    I: Iterator<Item = Event<'a>>,
+{
+    /// 默认的块大小，单位为字节。
+    pub const DEFAULT_CHUNK_SIZE: usize = 8 * 1024;
+
+    /// 使用给定的渲染配置创建迭代器。
+    pub fn new(iter: I, config: HtmlConfig) -> Self {
+        Self {
+            writer: HtmlWriter::new(iter, String::new(), config),
+            chunk_size: Self::DEFAULT_CHUNK_SIZE,
+            done: false,
+        }
+    }
+
+    /// 设置块大小。单个事件的输出不会被拆开，所以块可能略大于这个值。
+    pub fn chunk_size(mut self, bytes: usize) -> Self {
+        self.chunk_size = bytes.max(1);
+        self
+    }
+}
+
+impl<'a, I> Iterator for HtmlStream<'a, I>
+where
+    I: Iterator<Item = Event<'a>>,
+{
+    type Item = String;
+
+    fn next(&mut self) -> Option<String> {
+        if self.done {
+            return None;
+        }
+        // 写入 String 不会失败
+        while self.writer.writer.len() < self.chunk_size {
+            match self.writer.next_event() {
+                Some(event) => self.writer.render_event(event).unwrap(),
+                None => {
+                    self.writer.finish().unwrap();
+                    self.done = true;
+                    break;
+                }
+            }
+        }
+        if self.writer.writer.is_empty() {
+            return None;
+        }
+        let capacity = if self.done {
+            0
+        } else {
+            self.writer.writer.len()
+        };
+        Some(core::mem::replace(
+            &mut self.writer.writer,
+            String::with_capacity(capacity),
+        ))
+    }
+}
+
+impl<'a, I> core::fmt::Debug for HtmlStream<'a, I> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("HtmlStream")
+            .field("buffered", &self.writer.writer.len())
+            .field("chunk_size", &self.chunk_size)
+            .field("done", &self.done)
+            .finish()
+    }
+}
+
+impl<'a, I> core::iter::FusedIterator for HtmlStream<'a, I> where I: Iterator<Item = Event<'a>> {}
+
+/// 遍历 `Event` 迭代器，为每个 `Event` 生成HTML，
+/// 并分块写入 `futures` 的异步写入器，每块写完后才继续渲染。
+///
+/// 渲染本身是同步的，每次只渲染一块（见 [`HtmlStream`]），
+/// 在等待写入时让出执行器，适合直接渲染到 Web 服务器的响应体。
+/// 写完所有内容后会刷新写入器。
+#[cfg(feature = "futures")]
+pub async fn write_html_async<'a, I, W>(writer: W, iter: I) -> std::io::Result<()>
+where
+    I: Iterator<Item = Event<'a>>,
+    W: futures_io::AsyncWrite + Unpin,
+{
+    write_html_async_with_config(writer, iter, HtmlConfig::default()).await
+}
+
+/// 与 [`write_html_async`] 相同，但使用给定的渲染配置。
+#[cfg(feature = "futures")]
+pub async fn write_html_async_with_config<'a, I, W>(
+    mut writer: W,
+    iter: I,
+    config: HtmlConfig,
+) -> std::io::Result<()>
+where
+    I: Iterator<Item = Event<'a>>,
+    W: futures_io::AsyncWrite + Unpin,
+{
+    use futures_io::AsyncWrite;
+
+    write_chunks(
+        &mut writer,
+        iter,
+        config,
+        AsyncWrite::poll_write,
+        AsyncWrite::poll_flush,
+    )
+    .await
+}
+
+/// 与 [`write_html_async`] 相同，但写入 `tokio` 的异步写入器。
+#[cfg(feature = "tokio")]
+pub async fn write_html_tokio<'a, I, W>(writer: W, iter: I) -> std::io::Result<()>
+where
+    I: Iterator<Item = Event<'a>>,
+    W: tokio::io::AsyncWrite + Unpin,
+{
+    write_html_tokio_with_config(writer, iter, HtmlConfig::default()).await
+}
+
+/// 与 [`write_html_tokio`] 相同，但使用给定的渲染配置。
+#[cfg(feature = "tokio")]
+pub async fn write_html_tokio_with_config<'a, I, W>(
+    mut writer: W,
+    iter: I,
+    config: HtmlConfig,
+) -> std::io::Result<()>
+where
+    I: Iterator<Item = Event<'a>>,
+    W: tokio::io::AsyncWrite + Unpin,
+{
+    use tokio::io::AsyncWrite;
+
+    write_chunks(
+        &mut writer,
+        iter,
+        config,
+        AsyncWrite::poll_write,
+        AsyncWrite::poll_flush,
+    )
+    .await
+}
+
+/// 把 [`HtmlStream`] 的各块依次写入异步写入器并刷新，`futures` 和 `tokio`
+/// 的写入器通过各自的 `poll_write` 和 `poll_flush` 共用这个循环。
+#[cfg(any(feature = "futures", feature = "tokio"))]
+async fn write_chunks<'a, I, W>(
+    writer: &mut W,
+    iter: I,
+    config: HtmlConfig,
+    mut poll_write: impl FnMut(
+        core::pin::Pin<&mut W>,
+        &mut core::task::Context<'_>,
+        &[u8],
+    ) -> core::task::Poll<std::io::Result<usize>>,
+    mut poll_flush: impl FnMut(
+        core::pin::Pin<&mut W>,
+        &mut core::task::Context<'_>,
+    ) -> core::task::Poll<std::io::Result<()>>,
+) -> std::io::Result<()>
+where
+    I: Iterator<Item = Event<'a>>,
+    W: Unpin,
+{
+    use core::pin::Pin;
+
+    for chunk in HtmlStream::new(iter, config) {
+        let mut buf = chunk.as_bytes();
+        while !buf.is_empty() {
+            let n = core::future::poll_fn(|cx| poll_write(Pin::new(writer), cx, buf)).await?;
+            if n == 0 {
+                return Err(std::io::ErrorKind::WriteZero.into());
+            }
+            buf = &buf[n..];
+        }
+    }
+    core::future::poll_fn(|cx| poll_flush(Pin::new(writer), cx)).await
+}
+
+/// 与 [`push_html_with_config`] 相同，但并行渲染各个顶层块。
+///
+/// 脚注编号先按出现顺序统一分配，然后每个顶层块分别渲染到独立的缓冲区，
+/// 最后按顺序拼接，输出与顺序渲染完全相同。
+/// 适合把整本书渲染为单个 HTML 文件这类大文档。
+///
+/// # 示例
+///
+/// ```
+/// use pulldown_cmark::{html::{self, HtmlConfig}, Options, Parser};
+///
+/// let markdown_str = "# Title\n\ntext[^1]\n\n[^1]: note\n";
+/// let events: Vec<_> = Parser::new_ext(markdown_str, Options::ENABLE_FOOTNOTES).collect();
+///
+/// let mut parallel = String::new();
+/// html::push_html_parallel(&mut parallel, &events, &HtmlConfig::default());
+///
+/// let mut sequential = String::new();
+/// html::push_html(&mut sequential, events.into_iter());
+/// assert_eq!(parallel, sequential);
+/// ```
+#[cfg(feature = "rayon")]
+pub fn push_html_parallel(s: &mut String, events: &[Event<'_>], config: &HtmlConfig) {
+    use rayon::prelude::*;
+
+    if config.footnote_placement != FootnotePlacement::InPlace {
+        push_html_with_config(s, events.iter().cloned(), config.clone());
+        return;
+    }
+
+    let blocks = top_level_blocks(events);
+    let numbers = Arc::new(footnote_numbers(events));
+    let render = |range: &Range<usize>, end_newline: bool| {
+        let mut html = String::new();
+        let mut writer = HtmlWriter::new(
+            events[range.clone()].iter().cloned(),
+            FmtWriter(&mut html),
+            config.clone(),
+        );
+        writer.end_newline = end_newline;
+        writer.resolved_numbers = Some(numbers.clone());
+        writer.render().unwrap();
+        let end_newline = writer.end_newline;
+        (html, end_newline)
+    };
+
+    // 每块都假设从新行开始渲染。极少数情况下前一块没有以换行结束，
+    // 此时按实际状态重新渲染该块，以保证输出与顺序渲染一致。
+    let mut rendered: Vec<_> = blocks.par_iter().map(|range| render(range, true)).collect();
+    let mut end_newline = true;
+    for (range, (html, block_end_newline)) in blocks.iter().zip(&mut rendered) {
+        if !end_newline {
+            (*html, *block_end_newline) = render(range, false);
+        }
+        s.push_str(html);
+        end_newline = *block_end_newline;
+    }
+}
+
+/// 将事件切分为顶层块，返回各块的事件范围。
+#[cfg(feature = "rayon")]
+fn top_level_blocks(events: &[Event<'_>]) -> Vec<Range<usize>> {
+    let mut blocks = Vec::new();
+    let mut depth = 0usize;
+    let mut start = 0;
+    for (ix, event) in events.iter().enumerate() {
+        match event {
+            Start(_) => depth += 1,
+            End(_) => depth = depth.saturating_sub(1),
+            _ => {}
+        }
+        if depth == 0 {
+            blocks.push(start..ix + 1);
+            start = ix + 1;
+        }
+    }
+    if start < events.len() {
+        blocks.push(start..events.len());
+    }
+    blocks
+}
+
+/// 按首次出现的顺序为脚注分配编号，与顺序渲染时的编号一致。
+#[cfg(feature = "rayon")]
+fn footnote_numbers<'a>(events: &[Event<'a>]) -> HashMap<CowStr<'a>, usize> {
+    let mut numbers = HashMap::new();
+    for event in events {
+        if let FootnoteReference(name) | Start(Tag::FootnoteDefinition(name)) = event {
+            let len = numbers.len() + 1;
+            numbers.entry(name.clone()).or_insert(len);
+        }
+    }
+    numbers
+}
+
+/// [`debug_report`] 的输出格式。
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ReportFormat {
+    /// 独立的 HTML 页面。
+    #[default]
+    Html,
+    /// 单个 JSON 对象。
+    Json,
+}
+
+/// 生成输入的综合调试报告，便于提交问题和排查方言与选项之间的相互影响。
+///
+/// 报告包含：启用的选项、实际用到的扩展（见
+/// [`used_extensions`](crate::utils::used_extensions)）、带源码范围的事件序列、
+/// 块级元素树以及渲染出的 HTML。事件和标签按其 `Debug` 形式输出。HTML 格式中的渲染预览
+/// 位于带 `sandbox` 属性的 `iframe` 内，因此可以放心打开来自不可信输入的报告。
+///
+/// JSON 格式的字段为 `options`、`extensions`（标志名称的数组）、`events`
+/// （`{"event", "span"}` 的数组）、`tree`（`{"tag", "span", "children"}` 的嵌套数组）
+/// 和 `html`。
+///
+/// ```
+/// use pulldown_cmark::{html::{self, ReportFormat}, Options};
+///
+/// let report = html::debug_report("~~a~~\n", Options::ENABLE_STRIKETHROUGH, ReportFormat::Json);
+/// assert!(report.starts_with(r#"{"options":"strikethrough","extensions":["strikethrough"],"#));
+/// assert!(report.contains(r#"{"event":"Start(Paragraph(None))","span":[0,6]}"#));
+/// assert!(report.ends_with(r#""html":"<p><del>a</del></p>\n"}"#));
+/// ```
+pub fn debug_report(input: &str, options: Options, format: ReportFormat) -> String {
+    let events: Vec<_> = Parser::new_ext(input, options).into_offset_iter().collect();
+    let extensions = used_extensions(input, options);
+    let mut html = String::new();
+    push_html(&mut html, events.iter().map(|(event, _)| event.clone()));
+
+    let mut out = String::new();
+    match format {
+        ReportFormat::Html => {
+            out.push_str("<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n");
+            out.push_str("<title>pulldown-cmark debug report</title>\n</head>\n<body>\n");
+            out.push_str("<h2>Options</h2>\n<pre>");
+            report_escape(&mut out, &options.to_string());
+            out.push_str("</pre>\n<h2>Extensions used</h2>\n<pre>");
+            report_escape(&mut out, &extensions.to_string());
+            out.push_str("</pre>\n<h2>Input</h2>\n<pre>");
+            report_escape(&mut out, input);
+            out.push_str("</pre>\n<h2>Events</h2>\n<table>\n");
+            out.push_str("<tr><th>#</th><th>Span</th><th>Event</th></tr>\n");
+            for (ix, (event, span)) in events.iter().enumerate() {
+                out.push_str(&format!(
+                    "<tr><td>{}</td><td>{}..{}</td><td><code>",
+                    ix, span.start, span.end
+                ));
+                report_escape(&mut out, &format!("{:?}", event));
+                out.push_str("</code></td></tr>\n");
+            }
+            out.push_str("</table>\n<h2>Block tree</h2>\n<ul>\n");
+            for (event, span) in &events {
+                match event {
+                    Start(tag) if !is_inline_tag(&tag.to_end()) => {
+                        out.push_str("<li><code>");
+                        report_escape(&mut out, &format!("{:?}", tag));
+                        out.push_str(&format!("</code> {}..{}\n<ul>\n", span.start, span.end));
+                    }
+                    End(tag) if !is_inline_tag(tag) => out.push_str("</ul>\n</li>\n"),
+                    _ => {}
+                }
+            }
+            out.push_str("</ul>\n<h2>HTML</h2>\n<pre>");
+            report_escape(&mut out, &html);
+            // 渲染结果放进沙箱 iframe，输入中的原始 HTML 和脚本不会作用于报告页面本身。
+            out.push_str("</pre>\n<h2>Rendered</h2>\n<iframe class=\"rendered\" sandbox srcdoc=\"");
+            report_escape(&mut out, &html);
+            out.push_str("\"></iframe>\n</body>\n</html>\n");
+        }
+        ReportFormat::Json => {
+            out.push_str("{\"options\":");
+            json_string(&mut out, &options.to_string());
+            out.push_str(",\"extensions\":[");
+            let names = extensions.to_string();
+            for (ix, name) in names.split(',').filter(|name| !name.is_empty()).enumerate() {
+                if ix > 0 {
+                    out.push(',');
+                }
+                json_string(&mut out, name);
+            }
+            out.push_str("],\"events\":[");
+            for (ix, (event, span)) in events.iter().enumerate() {
+                if ix > 0 {
+                    out.push(',');
+                }
+                out.push_str("{\"event\":");
+                json_string(&mut out, &format!("{:?}", event));
+                out.push_str(&format!(",\"span\":[{},{}]}}", span.start, span.end));
+            }
+            out.push_str("],\"tree\":[");
+            // 每层记录是否已经输出过子节点，用于决定是否需要逗号。
+            let mut has_children = vec![false];
+            for (event, span) in &events {
+                match event {
+                    Start(tag) if !is_inline_tag(&tag.to_end()) => {
+                        if let Some(last) = has_children.last_mut() {
+                            if core::mem::replace(last, true) {
+                                out.push(',');
+                            }
+                        }
+                        out.push_str("{\"tag\":");
+                        json_string(&mut out, &format!("{:?}", tag));
+                        out.push_str(&format!(
+                            ",\"span\":[{},{}],\"children\":[",
+                            span.start, span.end
+                        ));
+                        has_children.push(false);
+                    }
+                    End(tag) if !is_inline_tag(tag) => {
+                        has_children.pop();
+                        out.push_str("]}");
+                    }
+                    _ => {}
+                }
+            }
+            out.push_str("],\"html\":");
+            json_string(&mut out, &html);
+            out.push('}');
+        }
+    }
+    out
+}
+
+/// 为调试报告转义 HTML 文本。
+fn report_escape(out: &mut String, text: &str) {
+    escape_html(out, text).unwrap();
+}
+
+/// 写入 JSON 字符串字面量。
+fn json_string(out: &mut String, text: &str) {
+    out.push('"');
+    for c in text.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
 }
@@ -88,7 +88,7 @@ extern crate std;
 #[cfg(all(not(feature = "std"), not(feature = "hashbrown")))]
 compile_error!("\"hashbrown\" feature should be enabled in \"no_std\" environment.");
 
-use alloc::vec::Vec;
+use alloc::{boxed::Box, vec::Vec};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -97,7 +97,13 @@ use serde::{Deserialize, Serialize};
 pub mod html;
 
 pub mod utils;
+pub mod ast;
 pub mod chunk;
+pub mod context;
+#[cfg(feature = "testing")]
+pub mod testing;
+pub mod transform;
+pub mod visit;
 
 mod entities;
 mod firstpass;
@@ -112,13 +118,166 @@ use core::fmt::Display;
 
 pub use crate::{
     parse::{
-        BrokenLink, BrokenLinkCallback, DefaultParserCallbacks, OffsetIter, Parser,
-        ParserCallbacks, RefDefs,
+        BrokenFootnote, BrokenLink, BrokenLinkCallback, BudgetedParser, DefaultParserCallbacks,
+        Diagnostic, DiagnosticKind, DialectConfig, FootnoteDefs, OffsetIter, ParseArena, Parser,
+        ParserBuilder, ParserCallbacks, RefDefs,
     },
     strings::{CowStr, InlineStr},
     utils::*,
 };
 
+/// 使用给定的选项将 Markdown 渲染为 HTML。
+///
+/// 等价于将 [`Parser::new_ext`] 的输出交给 [`html::push_html`]。
+///
+/// ```
+/// use pulldown_cmark::{to_html, Options};
+///
+/// let html = to_html("Hello ~~world~~ *you*", Options::ENABLE_STRIKETHROUGH);
+/// assert_eq!(html, "<p>Hello <del>world</del> <em>you</em></p>\n");
+/// ```
+#[cfg(feature = "html")]
+pub fn to_html(input: &str, options: Options) -> alloc::string::String {
+    to_html_with_config(input, options, html::HtmlConfig::default())
+}
+
+/// 与 [`to_html`] 相同，但使用给定的渲染配置。
+///
+/// ```
+/// use pulldown_cmark::{html::HtmlConfig, to_html_with_config, utils::UrlPolicy, Options};
+///
+/// let config = HtmlConfig {
+///     url_policy: Some(UrlPolicy::default()),
+///     ..Default::default()
+/// };
+/// let html = to_html_with_config("![x](javascript:alert(1))", Options::empty(), config);
+/// assert_eq!(html, "<p><img src=\"\" alt=\"x\" /></p>\n");
+/// ```
+#[cfg(feature = "html")]
+pub fn to_html_with_config(
+    input: &str,
+    options: Options,
+    config: html::HtmlConfig,
+) -> alloc::string::String {
+    let mut output = alloc::string::String::with_capacity(input.len() * 3 / 2);
+    html::push_html_with_config(&mut output, Parser::new_ext(input, options), config);
+    output
+}
+
+/// 解析 Markdown 并收集全部事件。
+///
+/// ```
+/// use pulldown_cmark::{to_events, Event, Options, Tag, TagEnd};
+///
+/// assert_eq!(
+///     to_events("Hi", Options::empty()),
+///     [
+///         Event::Start(Tag::Paragraph(None)),
+///         Event::Text("Hi".into()),
+///         Event::End(TagEnd::Paragraph),
+///     ]
+/// );
+/// ```
+pub fn to_events(input: &str, options: Options) -> Vec<Event<'_>> {
+    Parser::new_ext(input, options).collect()
+}
+
+/// 标题、图片或容器块的类和自定义属性，见[`Tag::Heading`]、[`ImageExtras`]和
+/// [`Tag::ContainerBlock`]。
+///
+/// 大多数标题、图片和容器块没有这些属性，单独装箱可以让[`Tag`]保持较小。
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Attributes<'a> {
+    /// 以`.`为前缀的类。
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub classes: Vec<CowStr<'a>>,
+    /// 自定义属性。元组的第一个项是属性，第二个项是值。
+    pub attrs: Vec<(CowStr<'a>, Option<CowStr<'a>>)>,
+}
+
+impl<'a> Attributes<'a> {
+    /// 将类和自定义属性装箱，两者都为空时返回`None`，用于构造[`Tag::Heading`]
+    /// 和[`Tag::ContainerBlock`]。
+    pub fn boxed(
+        classes: Vec<CowStr<'a>>,
+        attrs: Vec<(CowStr<'a>, Option<CowStr<'a>>)>,
+    ) -> Option<Box<Self>> {
+        if classes.is_empty() && attrs.is_empty() {
+            None
+        } else {
+            Some(Box::new(Self { classes, attrs }))
+        }
+    }
+
+    /// 是否既没有类也没有自定义属性。
+    pub fn is_empty(&self) -> bool {
+        self.classes.is_empty() && self.attrs.is_empty()
+    }
+
+    /// 所有字符串是否都是借用的切片。
+    fn is_borrowed(&self) -> bool {
+        self.classes.iter().all(CowStr::is_borrowed)
+            && self
+                .attrs
+                .iter()
+                .all(|(k, v)| k.is_borrowed() && v.as_ref().map_or(true, CowStr::is_borrowed))
+    }
+
+    /// 复制所有借用的字符串，得到不依赖输入生命周期的属性。
+    pub fn into_static(self) -> Attributes<'static> {
+        Attributes {
+            classes: self.classes.into_iter().map(|s| s.into_static()).collect(),
+            attrs: self
+                .attrs
+                .into_iter()
+                .map(|(k, v)| (k.into_static(), v.map(|s| s.into_static())))
+                .collect(),
+        }
+    }
+}
+
+/// 图片中较少出现的部分，见[`Tag::Image`]。
+///
+/// 大多数图片既不是引用图片也没有属性块，单独装箱可以让[`Tag`]保持较小。
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ImageExtras<'a> {
+    /// 引用链接的标识符，例如图片`![hello][world]`中的`world`。
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub id: CowStr<'a>,
+    /// 内联图片之后属性块中的类和属性，如`![a](a.png){.wide srcset="a2.png 2x"}`，
+    /// 其中`#id`作为`id`属性。仅在使用[`Options::ENABLE_IMAGE_ATTRIBUTES`]时解析。
+    pub attributes: Attributes<'a>,
+}
+
+impl<'a> ImageExtras<'a> {
+    /// 将引用标识符和属性装箱，两者都为空时返回`None`，用于构造[`Tag::Image`]。
+    pub fn boxed(id: CowStr<'a>, attributes: Option<Box<Attributes<'a>>>) -> Option<Box<Self>> {
+        if id.is_empty() && attributes.is_none() {
+            None
+        } else {
+            Some(Box::new(Self {
+                id,
+                attributes: attributes.map(|a| *a).unwrap_or_default(),
+            }))
+        }
+    }
+
+    /// 所有字符串是否都是借用的切片。
+    fn is_borrowed(&self) -> bool {
+        self.id.is_borrowed() && self.attributes.is_borrowed()
+    }
+
+    /// 复制所有借用的字符串，得到不依赖输入生命周期的部分。
+    pub fn into_static(self) -> ImageExtras<'static> {
+        ImageExtras {
+            id: self.id.into_static(),
+            attributes: self.attributes.into_static(),
+        }
+    }
+}
+
 /// 代码块类型。
 #[derive(Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -178,35 +337,59 @@ pub enum MetadataBlockKind {
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum Tag<'a> {
     /// 文本和其他内联元素的段落。
-    Paragraph,
+    ///
+    /// 字段是段落的块标识符，不包含开头的`^`，仅在使用[`Options::ENABLE_BLOCK_IDS`]时解析和填充。
+    /// 紧凑列表项中的段落不发出标签，其块标识符属于[`Tag::Item`]。
+    Paragraph(Option<CowStr<'a>>),
 
     /// 标题，带有可选的标识符、类和自定义属性。
     /// 标识符以`#`为前缀，选择属性列表中的最后一个，
     /// 类以`.`为前缀，自定义属性没有前缀，可以选择性地有值（`myattr`或`myattr=myvalue`）。
     ///
-    /// `id`、`classes`和`attrs`仅在使用[`Options::ENABLE_HEADING_ATTRIBUTES`]时解析和填充，否则为`None`或空。
+    /// `id`和`attributes`仅在使用[`Options::ENABLE_HEADING_ATTRIBUTES`]时解析和填充，否则为`None`。
+    /// 类和自定义属性也可以通过[`Tag::heading_classes`]和[`Tag::heading_attrs`]访问。
     Heading {
         level: HeadingLevel,
         id: Option<CowStr<'a>>,
-        classes: Vec<CowStr<'a>>,
-        /// 元组的第一个项是属性，第二个项是值。
-        attrs: Vec<(CowStr<'a>, Option<CowStr<'a>>)>,
+        /// 类和自定义属性，两者都为空时为`None`。
+        attributes: Option<Box<Attributes<'a>>>,
     },
 
     /// 引用块。
     ///
-    /// `BlockQuoteKind`仅在使用[`Options::ENABLE_GFM`]时解析和填充，否则为`None`。
+    /// `kind`、`folded`和`title`仅在使用[`Options::ENABLE_GFM`]时解析和填充，否则为`None`。
     ///
     /// ```markdown
     /// > 常规引用
     ///
     /// > [!NOTE]
     /// > 注意引用
+    ///
+    /// > [!TIP]- 自定义标题
+    /// > 默认折叠的引用
     /// ```
-    BlockQuote(Option<BlockQuoteKind>),
+    BlockQuote {
+        kind: Option<BlockQuoteKind>,
+        /// Obsidian风格的折叠标记：`[!NOTE]-`为`Some(true)`（默认折叠），
+        /// `[!NOTE]+`为`Some(false)`（可折叠，默认展开），没有标记时为`None`。
+        folded: Option<bool>,
+        /// 类型标记后面同一行的自定义标题，按原文保留。
+        title: Option<CowStr<'a>>,
+    },
     /// 代码块。
     CodeBlock(CodeBlockKind<'a>),
-    ContainerBlock(ContainerKind, CowStr<'a>),
+    /// 容器扩展块，见[`Options::ENABLE_CONTAINER_EXTENSIONS`]。
+    ///
+    /// 第二个字段对[`ContainerKind::Spoiler`]是摘要，对[`ContainerKind::Default`]是类型名。
+    /// 第三个字段是信息字符串末尾属性块中的类和自定义属性，`#id`作为`id`属性保存，
+    /// 没有属性块时为`None`。
+    ///
+    /// ```markdown
+    /// ::: spoiler 摘要 {#id .class open}
+    /// 内容
+    /// :::
+    /// ```
+    ContainerBlock(ContainerKind, CowStr<'a>, Option<Box<Attributes<'a>>>),
 
     /// HTML块。
     ///
@@ -230,8 +413,10 @@ pub enum Tag<'a> {
     /// 列表。如果列表是有序的，字段指示第一项的编号。
     /// 仅包含列表项。
     List(Option<u64>), // TODO: 为AST添加分隔符和紧凑模式（HTML不需要）
-    /// 列表项。
-    Item,
+    /// 列表项。有序列表项包含其书写的编号，即使编号不连续。
+    ///
+    /// 第二个字段是紧凑列表项中段落的块标识符，见[`Tag::Paragraph`]。
+    Item(Option<u64>, Option<CowStr<'a>>),
     /// 脚注定义。包含的值是脚注的标签，通过该标签可以引用它。
     ///
     /// 仅在使用[`Options::ENABLE_FOOTNOTES`]或[`Options::ENABLE_OLD_FOOTNOTES`]时解析和发出。
@@ -252,10 +437,15 @@ pub enum Tag<'a> {
     /// 没有`TableBody`标签。
     /// 仅在使用[`Options::ENABLE_TABLES`]时解析和发出。
     TableHead,
-    /// 表格行。用于表头行和表体行。仅包含`TableCell`。
+    /// 表尾。仅包含`TableRow`，位于表体之后。
+    /// 仅在使用[`Options::ENABLE_TABLE_FOOTERS`]时解析和发出。
+    TableFoot,
+    /// 表格行。用于表头行、表体行和表尾行。仅包含`TableCell`。
     TableRow,
+    /// 表格单元格。包含覆盖所在列对齐方式的单元格对齐方式，
+    /// 只有使用[`Options::ENABLE_CELL_ALIGNMENT`]时才可能为`Some`。
     /// 仅在使用[`Options::ENABLE_TABLES`]时解析和发出。
-    TableCell,
+    TableCell(Option<Alignment>),
 
     // 跨度级别标签
     /// [Emphasis](https://spec.commonmark.org/0.31.2/#emphasis-and-strong-emphasis).
@@ -295,14 +485,14 @@ pub enum Tag<'a> {
         id: CowStr<'a>,
     },
 
-    /// 图片。第一个字段是链接类型，第二个是目标URL，第三个是标题，
-    /// 第四个是链接标识符。
+    /// 图片。第一个字段是链接类型，第二个是目标URL，第三个是标题。
     Image {
         link_type: LinkType,
         dest_url: CowStr<'a>,
         title: CowStr<'a>,
-        /// 引用链接的标识符，例如链接`[hello][world]`中的`world`。
-        id: CowStr<'a>,
+        /// 链接标识符和属性块，两者都为空时为`None`。
+        /// 也可以通过[`Tag::image_id`]和[`Tag::image_attributes`]访问。
+        extras: Option<Box<ImageExtras<'a>>>,
     },
 
     /// 元数据块。
@@ -314,19 +504,20 @@ pub enum Tag<'a> {
 impl<'a> Tag<'a> {
     pub fn to_end(&self) -> TagEnd {
         match self {
-            Tag::Paragraph => TagEnd::Paragraph,
+            Tag::Paragraph(_) => TagEnd::Paragraph,
             Tag::Heading { level, .. } => TagEnd::Heading(*level),
-            Tag::BlockQuote(kind) => TagEnd::BlockQuote(*kind),
+            Tag::BlockQuote { kind, .. } => TagEnd::BlockQuote(*kind),
             Tag::CodeBlock(_) => TagEnd::CodeBlock,
-            Tag::ContainerBlock(kind, _) => TagEnd::ContainerBlock(*kind),
+            Tag::ContainerBlock(kind, ..) => TagEnd::ContainerBlock(*kind),
             Tag::HtmlBlock => TagEnd::HtmlBlock,
             Tag::List(number) => TagEnd::List(number.is_some()),
-            Tag::Item => TagEnd::Item,
+            Tag::Item(..) => TagEnd::Item,
             Tag::FootnoteDefinition(_) => TagEnd::FootnoteDefinition,
             Tag::Table(_) => TagEnd::Table,
             Tag::TableHead => TagEnd::TableHead,
+            Tag::TableFoot => TagEnd::TableFoot,
             Tag::TableRow => TagEnd::TableRow,
-            Tag::TableCell => TagEnd::TableCell,
+            Tag::TableCell(_) => TagEnd::TableCell,
             Tag::Subscript => TagEnd::Subscript,
             Tag::Superscript => TagEnd::Superscript,
             Tag::Emphasis => TagEnd::Emphasis,
@@ -341,34 +532,145 @@ impl<'a> Tag<'a> {
         }
     }
 
+    /// 标题的类。不是标题或没有类时为空。
+    ///
+    /// ```
+    /// use pulldown_cmark::{CowStr, Event, Options, Parser};
+    ///
+    /// let mut parser = Parser::new_ext("# Title {#top .a .b}", Options::ENABLE_HEADING_ATTRIBUTES);
+    /// let Some(Event::Start(tag)) = parser.next() else {
+    ///     unreachable!()
+    /// };
+    /// assert_eq!(tag.heading_classes(), [CowStr::from("a"), "b".into()]);
+    /// assert!(tag.heading_attrs().is_empty());
+    /// ```
+    pub fn heading_classes(&self) -> &[CowStr<'a>] {
+        match self {
+            Tag::Heading {
+                attributes: Some(attributes),
+                ..
+            } => &attributes.classes,
+            _ => &[],
+        }
+    }
+
+    /// 标题的自定义属性。不是标题或没有属性时为空。
+    pub fn heading_attrs(&self) -> &[(CowStr<'a>, Option<CowStr<'a>>)] {
+        match self {
+            Tag::Heading {
+                attributes: Some(attributes),
+                ..
+            } => &attributes.attrs,
+            _ => &[],
+        }
+    }
+
+    /// 图片的链接标识符。不是图片或不是引用图片时为空。
+    ///
+    /// ```
+    /// use pulldown_cmark::{Event, Parser};
+    ///
+    /// let mut parser = Parser::new("![a][logo]\n\n[logo]: logo.png");
+    /// let Some(Event::Start(tag)) = parser.nth(1) else {
+    ///     unreachable!()
+    /// };
+    /// assert_eq!(tag.image_id(), "logo");
+    /// assert!(tag.image_attributes().is_none());
+    /// ```
+    pub fn image_id(&self) -> &str {
+        match self {
+            Tag::Image {
+                extras: Some(extras),
+                ..
+            } => &extras.id,
+            _ => "",
+        }
+    }
+
+    /// 图片的属性块。不是图片或没有属性块时为`None`。
+    pub fn image_attributes(&self) -> Option<&Attributes<'a>> {
+        match self {
+            Tag::Image {
+                extras: Some(extras),
+                ..
+            } if !extras.attributes.is_empty() => Some(&extras.attributes),
+            _ => None,
+        }
+    }
+
+    /// 标签中的所有字符串是否都是借用的切片，参见[`Event::is_borrowed`]。
+    pub fn is_borrowed(&self) -> bool {
+        match self {
+            Tag::Heading { id, attributes, .. } => {
+                id.as_ref().map_or(true, CowStr::is_borrowed)
+                    && attributes.as_deref().map_or(true, Attributes::is_borrowed)
+            }
+            Tag::Paragraph(block_id) | Tag::Item(_, block_id) => {
+                block_id.as_ref().map_or(true, CowStr::is_borrowed)
+            }
+            Tag::BlockQuote { title, .. } => title.as_ref().map_or(true, CowStr::is_borrowed),
+            Tag::ContainerBlock(_, s, attributes) => {
+                s.is_borrowed() && attributes.as_deref().map_or(true, Attributes::is_borrowed)
+            }
+            Tag::CodeBlock(CodeBlockKind::Fenced(s)) | Tag::FootnoteDefinition(s) => {
+                s.is_borrowed()
+            }
+            Tag::Link {
+                dest_url,
+                title,
+                id,
+                ..
+            } => dest_url.is_borrowed() && title.is_borrowed() && id.is_borrowed(),
+            Tag::Image {
+                dest_url,
+                title,
+                extras,
+                ..
+            } => {
+                dest_url.is_borrowed()
+                    && title.is_borrowed()
+                    && extras.as_deref().map_or(true, ImageExtras::is_borrowed)
+            }
+            _ => true,
+        }
+    }
+
     pub fn into_static(self) -> Tag<'static> {
         match self {
-            Tag::Paragraph => Tag::Paragraph,
+            Tag::Paragraph(block_id) => Tag::Paragraph(block_id.map(|s| s.into_static())),
             Tag::Heading {
                 level,
                 id,
-                classes,
-                attrs,
+                attributes,
             } => Tag::Heading {
                 level,
                 id: id.map(|s| s.into_static()),
-                classes: classes.into_iter().map(|s| s.into_static()).collect(),
-                attrs: attrs
-                    .into_iter()
-                    .map(|(k, v)| (k.into_static(), v.map(|s| s.into_static())))
-                    .collect(),
+                attributes: attributes.map(|a| Box::new(a.into_static())),
+            },
+            Tag::BlockQuote {
+                kind,
+                folded,
+                title,
+            } => Tag::BlockQuote {
+                kind,
+                folded,
+                title: title.map(|s| s.into_static()),
             },
-            Tag::BlockQuote(k) => Tag::BlockQuote(k),
             Tag::CodeBlock(kb) => Tag::CodeBlock(kb.into_static()),
-            Tag::ContainerBlock(k, s) => Tag::ContainerBlock(k, s.into_static()),
+            Tag::ContainerBlock(k, s, attributes) => Tag::ContainerBlock(
+                k,
+                s.into_static(),
+                attributes.map(|a| Box::new(a.into_static())),
+            ),
             Tag::HtmlBlock => Tag::HtmlBlock,
             Tag::List(v) => Tag::List(v),
-            Tag::Item => Tag::Item,
+            Tag::Item(number, block_id) => Tag::Item(number, block_id.map(|s| s.into_static())),
             Tag::FootnoteDefinition(a) => Tag::FootnoteDefinition(a.into_static()),
             Tag::Table(v) => Tag::Table(v),
             Tag::TableHead => Tag::TableHead,
+            Tag::TableFoot => Tag::TableFoot,
             Tag::TableRow => Tag::TableRow,
-            Tag::TableCell => Tag::TableCell,
+            Tag::TableCell(a) => Tag::TableCell(a),
             Tag::Emphasis => Tag::Emphasis,
             Tag::Strong => Tag::Strong,
             Tag::Strikethrough => Tag::Strikethrough,
@@ -389,12 +691,12 @@ impl<'a> Tag<'a> {
                 link_type,
                 dest_url,
                 title,
-                id,
+                extras,
             } => Tag::Image {
                 link_type,
                 dest_url: dest_url.into_static(),
                 title: title.into_static(),
-                id: id.into_static(),
+                extras: extras.map(|e| Box::new(e.into_static())),
             },
             Tag::MetadataBlock(v) => Tag::MetadataBlock(v),
             Tag::DefinitionList => Tag::DefinitionList,
@@ -428,6 +730,7 @@ pub enum TagEnd {
 
     Table,
     TableHead,
+    TableFoot,
     TableRow,
     TableCell,
 
@@ -448,6 +751,11 @@ pub enum TagEnd {
 #[cfg(target_pointer_width = "64")]
 const _STATIC_ASSERT_TAG_END_SIZE: [(); 2] = [(); core::mem::size_of::<TagEnd>()];
 
+/// 确保`Event`的大小不超过80字节。
+/// 标题和图片中较少出现的部分因此装箱，见[`Attributes`]和[`ImageExtras`]。
+#[cfg(all(target_pointer_width = "64", not(feature = "large-inline-str")))]
+const _STATIC_ASSERT_EVENT_SIZE: () = assert!(core::mem::size_of::<Event>() <= 80);
+
 impl<'a> From<Tag<'a>> for TagEnd {
     fn from(value: Tag) -> Self {
         value.to_end()
@@ -500,7 +808,7 @@ impl TryFrom<usize> for HeadingLevel {
 }
 
 /// 内联链接的类型说明符。更多信息请参见[Tag::Link](enum.Tag.html#variant.Link)。
-#[derive(Clone, Debug, PartialEq, Copy)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Copy)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum LinkType {
     /// 内联链接，如`[foo](bar)`
@@ -521,6 +829,11 @@ pub enum LinkType {
     Autolink,
     /// 自动链接中的电子邮件地址，如`<john@example.org>`
     Email,
+    /// 不带尖括号的自动链接字面量，如`https://foo.bar`或`www.foo.bar`，
+    /// 见[`Options::ENABLE_AUTOLINK_LITERALS`]
+    AutolinkLiteral,
+    /// 不带尖括号的电子邮件地址字面量，如`john@example.org`
+    EmailLiteral,
     /// 维基链接，如`[[foo]]`或`[[foo|bar]]`
     WikiLink {
         /// 如果维基链接是管道形式的则为`true`。
@@ -532,6 +845,22 @@ pub enum LinkType {
 }
 
 impl LinkType {
+    /// 是否为自动链接，包括不带尖括号的字面量。自动链接的文本就是书写的地址。
+    pub fn is_autolink(self) -> bool {
+        matches!(
+            self,
+            LinkType::Autolink
+                | LinkType::Email
+                | LinkType::AutolinkLiteral
+                | LinkType::EmailLiteral
+        )
+    }
+
+    /// 是否为电子邮件地址，此时目标地址不带`mailto:`。
+    pub fn is_email(self) -> bool {
+        matches!(self, LinkType::Email | LinkType::EmailLiteral)
+    }
+
     /// 将链接类型映射到等效的未知链接类型。
     fn to_unknown(self) -> Self {
         match self {
@@ -564,24 +893,38 @@ pub enum Event<'a> {
     /// ```markdown
     /// `code`
     /// ```
-    #[cfg_attr(feature = "serde", serde(borrow))]
-    Code(CowStr<'a>),
+    ///
+    /// 同时携带源文本中的定界符，以便重新写出Markdown。
+    Code(
+        #[cfg_attr(feature = "serde", serde(borrow))] CowStr<'a>,
+        CodeDelimiter,
+    ),
     /// An inline math environment node.
     /// Requires [`Options::ENABLE_MATH`].
     ///
     /// ```markdown
     /// $math$
     /// ```
-    #[cfg_attr(feature = "serde", serde(borrow))]
-    InlineMath(CowStr<'a>),
+    ///
+    /// The syntax is always [`MathSyntax::Tex`] unless
+    /// [`Options::ENABLE_ASCIIMATH`] is enabled.
+    InlineMath(
+        #[cfg_attr(feature = "serde", serde(borrow))] CowStr<'a>,
+        MathSyntax,
+    ),
     /// A display math environment node.
     /// Requires [`Options::ENABLE_MATH`].
     ///
     /// ```markdown
     /// $$math$$
     /// ```
-    #[cfg_attr(feature = "serde", serde(borrow))]
-    DisplayMath(CowStr<'a>),
+    ///
+    /// The syntax is always [`MathSyntax::Tex`] unless
+    /// [`Options::ENABLE_ASCIIMATH`] is enabled.
+    DisplayMath(
+        #[cfg_attr(feature = "serde", serde(borrow))] CowStr<'a>,
+        MathSyntax,
+    ),
     /// An HTML node.
     ///
     /// A line of HTML inside [`Tag::HtmlBlock`] includes the line break.
@@ -616,7 +959,7 @@ pub enum Event<'a> {
     /// breaks
     /// ```
     /// *`·`是一个空格*
-    HardBreak,
+    HardBreak(HardBreakKind),
     /// A horizontal ruler.
     ///
     /// ```markdown
@@ -633,24 +976,67 @@ pub enum Event<'a> {
     /// - [x] checked
     /// ```
     TaskListMarker(bool),
+    /// 行内标签，不包含开头的`#`。仅在使用[`Options::ENABLE_HASHTAGS`]时解析和发出。
+    ///
+    /// ```markdown
+    /// #project/alpha
+    /// ```
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    Hashtag(CowStr<'a>),
 }
 
 impl<'a> Event<'a> {
+    /// 事件中的所有字符串是否都是借用的切片，即没有复制任何文本。
+    ///
+    /// 解析器只在必须改写文本时才会复制：含转义或实体的链接标题、
+    /// 跨行的行内代码、智能标点实际生成的字符等。其余情况下，
+    /// 包括启用了智能标点但文本中没有可替换的字符时，
+    /// 产生的事件都满足此条件，高吞吐场景可以据此确认没有隐藏的复制。
+    ///
+    /// ```
+    /// use pulldown_cmark::{Options, Parser};
+    ///
+    /// let parser = Parser::new_ext("*plain* `code` [link](/url)", Options::ENABLE_SMART_PUNCTUATION);
+    /// assert!(parser.into_iter().all(|event| event.is_borrowed()));
+    ///
+    /// let parser = Parser::new_ext("it's", Options::ENABLE_SMART_PUNCTUATION);
+    /// assert!(!parser.into_iter().all(|event| event.is_borrowed()));
+    /// ```
+    pub fn is_borrowed(&self) -> bool {
+        match self {
+            Event::Start(tag) => tag.is_borrowed(),
+            Event::Text(s)
+            | Event::Code(s, _)
+            | Event::InlineMath(s, _)
+            | Event::DisplayMath(s, _)
+            | Event::Html(s)
+            | Event::InlineHtml(s)
+            | Event::FootnoteReference(s)
+            | Event::Hashtag(s) => s.is_borrowed(),
+            Event::End(_)
+            | Event::SoftBreak
+            | Event::HardBreak(_)
+            | Event::Rule
+            | Event::TaskListMarker(_) => true,
+        }
+    }
+
     pub fn into_static(self) -> Event<'static> {
         match self {
             Event::Start(t) => Event::Start(t.into_static()),
             Event::End(e) => Event::End(e),
             Event::Text(s) => Event::Text(s.into_static()),
-            Event::Code(s) => Event::Code(s.into_static()),
-            Event::InlineMath(s) => Event::InlineMath(s.into_static()),
-            Event::DisplayMath(s) => Event::DisplayMath(s.into_static()),
+            Event::Code(s, delimiter) => Event::Code(s.into_static(), delimiter),
+            Event::InlineMath(s, syntax) => Event::InlineMath(s.into_static(), syntax),
+            Event::DisplayMath(s, syntax) => Event::DisplayMath(s.into_static(), syntax),
             Event::Html(s) => Event::Html(s.into_static()),
             Event::InlineHtml(s) => Event::InlineHtml(s.into_static()),
             Event::FootnoteReference(s) => Event::FootnoteReference(s.into_static()),
             Event::SoftBreak => Event::SoftBreak,
-            Event::HardBreak => Event::HardBreak,
+            Event::HardBreak(kind) => Event::HardBreak(kind),
             Event::Rule => Event::Rule,
             Event::TaskListMarker(b) => Event::TaskListMarker(b),
+            Event::Hashtag(s) => Event::Hashtag(s.into_static()),
         }
     }
 }
@@ -666,6 +1052,78 @@ pub enum Alignment {
     Right,
 }
 
+/// 行内代码在源文本中的定界符，由[`Event::Code`]携带。
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CodeDelimiter {
+    /// 两侧反引号的数量。
+    pub backticks: usize,
+    /// 内容两侧各有一个被去掉的空格（或行结尾）。
+    ///
+    /// 内容以反引号开头或结尾时需要这样的填充，例如`` `` `a` `` ``。
+    pub padded: bool,
+}
+
+/// 默认值是没有填充的单个反引号。
+impl Default for CodeDelimiter {
+    fn default() -> Self {
+        CodeDelimiter {
+            backticks: 1,
+            padded: false,
+        }
+    }
+}
+
+/// 硬换行的写法，由[`Event::HardBreak`]携带。
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum HardBreakKind {
+    /// 行尾的两个或更多空格。
+    Spaces,
+    /// 行尾的`\`，包括多行表格单元格的续行标记。
+    Backslash,
+    /// 表格单元格内的`<br>`标签，见[`Options::ENABLE_TABLE_CELL_BLOCKS`]。
+    Html,
+}
+
+/// 数学公式的输入语法，由[`Event::InlineMath`]和[`Event::DisplayMath`]携带。
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum MathSyntax {
+    /// TeX/LaTeX，例如`\frac{a}{b}`。
+    #[default]
+    Tex,
+    /// AsciiMath，例如`a/b`。见[`Options::ENABLE_ASCIIMATH`]。
+    AsciiMath,
+}
+
+/// 数学公式的定界符，通过[`ParserBuilder::math_delimiters`]设置。
+///
+/// 只在启用[`Options::ENABLE_MATH`]时使用。
+///
+/// ```
+/// use pulldown_cmark::{Event, MathDelimiters, MathSyntax, Options, Parser};
+///
+/// let parser = Parser::builder()
+///     .options(Options::ENABLE_MATH)
+///     .math_delimiters(MathDelimiters::Brackets)
+///     .build(r"\(x\) costs $5");
+/// let events: Vec<_> = parser.collect();
+/// assert_eq!(events[1], Event::InlineMath("x".into(), MathSyntax::Tex));
+/// assert_eq!(events[2], Event::Text(" costs $5".into()));
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum MathDelimiters {
+    /// 行内公式为`$...$`，行间公式为`$$...$$`。
+    #[default]
+    Dollars,
+    /// 行内公式为`\(...\)`，行间公式为`\[...\]`，`$`是普通字符。
+    ///
+    /// 这些反斜杠不再是转义：`\(`和`\[`总是尝试开始公式。
+    Brackets,
+}
+
 bitflags::bitflags! {
     /// 包含用于启用额外功能的标志的选项结构体，
     /// 这些功能不是CommonMark规范的一部分。
@@ -698,6 +1156,8 @@ bitflags::bitflags! {
         /// `"quote"`替换为`"quote"`，以及`'quote'`替换为`'quote'`。
         ///
         /// 替换在文档解析过程中进行。
+        /// 可以通过[`ParserBuilder::smart_punctuation`]只启用其中的部分替换，
+        /// 或者启用额外的替换，见[`SmartPunctuation`]。
         const ENABLE_SMART_PUNCTUATION = 1 << 5;
         /// 允许标题具有ID和类的扩展。
         ///
@@ -707,6 +1167,8 @@ bitflags::bitflags! {
         /// 自定义属性`myattr`（无值）和
         /// 值为`myvalue`的`other_attr`。
         /// 注意，ID、类和自定义属性应该用空格分隔。
+        /// 格式错误的属性块（例如缺少`}`）会按普通文本处理，
+        /// 启用[`Options::ENABLE_DIAGNOSTICS`]时会被报告。
         const ENABLE_HEADING_ATTRIBUTES = 1 << 6;
         /// YAML风格的元数据块，即：
         /// - 以`---`行开始
@@ -758,7 +1220,123 @@ bitflags::bitflags! {
         /// Obsidian风格的维基链接。
         const ENABLE_WIKILINKS = 1 << 15;
         /// 冒号分隔的容器扩展块。
+        ///
+        /// 开始围栏至少有三个冒号，后面跟类型名；类型为`spoiler`时其余部分是摘要。
+        /// 信息字符串末尾可以带一个与标题属性写法相同的属性块。
+        ///
+        /// 容器可以嵌套，也可以包含列表、引用块等任意块。结束围栏关闭最内层的、
+        /// 开始围栏不长于它的容器，途中未延续的列表随之结束；引用块等其他容器中的
+        /// 结束围栏只能关闭同在其中的容器。
+        ///
+        /// ```markdown
+        /// :::: note {#outer .wide}
+        /// - 列表项
+        ///
+        /// ::: spoiler 摘要 {open}
+        /// 内容
+        /// :::
+        /// ::::
+        /// ```
         const ENABLE_CONTAINER_EXTENSIONS = 1 << 16;
+        /// 收集解析诊断信息（未定义的脚注、重复的引用定义、未闭合的代码围栏、
+        /// 多余的表格单元格、格式错误的标题属性块等），通过[`Parser::diagnostics`]获取。
+        const ENABLE_DIAGNOSTICS = 1 << 17;
+        /// Obsidian/Logseq风格的行内标签，发出[`Event::Hashtag`]。
+        ///
+        /// 标签以`#`开头，位于行首或空白之后，由字母、数字、`_`、`-`和`/`组成，
+        /// 且不能全是数字。标题需要`#`后跟空格，因此不会冲突。
+        ///
+        /// ```markdown
+        /// Filed under #project/alpha and #todo
+        /// ```
+        const ENABLE_HASHTAGS = 1 << 18;
+        /// Obsidian风格的块标识符，填充[`Tag::Paragraph`]和[`Tag::Item`]的块标识符字段。
+        ///
+        /// 标识符以`^`开头，位于段落最后一行的末尾且前面有空白，由ASCII字母、数字和`-`组成，
+        /// 不出现在段落的文本中。标题、表格等其他块中的标识符保留为文本。
+        /// 维基链接可以通过`[[note#^quote-of-the-day]]`引用它，链接目标原样保留在`dest_url`中。
+        ///
+        /// ```markdown
+        /// some paragraph ^quote-of-the-day
+        /// ```
+        const ENABLE_BLOCK_IDS = 1 << 19;
+        /// 表格中的第二个分隔行把它之后的行标记为表尾，发出[`Tag::TableFoot`]。
+        /// 分隔行的列数必须与表头相同。
+        ///
+        /// ```markdown
+        /// | Item  | Price |
+        /// |-------|------:|
+        /// | Apple |  1.20 |
+        /// | Pear  |  0.80 |
+        /// |-------|------:|
+        /// | Total |  2.00 |
+        /// ```
+        const ENABLE_TABLE_FOOTERS = 1 << 20;
+        /// 表格单元格内紧贴竖线的冒号覆盖该单元格的对齐方式，与分隔行的写法相同：
+        /// `|:左|`、`|右:|`和`|:居中:|`。冒号与竖线之间有空白时按普通文本处理，
+        /// 因此`| :smile: |`不受影响。对齐方式通过[`Tag::TableCell`]发出。
+        ///
+        /// ```markdown
+        /// | Item   | Amount |
+        /// |--------|-------:|
+        /// | Apples |     12 |
+        /// |:n/a    |   none:|
+        /// ```
+        const ENABLE_CELL_ALIGNMENT = 1 << 21;
+        /// 表体行以竖线之后的`\`结尾时，下一行并入同一个逻辑行，
+        /// 每个单元格的内容以硬换行接在上一行对应单元格之后。
+        ///
+        /// ```markdown
+        /// | Step | Notes              |
+        /// |------|--------------------|
+        /// | 1    | Unpack the box     | \
+        /// |      | and check contents |
+        /// ```
+        const ENABLE_MULTILINE_TABLE_CELLS = 1 << 22;
+        /// 区分AsciiMath与TeX公式，需要同时启用[`Options::ENABLE_MATH`]。
+        ///
+        /// 内容以反引号包围的公式（`` $`a/b`$ ``和`` $$`a/b`$$ ``）是AsciiMath，
+        /// 发出的内容不含反引号。语言为`asciimath`的围栏代码块发出
+        /// AsciiMath的[`Event::DisplayMath`]，语言为`math`的则发出TeX的。
+        /// 语法通过[`MathSyntax`]携带，渲染器据此选择客户端库。
+        const ENABLE_ASCIIMATH = 1 << 23;
+        /// GitHub风格的自动链接字面量：不带尖括号的`http://`、`https://`和`www.`地址
+        /// 发出[`LinkType::AutolinkLiteral`]链接，`www.`地址的目标补上`http://`；
+        /// 电子邮件地址发出[`LinkType::EmailLiteral`]链接。
+        ///
+        /// 末尾的标点和不成对的右括号不属于链接，去掉的规则见[`AutolinkTrimming`]。
+        /// 识别哪些地址见[`AutolinkKinds`]，逐个允许或拒绝链接见
+        /// [`ParserCallbacks::allow_autolink_literal`]。
+        ///
+        /// ```markdown
+        /// Visit www.commonmark.org/help, or https://example.com/a_(b).
+        /// Write to foo@example.com.
+        /// ```
+        const ENABLE_AUTOLINK_LITERALS = 1 << 24;
+        /// 内联图片之后紧跟的属性块，例如响应式图片的`srcset`和`sizes`：
+        ///
+        /// ```markdown
+        /// ![Chart](chart.png){.wide srcset="chart@2x.png 2x" sizes="50vw" width=640}
+        /// ```
+        ///
+        /// 语法同[`Options::ENABLE_HEADING_ATTRIBUTES`]，但值可以用引号包围，从而包含空白。
+        /// 属性通过[`Tag::Image`]的`attributes`发出，HTML渲染器只输出
+        /// [`html::HtmlConfig::image_attributes`]允许的属性。
+        const ENABLE_IMAGE_ATTRIBUTES = 1 << 25;
+        /// 表格单元格内的有限块级内容，需要同时启用[`Options::ENABLE_TABLES`]。
+        ///
+        /// `<br>`发出[`HardBreakKind::Html`]的硬换行；成对的`<ul>`、`<ol>`和`<li>`
+        /// 发出嵌套在[`Tag::TableCell`]内的[`Tag::List`]和[`Tag::Item`]，
+        /// 列表项之间只能有空白。不成对的列表标签仍按行内HTML发出。
+        /// 使用[`DisabledFeatures::RAW_HTML`]时不识别这些标签。
+        ///
+        /// ```markdown
+        /// | Name | Notes                                  |
+        /// |------|----------------------------------------|
+        /// | Pear | ripe<br>sweet                          |
+        /// | Fig  | <ul><li>fresh</li><li>dried</li></ul>  |
+        /// ```
+        const ENABLE_TABLE_CELL_BLOCKS = 1 << 26;
     }
 }
 
@@ -766,4 +1344,504 @@ impl Options {
     pub(crate) fn has_gfm_footnotes(&self) -> bool {
         self.contains(Options::ENABLE_FOOTNOTES) && !self.contains(Options::ENABLE_OLD_FOOTNOTES)
     }
+
+    /// 纯CommonMark，不启用任何扩展。与[`Options::empty`]相同。
+    pub const fn commonmark() -> Options {
+        Options::empty()
+    }
+
+    /// GitHub风格Markdown：表格、脚注、删除线、任务列表和引用块标签。
+    pub const fn gfm() -> Options {
+        Options::ENABLE_TABLES
+            .union(Options::ENABLE_FOOTNOTES)
+            .union(Options::ENABLE_STRIKETHROUGH)
+            .union(Options::ENABLE_TASKLISTS)
+            .union(Options::ENABLE_GFM)
+    }
+
+    /// Obsidian风格Markdown：在[`Options::gfm`]的基础上启用维基链接、数学公式、
+    /// 行内标签、块标识符和YAML风格的元数据块（frontmatter）。
+    ///
+    /// Obsidian的`==高亮==`语法目前不受支持。
+    pub const fn obsidian() -> Options {
+        Options::gfm()
+            .union(Options::ENABLE_WIKILINKS)
+            .union(Options::ENABLE_MATH)
+            .union(Options::ENABLE_HASHTAGS)
+            .union(Options::ENABLE_BLOCK_IDS)
+            .union(Options::ENABLE_YAML_STYLE_METADATA_BLOCKS)
+    }
+
+    /// 所有语法扩展。
+    ///
+    /// 与[`Options::all`]不同，脚注使用GitHub兼容语法（不包含
+    /// [`Options::ENABLE_OLD_FOOTNOTES`]），并且不包含不影响语法的
+    /// [`Options::ENABLE_DIAGNOSTICS`]。
+    pub const fn all_extensions() -> Options {
+        Options::all()
+            .difference(Options::ENABLE_OLD_FOOTNOTES)
+            .union(Options::ENABLE_FOOTNOTES)
+            .difference(Options::ENABLE_DIAGNOSTICS)
+    }
+
+    /// 每个标志的稳定名称，用于[`Display`]和[`FromStr`](core::str::FromStr)。
+    ///
+    /// `old_footnotes`包含`footnotes`，因此排在它前面。
+    const NAMES: [(&'static str, Options); 26] = [
+        ("tables", Options::ENABLE_TABLES),
+        ("old_footnotes", Options::ENABLE_OLD_FOOTNOTES),
+        ("footnotes", Options::ENABLE_FOOTNOTES),
+        ("strikethrough", Options::ENABLE_STRIKETHROUGH),
+        ("tasklists", Options::ENABLE_TASKLISTS),
+        ("smart_punctuation", Options::ENABLE_SMART_PUNCTUATION),
+        ("heading_attributes", Options::ENABLE_HEADING_ATTRIBUTES),
+        (
+            "yaml_style_metadata_blocks",
+            Options::ENABLE_YAML_STYLE_METADATA_BLOCKS,
+        ),
+        (
+            "pluses_delimited_metadata_blocks",
+            Options::ENABLE_PLUSES_DELIMITED_METADATA_BLOCKS,
+        ),
+        ("math", Options::ENABLE_MATH),
+        ("gfm", Options::ENABLE_GFM),
+        ("definition_list", Options::ENABLE_DEFINITION_LIST),
+        ("superscript", Options::ENABLE_SUPERSCRIPT),
+        ("subscript", Options::ENABLE_SUBSCRIPT),
+        ("wikilinks", Options::ENABLE_WIKILINKS),
+        ("container_extensions", Options::ENABLE_CONTAINER_EXTENSIONS),
+        ("diagnostics", Options::ENABLE_DIAGNOSTICS),
+        ("hashtags", Options::ENABLE_HASHTAGS),
+        ("block_ids", Options::ENABLE_BLOCK_IDS),
+        ("table_footers", Options::ENABLE_TABLE_FOOTERS),
+        ("cell_alignment", Options::ENABLE_CELL_ALIGNMENT),
+        (
+            "multiline_table_cells",
+            Options::ENABLE_MULTILINE_TABLE_CELLS,
+        ),
+        ("asciimath", Options::ENABLE_ASCIIMATH),
+        ("autolink_literals", Options::ENABLE_AUTOLINK_LITERALS),
+        ("image_attributes", Options::ENABLE_IMAGE_ATTRIBUTES),
+        ("table_cell_blocks", Options::ENABLE_TABLE_CELL_BLOCKS),
+    ];
+
+    /// 根据稳定名称（例如`"tables"`）查找单个标志。
+    pub fn from_flag_name(name: &str) -> Option<Options> {
+        Self::NAMES
+            .iter()
+            .find(|(flag_name, _)| *flag_name == name)
+            .map(|&(_, flag)| flag)
+    }
+}
+
+/// 以逗号分隔的稳定标志名称输出选项，例如`tables,footnotes,gfm`。
+impl Display for Options {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut remaining = *self;
+        let mut first = true;
+        for &(name, flag) in Self::NAMES.iter() {
+            if remaining.contains(flag) {
+                remaining.remove(flag);
+                if !first {
+                    f.write_str(",")?;
+                }
+                f.write_str(name)?;
+                first = false;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// 当解析选项字符串时遇到未知标志名称时返回的错误类型。
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct InvalidOptionName(alloc::string::String);
+
+impl Display for InvalidOptionName {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "unknown option name `{}`", self.0)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for InvalidOptionName {}
+
+/// 解析逗号分隔的稳定标志名称，例如`tables, footnotes,gfm`。
+/// 名称周围的空白和空项会被忽略。
+impl core::str::FromStr for Options {
+    type Err = InvalidOptionName;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut options = Options::empty();
+        for name in s.split(',').map(str::trim).filter(|name| !name.is_empty()) {
+            match Options::from_flag_name(name) {
+                Some(flag) => options.insert(flag),
+                None => return Err(InvalidOptionName(name.into())),
+            }
+        }
+        Ok(options)
+    }
+}
+
+bitflags::bitflags! {
+    /// 要关闭的CommonMark核心语法，通过[`ParserBuilder::disable`]设置。
+    ///
+    /// 被关闭的语法按普通文本解析，例如`# 标题`输出为包含`# 标题`的段落，
+    /// 原始HTML输出为文本（渲染时会被转义）。
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    pub struct DisabledFeatures: u32 {
+        /// ATX标题和Setext标题。
+        const HEADINGS = 1 << 0;
+        /// 图片。`![alt](src)`解析为`!`和一个链接。
+        const IMAGES = 1 << 1;
+        /// HTML块和行内HTML。自动链接不受影响。
+        const RAW_HTML = 1 << 2;
+        /// 脚注，即使启用了[`Options::ENABLE_FOOTNOTES`]。
+        const FOOTNOTES = 1 << 3;
+    }
+}
+
+impl DisabledFeatures {
+    /// 渲染用户评论的常用配置：关闭标题、图片、原始HTML和脚注，
+    /// 只保留行内格式、链接、列表和代码。
+    ///
+    /// ```
+    /// use pulldown_cmark::{html, DisabledFeatures, Parser};
+    ///
+    /// let parser = Parser::builder()
+    ///     .disable(DisabledFeatures::comment())
+    ///     .build("# Hi <b>there</b> ![x](y.png)");
+    /// let mut output = String::new();
+    /// html::push_html(&mut output, parser);
+    /// assert!(!output.contains("<h1>") && !output.contains("<b>") && !output.contains("<img"));
+    /// ```
+    pub const fn comment() -> DisabledFeatures {
+        DisabledFeatures::HEADINGS
+            .union(DisabledFeatures::IMAGES)
+            .union(DisabledFeatures::RAW_HTML)
+            .union(DisabledFeatures::FOOTNOTES)
+    }
+}
+
+bitflags::bitflags! {
+    /// 智能标点的各项替换，通过[`ParserBuilder::smart_punctuation`]设置。
+    ///
+    /// 只启用[`Options::ENABLE_SMART_PUNCTUATION`]时使用[`SmartPunctuation::standard`]。
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    pub struct SmartPunctuation: u32 {
+        /// `"quote"`替换为`“quote”`，`'quote'`替换为`‘quote’`。
+        const QUOTES = 1 << 0;
+        /// `--`替换为`–`，`---`替换为`—`。
+        const DASHES = 1 << 1;
+        /// `...`替换为`…`。
+        const ELLIPSES = 1 << 2;
+        /// 单词中间的撇号（如`don't`）保持为直撇号，不替换为`’`。
+        /// 只在同时启用[`QUOTES`](Self::QUOTES)时有意义。
+        const STRAIGHT_APOSTROPHES = 1 << 3;
+        /// `1/2`、`1/4`和`3/4`替换为`½`、`¼`和`¾`。
+        const FRACTIONS = 1 << 4;
+        /// `(c)`、`(r)`和`(tm)`（不区分大小写）替换为`©`、`®`和`™`。
+        const SYMBOLS = 1 << 5;
+    }
+}
+
+impl SmartPunctuation {
+    /// [`Options::ENABLE_SMART_PUNCTUATION`]默认进行的替换：引号、破折号和省略号。
+    pub const fn standard() -> SmartPunctuation {
+        SmartPunctuation::QUOTES
+            .union(SmartPunctuation::DASHES)
+            .union(SmartPunctuation::ELLIPSES)
+    }
+}
+
+impl Default for SmartPunctuation {
+    fn default() -> Self {
+        Self::standard()
+    }
+}
+
+/// 智能标点替换引号时使用的字符，通过[`ParserBuilder::smart_quotes`]设置。
+///
+/// 默认使用英文引号。单词中间的撇号总是替换为`’`，不受此设置影响。
+///
+/// ```
+/// use pulldown_cmark::{Event, Options, Parser, SmartQuotes};
+///
+/// let parser = Parser::builder()
+///     .options(Options::ENABLE_SMART_PUNCTUATION)
+///     .smart_quotes(SmartQuotes::german())
+///     .build("\"Don't\"");
+/// let text: String = parser
+///     .filter_map(|event| match event {
+///         Event::Text(text) => Some(text.into_string()),
+///         _ => None,
+///     })
+///     .collect();
+/// assert_eq!(text, "„Don’t“");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SmartQuotes {
+    /// 开双引号。
+    pub double_open: char,
+    /// 闭双引号。
+    pub double_close: char,
+    /// 开单引号。
+    pub single_open: char,
+    /// 闭单引号。
+    pub single_close: char,
+}
+
+impl SmartQuotes {
+    /// 英文引号：`“…”`和`‘…’`。
+    pub const fn english() -> SmartQuotes {
+        SmartQuotes {
+            double_open: '“',
+            double_close: '”',
+            single_open: '‘',
+            single_close: '’',
+        }
+    }
+
+    /// 德文引号：`„…“`和`‚…‘`。
+    pub const fn german() -> SmartQuotes {
+        SmartQuotes {
+            double_open: '„',
+            double_close: '“',
+            single_open: '‚',
+            single_close: '‘',
+        }
+    }
+
+    /// 法文书名号式引号：`«…»`和`‹…›`，不添加额外的空格。
+    pub const fn french() -> SmartQuotes {
+        SmartQuotes {
+            double_open: '«',
+            double_close: '»',
+            single_open: '‹',
+            single_close: '›',
+        }
+    }
+}
+
+impl Default for SmartQuotes {
+    fn default() -> Self {
+        Self::english()
+    }
+}
+
+bitflags::bitflags! {
+    /// 从自动链接字面量末尾去掉的字符，通过[`ParserBuilder::autolink_trimming`]设置。
+    ///
+    /// 默认使用[`AutolinkTrimming::gfm`]。尖括号自动链接（`<https://...>`）由尖括号
+    /// 明确界定，不受影响。
+    ///
+    /// ```
+    /// use pulldown_cmark::{AutolinkTrimming, Event, Options, Parser, Tag};
+    ///
+    /// let dest = |trimming| {
+    ///     Parser::builder()
+    ///         .enable(Options::ENABLE_AUTOLINK_LITERALS)
+    ///         .autolink_trimming(trimming)
+    ///         .build("(see https://example.com/a_(b)).")
+    ///         .find_map(|event| match event {
+    ///             Event::Start(Tag::Link { dest_url, .. }) => Some(dest_url.into_string()),
+    ///             _ => None,
+    ///         })
+    /// };
+    /// assert_eq!(dest(AutolinkTrimming::gfm()).unwrap(), "https://example.com/a_(b)");
+    /// assert_eq!(dest(AutolinkTrimming::all()).unwrap(), "https://example.com/a_(b");
+    /// assert_eq!(dest(AutolinkTrimming::empty()).unwrap(), "https://example.com/a_(b)).");
+    /// ```
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    pub struct AutolinkTrimming: u32 {
+        /// 末尾的`?`、`!`、`.`、`,`、`:`、`*`、`_`和`~`。
+        const PUNCTUATION = 1 << 0;
+        /// 末尾的`"`和`'`。
+        const QUOTES = 1 << 1;
+        /// 末尾多于左括号的`)`，保留`https://en.wikipedia.org/wiki/Rust_(language)`
+        /// 这样成对的括号。
+        const UNBALANCED_PARENTHESES = 1 << 2;
+        /// 末尾所有的`)`，即使括号成对。
+        const PARENTHESES = 1 << 3;
+        /// 末尾形如实体引用的`&hl;`。
+        const ENTITY_REFERENCES = 1 << 4;
+    }
+}
+
+impl AutolinkTrimming {
+    /// GitHub的规则：标点、不成对的右括号和实体引用。
+    pub const fn gfm() -> AutolinkTrimming {
+        AutolinkTrimming::PUNCTUATION
+            .union(AutolinkTrimming::UNBALANCED_PARENTHESES)
+            .union(AutolinkTrimming::ENTITY_REFERENCES)
+    }
+}
+
+impl Default for AutolinkTrimming {
+    fn default() -> Self {
+        Self::gfm()
+    }
+}
+
+bitflags::bitflags! {
+    /// 识别为自动链接字面量的地址，通过[`ParserBuilder::autolink_kinds`]设置。
+    ///
+    /// 默认使用[`AutolinkKinds::gfm`]。
+    ///
+    /// ```
+    /// use pulldown_cmark::{AutolinkKinds, Event, Options, Parser, Tag};
+    ///
+    /// let parser = Parser::builder()
+    ///     .enable(Options::ENABLE_AUTOLINK_LITERALS)
+    ///     .autolink_kinds(AutolinkKinds::WEB | AutolinkKinds::TEL)
+    ///     .build("Call tel:+1-555-0100 or mail me@example.com.");
+    /// let dests: Vec<_> = parser
+    ///     .filter_map(|event| match event {
+    ///         Event::Start(Tag::Link { dest_url, .. }) => Some(dest_url.into_string()),
+    ///         _ => None,
+    ///     })
+    ///     .collect();
+    /// assert_eq!(dests, ["tel:+1-555-0100"]);
+    /// ```
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    pub struct AutolinkKinds: u32 {
+        /// `http://`、`https://`和`www.`开头的地址。
+        const WEB = 1 << 0;
+        /// 电子邮件地址，如`foo@example.com`，以及`mailto:foo@example.com`。
+        const EMAIL = 1 << 1;
+        /// `tel:`开头的电话号码，如`tel:+1-201-555-0123`。
+        const TEL = 1 << 2;
+    }
+}
+
+impl AutolinkKinds {
+    /// GitHub识别的地址：网址和电子邮件地址。
+    pub const fn gfm() -> AutolinkKinds {
+        AutolinkKinds::WEB.union(AutolinkKinds::EMAIL)
+    }
+}
+
+impl Default for AutolinkKinds {
+    fn default() -> Self {
+        Self::gfm()
+    }
+}
+
+bitflags::bitflags! {
+    /// 开始无序列表项的标记字符，通过[`ParserBuilder::bullet_markers`]设置。
+    ///
+    /// 默认全部启用。未启用的标记按普通文本解析，例如在聊天记录或类似diff的内容里，
+    /// 行首的`+`不应开始列表。有序列表不受影响。
+    ///
+    /// ```
+    /// use pulldown_cmark::{html, BulletMarkers, Parser};
+    ///
+    /// let parser = Parser::builder()
+    ///     .bullet_markers(BulletMarkers::all() - BulletMarkers::PLUS)
+    ///     .build("+ added\n- item\n");
+    /// let mut output = String::new();
+    /// html::push_html(&mut output, parser);
+    /// assert!(output.starts_with("<p>+ added</p>\n<ul"));
+    /// ```
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    pub struct BulletMarkers: u32 {
+        /// `-`。
+        const DASH = 1 << 0;
+        /// `+`。
+        const PLUS = 1 << 1;
+        /// `*`。
+        const STAR = 1 << 2;
+    }
+}
+
+impl BulletMarkers {
+    /// 字符`c`是否为启用的标记。
+    pub(crate) fn allows(self, c: u8) -> bool {
+        match c {
+            b'-' => self.contains(BulletMarkers::DASH),
+            b'+' => self.contains(BulletMarkers::PLUS),
+            b'*' => self.contains(BulletMarkers::STAR),
+            _ => false,
+        }
+    }
+}
+
+impl Default for BulletMarkers {
+    fn default() -> Self {
+        Self::all()
+    }
+}
+
+#[cfg(feature = "serde")]
+mod options_serde {
+    use core::fmt;
+
+    use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+    use super::{CowStr, Options};
+
+    /// 序列化为逗号分隔的标志名称字符串。
+    impl Serialize for Options {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            serializer.collect_str(self)
+        }
+    }
+
+    struct OptionsVisitor;
+
+    impl<'de> de::Visitor<'de> for OptionsVisitor {
+        type Value = Options;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("a comma-separated string or a sequence of option names")
+        }
+
+        fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            v.parse().map_err(E::custom)
+        }
+
+        fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+        where
+            A: de::SeqAccess<'de>,
+        {
+            let mut options = Options::empty();
+            while let Some(name) = seq.next_element::<CowStr<'de>>()? {
+                match Options::from_flag_name(&name) {
+                    Some(flag) => options.insert(flag),
+                    None => {
+                        return Err(de::Error::custom(super::InvalidOptionName(
+                            name.into_string(),
+                        )))
+                    }
+                }
+            }
+            Ok(options)
+        }
+    }
+
+    /// 既可以从逗号分隔的字符串，也可以从名称序列反序列化。
+    impl<'de> Deserialize<'de> for Options {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            if deserializer.is_human_readable() {
+                deserializer.deserialize_any(OptionsVisitor)
+            } else {
+                deserializer.deserialize_str(OptionsVisitor)
+            }
+        }
+    }
 }
@@ -26,6 +26,7 @@ use core::{
     iter::FusedIterator,
     num::NonZeroUsize,
     ops::{Index, Range},
+    task::Poll,
 };
 #[cfg(all(feature = "std", not(feature = "hashbrown")))]
 use std::collections::HashMap;
@@ -35,13 +36,15 @@ use hashbrown::HashMap;
 use unicase::UniCase;
 
 use crate::{
-    firstpass::run_first_pass,
+    firstpass::{first_pass_tree, run_first_pass_in, FirstPass, FirstPassConfig},
     linklabel::{scan_link_label_rest, FootnoteLabel, LinkLabel, ReferenceLabel},
     scanners::*,
     strings::CowStr,
     tree::{Tree, TreeIndex},
-    Alignment, BlockQuoteKind, CodeBlockKind, ContainerKind, Event, HeadingLevel, LinkType,
-    MetadataBlockKind, Options, Tag, TagEnd,
+    Alignment, Attributes, AutolinkKinds, AutolinkTrimming, BlockQuoteKind, BulletMarkers,
+    CodeBlockKind, CodeDelimiter, ContainerKind, DisabledFeatures, Event, HardBreakKind,
+    HeadingLevel, ImageExtras, LinkType, MathDelimiters, MathSyntax, MetadataBlockKind, Options,
+    SmartPunctuation, SmartQuotes, Tag, TagEnd,
 };
 
 // 在链接目标中允许任意深度的嵌套括号，
@@ -51,6 +54,10 @@ use crate::{
 // https://spec.commonmark.org/0.29/#link-destination
 pub(crate) const LINK_MAX_NESTED_PARENS: usize = 32;
 
+// 线性引用解析模式下可识别的最长链接标签（源文本字节数），
+// 参见 `ParserBuilder::linear_references`。规范允许最多 999 个字符。
+const LINEAR_LABEL_MAX_LEN: usize = 256;
+
 #[derive(Debug, Default, Clone, Copy)]
 pub(crate) struct Item {
     pub start: usize,
@@ -74,6 +81,9 @@ pub(crate) enum ItemBody {
     // 布尔值表示前一部分是否可能是引用
     MaybeLinkClose(bool),
     MaybeImage,
+    MaybeBlockId,
+    // 自动链接字面量，链接文本的节点在第二遍解析中创建
+    MaybeAutolinkLiteral(LinkIndex),
 
     // 这些是解决后的内联项。
     Emphasis,
@@ -81,12 +91,13 @@ pub(crate) enum ItemBody {
     Strikethrough,
     Superscript,
     Subscript,
-    Math(CowIndex, bool), // true表示数学模式
-    Code(CowIndex),
+    Math(CowIndex, bool, MathSyntax), // true表示数学模式
+    Code(CowIndex, bool),             // 两侧是否各去掉了一个空格
     Link(LinkIndex),
     Image(LinkIndex),
     FootnoteReference(CowIndex),
     TaskListMarker(bool), // true表示已选中
+    Hashtag,
 
     // 这些也是内联项。
     InlineHtml,
@@ -98,8 +109,7 @@ pub(crate) enum ItemBody {
         backslash_escaped: bool,
     },
     SoftBreak,
-    // true = 表示反斜杠
-    HardBreak(bool),
+    HardBreak(HardBreakKind),
 
     // 树顶部的虚拟节点 - 不应在其他地方使用！
     #[default]
@@ -113,7 +123,7 @@ pub(crate) enum ItemBody {
     FencedCodeBlock(CowIndex),
     IndentCodeBlock,
     HtmlBlock,
-    BlockQuote(Option<BlockQuoteKind>),
+    BlockQuote(Option<BlockQuoteKind>, Option<CalloutIndex>),
     Container(u8, ContainerKind, CowIndex), // (fence length, specific renderer, descriptor used in renderer)
     List(bool, u8, u64),                    // is_tight, list character, list start index
     ListItem(usize),                        // indent level
@@ -131,8 +141,9 @@ pub(crate) enum ItemBody {
     // Tables
     Table(AlignmentIndex),
     TableHead,
+    TableFoot,
     TableRow,
-    TableCell,
+    TableCell(Option<Alignment>),
 }
 
 impl ItemBody {
@@ -148,6 +159,8 @@ impl ItemBody {
                 | MaybeLinkOpen
                 | MaybeLinkClose(..)
                 | MaybeImage
+                | MaybeBlockId
+                | MaybeAutolinkLiteral(..)
         )
     }
     fn is_inline(&self) -> bool {
@@ -162,6 +175,8 @@ impl ItemBody {
                 | MaybeLinkOpen
                 | MaybeLinkClose(..)
                 | MaybeImage
+                | MaybeBlockId
+                | MaybeAutolinkLiteral(..)
                 | Emphasis
                 | Strong
                 | Strikethrough
@@ -171,6 +186,7 @@ impl ItemBody {
                 | Image(..)
                 | FootnoteReference(..)
                 | TaskListMarker(..)
+                | Hashtag
                 | InlineHtml
                 | OwnedInlineHtml(..)
                 | SynthesizeText(..)
@@ -190,6 +206,72 @@ pub struct BrokenLink<'a> {
     pub reference: CowStr<'a>,
 }
 
+/// A footnote reference without a matching definition.
+///
+/// Passed to [`ParserCallbacks::handle_broken_footnote`].
+#[derive(Debug)]
+pub struct BrokenFootnote<'a> {
+    pub span: core::ops::Range<usize>,
+    pub reference: CowStr<'a>,
+}
+
+/// A problem noticed while parsing, reported by [`Parser::diagnostics`].
+///
+/// Only collected with [`Options::ENABLE_DIAGNOSTICS`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Diagnostic {
+    pub kind: DiagnosticKind,
+    /// The offending range in the markdown source.
+    pub span: Range<usize>,
+}
+
+/// The kind of a [`Diagnostic`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DiagnosticKind {
+    /// A footnote reference without a matching definition, rendered as literal text.
+    UndefinedFootnote,
+    /// A reference definition whose label is already defined. It is ignored.
+    DuplicateReferenceDefinition,
+    /// A fenced code block closed by the end of the document or of its container
+    /// instead of a closing fence.
+    UnclosedCodeFence,
+    /// A table row with more cells than the header row. The excess cells are dropped.
+    ExcessTableCells { expected: usize, found: usize },
+    /// The work budget set with [`ParserBuilder::work_budget`] ran out. Inline markup
+    /// from here on is rendered as literal text.
+    WorkBudgetExceeded,
+    /// A heading attribute block that is not closed, or holds an empty id, class or
+    /// attribute name. An unclosed block is rendered as heading text; the empty
+    /// attributes are ignored.
+    MalformedHeadingAttributes,
+}
+
+impl core::fmt::Display for DiagnosticKind {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            DiagnosticKind::UndefinedFootnote => {
+                f.write_str("footnote reference has no definition")
+            }
+            DiagnosticKind::DuplicateReferenceDefinition => {
+                f.write_str("reference definition is already defined")
+            }
+            DiagnosticKind::UnclosedCodeFence => f.write_str("code fence is never closed"),
+            DiagnosticKind::ExcessTableCells { expected, found } => write!(
+                f,
+                "table row has {} cells but the header has {}",
+                found, expected
+            ),
+            DiagnosticKind::WorkBudgetExceeded => {
+                f.write_str("work budget exceeded, inline markup is left unparsed")
+            }
+            DiagnosticKind::MalformedHeadingAttributes => {
+                f.write_str("heading attribute block is malformed")
+            }
+        }
+    }
+}
+
 /// Markdown event iterator.
 pub struct Parser<'input, CB = DefaultParserCallbacks> {
     callbacks: CB,
@@ -223,6 +305,23 @@ struct ParserInner<'input> {
     // To prevent this, track how much it's expanded and limit it.
     link_ref_expansion_limit: usize,
 
+    // Remaining units of inline work, see `ParserBuilder::work_budget`.
+    work_budget: Option<usize>,
+    work_budget_exceeded: bool,
+
+    // Remaining bytes of reference labels that may be looked up, see
+    // `ParserBuilder::linear_references`. `None` means unbounded.
+    reference_budget: Option<usize>,
+
+    // Core constructs parsed as plain text, see `ParserBuilder::disable`.
+    disabled: DisabledFeatures,
+
+    // Replacements for smart quotes, see `ParserBuilder::smart_quotes`.
+    smart_quotes: SmartQuotes,
+
+    #[cfg(feature = "tracing")]
+    events_emitted: usize,
+
     // used by inline passes. store them here for reuse
     inline_stack: InlineStack,
     link_stack: LinkStack,
@@ -255,16 +354,630 @@ impl<'a> BrokenLink<'a> {
     }
 }
 
+impl<'a> BrokenFootnote<'a> {
+    /// Moves the footnote reference into version with a static lifetime.
+    ///
+    /// The `reference` member is cloned to a Boxed or Inline version.
+    pub fn into_static(self) -> BrokenFootnote<'static> {
+        BrokenFootnote {
+            span: self.span.clone(),
+            reference: self.reference.into_string().into(),
+        }
+    }
+}
+
+/// Builder for a [`Parser`], for configuration beyond [`Options`].
+///
+/// ```
+/// # use pulldown_cmark::{Options, Parser};
+/// let parser = Parser::builder()
+///     .options(Options::ENABLE_TABLES)
+///     .link_ref_expansion_limit(1024)
+///     .build("| a |\n|---|\n| b |");
+/// assert_eq!(parser.count(), 12);
+/// ```
+#[allow(missing_debug_implementations)]
+#[derive(Clone)]
+pub struct ParserBuilder<CB = DefaultParserCallbacks> {
+    options: Options,
+    callbacks: CB,
+    link_ref_expansion_limit: Option<usize>,
+    work_budget: Option<usize>,
+    linear_references: bool,
+    disabled: DisabledFeatures,
+    bullets: BulletMarkers,
+    smart_punctuation: Option<SmartPunctuation>,
+    smart_quotes: SmartQuotes,
+    autolink: AutolinkConfig,
+    math_delimiters: MathDelimiters,
+}
+
+impl ParserBuilder<DefaultParserCallbacks> {
+    /// Creates a builder without any options enabled.
+    pub fn new() -> Self {
+        ParserBuilder {
+            options: Options::empty(),
+            callbacks: DefaultParserCallbacks,
+            link_ref_expansion_limit: None,
+            work_budget: None,
+            linear_references: false,
+            disabled: DisabledFeatures::empty(),
+            bullets: BulletMarkers::all(),
+            smart_punctuation: None,
+            smart_quotes: SmartQuotes::default(),
+            autolink: AutolinkConfig::default(),
+            math_delimiters: MathDelimiters::default(),
+        }
+    }
+}
+
+impl Default for ParserBuilder<DefaultParserCallbacks> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<CB> ParserBuilder<CB> {
+    /// Replaces the enabled options.
+    pub fn options(mut self, options: Options) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Enables the given options in addition to the ones already enabled.
+    pub fn enable(mut self, options: Options) -> Self {
+        self.options.insert(options);
+        self
+    }
+
+    /// Sets the callbacks, see [`ParserCallbacks`].
+    pub fn callbacks<C>(self, callbacks: C) -> ParserBuilder<C> {
+        ParserBuilder {
+            options: self.options,
+            callbacks,
+            link_ref_expansion_limit: self.link_ref_expansion_limit,
+            work_budget: self.work_budget,
+            linear_references: self.linear_references,
+            disabled: self.disabled,
+            bullets: self.bullets,
+            smart_punctuation: self.smart_punctuation,
+            smart_quotes: self.smart_quotes,
+            autolink: self.autolink,
+            math_delimiters: self.math_delimiters,
+        }
+    }
+
+    /// Sets the maximum number of bytes that reference links may expand to,
+    /// protecting against exponential blowup from nested references.
+    ///
+    /// Defaults to the length of the input, but at least 100KiB.
+    pub fn link_ref_expansion_limit(mut self, limit: usize) -> Self {
+        self.link_ref_expansion_limit = Some(limit);
+        self
+    }
+
+    /// Limits the work spent on resolving inline markup, protecting against
+    /// crafted inputs that trigger superlinear behavior.
+    ///
+    /// Every inline item visited by the emphasis and link passes and every
+    /// step of a delimiter scan costs one unit. Once the budget is spent the
+    /// parser degrades instead of failing: all inline markup that is not yet
+    /// resolved is emitted as literal text, see [`Parser::work_budget_exceeded`].
+    /// Unlimited by default.
+    pub fn work_budget(mut self, units: usize) -> Self {
+        self.work_budget = Some(units);
+        self
+    }
+
+    /// Bounds the work spent resolving link references and footnote
+    /// references, so that parsing untrusted input stays linear in its length.
+    ///
+    /// This deviates from the spec in two ways:
+    ///
+    /// - A reference label spanning more than 256 bytes of source text is
+    ///   not recognized, where the spec allows up to 999 characters. The
+    ///   brackets are emitted as literal text.
+    /// - The total length of all labels looked up is capped at the length of
+    ///   the input. Once that is spent, remaining reference links and
+    ///   footnote references are left unresolved, and the broken link
+    ///   callbacks are no longer invoked.
+    ///
+    /// Inline links and reference definitions are not affected. Disabled by
+    /// default.
+    pub fn linear_references(mut self, enable: bool) -> Self {
+        self.linear_references = enable;
+        self
+    }
+
+    /// Turns off the given core constructs, which are then parsed as plain
+    /// text. See [`DisabledFeatures::comment`] for rendering user comments.
+    pub fn disable(mut self, features: DisabledFeatures) -> Self {
+        self.disabled.insert(features);
+        self
+    }
+
+    /// Sets which characters start bullet list items, see [`BulletMarkers`].
+    /// Disabled markers are parsed as plain text. All are enabled by default.
+    pub fn bullet_markers(mut self, markers: BulletMarkers) -> Self {
+        self.bullets = markers;
+        self
+    }
+
+    /// Enables smart punctuation with exactly the given replacements, see
+    /// [`SmartPunctuation`]. An empty set turns smart punctuation off, even if
+    /// [`Options::ENABLE_SMART_PUNCTUATION`] is enabled.
+    ///
+    /// ```
+    /// use pulldown_cmark::{Event, Parser, SmartPunctuation};
+    ///
+    /// let parser = Parser::builder()
+    ///     .smart_punctuation(SmartPunctuation::DASHES | SmartPunctuation::SYMBOLS)
+    ///     .build("\"Don't\" -- (c)");
+    /// let text: String = parser
+    ///     .filter_map(|event| match event {
+    ///         Event::Text(text) => Some(text.into_string()),
+    ///         _ => None,
+    ///     })
+    ///     .collect();
+    /// assert_eq!(text, "\"Don't\" – ©");
+    /// ```
+    pub fn smart_punctuation(mut self, replacements: SmartPunctuation) -> Self {
+        self.smart_punctuation = Some(replacements);
+        self
+    }
+
+    /// Sets the characters that smart punctuation replaces quotes with, see
+    /// [`SmartQuotes`]. Defaults to [`SmartQuotes::english`].
+    pub fn smart_quotes(mut self, quotes: SmartQuotes) -> Self {
+        self.smart_quotes = quotes;
+        self
+    }
+
+    /// Sets which trailing characters are left out of autolink literals, see
+    /// [`AutolinkTrimming`]. Defaults to [`AutolinkTrimming::gfm`].
+    ///
+    /// Only used with [`Options::ENABLE_AUTOLINK_LITERALS`].
+    pub fn autolink_trimming(mut self, trimming: AutolinkTrimming) -> Self {
+        self.autolink.trimming = trimming;
+        self
+    }
+
+    /// Sets which addresses are recognized as autolink literals, see
+    /// [`AutolinkKinds`]. Defaults to [`AutolinkKinds::gfm`].
+    ///
+    /// Only used with [`Options::ENABLE_AUTOLINK_LITERALS`]. Use
+    /// [`ParserCallbacks::allow_autolink_literal`] to reject single links.
+    pub fn autolink_kinds(mut self, kinds: AutolinkKinds) -> Self {
+        self.autolink.kinds = kinds;
+        self
+    }
+
+    /// Sets the delimiters of inline and display math, see [`MathDelimiters`].
+    /// Defaults to [`MathDelimiters::Dollars`].
+    ///
+    /// Only used with [`Options::ENABLE_MATH`].
+    pub fn math_delimiters(mut self, delimiters: MathDelimiters) -> Self {
+        self.math_delimiters = delimiters;
+        self
+    }
+
+    /// The settings used by the first pass.
+    fn first_pass_config(&self) -> FirstPassConfig {
+        FirstPassConfig {
+            disabled: self.disabled,
+            bullets: self.bullets,
+            smart: self.smart_punctuation.unwrap_or_default(),
+            autolink: self.autolink,
+            math_delimiters: self.math_delimiters,
+        }
+    }
+
+    /// Returns the block structure of the given markdown text without
+    /// parsing inlines, see [`Parser::into_outline`].
+    pub fn build_outline<'input>(self, text: &'input str) -> Vec<(Event<'input>, Range<usize>)>
+    where
+        CB: ParserCallbacks<'input>,
+    {
+        self.build(text).into_outline()
+    }
+
+    /// Creates the parser for the given markdown text.
+    pub fn build<'input>(mut self, text: &'input str) -> Parser<'input, CB>
+    where
+        CB: ParserCallbacks<'input>,
+    {
+        self.resolve_options(text);
+        let (tree, allocs) = run_first_pass_in(
+            text,
+            self.options,
+            self.first_pass_config(),
+            first_pass_tree(text),
+        );
+        self.into_parser(text, tree, allocs)
+    }
+
+    /// Creates a parser that builds the block structure over several calls,
+    /// see [`BudgetedParser`].
+    pub fn build_budgeted<'input>(mut self, text: &'input str) -> BudgetedParser<'input, CB>
+    where
+        CB: ParserCallbacks<'input>,
+    {
+        self.resolve_options(text);
+        let first_pass = FirstPass::new(
+            text,
+            self.options,
+            self.first_pass_config(),
+            first_pass_tree(text),
+        );
+        BudgetedParser {
+            text,
+            options: self.options,
+            pending: Some((first_pass, self)),
+            parser: None,
+        }
+    }
+
+    /// Applies the smart punctuation setting, the front matter overrides and
+    /// the disabled features to the options.
+    fn resolve_options<'input>(&mut self, text: &'input str)
+    where
+        CB: ParserCallbacks<'input>,
+    {
+        if let Some(smart) = self.smart_punctuation {
+            self.options
+                .set(Options::ENABLE_SMART_PUNCTUATION, !smart.is_empty());
+        }
+        for (key, value) in scan_front_matter_options(
+            text,
+            self.options
+                .contains(Options::ENABLE_YAML_STYLE_METADATA_BLOCKS),
+            self.options
+                .contains(Options::ENABLE_PLUSES_DELIMITED_METADATA_BLOCKS),
+        ) {
+            self.options = self
+                .callbacks
+                .front_matter_override(key, value, self.options);
+        }
+        // overrides cannot bring back disabled features
+        if self.disabled.contains(DisabledFeatures::FOOTNOTES) {
+            self.options
+                .remove(Options::ENABLE_FOOTNOTES | Options::ENABLE_OLD_FOOTNOTES);
+        }
+    }
+
+    /// Creates the parser from the result of the first pass.
+    fn into_parser<'input>(
+        self,
+        text: &'input str,
+        tree: Tree<Item>,
+        allocs: Allocations<'input>,
+    ) -> Parser<'input, CB>
+    where
+        CB: ParserCallbacks<'input>,
+    {
+        let mut parser = Parser::from_first_pass(text, self.options, self.callbacks, tree, allocs);
+        parser.inner.disabled = self.disabled;
+        parser.inner.smart_quotes = self.smart_quotes;
+        if let Some(limit) = self.link_ref_expansion_limit {
+            parser.inner.link_ref_expansion_limit = limit;
+        }
+        parser.inner.work_budget = self.work_budget;
+        if self.linear_references {
+            parser.inner.reference_budget = Some(text.len());
+        }
+        parser
+    }
+}
+
+/// A markdown dialect: the syntax and limits of a [`Parser`], and the HTML
+/// policy for rendering it, as plain data.
+///
+/// With the `serde` feature, a dialect can be read from a configuration file,
+/// so users can pick or author dialects without code changes. Missing fields
+/// take their default, which is plain CommonMark.
+///
+/// ```
+/// use pulldown_cmark::{DialectConfig, Options, Parser, SmartPunctuation};
+///
+/// let dialect = DialectConfig {
+///     options: Options::ENABLE_TABLES | Options::ENABLE_MATH,
+///     smart_punctuation: Some(SmartPunctuation::DASHES),
+///     work_budget: Some(100_000),
+///     ..Default::default()
+/// };
+/// let parser = Parser::from_dialect("$x$ -- \"y\"", &dialect);
+/// assert_eq!(parser.count(), 6);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
+pub struct DialectConfig {
+    /// The enabled extensions, see [`ParserBuilder::options`].
+    pub options: Options,
+    /// The smart punctuation replacements, see
+    /// [`ParserBuilder::smart_punctuation`]. `None` uses the standard ones
+    /// when [`Options::ENABLE_SMART_PUNCTUATION`] is enabled.
+    pub smart_punctuation: Option<SmartPunctuation>,
+    /// See [`ParserBuilder::smart_quotes`].
+    pub smart_quotes: SmartQuotes,
+    /// The core constructs to turn off, see [`ParserBuilder::disable`].
+    pub disabled: DisabledFeatures,
+    /// See [`ParserBuilder::bullet_markers`].
+    pub bullet_markers: BulletMarkers,
+    /// See [`ParserBuilder::link_ref_expansion_limit`].
+    pub link_ref_expansion_limit: Option<usize>,
+    /// See [`ParserBuilder::work_budget`].
+    pub work_budget: Option<usize>,
+    /// See [`ParserBuilder::linear_references`].
+    pub linear_references: bool,
+    /// See [`ParserBuilder::autolink_trimming`].
+    pub autolink_trimming: AutolinkTrimming,
+    /// See [`ParserBuilder::autolink_kinds`].
+    pub autolink_kinds: AutolinkKinds,
+    /// See [`ParserBuilder::math_delimiters`].
+    pub math_delimiters: MathDelimiters,
+    /// How raw HTML is rendered, see [`html::HtmlConfig::raw_html`](crate::html::HtmlConfig::raw_html).
+    #[cfg(feature = "html")]
+    pub raw_html: crate::html::RawHtmlPolicy,
+}
+
+impl Default for DialectConfig {
+    fn default() -> Self {
+        DialectConfig {
+            options: Options::empty(),
+            smart_punctuation: None,
+            smart_quotes: SmartQuotes::english(),
+            disabled: DisabledFeatures::empty(),
+            bullet_markers: BulletMarkers::all(),
+            link_ref_expansion_limit: None,
+            work_budget: None,
+            linear_references: false,
+            autolink_trimming: AutolinkTrimming::gfm(),
+            autolink_kinds: AutolinkKinds::gfm(),
+            math_delimiters: MathDelimiters::Dollars,
+            #[cfg(feature = "html")]
+            raw_html: Default::default(),
+        }
+    }
+}
+
+impl DialectConfig {
+    /// Creates a [`ParserBuilder`] configured for this dialect.
+    pub fn builder(&self) -> ParserBuilder {
+        let mut builder = ParserBuilder::new()
+            .options(self.options)
+            .linear_references(self.linear_references)
+            .disable(self.disabled)
+            .bullet_markers(self.bullet_markers)
+            .autolink_trimming(self.autolink_trimming)
+            .autolink_kinds(self.autolink_kinds)
+            .math_delimiters(self.math_delimiters)
+            .smart_quotes(self.smart_quotes);
+        builder.smart_punctuation = self.smart_punctuation;
+        builder.link_ref_expansion_limit = self.link_ref_expansion_limit;
+        builder.work_budget = self.work_budget;
+        builder
+    }
+
+    /// The HTML renderer configuration for this dialect.
+    #[cfg(feature = "html")]
+    pub fn html_config(&self) -> crate::html::HtmlConfig {
+        crate::html::HtmlConfig {
+            raw_html: self.raw_html,
+            ..Default::default()
+        }
+    }
+}
+
+/// Which autolink literals are recognized and how they are trimmed.
+#[derive(Clone, Copy, Debug, Default)]
+pub(crate) struct AutolinkConfig {
+    pub kinds: AutolinkKinds,
+    pub trimming: AutolinkTrimming,
+}
+
 impl<'input> Parser<'input, DefaultParserCallbacks> {
     /// Creates a new event iterator for a markdown string without any options enabled.
     pub fn new(text: &'input str) -> Self {
         Self::new_ext(text, Options::empty())
     }
 
+    /// Creates a [`ParserBuilder`], for configuration beyond [`Options`].
+    pub fn builder() -> ParserBuilder {
+        ParserBuilder::new()
+    }
+
     /// Creates a new event iterator for a markdown string with given options.
     pub fn new_ext(text: &'input str, options: Options) -> Self {
         Self::new_with_callbacks(text, options, DefaultParserCallbacks)
     }
+
+    /// Creates a new event iterator for the given dialect, see [`DialectConfig`].
+    pub fn from_dialect(text: &'input str, dialect: &DialectConfig) -> Self {
+        dialect.builder().build(text)
+    }
+
+    /// Creates a new event iterator like [`Parser::new_ext`], building the
+    /// document tree in the storage kept by `arena`.
+    ///
+    /// Hand the storage back with [`ParseArena::reclaim`] once the parser is
+    /// no longer needed, so the next document can reuse it.
+    pub fn new_in(arena: &mut ParseArena, text: &'input str, options: Options) -> Self {
+        let tree = arena
+            .tree
+            .take()
+            .unwrap_or_else(|| Tree::with_capacity(128));
+        let (tree, allocs) = run_first_pass_in(text, options, FirstPassConfig::default(), tree);
+        Self::from_first_pass(text, options, DefaultParserCallbacks, tree, allocs)
+    }
+}
+
+/// Reusable storage for the document tree, which holds most of the parser's
+/// working memory.
+///
+/// A long-running service can keep an arena between requests so that parsing
+/// does not allocate the tree afresh for every document: create parsers with
+/// [`Parser::new_in`] and hand the storage back with [`ParseArena::reclaim`].
+///
+/// ```
+/// use pulldown_cmark::{html, Options, ParseArena, Parser};
+///
+/// let mut arena = ParseArena::new();
+/// let mut output = String::new();
+/// for input in ["# First", "*Second*"] {
+///     let mut parser = Parser::new_in(&mut arena, input, Options::empty());
+///     html::push_html(&mut output, &mut parser);
+///     arena.reclaim(parser);
+/// }
+/// assert!(arena.capacity() > 0);
+/// ```
+#[derive(Default)]
+pub struct ParseArena {
+    tree: Option<Tree<Item>>,
+}
+
+impl ParseArena {
+    /// Creates an empty arena; storage is allocated by the first parse.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Takes back the tree storage of a parser, dropping the parser.
+    ///
+    /// Storage from any parser is accepted, not only from ones created with
+    /// [`Parser::new_in`]. The larger of the two allocations is kept.
+    pub fn reclaim<CB>(&mut self, parser: Parser<'_, CB>) {
+        let mut tree = parser.inner.tree;
+        if tree.capacity() >= self.capacity() {
+            tree.clear();
+            self.tree = Some(tree);
+        }
+    }
+
+    /// Returns the number of tree nodes that can be parsed without
+    /// reallocating.
+    pub fn capacity(&self) -> usize {
+        self.tree.as_ref().map_or(0, Tree::capacity)
+    }
+}
+
+impl core::fmt::Debug for ParseArena {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ParseArena")
+            .field("capacity", &self.capacity())
+            .finish()
+    }
+}
+
+/// A parser that can pause while building the document, for environments
+/// that must not block for long, such as WASM on a browser's main thread.
+///
+/// The block structure of the whole document has to be known before the
+/// first event, as reference definitions may appear anywhere. A [`Parser`]
+/// builds it when it is created; this type builds it over as many calls to
+/// [`next_budgeted`](Self::next_budgeted) as needed, returning
+/// [`Poll::Pending`] until it is done. Events are then produced one per call.
+/// [`ParserBuilder::build_budgeted`] creates one with the builder's settings.
+///
+/// ```
+/// use core::task::Poll;
+/// use pulldown_cmark::{BudgetedParser, Event, Options};
+///
+/// let text = "# Title\n\nSome text.\n".repeat(100);
+/// let mut parser = BudgetedParser::new_ext(&text, Options::empty());
+/// let mut pending = 0;
+/// let mut events = Vec::new();
+/// loop {
+///     match parser.next_budgeted(256) {
+///         // A real application would yield to its event loop here.
+///         Poll::Pending => pending += 1,
+///         Poll::Ready(Some(event)) => events.push(event),
+///         Poll::Ready(None) => break,
+///     }
+/// }
+/// assert!(pending > 0);
+/// assert_eq!(events.len(), 600);
+/// ```
+pub struct BudgetedParser<'input, CB = DefaultParserCallbacks> {
+    text: &'input str,
+    options: Options,
+    pending: Option<(FirstPass<'input>, ParserBuilder<CB>)>,
+    parser: Option<Parser<'input, CB>>,
+}
+
+impl<'input> BudgetedParser<'input, DefaultParserCallbacks> {
+    /// Creates a budgeted parser for a markdown string with given options.
+    pub fn new_ext(text: &'input str, options: Options) -> Self {
+        Self::new_with_callbacks(text, options, DefaultParserCallbacks)
+    }
+}
+
+impl<'input, CB: ParserCallbacks<'input>> BudgetedParser<'input, CB> {
+    /// Creates a budgeted parser with given options and callbacks, see
+    /// [`Parser::new_with_callbacks`].
+    pub fn new_with_callbacks(text: &'input str, options: Options, callbacks: CB) -> Self {
+        ParserBuilder::new()
+            .options(options)
+            .callbacks(callbacks)
+            .build_budgeted(text)
+    }
+
+    /// Returns the next event, or [`Poll::Pending`] if the block structure is
+    /// still being built.
+    ///
+    /// While building, each call parses blocks until at least `fuel` bytes of
+    /// the input have been consumed. A block is always parsed as a whole and
+    /// every call parses at least one, so a call may consume more. Once the
+    /// structure is complete, each call returns a single event and `fuel` is
+    /// ignored.
+    pub fn next_budgeted(&mut self, fuel: usize) -> Poll<Option<Event<'input>>> {
+        if let Some((first_pass, _)) = &mut self.pending {
+            if !first_pass.parse_blocks(fuel) {
+                return Poll::Pending;
+            }
+        }
+        Poll::Ready(self.parser().next())
+    }
+
+    /// Whether the block structure is complete, so that
+    /// [`next_budgeted`](Self::next_budgeted) no longer returns
+    /// [`Poll::Pending`].
+    pub fn is_ready(&self) -> bool {
+        self.pending.is_none()
+    }
+
+    /// Finishes building the block structure without a budget and returns the
+    /// parser for the remaining events.
+    pub fn into_parser(mut self) -> Parser<'input, CB> {
+        if let Some((first_pass, _)) = &mut self.pending {
+            first_pass.parse_blocks(usize::MAX);
+        }
+        self.parser();
+        self.parser.unwrap()
+    }
+
+    /// Completes the first pass, which must already have consumed the whole
+    /// input, and returns the parser.
+    fn parser(&mut self) -> &mut Parser<'input, CB> {
+        if let Some((first_pass, builder)) = self.pending.take() {
+            let (tree, allocs) = first_pass.finish();
+            self.parser = Some(builder.into_parser(self.text, tree, allocs));
+        }
+        self.parser.as_mut().unwrap()
+    }
+}
+
+impl<'input, CB> core::fmt::Debug for BudgetedParser<'input, CB> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("BudgetedParser")
+            .field("text", &self.text)
+            .field("options", &self.options)
+            .field("ready", &self.pending.is_none())
+            .finish()
+    }
 }
 
 impl<'input, CB: ParserCallbacks<'input>> Parser<'input, CB> {
@@ -293,7 +1006,19 @@ impl<'input, CB: ParserCallbacks<'input>> Parser<'input, CB> {
     ///
     /// See the [`ParserCallbacks`] trait for a list of callbacks that can be overridden.
     pub fn new_with_callbacks(text: &'input str, options: Options, callbacks: CB) -> Self {
-        let (mut tree, allocs) = run_first_pass(text, options);
+        ParserBuilder::new()
+            .options(options)
+            .callbacks(callbacks)
+            .build(text)
+    }
+
+    fn from_first_pass(
+        text: &'input str,
+        options: Options,
+        callbacks: CB,
+        mut tree: Tree<Item>,
+        allocs: Allocations<'input>,
+    ) -> Self {
         tree.reset();
         let inline_stack = Default::default();
         let link_stack = Default::default();
@@ -313,6 +1038,13 @@ impl<'input, CB: ParserCallbacks<'input>> Parser<'input, CB> {
                 html_scan_guard,
                 // always allow 100KiB
                 link_ref_expansion_limit: text.len().max(100_000),
+                work_budget: None,
+                work_budget_exceeded: false,
+                reference_budget: None,
+                disabled: DisabledFeatures::empty(),
+                smart_quotes: SmartQuotes::english(),
+                #[cfg(feature = "tracing")]
+                events_emitted: 0,
                 code_delims: CodeDelims::new(),
                 math_delims: MathDelims::new(),
             },
@@ -325,12 +1057,198 @@ impl<'input, CB: ParserCallbacks<'input>> Parser<'input, CB> {
         &self.inner.allocs.refdefs
     }
 
+    /// Returns a reference to the internal `FootnoteDefs` object, which provides access
+    /// to the footnote definitions of the document.
+    ///
+    /// The definitions are collected before the first event is produced, so
+    /// they can be inspected without consuming the iterator.
+    pub fn footnote_definitions(&self) -> &FootnoteDefs<'_> {
+        &self.inner.allocs.footdefs
+    }
+
+    /// Returns the events of the footnote definition with the given label, from its
+    /// `Start(Tag::FootnoteDefinition)` to its matching end, or `None` if there is no
+    /// such definition. Labels are matched case-insensitively.
+    ///
+    /// This does not advance the parser, so it can be called any number of times, for
+    /// example to show a footnote when hovering its reference. Call it before the
+    /// parser reaches the definition: once the definition has been streamed, owned
+    /// strings such as link destinations have been moved out and come back empty.
+    ///
+    /// ```
+    /// # use pulldown_cmark::{Event, Options, Parser, Tag, TagEnd};
+    /// let mut parser = Parser::new_ext("See[^n].\n\n[^n]: A *note*.\n", Options::ENABLE_FOOTNOTES);
+    /// let events: Vec<_> = parser.footnote("N").unwrap().collect();
+    /// assert_eq!(events, [
+    ///     Event::Start(Tag::FootnoteDefinition("n".into())),
+    ///     Event::Start(Tag::Paragraph(None)),
+    ///     Event::Text("A ".into()),
+    ///     Event::Start(Tag::Emphasis),
+    ///     Event::Text("note".into()),
+    ///     Event::End(TagEnd::Emphasis),
+    ///     Event::Text(".".into()),
+    ///     Event::End(TagEnd::Paragraph),
+    ///     Event::End(TagEnd::FootnoteDefinition),
+    /// ]);
+    /// assert!(parser.footnote("missing").is_none());
+    /// ```
+    pub fn footnote(&mut self, label: &str) -> Option<impl Iterator<Item = Event<'input>>> {
+        let inner = &mut self.inner;
+        if !inner.allocs.footdefs.contains(label) {
+            return None;
+        }
+        let label = UniCase::new(label);
+        let (path, def_ix) = inner.tree.find_path(|item| match item.body {
+            ItemBody::FootnoteDefinition(cow_ix) => UniCase::new(&*inner.allocs[cow_ix]) == label,
+            _ => false,
+        })?;
+        let (spine, cur) = inner.tree.position();
+        let depth = path.len();
+        inner.tree.set_position(path, Some(def_ix));
+        inner.allocs.keep = true;
+        let mut events = Vec::new();
+        while let Some((event, _)) = inner.next_event_range_inner(&mut self.callbacks) {
+            events.push(event);
+            if inner.tree.spine_len() == depth {
+                break;
+            }
+        }
+        inner.allocs.keep = false;
+        inner.tree.set_position(spine, cur);
+        Some(events.into_iter())
+    }
+
+    /// Returns the problems noticed so far while parsing.
+    ///
+    /// Diagnostics are only collected with [`Options::ENABLE_DIAGNOSTICS`].
+    /// Inline content is parsed lazily, so the list is only complete once
+    /// the iterator has been exhausted.
+    pub fn diagnostics(&self) -> &[Diagnostic] {
+        &self.inner.allocs.diagnostics
+    }
+
+    /// Returns whether the work budget set with [`ParserBuilder::work_budget`]
+    /// has run out, meaning some inline markup was emitted as literal text.
+    pub fn work_budget_exceeded(&self) -> bool {
+        self.inner.work_budget_exceeded
+    }
+
+    /// Parses the whole document and converts every event with [`Event::into_static`],
+    /// so the events no longer borrow the input.
+    ///
+    /// This is useful to move events across threads or to store them beyond the
+    /// lifetime of the markdown source.
+    ///
+    /// ```
+    /// # use pulldown_cmark::{Event, Parser};
+    /// let events: Vec<Event<'static>> = {
+    ///     let text = String::from("*hello*");
+    ///     Parser::new(&text).collect_static()
+    /// };
+    /// assert_eq!(events[2], Event::Text("hello".into()));
+    /// ```
+    pub fn collect_static(self) -> Vec<Event<'static>> {
+        self.map(Event::into_static).collect()
+    }
+
     /// Consumes the event iterator and produces an iterator that produces
     /// `(Event, Range)` pairs, where the `Range` value maps to the corresponding
     /// range in the markdown source.
     pub fn into_offset_iter(self) -> OffsetIter<'input, CB> {
         OffsetIter { parser: self }
     }
+
+    /// Consumes the parser and returns only the block structure of the
+    /// document, with the source range of each event.
+    ///
+    /// Inline parsing is skipped entirely, which makes this much faster than
+    /// iterating over all events, for example to build navigation trees over
+    /// many documents. The result contains the start and end events of all
+    /// blocks, and events like [`Event::Rule`] that stand for a whole block.
+    /// The content of a heading is a single [`Event::Text`] with its markdown
+    /// source, where inline markup is not interpreted and the lines of a
+    /// setext heading are joined with `\n`. The content of other
+    /// blocks is left out, as are tight paragraphs, which are not emitted as
+    /// tags by the parser either.
+    ///
+    /// Should be called before any events are taken from the parser.
+    ///
+    /// ```
+    /// # use pulldown_cmark::{Event, HeadingLevel, Parser, Tag, TagEnd};
+    /// let outline = Parser::new("# Hello *world*\n\n> text\n").into_outline();
+    /// let events: Vec<_> = outline.into_iter().map(|(event, _)| event).collect();
+    /// assert_eq!(events, [
+    ///     Event::Start(Tag::Heading { level: HeadingLevel::H1, id: None, attributes: None }),
+    ///     Event::Text("Hello *world*".into()),
+    ///     Event::End(TagEnd::Heading(HeadingLevel::H1)),
+    ///     Event::Start(Tag::BlockQuote { kind: None, folded: None, title: None }),
+    ///     Event::Start(Tag::Paragraph(None)),
+    ///     Event::End(TagEnd::Paragraph),
+    ///     Event::End(TagEnd::BlockQuote(None)),
+    /// ]);
+    /// ```
+    pub fn into_outline(mut self) -> Vec<(Event<'input>, Range<usize>)> {
+        let inner = &mut self.inner;
+        let mut events = Vec::new();
+        inner.tree.reset();
+        loop {
+            let Some(cur_ix) = inner.tree.cur() else {
+                let Some(ix) = inner.tree.pop() else {
+                    return events;
+                };
+                let item = inner.tree[ix].item;
+                events.push((
+                    Event::End(body_to_tag_end(&item.body)),
+                    item.start..item.end,
+                ));
+                inner.tree.next_sibling(ix);
+                continue;
+            };
+            let item = inner.tree[cur_ix].item;
+            if item.body.is_inline() || matches!(item.body, ItemBody::TightParagraph) {
+                inner.tree.next_sibling(cur_ix);
+                continue;
+            }
+            let event = item_to_event(item, inner.text, &mut inner.allocs);
+            let is_start = matches!(event, Event::Start(..));
+            events.push((event, item.start..item.end));
+            if !is_start {
+                inner.tree.next_sibling(cur_ix);
+                continue;
+            }
+            if let (ItemBody::Heading(..), Some(first_ix)) = (item.body, inner.tree[cur_ix].child) {
+                // a setext heading spans several lines, whose container
+                // prefixes lie between the line breaks and the next line
+                let mut lines = Vec::new();
+                let mut line_start = Some(inner.tree[first_ix].item.start);
+                let mut last_ix = first_ix;
+                let mut child = Some(first_ix);
+                while let Some(child_ix) = child {
+                    let child_item = inner.tree[child_ix].item;
+                    if let ItemBody::SoftBreak | ItemBody::HardBreak(_) = child_item.body {
+                        lines.extend(line_start.take().map(|start| start..child_item.start));
+                    } else {
+                        line_start.get_or_insert(child_item.start);
+                    }
+                    last_ix = child_ix;
+                    child = inner.tree[child_ix].next;
+                }
+                let span = inner.tree[first_ix].item.start..inner.tree[last_ix].item.end;
+                lines.extend(line_start.map(|start| start..span.end));
+                let text = match &lines[..] {
+                    [line] => inner.text[line.clone()].into(),
+                    lines => lines
+                        .iter()
+                        .map(|line| &inner.text[line.clone()])
+                        .collect::<Vec<_>>()
+                        .join("\n")
+                        .into(),
+                };
+                events.push((Event::Text(text), span));
+            }
+            inner.tree.push();
+        }
+    }
 }
 
 impl<'input, F> Parser<'input, BrokenLinkCallback<F>> {
@@ -355,6 +1273,69 @@ impl<'input, F> Parser<'input, BrokenLinkCallback<F>> {
 }
 
 impl<'input> ParserInner<'input> {
+    /// Returns the longest reference label that may be scanned, if bounded.
+    fn label_limit(&self) -> Option<usize> {
+        self.reference_budget.map(|_| LINEAR_LABEL_MAX_LEN)
+    }
+
+    /// Charges a reference lookup against the reference budget.
+    ///
+    /// Returns `false` if the budget is spent and the label must be left
+    /// unresolved.
+    fn charge_reference_lookup(&mut self, label: &ReferenceLabel<'_>) -> bool {
+        let Some(budget) = &mut self.reference_budget else {
+            return true;
+        };
+        let (ReferenceLabel::Link(l) | ReferenceLabel::Footnote(l)) = label;
+        let cost = l.len().max(1);
+        if *budget < cost {
+            *budget = 0;
+            return false;
+        }
+        *budget -= cost;
+        true
+    }
+
+    /// Appends a footnote definition supplied by
+    /// [`ParserCallbacks::handle_broken_footnote`] to the end of the document.
+    ///
+    /// The definition holds a single paragraph with `text`. Nothing is added if
+    /// the label is already defined.
+    fn append_footnote_definition(&mut self, label: CowStr<'input>, text: CowStr<'input>) {
+        if self.allocs.footdefs.contains(&label) {
+            return;
+        }
+        let end = self.text.len();
+        self.allocs.footdefs.0.insert(
+            UniCase::new(label.clone()),
+            FootnoteDef {
+                use_count: 0,
+                span: end..end,
+                body: text.clone(),
+            },
+        );
+        let text = self.allocs.allocate_cow(text);
+        let text_ix = self.tree.create_node(Item {
+            start: end,
+            end,
+            body: ItemBody::SynthesizeText(text),
+        });
+        let para_ix = self.tree.create_node(Item {
+            start: end,
+            end,
+            body: ItemBody::Paragraph,
+        });
+        self.tree[para_ix].child = Some(text_ix);
+        let label = self.allocs.allocate_cow(label);
+        let def_ix = self.tree.create_node(Item {
+            start: end,
+            end,
+            body: ItemBody::FootnoteDefinition(label),
+        });
+        self.tree[def_ix].child = Some(para_ix);
+        self.tree.append_top_level(def_ix);
+    }
+
     /// Use a link label to fetch a type, url, and title.
     ///
     /// This function enforces the [`link_ref_expansion_limit`].
@@ -428,8 +1409,176 @@ impl<'input> ParserInner<'input> {
     ///
     /// Note: there's some potential for optimization here, but that's future work.
     fn handle_inline(&mut self, callbacks: &mut dyn ParserCallbacks<'input>) {
-        self.handle_inline_pass1(callbacks);
-        self.handle_emphasis_and_hard_break();
+        #[cfg(feature = "tracing")]
+        let _span = tracing::trace_span!("inline_pass").entered();
+        if !self.work_budget_exceeded {
+            self.handle_inline_pass1(callbacks);
+            self.handle_emphasis_and_hard_break();
+        }
+        if self.work_budget_exceeded {
+            self.flatten_inline();
+        } else if self.options.contains(Options::ENABLE_TABLE_CELL_BLOCKS)
+            && matches!(
+                self.tree[self.tree.peek_up().unwrap()].item.body,
+                ItemBody::TableCell(_)
+            )
+        {
+            self.handle_table_cell_blocks();
+        }
+    }
+
+    /// Charges `units` of inline work against the budget, if any.
+    ///
+    /// Returns false once the budget has run out.
+    fn spend_work(&mut self, units: usize, ix: TreeIndex) -> bool {
+        if self.work_budget_exceeded {
+            return false;
+        }
+        let Some(budget) = &mut self.work_budget else {
+            return true;
+        };
+        if let Some(rest) = budget.checked_sub(units) {
+            *budget = rest;
+            return true;
+        }
+        self.work_budget_exceeded = true;
+        if self.options.contains(Options::ENABLE_DIAGNOSTICS) {
+            self.allocs.diagnostics.push(Diagnostic {
+                kind: DiagnosticKind::WorkBudgetExceeded,
+                span: self.tree[ix].item.start..self.tree[ix].item.end,
+            });
+        }
+        false
+    }
+
+    /// Turns the unresolved inline items of the current chain into text.
+    ///
+    /// Used instead of the inline passes once the work budget has run out.
+    fn flatten_inline(&mut self) {
+        let mut cur = self.tree.cur();
+        while let Some(cur_ix) = cur {
+            if self.tree[cur_ix].item.body.is_maybe_inline() {
+                self.tree[cur_ix].item.body = ItemBody::Text {
+                    backslash_escaped: false,
+                };
+            }
+            cur = self.tree[cur_ix].next;
+        }
+    }
+
+    /// Turns `<br>` tags in the current table cell into hard breaks, and its
+    /// `<ul>`, `<ol>` and `<li>` tags into lists if they are balanced.
+    ///
+    /// Only tags at the top level of the cell are considered, so a list tag
+    /// inside emphasis or a link leaves all list tags of the cell as HTML.
+    fn handle_table_cell_blocks(&mut self) {
+        let mut nodes = Vec::new();
+        let mut cur = self.tree.cur();
+        while let Some(cur_ix) = cur {
+            let item = self.tree[cur_ix].item;
+            let tag = match item.body {
+                ItemBody::InlineHtml => scan_table_cell_tag(&self.text[item.start..item.end]),
+                _ => None,
+            };
+            nodes.push((cur_ix, tag));
+            cur = self.tree[cur_ix].next;
+        }
+        let lists = self.table_cell_lists_balanced(&nodes);
+
+        // open lists and items, with the last child appended to each
+        let mut levels: Vec<(Option<TreeIndex>, Option<TreeIndex>)> = vec![(None, None)];
+        for (ix, tag) in nodes {
+            let parent = levels.last().and_then(|&(parent, _)| parent);
+            match tag {
+                Some(TableCellTag::Break) => {
+                    self.tree[ix].item.body = ItemBody::HardBreak(HardBreakKind::Html);
+                }
+                Some(TableCellTag::List(ordered)) if lists => {
+                    let (c, start) = if ordered { (b'.', 1) } else { (b'-', 0) };
+                    self.tree[ix].item.body = ItemBody::List(true, c, start);
+                }
+                Some(TableCellTag::Item) if lists => {
+                    self.tree[ix].item.body = ItemBody::ListItem(0);
+                }
+                Some(TableCellTag::ListEnd(_) | TableCellTag::ItemEnd) if lists => {
+                    let parent = parent.unwrap();
+                    self.tree[parent].item.end = self.tree[ix].item.end;
+                    levels.pop();
+                    continue;
+                }
+                // whitespace between list items
+                _ if lists
+                    && parent.is_some_and(|parent| {
+                        matches!(self.tree[parent].item.body, ItemBody::List(..))
+                    }) =>
+                {
+                    continue;
+                }
+                _ => {}
+            }
+            let (parent, last) = levels.last_mut().unwrap();
+            match (*last, *parent) {
+                (Some(last), _) => self.tree[last].next = Some(ix),
+                (None, Some(parent)) => self.tree[parent].child = Some(ix),
+                (None, None) => {}
+            }
+            *last = Some(ix);
+            self.tree[ix].next = None;
+            if matches!(
+                self.tree[ix].item.body,
+                ItemBody::List(..) | ItemBody::ListItem(_)
+            ) && lists
+            {
+                self.tree[ix].child = None;
+                levels.push((Some(ix), None));
+            }
+        }
+    }
+
+    /// Checks that the list tags of a table cell nest properly, with only
+    /// whitespace directly inside lists. Returns false if there are none.
+    fn table_cell_lists_balanced(&self, nodes: &[(TreeIndex, Option<TableCellTag>)]) -> bool {
+        let mut stack = Vec::new();
+        let mut any = false;
+        for &(ix, tag) in nodes {
+            let top = stack.last().copied();
+            match tag {
+                Some(TableCellTag::List(_)) if matches!(top, None | Some(TableCellTag::Item)) => {}
+                Some(TableCellTag::Item) if matches!(top, Some(TableCellTag::List(_))) => {}
+                Some(TableCellTag::ItemEnd) if top == Some(TableCellTag::Item) => {
+                    stack.pop();
+                    continue;
+                }
+                Some(TableCellTag::ListEnd(ordered))
+                    if top == Some(TableCellTag::List(ordered)) =>
+                {
+                    stack.pop();
+                    continue;
+                }
+                Some(
+                    TableCellTag::List(_)
+                    | TableCellTag::Item
+                    | TableCellTag::ItemEnd
+                    | TableCellTag::ListEnd(_),
+                ) => {
+                    return false;
+                }
+                _ => {
+                    let item = self.tree[ix].item;
+                    let whitespace = matches!(item.body, ItemBody::Text { .. })
+                        && self.text[item.start..item.end]
+                            .bytes()
+                            .all(is_ascii_whitespace);
+                    if matches!(top, Some(TableCellTag::List(_))) && !whitespace {
+                        return false;
+                    }
+                    continue;
+                }
+            }
+            any = true;
+            stack.extend(tag);
+        }
+        any && stack.is_empty()
     }
 
     /// Handle inline HTML, code spans, and links.
@@ -438,13 +1587,17 @@ impl<'input> ParserInner<'input> {
     /// the same precedence. It also handles links, even though they have lower
     /// precedence, because the URL of links must not be processed.
     fn handle_inline_pass1(&mut self, callbacks: &mut dyn ParserCallbacks<'input>) {
-        let mut cur = self.tree.cur();
+        let first = self.tree.cur();
+        let mut cur = first;
         let mut prev = None;
 
         let block_end = self.tree[self.tree.peek_up().unwrap()].item.end;
         let block_text = &self.text[..block_end];
 
         while let Some(mut cur_ix) = cur {
+            if !self.spend_work(1, cur_ix) {
+                break;
+            }
             match self.tree[cur_ix].item.body {
                 ItemBody::MaybeHtml => {
                     let next = self.tree[cur_ix].next;
@@ -476,7 +1629,7 @@ impl<'input> ParserInner<'input> {
                             self.tree[node_ix].item.start = max(self.tree[node_ix].item.start, ix);
                         }
                         continue;
-                    } else {
+                    } else if !self.disabled.contains(DisabledFeatures::RAW_HTML) {
                         let inline_html = next.and_then(|next_ix| {
                             self.scan_inline_html(
                                 block_text.as_bytes(),
@@ -511,53 +1664,47 @@ impl<'input> ParserInner<'input> {
                 }
                 ItemBody::MaybeMath(can_open, _can_close, brace_context) => {
                     if !can_open {
-                        self.tree[cur_ix].item.body = ItemBody::Text {
-                            backslash_escaped: false,
-                        };
+                        self.unmatched_math_delim(cur_ix);
                         prev = cur;
                         cur = self.tree[cur_ix].next;
                         continue;
                     }
-                    let is_display = self.tree[cur_ix].next.is_some_and(|next_ix| {
-                        matches!(
-                            self.tree[next_ix].item.body,
-                            ItemBody::MaybeMath(_can_open, _can_close, _brace_context)
-                        )
-                    });
+                    let is_display = self.is_display_math_delim(cur_ix);
+                    let bracketed = self.is_bracket_math_delim(cur_ix);
                     let result = if self.math_delims.is_populated() {
                         // we have previously scanned all math environment delimiters,
                         // so we can reuse that work
-                        self.math_delims
-                            .find(&self.tree, cur_ix, is_display, brace_context)
+                        self.math_delims.find(
+                            &self.tree,
+                            cur_ix,
+                            is_display,
+                            bracketed,
+                            brace_context,
+                        )
                     } else {
                         // we haven't previously scanned all math delimiters,
                         // so walk the AST
                         let mut scan = self.tree[cur_ix].next;
-                        if is_display {
+                        if is_display && !bracketed {
                             // a display delimiter, `$$`, is actually two delimiters
                             // skip the second one
                             scan = self.tree[scan.unwrap()].next;
                         }
                         let mut invalid = false;
+                        let mut steps = 0;
                         while let Some(scan_ix) = scan {
+                            steps += 1;
                             if let ItemBody::MaybeMath(_can_open, can_close, delim_brace_context) =
                                 self.tree[scan_ix].item.body
                             {
-                                let delim_is_display =
-                                    self.tree[scan_ix].next.is_some_and(|next_ix| {
-                                        matches!(
-                                            self.tree[next_ix].item.body,
-                                            ItemBody::MaybeMath(
-                                                _can_open,
-                                                _can_close,
-                                                _brace_context
-                                            )
-                                        )
-                                    });
+                                let delim_is_display = self.is_display_math_delim(scan_ix);
                                 if !invalid && delim_brace_context == brace_context {
-                                    if (!is_display && can_close)
-                                        || (is_display && delim_is_display)
-                                    {
+                                    if math_delims_match(
+                                        is_display,
+                                        bracketed,
+                                        can_close,
+                                        delim_is_display,
+                                    ) {
                                         // This will skip ahead past everything we
                                         // just inserted. Needed for correctness to
                                         // ensure that a new scan is done after this item.
@@ -578,15 +1725,14 @@ impl<'input> ParserInner<'input> {
                             }
                             scan = self.tree[scan_ix].next;
                         }
+                        self.spend_work(steps, cur_ix);
                         scan
                     };
 
                     if let Some(scan_ix) = result {
                         self.make_math_span(cur_ix, scan_ix);
                     } else {
-                        self.tree[cur_ix].item.body = ItemBody::Text {
-                            backslash_escaped: false,
-                        };
+                        self.unmatched_math_delim(cur_ix);
                     }
                 }
                 ItemBody::MaybeCode(mut search_count, preceded_by_backslash) => {
@@ -620,7 +1766,9 @@ impl<'input> ParserInner<'input> {
                         } else {
                             None
                         };
+                        let mut steps = 0;
                         while let Some(scan_ix) = scan {
+                            steps += 1;
                             if let ItemBody::MaybeCode(delim_count, _) =
                                 self.tree[scan_ix].item.body
                             {
@@ -634,6 +1782,7 @@ impl<'input> ParserInner<'input> {
                             }
                             scan = self.tree[scan_ix].next;
                         }
+                        self.spend_work(steps, cur_ix);
                         if scan.is_none() {
                             self.tree[cur_ix].item.body = ItemBody::Text {
                                 backslash_escaped: false,
@@ -712,9 +1861,21 @@ impl<'input> ParserInner<'input> {
                             continue;
                         }
                         let next = self.tree[cur_ix].next;
-                        if let Some((next_ix, url, title)) =
+                        if let Some((mut next_ix, url, title)) =
                             self.scan_inline_link(block_text, self.tree[cur_ix].item.end, next)
                         {
+                            let attributes = if tos.ty == LinkStackTy::Image
+                                && self.options.contains(Options::ENABLE_IMAGE_ATTRIBUTES)
+                            {
+                                scan_attribute_block(&block_text[next_ix..]).map(
+                                    |(len, attributes)| {
+                                        next_ix += len;
+                                        attributes
+                                    },
+                                )
+                            } else {
+                                None
+                            };
                             let next_node = scan_nodes_to_ix(&self.tree, next, next_ix);
                             if let Some(prev_ix) = prev {
                                 self.tree[prev_ix].next = None;
@@ -724,6 +1885,9 @@ impl<'input> ParserInner<'input> {
                             let link_ix =
                                 self.allocs
                                     .allocate_link(LinkType::Inline, url, title, "".into());
+                            if let Some(attributes) = attributes {
+                                self.allocs.allocate_image_attributes(link_ix, attributes);
+                            }
                             self.tree[cur_ix].item.body = if tos.ty == LinkStackTy::Image {
                                 ItemBody::Image(link_ix)
                             } else {
@@ -743,8 +1907,13 @@ impl<'input> ParserInner<'input> {
                         } else {
                             // ok, so its not an inline link. maybe it is a reference
                             // to a defined link?
-                            let scan_result =
-                                scan_reference(&self.tree, block_text, next, self.options);
+                            let scan_result = scan_reference(
+                                &self.tree,
+                                block_text,
+                                next,
+                                self.options,
+                                self.label_limit(),
+                            );
                             let (node_after_link, link_type) = match scan_result {
                                 // [label][reference]
                                 RefScan::LinkLabel(_, end_ix) => {
@@ -801,13 +1970,17 @@ impl<'input> ParserInner<'input> {
                                     let label_end = self.tree[cur_ix].item.end;
                                     scan_link_label(
                                         &self.tree,
-                                        &self.text[label_start..label_end],
+                                        bounded_label_text(
+                                            &self.text[label_start..label_end],
+                                            self.label_limit(),
+                                        ),
                                         self.options,
                                     )
                                     .map(|(ix, label)| (label, label_start + ix))
                                     .filter(|(_, end)| *end == label_end)
                                 }
                             };
+                            let label = label.filter(|(l, _)| self.charge_reference_lookup(l));
 
                             let id = match &label {
                                 Some(
@@ -818,24 +1991,58 @@ impl<'input> ParserInner<'input> {
 
                             // see if it's a footnote reference
                             if let Some((ReferenceLabel::Footnote(l), end)) = label {
-                                let footref = self.allocs.allocate_cow(l);
-                                if let Some(def) = self
-                                    .allocs
-                                    .footdefs
-                                    .get_mut(self.allocs.cows[footref.0].to_owned())
-                                {
-                                    def.use_count += 1;
-                                }
-                                if !self.options.has_gfm_footnotes()
-                                    || self.allocs.footdefs.contains(&self.allocs.cows[footref.0])
+                                let l = if !self.options.has_gfm_footnotes()
+                                    || self.allocs.footdefs.contains(&l)
                                 {
+                                    Some(l)
+                                } else {
+                                    // If this came from a MaybeImage, then the `!` prefix
+                                    // isn't part of the footnote reference.
+                                    let start = if tos.ty == LinkStackTy::Image {
+                                        self.tree[tos.node].item.start + 1
+                                    } else {
+                                        self.tree[tos.node].item.start
+                                    };
+                                    let resolved =
+                                        callbacks.handle_broken_footnote(BrokenFootnote {
+                                            span: start..end,
+                                            reference: l,
+                                        });
+                                    if resolved.is_none()
+                                        && self.options.contains(Options::ENABLE_DIAGNOSTICS)
+                                    {
+                                        self.allocs.diagnostics.push(Diagnostic {
+                                            kind: DiagnosticKind::UndefinedFootnote,
+                                            span: start..end,
+                                        });
+                                    }
+                                    resolved.map(|(label, definition)| {
+                                        if let Some(definition) = definition {
+                                            self.append_footnote_definition(
+                                                label.clone(),
+                                                definition,
+                                            );
+                                        }
+                                        label
+                                    })
+                                };
+                                if let Some(l) = l {
+                                    let footref = self.allocs.allocate_cow(l);
+                                    if let Some(def) = self
+                                        .allocs
+                                        .footdefs
+                                        .get_mut(self.allocs.cows[footref.0].to_owned())
+                                    {
+                                        def.use_count += 1;
+                                    }
                                     // If this came from a MaybeImage, then the `!` prefix
                                     // isn't part of the footnote reference.
                                     let footnote_ix = if tos.ty == LinkStackTy::Image {
                                         self.tree[tos.node].next = Some(cur_ix);
                                         self.tree[tos.node].child = None;
-                                        self.tree[tos.node].item.body =
-                                            ItemBody::SynthesizeChar('!');
+                                        self.tree[tos.node].item.body = ItemBody::Text {
+                                            backslash_escaped: false,
+                                        };
                                         self.tree[cur_ix].item.start =
                                             self.tree[tos.node].item.start + 1;
                                         self.tree[tos.node].item.end =
@@ -904,6 +2111,12 @@ impl<'input> ParserInner<'input> {
                         }
                     }
                 }
+                ItemBody::MaybeBlockId => {
+                    // 第一遍解析已经移除了属于段落的块标识符，剩下的保留为文本
+                    self.tree[cur_ix].item.body = ItemBody::Text {
+                        backslash_escaped: false,
+                    };
+                }
                 _ => {}
             }
             prev = cur;
@@ -913,6 +2126,49 @@ impl<'input> ParserInner<'input> {
         self.wikilink_stack.clear();
         self.code_delims.clear();
         self.math_delims.clear();
+        if !self.work_budget_exceeded {
+            self.handle_autolink_literals(first, callbacks);
+        }
+    }
+
+    /// Turns the autolink literals of the chain starting at `first` into links.
+    ///
+    /// This runs after code spans and links are resolved: literals inside code
+    /// spans are gone by then, and literals that ended up in link text are
+    /// left for when that text is parsed, where they become plain text.
+    fn handle_autolink_literals(
+        &mut self,
+        first: Option<TreeIndex>,
+        callbacks: &mut dyn ParserCallbacks<'input>,
+    ) {
+        let in_link = self
+            .tree
+            .walk_spine()
+            .any(|&ix| matches!(self.tree[ix].item.body, ItemBody::Link(..)));
+        let mut cur = first;
+        while let Some(cur_ix) = cur {
+            if let ItemBody::MaybeAutolinkLiteral(link_ix) = self.tree[cur_ix].item.body {
+                let (link_type, ref dest_url, ..) = self.allocs[link_ix];
+                self.tree[cur_ix].item.body =
+                    if !in_link && callbacks.allow_autolink_literal(link_type, dest_url) {
+                        // 第一遍解析中的节点按链中的顺序编号，文本节点只能在这里创建
+                        let text_node = self.tree.create_node(Item {
+                            start: self.tree[cur_ix].item.start,
+                            end: self.tree[cur_ix].item.end,
+                            body: ItemBody::Text {
+                                backslash_escaped: false,
+                            },
+                        });
+                        self.tree[cur_ix].child = Some(text_node);
+                        ItemBody::Link(link_ix)
+                    } else {
+                        ItemBody::Text {
+                            backslash_escaped: false,
+                        }
+                    };
+            }
+            cur = self.tree[cur_ix].next;
+        }
     }
 
     /// Handles a wikilink.
@@ -1013,6 +2269,9 @@ impl<'input> ParserInner<'input> {
         let mut double_quote_open: bool = false;
 
         while let Some(mut cur_ix) = cur {
+            if !self.spend_work(1, cur_ix) {
+                break;
+            }
             match self.tree[cur_ix].item.body {
                 ItemBody::MaybeEmphasis(mut count, can_open, can_close) => {
                     let run_length = count;
@@ -1023,6 +2282,7 @@ impl<'input> ParserInner<'input> {
                             self.inline_stack
                                 .find_match(&mut self.tree, c, run_length, both)
                         {
+                            self.spend_work(1, cur_ix);
                             // have a match!
                             if let Some(prev_ix) = prev {
                                 self.tree[prev_ix].next = None;
@@ -1131,31 +2391,40 @@ impl<'input> ParserInner<'input> {
                     self.tree[cur_ix].item.body = match c {
                         b'\'' => {
                             if let (Some(open_ix), true) = (single_quote_open, can_close) {
-                                self.tree[open_ix].item.body = ItemBody::SynthesizeChar('‘');
+                                self.tree[open_ix].item.body =
+                                    ItemBody::SynthesizeChar(self.smart_quotes.single_open);
                                 single_quote_open = None;
-                            } else if can_open {
-                                single_quote_open = Some(cur_ix);
+                                ItemBody::SynthesizeChar(self.smart_quotes.single_close)
+                            } else {
+                                if can_open {
+                                    single_quote_open = Some(cur_ix);
+                                }
+                                // an apostrophe, unless a later quote closes it
+                                ItemBody::SynthesizeChar('’')
                             }
-                            ItemBody::SynthesizeChar('’')
                         }
                         _ /* double quote */ => {
                             if can_close && double_quote_open {
                                 double_quote_open = false;
-                                ItemBody::SynthesizeChar('”')
+                                ItemBody::SynthesizeChar(self.smart_quotes.double_close)
                             } else {
                                 if can_open && !double_quote_open {
                                     double_quote_open = true;
                                 }
-                                ItemBody::SynthesizeChar('“')
+                                ItemBody::SynthesizeChar(self.smart_quotes.double_open)
                             }
                         }
                     };
                     prev = cur;
                     cur = self.tree[cur_ix].next;
                 }
-                ItemBody::HardBreak(true) => {
+                ItemBody::HardBreak(HardBreakKind::Backslash) => {
                     if self.tree[cur_ix].next.is_none() {
-                        self.tree[cur_ix].item.body = ItemBody::SynthesizeChar('\\');
+                        // 保留源文本中的反斜杠，使事件保持借用
+                        self.tree[cur_ix].item.body = ItemBody::Text {
+                            backslash_escaped: false,
+                        };
+                        self.tree[cur_ix].item.end = self.tree[cur_ix].item.start + 1;
                     }
                     prev = cur;
                     cur = self.tree[cur_ix].next;
@@ -1244,7 +2513,7 @@ impl<'input> ParserInner<'input> {
             let c = bytes[i];
 
             if c == close {
-                let cow = if mark == 1 {
+                let cow = if mark == start_ix + 1 {
                     (i - start_ix + 1, text[mark..i].into())
                 } else {
                     title.push_str(&text[mark..i]);
@@ -1301,27 +2570,53 @@ impl<'input> ParserInner<'input> {
         None
     }
 
-    fn make_math_span(&mut self, open: TreeIndex, mut close: TreeIndex) {
-        let start_is_display = self.tree[open].next.filter(|&next_ix| {
-            next_ix != close
-                && matches!(
+    /// Returns true if the math delimiter at `ix` opens or closes display math.
+    /// `$$` is split in two items, of which this is true for the first one.
+    fn is_display_math_delim(&self, ix: TreeIndex) -> bool {
+        if self.is_bracket_math_delim(ix) {
+            let start = self.tree[ix].item.start;
+            matches!(self.text.as_bytes().get(start + 1), Some(b'[' | b']'))
+        } else {
+            self.tree[ix].next.is_some_and(|next_ix| {
+                matches!(
                     self.tree[next_ix].item.body,
                     ItemBody::MaybeMath(_can_open, _can_close, _brace_context)
                 )
-        });
-        let end_is_display = self.tree[close].next.filter(|&next_ix| {
-            matches!(
-                self.tree[next_ix].item.body,
-                ItemBody::MaybeMath(_can_open, _can_close, _brace_context)
-            )
-        });
-        let is_display = start_is_display.is_some() && end_is_display.is_some();
-        if is_display {
-            // This unwrap() can't panic, because if the next variable were None, end_is_display would be None
+            })
+        }
+    }
+
+    /// Returns true if the math delimiter at `ix` starts with a backslash, which
+    /// is only the case with [`MathDelimiters::Brackets`].
+    fn is_bracket_math_delim(&self, ix: TreeIndex) -> bool {
+        self.text.as_bytes()[self.tree[ix].item.start] == b'\\'
+    }
+
+    /// Turns a math delimiter that didn't match back into text. A bracket
+    /// delimiter is then an ordinary backslash escape.
+    fn unmatched_math_delim(&mut self, ix: TreeIndex) {
+        let backslash_escaped = self.is_bracket_math_delim(ix);
+        if backslash_escaped {
+            self.tree[ix].item.start += 1;
+        }
+        self.tree[ix].item.body = ItemBody::Text { backslash_escaped };
+    }
+
+    fn make_math_span(&mut self, open: TreeIndex, mut close: TreeIndex) {
+        let row_gaps = self.table_row_gaps(open, close);
+        let bracketed = self.is_bracket_math_delim(open);
+        let is_display = (bracketed || self.tree[open].next != Some(close))
+            && self.is_display_math_delim(open)
+            && self.is_display_math_delim(close);
+        if is_display && !bracketed {
+            // This unwrap() can't panic, because a display delimiter is always followed by its
+            // second half
             close = self.tree[close].next.unwrap();
             self.tree[open].next = Some(close);
             self.tree[open].item.end += 1;
             self.tree[close].item.start -= 1;
+        } else if is_display {
+            self.tree[open].next = Some(close);
         } else {
             if self.tree[open].item.end == self.tree[close].item.start {
                 // inline math spans cannot be empty
@@ -1332,18 +2627,37 @@ impl<'input> ParserInner<'input> {
             }
             self.tree[open].next = Some(close);
         }
-        let span_start = self.tree[open].item.end;
-        let span_end = self.tree[close].item.start;
+        let mut span_start = self.tree[open].item.end;
+        let mut span_end = self.tree[close].item.start;
+
+        let mut syntax = MathSyntax::Tex;
+        let content = &self.text.as_bytes()[span_start..span_end];
+        if self.options.contains(Options::ENABLE_ASCIIMATH)
+            && content.len() >= 2
+            && content.starts_with(b"`")
+            && content.ends_with(b"`")
+        {
+            syntax = MathSyntax::AsciiMath;
+            span_start += 1;
+            span_end -= 1;
+        }
 
         let spanned_text = &self.text[span_start..span_end];
         let spanned_bytes = spanned_text.as_bytes();
         let mut buf: Option<String> = None;
+        let mut row_gaps = row_gaps.into_iter().peekable();
 
         let mut start_ix = 0;
         let mut ix = 0;
         while ix < spanned_bytes.len() {
             let c = spanned_bytes[ix];
-            if c == b'\r' || c == b'\n' {
+            if let Some(gap) = row_gaps.next_if(|gap| gap.start == span_start + ix) {
+                let buf = buf.get_or_insert_with(|| String::with_capacity(spanned_bytes.len()));
+                buf.push_str(&spanned_text[start_ix..ix]);
+                buf.push('\n');
+                ix = gap.end - span_start;
+                start_ix = ix;
+            } else if c == b'\r' || c == b'\n' {
                 ix += 1;
                 let buf = buf.get_or_insert_with(|| String::with_capacity(spanned_bytes.len()));
                 buf.push_str(&spanned_text[start_ix..ix]);
@@ -1370,11 +2684,39 @@ impl<'input> ParserInner<'input> {
             spanned_text.into()
         };
 
-        self.tree[open].item.body = ItemBody::Math(self.allocs.allocate_cow(cow), is_display);
+        self.tree[open].item.body =
+            ItemBody::Math(self.allocs.allocate_cow(cow), is_display, syntax);
         self.tree[open].item.end = self.tree[close].item.end;
         self.tree[open].next = self.tree[close].next;
     }
 
+    /// Returns the source ranges between the rows of a multiline table cell
+    /// that fall between `open` and `close`.
+    ///
+    /// Each range covers the continuation marker, the line ending and the
+    /// leading pipe of the next row, and stands for a single line ending.
+    /// See [`Options::ENABLE_MULTILINE_TABLE_CELLS`].
+    fn table_row_gaps(&self, open: TreeIndex, close: TreeIndex) -> Vec<Range<usize>> {
+        let mut row_gaps = Vec::new();
+        if self.tree.is_in_table() {
+            let mut prev = open;
+            let mut cur = self.tree[open].next;
+            while let Some(cur_ix) = cur.filter(|&ix| ix != close) {
+                let next = self.tree[cur_ix].next;
+                // inside a table cell, a backslash hard break can only come
+                // from a continuation marker
+                if let (ItemBody::HardBreak(HardBreakKind::Backslash), Some(next_ix)) =
+                    (self.tree[cur_ix].item.body, next)
+                {
+                    row_gaps.push(self.tree[prev].item.end..self.tree[next_ix].item.start);
+                }
+                prev = cur_ix;
+                cur = next;
+            }
+        }
+        row_gaps
+    }
+
     /// Make a code span.
     ///
     /// Both `open` and `close` are matching MaybeCode items.
@@ -1385,15 +2727,27 @@ impl<'input> ParserInner<'input> {
 
         let spanned_text = &self.text[span_start..span_end];
         let spanned_bytes = spanned_text.as_bytes();
+        let mut row_gaps = self.table_row_gaps(open, close).into_iter().peekable();
         let mut start_ix = 0;
         let mut ix = 0;
         while ix < spanned_bytes.len() {
             let c = spanned_bytes[ix];
-            if c == b'\r' || c == b'\n' {
+            if let Some(gap) = row_gaps.next_if(|gap| gap.start == span_start + ix) {
                 let buf = buf.get_or_insert_with(|| String::with_capacity(spanned_bytes.len()));
                 buf.push_str(&spanned_text[start_ix..ix]);
                 buf.push(' ');
-                ix += 1;
+                ix = gap.end - span_start;
+                start_ix = ix;
+            } else if c == b'\r' || c == b'\n' {
+                let buf = buf.get_or_insert_with(|| String::with_capacity(spanned_bytes.len()));
+                buf.push_str(&spanned_text[start_ix..ix]);
+                buf.push(' ');
+                // a CRLF line ending is a single line ending
+                ix += if spanned_bytes[ix..].starts_with(b"\r\n") {
+                    2
+                } else {
+                    1
+                };
                 ix += skip_container_prefixes(&self.tree, &spanned_bytes[ix..], self.options);
                 start_ix = ix;
             } else if c == b'\\'
@@ -1424,7 +2778,8 @@ impl<'input> ParserInner<'input> {
             )
         };
 
-        let cow: CowStr<'input> = if !all_spaces && opening && closing {
+        let padded = !all_spaces && opening && closing;
+        let cow: CowStr<'input> = if padded {
             if let Some(mut buf) = buf {
                 if !buf.is_empty() {
                     buf.remove(0);
@@ -1446,10 +2801,10 @@ impl<'input> ParserInner<'input> {
             };
             self.tree[open].item.end = self.tree[open].item.start + 1;
             self.tree[open].next = Some(close);
-            self.tree[close].item.body = ItemBody::Code(self.allocs.allocate_cow(cow));
+            self.tree[close].item.body = ItemBody::Code(self.allocs.allocate_cow(cow), padded);
             self.tree[close].item.start = self.tree[open].item.start + 1;
         } else {
-            self.tree[open].item.body = ItemBody::Code(self.allocs.allocate_cow(cow));
+            self.tree[open].item.body = ItemBody::Code(self.allocs.allocate_cow(cow), padded);
             self.tree[open].item.end = self.tree[close].item.end;
             self.tree[open].next = self.tree[close].next;
         }
@@ -1552,14 +2907,19 @@ impl Tree<Item> {
     /// Returns true if the current node is inside a table.
     ///
     /// If `cur` is an ItemBody::Table, it would return false,
-    /// but since the `TableRow` and `TableHead` and `TableCell`
-    /// are children of the table, anything doing inline parsing
+    /// but since the `TableRow`, `TableHead`, `TableFoot` and `TableCell`
+    /// are descendants of the table, anything doing inline parsing
     /// doesn't need to care about that.
     pub(crate) fn is_in_table(&self) -> bool {
         fn might_be_in_table(item: &Item) -> bool {
             item.body.is_inline()
-                || matches!(item.body, |ItemBody::TableHead| ItemBody::TableRow
-                    | ItemBody::TableCell)
+                || matches!(
+                    item.body,
+                    ItemBody::TableHead
+                        | ItemBody::TableFoot
+                        | ItemBody::TableRow
+                        | ItemBody::TableCell(_)
+                )
         }
         for &ix in self.walk_spine().rev() {
             if matches!(self[ix].item.body, ItemBody::Table(_)) {
@@ -1755,6 +3115,62 @@ fn scan_nodes_to_ix(
     node
 }
 
+/// An HTML tag that [`Options::ENABLE_TABLE_CELL_BLOCKS`] recognizes in table
+/// cells.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum TableCellTag {
+    Break,
+    /// `<ol>` if true, `<ul>` otherwise.
+    List(bool),
+    ListEnd(bool),
+    Item,
+    ItemEnd,
+}
+
+/// Recognizes `<br>`, `<ul>`, `<ol>`, `<li>` and their closing tags, without
+/// attributes and in any case. `<br>` may be written as `<br/>` or `<br />`.
+fn scan_table_cell_tag(html: &str) -> Option<TableCellTag> {
+    let name = html.strip_prefix('<')?.strip_suffix('>')?;
+    let (closing, name) = match name.strip_prefix('/') {
+        Some(name) => (true, name),
+        None => (false, name),
+    };
+    let name = name.trim_end();
+    if !closing {
+        if let Some(name) = name.strip_suffix('/') {
+            let name = name.trim_end();
+            return name
+                .eq_ignore_ascii_case("br")
+                .then_some(TableCellTag::Break);
+        }
+    }
+    let tag = |tag: &str| name.eq_ignore_ascii_case(tag);
+    match closing {
+        false if tag("br") => Some(TableCellTag::Break),
+        false if tag("ul") || tag("ol") => Some(TableCellTag::List(tag("ol"))),
+        false if tag("li") => Some(TableCellTag::Item),
+        true if tag("ul") || tag("ol") => Some(TableCellTag::ListEnd(tag("ol"))),
+        true if tag("li") => Some(TableCellTag::ItemEnd),
+        _ => None,
+    }
+}
+
+/// Cuts `text` off after `limit` bytes, so that a label scan started at its
+/// beginning fails instead of running past the limit.
+fn bounded_label_text(text: &str, limit: Option<usize>) -> &str {
+    match limit {
+        // 为 `[`、`^` 和 `]` 留出空间
+        Some(limit) if text.len() > limit + 3 => {
+            let mut end = limit + 3;
+            while !text.is_char_boundary(end) {
+                end -= 1;
+            }
+            &text[..end]
+        }
+        _ => text,
+    }
+}
+
 /// Scans an inline link label, which cannot be interrupted.
 /// Returns number of bytes (including brackets) and label on success.
 fn scan_link_label<'text>(
@@ -1792,6 +3208,7 @@ fn scan_reference<'b>(
     text: &'b str,
     cur: Option<TreeIndex>,
     options: Options,
+    label_limit: Option<usize>,
 ) -> RefScan<'b> {
     let cur_ix = match cur {
         None => return RefScan::Failed,
@@ -1805,7 +3222,11 @@ fn scan_reference<'b>(
         let closing_node = tree[cur_ix].next.unwrap();
         RefScan::Collapsed(tree[closing_node].next)
     } else {
-        let label = scan_link_label(tree, &text[start..], options);
+        let label = scan_link_label(
+            tree,
+            bounded_label_text(&text[start..], label_limit),
+            options,
+        );
         match label {
             Some((ix, ReferenceLabel::Link(label))) => RefScan::LinkLabel(label, start + ix),
             Some((_ix, ReferenceLabel::Footnote(_label))) => RefScan::UnexpectedFootnote,
@@ -1877,10 +3298,17 @@ impl<'a> LinkDef<'a> {
     }
 }
 
-/// Contains the destination URL, title and source span of a reference definition.
+/// Contains the source span and body of a footnote definition.
 #[derive(Clone, Debug)]
-pub struct FootnoteDef {
+pub struct FootnoteDef<'a> {
+    /// The number of references to this footnote resolved so far. Inline content
+    /// is parsed lazily, so this is only complete once the parser has been exhausted.
     pub use_count: usize,
+    /// The whole definition, including the `[^label]:` marker.
+    pub span: Range<usize>,
+    /// The source text of the definition's content, after the marker. Block container
+    /// markers of continuation lines, such as `>`, are included.
+    pub body: CowStr<'a>,
 }
 
 /// Tracks tree indices of code span delimiters of each length. It should prevent
@@ -1927,6 +3355,21 @@ impl CodeDelims {
     }
 }
 
+/// Returns true if a math delimiter can close the opener. `\(` is closed by `\)`
+/// and `\[` by `\]`, while a `$$` closer can also be a `$$` opener.
+fn math_delims_match(
+    is_display: bool,
+    bracketed: bool,
+    can_close: bool,
+    delim_is_display: bool,
+) -> bool {
+    if bracketed {
+        can_close && is_display == delim_is_display
+    } else {
+        (!is_display && can_close) || (is_display && delim_is_display)
+    }
+}
+
 /// Tracks brace contexts and delimiter length for math delimiters.
 /// Provides amortized constant-time lookups.
 struct MathDelims {
@@ -1962,16 +3405,17 @@ impl MathDelims {
         tree: &Tree<Item>,
         open_ix: TreeIndex,
         is_display: bool,
+        bracketed: bool,
         brace_context: u8,
     ) -> Option<TreeIndex> {
         while let Some((ix, can_close, delim_is_display)) =
             self.inner.get_mut(&brace_context)?.pop_front()
         {
-            if ix <= open_ix || (is_display && tree[open_ix].next == Some(ix)) {
+            if ix <= open_ix || (is_display && !bracketed && tree[open_ix].next == Some(ix)) {
                 continue;
             }
             let can_close = can_close && tree[open_ix].item.end != tree[ix].item.start;
-            if (!is_display && can_close) || (is_display && delim_is_display) {
+            if math_delims_match(is_display, bracketed, can_close, delim_is_display) {
                 return Some(ix);
             }
             // if we can't use it, leave it in the queue as a tombstone for the next
@@ -2001,6 +3445,9 @@ pub(crate) struct AlignmentIndex(usize);
 #[derive(Copy, Clone, PartialEq, Eq, Debug)]
 pub(crate) struct HeadingIndex(NonZeroUsize);
 
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub(crate) struct CalloutIndex(NonZeroUsize);
+
 #[derive(Clone)]
 pub(crate) struct Allocations<'a> {
     pub refdefs: RefDefs<'a>,
@@ -2009,6 +3456,18 @@ pub(crate) struct Allocations<'a> {
     cows: Vec<CowStr<'a>>,
     alignments: Vec<Vec<Alignment>>,
     headings: Vec<HeadingAttributes<'a>>,
+    /// Attribute blocks following inline images, keyed by link index.
+    image_attributes: HashMap<usize, HeadingAttributes<'a>>,
+    /// Attribute blocks on container info strings, keyed by the container's cow index.
+    container_attributes: HashMap<usize, HeadingAttributes<'a>>,
+    /// Block ids of paragraphs and tight list items, keyed by the block's start.
+    block_ids: HashMap<usize, CowStr<'a>>,
+    /// Fold markers and titles of blockquote callouts.
+    callouts: Vec<(Option<bool>, Option<CowStr<'a>>)>,
+    pub diagnostics: Vec<Diagnostic>,
+    /// While set, the `take_*` methods clone instead of moving the value out, so
+    /// the same nodes can be turned into events again later.
+    pub keep: bool,
 }
 
 /// Used by the heading attributes extension.
@@ -2019,13 +3478,27 @@ pub(crate) struct HeadingAttributes<'a> {
     pub attrs: Vec<(CowStr<'a>, Option<CowStr<'a>>)>,
 }
 
+impl<'a> HeadingAttributes<'a> {
+    /// Folds the id into the attributes, as images and containers carry no
+    /// separate id field.
+    fn into_boxed_attrs(self) -> Option<Box<Attributes<'a>>> {
+        let attrs = self
+            .id
+            .map(|id| ("id".into(), Some(id)))
+            .into_iter()
+            .chain(self.attrs)
+            .collect();
+        Attributes::boxed(self.classes, attrs)
+    }
+}
+
 /// Keeps track of the reference definitions defined in the document.
 #[derive(Clone, Default, Debug)]
 pub struct RefDefs<'input>(pub(crate) HashMap<LinkLabel<'input>, LinkDef<'input>>);
 
 /// Keeps track of the footnote definitions defined in the document.
 #[derive(Clone, Default, Debug)]
-pub struct FootnoteDefs<'input>(pub(crate) HashMap<FootnoteLabel<'input>, FootnoteDef>);
+pub struct FootnoteDefs<'input>(pub(crate) HashMap<FootnoteLabel<'input>, FootnoteDef<'input>>);
 
 impl<'input, 'b, 's> RefDefs<'input>
 where
@@ -2051,9 +3524,18 @@ where
         self.0.contains_key(&UniCase::new(key.into()))
     }
     /// Performs a lookup on reference label using unicode case folding.
-    pub fn get_mut(&'s mut self, key: CowStr<'input>) -> Option<&'s mut FootnoteDef> {
+    pub fn get(&'s self, key: &'b str) -> Option<&'b FootnoteDef<'input>> {
+        self.0.get(&UniCase::new(key.into()))
+    }
+    /// Performs a lookup on reference label using unicode case folding.
+    pub fn get_mut(&'s mut self, key: CowStr<'input>) -> Option<&'s mut FootnoteDef<'input>> {
         self.0.get_mut(&UniCase::new(key))
     }
+
+    /// Provides an iterator over all the document's footnote definitions.
+    pub fn iter(&'s self) -> impl Iterator<Item = (&'s str, &'s FootnoteDef<'input>)> {
+        self.0.iter().map(|(k, v)| (k.as_ref(), v))
+    }
 }
 
 impl<'a> Allocations<'a> {
@@ -2065,6 +3547,12 @@ impl<'a> Allocations<'a> {
             cows: Vec::new(),
             alignments: Vec::new(),
             headings: Vec::new(),
+            image_attributes: HashMap::new(),
+            container_attributes: HashMap::new(),
+            block_ids: HashMap::new(),
+            callouts: Vec::new(),
+            diagnostics: Vec::new(),
+            keep: false,
         }
     }
 
@@ -2101,16 +3589,82 @@ impl<'a> Allocations<'a> {
         HeadingIndex(ix_nonzero)
     }
 
+    pub fn allocate_image_attributes(&mut self, ix: LinkIndex, attrs: HeadingAttributes<'a>) {
+        self.image_attributes.insert(ix.0, attrs);
+    }
+
+    pub fn allocate_container_attributes(&mut self, ix: CowIndex, attrs: HeadingAttributes<'a>) {
+        self.container_attributes.insert(ix.0, attrs);
+    }
+
+    pub fn allocate_block_id(&mut self, block_start: usize, id: CowStr<'a>) {
+        self.block_ids.insert(block_start, id);
+    }
+
+    pub fn has_block_id(&self, block_start: usize) -> bool {
+        self.block_ids.contains_key(&block_start)
+    }
+
+    pub fn allocate_callout(
+        &mut self,
+        folded: Option<bool>,
+        title: Option<CowStr<'a>>,
+    ) -> CalloutIndex {
+        let ix = self.callouts.len();
+        self.callouts.push((folded, title));
+        let ix_nonzero = NonZeroUsize::new(ix.wrapping_add(1)).expect("too many callouts");
+        CalloutIndex(ix_nonzero)
+    }
+
     pub fn take_cow(&mut self, ix: CowIndex) -> CowStr<'a> {
+        if self.keep {
+            return self.cows[ix.0].clone();
+        }
         core::mem::replace(&mut self.cows[ix.0], "".into())
     }
 
     pub fn take_link(&mut self, ix: LinkIndex) -> (LinkType, CowStr<'a>, CowStr<'a>, CowStr<'a>) {
+        if self.keep {
+            return self.links[ix.0].clone();
+        }
         let default_link = (LinkType::ShortcutUnknown, "".into(), "".into(), "".into());
         core::mem::replace(&mut self.links[ix.0], default_link)
     }
 
+    pub fn take_image_attributes(&mut self, ix: LinkIndex) -> Option<HeadingAttributes<'a>> {
+        if self.image_attributes.is_empty() {
+            return None;
+        }
+        if self.keep {
+            return self.image_attributes.get(&ix.0).cloned();
+        }
+        self.image_attributes.remove(&ix.0)
+    }
+
+    pub fn take_container_attributes(&mut self, ix: CowIndex) -> Option<HeadingAttributes<'a>> {
+        if self.container_attributes.is_empty() {
+            return None;
+        }
+        if self.keep {
+            return self.container_attributes.get(&ix.0).cloned();
+        }
+        self.container_attributes.remove(&ix.0)
+    }
+
+    pub fn take_block_id(&mut self, block_start: usize) -> Option<CowStr<'a>> {
+        if self.block_ids.is_empty() {
+            return None;
+        }
+        if self.keep {
+            return self.block_ids.get(&block_start).cloned();
+        }
+        self.block_ids.remove(&block_start)
+    }
+
     pub fn take_alignment(&mut self, ix: AlignmentIndex) -> Vec<Alignment> {
+        if self.keep {
+            return self.alignments[ix.0].clone();
+        }
         core::mem::take(&mut self.alignments[ix.0])
     }
 }
@@ -2147,6 +3701,14 @@ impl<'a> Index<HeadingIndex> for Allocations<'a> {
     }
 }
 
+impl<'a> Index<CalloutIndex> for Allocations<'a> {
+    type Output = (Option<bool>, Option<CowStr<'a>>);
+
+    fn index(&self, ix: CalloutIndex) -> &Self::Output {
+        self.callouts.index(ix.0.get() - 1)
+    }
+}
+
 /// A struct containing information on the reachability of certain inline HTML
 /// elements. In particular, for cdata elements (`<![CDATA[`), processing
 /// elements (`<?`) and declarations (`<!DECLARATION`). The respectives usizes
@@ -2177,6 +3739,120 @@ pub trait ParserCallbacks<'input> {
     ) -> Option<(CowStr<'input>, CowStr<'input>)> {
         None
     }
+
+    /// Potentially resolve a footnote reference that has no definition.
+    ///
+    /// Only called when GFM-style footnotes are enabled (e.g. `[^foo]` when
+    /// there is no `[^foo]: ` definition in the document). If a label is
+    /// returned, a [`Event::FootnoteReference`] with that label is emitted;
+    /// otherwise the reference is rendered as literal text.
+    ///
+    /// The returned pair holds the label and, optionally, the text of a
+    /// definition for it. A supplied definition is emitted at the end of the
+    /// document as a footnote definition holding one paragraph with that text,
+    /// and later references to the label resolve to it without calling this
+    /// again. Return no text when the definition lives in another document.
+    ///
+    /// ```
+    /// # use pulldown_cmark::{BrokenFootnote, CowStr, Options, Parser, ParserCallbacks};
+    /// struct Glossary;
+    /// impl<'input> ParserCallbacks<'input> for Glossary {
+    ///     fn handle_broken_footnote(
+    ///         &mut self,
+    ///         footnote: BrokenFootnote<'input>,
+    ///     ) -> Option<(CowStr<'input>, Option<CowStr<'input>>)> {
+    ///         (footnote.reference.as_ref() == "md").then(|| {
+    ///             let definition = "Markdown, a lightweight markup language.".into();
+    ///             (footnote.reference, Some(definition))
+    ///         })
+    ///     }
+    /// }
+    ///
+    /// let parser = Parser::new_with_callbacks("See [^md].\n", Options::ENABLE_FOOTNOTES, Glossary);
+    /// let mut html = String::new();
+    /// pulldown_cmark::html::push_html(&mut html, parser);
+    /// assert!(html.contains("Markdown, a lightweight markup language."));
+    /// ```
+    fn handle_broken_footnote(
+        &mut self,
+        #[allow(unused_variables)] footnote: BrokenFootnote<'input>,
+    ) -> Option<(CowStr<'input>, Option<CowStr<'input>>)> {
+        None
+    }
+
+    /// Decides whether an autolink literal becomes a link.
+    ///
+    /// Called with [`Options::ENABLE_AUTOLINK_LITERALS`] for every recognized
+    /// address, see [`ParserBuilder::autolink_kinds`]. The link type is
+    /// [`LinkType::EmailLiteral`] for email addresses and [`LinkType::AutolinkLiteral`]
+    /// otherwise. Rejected addresses are emitted as text.
+    ///
+    /// ```
+    /// # use pulldown_cmark::{Event, LinkType, Options, Parser, ParserCallbacks};
+    /// struct NoEmail;
+    /// impl<'input> ParserCallbacks<'input> for NoEmail {
+    ///     fn allow_autolink_literal(&mut self, link_type: LinkType, _dest_url: &str) -> bool {
+    ///         link_type != LinkType::EmailLiteral
+    ///     }
+    /// }
+    ///
+    /// let options = Options::ENABLE_AUTOLINK_LITERALS;
+    /// let parser = Parser::new_with_callbacks("www.a.com me@a.com", options, NoEmail);
+    /// let links = parser.filter(|event| matches!(event, Event::End(_))).count();
+    /// assert_eq!(links, 2); // the link and the paragraph
+    /// ```
+    fn allow_autolink_literal(
+        &mut self,
+        #[allow(unused_variables)] link_type: LinkType,
+        #[allow(unused_variables)] dest_url: &str,
+    ) -> bool {
+        true
+    }
+
+    /// Applies a per-document override from the front matter.
+    ///
+    /// Called before parsing with every `key: value` pair under the
+    /// `markdown` key of a metadata block that starts the document, when
+    /// [`Options::ENABLE_YAML_STYLE_METADATA_BLOCKS`] or
+    /// [`Options::ENABLE_PLUSES_DELIMITED_METADATA_BLOCKS`] is enabled. Both
+    /// the block form and the inline `markdown: { key: value }` form are
+    /// recognized; TOML front matter uses `=` and a `[markdown]` table
+    /// instead. Values are passed unparsed, without surrounding quotes.
+    ///
+    /// The returned options are used for the document, so only the keys the
+    /// caller maps to an option have an effect. Keys for render settings can
+    /// be recorded here and applied when rendering.
+    ///
+    /// ```
+    /// # use pulldown_cmark::{Event, Options, Parser, ParserCallbacks};
+    /// struct SmartPunct;
+    /// impl<'input> ParserCallbacks<'input> for SmartPunct {
+    ///     fn front_matter_override(&mut self, key: &str, value: &str, mut options: Options) -> Options {
+    ///         if key == "smart_punct" {
+    ///             options.set(Options::ENABLE_SMART_PUNCTUATION, value == "true");
+    ///         }
+    ///         options
+    ///     }
+    /// }
+    ///
+    /// let markdown = "---\nmarkdown: { smart_punct: false }\n---\n\"Hi\"\n";
+    /// let options = Options::ENABLE_YAML_STYLE_METADATA_BLOCKS | Options::ENABLE_SMART_PUNCTUATION;
+    /// let parser = Parser::new_with_callbacks(markdown, options, SmartPunct);
+    /// assert!(parser
+    ///     .filter_map(|event| match event {
+    ///         Event::Text(text) => Some(text),
+    ///         _ => None,
+    ///     })
+    ///     .any(|text| text.as_ref() == "\"Hi\""));
+    /// ```
+    fn front_matter_override(
+        &mut self,
+        #[allow(unused_variables)] key: &str,
+        #[allow(unused_variables)] value: &str,
+        options: Options,
+    ) -> Options {
+        options
+    }
 }
 
 /// Wrapper to implement [`ParserCallbacks::handle_broken_link`] with a closure.
@@ -2204,6 +3880,21 @@ impl<'input> ParserCallbacks<'input> for Box<dyn ParserCallbacks<'input>> {
     ) -> Option<(CowStr<'input>, CowStr<'input>)> {
         (**self).handle_broken_link(link)
     }
+
+    fn handle_broken_footnote(
+        &mut self,
+        footnote: BrokenFootnote<'input>,
+    ) -> Option<(CowStr<'input>, Option<CowStr<'input>>)> {
+        (**self).handle_broken_footnote(footnote)
+    }
+
+    fn allow_autolink_literal(&mut self, link_type: LinkType, dest_url: &str) -> bool {
+        (**self).allow_autolink_literal(link_type, dest_url)
+    }
+
+    fn front_matter_override(&mut self, key: &str, value: &str, options: Options) -> Options {
+        (**self).front_matter_override(key, value, options)
+    }
 }
 
 /// [Parser] callbacks that do nothing.
@@ -2231,6 +3922,21 @@ impl<'a, CB: ParserCallbacks<'a>> OffsetIter<'a, CB> {
     pub fn reference_definitions(&self) -> &RefDefs<'_> {
         self.parser.reference_definitions()
     }
+
+    /// Returns a reference to the internal footnote definition tracker.
+    pub fn footnote_definitions(&self) -> &FootnoteDefs<'_> {
+        self.parser.footnote_definitions()
+    }
+
+    /// Returns the problems noticed so far while parsing. See [`Parser::diagnostics`].
+    pub fn diagnostics(&self) -> &[Diagnostic] {
+        self.parser.diagnostics()
+    }
+
+    /// Returns whether the work budget has run out. See [`Parser::work_budget_exceeded`].
+    pub fn work_budget_exceeded(&self) -> bool {
+        self.parser.work_budget_exceeded()
+    }
 }
 
 impl<'a, CB: ParserCallbacks<'a>> Iterator for OffsetIter<'a, CB> {
@@ -2259,6 +3965,31 @@ impl<'input> ParserInner<'input> {
     fn next_event_range(
         &mut self,
         callbacks: &mut dyn ParserCallbacks<'input>,
+    ) -> Option<(Event<'input>, Range<usize>)> {
+        #[cfg(not(feature = "tracing"))]
+        return self.next_event_range_inner(callbacks);
+
+        #[cfg(feature = "tracing")]
+        {
+            let next = self.next_event_range_inner(callbacks);
+            if next.is_some() {
+                self.events_emitted += 1;
+            } else if self.events_emitted > 0 {
+                tracing::debug!(
+                    bytes = self.text.len(),
+                    events = self.events_emitted,
+                    "parse finished"
+                );
+                // Only report once, the iterator is fused.
+                self.events_emitted = 0;
+            }
+            next
+        }
+    }
+
+    fn next_event_range_inner(
+        &mut self,
+        callbacks: &mut dyn ParserCallbacks<'input>,
     ) -> Option<(Event<'input>, Range<usize>)> {
         match self.tree.cur() {
             None => {
@@ -2266,7 +3997,7 @@ impl<'input> ParserInner<'input> {
                 let ix = if matches!(self.tree[ix].item.body, ItemBody::TightParagraph) {
                     // tight paragraphs emit nothing
                     self.tree.next_sibling(ix);
-                    return self.next_event_range(callbacks);
+                    return self.next_event_range_inner(callbacks);
                 } else {
                     ix
                 };
@@ -2316,7 +4047,7 @@ fn body_to_tag_end(body: &ItemBody) -> TagEnd {
         ItemBody::Heading(level, _) => TagEnd::Heading(level),
         ItemBody::IndentCodeBlock | ItemBody::FencedCodeBlock(..) => TagEnd::CodeBlock,
         ItemBody::Container(_, kind, _) => TagEnd::ContainerBlock(kind),
-        ItemBody::BlockQuote(kind) => TagEnd::BlockQuote(kind),
+        ItemBody::BlockQuote(kind, _) => TagEnd::BlockQuote(kind),
         ItemBody::HtmlBlock => TagEnd::HtmlBlock,
         ItemBody::List(_, c, _) => {
             let is_ordered = c == b'.' || c == b')';
@@ -2324,7 +4055,8 @@ fn body_to_tag_end(body: &ItemBody) -> TagEnd {
         }
         ItemBody::ListItem(_) => TagEnd::Item,
         ItemBody::TableHead => TagEnd::TableHead,
-        ItemBody::TableCell => TagEnd::TableCell,
+        ItemBody::TableFoot => TagEnd::TableFoot,
+        ItemBody::TableCell(_) => TagEnd::TableCell,
         ItemBody::TableRow => TagEnd::TableRow,
         ItemBody::Table(..) => TagEnd::Table,
         ItemBody::FootnoteDefinition(..) => TagEnd::FootnoteDefinition,
@@ -2339,7 +4071,16 @@ fn body_to_tag_end(body: &ItemBody) -> TagEnd {
 fn item_to_event<'a>(item: Item, text: &'a str, allocs: &mut Allocations<'a>) -> Event<'a> {
     let tag = match item.body {
         ItemBody::Text { .. } => return Event::Text(text[item.start..item.end].into()),
-        ItemBody::Code(cow_ix) => return Event::Code(allocs.take_cow(cow_ix)),
+        ItemBody::Code(cow_ix, padded) => {
+            // 结束定界符紧挨着代码节点的末尾，且与开始定界符等长
+            let backticks = text[..item.end]
+                .bytes()
+                .rev()
+                .take_while(|&b| b == b'`')
+                .count();
+            let delimiter = CodeDelimiter { backticks, padded };
+            return Event::Code(allocs.take_cow(cow_ix), delimiter);
+        }
         ItemBody::SynthesizeText(cow_ix) => return Event::Text(allocs.take_cow(cow_ix)),
         ItemBody::SynthesizeChar(c) => return Event::Text(c.into()),
         ItemBody::HtmlBlock => Tag::HtmlBlock,
@@ -2347,13 +4088,14 @@ fn item_to_event<'a>(item: Item, text: &'a str, allocs: &mut Allocations<'a>) ->
         ItemBody::InlineHtml => return Event::InlineHtml(text[item.start..item.end].into()),
         ItemBody::OwnedInlineHtml(cow_ix) => return Event::InlineHtml(allocs.take_cow(cow_ix)),
         ItemBody::SoftBreak => return Event::SoftBreak,
-        ItemBody::HardBreak(_) => return Event::HardBreak,
+        ItemBody::HardBreak(kind) => return Event::HardBreak(kind),
         ItemBody::FootnoteReference(cow_ix) => {
             return Event::FootnoteReference(allocs.take_cow(cow_ix))
         }
         ItemBody::TaskListMarker(checked) => return Event::TaskListMarker(checked),
+        ItemBody::Hashtag => return Event::Hashtag(text[item.start + 1..item.end].into()),
         ItemBody::Rule => return Event::Rule,
-        ItemBody::Paragraph => Tag::Paragraph,
+        ItemBody::Paragraph => Tag::Paragraph(allocs.take_block_id(item.start)),
         ItemBody::Emphasis => Tag::Emphasis,
         ItemBody::Superscript => Tag::Superscript,
         ItemBody::Subscript => Tag::Subscript,
@@ -2369,12 +4111,15 @@ fn item_to_event<'a>(item: Item, text: &'a str, allocs: &mut Allocations<'a>) ->
             }
         }
         ItemBody::Image(link_ix) => {
+            let attributes = allocs
+                .take_image_attributes(link_ix)
+                .and_then(HeadingAttributes::into_boxed_attrs);
             let (link_type, dest_url, title, id) = allocs.take_link(link_ix);
             Tag::Image {
                 link_type,
                 dest_url,
                 title,
-                id,
+                extras: ImageExtras::boxed(id, attributes),
             }
         }
         ItemBody::Heading(level, Some(heading_ix)) => {
@@ -2382,22 +4127,35 @@ fn item_to_event<'a>(item: Item, text: &'a str, allocs: &mut Allocations<'a>) ->
             Tag::Heading {
                 level,
                 id: id.clone(),
-                classes: classes.clone(),
-                attrs: attrs.clone(),
+                attributes: Attributes::boxed(classes.clone(), attrs.clone()),
             }
         }
         ItemBody::Heading(level, None) => Tag::Heading {
             level,
             id: None,
-            classes: Vec::new(),
-            attrs: Vec::new(),
+            attributes: None,
         },
         ItemBody::FencedCodeBlock(cow_ix) => {
             Tag::CodeBlock(CodeBlockKind::Fenced(allocs.take_cow(cow_ix)))
         }
         ItemBody::IndentCodeBlock => Tag::CodeBlock(CodeBlockKind::Indented),
-        ItemBody::Container(_, kind, cow_ix) => Tag::ContainerBlock(kind, allocs.take_cow(cow_ix)),
-        ItemBody::BlockQuote(kind) => Tag::BlockQuote(kind),
+        ItemBody::Container(_, kind, cow_ix) => {
+            let attributes = allocs
+                .take_container_attributes(cow_ix)
+                .and_then(HeadingAttributes::into_boxed_attrs);
+            Tag::ContainerBlock(kind, allocs.take_cow(cow_ix), attributes)
+        }
+        ItemBody::BlockQuote(kind, callout) => {
+            let (folded, title) = match callout {
+                Some(callout_ix) => allocs.index(callout_ix).clone(),
+                None => (None, None),
+            };
+            Tag::BlockQuote {
+                kind,
+                folded,
+                title,
+            }
+        }
         ItemBody::List(_, c, listitem_start) => {
             if c == b'.' || c == b')' {
                 Tag::List(Some(listitem_start))
@@ -2405,18 +4163,26 @@ fn item_to_event<'a>(item: Item, text: &'a str, allocs: &mut Allocations<'a>) ->
                 Tag::List(None)
             }
         }
-        ItemBody::ListItem(_) => Tag::Item,
+        ItemBody::ListItem(_) => {
+            // list items start at their marker, possibly after some indentation
+            let marker = text[item.start..].trim_start_matches([' ', '\t']);
+            let number = scan_listitem(marker.as_bytes())
+                .filter(|&(_, c, _, _)| c == b'.' || c == b')')
+                .map(|(_, _, number, _)| number as u64);
+            Tag::Item(number, allocs.take_block_id(item.start))
+        }
         ItemBody::TableHead => Tag::TableHead,
-        ItemBody::TableCell => Tag::TableCell,
+        ItemBody::TableFoot => Tag::TableFoot,
+        ItemBody::TableCell(alignment) => Tag::TableCell(alignment),
         ItemBody::TableRow => Tag::TableRow,
         ItemBody::Table(alignment_ix) => Tag::Table(allocs.take_alignment(alignment_ix)),
         ItemBody::FootnoteDefinition(cow_ix) => Tag::FootnoteDefinition(allocs.take_cow(cow_ix)),
         ItemBody::MetadataBlock(kind) => Tag::MetadataBlock(kind),
-        ItemBody::Math(cow_ix, is_display) => {
+        ItemBody::Math(cow_ix, is_display, syntax) => {
             return if is_display {
-                Event::DisplayMath(allocs.take_cow(cow_ix))
+                Event::DisplayMath(allocs.take_cow(cow_ix), syntax)
             } else {
-                Event::InlineMath(allocs.take_cow(cow_ix))
+                Event::InlineMath(allocs.take_cow(cow_ix), syntax)
             }
         }
         ItemBody::DefinitionList(_) => Tag::DefinitionList,
@@ -2449,6 +4215,62 @@ mod test {
         Parser::new_ext(text, opts)
     }
 
+    #[test]
+    fn outline_matches_block_events() {
+        let text = "Title *one*\n===\n\n- a\n- b\n\n  c\n\n---\n\n| x |\n|---|\n| *y* |\n\n\
+                    ```rust\ncode\n```\n\n<div>\nhtml\n</div>\n\n> ## Nested `code` {#n}\n> text[^1]\n\n\
+                    [^1]: note\n";
+        let outline = parser_with_extensions(text).into_outline();
+        let headings: Vec<_> = outline
+            .iter()
+            .filter_map(|(event, span)| match event {
+                Event::Text(text) => Some((text.as_ref(), span.clone())),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(
+            headings,
+            [("Title *one*", 0..11), ("Nested `code` {#n}", 99..117)]
+        );
+
+        let is_inline = |tag: &TagEnd| {
+            matches!(
+                tag,
+                TagEnd::Emphasis
+                    | TagEnd::Strong
+                    | TagEnd::Strikethrough
+                    | TagEnd::Superscript
+                    | TagEnd::Subscript
+                    | TagEnd::Link
+                    | TagEnd::Image
+            )
+        };
+        let blocks: Vec<_> = parser_with_extensions(text)
+            .into_offset_iter()
+            .filter(|(event, _)| match event {
+                Event::Start(tag) => !is_inline(&tag.to_end()),
+                Event::End(tag) => !is_inline(tag),
+                Event::Rule => true,
+                _ => false,
+            })
+            .collect();
+        let outline: Vec<_> = outline
+            .into_iter()
+            .filter(|(event, _)| !matches!(event, Event::Text(_)))
+            .collect();
+        assert_eq!(outline, blocks);
+
+        // Container prefixes are not part of setext heading text.
+        for text in ["> one\n> two\n> ===\n", "- one\n  two\n  ===\n"] {
+            let outline = Parser::new(text).into_outline();
+            let headings: Vec<_> = outline
+                .iter()
+                .filter(|(event, _)| matches!(event, Event::Text(_)))
+                .collect();
+            assert_eq!(headings, [&(Event::Text("one\ntwo".into()), 2..11)]);
+        }
+    }
+
     #[test]
     #[cfg(target_pointer_width = "64")]
     fn node_size() {
@@ -2655,7 +4477,7 @@ mod test {
         let event_offset = parser_with_extensions(markdown)
             .into_offset_iter()
             .filter_map(|(ev, span)| match ev {
-                Event::Start(Tag::TableCell) => Some(span),
+                Event::Start(Tag::TableCell(_)) => Some(span),
                 _ => None,
             })
             .nth(4)
@@ -2796,6 +4618,602 @@ mod test {
         assert!(link_tag_count > 0);
     }
 
+    #[test]
+    fn broken_footnote_callback_supplies_label() {
+        struct FootnoteCallbacks(usize);
+        impl<'input> ParserCallbacks<'input> for FootnoteCallbacks {
+            fn handle_broken_footnote(
+                &mut self,
+                footnote: BrokenFootnote<'input>,
+            ) -> Option<(CowStr<'input>, Option<CowStr<'input>>)> {
+                self.0 += 1;
+                assert_eq!(footnote.reference.as_ref(), "elsewhere");
+                Some((footnote.reference, None))
+            }
+        }
+
+        let test_str = "See [^elsewhere] and [^local].\n\n[^local]: here\n";
+        let mut parser =
+            Parser::new_with_callbacks(test_str, Options::ENABLE_FOOTNOTES, FootnoteCallbacks(0));
+        let refs: Vec<_> = parser
+            .by_ref()
+            .filter_map(|event| match event {
+                Event::FootnoteReference(label) => Some(label),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(refs, vec!["elsewhere".into(), CowStr::from("local")]);
+        assert_eq!(parser.callbacks.0, 1);
+    }
+
+    #[test]
+    fn broken_footnote_callback_supplies_definition() {
+        struct DefinitionCallbacks(usize);
+        impl<'input> ParserCallbacks<'input> for DefinitionCallbacks {
+            fn handle_broken_footnote(
+                &mut self,
+                footnote: BrokenFootnote<'input>,
+            ) -> Option<(CowStr<'input>, Option<CowStr<'input>>)> {
+                self.0 += 1;
+                Some((footnote.reference, Some("Supplied *text*.".into())))
+            }
+        }
+
+        let test_str = "> See [^a] and [^A].\n\n[^b]: local\n";
+        let mut parser =
+            Parser::new_with_callbacks(test_str, Options::ENABLE_FOOTNOTES, DefinitionCallbacks(0));
+        let events: Vec<_> = parser.by_ref().collect();
+        assert_eq!(
+            events[events.len() - 5..],
+            [
+                Event::Start(Tag::FootnoteDefinition("a".into())),
+                Event::Start(Tag::Paragraph(None)),
+                Event::Text("Supplied *text*.".into()),
+                Event::End(TagEnd::Paragraph),
+                Event::End(TagEnd::FootnoteDefinition),
+            ]
+        );
+        // The second reference resolves to the supplied definition.
+        assert_eq!(parser.callbacks.0, 1);
+        let def = parser.footnote_definitions().get("a").unwrap();
+        assert_eq!(def.use_count, 2);
+        assert_eq!(def.span, test_str.len()..test_str.len());
+    }
+
+    #[test]
+    fn broken_footnote_callback_span() {
+        let test_str = "x ![^missing] y";
+        let mut spans = Vec::new();
+        struct SpanCallbacks<'a>(&'a mut Vec<Range<usize>>);
+        impl<'input> ParserCallbacks<'input> for SpanCallbacks<'_> {
+            fn handle_broken_footnote(
+                &mut self,
+                footnote: BrokenFootnote<'input>,
+            ) -> Option<(CowStr<'input>, Option<CowStr<'input>>)> {
+                self.0.push(footnote.span);
+                None
+            }
+        }
+        let parser = Parser::new_with_callbacks(
+            test_str,
+            Options::ENABLE_FOOTNOTES,
+            SpanCallbacks(&mut spans),
+        );
+        let text: String = parser
+            .filter_map(|event| match event {
+                Event::Text(text) => Some(text.into_string()),
+                Event::FootnoteReference(_) => panic!("unexpected footnote reference"),
+                _ => None,
+            })
+            .collect();
+        // Without a supplied label, the reference falls back to literal text.
+        assert_eq!(text, test_str);
+        assert_eq!(spans.len(), 1);
+        assert_eq!(&test_str[spans[0].clone()], "[^missing]");
+    }
+
+    #[test]
+    fn diagnostics() {
+        let test_str = "[a]: /one\n[a]: /two\n\n| a | b |\n|---|---|\n| 1 | 2 | 3 |\n\nSee [^missing].\n\n> ```\n> quoted\n\n```\nnever closed\n";
+        let options = Options::ENABLE_TABLES | Options::ENABLE_FOOTNOTES;
+
+        let mut parser = Parser::new_ext(test_str, options);
+        parser.by_ref().for_each(drop);
+        assert!(parser.diagnostics().is_empty());
+
+        let mut parser = Parser::new_ext(test_str, options | Options::ENABLE_DIAGNOSTICS);
+        parser.by_ref().for_each(drop);
+        let kinds: Vec<_> = parser
+            .diagnostics()
+            .iter()
+            .map(|d| (d.kind, &test_str[d.span.clone()]))
+            .collect();
+        assert_eq!(
+            kinds,
+            [
+                (DiagnosticKind::DuplicateReferenceDefinition, "[a]: /two"),
+                (
+                    DiagnosticKind::ExcessTableCells {
+                        expected: 2,
+                        found: 3
+                    },
+                    "| 1 | 2 | 3 |\n"
+                ),
+                (DiagnosticKind::UnclosedCodeFence, "```\n> quoted\n"),
+                (DiagnosticKind::UnclosedCodeFence, "```\nnever closed\n"),
+                (DiagnosticKind::UndefinedFootnote, "[^missing]"),
+            ]
+        );
+    }
+
+    #[test]
+    fn malformed_heading_attributes() {
+        let test_str =
+            "# One {#id .class\n\n## Two {#id . x=1}\n\nThree {=x}\n===\n\n# `{#code` {#ok}\n";
+        let options = Options::ENABLE_HEADING_ATTRIBUTES | Options::ENABLE_DIAGNOSTICS;
+        let mut parser = Parser::new_ext(test_str, options);
+        parser.by_ref().for_each(drop);
+        let kinds: Vec<_> = parser
+            .diagnostics()
+            .iter()
+            .map(|d| (d.kind, &test_str[d.span.clone()]))
+            .collect();
+        assert_eq!(
+            kinds,
+            [
+                (DiagnosticKind::MalformedHeadingAttributes, "{#id .class"),
+                (DiagnosticKind::MalformedHeadingAttributes, "{#id . x=1}"),
+                (DiagnosticKind::MalformedHeadingAttributes, "{=x}"),
+            ]
+        );
+    }
+
+    #[test]
+    fn footnote_definitions() {
+        let test_str =
+            "Text[^a].\n\n[^a]: First *line*\n    second line\n\n> [^B]: quoted\n\nafter\n";
+        let parser = Parser::new_ext(test_str, Options::ENABLE_FOOTNOTES);
+        let defs = parser.footnote_definitions();
+        let a = defs.get("a").unwrap();
+        assert_eq!(
+            &test_str[a.span.clone()],
+            "[^a]: First *line*\n    second line"
+        );
+        assert_eq!(a.body, "First *line*\n    second line".into());
+        assert_eq!(a.use_count, 0);
+        let b = defs.get("b").unwrap();
+        assert_eq!(b.body, "quoted".into());
+        assert_eq!(defs.iter().count(), 2);
+
+        let mut parser = parser;
+        parser.by_ref().for_each(drop);
+        assert_eq!(parser.footnote_definitions().get("A").unwrap().use_count, 1);
+    }
+
+    #[test]
+    fn hard_break_kind() {
+        let test_str = "a  \nb\\\nc\n\n| x |\n|---|\n| y | \\\n| z |\n";
+        let breaks: Vec<_> = Parser::new_ext(
+            test_str,
+            Options::ENABLE_TABLES | Options::ENABLE_MULTILINE_TABLE_CELLS,
+        )
+        .filter_map(|event| match event {
+            Event::HardBreak(kind) => Some(kind),
+            _ => None,
+        })
+        .collect();
+        assert_eq!(
+            breaks,
+            [
+                HardBreakKind::Spaces,
+                HardBreakKind::Backslash,
+                HardBreakKind::Backslash
+            ]
+        );
+    }
+
+    #[test]
+    fn code_delimiter() {
+        let test_str = "`a` `` `b` `` ``` c``d ``` \\``e` ` `";
+        let codes: Vec<_> = Parser::new(test_str)
+            .filter_map(|event| match event {
+                Event::Code(text, delimiter) => Some((text, delimiter.backticks, delimiter.padded)),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(
+            codes,
+            [
+                ("a".into(), 1, false),
+                ("`b`".into(), 2, true),
+                ("c``d".into(), 3, true),
+                ("e".into(), 1, false),
+                (" ".into(), 1, false),
+            ]
+        );
+    }
+
+    #[test]
+    fn footnote_random_access() {
+        let test_str = "Text[^a].\n\n> [^b]: [link](/url \"title\") `code`\n\n[^a]: First\n";
+        let options = Options::ENABLE_FOOTNOTES;
+        let mut parser = Parser::new_ext(test_str, options);
+        let b: Vec<_> = parser.footnote("b").unwrap().collect();
+        assert_eq!(b, parser.footnote("B").unwrap().collect::<Vec<_>>());
+        assert_eq!(b.len(), 9);
+        assert_eq!(
+            b[2],
+            Event::Start(Tag::Link {
+                link_type: LinkType::Inline,
+                dest_url: "/url".into(),
+                title: "title".into(),
+                id: "".into(),
+            })
+        );
+        assert_eq!(b[6], Event::Code("code".into(), Default::default()));
+        assert_eq!(parser.footnote("a").unwrap().count(), 5);
+
+        // Looking up footnotes leaves the event stream untouched.
+        assert_eq!(
+            parser.collect::<Vec<_>>(),
+            Parser::new_ext(test_str, options).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn container_nesting_and_attributes() {
+        let options = Options::ENABLE_CONTAINER_EXTENSIONS;
+        let test_str = ":::: note {#outer .wide data-x=1}\n- a\n- b\n::: spoiler Title {open}\nx\n:::\n::::\nafter\n";
+        let events: Vec<_> = Parser::new_ext(test_str, options).collect();
+        assert_eq!(
+            events[0],
+            Event::Start(Tag::ContainerBlock(
+                ContainerKind::Default,
+                "note".into(),
+                Attributes::boxed(
+                    vec!["wide".into()],
+                    vec![
+                        ("id".into(), Some("outer".into())),
+                        ("data-x".into(), Some("1".into())),
+                    ],
+                ),
+            ))
+        );
+        assert!(events.contains(&Event::Start(Tag::ContainerBlock(
+            ContainerKind::Spoiler,
+            "Title".into(),
+            Attributes::boxed(vec![], vec![("open".into(), None)]),
+        ))));
+        // The list ends at the inner fence and the outer fence closes the note.
+        let after_ix = events
+            .iter()
+            .position(|e| *e == Event::Text("after".into()))
+            .unwrap();
+        assert_eq!(
+            events[after_ix - 2],
+            Event::End(TagEnd::ContainerBlock(ContainerKind::Default))
+        );
+
+        let test_str = "::: note\n1. a\n\n:::\nafter\n";
+        let mut html = String::new();
+        crate::html::push_html(&mut html, Parser::new_ext(test_str, options));
+        assert!(html.contains("</ol>\n</div>\n<p>after</p>"));
+
+        let mut html = String::new();
+        crate::html::push_html(&mut html, Parser::new_ext("::: spoiler\nx\n:::\n", options));
+        assert_eq!(
+            html,
+            "<details class=\"collapse collapse-plus border\"><div class=\"collapse-content text-sm\">\n<p>x</p>\n</div></details>\n"
+        );
+
+        // The closing fence wins over a definition marker.
+        let options = options | Options::ENABLE_DEFINITION_LIST;
+        for test_str in ["::: a\nterm\n:::\nz\n", "::: a\nterm\n: def\n:::\nz\n"] {
+            let events: Vec<_> = Parser::new_ext(test_str, options).collect();
+            let z_ix = events
+                .iter()
+                .position(|e| *e == Event::Text("z".into()))
+                .unwrap();
+            assert_eq!(
+                events[z_ix - 2],
+                Event::End(TagEnd::ContainerBlock(ContainerKind::Default))
+            );
+        }
+    }
+
+    #[test]
+    fn budgeted_parser_matches_parser() {
+        let test_str = "# [a]\n\n> - b\n>   c\n\n```\ncode\n```\n\n[a]: /url\n";
+        let options = Options::ENABLE_TABLES | Options::ENABLE_FOOTNOTES;
+        let expected: Vec<_> = Parser::new_ext(test_str, options).collect();
+        for fuel in [0, 1, 10, usize::MAX] {
+            let mut parser = BudgetedParser::new_ext(test_str, options);
+            let mut events = Vec::new();
+            let mut pending = 0;
+            loop {
+                match parser.next_budgeted(fuel) {
+                    Poll::Pending => pending += 1,
+                    Poll::Ready(Some(event)) => events.push(event),
+                    Poll::Ready(None) => break,
+                }
+            }
+            assert_eq!(events, expected);
+            assert!(parser.is_ready());
+            assert!(pending < test_str.len());
+            assert_eq!(pending == 0, fuel == usize::MAX);
+        }
+
+        let mut parser = BudgetedParser::new_ext(test_str, options);
+        assert_eq!(parser.next_budgeted(1), Poll::Pending);
+        assert_eq!(parser.into_parser().collect::<Vec<_>>(), expected);
+
+        // The builder's settings apply to both passes.
+        let builder = || {
+            Parser::builder()
+                .options(options)
+                .disable(DisabledFeatures::HEADINGS)
+                .smart_punctuation(SmartPunctuation::DASHES)
+        };
+        let test_str = "# [a] -- \"b\"\n\n[a]: /url\n";
+        let expected: Vec<_> = builder().build(test_str).collect();
+        let mut parser = builder().build_budgeted(test_str);
+        let mut events = Vec::new();
+        loop {
+            match parser.next_budgeted(1) {
+                Poll::Pending => {}
+                Poll::Ready(Some(event)) => events.push(event),
+                Poll::Ready(None) => break,
+            }
+        }
+        assert_eq!(events, expected);
+        assert_eq!(events[0], Event::Start(Tag::Paragraph(None)));
+        let text: String = events
+            .iter()
+            .filter_map(|event| match event {
+                Event::Text(text) => Some(text.as_ref()),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(text, "# a – \"b\"");
+    }
+
+    #[test]
+    fn builder_link_ref_expansion_limit() {
+        let test_str = "[a]: /a\n\n[a] [a] [a]";
+        fn count_links(parser: Parser<'_>) -> usize {
+            parser
+                .filter(|event| matches!(event, Event::Start(Tag::Link { .. })))
+                .count()
+        }
+        assert_eq!(count_links(Parser::builder().build(test_str)), 3);
+        // Each expansion costs the length of the URL and title.
+        assert_eq!(
+            count_links(
+                Parser::builder()
+                    .link_ref_expansion_limit(2)
+                    .build(test_str)
+            ),
+            1
+        );
+    }
+
+    #[test]
+    fn builder_disable() {
+        let test_str =
+            "# a\nb\n===\n\n<div>\n\nc <b>d</b> <https://e.org> ![f](/g) [^h]\n\n- i\n\n[^h]: j\n";
+        let parser = Parser::builder()
+            .enable(Options::ENABLE_FOOTNOTES)
+            .disable(DisabledFeatures::comment())
+            .build(test_str);
+        let mut tags = Vec::new();
+        let mut text = String::new();
+        for event in parser {
+            match event {
+                Event::Start(tag) => tags.push(tag.to_end()),
+                Event::Text(t) => text.push_str(&t),
+                Event::SoftBreak => text.push('\n'),
+                _ => {}
+            }
+        }
+        assert_eq!(
+            tags,
+            [
+                TagEnd::Paragraph,
+                TagEnd::Paragraph,
+                TagEnd::Paragraph,
+                TagEnd::Link,
+                TagEnd::Link,
+                // without footnotes, `[^h]: j` is a link reference definition
+                TagEnd::Link,
+                TagEnd::List(false),
+                TagEnd::Item,
+            ]
+        );
+        assert_eq!(text, "# a\nb\n===<div>c <b>d</b> https://e.org !f ^hi");
+    }
+
+    #[test]
+    fn builder_bullet_markers() {
+        let tags = |markers, input| {
+            Parser::builder()
+                .bullet_markers(markers)
+                .build(input)
+                .filter_map(|event| match event {
+                    Event::Start(tag) => Some(tag.to_end()),
+                    _ => None,
+                })
+                .collect::<Vec<_>>()
+        };
+        // `+` neither starts a list nor interrupts a paragraph, even lazily
+        let no_plus = BulletMarkers::DASH | BulletMarkers::STAR;
+        assert_eq!(tags(no_plus, "+ a\n+ b\n"), [TagEnd::Paragraph]);
+        assert_eq!(
+            tags(no_plus, "> a\n+ b\n"),
+            [TagEnd::BlockQuote(None), TagEnd::Paragraph]
+        );
+        assert_eq!(
+            tags(no_plus, "- a\n+ b\n1. c\n"),
+            [
+                TagEnd::List(false),
+                TagEnd::Item,
+                TagEnd::List(true),
+                TagEnd::Item
+            ]
+        );
+        // thematic breaks are not affected
+        assert_eq!(
+            tags(BulletMarkers::empty(), "* a\n\n* * *\n"),
+            [TagEnd::Paragraph]
+        );
+        assert_eq!(
+            tags(BulletMarkers::all(), "+ a\n"),
+            [TagEnd::List(false), TagEnd::Item]
+        );
+    }
+
+    #[test]
+    fn builder_smart_punctuation() {
+        let text = |builder: ParserBuilder, input| {
+            builder
+                .build(input)
+                .filter_map(|event| match event {
+                    Event::Text(t) => Some(t.into_string()),
+                    _ => None,
+                })
+                .collect::<String>()
+        };
+        let test_str = "\"Don't\" -- wait... 1/2 (C) (tm) 11/2 1/2/3 [x](c)";
+        let smart = Parser::builder().enable(Options::ENABLE_SMART_PUNCTUATION);
+        assert_eq!(
+            text(smart, test_str),
+            "“Don’t” – wait… 1/2 (C) (tm) 11/2 1/2/3 x"
+        );
+        let all = Parser::builder().smart_punctuation(SmartPunctuation::all());
+        assert_eq!(text(all, test_str), "“Don't” – wait… ½ © ™ 11/2 1/2/3 x");
+        let dashes = Parser::builder().smart_punctuation(SmartPunctuation::DASHES);
+        assert_eq!(
+            text(dashes, test_str),
+            "\"Don't\" – wait... 1/2 (C) (tm) 11/2 1/2/3 x"
+        );
+        let off = Parser::builder()
+            .enable(Options::ENABLE_SMART_PUNCTUATION)
+            .smart_punctuation(SmartPunctuation::empty());
+        assert_eq!(text(off, test_str), test_str.replace("[x](c)", "x"));
+        let french = Parser::builder()
+            .enable(Options::ENABLE_SMART_PUNCTUATION)
+            .smart_quotes(SmartQuotes::french());
+        assert_eq!(text(french, "\"Don't\" 'x' it's"), "«Don’t» ‹x› it’s");
+    }
+
+    #[test]
+    fn parse_arena_reuse() {
+        let mut arena = ParseArena::new();
+        let long = "- item *a*\n".repeat(200);
+        let mut capacity = 0;
+        for text in [long.as_str(), "# a\n\n> b [c](/d)\n", ""] {
+            let mut parser = Parser::new_in(&mut arena, text, Options::empty());
+            let events: Vec<_> = parser.by_ref().collect();
+            assert_eq!(events, Parser::new(text).collect::<Vec<_>>());
+            arena.reclaim(parser);
+            capacity = capacity.max(arena.capacity());
+            assert_eq!(arena.capacity(), capacity);
+        }
+        assert!(capacity > 800);
+    }
+
+    #[test]
+    fn builder_work_budget() {
+        let test_str = "*a* [b](/c) `d`\n\n**e**";
+        let expected: Vec<_> = Parser::new(test_str).collect();
+        let mut parser = Parser::builder().work_budget(1000).build(test_str);
+        assert_eq!(parser.by_ref().collect::<Vec<_>>(), expected);
+        assert!(!parser.work_budget_exceeded());
+
+        let mut parser = Parser::builder()
+            .enable(Options::ENABLE_DIAGNOSTICS)
+            .work_budget(3)
+            .build(test_str);
+        let text: String = parser
+            .by_ref()
+            .map(|event| match event {
+                Event::Text(text) => text.into_string(),
+                Event::Start(Tag::Paragraph(_)) | Event::End(TagEnd::Paragraph) => String::new(),
+                _ => panic!("unexpected event {:?}", event),
+            })
+            .collect();
+        // The inline markup is left as literal text.
+        assert_eq!(text, "*a* [b](/c) `d`**e**");
+        assert!(parser.work_budget_exceeded());
+        assert_eq!(
+            parser.diagnostics()[0].kind,
+            DiagnosticKind::WorkBudgetExceeded
+        );
+    }
+
+    #[test]
+    fn borrowed_events() {
+        let inputs = [
+            "# Heading {#id .class}\n\nplain *emphasis* **strong** text\nsoft break",
+            "- item\n- [x] task\n\n1. one\n2. two",
+            "> quote\n>\n> `code span` and `` a ` b ``",
+            "```rust\nfn main() {}\n```\n\n    indented",
+            "[inline](/url 'title') [ref] <https://example.com> ![img](/img.png)\n\n[ref]: /ref",
+            "| a | b |\n|---|---|\n| c | d |",
+            "footnote[^1] and ![^1]\n\n[^1]: note",
+            "trailing backslash\\",
+            "<div>\nhtml\n</div>\n\n<span>inline</span>",
+            "$x$ and $$y$$",
+        ];
+        for input in inputs {
+            for event in Parser::new_ext(input, Options::all()) {
+                assert!(event.is_borrowed(), "{:?} in {:?}", event, input);
+            }
+        }
+
+        for input in ["it's", "a -- b", "`code\nspan`", "[a](/url 't\\'s')"] {
+            assert!(
+                !Parser::new_ext(input, Options::all()).all(|event| event.is_borrowed()),
+                "{:?}",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn builder_linear_references() {
+        let long = "x".repeat(300);
+        let test_str = format!("[a] [{long}]\n\n[a]: /a\n[{long}]: /long\n");
+        let links = |parser: Parser| {
+            parser
+                .filter(|event| matches!(event, Event::Start(Tag::Link { .. })))
+                .count()
+        };
+        assert_eq!(links(Parser::new(&test_str)), 2);
+        let parser = Parser::builder().linear_references(true).build(&test_str);
+        assert_eq!(links(parser), 1);
+
+        // Lookups stop once the reference budget is spent.
+        let mut parser = Parser::builder()
+            .linear_references(true)
+            .build("[a] [a] [a]\n\n[a]: /a\n");
+        parser.inner.reference_budget = Some(2);
+        assert_eq!(links(parser), 2);
+    }
+
+    #[test]
+    fn builder_callbacks() {
+        let test_str = "[broken]";
+        let callback = |link: BrokenLink<'static>| Some(("/url".into(), link.reference));
+        let mut parser = Parser::builder()
+            .enable(Options::ENABLE_FOOTNOTES)
+            .callbacks(BrokenLinkCallback(Some(callback)))
+            .build(test_str);
+        assert!(matches!(
+            parser.nth(1),
+            Some(Event::Start(Tag::Link { .. }))
+        ));
+    }
+
     #[test]
     fn code_block_kind_check_fenced() {
         let parser = Parser::new("hello\n```test\ntadam\n```");
@@ -2894,8 +5312,12 @@ text
         let input = "> <foo\n> bar>";
         let events: Vec<_> = Parser::new(input).collect();
         let expected = [
-            Event::Start(Tag::BlockQuote(None)),
-            Event::Start(Tag::Paragraph),
+            Event::Start(Tag::BlockQuote {
+                kind: None,
+                folded: None,
+                title: None,
+            }),
+            Event::Start(Tag::Paragraph(None)),
             Event::InlineHtml(CowStr::Boxed("<foo\nbar>".to_string().into())),
             Event::End(TagEnd::Paragraph),
             Event::End(TagEnd::BlockQuote(None)),
@@ -2908,7 +5330,7 @@ text
         let input = "[[foo]] [[bar|baz]]";
         let events: Vec<_> = Parser::new_ext(input, Options::ENABLE_WIKILINKS).collect();
         let expected = [
-            Event::Start(Tag::Paragraph),
+            Event::Start(Tag::Paragraph(None)),
             Event::Start(Tag::Link {
                 link_type: LinkType::WikiLink { has_pothole: false },
                 dest_url: CowStr::Borrowed("foo"),
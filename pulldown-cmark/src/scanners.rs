@@ -21,13 +21,16 @@
 //! CommonMark语法片段的扫描器
 
 use alloc::{string::String, vec::Vec};
-use core::char;
+use core::{char, ops::Range};
 
 use memchr::memchr;
 
 pub(crate) use crate::puncttable::{is_ascii_punctuation, is_punctuation};
 use crate::{
-    entities, parse::HtmlScanGuard, strings::CowStr, Alignment, BlockQuoteKind, HeadingLevel,
+    entities,
+    parse::{HeadingAttributes, HtmlScanGuard},
+    strings::CowStr,
+    Alignment, AutolinkKinds, AutolinkTrimming, BlockQuoteKind, BulletMarkers, HeadingLevel,
     LinkType,
 };
 
@@ -221,10 +224,14 @@ impl<'a> LineStart<'a> {
         ok
     }
 
-    pub(crate) fn scan_blockquote_tag(&mut self) -> Option<BlockQuoteKind> {
+    /// 扫描`[!NOTE]`这样的引用块类型标记，以及可选的折叠标记（`-`或`+`）和
+    /// 同一行剩余的标题。返回类型、折叠标记和标题的范围（相对于行首，没有标题时为空）。
+    pub(crate) fn scan_blockquote_tag(
+        &mut self,
+    ) -> Option<(BlockQuoteKind, Option<bool>, Range<usize>)> {
         let saved_ix = self.ix;
         let tag = if self.scan_ch(b'[') && self.scan_ch(b'!') {
-            let tag = if self.scan_case_insensitive(b"note") {
+            let kind = if self.scan_case_insensitive(b"note") {
                 Some(BlockQuoteKind::Note)
             } else if self.scan_case_insensitive(b"tip") {
                 Some(BlockQuoteKind::Tip)
@@ -237,15 +244,30 @@ impl<'a> LineStart<'a> {
             } else {
                 None
             };
-            if tag.is_some() && self.scan_ch(b']') {
-                if let Some(nl) = scan_blank_line(&self.bytes[self.ix..]) {
-                    self.ix += nl;
-                    tag
-                } else {
-                    None
+            match kind {
+                Some(kind) if self.scan_ch(b']') => {
+                    let folded = if self.scan_ch(b'-') {
+                        Some(true)
+                    } else if self.scan_ch(b'+') {
+                        Some(false)
+                    } else {
+                        None
+                    };
+                    let rest = &self.bytes[self.ix..];
+                    let line_len = scan_nextline(rest);
+                    let line = &rest[..line_len];
+                    let title_start = scan_while(line, is_ascii_whitespace_no_nl);
+                    let title_end = line.len() - scan_rev_while(line, is_ascii_whitespace);
+                    if title_start == 0 && title_start < title_end {
+                        // `[!NOTE]title`不是类型标记
+                        None
+                    } else {
+                        let title = self.ix + title_start..self.ix + title_end.max(title_start);
+                        self.ix += line_len;
+                        Some((kind, folded, title))
+                    }
                 }
-            } else {
-                None
+                _ => None,
             }
         } else {
             None
@@ -310,15 +332,17 @@ impl<'a> LineStart<'a> {
     ///
     /// Return value is the character, the start index, and the indent in spaces.
     /// For ordered list markers, the character will be one of b'.' or b')'. For
-    /// bullet list markers, it will be one of b'-', b'+', or b'*'.
+    /// bullet list markers, it will be one of b'-', b'+', or b'*', if enabled in
+    /// `bullets`.
     pub(crate) fn scan_list_marker_with_indent(
         &mut self,
         indent: usize,
+        bullets: BulletMarkers,
     ) -> Option<(u8, u64, usize)> {
         let save = self.clone();
         if self.ix < self.bytes.len() {
             let c = self.bytes[self.ix];
-            if c == b'-' || c == b'+' || c == b'*' {
+            if bullets.allows(c) {
                 if self.ix >= self.min_hrule_offset {
                     // there could be an hrule here
                     if let Err(min_offset) = scan_hrule(&self.bytes[self.ix..]) {
@@ -822,6 +846,102 @@ pub(crate) fn scan_metadata_block(
     }
 }
 
+/// 扫描文档开头的元数据块（front matter），返回分隔符、内容的范围和元数据块的结束位置。
+///
+/// 内容不包括开始和结束分隔符所在的行，结束位置在结束分隔符及其后的空格之后。
+pub(crate) fn scan_front_matter(
+    bytes: &[u8],
+    yaml_style_enabled: bool,
+    pluses_style_enabled: bool,
+) -> Option<(u8, Range<usize>, usize)> {
+    let (n, fence_char) = scan_metadata_block(bytes, yaml_style_enabled, pluses_style_enabled)?;
+    let start = n + scan_nextline(&bytes[n..]);
+    let mut ix = start;
+    loop {
+        if let Some(end) = scan_closing_metadata_block(&bytes[ix..], fence_char) {
+            return Some((fence_char, start..ix, ix + end));
+        }
+        ix += scan_nextline(&bytes[ix..]);
+    }
+}
+
+/// 扫描文档开头的元数据块（front matter）中`markdown`键下的选项，返回键值对。
+///
+/// YAML风格支持`markdown: { key: value }`和缩进的`key: value`行，加号风格
+/// （TOML）支持`markdown = { key = value }`和`[markdown]`表。键和值两端的空白
+/// 与引号会被去掉，值不做进一步解析。
+pub(crate) fn scan_front_matter_options(
+    text: &str,
+    yaml_style_enabled: bool,
+    pluses_style_enabled: bool,
+) -> Vec<(&str, &str)> {
+    let mut options = Vec::new();
+    let Some((fence_char, content, _)) =
+        scan_front_matter(text.as_bytes(), yaml_style_enabled, pluses_style_enabled)
+    else {
+        return options;
+    };
+    let (assign, table) = if fence_char == b'-' {
+        (':', None)
+    } else {
+        ('=', Some("[markdown]"))
+    };
+    let mut in_section = false;
+    for line in text[content].lines() {
+        let line = line.trim_end();
+        let trimmed = line.trim_start();
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            continue;
+        }
+        let is_nested = match table {
+            // YAML的嵌套映射需要缩进
+            None => trimmed.len() < line.len(),
+            Some(_) => !trimmed.starts_with('['),
+        };
+        if in_section && is_nested {
+            options.extend(split_front_matter_option(trimmed, assign));
+            continue;
+        }
+        in_section = false;
+        if Some(trimmed) == table {
+            in_section = true;
+        } else if let Some((key, value)) = split_front_matter_option(line, assign) {
+            if key != "markdown" {
+                continue;
+            }
+            if let Some(inline) = value.strip_prefix('{').and_then(|v| v.strip_suffix('}')) {
+                options.extend(
+                    inline
+                        .split(',')
+                        .filter_map(|item| split_front_matter_option(item, assign)),
+                );
+            } else if value.is_empty() {
+                in_section = table.is_none();
+            }
+        }
+    }
+    options
+}
+
+/// 按`assign`拆分`key: value`或`key = value`，去掉两端的空白和引号。
+fn split_front_matter_option(item: &str, assign: char) -> Option<(&str, &str)> {
+    fn unquote(s: &str) -> &str {
+        let s = s.trim();
+        for quote in ['"', '\''] {
+            if let Some(inner) = s.strip_prefix(quote).and_then(|s| s.strip_suffix(quote)) {
+                return inner;
+            }
+        }
+        s
+    }
+    let (key, value) = item.split_once(assign)?;
+    let key = unquote(key);
+    if key.is_empty() {
+        return None;
+    }
+    Some((key, unquote(value)))
+}
+
 pub(crate) fn scan_blockquote_start(data: &[u8]) -> Option<usize> {
     if data.first().copied() == Some(b'>') {
         let space = if data.get(1).copied() == Some(b' ') {
@@ -958,6 +1078,127 @@ pub(crate) fn scan_entity(bytes: &[u8]) -> (usize, Option<CowStr<'static>>) {
     (0, None)
 }
 
+// 扫描`#tag`形式的行内标签，返回包括`#`在内的字节数。
+// 标签由字母、数字、`_`、`-`和`/`组成，且不能全是数字。
+pub(crate) fn scan_hashtag(text: &str) -> Option<usize> {
+    let rest = text.strip_prefix('#')?;
+    let len = rest
+        .find(|c: char| !(c.is_alphanumeric() || matches!(c, '_' | '-' | '/')))
+        .unwrap_or(rest.len());
+    let tag = &rest[..len];
+    if tag.is_empty() || tag.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    Some(len + 1)
+}
+
+/// 扫描以`{`开头、以`}`结束的属性块，例如`{#id .class key=value key="a b"}`，
+/// 返回属性块的长度和其中的属性。
+///
+/// 属性之间以空白分隔，值可以用单引号或双引号包围从而包含空白。属性块不能跨行。
+pub(crate) fn scan_attribute_block(text: &str) -> Option<(usize, HeadingAttributes<'_>)> {
+    let bytes = text.as_bytes();
+    if bytes.first() != Some(&b'{') {
+        return None;
+    }
+    let mut attributes = HeadingAttributes {
+        id: None,
+        classes: Vec::new(),
+        attrs: Vec::new(),
+    };
+    let mut ix = 1;
+    loop {
+        ix += scan_while(&bytes[ix..], is_ascii_whitespace_no_nl);
+        let marker = match bytes.get(ix) {
+            Some(b'}') => return Some((ix + 1, attributes)),
+            Some(&marker @ (b'#' | b'.')) => {
+                ix += 1;
+                Some(marker)
+            }
+            _ => None,
+        };
+        let name_len = scan_while(&bytes[ix..], |b| {
+            b.is_ascii_alphanumeric() || matches!(b, b'-' | b'_' | b':')
+        });
+        if name_len == 0 {
+            return None;
+        }
+        let name = &text[ix..ix + name_len];
+        ix += name_len;
+        match marker {
+            Some(b'#') => attributes.id = Some(name.into()),
+            Some(_) => attributes.classes.push(name.into()),
+            None if bytes.get(ix) == Some(&b'=') => {
+                ix += 1;
+                let quote = bytes.get(ix).copied().filter(|&b| b == b'"' || b == b'\'');
+                let value_len = match quote {
+                    Some(quote) => {
+                        ix += 1;
+                        memchr(quote, &bytes[ix..])?
+                    }
+                    None => scan_while(&bytes[ix..], |b| {
+                        !is_ascii_whitespace(b) && !matches!(b, b'}' | b'"' | b'\'')
+                    }),
+                };
+                let value = &text[ix..ix + value_len];
+                if (quote.is_none() && value.is_empty()) || value.contains(['\n', '\r']) {
+                    return None;
+                }
+                ix += value_len + usize::from(quote.is_some());
+                attributes.attrs.push((name.into(), Some(value.into())));
+            }
+            None => attributes.attrs.push((name.into(), None)),
+        }
+        match bytes.get(ix) {
+            Some(b'}') => {}
+            Some(&b) if is_ascii_whitespace_no_nl(b) => {}
+            _ => return None,
+        }
+    }
+}
+
+/// 扫描`^block-id`，后面只能跟空白直到行尾。返回标识符（包括`^`）的长度。
+pub(crate) fn scan_block_id(bytes: &[u8]) -> Option<usize> {
+    let rest = bytes.strip_prefix(b"^")?;
+    let len = scan_while(rest, |b| b.is_ascii_alphanumeric() || b == b'-');
+    if len == 0 {
+        return None;
+    }
+    let trailing = scan_while(&rest[len..], is_ascii_whitespace_no_nl);
+    match rest.get(len + trailing) {
+        None | Some(b'\n' | b'\r') => Some(len + 1),
+        Some(_) => None,
+    }
+}
+
+/// 扫描`ix`处的`/`所在的分数`1/2`、`1/4`或`3/4`，返回对应的字符。
+///
+/// 分数前后不能紧挨字母、数字或`/`，以免替换`11/2`或日期`1/2/2024`中的部分内容。
+pub(crate) fn scan_fraction(bytes: &[u8], ix: usize) -> Option<char> {
+    let fraction = match (bytes[ix - 1], bytes.get(ix + 1)?) {
+        (b'1', b'2') => '½',
+        (b'1', b'4') => '¼',
+        (b'3', b'4') => '¾',
+        _ => return None,
+    };
+    let is_word = |b: &u8| b.is_ascii_alphanumeric() || *b == b'/';
+    if ix >= 2 && is_word(&bytes[ix - 2]) || bytes.get(ix + 2).is_some_and(is_word) {
+        return None;
+    }
+    Some(fraction)
+}
+
+/// 扫描`(c)`、`(r)`或`(tm)`（不区分大小写），返回对应的符号和长度。
+pub(crate) fn scan_symbol(bytes: &[u8]) -> Option<(char, usize)> {
+    const SYMBOLS: [(&[u8], char); 3] = [(b"(c)", '©'), (b"(r)", '®'), (b"(tm)", '™')];
+    SYMBOLS.iter().find_map(|&(pattern, symbol)| {
+        let candidate = bytes.get(..pattern.len())?;
+        candidate
+            .eq_ignore_ascii_case(pattern)
+            .then_some((symbol, pattern.len()))
+    })
+}
+
 pub(crate) fn scan_wikilink_pipe(data: &str, start_ix: usize, len: usize) -> Option<(usize, &str)> {
     let bytes = data.as_bytes();
     let end_ix = core::cmp::min(start_ix + len, bytes.len());
@@ -1369,6 +1610,173 @@ pub(crate) fn scan_autolink(text: &str, start_ix: usize) -> Option<(usize, CowSt
         .or_else(|| scan_email(text, start_ix).map(|(bytes, uri)| (bytes, uri, LinkType::Email)))
 }
 
+/// 扫描`ix`处的`:`或`.`所在的自动链接字面量，即以`http://`、`https://`、`www.`
+/// 或`tel:`开头的地址，返回它在`bytes`中的范围。
+///
+/// 地址必须从`line_start`或空白、`*`、`_`、`~`、`(`之后开始，且不早于`min_start`。
+/// 它在空白、`<`、`|`、`\\`和方括号处结束，末尾的字符按`trimming`去掉。
+pub(crate) fn scan_autolink_literal(
+    bytes: &[u8],
+    ix: usize,
+    line_start: usize,
+    min_start: usize,
+    kinds: AutolinkKinds,
+    trimming: AutolinkTrimming,
+) -> Option<Range<usize>> {
+    let prefix = &bytes[..ix];
+    let (start, domain_start) = match bytes[ix] {
+        b':' if kinds.contains(AutolinkKinds::TEL) && prefix.ends_with(b"tel") => {
+            let start = ix - 3;
+            if !is_autolink_literal_start(bytes, start, line_start, min_start) {
+                return None;
+            }
+            let len = scan_while(&bytes[ix + 1..], |b| {
+                b.is_ascii_digit() || matches!(b, b'+' | b'-' | b'.' | b'(' | b')')
+            });
+            let end = trim_autolink_literal(bytes, start, ix + 1 + len, ix + 1, trimming);
+            return bytes[ix + 1..end]
+                .iter()
+                .any(u8::is_ascii_digit)
+                .then_some(start..end);
+        }
+        b':' if kinds.contains(AutolinkKinds::WEB) && bytes[ix + 1..].starts_with(b"//") => {
+            let scheme_len = if prefix.ends_with(b"https") {
+                5
+            } else if prefix.ends_with(b"http") {
+                4
+            } else {
+                return None;
+            };
+            (ix - scheme_len, ix + 3)
+        }
+        b'.' if kinds.contains(AutolinkKinds::WEB) && prefix.ends_with(b"www") => (ix - 3, ix - 3),
+        _ => return None,
+    };
+    if !is_autolink_literal_start(bytes, start, line_start, min_start) {
+        return None;
+    }
+
+    let domain_len = scan_while(&bytes[domain_start..], |b| {
+        b.is_ascii_alphanumeric() || matches!(b, b'-' | b'_' | b'.') || b >= 0x80
+    });
+    let end = domain_start
+        + domain_len
+        + scan_while(&bytes[domain_start + domain_len..], |b| {
+            !is_ascii_whitespace(b) && !matches!(b, b'<' | b'|' | b'\\' | b'[' | b']' | b'`')
+        });
+    let end = trim_autolink_literal(bytes, start, end, domain_start, trimming);
+
+    // 域名至少包含一个`.`，最后两段不能包含`_`
+    let domain = &bytes[domain_start..end.min(domain_start + domain_len)];
+    let mut segments = domain.split(|&b| b == b'.').rev();
+    let last = segments.next()?;
+    let second_last = segments.next()?;
+    if last.is_empty()
+        || second_last.is_empty()
+        || last.contains(&b'_')
+        || second_last.contains(&b'_')
+    {
+        return None;
+    }
+    Some(start..end)
+}
+
+/// 扫描`ix`处的`@`所在的电子邮件地址，可以带有`mailto:`前缀，返回它在`bytes`中的范围。
+///
+/// 开头的规则与[`scan_autolink_literal`]相同。域名至少包含一个`.`，
+/// 不能以`-`或`_`结尾，末尾的`.`不属于地址。
+pub(crate) fn scan_email_literal(
+    bytes: &[u8],
+    ix: usize,
+    line_start: usize,
+    min_start: usize,
+) -> Option<Range<usize>> {
+    let local_len = scan_rev_while(&bytes[..ix], |b| {
+        b.is_ascii_alphanumeric() || matches!(b, b'.' | b'+' | b'-' | b'_')
+    });
+    if local_len == 0 {
+        return None;
+    }
+    let mut start = ix - local_len;
+    if bytes[..start].ends_with(b"mailto:") {
+        start -= 7;
+    }
+    if !is_autolink_literal_start(bytes, start, line_start, min_start) {
+        return None;
+    }
+    let mut end = ix
+        + 1
+        + scan_while(&bytes[ix + 1..], |b| {
+            b.is_ascii_alphanumeric() || matches!(b, b'.' | b'-' | b'_')
+        });
+    while bytes[end - 1] == b'.' {
+        end -= 1;
+    }
+    let domain = &bytes[ix + 1..end];
+    if !domain.contains(&b'.') || matches!(bytes[end - 1], b'-' | b'_') {
+        return None;
+    }
+    Some(start..end)
+}
+
+/// 自动链接字面量能否从`start`开始。
+fn is_autolink_literal_start(
+    bytes: &[u8],
+    start: usize,
+    line_start: usize,
+    min_start: usize,
+) -> bool {
+    start >= min_start
+        && (start == line_start
+            || matches!(bytes[start - 1], b'*' | b'_' | b'~' | b'(')
+            || is_ascii_whitespace(bytes[start - 1]))
+}
+
+/// 按`trimming`去掉自动链接字面量`bytes[start..end]`末尾的字符，但不早于`min_end`。
+/// 返回新的结束位置。
+fn trim_autolink_literal(
+    bytes: &[u8],
+    start: usize,
+    mut end: usize,
+    min_end: usize,
+    trimming: AutolinkTrimming,
+) -> usize {
+    while end > min_end {
+        let link = &bytes[start..end];
+        let trim = match link[link.len() - 1] {
+            b'?' | b'!' | b'.' | b',' | b':' | b'*' | b'_' | b'~' => {
+                trimming.contains(AutolinkTrimming::PUNCTUATION)
+            }
+            b'"' | b'\'' => trimming.contains(AutolinkTrimming::QUOTES),
+            b')' => {
+                trimming.contains(AutolinkTrimming::PARENTHESES)
+                    || (trimming.contains(AutolinkTrimming::UNBALANCED_PARENTHESES)
+                        && link.iter().filter(|&&b| b == b')').count()
+                            > link.iter().filter(|&&b| b == b'(').count())
+            }
+            b';' if trimming.contains(AutolinkTrimming::ENTITY_REFERENCES) => {
+                let name_len =
+                    scan_rev_while(&link[..link.len() - 1], |b| b.is_ascii_alphanumeric());
+                if name_len > 0
+                    && link.len() > name_len + 1
+                    && link[link.len() - name_len - 2] == b'&'
+                {
+                    end -= name_len + 1;
+                    true
+                } else {
+                    false
+                }
+            }
+            _ => false,
+        };
+        if !trim {
+            break;
+        }
+        end -= 1;
+    }
+    end
+}
+
 /// Returns (next_byte_offset, uri)
 fn scan_uri(text: &str, start_ix: usize) -> Option<(usize, CowStr<'_>)> {
     let bytes = &text.as_bytes()[start_ix..];
@@ -1589,4 +1997,26 @@ mod test {
             assert!(scan_email(email, 1).is_none());
         }
     }
+
+    #[test]
+    fn front_matter_options() {
+        let yaml =
+            "---\ntitle: x\nmarkdown:\n  smart_punct: false\n  'tables': \"yes\"\nauthor: y\n---\n";
+        assert_eq!(
+            scan_front_matter_options(yaml, true, false),
+            [("smart_punct", "false"), ("tables", "yes")]
+        );
+        let inline = "---\nmarkdown: { a: 1, b: two }\n---\n";
+        assert_eq!(
+            scan_front_matter_options(inline, true, false),
+            [("a", "1"), ("b", "two")]
+        );
+        let toml = "+++\nmarkdown = { a = true }\n[markdown]\nb = \"c\"\n[other]\nd = 1\n+++\n";
+        assert_eq!(
+            scan_front_matter_options(toml, false, true),
+            [("a", "true"), ("b", "c")]
+        );
+        assert!(scan_front_matter_options(yaml, false, true).is_empty());
+        assert!(scan_front_matter_options(&format!("x\n{}", yaml), true, false).is_empty());
+    }
 }
@@ -11,21 +11,34 @@ use core::{
     str::from_utf8,
 };
 
-const MAX_INLINE_STR_LEN: usize = 3 * core::mem::size_of::<isize>() - 2;
+// `CowStr` 占用的字数，由内联缓冲区决定。`large-inline-str` 特性
+// 以更大的 `CowStr` 为代价，让较长的字符串（尤其是多字节的 CJK 文本）免于分配。
+#[cfg(not(feature = "large-inline-str"))]
+const INLINE_STR_WORDS: usize = 3;
+#[cfg(feature = "large-inline-str")]
+const INLINE_STR_WORDS: usize = 5;
+
+// 减去长度字节和枚举标签。
+const MAX_INLINE_STR_LEN: usize = INLINE_STR_WORDS * core::mem::size_of::<isize>() - 2;
 
 /// 当尝试将`&str`转换为`InlineStr`时返回的错误，
 /// 转换失败是因为字符串太长。
 #[derive(Debug)]
 pub struct StringTooLongError;
 
-/// 一个可以包含近三个单词的utf-8文本的
-/// 内联字符串。
+/// 一个可以包含近三个字（启用 `large-inline-str` 时为近五个字）的
+/// utf-8文本的内联字符串。
 #[derive(Debug, Clone, Copy, Eq)]
 pub struct InlineStr {
     inner: [u8; MAX_INLINE_STR_LEN],
     len: u8,
 }
 
+impl InlineStr {
+    /// 可以内联存储的最大字节数。
+    pub const CAPACITY: usize = MAX_INLINE_STR_LEN;
+}
+
 impl AsRef<str> for InlineStr {
     fn as_ref(&self) -> &str {
         self.deref()
@@ -87,7 +100,7 @@ impl fmt::Display for InlineStr {
 /// 一个写时复制字符串，可以是拥有的、借用的
 /// 或内联的。
 ///
-/// 它长度为三个字。
+/// 它长度为三个字，启用 `large-inline-str` 时为五个字。
 #[derive(Debug, Eq)]
 pub enum CowStr<'a> {
     /// 一个拥有的、不可变的字符串。
@@ -202,6 +215,18 @@ impl<'a> From<String> for CowStr<'a> {
     }
 }
 
+impl<'a> From<&'a String> for CowStr<'a> {
+    fn from(s: &'a String) -> Self {
+        CowStr::Borrowed(s)
+    }
+}
+
+impl<'a> From<Box<str>> for CowStr<'a> {
+    fn from(s: Box<str>) -> Self {
+        CowStr::Boxed(s)
+    }
+}
+
 impl<'a> From<char> for CowStr<'a> {
     fn from(c: char) -> Self {
         CowStr::Inlined(c.into())
@@ -220,7 +245,7 @@ impl<'a> From<Cow<'a, str>> for CowStr<'a> {
 impl<'a> From<CowStr<'a>> for Cow<'a, str> {
     fn from(s: CowStr<'a>) -> Self {
         match s {
-            CowStr::Boxed(s) => Cow::Owned(s.to_string()),
+            CowStr::Boxed(s) => Cow::Owned(s.into()),
             CowStr::Inlined(s) => Cow::Owned(s.to_string()),
             CowStr::Borrowed(s) => Cow::Borrowed(s),
         }
@@ -262,6 +287,7 @@ impl<'a> Borrow<str> for CowStr<'a> {
 }
 
 impl<'a> CowStr<'a> {
+    /// 转换为`String`。`Boxed`变体会复用其分配，不会复制。
     pub fn into_string(self) -> String {
         match self {
             CowStr::Boxed(b) => b.into(),
@@ -270,6 +296,11 @@ impl<'a> CowStr<'a> {
         }
     }
 
+    /// 是否为借用的切片，即没有复制任何文本。
+    pub fn is_borrowed(&self) -> bool {
+        matches!(self, CowStr::Borrowed(_))
+    }
+
     pub fn into_static(self) -> CowStr<'static> {
         match self {
             CowStr::Boxed(b) => CowStr::Boxed(b),
@@ -313,7 +344,7 @@ mod test_special_string {
     fn cowstr_size() {
         let size = core::mem::size_of::<CowStr>();
         let word_size = core::mem::size_of::<isize>();
-        assert_eq!(3 * word_size, size);
+        assert_eq!(INLINE_STR_WORDS * word_size, size);
     }
 
     #[test]
@@ -325,6 +356,27 @@ mod test_special_string {
         assert_eq!(expected, owned);
     }
 
+    #[test]
+    fn cowstr_boxed_conversions_reuse_allocation() {
+        let boxed: Box<str> = "a string that is too long to be inlined".into();
+        let ptr = boxed.as_ptr();
+        let cow: Cow<str> = CowStr::from(boxed).into();
+        assert!(matches!(cow, Cow::Owned(_)));
+        assert_eq!(ptr, cow.as_ptr());
+        let string = CowStr::from(cow).into_string();
+        assert_eq!(ptr, string.as_ptr());
+    }
+
+    #[test]
+    fn cowstr_from_std_cow() {
+        let owned = "owned".to_owned();
+        let borrowed: CowStr = Cow::Borrowed(owned.as_str()).into();
+        assert!(matches!(borrowed, CowStr::Borrowed(_)));
+        assert!(matches!(CowStr::from(&owned), CowStr::Borrowed(_)));
+        let back: Cow<str> = borrowed.into();
+        assert!(matches!(back, Cow::Borrowed("owned")));
+    }
+
     #[test]
     fn max_inline_str_len_atleast_four() {
         // 我们需要4个字节来存储一个字符
@@ -332,7 +384,7 @@ mod test_special_string {
     }
 
     #[test]
-    #[cfg(target_pointer_width = "64")]
+    #[cfg(all(target_pointer_width = "64", not(feature = "large-inline-str")))]
     fn inlinestr_fits_twentytwo() {
         let s = "0123456789abcdefghijkl";
         let stack_str = InlineStr::try_from(s).unwrap();
@@ -340,12 +392,22 @@ mod test_special_string {
     }
 
     #[test]
-    #[cfg(target_pointer_width = "64")]
+    #[cfg(all(target_pointer_width = "64", not(feature = "large-inline-str")))]
     fn inlinestr_not_fits_twentythree() {
         let s = "0123456789abcdefghijklm";
         let _stack_str = InlineStr::try_from(s).unwrap_err();
     }
 
+    #[test]
+    #[cfg(all(target_pointer_width = "64", feature = "large-inline-str"))]
+    fn inlinestr_fits_twelve_cjk_chars() {
+        let s = "一二三四五六七八九十百千";
+        assert_eq!(InlineStr::CAPACITY, 38);
+        let stack_str = InlineStr::try_from(s).unwrap();
+        assert_eq!(stack_str.deref(), s);
+        let _stack_str = InlineStr::try_from("一二三四五六七八九十百千万").unwrap_err();
+    }
+
     #[test]
     #[cfg(target_pointer_width = "64")]
     fn small_boxed_str_clones_to_stack() {
@@ -0,0 +1,190 @@
+//! 规范一致性测试工具。
+//!
+//! 本模块公开了本仓库自身运行 CommonMark/GFM 规范测试所用的机制：
+//! 从规范文本中解析示例、规范化 HTML 以及运行测试并汇总结果。
+//! 基于本分支构建的方言可以用它针对自己的配置运行一致性测试套件。
+//!
+//! 规范文本的格式与 `specs/` 目录下的文件相同：每个示例以
+//! 32 个反引号加 `example` 开头（可带 `_smartpunct` 等后缀），
+//! Markdown 与期望的 HTML 之间以单独一行的 `.` 分隔，
+//! 最后以 32 个反引号结束。示例中的 `→` 表示制表符。
+//!
+//! ~~~
+//! use pulldown_cmark::{testing, Options};
+//!
+//! let spec = "\
+//! ```````````````````````````````` example
+//! *hi*
+//! .
+//! <p><em>hi</em></p>
+//! ````````````````````````````````
+//! ";
+//! let cases = testing::parse_spec(spec);
+//! assert_eq!(cases.len(), 1);
+//!
+//! let report = testing::run(&cases, Options::empty());
+//! assert!(report.is_success());
+//! ~~~
+
+use alloc::{string::String, vec::Vec};
+
+use crate::{html, Options, Parser};
+
+mod spec;
+
+pub use spec::{parse_spec, TestCase};
+
+impl TestCase {
+    /// 在 `base` 的基础上加入该示例的后缀所要求的选项。
+    ///
+    /// 已知的后缀与本仓库的测试套件一致：`smartpunct`、`metadata_blocks`、
+    /// `old_footnotes`、`super_sub`、`wikilinks`、`deflists` 和
+    /// `container_extensions`。未知的后缀不改变选项，交由调用方处理。
+    pub fn options(&self, base: Options) -> Options {
+        let mut opts = base;
+        match self.variant.as_str() {
+            "smartpunct" => opts.insert(Options::ENABLE_SMART_PUNCTUATION),
+            "metadata_blocks" => {
+                opts.insert(Options::ENABLE_YAML_STYLE_METADATA_BLOCKS);
+                opts.insert(Options::ENABLE_PLUSES_DELIMITED_METADATA_BLOCKS);
+            }
+            "old_footnotes" => {
+                opts.remove(Options::ENABLE_FOOTNOTES);
+                opts.insert(Options::ENABLE_OLD_FOOTNOTES);
+            }
+            "super_sub" => {
+                opts.insert(Options::ENABLE_SUPERSCRIPT);
+                opts.insert(Options::ENABLE_SUBSCRIPT);
+            }
+            "wikilinks" => opts.insert(Options::ENABLE_WIKILINKS),
+            "deflists" => opts.insert(Options::ENABLE_DEFINITION_LIST),
+            "container_extensions" => opts.insert(Options::ENABLE_CONTAINER_EXTENSIONS),
+            _ => {}
+        }
+        opts
+    }
+}
+
+/// 规范化 HTML，使等价的写法可以直接比较。
+///
+/// 统一 `<br>` 与 `<hr>` 的自闭合形式，并忽略标签之间多出或缺少的换行。
+pub fn normalize_html(html: &str) -> String {
+    html.replace("<br>", "<br />")
+        .replace("<br/>", "<br />")
+        .replace("<hr>", "<hr />")
+        .replace("<hr/>", "<hr />")
+        .replace(">\n<", "><")
+}
+
+/// 一个未通过的示例。
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Failure {
+    /// 未通过的示例。
+    pub case: TestCase,
+    /// 实际渲染出的 HTML。
+    pub actual: String,
+}
+
+/// 一次测试运行的结果。
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SpecReport {
+    /// 通过的示例数量。
+    pub passed: usize,
+    /// 未通过的示例。
+    pub failures: Vec<Failure>,
+}
+
+impl SpecReport {
+    /// 运行的示例总数。
+    pub fn total(&self) -> usize {
+        self.passed + self.failures.len()
+    }
+
+    /// 是否全部通过。
+    pub fn is_success(&self) -> bool {
+        self.failures.is_empty()
+    }
+}
+
+/// 用默认的 HTML 渲染器运行示例。
+///
+/// 每个示例的选项为 `base` 加上 [`TestCase::options`] 中后缀对应的选项。
+pub fn run(cases: &[TestCase], base: Options) -> SpecReport {
+    run_with(cases, |case| {
+        render_html(&case.markdown, case.options(base))
+    })
+}
+
+/// 用自定义的渲染函数运行示例。
+///
+/// `render` 返回示例的实际输出，与期望的 HTML 在 [`normalize_html`] 之后比较。
+pub fn run_with<F>(cases: &[TestCase], mut render: F) -> SpecReport
+where
+    F: FnMut(&TestCase) -> String,
+{
+    let mut report = SpecReport::default();
+    for case in cases {
+        let actual = render(case);
+        if normalize_html(&actual) == normalize_html(&case.html) {
+            report.passed += 1;
+        } else {
+            report.failures.push(Failure {
+                case: case.clone(),
+                actual,
+            });
+        }
+    }
+    report
+}
+
+/// 用给定选项把 Markdown 渲染为 HTML。
+pub fn render_html(markdown: &str, options: Options) -> String {
+    let mut out = String::new();
+    html::push_html(&mut out, Parser::new_ext(markdown, options));
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_spec_variants() {
+        let spec = "\
+# Title
+
+```````````````````````````````` example
+a→b
+.
+<p>a\tb</p>
+````````````````````````````````
+
+Some prose.
+
+```````````````````````````````` example_smartpunct
+\"hi\"
+.
+<p>“hi”</p>
+````````````````````````````````
+";
+        let cases = parse_spec(spec);
+        assert_eq!(cases.len(), 2);
+        assert_eq!(cases[0].number, 1);
+        assert_eq!(cases[0].line, 3);
+        assert_eq!(cases[0].markdown, "a\tb\n");
+        assert_eq!(cases[0].html, "<p>a\tb</p>\n");
+        assert_eq!(cases[0].variant, "");
+        assert_eq!(cases[1].variant, "smartpunct");
+        assert_eq!(cases[1].line, 11);
+        assert!(cases[1]
+            .options(Options::empty())
+            .contains(Options::ENABLE_SMART_PUNCTUATION));
+
+        let report = run(&cases, Options::empty());
+        assert!(report.is_success(), "{:?}", report.failures);
+
+        let report = run_with(&cases, |_| "<p>x</p>".into());
+        assert_eq!(report.total(), 2);
+        assert_eq!(report.failures.len(), 2);
+    }
+}
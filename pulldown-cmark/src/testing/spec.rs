@@ -0,0 +1,63 @@
+//! 规范文本的解析，构建脚本生成测试套件时也使用这里的代码。
+
+use alloc::{string::String, vec::Vec};
+
+const FENCE: &str = "````````````````````````````````";
+
+/// 规范中的一个示例。
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TestCase {
+    /// 示例在规范中的序号，从 1 开始。
+    pub number: usize,
+    /// 示例开头的反引号所在的行号，从 1 开始。
+    pub line: usize,
+    /// 输入的 Markdown 文本。
+    pub markdown: String,
+    /// 期望输出的 HTML。
+    pub html: String,
+    /// `example` 之后的后缀（不含前导下划线），例如 `smartpunct`。
+    /// 没有后缀时为空字符串。
+    pub variant: String,
+}
+
+/// 从规范文本中解析出全部示例。
+///
+/// 格式不完整的尾部示例会被忽略。
+pub fn parse_spec(spec: &str) -> Vec<TestCase> {
+    let prefix = "```````````````````````````````` example";
+    let mut cases = Vec::new();
+    let mut rest = spec;
+
+    while let Some(pos) = rest.find(prefix) {
+        let after = &rest[pos + prefix.len()..];
+        let Some(header_end) = after.find('\n') else {
+            break;
+        };
+        let header = &after[..header_end];
+        let start = pos + prefix.len() + header_end + 1;
+        if !header.is_empty() && !header.starts_with('_') {
+            // 不是示例的起始行，例如 "example" 后紧跟其他文字。
+            rest = &rest[start..];
+            continue;
+        }
+        let Some(md_len) = rest[start..].find("\n.\n") else {
+            break;
+        };
+        let md_end = start + md_len + 1;
+        let Some(html_len) = rest[md_end + 2..].find(FENCE) else {
+            break;
+        };
+        let html_end = md_end + 2 + html_len;
+
+        let line = spec[..spec.len() - rest.len() + pos].matches('\n').count() + 1;
+        cases.push(TestCase {
+            number: cases.len() + 1,
+            line,
+            markdown: rest[start..md_end].replace('→', "\t"),
+            html: rest[md_end + 2..html_end].replace('→', "\t"),
+            variant: header.trim_start_matches('_').into(),
+        });
+        rest = &rest[(html_end + FENCE.len() + 1).min(rest.len())..];
+    }
+    cases
+}
@@ -0,0 +1,1220 @@
+//! 事件流转换工具
+//!
+//! 提供可组合的 [`Event`] 迭代器适配器：映射标签、过滤子树、替换文本，
+//! 以及在丢弃部分事件后自动重新平衡开始/结束标签，保证输出可以直接交给 HTML 写入器。
+//! [`Recorder`] 记录经过的事件，之后可以重放而无需再次解析。
+//! [`WithContainerPath`] 为每个事件附加所在的容器路径，渲染器无需自行维护标签栈。
+//!
+//! ```rust
+//! use pulldown_cmark::{transform::EventStreamExt, Event, Parser, Tag, TagEnd};
+//!
+//! let events: Vec<_> = Parser::new("Hello *world*\n\n```\ncode\n```")
+//!     .skip_subtrees(|tag| matches!(tag, Tag::CodeBlock(_)))
+//!     .map_text(|text| text.to_uppercase().into())
+//!     .collect();
+//!
+//! assert_eq!(
+//!     events,
+//!     vec![
+//!         Event::Start(Tag::Paragraph(None)),
+//!         Event::Text("HELLO ".into()),
+//!         Event::Start(Tag::Emphasis),
+//!         Event::Text("WORLD".into()),
+//!         Event::End(TagEnd::Emphasis),
+//!         Event::End(TagEnd::Paragraph),
+//!     ]
+//! );
+//! ```
+
+use alloc::{
+    collections::VecDeque,
+    string::{String, ToString},
+    vec::{self, Vec},
+};
+use core::ops::Range;
+#[cfg(all(feature = "std", not(feature = "hashbrown")))]
+use std::collections::HashMap;
+
+#[cfg(feature = "hashbrown")]
+use hashbrown::HashMap;
+use unicase::UniCase;
+#[cfg(feature = "unicode-normalization")]
+use unicode_normalization::{is_nfc, is_nfkc, UnicodeNormalization};
+
+use crate::{utils::resolve_url, CowStr, Event, HeadingLevel, Tag, TagEnd};
+
+/// 映射开始标签，并自动改写对应的结束标签。
+///
+/// 由 [`EventStreamExt::map_tag`] 创建。
+#[derive(Debug)]
+pub struct MapTag<I, F> {
+    iter: I,
+    f: F,
+    stack: Vec<TagEnd>,
+}
+
+impl<'a, I, F> Iterator for MapTag<I, F>
+where
+    I: Iterator<Item = Event<'a>>,
+    F: FnMut(Tag<'a>) -> Tag<'a>,
+{
+    type Item = Event<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.iter.next()? {
+            Event::Start(tag) => {
+                let tag = (self.f)(tag);
+                self.stack.push(tag.to_end());
+                Some(Event::Start(tag))
+            }
+            // 结束标签总是与映射后的开始标签保持一致
+            Event::End(end) => Some(Event::End(self.stack.pop().unwrap_or(end))),
+            event => Some(event),
+        }
+    }
+}
+
+/// 丢弃满足条件的整个子树（包括其开始和结束标签）。
+///
+/// 由 [`EventStreamExt::skip_subtrees`] 创建。
+#[derive(Debug)]
+pub struct SkipSubtrees<I, P> {
+    iter: I,
+    predicate: P,
+}
+
+impl<'a, I, P> Iterator for SkipSubtrees<I, P>
+where
+    I: Iterator<Item = Event<'a>>,
+    P: FnMut(&Tag<'a>) -> bool,
+{
+    type Item = Event<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.iter.next()? {
+                Event::Start(tag) if (self.predicate)(&tag) => {
+                    let mut depth = 1usize;
+                    while depth > 0 {
+                        match self.iter.next()? {
+                            Event::Start(_) => depth += 1,
+                            Event::End(_) => depth -= 1,
+                            _ => {}
+                        }
+                    }
+                }
+                event => return Some(event),
+            }
+        }
+    }
+}
+
+/// 使用回调替换 `Event::Text` 的内容。
+///
+/// 由 [`EventStreamExt::map_text`] 创建。
+#[derive(Debug)]
+pub struct MapText<I, F> {
+    iter: I,
+    f: F,
+}
+
+impl<'a, I, F> Iterator for MapText<I, F>
+where
+    I: Iterator<Item = Event<'a>>,
+    F: FnMut(CowStr<'a>) -> CowStr<'a>,
+{
+    type Item = Event<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.iter.next()? {
+            Event::Text(text) => Some(Event::Text((self.f)(text))),
+            event => Some(event),
+        }
+    }
+}
+
+/// 重新平衡开始/结束标签。
+///
+/// 没有对应开始标签的结束标签会被丢弃；当结束标签关闭了外层标签时，
+/// 先补齐内层尚未关闭的结束标签；流结束时关闭所有仍然打开的标签。
+///
+/// 由 [`EventStreamExt::rebalance`] 创建。
+#[derive(Debug)]
+pub struct Rebalance<'a, I> {
+    iter: I,
+    stack: Vec<TagEnd>,
+    /// 等待关闭的标签数量，以及最终触发关闭的事件。
+    pending: Option<(usize, Option<Event<'a>>)>,
+}
+
+impl<'a, I> Iterator for Rebalance<'a, I>
+where
+    I: Iterator<Item = Event<'a>>,
+{
+    type Item = Event<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some((count, last)) = self.pending.take() {
+                if count > 0 {
+                    self.pending = Some((count - 1, last));
+                    return self.stack.pop().map(Event::End);
+                }
+                if let Some(event) = last {
+                    return Some(event);
+                }
+            }
+            match self.iter.next() {
+                Some(Event::Start(tag)) => {
+                    self.stack.push(tag.to_end());
+                    return Some(Event::Start(tag));
+                }
+                Some(Event::End(end)) => {
+                    if let Some(pos) = self.stack.iter().rposition(|open| *open == end) {
+                        let unclosed = self.stack.len() - pos - 1;
+                        if unclosed == 0 {
+                            self.stack.pop();
+                            return Some(Event::End(end));
+                        }
+                        // 先移除被关闭的标签，内层标签随后依次弹出
+                        self.stack.remove(pos);
+                        self.pending = Some((unclosed, Some(Event::End(end))));
+                    }
+                    // 未匹配的结束标签直接丢弃
+                }
+                Some(event) => return Some(event),
+                None => return self.stack.pop().map(Event::End),
+            }
+        }
+    }
+}
+
+/// 规范化事件流。
+///
+/// - 删除空的 `Event::Text`；
+/// - 合并相邻且相同的行内标签（例如 `<em>a</em><em>b</em>` 合并为 `<em>ab</em>`）；
+/// - 去掉仅包裹单个图片的段落。
+///
+/// 规范化后的事件流更便于比较和渲染对比。由 [`EventStreamExt::normalize`] 创建。
+#[derive(Debug)]
+pub struct Normalize<'a, I> {
+    inner: MergeInlineTags<'a, UnwrapImageParagraphs<'a, SkipEmptyText<I>>>,
+}
+
+impl<'a, I> Iterator for Normalize<'a, I>
+where
+    I: Iterator<Item = Event<'a>>,
+{
+    type Item = Event<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next()
+    }
+}
+
+#[derive(Debug)]
+struct SkipEmptyText<I>(I);
+
+impl<'a, I> Iterator for SkipEmptyText<I>
+where
+    I: Iterator<Item = Event<'a>>,
+{
+    type Item = Event<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0
+            .by_ref()
+            .find(|event| !matches!(event, Event::Text(text) if text.is_empty()))
+    }
+}
+
+#[derive(Debug)]
+struct UnwrapImageParagraphs<'a, I> {
+    iter: I,
+    queue: VecDeque<Event<'a>>,
+}
+
+impl<'a, I> Iterator for UnwrapImageParagraphs<'a, I>
+where
+    I: Iterator<Item = Event<'a>>,
+{
+    type Item = Event<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(event) = self.queue.pop_front() {
+            return Some(event);
+        }
+        let event = self.iter.next()?;
+        // 带块标识符的段落不展开，以免丢失标识符
+        if event != Event::Start(Tag::Paragraph(None)) {
+            return Some(event);
+        }
+        match self.iter.next() {
+            Some(Event::Start(image @ Tag::Image { .. })) => {
+                // 缓冲整个图片，直到确认它是段落中唯一的内容
+                self.queue.push_back(Event::Start(image));
+                let mut depth = 1usize;
+                while depth > 0 {
+                    let Some(event) = self.iter.next() else {
+                        break;
+                    };
+                    match event {
+                        Event::Start(_) => depth += 1,
+                        Event::End(_) => depth -= 1,
+                        _ => {}
+                    }
+                    self.queue.push_back(event);
+                }
+                match self.iter.next() {
+                    Some(Event::End(TagEnd::Paragraph)) => {}
+                    next => {
+                        self.queue.push_front(Event::Start(Tag::Paragraph(None)));
+                        self.queue.extend(next);
+                    }
+                }
+                self.queue.pop_front()
+            }
+            next => {
+                self.queue.extend(next);
+                Some(event)
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+struct MergeInlineTags<'a, I> {
+    iter: I,
+    lookahead: Option<Event<'a>>,
+}
+
+impl<'a, I> Iterator for MergeInlineTags<'a, I>
+where
+    I: Iterator<Item = Event<'a>>,
+{
+    type Item = Event<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let event = self.lookahead.take().or_else(|| self.iter.next())?;
+            let Event::End(end) = event else {
+                return Some(event);
+            };
+            if !matches!(
+                end,
+                TagEnd::Emphasis
+                    | TagEnd::Strong
+                    | TagEnd::Strikethrough
+                    | TagEnd::Superscript
+                    | TagEnd::Subscript
+            ) {
+                return Some(event);
+            }
+            match self.iter.next() {
+                // 结束标签后紧跟相同的开始标签，两者都丢弃
+                Some(Event::Start(tag)) if tag.to_end() == end => {}
+                next => {
+                    self.lookahead = next;
+                    return Some(event);
+                }
+            }
+        }
+    }
+}
+
+/// 标题编号配置，见 [`EventStreamExt::number_headings`]。
+#[derive(Clone, Debug)]
+pub struct HeadingNumbering {
+    /// 开始编号的标题级别。更高级别的标题不编号，但会重新开始编号。
+    pub start_level: HeadingLevel,
+    /// 编号各部分之间的分隔符；只有一部分的编号以它结尾，例如 `1.`、`1.2`。
+    pub separator: String,
+}
+
+impl Default for HeadingNumbering {
+    fn default() -> Self {
+        Self {
+            start_level: HeadingLevel::H1,
+            separator: ".".into(),
+        }
+    }
+}
+
+/// 在标题文本前插入层级编号。
+///
+/// 由 [`EventStreamExt::number_headings`] 创建。
+#[derive(Debug)]
+pub struct NumberHeadings<'a, I> {
+    iter: I,
+    config: HeadingNumbering,
+    counters: [usize; 6],
+    pending: Option<Event<'a>>,
+}
+
+impl<'a, I> Iterator for NumberHeadings<'a, I>
+where
+    I: Iterator<Item = Event<'a>>,
+{
+    type Item = Event<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(event) = self.pending.take() {
+            return Some(event);
+        }
+        let event = self.iter.next()?;
+        if let Event::Start(Tag::Heading { level, .. }) = &event {
+            let start = self.config.start_level as usize;
+            let level = *level as usize;
+            if level < start {
+                self.counters.fill(0);
+            } else {
+                let depth = level - start;
+                self.counters[depth] += 1;
+                self.counters[depth + 1..].fill(0);
+                let mut number = String::new();
+                for (i, counter) in self.counters[..=depth].iter().enumerate() {
+                    if i > 0 {
+                        number.push_str(&self.config.separator);
+                    }
+                    number.push_str(&counter.to_string());
+                }
+                if depth == 0 {
+                    number.push_str(&self.config.separator);
+                }
+                number.push(' ');
+                self.pending = Some(Event::Text(number.into()));
+            }
+        }
+        Some(event)
+    }
+}
+
+/// 按首次引用的顺序将脚注重新编号为 `1`、`2`……，并可选地将脚注定义移到文档末尾。
+///
+/// 从未被引用的定义排在被引用的定义之后。标签匹配忽略大小写，与解析器一致。
+/// 该适配器需要先缓冲整个事件流。
+///
+/// 由 [`EventStreamExt::renumber_footnotes`] 创建。
+#[derive(Debug)]
+pub struct RenumberFootnotes<'a, I> {
+    iter: Option<I>,
+    events: vec::IntoIter<Event<'a>>,
+    move_definitions: bool,
+}
+
+impl<'a, I> RenumberFootnotes<'a, I>
+where
+    I: Iterator<Item = Event<'a>>,
+{
+    fn renumber(&self, iter: I) -> Vec<Event<'a>> {
+        let events: Vec<_> = iter.collect();
+        let mut numbers: HashMap<UniCase<CowStr<'a>>, usize> = HashMap::new();
+        for event in &events {
+            if let Event::FootnoteReference(label) = event {
+                let next = numbers.len() + 1;
+                numbers.entry(UniCase::new(label.clone())).or_insert(next);
+            }
+        }
+        for event in &events {
+            if let Event::Start(Tag::FootnoteDefinition(label)) = event {
+                let next = numbers.len() + 1;
+                numbers.entry(UniCase::new(label.clone())).or_insert(next);
+            }
+        }
+        let number =
+            |label: CowStr<'a>| -> CowStr<'a> { numbers[&UniCase::new(label)].to_string().into() };
+
+        let mut output = Vec::with_capacity(events.len());
+        let mut definitions: Vec<(usize, Vec<Event<'a>>)> = Vec::new();
+        let mut depth = 0usize;
+        for event in events {
+            let event = match event {
+                Event::FootnoteReference(label) => Event::FootnoteReference(number(label)),
+                Event::Start(Tag::FootnoteDefinition(label)) => {
+                    Event::Start(Tag::FootnoteDefinition(number(label)))
+                }
+                event => event,
+            };
+            if !self.move_definitions {
+                output.push(event);
+                continue;
+            }
+            if depth == 0 {
+                if let Event::Start(Tag::FootnoteDefinition(label)) = &event {
+                    definitions.push((label.parse().unwrap_or(0), Vec::new()));
+                }
+            }
+            match &event {
+                Event::Start(Tag::FootnoteDefinition(_)) => depth += 1,
+                Event::End(TagEnd::FootnoteDefinition) => depth -= 1,
+                _ => {}
+            }
+            match definitions.last_mut() {
+                Some((_, definition))
+                    if depth > 0 || event == Event::End(TagEnd::FootnoteDefinition) =>
+                {
+                    definition.push(event)
+                }
+                _ => output.push(event),
+            }
+        }
+        definitions.sort_by_key(|(number, _)| *number);
+        output.extend(definitions.into_iter().flat_map(|(_, events)| events));
+        output
+    }
+}
+
+impl<'a, I> Iterator for RenumberFootnotes<'a, I>
+where
+    I: Iterator<Item = Event<'a>>,
+{
+    type Item = Event<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(iter) = self.iter.take() {
+            self.events = self.renumber(iter).into_iter();
+        }
+        self.events.next()
+    }
+}
+
+/// 将相对的链接和图片地址解析为相对于基础 URL 或路径的地址，参见 [`resolve_url`]。
+///
+/// 由 [`EventStreamExt::resolve_relative_to`] 创建。
+#[derive(Debug)]
+pub struct ResolveRelativeTo<I> {
+    iter: I,
+    base: String,
+}
+
+impl<'a, I> Iterator for ResolveRelativeTo<I>
+where
+    I: Iterator<Item = Event<'a>>,
+{
+    type Item = Event<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut event = self.iter.next()?;
+        if let Event::Start(
+            Tag::Link {
+                link_type,
+                dest_url,
+                ..
+            }
+            | Tag::Image {
+                link_type,
+                dest_url,
+                ..
+            },
+        ) = &mut event
+        {
+            // 邮件自动链接的地址不是 URL
+            if !link_type.is_email() {
+                // 只有地址改变时 `resolve_url` 才会分配新的字符串
+                let resolved = match resolve_url(&self.base, dest_url) {
+                    CowStr::Borrowed(_) => None,
+                    resolved => Some(resolved.into_string()),
+                };
+                if let Some(resolved) = resolved {
+                    *dest_url = resolved.into();
+                }
+            }
+        }
+        Some(event)
+    }
+}
+
+/// Unicode 规范化形式，见 [`UnicodeNormalizationConfig`]。
+#[cfg(feature = "unicode-normalization")]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum NormalizationForm {
+    /// 标准组合形式，不改变文本的含义。
+    #[default]
+    Nfc,
+    /// 兼容组合形式，还会统一全角字符、连字等兼容字符。
+    Nfkc,
+}
+
+/// Unicode 规范化配置，见 [`EventStreamExt::normalize_unicode`]。
+#[cfg(feature = "unicode-normalization")]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct UnicodeNormalizationConfig {
+    /// 规范化形式。
+    pub form: NormalizationForm,
+    /// 是否删除变体选择符和零宽字符（零宽空格、零宽连接符、零宽非连接符、
+    /// 词连接符和 BOM）。注意这会拆开由零宽连接符组成的 emoji 序列。
+    pub strip_invisible: bool,
+}
+
+#[cfg(feature = "unicode-normalization")]
+fn is_invisible(c: char) -> bool {
+    matches!(
+        c,
+        '\u{200B}'..='\u{200D}'
+            | '\u{2060}'
+            | '\u{FEFF}'
+            | '\u{FE00}'..='\u{FE0F}'
+            | '\u{E0100}'..='\u{E01EF}'
+    )
+}
+
+/// 将文本规范化为 NFC 或 NFKC，并可选地删除不可见字符。
+///
+/// 代码块和元数据块中的文本保持不变；已经规范化的文本不会重新分配。
+///
+/// 由 [`EventStreamExt::normalize_unicode`] 创建。
+#[cfg(feature = "unicode-normalization")]
+#[derive(Debug)]
+pub struct NormalizeUnicode<I> {
+    iter: I,
+    config: UnicodeNormalizationConfig,
+    verbatim: usize,
+}
+
+#[cfg(feature = "unicode-normalization")]
+impl<I> NormalizeUnicode<I> {
+    fn normalize_text<'a>(&self, text: CowStr<'a>) -> CowStr<'a> {
+        let strip = self.config.strip_invisible && text.chars().any(is_invisible);
+        let normalized = match self.config.form {
+            NormalizationForm::Nfc => is_nfc(&text),
+            NormalizationForm::Nfkc => is_nfkc(&text),
+        };
+        if !strip && normalized {
+            return text;
+        }
+        let chars = text
+            .chars()
+            .filter(|&c| !(self.config.strip_invisible && is_invisible(c)));
+        let text: String = match self.config.form {
+            NormalizationForm::Nfc => chars.nfc().collect(),
+            NormalizationForm::Nfkc => chars.nfkc().collect(),
+        };
+        text.into()
+    }
+}
+
+#[cfg(feature = "unicode-normalization")]
+impl<'a, I> Iterator for NormalizeUnicode<I>
+where
+    I: Iterator<Item = Event<'a>>,
+{
+    type Item = Event<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.iter.next()? {
+            Event::Text(text) if self.verbatim == 0 => Some(Event::Text(self.normalize_text(text))),
+            event => {
+                match &event {
+                    Event::Start(Tag::CodeBlock(_) | Tag::MetadataBlock(_)) => self.verbatim += 1,
+                    Event::End(TagEnd::CodeBlock | TagEnd::MetadataBlock(_)) => self.verbatim -= 1,
+                    _ => {}
+                }
+                Some(event)
+            }
+        }
+    }
+}
+
+/// 记录下来的事件及其在源文本中的范围，见 [`Recorder`]。
+///
+/// 启用 `serde` 特性时可以序列化，之后用 [`EventLog::replay`] 重新渲染，无需再次解析。
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EventLog<'a> {
+    /// 按顺序排列的事件和范围。
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub events: Vec<(Event<'a>, Range<usize>)>,
+}
+
+impl<'a> EventLog<'a> {
+    /// 按记录的顺序重放事件。
+    pub fn replay(&self) -> Replay<'_, 'a> {
+        Replay {
+            events: self.events.iter(),
+        }
+    }
+
+    /// 转换为不借用源文本的记录。
+    pub fn into_static(self) -> EventLog<'static> {
+        EventLog {
+            events: self
+                .events
+                .into_iter()
+                .map(|(event, range)| (event.into_static(), range))
+                .collect(),
+        }
+    }
+}
+
+/// 重放 [`EventLog`] 中的事件。
+///
+/// 由 [`EventLog::replay`] 创建。
+#[derive(Clone, Debug)]
+pub struct Replay<'l, 'a> {
+    events: core::slice::Iter<'l, (Event<'a>, Range<usize>)>,
+}
+
+impl<'l, 'a> Replay<'l, 'a> {
+    /// 同时重放事件的范围，与 [`Parser::into_offset_iter`](crate::Parser::into_offset_iter) 相同。
+    pub fn into_offset_iter(self) -> impl Iterator<Item = (Event<'a>, Range<usize>)> + 'l {
+        self.events.cloned()
+    }
+}
+
+impl<'l, 'a> Iterator for Replay<'l, 'a> {
+    type Item = Event<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.events.next().map(|(event, _)| event.clone())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.events.size_hint()
+    }
+}
+
+/// 原样传递带范围的事件，同时把它们记录到 [`EventLog`] 中。
+///
+/// 渲染一次之后，可以用记录的事件渲染为其他格式而无需再次解析。
+///
+/// ```
+/// use pulldown_cmark::{html, transform::Recorder, Parser};
+///
+/// let mut recorder = Recorder::new(Parser::new("*Hello*").into_offset_iter());
+/// let mut first = String::new();
+/// html::push_html(&mut first, recorder.by_ref().map(|(event, _)| event));
+///
+/// let log = recorder.into_log();
+/// let mut second = String::new();
+/// html::push_html(&mut second, log.replay());
+/// assert_eq!(first, second);
+/// assert_eq!(log.events[1].1, 0..7);
+/// ```
+#[derive(Debug)]
+pub struct Recorder<'a, I> {
+    iter: I,
+    log: EventLog<'a>,
+}
+
+impl<'a, I> Recorder<'a, I> {
+    /// 包装带范围的事件迭代器，例如 [`Parser::into_offset_iter`](crate::Parser::into_offset_iter) 的结果。
+    pub fn new(iter: I) -> Self {
+        Recorder {
+            iter,
+            log: EventLog::default(),
+        }
+    }
+
+    /// 到目前为止记录的事件。
+    pub fn log(&self) -> &EventLog<'a> {
+        &self.log
+    }
+
+    /// 停止记录并返回记录的事件，未取出的事件不会被记录。
+    pub fn into_log(self) -> EventLog<'a> {
+        self.log
+    }
+}
+
+impl<'a, I> Iterator for Recorder<'a, I>
+where
+    I: Iterator<Item = (Event<'a>, Range<usize>)>,
+{
+    type Item = (Event<'a>, Range<usize>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (event, range) = self.iter.next()?;
+        self.log.events.push((event.clone(), range.clone()));
+        Some((event, range))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+/// 事件所在的容器路径，由 [`WithContainerPath`] 产生。
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct EventContext {
+    /// 从外到内包含该事件的容器块，例如 `[BlockQuote(None), List(false), Item]`。
+    ///
+    /// 容器自身的开始和结束事件不包含该容器。
+    pub path: Vec<TagEnd>,
+}
+
+impl EventContext {
+    /// 容器的嵌套深度。
+    pub fn depth(&self) -> usize {
+        self.path.len()
+    }
+
+    /// 引用块的嵌套深度。
+    pub fn quote_depth(&self) -> usize {
+        self.path
+            .iter()
+            .filter(|end| matches!(end, TagEnd::BlockQuote(_)))
+            .count()
+    }
+
+    /// 是否位于列表项、表格或脚注定义等指定容器中。
+    pub fn is_inside(&self, container: TagEnd) -> bool {
+        self.path.contains(&container)
+    }
+}
+
+/// 为每个事件附加其所在的容器路径。
+///
+/// 容器块包括引用块、容器块、列表、列表项、脚注定义、定义列表及其定义，以及表格的各个部分；
+/// 段落、标题和行内标签不计入路径。
+///
+/// 由 [`EventStreamExt::with_container_path`] 创建。
+///
+/// ```
+/// use pulldown_cmark::{transform::EventStreamExt, Event, Parser, TagEnd};
+///
+/// let context = Parser::new("> - item")
+///     .with_container_path()
+///     .find_map(|(event, context)| matches!(event, Event::Text(_)).then_some(context))
+///     .unwrap();
+/// assert_eq!(context.path, [TagEnd::BlockQuote(None), TagEnd::List(false), TagEnd::Item]);
+/// assert_eq!(context.quote_depth(), 1);
+/// ```
+#[derive(Debug)]
+pub struct WithContainerPath<I> {
+    iter: I,
+    path: Vec<TagEnd>,
+}
+
+fn is_container(end: TagEnd) -> bool {
+    matches!(
+        end,
+        TagEnd::BlockQuote(_)
+            | TagEnd::ContainerBlock(_)
+            | TagEnd::List(_)
+            | TagEnd::Item
+            | TagEnd::FootnoteDefinition
+            | TagEnd::DefinitionList
+            | TagEnd::DefinitionListDefinition
+            | TagEnd::Table
+            | TagEnd::TableHead
+            | TagEnd::TableFoot
+            | TagEnd::TableRow
+            | TagEnd::TableCell
+    )
+}
+
+impl<'a, I> Iterator for WithContainerPath<I>
+where
+    I: Iterator<Item = Event<'a>>,
+{
+    type Item = (Event<'a>, EventContext);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let event = self.iter.next()?;
+        if let Event::End(end) = &event {
+            if is_container(*end) {
+                self.path.pop();
+            }
+        }
+        let context = EventContext {
+            path: self.path.clone(),
+        };
+        if let Event::Start(tag) = &event {
+            let end = tag.to_end();
+            if is_container(end) {
+                self.path.push(end);
+            }
+        }
+        Some((event, context))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+/// 为 [`Event`] 迭代器提供转换适配器的扩展 trait。
+pub trait EventStreamExt<'a>: Iterator<Item = Event<'a>> + Sized {
+    /// 映射每个开始标签，对应的结束标签会随之改写。
+    fn map_tag<F>(self, f: F) -> MapTag<Self, F>
+    where
+        F: FnMut(Tag<'a>) -> Tag<'a>,
+    {
+        MapTag {
+            iter: self,
+            f,
+            stack: Vec::new(),
+        }
+    }
+
+    /// 丢弃 `predicate` 返回 `true` 的标签及其全部内容。
+    fn skip_subtrees<P>(self, predicate: P) -> SkipSubtrees<Self, P>
+    where
+        P: FnMut(&Tag<'a>) -> bool,
+    {
+        SkipSubtrees {
+            iter: self,
+            predicate,
+        }
+    }
+
+    /// 替换每个 `Event::Text` 的文本。
+    fn map_text<F>(self, f: F) -> MapText<Self, F>
+    where
+        F: FnMut(CowStr<'a>) -> CowStr<'a>,
+    {
+        MapText { iter: self, f }
+    }
+
+    /// 修复不平衡的开始/结束标签，参见 [`Rebalance`]。
+    fn rebalance(self) -> Rebalance<'a, Self> {
+        Rebalance {
+            iter: self,
+            stack: Vec::new(),
+            pending: None,
+        }
+    }
+
+    /// 规范化事件流，参见 [`Normalize`]。
+    fn normalize(self) -> Normalize<'a, Self> {
+        Normalize {
+            inner: MergeInlineTags {
+                iter: UnwrapImageParagraphs {
+                    iter: SkipEmptyText(self),
+                    queue: VecDeque::new(),
+                },
+                lookahead: None,
+            },
+        }
+    }
+
+    /// 在标题前插入层级编号（`1.`、`1.2`、`1.2.3`），参见 [`HeadingNumbering`]。
+    ///
+    /// 跳过的级别编号为 0，例如 `#` 之后直接出现 `###` 时编号为 `1.0.1`。
+    fn number_headings(self, config: HeadingNumbering) -> NumberHeadings<'a, Self> {
+        NumberHeadings {
+            iter: self,
+            config,
+            counters: [0; 6],
+            pending: None,
+        }
+    }
+
+    /// 按首次引用的顺序重新编号脚注，参见 [`RenumberFootnotes`]。
+    ///
+    /// `move_definitions` 为 `true` 时，所有脚注定义按编号顺序移到事件流末尾。
+    fn renumber_footnotes(self, move_definitions: bool) -> RenumberFootnotes<'a, Self> {
+        RenumberFootnotes {
+            iter: Some(self),
+            events: Vec::new().into_iter(),
+            move_definitions,
+        }
+    }
+
+    /// 将相对的链接和图片地址解析为相对于 `base` 的地址，参见 [`ResolveRelativeTo`]。
+    fn resolve_relative_to(self, base: &str) -> ResolveRelativeTo<Self> {
+        ResolveRelativeTo {
+            iter: self,
+            base: base.into(),
+        }
+    }
+
+    /// 为每个事件附加其所在的容器路径，参见 [`WithContainerPath`]。
+    fn with_container_path(self) -> WithContainerPath<Self> {
+        WithContainerPath {
+            iter: self,
+            path: Vec::new(),
+        }
+    }
+
+    /// 将文本规范化为 NFC 或 NFKC，参见 [`NormalizeUnicode`]。
+    #[cfg(feature = "unicode-normalization")]
+    fn normalize_unicode(self, config: UnicodeNormalizationConfig) -> NormalizeUnicode<Self> {
+        NormalizeUnicode {
+            iter: self,
+            config,
+            verbatim: 0,
+        }
+    }
+}
+
+impl<'a, I> EventStreamExt<'a> for I where I: Iterator<Item = Event<'a>> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Options, Parser};
+
+    #[test]
+    fn test_map_tag_rewrites_end() {
+        let events: Vec<_> = Parser::new("*a*")
+            .map_tag(|tag| match tag {
+                Tag::Emphasis => Tag::Strong,
+                tag => tag,
+            })
+            .collect();
+        assert_eq!(events[1], Event::Start(Tag::Strong));
+        assert_eq!(events[3], Event::End(TagEnd::Strong));
+    }
+
+    #[test]
+    fn test_skip_nested_subtrees() {
+        let events: Vec<_> = Parser::new("> a\n> > b\n\nc")
+            .skip_subtrees(|tag| matches!(tag, Tag::BlockQuote { .. }))
+            .collect();
+        assert_eq!(
+            events,
+            vec![
+                Event::Start(Tag::Paragraph(None)),
+                Event::Text("c".into()),
+                Event::End(TagEnd::Paragraph),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_map_text() {
+        let events: Vec<_> = Parser::new_ext("~~foo~~ bar", Options::ENABLE_STRIKETHROUGH)
+            .map_text(|text| text.replace("o", "0").into())
+            .collect();
+        assert_eq!(events[2], Event::Text("f00".into()));
+        assert_eq!(events[4], Event::Text(" bar".into()));
+    }
+
+    #[test]
+    fn test_rebalance_after_filter() {
+        // 用户过滤掉了强调的开始标签，但保留了结束标签
+        let events: Vec<_> = Parser::new("*a* b")
+            .filter(|event| *event != Event::Start(Tag::Emphasis))
+            .rebalance()
+            .collect();
+        assert_eq!(
+            events,
+            vec![
+                Event::Start(Tag::Paragraph(None)),
+                Event::Text("a".into()),
+                Event::Text(" b".into()),
+                Event::End(TagEnd::Paragraph),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_rebalance_closes_inner_and_trailing() {
+        let events = vec![
+            Event::Start(Tag::BlockQuote {
+                kind: None,
+                folded: None,
+                title: None,
+            }),
+            Event::Start(Tag::Paragraph(None)),
+            Event::Start(Tag::Emphasis),
+            Event::Text("x".into()),
+            Event::End(TagEnd::Paragraph),
+        ];
+        let out: Vec<_> = events.into_iter().rebalance().collect();
+        let rendered: Vec<_> = out
+            .iter()
+            .map(|event| match event {
+                Event::Start(_) => "start".to_string(),
+                Event::End(end) => format!("{:?}", end),
+                _ => "text".to_string(),
+            })
+            .collect();
+        assert_eq!(
+            rendered,
+            vec![
+                "start",
+                "start",
+                "start",
+                "text",
+                "Emphasis",
+                "Paragraph",
+                "BlockQuote(None)",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_normalize() {
+        let events = vec![
+            Event::Start(Tag::Paragraph(None)),
+            Event::Start(Tag::Emphasis),
+            Event::Text("a".into()),
+            Event::End(TagEnd::Emphasis),
+            Event::Text("".into()),
+            Event::Start(Tag::Emphasis),
+            Event::Text("b".into()),
+            Event::End(TagEnd::Emphasis),
+            Event::Start(Tag::Strong),
+            Event::Text("c".into()),
+            Event::End(TagEnd::Strong),
+            Event::End(TagEnd::Paragraph),
+        ];
+        let normalized: Vec<_> = events.into_iter().normalize().collect();
+        assert_eq!(
+            normalized,
+            vec![
+                Event::Start(Tag::Paragraph(None)),
+                Event::Start(Tag::Emphasis),
+                Event::Text("a".into()),
+                Event::Text("b".into()),
+                Event::End(TagEnd::Emphasis),
+                Event::Start(Tag::Strong),
+                Event::Text("c".into()),
+                Event::End(TagEnd::Strong),
+                Event::End(TagEnd::Paragraph),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_normalize_image_paragraph() {
+        let events: Vec<_> = Parser::new("![alt](a.png)\n\n![alt](b.png) text")
+            .normalize()
+            .collect();
+        assert!(matches!(events[0], Event::Start(Tag::Image { .. })));
+        assert_eq!(events[2], Event::End(TagEnd::Image));
+        assert_eq!(events[3], Event::Start(Tag::Paragraph(None)));
+        assert!(matches!(events[4], Event::Start(Tag::Image { .. })));
+        assert_eq!(events.last(), Some(&Event::End(TagEnd::Paragraph)));
+        assert_eq!(events.len(), 9);
+    }
+
+    #[test]
+    fn test_number_headings() {
+        let source = "# Title\n## Intro\n### Scope\n## Usage\n# Next\n## More";
+        let config = HeadingNumbering {
+            start_level: HeadingLevel::H2,
+            ..Default::default()
+        };
+        let numbers: Vec<_> = Parser::new(source)
+            .number_headings(config)
+            .filter_map(|event| match event {
+                Event::Text(text) if text.ends_with(' ') => Some(text.into_string()),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(numbers, ["1. ", "1.1 ", "2. ", "1. "]);
+
+        let events: Vec<_> = Parser::new("# a\n\n#### b")
+            .number_headings(HeadingNumbering::default())
+            .collect();
+        assert_eq!(events[1], Event::Text("1. ".into()));
+        assert_eq!(events[5], Event::Text("1.0.0.1 ".into()));
+    }
+
+    #[test]
+    fn test_renumber_footnotes() {
+        let source = "[^b]: B\n\n[^unused]: U\n\nx[^a] y[^B] z[^a]\n\n[^a]: A\n";
+        let options = Options::ENABLE_FOOTNOTES;
+        let events: Vec<_> = Parser::new_ext(source, options)
+            .renumber_footnotes(false)
+            .collect();
+        let labels: Vec<_> = events
+            .iter()
+            .filter_map(|event| match event {
+                Event::FootnoteReference(label) | Event::Start(Tag::FootnoteDefinition(label)) => {
+                    Some(label.to_string())
+                }
+                _ => None,
+            })
+            .collect();
+        assert_eq!(labels, ["2", "3", "1", "2", "1", "1"]);
+
+        let events: Vec<_> = Parser::new_ext(source, options)
+            .renumber_footnotes(true)
+            .collect();
+        assert_eq!(events[0], Event::Start(Tag::Paragraph(None)));
+        let definitions: Vec<_> = events
+            .iter()
+            .filter_map(|event| match event {
+                Event::Start(Tag::FootnoteDefinition(label)) => Some(label.to_string()),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(definitions, ["1", "2", "3"]);
+        assert_eq!(events.last(), Some(&Event::End(TagEnd::FootnoteDefinition)));
+        assert_eq!(events[events.len() - 3], Event::Text("U".into()));
+    }
+
+    #[test]
+    fn test_resolve_relative_to() {
+        let source = "[a](../a.md) ![b](img/b.png) <c@example.com> [d](https://x.org) [e](#e)";
+        let urls: Vec<_> = Parser::new(source)
+            .resolve_relative_to("/docs/guide/")
+            .filter_map(|event| match event {
+                Event::Start(Tag::Link { dest_url, .. } | Tag::Image { dest_url, .. }) => {
+                    Some(dest_url.into_string())
+                }
+                _ => None,
+            })
+            .collect();
+        assert_eq!(
+            urls,
+            [
+                "/docs/a.md",
+                "/docs/guide/img/b.png",
+                "c@example.com",
+                "https://x.org",
+                "#e"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_container_path() {
+        let source = "> 1. a\n>\n>    > b\n\n| x |\n|---|\n| y |\n";
+        let paths: Vec<_> = Parser::new_ext(source, crate::Options::ENABLE_TABLES)
+            .with_container_path()
+            .filter_map(|(event, context)| match event {
+                Event::Text(text) => {
+                    Some((text.into_string(), context.depth(), context.quote_depth()))
+                }
+                _ => None,
+            })
+            .collect();
+        assert_eq!(
+            paths,
+            [
+                ("a".to_string(), 3, 1),
+                ("b".to_string(), 4, 2),
+                ("x".to_string(), 3, 0),
+                ("y".to_string(), 3, 0),
+            ]
+        );
+        let (_, context) = Parser::new(source)
+            .with_container_path()
+            .find(|(event, _)| matches!(event, Event::End(TagEnd::List(true))))
+            .unwrap();
+        assert_eq!(context.path, [TagEnd::BlockQuote(None)]);
+        assert!(!context.is_inside(TagEnd::Item));
+    }
+
+    #[cfg(feature = "unicode-normalization")]
+    #[test]
+    fn test_normalize_unicode() {
+        let source = "Cafe\u{301} \u{FB01}x\u{200B}y \u{2764}\u{FE0F}\n\n```\ne\u{301}\n```";
+        let texts = |config| -> Vec<_> {
+            Parser::new(source)
+                .normalize_unicode(config)
+                .filter_map(|event| match event {
+                    Event::Text(text) => Some(text.into_string()),
+                    _ => None,
+                })
+                .collect()
+        };
+        assert_eq!(
+            texts(UnicodeNormalizationConfig::default()),
+            [
+                "Caf\u{e9} \u{FB01}x\u{200B}y \u{2764}\u{FE0F}",
+                "e\u{301}\n"
+            ]
+        );
+        let config = UnicodeNormalizationConfig {
+            form: NormalizationForm::Nfkc,
+            strip_invisible: true,
+        };
+        assert_eq!(texts(config), ["Caf\u{e9} fixy \u{2764}", "e\u{301}\n"]);
+    }
+}
@@ -152,11 +152,34 @@ impl<T: Default> Tree<T> {
         self.nodes.len() <= 1
     }
 
+    /// Returns the number of nodes, not counting the root node.
+    #[cfg(feature = "tracing")]
+    pub(crate) fn node_count(&self) -> usize {
+        self.nodes.len() - 1
+    }
+
     /// Returns the length of the spine.
     pub(crate) fn spine_len(&self) -> usize {
         self.spine.len()
     }
 
+    /// Returns the number of nodes the tree can hold without reallocating.
+    pub(crate) fn capacity(&self) -> usize {
+        self.nodes.capacity()
+    }
+
+    /// Removes all nodes but keeps the allocated capacity.
+    pub(crate) fn clear(&mut self) {
+        self.nodes.truncate(1);
+        self.nodes[0] = Node {
+            child: None,
+            next: None,
+            item: <T as Default>::default(),
+        };
+        self.spine.clear();
+        self.cur = None;
+    }
+
     /// Resets the focus to the first node added to the tree, if it exists.
     pub(crate) fn reset(&mut self) {
         self.cur = if self.is_empty() {
@@ -178,6 +201,56 @@ impl<T: Default> Tree<T> {
         self.cur
     }
 
+    /// 将一个孤立节点链接到最后一个顶层节点之后，使其在文档末尾被遍历到。
+    pub(crate) fn append_top_level(&mut self, ix: TreeIndex) {
+        let mut last = TreeIndex::new(1);
+        while let Some(next) = self[last].next {
+            last = next;
+        }
+        self[last].next = Some(ix);
+    }
+
+    /// 在整棵树中按先序查找第一个满足条件的节点，返回从根到它的路径（不含该节点）及其索引。
+    pub(crate) fn find_path(
+        &self,
+        mut predicate: impl FnMut(&T) -> bool,
+    ) -> Option<(Vec<TreeIndex>, TreeIndex)> {
+        let mut path = Vec::new();
+        let mut next = if self.is_empty() {
+            None
+        } else {
+            Some(TreeIndex::new(1))
+        };
+        loop {
+            let Some(ix) = next else {
+                // 当前层已遍历完，回到父节点的下一个兄弟节点
+                let parent = path.pop()?;
+                next = self[parent].next;
+                continue;
+            };
+            if predicate(&self[ix].item) {
+                return Some((path, ix));
+            }
+            if let Some(child) = self[ix].child {
+                path.push(ix);
+                next = Some(child);
+            } else {
+                next = self[ix].next;
+            }
+        }
+    }
+
+    /// 返回当前位置，即路径和聚焦节点，供 [`Tree::set_position`] 恢复。
+    pub(crate) fn position(&self) -> (Vec<TreeIndex>, Option<TreeIndex>) {
+        (self.spine.clone(), self.cur)
+    }
+
+    /// 将焦点移到给定路径下的节点。
+    pub(crate) fn set_position(&mut self, spine: Vec<TreeIndex>, cur: Option<TreeIndex>) {
+        self.spine = spine;
+        self.cur = cur;
+    }
+
     pub(crate) fn truncate_to_parent(&mut self, child_ix: TreeIndex) {
         let next = self[child_ix].next;
         self[child_ix].next = None;
@@ -11,12 +11,34 @@
 //!   Its author proposed the solution in
 //!   <https://github.com/raphlinus/pulldown-cmark/issues/708>.
 
-use alloc::string::String;
-use core::ops::Range;
+use alloc::{
+    borrow::ToOwned,
+    boxed::Box,
+    collections::{BTreeMap, VecDeque},
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use core::{hash::BuildHasher, mem, ops::Range};
+#[cfg(all(feature = "std", not(feature = "hashbrown")))]
+use std::collections::HashMap;
 
-use crate::{CowStr, Event};
+#[cfg(feature = "hashbrown")]
+use hashbrown::HashMap;
+use unicase::UniCase;
+
+use crate::{
+    ast::{Ast, Node, NodeKind},
+    context::Symbol,
+    scanners::scan_front_matter,
+    Alignment, CodeBlockKind, CowStr, Diagnostic, DiagnosticKind, Event, HeadingLevel, LinkType,
+    MathSyntax, MetadataBlockKind, Options, Parser, Tag, TagEnd,
+};
 
 /// Merge consecutive `Event::Text` events into only one.
+///
+/// Consecutive `Event::Html` and `Event::InlineHtml` events can also be merged
+/// by enabling [`merge_html`](Self::merge_html).
 #[derive(Debug)]
 pub struct TextMergeStream<'a, I> {
     inner: TextMergeWithOffset<'a, DummyOffsets<I>>,
@@ -31,6 +53,13 @@ where
             inner: TextMergeWithOffset::new(DummyOffsets(iter)),
         }
     }
+
+    /// Also merge consecutive `Event::Html` events, and consecutive
+    /// `Event::InlineHtml` events, into only one.
+    pub fn merge_html(mut self, merge_html: bool) -> Self {
+        self.inner = self.inner.merge_html(merge_html);
+        self
+    }
 }
 
 impl<'a, I> Iterator for TextMergeStream<'a, I>
@@ -60,11 +89,24 @@ where
 
 /// Merge consecutive `Event::Text` events into only one, with offsets.
 ///
+/// The range of a merged event spans from the start of the first event to the
+/// end of the last one. Consecutive `Event::Html` and `Event::InlineHtml`
+/// events can also be merged by enabling [`merge_html`](Self::merge_html).
+///
 /// Compatible with with [`OffsetIter`](crate::OffsetIter).
 #[derive(Debug)]
 pub struct TextMergeWithOffset<'a, I> {
     iter: I,
     last_event: Option<(Event<'a>, Range<usize>)>,
+    merge_html: bool,
+}
+
+/// Kinds of events that can be merged together.
+#[derive(Clone, Copy, PartialEq)]
+enum MergeKind {
+    Text,
+    Html,
+    InlineHtml,
 }
 
 impl<'a, I> TextMergeWithOffset<'a, I>
@@ -75,6 +117,23 @@ where
         Self {
             iter,
             last_event: None,
+            merge_html: false,
+        }
+    }
+
+    /// Also merge consecutive `Event::Html` events, and consecutive
+    /// `Event::InlineHtml` events, into only one.
+    pub fn merge_html(mut self, merge_html: bool) -> Self {
+        self.merge_html = merge_html;
+        self
+    }
+
+    fn merge_kind(&self, event: &Event<'a>) -> Option<MergeKind> {
+        match event {
+            Event::Text(_) => Some(MergeKind::Text),
+            Event::Html(_) if self.merge_html => Some(MergeKind::Html),
+            Event::InlineHtml(_) if self.merge_html => Some(MergeKind::InlineHtml),
+            _ => None,
         }
     }
 }
@@ -86,102 +145,4593 @@ where
     type Item = (Event<'a>, Range<usize>);
 
     fn next(&mut self) -> Option<Self::Item> {
-        match (self.last_event.take(), self.iter.next()) {
-            (
-                Some((Event::Text(last_text), last_offset)),
-                Some((Event::Text(next_text), next_offset)),
-            ) => {
-                // We need to start merging consecutive text events together into one
-                let mut string_buf: String = last_text.into_string();
-                string_buf.push_str(&next_text);
-                let mut offset = last_offset;
-                offset.end = next_offset.end;
-                loop {
+        loop {
+            let (event, mut offset) = match self.last_event.take() {
+                Some(last_event) => last_event,
+                None => self.iter.next()?,
+            };
+            let Some(kind) = self.merge_kind(&event) else {
+                // The ordinary case, emit one event after the other without modification
+                return Some((event, offset));
+            };
+            match self.iter.next() {
+                Some((next_event, next_offset)) if self.merge_kind(&next_event) == Some(kind) => {
+                    // We need to start merging consecutive events together into one
+                    let mut string_buf: String = into_cow_str(event).into_string();
+                    string_buf.push_str(&into_cow_str(next_event));
+                    offset.end = next_offset.end;
                     // Avoid recursion to avoid stack overflow and to optimize concatenation
-                    match self.iter.next() {
-                        Some((Event::Text(next_text), next_offset)) => {
-                            string_buf.push_str(&next_text);
-                            offset.end = next_offset.end;
-                        }
-                        next_event => {
-                            self.last_event = next_event;
-                            if string_buf.is_empty() {
-                                // Discard text event(s) altogether if there is no text
-                                break self.next();
-                            } else {
-                                break Some((
-                                    Event::Text(CowStr::Boxed(string_buf.into_boxed_str())),
-                                    offset,
-                                ));
+                    loop {
+                        match self.iter.next() {
+                            Some((next_event, next_offset))
+                                if self.merge_kind(&next_event) == Some(kind) =>
+                            {
+                                string_buf.push_str(&into_cow_str(next_event));
+                                offset.end = next_offset.end;
+                            }
+                            next_event => {
+                                self.last_event = next_event;
+                                break;
                             }
                         }
                     }
+                    if string_buf.is_empty() {
+                        // Discard merged event(s) altogether if there is no text
+                        continue;
+                    }
+                    let merged = CowStr::Boxed(string_buf.into_boxed_str());
+                    let event = match kind {
+                        MergeKind::Text => Event::Text(merged),
+                        MergeKind::Html => Event::Html(merged),
+                        MergeKind::InlineHtml => Event::InlineHtml(merged),
+                    };
+                    return Some((event, offset));
+                }
+                next_event => {
+                    self.last_event = next_event;
+                    return Some((event, offset));
                 }
             }
-            (None, Some(next_event)) => {
-                // This only happens once during the first iteration and if there are items
-                self.last_event = Some(next_event);
-                self.next()
+        }
+    }
+}
+
+fn into_cow_str(event: Event<'_>) -> CowStr<'_> {
+    match event {
+        Event::Text(text) | Event::Html(text) | Event::InlineHtml(text) => text,
+        _ => unreachable!(),
+    }
+}
+
+/// Groups consecutive events by the source line they belong to, yielding
+/// `(line, events)` pairs. Lines are counted from zero, as in
+/// [`folding_ranges`].
+///
+/// A start tag and a leaf event belong to the line where their range starts,
+/// an end tag to the last line with content in the element, so a
+/// multi-line block opens on its first line and closes on its last. A text
+/// event that spans lines, such as the content of a code block, is split into
+/// one event per line; other events, such as display math, are kept whole on
+/// their first line. Lines without any event are skipped.
+///
+/// Syntax highlighters and pagers can use this to render a document line by
+/// line while still knowing which elements are open.
+///
+/// Compatible with [`OffsetIter`](crate::OffsetIter).
+///
+/// ```
+/// # use pulldown_cmark::{utils::LineGroups, Event, Parser, Tag, TagEnd};
+/// let input = "# Title\n\nsome\ntext\n";
+/// let lines: Vec<_> = LineGroups::new(input, Parser::new(input).into_offset_iter()).collect();
+/// assert_eq!(lines.len(), 3);
+/// assert_eq!(lines[1].0, 2);
+/// assert_eq!(
+///     lines[2],
+///     (
+///         3,
+///         vec![Event::Text("text".into()), Event::End(TagEnd::Paragraph)],
+///     )
+/// );
+/// ```
+#[derive(Debug)]
+pub struct LineGroups<'a, I> {
+    text: &'a str,
+    iter: I,
+    line_starts: Vec<usize>,
+    pending: VecDeque<(usize, Event<'a>)>,
+}
+
+impl<'a, I> LineGroups<'a, I>
+where
+    I: Iterator<Item = (Event<'a>, Range<usize>)>,
+{
+    /// Groups the events of `iter`, whose offsets refer to `text`.
+    pub fn new(text: &'a str, iter: I) -> Self {
+        let line_starts = core::iter::once(0)
+            .chain(memchr::memchr_iter(b'\n', text.as_bytes()).map(|ix| ix + 1))
+            .collect();
+        Self {
+            text,
+            iter,
+            line_starts,
+            pending: VecDeque::new(),
+        }
+    }
+
+    fn line_of(&self, offset: usize) -> usize {
+        self.line_starts.partition_point(|&start| start <= offset) - 1
+    }
+
+    fn next_with_line(&mut self) -> Option<(usize, Event<'a>)> {
+        if let Some(pending) = self.pending.pop_front() {
+            return Some(pending);
+        }
+        let (event, range) = self.iter.next()?;
+        let line = match event {
+            Event::End(_) => {
+                let content = self.text.get(range.clone()).unwrap_or("").trim_end();
+                self.line_of(range.start + content.len().saturating_sub(1))
             }
-            (None, None) => {
-                // This happens when the iterator is depleted
-                None
+            Event::Text(text) if text.trim_end_matches('\n').contains('\n') => {
+                let line = self.line_of(range.start);
+                let lines: Vec<CowStr<'a>> = match text {
+                    CowStr::Borrowed(text) => text.split_inclusive('\n').map(Into::into).collect(),
+                    text => text
+                        .split_inclusive('\n')
+                        .map(|line| line.to_string().into())
+                        .collect(),
+                };
+                self.pending.extend(
+                    lines
+                        .into_iter()
+                        .enumerate()
+                        .map(|(i, text)| (line + i, Event::Text(text))),
+                );
+                return self.pending.pop_front();
             }
-            (last_event, next_event) => {
-                // The ordinary case, emit one event after the other without modification
-                self.last_event = next_event;
-                last_event
+            _ => self.line_of(range.start),
+        };
+        Some((line, event))
+    }
+}
+
+impl<'a, I> Iterator for LineGroups<'a, I>
+where
+    I: Iterator<Item = (Event<'a>, Range<usize>)>,
+{
+    type Item = (usize, Vec<Event<'a>>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (line, event) = self.next_with_line()?;
+        let mut events = vec![event];
+        while let Some((next_line, event)) = self.next_with_line() {
+            if next_line != line {
+                self.pending.push_front((next_line, event));
+                break;
             }
+            events.push(event);
         }
+        Some((line, events))
     }
 }
 
-#[cfg(test)]
-mod test {
-    use alloc::vec::Vec;
+/// Turns heading text into an anchor the way GitHub does.
+///
+/// The text is lowercased, every character that is not a letter, number,
+/// combining mark, `-`, `_` or space is removed, and spaces become `-`.
+/// Use a [`Slugger`] to also deduplicate anchors within a document.
+///
+/// ```
+/// # use pulldown_cmark::utils::slugify;
+/// assert_eq!(slugify("Hello, World!"), "hello-world");
+/// assert_eq!(slugify("Crème brûlée — 2 ways"), "crème-brûlée--2-ways");
+/// ```
+pub fn slugify(text: &str) -> String {
+    let mut slug = String::with_capacity(text.len());
+    for c in text.chars().flat_map(char::to_lowercase) {
+        if c == ' ' {
+            slug.push('-');
+        } else if c.is_alphanumeric() || c == '-' || is_connector_punctuation(c) || is_mark(c) {
+            slug.push(c);
+        }
+    }
+    slug
+}
 
-    use super::*;
-    use crate::Parser;
+// Unicode category Pc.
+fn is_connector_punctuation(c: char) -> bool {
+    matches!(c, '_' | '\u{203F}'..='\u{2040}' | '\u{2054}')
+        || matches!(c, '\u{FE33}'..='\u{FE34}' | '\u{FE4D}'..='\u{FE4F}' | '\u{FF3F}')
+}
 
-    #[test]
-    fn text_merge_stream_indent() {
-        let source = r#"
-    first line
-    second line
-"#;
-        let parser = TextMergeStream::new(Parser::new(source));
-        let text_events: Vec<_> = parser.filter(|e| matches!(e, Event::Text(_))).collect();
-        assert_eq!(
-            text_events,
-            [Event::Text("first line\nsecond line\n".into())]
-        );
+// The combining diacritical mark blocks, which cover the marks used with
+// Latin, Greek and Cyrillic text. Marks of other scripts are mostly
+// alphabetic and kept by `is_alphanumeric`.
+fn is_mark(c: char) -> bool {
+    matches!(
+        c,
+        '\u{0300}'..='\u{036F}'
+            | '\u{1AB0}'..='\u{1AFF}'
+            | '\u{1DC0}'..='\u{1DFF}'
+            | '\u{20D0}'..='\u{20FF}'
+            | '\u{FE20}'..='\u{FE2F}'
+    )
+}
+
+/// Generates unique anchors, following GitHub: repeated headings get
+/// `-1`, `-2`, … appended.
+///
+/// ```
+/// # use pulldown_cmark::utils::Slugger;
+/// let mut slugger = Slugger::new();
+/// assert_eq!(slugger.slug("Foo"), "foo");
+/// assert_eq!(slugger.slug("Foo"), "foo-1");
+/// assert_eq!(slugger.slug("foo 1"), "foo-1-1");
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Slugger {
+    occurrences: HashMap<String, usize>,
+}
+
+impl Slugger {
+    pub fn new() -> Self {
+        Self::default()
     }
 
-    #[test]
-    fn text_merge_with_offset_indent() {
-        let source = r#"
-    first line
-    second line
-"#;
-        let parser = TextMergeWithOffset::new(Parser::new(source).into_offset_iter());
-        let text_events: Vec<_> = parser
-            .filter(|e| matches!(e, (Event::Text(_), _)))
-            .collect();
-        assert_eq!(
-            text_events,
-            [(Event::Text("first line\nsecond line\n".into()), 5..32)]
-        );
+    /// Returns the unique anchor for the given heading text.
+    pub fn slug(&mut self, text: &str) -> String {
+        self.unique(&slugify(text))
     }
 
-    #[test]
-    fn text_merge_empty_is_discarded() {
-        let events = [
-            Event::Rule,
-            Event::Text("".into()),
-            Event::Text("".into()),
-            Event::Rule,
-        ];
-        let result: Vec<_> = TextMergeStream::new(events.into_iter()).collect();
-        assert_eq!(result, [Event::Rule, Event::Rule]);
+    /// Deduplicates an anchor that is already slugified, for example a
+    /// custom heading id.
+    pub fn unique(&mut self, slug: &str) -> String {
+        let Some(&(mut count)) = self.occurrences.get(slug) else {
+            self.occurrences.insert(slug.to_owned(), 0);
+            return slug.to_owned();
+        };
+        let candidate = loop {
+            count += 1;
+            let candidate = format!("{}-{}", slug, count);
+            if !self.occurrences.contains_key(&candidate) {
+                break candidate;
+            }
+        };
+        self.occurrences.insert(slug.to_owned(), count);
+        self.occurrences.insert(candidate.clone(), 0);
+        candidate
+    }
+
+    /// Forgets all anchors handed out so far.
+    pub fn reset(&mut self) {
+        self.occurrences.clear();
+    }
+}
+
+/// A link found by [`extract_links`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct LinkInfo<'a> {
+    pub dest_url: CowStr<'a>,
+    pub title: CowStr<'a>,
+    pub link_type: LinkType,
+    /// The plain text of the link, including the alt text of nested images.
+    pub text: String,
+    /// The whole link in the markdown source.
+    pub span: Range<usize>,
+}
+
+/// Returns every link in the document, including autolinks, reference links
+/// and wikilinks, in document order. Images are not included.
+///
+/// ```
+/// # use pulldown_cmark::{utils::extract_links, LinkType, Options};
+/// let input = "See [the docs](https://docs.rs \"Docs\") or <https://example.com>.";
+/// let links = extract_links(input, Options::empty());
+/// assert_eq!(links[0].dest_url, "https://docs.rs".into());
+/// assert_eq!(links[0].text, "the docs");
+/// assert_eq!(links[1].link_type, LinkType::Autolink);
+/// assert_eq!(&input[links[1].span.clone()], "<https://example.com>");
+/// ```
+pub fn extract_links(input: &str, options: Options) -> Vec<LinkInfo<'_>> {
+    let mut links = Vec::new();
+    let mut open = None;
+    for (event, range) in Parser::new_ext(input, options).into_offset_iter() {
+        match event {
+            Event::Start(Tag::Link {
+                link_type,
+                dest_url,
+                title,
+                ..
+            }) => {
+                open = Some(links.len());
+                links.push(LinkInfo {
+                    dest_url,
+                    title,
+                    link_type,
+                    text: String::new(),
+                    span: range,
+                });
+            }
+            Event::End(TagEnd::Link) => open = None,
+            Event::Text(text) | Event::Code(text, _) => {
+                if let Some(ix) = open {
+                    links[ix].text.push_str(&text);
+                }
+            }
+            _ => {}
+        }
+    }
+    links
+}
+
+/// Finds `needle` in the text a reader sees and returns the matches as
+/// ranges in the markdown source, in document order.
+///
+/// Only text is searched: markup, code spans, code and metadata blocks, raw
+/// HTML, math and image descriptions are skipped. Matches may span inline
+/// markup like emphasis and links, but not blocks. A line break within a
+/// paragraph counts as a space. Where the text differs from its source, as
+/// for backslash escapes and entities, a match covers the whole source of
+/// the characters it contains.
+///
+/// ```
+/// # use pulldown_cmark::{utils::find_in_text, Options};
+/// let input = "Say *hello\nworld* &amp; `hello world`.\n";
+/// let matches = find_in_text(input, Options::empty(), "hello world &");
+/// assert_eq!(matches, [5..23]);
+/// assert_eq!(&input[matches[0].clone()], "hello\nworld* &amp;");
+/// ```
+pub fn find_in_text(input: &str, options: Options, needle: &str) -> Vec<Range<usize>> {
+    let mut matches = Vec::new();
+    if needle.is_empty() {
+        return matches;
+    }
+    // the visible text of the current block, with the source of each byte
+    let mut text = String::new();
+    let mut sources: Vec<Range<usize>> = Vec::new();
+    let mut flush = |text: &mut String, sources: &mut Vec<Range<usize>>| {
+        for (ix, found) in text.match_indices(needle) {
+            matches.push(sources[ix].start..sources[ix + found.len() - 1].end);
+        }
+        text.clear();
+        sources.clear();
+    };
+    let mut hidden = 0usize;
+    for (event, range) in Parser::new_ext(input, options).into_offset_iter() {
+        match event {
+            Event::Start(Tag::CodeBlock(_) | Tag::MetadataBlock(_) | Tag::Image { .. }) => {
+                hidden += 1
+            }
+            Event::End(TagEnd::CodeBlock | TagEnd::MetadataBlock(_) | TagEnd::Image) => hidden -= 1,
+            Event::Start(
+                Tag::Emphasis
+                | Tag::Strong
+                | Tag::Strikethrough
+                | Tag::Superscript
+                | Tag::Subscript
+                | Tag::Link { .. },
+            )
+            | Event::End(
+                TagEnd::Emphasis
+                | TagEnd::Strong
+                | TagEnd::Strikethrough
+                | TagEnd::Superscript
+                | TagEnd::Subscript
+                | TagEnd::Link,
+            ) => {}
+            Event::Start(_) | Event::End(_) => flush(&mut text, &mut sources),
+            Event::Text(content) if hidden == 0 => {
+                text.push_str(&content);
+                if input[range.clone()] == *content {
+                    sources.extend(range.map(|ix| ix..ix + 1));
+                } else {
+                    sources.extend(core::iter::repeat(range).take(content.len()));
+                }
+            }
+            Event::SoftBreak | Event::HardBreak(_) if hidden == 0 => {
+                text.push(' ');
+                sources.push(range);
+            }
+            _ => {}
+        }
+    }
+    flush(&mut text, &mut sources);
+    matches
+}
+
+/// Returns the plain text of the first paragraph with any text, for use as
+/// a summary or description.
+///
+/// Images, footnote references, inline HTML, and paragraphs inside footnote
+/// definitions are skipped, so a paragraph holding only a cover image is
+/// passed over. Line breaks become spaces. If the text is longer than
+/// `max_len` characters it is cut at the last word boundary that leaves room
+/// for an appended `…`, so the result never exceeds `max_len` characters.
+///
+/// ```
+/// # use pulldown_cmark::{utils::summary, Options};
+/// let input = "# Title\n\n![cover](c.png)\n\nThe *quick* brown\nfox jumps.[^1]\n\n[^1]: Note";
+/// assert_eq!(summary(input, Options::ENABLE_FOOTNOTES, 100), "The quick brown fox jumps.");
+/// assert_eq!(summary(input, Options::ENABLE_FOOTNOTES, 12), "The quick…");
+/// ```
+pub fn summary(input: &str, options: Options, max_len: usize) -> String {
+    let mut text = String::new();
+    let mut in_paragraph = false;
+    // Depth of elements whose content is skipped: images and footnote definitions.
+    let mut skip_depth = 0usize;
+    for event in Parser::new_ext(input, options) {
+        match event {
+            Event::Start(Tag::Image { .. } | Tag::FootnoteDefinition(_)) => skip_depth += 1,
+            Event::End(TagEnd::Image | TagEnd::FootnoteDefinition) => skip_depth -= 1,
+            _ if skip_depth > 0 => {}
+            Event::Start(Tag::Paragraph(_)) => in_paragraph = true,
+            Event::End(TagEnd::Paragraph) => {
+                in_paragraph = false;
+                if !text.trim().is_empty() {
+                    break;
+                }
+                text.clear();
+            }
+            Event::Text(s) | Event::Code(s, _) | Event::InlineMath(s, _) if in_paragraph => {
+                text.push_str(&s);
+            }
+            Event::SoftBreak | Event::HardBreak(_) if in_paragraph => text.push(' '),
+            _ => {}
+        }
+    }
+    truncate_at_word(text.trim(), max_len)
+}
+
+fn truncate_at_word(text: &str, max_len: usize) -> String {
+    if text.chars().nth(max_len).is_none() {
+        return text.to_owned();
+    }
+    // Keep one character of the limit for the ellipsis.
+    let Some(kept) = max_len.checked_sub(1) else {
+        return String::new();
+    };
+    // The text is longer than `max_len`, so the character exists.
+    let (cut, _) = text.char_indices().nth(kept).unwrap();
+    let head = &text[..cut];
+    // Only cut at a word boundary if the next character does not continue the word.
+    let head = if text[cut..].starts_with(char::is_whitespace) {
+        head
+    } else {
+        match head.rfind(char::is_whitespace) {
+            Some(ix) => &head[..ix],
+            None => head,
+        }
+    };
+    let mut truncated = head.trim_end().to_owned();
+    truncated.push('…');
+    truncated
+}
+
+/// Parses `input` and returns its events cut after `max_chars` visible
+/// characters, for previews that must stay valid markup.
+///
+/// Text, code spans and math count toward the limit, and a line break counts
+/// as one character; image descriptions and metadata blocks do not. When the
+/// document is longer than the limit, the text is cut, `…` is appended, and
+/// an end event is emitted for every element that is still open, so the
+/// result can be rendered as is. A shorter document is returned unchanged.
+///
+/// ```
+/// # use pulldown_cmark::{html, utils::truncate_markdown, Options};
+/// let events = truncate_markdown("Some **bold claim** here.\n\n- more", Options::empty(), 10);
+/// let mut output = String::new();
+/// html::push_html(&mut output, events.into_iter());
+/// assert_eq!(output, "<p>Some <strong>bold…</strong></p>\n");
+/// ```
+pub fn truncate_markdown(input: &str, options: Options, max_chars: usize) -> Vec<Event<'_>> {
+    let mut events = Vec::new();
+    let mut used = 0;
+    // Length of `events` up to and including the last visible event.
+    let mut visible_len = 0;
+    // Depth of elements whose content is not visible: images and metadata blocks.
+    let mut hidden_depth = 0usize;
+    for event in Parser::new_ext(input, options) {
+        match event {
+            Event::Start(Tag::Image { .. } | Tag::MetadataBlock(_)) => hidden_depth += 1,
+            Event::End(TagEnd::Image | TagEnd::MetadataBlock(_)) => hidden_depth -= 1,
+            _ => {}
+        }
+        let len = match &event {
+            _ if hidden_depth > 0 => 0,
+            Event::Text(s)
+            | Event::Code(s, _)
+            | Event::InlineMath(s, _)
+            | Event::DisplayMath(s, _) => s.chars().count(),
+            Event::SoftBreak | Event::HardBreak(_) => 1,
+            _ => 0,
+        };
+        if len == 0 || used + len <= max_chars {
+            events.push(event);
+            if len > 0 {
+                used += len;
+                visible_len = events.len();
+            }
+            continue;
+        }
+        let remaining = max_chars - used;
+        if remaining == 0 {
+            // Drop the elements opened since the last visible event, so the
+            // ellipsis follows the text it continues.
+            events.truncate(visible_len);
+            match events.last_mut() {
+                Some(Event::Text(s) | Event::Code(s, _)) => *s = ellipsize(s, s.len()),
+                _ => events.push(Event::Text("…".into())),
+            }
+        } else {
+            let cut = |s: &str| {
+                s.char_indices()
+                    .nth(remaining)
+                    .map_or(s.len(), |(ix, _)| ix)
+            };
+            events.push(match event {
+                Event::Code(s, delimiter) => Event::Code(ellipsize(&s, cut(&s)), delimiter),
+                Event::InlineMath(s, syntax) => Event::InlineMath(ellipsize(&s, cut(&s)), syntax),
+                Event::DisplayMath(s, syntax) => Event::DisplayMath(ellipsize(&s, cut(&s)), syntax),
+                Event::Text(s) => Event::Text(ellipsize(&s, cut(&s))),
+                event => event,
+            });
+        }
+        let mut open = Vec::new();
+        for event in &events {
+            match event {
+                Event::Start(tag) => open.push(tag.to_end()),
+                Event::End(_) => {
+                    open.pop();
+                }
+                _ => {}
+            }
+        }
+        events.extend(open.into_iter().rev().map(Event::End));
+        break;
+    }
+    events
+}
+
+fn ellipsize<'a>(text: &str, cut: usize) -> CowStr<'a> {
+    let mut truncated = text[..cut].trim_end().to_owned();
+    truncated.push('…');
+    truncated.into()
+}
+
+/// The document title found by [`extract_title`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Title {
+    pub text: String,
+    /// The heading, or the value of the `title` key, in the markdown source.
+    pub span: Range<usize>,
+}
+
+/// Returns the plain text of the first level 1 heading.
+///
+/// If there is none and metadata blocks are enabled, the top-level `title`
+/// key of the front matter is used instead: `title: ...` for YAML and
+/// `title = "..."` for TOML-style (`+++`) blocks.
+///
+/// ```
+/// # use pulldown_cmark::{utils::extract_title, Options};
+/// let input = "---\ntitle: \"From metadata\"\n---\n\n## Not a title\n";
+/// let options = Options::ENABLE_YAML_STYLE_METADATA_BLOCKS;
+/// let title = extract_title(input, options).unwrap();
+/// assert_eq!(title.text, "From metadata");
+/// assert_eq!(&input[title.span], "\"From metadata\"");
+///
+/// let title = extract_title("Intro\n\n# The `real` title\n", options).unwrap();
+/// assert_eq!(title.text, "The real title");
+/// ```
+pub fn extract_title(input: &str, options: Options) -> Option<Title> {
+    let mut metadata_title = None;
+    let mut heading: Option<Title> = None;
+    let mut metadata_kind = None;
+    for (event, range) in Parser::new_ext(input, options).into_offset_iter() {
+        match event {
+            Event::Start(Tag::Heading {
+                level: HeadingLevel::H1,
+                ..
+            }) => {
+                heading = Some(Title {
+                    text: String::new(),
+                    span: range,
+                });
+            }
+            Event::End(TagEnd::Heading(HeadingLevel::H1)) => return heading,
+            Event::Start(Tag::MetadataBlock(kind)) => metadata_kind = Some(kind),
+            Event::End(TagEnd::MetadataBlock(_)) => metadata_kind = None,
+            Event::Text(text) | Event::Code(text, _) => {
+                if let Some(heading) = &mut heading {
+                    heading.text.push_str(&text);
+                } else if let (Some(kind), None) = (metadata_kind, &metadata_title) {
+                    metadata_title = metadata_title_value(&text, kind).map(|value| Title {
+                        text: value.text,
+                        span: range.start + value.span.start..range.start + value.span.end,
+                    });
+                }
+            }
+            _ => {}
+        }
+    }
+    metadata_title
+}
+
+/// Finds the top-level `title` key of a metadata block. The span is
+/// relative to `block`.
+fn metadata_title_value(block: &str, kind: MetadataBlockKind) -> Option<Title> {
+    let separator = match kind {
+        MetadataBlockKind::YamlStyle => ':',
+        MetadataBlockKind::PlusesStyle => '=',
+    };
+    let mut line_start = 0;
+    for line in block.split_inclusive('\n') {
+        let offset = line_start;
+        line_start += line.len();
+        let Some(rest) = line.strip_prefix("title") else {
+            continue;
+        };
+        let Some(value) = rest.trim_start_matches([' ', '\t']).strip_prefix(separator) else {
+            continue;
+        };
+        let value_start = offset + line.len() - value.len();
+        let trimmed = value.trim_start();
+        let value_start = value_start + value.len() - trimmed.len();
+        let value = trimmed.trim_end();
+        let text = value
+            .strip_prefix('"')
+            .and_then(|v| v.strip_suffix('"'))
+            .or_else(|| value.strip_prefix('\'').and_then(|v| v.strip_suffix('\'')))
+            .unwrap_or(value);
+        if text.is_empty() {
+            return None;
+        }
+        return Some(Title {
+            text: text.to_owned(),
+            span: value_start..value_start + value.len(),
+        });
+    }
+    None
+}
+
+/// Returns the front matter of a document without parsing the rest of it.
+///
+/// Both YAML-style (`---`) and TOML-style (`+++`) metadata blocks are
+/// recognized, as with [`Options::ENABLE_YAML_STYLE_METADATA_BLOCKS`] and
+/// [`Options::ENABLE_PLUSES_DELIMITED_METADATA_BLOCKS`], but only at the very
+/// start of the input. Only the block itself is scanned, which keeps listing
+/// many large documents cheap.
+///
+/// Returns the kind, the text between the delimiter lines, and the range of
+/// the whole block, which is the range [`Parser::into_offset_iter`] reports
+/// for it.
+///
+/// ```
+/// # use pulldown_cmark::{utils::read_front_matter, MetadataBlockKind};
+/// let input = "+++\ntitle = \"Post\"\n+++\n\n# Body\n";
+/// let (kind, front_matter, span) = read_front_matter(input).unwrap();
+/// assert_eq!(kind, MetadataBlockKind::PlusesStyle);
+/// assert_eq!(front_matter, "title = \"Post\"\n");
+/// assert_eq!(span, 0..22);
+/// assert!(read_front_matter("# No front matter\n").is_none());
+/// ```
+pub fn read_front_matter(input: &str) -> Option<(MetadataBlockKind, &str, Range<usize>)> {
+    let (fence_char, content, end) = scan_front_matter(input.as_bytes(), true, true)?;
+    let kind = if fence_char == b'-' {
+        MetadataBlockKind::YamlStyle
+    } else {
+        MetadataBlockKind::PlusesStyle
+    };
+    Some((kind, &input[content], 0..end))
+}
+
+/// Escapes `text` so that it renders literally when inserted into markdown,
+/// for example a user-provided string in generated documents.
+///
+/// Characters that can start inline markup are escaped with a backslash
+/// wherever they appear. Characters that are only special at the start of a
+/// line (`-`, `+`, `=`, `:`, and the `.` or `)` of an ordered list marker) are
+/// escaped there. Every `@`, the `:` of `://` and `tel:`, and the `.` of
+/// `www.` are escaped so that no autolink literals are created. Indentation
+/// that would start a code block and trailing spaces that would make a hard
+/// line break are written as character references, so they are kept as text.
+/// The input is borrowed if nothing needs escaping.
+///
+/// ```
+/// # use pulldown_cmark::{utils::escape_markdown, CowStr};
+/// assert_eq!(escape_markdown("*not* [a link]"), CowStr::from(r"\*not\* \[a link\]"));
+/// assert_eq!(escape_markdown("1. item\n- item"), CowStr::from("1\\. item\n\\- item"));
+/// assert!(matches!(escape_markdown("plain text"), CowStr::Borrowed(_)));
+/// ```
+pub fn escape_markdown(text: &str) -> CowStr<'_> {
+    let is_special = |b: u8| {
+        matches!(
+            b,
+            b'\\'
+                | b'`'
+                | b'*'
+                | b'_'
+                | b'['
+                | b']'
+                | b'('
+                | b')'
+                | b'<'
+                | b'>'
+                | b'#'
+                | b'|'
+                | b'~'
+                | b'!'
+                | b'&'
+                | b'$'
+                | b'^'
+                | b'{'
+                | b'}'
+        )
+    };
+    let bytes = text.as_bytes();
+    let mut escaped = String::new();
+    let mut copied = 0;
+    let mut line_start = true;
+    let mut i = 0;
+    while i < bytes.len() {
+        let b = bytes[i];
+        // whitespace that would be stripped or start a block is kept as a character reference
+        let mut reference = None;
+        if line_start && (i == 0 || bytes[i - 1] == b'\n') && matches!(b, b' ' | b'\t') {
+            let indent = bytes[i..]
+                .iter()
+                .take_while(|b| matches!(b, b' ' | b'\t'))
+                .count();
+            let is_code = indent >= 4 || bytes[i..i + indent].contains(&b'\t');
+            if is_code && !matches!(bytes.get(i + indent), None | Some(b'\n' | b'\r')) {
+                reference = Some(if b == b' ' { "&#32;" } else { "&#9;" });
+            }
+        }
+        let line_end = &bytes[i + 1..];
+        if b == b' '
+            && i > 0
+            && bytes[i - 1] == b' '
+            && (line_end.starts_with(b"\n") || line_end.starts_with(b"\r\n"))
+        {
+            // a hard line break
+            reference = Some("&#32;");
+        }
+        if let Some(reference) = reference {
+            escaped.push_str(&text[copied..i]);
+            escaped.push_str(reference);
+            copied = i + 1;
+            i += 1;
+            continue;
+        }
+        // break up autolink literals such as `https://`, `www.`, `tel:` and emails
+        let ends_with = |suffix: &[u8]| {
+            i >= suffix.len() && bytes[i - suffix.len()..i].eq_ignore_ascii_case(suffix)
+        };
+        let mut escape = is_special(b)
+            || b == b'@'
+            || (b == b':' && (bytes[i + 1..].starts_with(b"//") || ends_with(b"tel")))
+            || (b == b'.' && ends_with(b"www"));
+        if line_start && !matches!(b, b' ' | b'\t') {
+            line_start = false;
+            if matches!(b, b'-' | b'+' | b'=' | b':') {
+                escape = true;
+            } else if b.is_ascii_digit() {
+                let digits = bytes[i..].iter().take_while(|b| b.is_ascii_digit()).count();
+                if matches!(bytes.get(i + digits), Some(b'.' | b')')) {
+                    i += digits;
+                    escape = true;
+                }
+            }
+        }
+        if bytes[i] == b'\n' {
+            line_start = true;
+        }
+        if escape {
+            escaped.push_str(&text[copied..i]);
+            escaped.push('\\');
+            copied = i;
+        }
+        i += 1;
+    }
+    if escaped.is_empty() {
+        return text.into();
+    }
+    escaped.push_str(&text[copied..]);
+    escaped.into()
+}
+
+/// Which URLs [`sanitize_url`] accepts.
+///
+/// The default policy allows `http`, `https`, `mailto` and `tel` URLs as well as
+/// relative ones, and so rejects `javascript:`, `vbscript:` and `data:` URLs.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UrlPolicy {
+    /// Allowed schemes, without the trailing `:`. Compared case-insensitively.
+    pub allowed_schemes: Vec<String>,
+    /// Whether URLs without a scheme, such as paths and fragments, are allowed.
+    pub allow_relative: bool,
+}
+
+impl Default for UrlPolicy {
+    fn default() -> Self {
+        Self {
+            allowed_schemes: ["http", "https", "mailto", "tel"]
+                .iter()
+                .map(|&scheme| scheme.to_owned())
+                .collect(),
+            allow_relative: true,
+        }
+    }
+}
+
+impl UrlPolicy {
+    /// Returns the policy with `scheme` added to the allowlist.
+    pub fn allow_scheme(mut self, scheme: &str) -> Self {
+        self.allowed_schemes.push(scheme.to_owned());
+        self
+    }
+
+    fn allows(&self, scheme: Option<&str>) -> bool {
+        match scheme {
+            Some(scheme) => self
+                .allowed_schemes
+                .iter()
+                .any(|allowed| allowed.eq_ignore_ascii_case(scheme)),
+            None => self.allow_relative,
+        }
+    }
+}
+
+/// Returns the scheme of `url`, if it has one.
+pub(crate) fn url_scheme(url: &str) -> Option<&str> {
+    let end = url.find(':')?;
+    let scheme = &url[..end];
+    let mut bytes = scheme.bytes();
+    let valid = bytes.next().is_some_and(|b| b.is_ascii_alphabetic())
+        && bytes.all(|b| b.is_ascii_alphanumeric() || matches!(b, b'+' | b'-' | b'.'));
+    valid.then_some(scheme)
+}
+
+/// Checks `url` against `policy` and normalizes its percent-encoding, returning
+/// `None` if the URL is not allowed.
+///
+/// The scheme is determined the way browsers do it: surrounding whitespace and
+/// control characters are ignored, as are tabs and newlines anywhere in the URL,
+/// so `" java\tscript:"` is still a `javascript:` URL. In the returned URL,
+/// existing escapes use uppercase hex digits, a `%` that does not start an escape
+/// becomes `%25`, and spaces, control characters, non-ASCII characters and
+/// `` "<>\^`{|} `` are percent-encoded. The input is borrowed if nothing changes.
+///
+/// ```
+/// # use pulldown_cmark::utils::{sanitize_url, UrlPolicy};
+/// let policy = UrlPolicy::default();
+/// assert_eq!(sanitize_url("https://example.com/a b", &policy).unwrap().as_ref(), "https://example.com/a%20b");
+/// assert_eq!(sanitize_url("/search?q=%e2%9c%93", &policy).unwrap().as_ref(), "/search?q=%E2%9C%93");
+/// assert_eq!(sanitize_url(" JavaScript:alert(1)", &policy), None);
+/// assert_eq!(sanitize_url("data:text/html,hi", &policy), None);
+/// assert!(sanitize_url("data:text/html,hi", &policy.allow_scheme("data")).is_some());
+/// ```
+pub fn sanitize_url<'a>(url: &'a str, policy: &UrlPolicy) -> Option<CowStr<'a>> {
+    let trimmed = url.trim_matches(|c: char| c <= ' ');
+    let url: CowStr<'a> = if trimmed.contains(['\t', '\n', '\r']) {
+        trimmed.replace(['\t', '\n', '\r'], "").into()
+    } else {
+        trimmed.into()
+    };
+    if !policy.allows(url_scheme(&url)) {
+        return None;
+    }
+
+    let bytes = url.as_bytes();
+    let mut normalized = String::new();
+    let mut copied = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        let b = bytes[i];
+        let (replacement, len) = match b {
+            b'%' => match bytes.get(i + 1..i + 3) {
+                Some(&[hi, lo]) if hi.is_ascii_hexdigit() && lo.is_ascii_hexdigit() => {
+                    if !hi.is_ascii_lowercase() && !lo.is_ascii_lowercase() {
+                        i += 3;
+                        continue;
+                    }
+                    let hi = hi.to_ascii_uppercase() as char;
+                    let lo = lo.to_ascii_uppercase() as char;
+                    (alloc::format!("%{}{}", hi, lo), 3)
+                }
+                _ => ("%25".to_owned(), 1),
+            },
+            b if b <= b' '
+                || b >= 0x7f
+                || matches!(
+                    b,
+                    b'"' | b'<' | b'>' | b'\\' | b'^' | b'`' | b'{' | b'|' | b'}'
+                ) =>
+            {
+                (alloc::format!("%{:02X}", b), 1)
+            }
+            _ => {
+                i += 1;
+                continue;
+            }
+        };
+        // Every byte of a multi-byte character is escaped, so `copied..i` is
+        // either empty or on character boundaries.
+        if copied < i {
+            normalized.push_str(&url[copied..i]);
+        }
+        normalized.push_str(&replacement);
+        i += len;
+        copied = i;
+    }
+    if copied == 0 {
+        return Some(url);
+    }
+    normalized.push_str(&url[copied..]);
+    Some(normalized.into())
+}
+
+/// Removes `.` and `..` segments from a path. `..` segments that would go above
+/// the root are dropped from absolute paths and kept in relative ones.
+fn remove_dot_segments(path: &str) -> String {
+    let absolute = path.starts_with('/');
+    let relative = path.strip_prefix('/').unwrap_or(path);
+    let mut segments: Vec<&str> = Vec::new();
+    let mut parts = relative.split('/').peekable();
+    while let Some(part) = parts.next() {
+        let is_last = parts.peek().is_none();
+        match part {
+            "." => {}
+            ".." => match segments.last() {
+                Some(&last) if last != ".." => {
+                    segments.pop();
+                }
+                _ if !absolute => segments.push(".."),
+                _ => {}
+            },
+            part => segments.push(part),
+        }
+        // A path ending in a dot segment names a directory.
+        if is_last && matches!(part, "." | "..") {
+            segments.push("");
+        }
+    }
+    let mut resolved = String::with_capacity(path.len());
+    if absolute {
+        resolved.push('/');
+    }
+    resolved.push_str(&segments.join("/"));
+    resolved
+}
+
+/// Resolves a link destination against a base URL or path, for example the
+/// location of the rendered file.
+///
+/// URLs with a scheme, fragment-only URLs such as `#intro` and empty URLs are
+/// returned as is. Otherwise `url` is joined with the directory of `base`: a base
+/// that names a directory must end with `/`. Dot segments are removed, and the
+/// query and fragment of `url` are kept. A relative base gives a relative result.
+/// The input is borrowed if it is returned unchanged.
+///
+/// ```
+/// # use pulldown_cmark::utils::resolve_url;
+/// let base = "https://example.com/docs/guide/intro.html?x=1";
+/// assert_eq!(resolve_url(base, "../api/index.html#top").as_ref(), "https://example.com/docs/api/index.html#top");
+/// assert_eq!(resolve_url(base, "/img/logo.png").as_ref(), "https://example.com/img/logo.png");
+/// assert_eq!(resolve_url(base, "//cdn.example.com/a.js").as_ref(), "https://cdn.example.com/a.js");
+/// assert_eq!(resolve_url("docs/guide/", "../../README.md").as_ref(), "README.md");
+/// assert_eq!(resolve_url("docs/", "../../README.md").as_ref(), "../README.md");
+/// assert_eq!(resolve_url(base, "#intro").as_ref(), "#intro");
+/// ```
+pub fn resolve_url<'a>(base: &str, url: &'a str) -> CowStr<'a> {
+    if url.is_empty() || url.starts_with('#') || url_scheme(url).is_some() {
+        return url.into();
+    }
+    let base = &base[..base.find(['?', '#']).unwrap_or(base.len())];
+    let scheme_len = url_scheme(base).map_or(0, |scheme| scheme.len() + 1);
+    if url.starts_with("//") {
+        return match scheme_len {
+            0 => url.into(),
+            _ => alloc::format!("{}{}", &base[..scheme_len], url).into(),
+        };
+    }
+    let origin_len = match base[scheme_len..].strip_prefix("//") {
+        Some(rest) => scheme_len + 2 + rest.find('/').unwrap_or(rest.len()),
+        None => scheme_len,
+    };
+    let (origin, base_path) = base.split_at(origin_len);
+
+    let (path, suffix) = url.split_at(url.find(['?', '#']).unwrap_or(url.len()));
+    let mut merged = String::new();
+    if path.is_empty() {
+        merged.push_str(base_path);
+    } else if path.starts_with('/') {
+        merged.push_str(path);
+    } else {
+        if !origin.is_empty() && !base_path.starts_with('/') {
+            merged.push('/');
+        }
+        merged.push_str(&base_path[..base_path.rfind('/').map_or(0, |ix| ix + 1)]);
+        merged.push_str(path);
+    }
+
+    let mut resolved = String::from(origin);
+    resolved.push_str(&remove_dot_segments(&merged));
+    resolved.push_str(suffix);
+    resolved.into()
+}
+
+/// Iterator returned by [`replace_text`].
+pub struct ReplaceText<'a, I, M, R> {
+    inner: TextMergeStream<'a, I>,
+    matcher: M,
+    replacer: R,
+    pending: VecDeque<Event<'a>>,
+    /// Depth of code and metadata blocks, whose text is left alone.
+    verbatim_depth: usize,
+}
+
+impl<I, M, R> core::fmt::Debug for ReplaceText<'_, I, M, R>
+where
+    I: core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ReplaceText")
+            .field("inner", &self.inner)
+            .field("pending", &self.pending)
+            .field("verbatim_depth", &self.verbatim_depth)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Applies replacements to the prose of an event stream, for example to fix
+/// typography or substitute emoji shortcodes.
+///
+/// Only `Text` events outside code blocks and metadata blocks are searched, so
+/// inline code, code blocks, HTML and link destinations are never touched.
+/// Adjacent text events are merged first, so a match cannot be hidden by the way
+/// the parser split the text. `matcher` returns the byte range of the first match
+/// in the given text, and `replacer` turns the matched text into the event that
+/// replaces it. The text around a match is kept as separate `Text` events,
+/// borrowing from the input where possible.
+///
+/// The replacement should be a leaf event such as `Text`, `InlineHtml` or `Code`;
+/// returning `Start` or `End` would unbalance the stream. An empty match ends the
+/// search in the current text.
+///
+/// ```
+/// # use pulldown_cmark::{utils::replace_text, Event, Parser};
+/// let events: Vec<_> = replace_text(
+///     Parser::new("a -- b `c -- d`"),
+///     |text| text.find("--").map(|ix| ix..ix + 2),
+///     |_| Event::Text("\u{2013}".into()),
+/// )
+/// .collect();
+/// assert_eq!(events[1..5], [
+///     Event::Text("a ".into()),
+///     Event::Text("\u{2013}".into()),
+///     Event::Text(" b ".into()),
+///     Event::Code("c -- d".into(), Default::default()),
+/// ]);
+/// ```
+pub fn replace_text<'a, I, M, R>(events: I, matcher: M, replacer: R) -> ReplaceText<'a, I, M, R>
+where
+    I: Iterator<Item = Event<'a>>,
+    M: FnMut(&str) -> Option<Range<usize>>,
+    R: FnMut(&str) -> Event<'a>,
+{
+    ReplaceText {
+        inner: TextMergeStream::new(events),
+        matcher,
+        replacer,
+        pending: VecDeque::new(),
+        verbatim_depth: 0,
+    }
+}
+
+impl<'a, I, M, R> ReplaceText<'a, I, M, R>
+where
+    I: Iterator<Item = Event<'a>>,
+    M: FnMut(&str) -> Option<Range<usize>>,
+    R: FnMut(&str) -> Event<'a>,
+{
+    fn split_text(&mut self, text: CowStr<'a>) {
+        let mut start = 0;
+        while let Some(found) = (self.matcher)(&text[start..]) {
+            if found.is_empty() {
+                break;
+            }
+            let found = start + found.start..start + found.end;
+            if start < found.start {
+                self.pending
+                    .push_back(Event::Text(substr(&text, start..found.start)));
+            }
+            self.pending
+                .push_back((self.replacer)(&text[found.clone()]));
+            start = found.end;
+        }
+        if start == 0 {
+            self.pending.push_back(Event::Text(text));
+        } else if start < text.len() {
+            self.pending
+                .push_back(Event::Text(substr(&text, start..text.len())));
+        }
+    }
+}
+
+/// Returns `text[range]`, borrowing from the input if `text` does.
+fn substr<'a>(text: &CowStr<'a>, range: Range<usize>) -> CowStr<'a> {
+    match text {
+        CowStr::Borrowed(s) => CowStr::Borrowed(&s[range]),
+        _ => text[range].to_owned().into(),
+    }
+}
+
+impl<'a, I, M, R> Iterator for ReplaceText<'a, I, M, R>
+where
+    I: Iterator<Item = Event<'a>>,
+    M: FnMut(&str) -> Option<Range<usize>>,
+    R: FnMut(&str) -> Event<'a>,
+{
+    type Item = Event<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(event) = self.pending.pop_front() {
+            return Some(event);
+        }
+        let event = self.inner.next()?;
+        match event {
+            Event::Start(Tag::CodeBlock(_) | Tag::MetadataBlock(_)) => self.verbatim_depth += 1,
+            Event::End(TagEnd::CodeBlock | TagEnd::MetadataBlock(_)) => self.verbatim_depth -= 1,
+            Event::Text(text) if self.verbatim_depth == 0 => {
+                self.split_text(text);
+                return self.pending.pop_front();
+            }
+            _ => {}
+        }
+        Some(event)
+    }
+}
+
+/// A difference reported by [`diff_events`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum NodeDiff {
+    /// A node only present in the new document.
+    Inserted { new_span: Range<usize> },
+    /// A node only present in the old document.
+    Removed { old_span: Range<usize> },
+    /// A node whose tag attributes or leaf content changed.
+    Changed {
+        old_span: Range<usize>,
+        new_span: Range<usize>,
+    },
+}
+
+/// Compares two documents structurally and returns the changed nodes in
+/// document order.
+///
+/// Both inputs are parsed with `options` and adjacent text is merged, so only
+/// differences in the document tree are reported, not differences in line
+/// wrapping or event splitting. Children are aligned with a longest common
+/// subsequence; unmatched nodes of the same kind are paired up and compared
+/// recursively when their tags are equal, and reported as
+/// [`Changed`](NodeDiff::Changed) otherwise.
+///
+/// ```
+/// # use pulldown_cmark::{utils::{diff_events, NodeDiff}, Options};
+/// let old = "# Title\n\nSome *text*.\n";
+/// let new = "# Title\n\nSome *other text*.\n\nMore.\n";
+/// assert_eq!(diff_events(old, new, Options::empty()), [
+///     NodeDiff::Changed { old_span: 15..19, new_span: 15..25 },
+///     NodeDiff::Inserted { new_span: 29..35 },
+/// ]);
+/// ```
+pub fn diff_events(a: &str, b: &str, options: Options) -> Vec<NodeDiff> {
+    let parse = |text| {
+        let events = Parser::new_ext(text, options).into_offset_iter();
+        Ast::from_offset_events(TextMergeWithOffset::new(events))
+    };
+    let mut diffs = Vec::new();
+    diff_nodes(&parse(a).children, &parse(b).children, &mut diffs);
+    diffs
+}
+
+/// Whether two nodes are equal, ignoring their spans.
+fn same_content(a: &Node<'_>, b: &Node<'_>) -> bool {
+    a.kind == b.kind
+        && a.children.len() == b.children.len()
+        && a.children
+            .iter()
+            .zip(&b.children)
+            .all(|(a, b)| same_content(a, b))
+}
+
+fn same_kind(a: &Node<'_>, b: &Node<'_>) -> bool {
+    match (&a.kind, &b.kind) {
+        (NodeKind::Tag(a), NodeKind::Tag(b)) => mem::discriminant(a) == mem::discriminant(b),
+        (NodeKind::Leaf(a), NodeKind::Leaf(b)) => mem::discriminant(a) == mem::discriminant(b),
+        _ => false,
+    }
+}
+
+fn diff_nodes(a: &[Node<'_>], b: &[Node<'_>], diffs: &mut Vec<NodeDiff>) {
+    // lcs[i][j] is the length of the longest common subsequence of a[i..] and b[j..].
+    let mut lcs = vec![vec![0usize; b.len() + 1]; a.len() + 1];
+    for i in (0..a.len()).rev() {
+        for j in (0..b.len()).rev() {
+            lcs[i][j] = if same_content(&a[i], &b[j]) {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let (mut i, mut j) = (0, 0);
+    let (mut gap_a, mut gap_b) = (0, 0);
+    while i < a.len() && j < b.len() {
+        if same_content(&a[i], &b[j]) {
+            diff_gap(&a[gap_a..i], &b[gap_b..j], diffs);
+            i += 1;
+            j += 1;
+            gap_a = i;
+            gap_b = j;
+        } else if lcs[i + 1][j] >= lcs[i][j + 1] {
+            i += 1;
+        } else {
+            j += 1;
+        }
+    }
+    diff_gap(&a[gap_a..], &b[gap_b..], diffs);
+}
+
+/// Reports the differences between two runs of nodes that have no common node.
+fn diff_gap(a: &[Node<'_>], b: &[Node<'_>], diffs: &mut Vec<NodeDiff>) {
+    let (mut i, mut j) = (0, 0);
+    while i < a.len() || j < b.len() {
+        match (a.get(i), b.get(j)) {
+            (Some(old), Some(new)) if same_kind(old, new) => {
+                if old.tag().is_some() && old.kind == new.kind {
+                    diff_nodes(&old.children, &new.children, diffs);
+                } else {
+                    diffs.push(NodeDiff::Changed {
+                        old_span: old.span.clone(),
+                        new_span: new.span.clone(),
+                    });
+                }
+                i += 1;
+                j += 1;
+            }
+            // A node of the old one's kind comes later, so `new` was inserted before it.
+            (Some(old), new) if new.is_none() || !b[j..].iter().any(|n| same_kind(old, n)) => {
+                diffs.push(NodeDiff::Removed {
+                    old_span: old.span.clone(),
+                });
+                i += 1;
+            }
+            (_, Some(new)) => {
+                diffs.push(NodeDiff::Inserted {
+                    new_span: new.span.clone(),
+                });
+                j += 1;
+            }
+            (_, None) => unreachable!(),
+        }
+    }
+}
+
+/// Source ranges of a link or image, as used by the link style converters.
+struct LinkSource<'a> {
+    span: Range<usize>,
+    /// The link text, without the surrounding brackets.
+    text: Range<usize>,
+    link_type: LinkType,
+    id: CowStr<'a>,
+    image: bool,
+}
+
+/// Collects the outermost links and images of `input`; nested ones are part of
+/// the text of the outer one. Links whose text cannot be located in the source
+/// are skipped.
+fn link_sources<'a>(input: &'a str, parser: Parser<'a>) -> (Vec<LinkSource<'a>>, Vec<CowStr<'a>>) {
+    let mut links = Vec::new();
+    let mut nested_ids = Vec::new();
+    let mut current: Option<LinkSource<'a>> = None;
+    let mut depth = 0usize;
+    for (event, range) in parser.into_offset_iter() {
+        let Some(link) = &mut current else {
+            let (link_type, id) = match event {
+                Event::Start(Tag::Link { link_type, id, .. }) => (link_type, id),
+                Event::Start(Tag::Image {
+                    link_type, extras, ..
+                }) => (
+                    link_type,
+                    extras.map_or(CowStr::Borrowed(""), |extras| extras.id),
+                ),
+                _ => continue,
+            };
+            let image = input[range.start..].starts_with('!');
+            let text_start = range.start + 1 + image as usize;
+            current = Some(LinkSource {
+                span: range,
+                text: text_start..text_start,
+                link_type,
+                id,
+                image,
+            });
+            depth = 1;
+            continue;
+        };
+        match event {
+            Event::Start(Tag::Link { id, .. }) => {
+                nested_ids.push(id);
+                depth += 1;
+            }
+            Event::Start(Tag::Image { extras, .. }) => {
+                nested_ids.push(extras.map_or(CowStr::Borrowed(""), |extras| extras.id));
+                depth += 1;
+            }
+            Event::Start(_) => depth += 1,
+            Event::End(_) => depth -= 1,
+            _ => {}
+        }
+        if depth > 0 {
+            link.text.end = link.text.end.max(range.end);
+        } else if let Some(link) = current.take() {
+            if input.as_bytes().get(link.text.end) == Some(&b']') {
+                links.push(link);
+            }
+        }
+    }
+    (links, nested_ids)
+}
+
+/// Source spans of the reference definitions of `parser`, by label.
+fn refdef_spans(parser: &Parser<'_>) -> HashMap<UniCase<String>, Range<usize>> {
+    parser
+        .reference_definitions()
+        .iter()
+        .map(|(label, def)| (UniCase::new(label.to_owned()), def.span.clone()))
+        .collect()
+}
+
+/// Returns the destination and title of a reference definition as written.
+fn refdef_destination(input: &str, span: Range<usize>) -> &str {
+    let source = &input[span];
+    let bytes = source.as_bytes();
+    let mut i = 1;
+    while i < bytes.len() && bytes[i] != b']' {
+        i += if bytes[i] == b'\\' { 2 } else { 1 };
+    }
+    source.get(i + 2..).unwrap_or("").trim()
+}
+
+/// Extends `span` to the whole lines it covers, including the final line ending.
+fn line_span(input: &str, span: Range<usize>) -> Range<usize> {
+    let start = input[..span.start].rfind('\n').map_or(0, |ix| ix + 1);
+    let rest = &input[span.end..];
+    let end = span.end + rest.find('\n').map_or(rest.len(), |ix| ix + 1);
+    start..end
+}
+
+/// Returns the opening fence of a top-level fenced code block that runs to the
+/// end of `input` without a closing fence.
+fn unclosed_code_fence(input: &str, options: Options) -> Option<&str> {
+    let mut depth = 0usize;
+    let mut last_block = None;
+    for (event, range) in Parser::new_ext(input, options).into_offset_iter() {
+        match event {
+            Event::Start(tag) => {
+                if depth == 0 {
+                    let fenced = matches!(tag, Tag::CodeBlock(CodeBlockKind::Fenced(_)));
+                    last_block = fenced.then_some(range);
+                }
+                depth += 1;
+            }
+            Event::End(_) => depth -= 1,
+            _ if depth == 0 => last_block = None,
+            _ => {}
+        }
+    }
+    let block = input[last_block?].trim_end();
+    let opening = block.trim_start_matches(' ');
+    let fence_char = opening.as_bytes()[0];
+    let fence = &opening[..opening.bytes().take_while(|&b| b == fence_char).count()];
+    let mut lines = block.lines();
+    lines.next();
+    let closed = lines.last().is_some_and(|line| {
+        let line = line.trim();
+        line.len() >= fence.len() && line.bytes().all(|b| b == fence_char)
+    });
+    (!closed).then_some(fence)
+}
+
+/// Rewrites inline links and images as reference-style ones, with the new
+/// definitions collected at the end of the document.
+///
+/// Destinations and titles are copied from the source as written. Links that
+/// share a destination and title share a definition, and existing definitions
+/// are reused. New labels are numbers that do not clash with existing labels.
+/// Links and images nested in a converted link are kept as they are.
+///
+/// ```
+/// # use pulldown_cmark::{utils::to_reference_links, Options};
+/// let input = "See [docs](https://example.com \"Docs\") and ![logo](logo.png).\n";
+/// assert_eq!(
+///     to_reference_links(input, Options::empty()),
+///     "See [docs][1] and ![logo][2].\n\n[1]: https://example.com \"Docs\"\n[2]: logo.png\n"
+/// );
+/// ```
+pub fn to_reference_links(input: &str, options: Options) -> String {
+    let parser = Parser::new_ext(input, options);
+    let refdefs = refdef_spans(&parser);
+    let mut labels: HashMap<&str, String> = HashMap::new();
+    for (label, span) in &refdefs {
+        labels
+            .entry(refdef_destination(input, span.clone()))
+            .or_insert_with(|| label.as_ref().to_owned());
+    }
+
+    let (links, _) = link_sources(input, parser);
+    let mut edits = Vec::new();
+    let mut definitions = String::new();
+    let mut next_label = 1;
+    for link in links {
+        if link.link_type != LinkType::Inline {
+            continue;
+        }
+        // The destination starts after the `](` that ends the link text.
+        let destination = input[link.text.end + 2..link.span.end - 1].trim();
+        let destination = if destination.is_empty() {
+            "<>"
+        } else {
+            destination
+        };
+        let label = labels.entry(destination).or_insert_with(|| {
+            while refdefs.contains_key(&UniCase::new(next_label.to_string())) {
+                next_label += 1;
+            }
+            let label = next_label.to_string();
+            next_label += 1;
+            definitions.push_str(&alloc::format!("[{}]: {}\n", label, destination));
+            label
+        });
+        let bang = if link.image { "!" } else { "" };
+        let text = &input[link.text];
+        edits.push(TextEdit::new(
+            link.span,
+            alloc::format!("{}[{}][{}]", bang, text, label),
+        ));
+    }
+
+    let mut output = apply_edits(input, &edits);
+    if !definitions.is_empty() {
+        if !output.is_empty() && !output.ends_with('\n') {
+            output.push('\n');
+        }
+        // Definitions after an unclosed fence would be part of the code block.
+        if let Some(fence) = unclosed_code_fence(&output, options).map(str::to_owned) {
+            output.push_str(&fence);
+            output.push('\n');
+        }
+        if !output.is_empty() {
+            output.push('\n');
+        }
+        output.push_str(&definitions);
+    }
+    output
+}
+
+/// Rewrites reference-style links and images as inline ones and removes the
+/// definitions that are no longer used.
+///
+/// Destinations and titles are copied from the definitions as written.
+/// Definitions that are not referenced, or that are still referenced by a link
+/// nested in a converted one, are kept.
+///
+/// ```
+/// # use pulldown_cmark::{utils::to_inline_links, Options};
+/// let input = "See [docs][d] and [d].\n\n[d]: https://example.com \"Docs\"\n";
+/// assert_eq!(
+///     to_inline_links(input, Options::empty()),
+///     "See [docs](https://example.com \"Docs\") and [d](https://example.com \"Docs\").\n\n"
+/// );
+/// ```
+pub fn to_inline_links(input: &str, options: Options) -> String {
+    let parser = Parser::new_ext(input, options);
+    let refdefs = refdef_spans(&parser);
+    let (links, nested_ids) = link_sources(input, parser);
+
+    let mut edits = Vec::new();
+    let mut used = Vec::new();
+    for link in links {
+        if !matches!(
+            link.link_type,
+            LinkType::Reference | LinkType::Collapsed | LinkType::Shortcut
+        ) {
+            continue;
+        }
+        let Some(span) = refdefs.get(&UniCase::new(link.id.to_string())) else {
+            continue;
+        };
+        let bang = if link.image { "!" } else { "" };
+        let text = &input[link.text];
+        let destination = refdef_destination(input, span.clone());
+        edits.push(TextEdit::new(
+            link.span,
+            alloc::format!("{}[{}]({})", bang, text, destination),
+        ));
+        used.push(span.clone());
+    }
+
+    for id in nested_ids {
+        if let Some(nested) = refdefs.get(&UniCase::new(id.to_string())) {
+            used.retain(|span| span != nested);
+        }
+    }
+    used.sort_by_key(|span| span.start);
+    used.dedup();
+    for span in used {
+        edits.push(TextEdit::new(line_span(input, span), ""));
+    }
+    apply_edits(input, &edits)
+}
+
+/// A replacement of a range of the markdown source, as produced by
+/// [`fix_bare_urls`] and [`fix_diagnostic`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TextEdit {
+    /// The replaced byte range. An empty range is an insertion.
+    pub range: Range<usize>,
+    /// The text that replaces the range.
+    pub replacement: String,
+}
+
+impl TextEdit {
+    /// Creates an edit that replaces `range` with `replacement`.
+    pub fn new(range: Range<usize>, replacement: impl Into<String>) -> Self {
+        Self {
+            range,
+            replacement: replacement.into(),
+        }
+    }
+}
+
+/// Applies `edits` to `input`.
+///
+/// Edits may be given in any order; insertions at the same offset are applied
+/// in the order given. An edit that overlaps an earlier one is skipped, so the
+/// edits of several fixes can be applied together.
+///
+/// ```
+/// # use pulldown_cmark::utils::{apply_edits, TextEdit};
+/// let edits = [
+///     TextEdit::new(6..11, "there"),
+///     TextEdit::new(0..5, "Hi"),
+///     TextEdit::new(8..11, "skipped"),
+/// ];
+/// assert_eq!(apply_edits("Hello world!", &edits), "Hi there!");
+/// ```
+pub fn apply_edits(input: &str, edits: &[TextEdit]) -> String {
+    let mut sorted: Vec<&TextEdit> = edits.iter().collect();
+    sorted.sort_by_key(|edit| edit.range.start);
+    let mut output = String::with_capacity(input.len());
+    let mut copied = 0;
+    for edit in sorted {
+        if edit.range.start < copied {
+            continue;
+        }
+        output.push_str(&input[copied..edit.range.start]);
+        output.push_str(&edit.replacement);
+        copied = edit.range.end;
+    }
+    output.push_str(&input[copied..]);
+    output
+}
+
+/// Returns the edits that turn the bare URLs and email addresses of `input`
+/// into autolinks.
+///
+/// URLs with a scheme and email addresses are wrapped in angle brackets.
+/// `www.` URLs become inline links, so that the link text stays as written.
+/// The autolink literal extension does not need to be enabled in `options`.
+/// URLs in link text are left alone, and so is any URL whose replacement would
+/// not parse as the same link in the fixed document.
+///
+/// ```
+/// # use pulldown_cmark::{utils::{apply_edits, fix_bare_urls}, Options};
+/// let input = "See https://example.com, www.example.org or me@example.com.\n";
+/// let edits = fix_bare_urls(input, Options::empty());
+/// assert_eq!(
+///     apply_edits(input, &edits),
+///     "See <https://example.com>, [www.example.org](http://www.example.org) or <me@example.com>.\n"
+/// );
+/// ```
+pub fn fix_bare_urls(input: &str, options: Options) -> Vec<TextEdit> {
+    let options = options | Options::ENABLE_AUTOLINK_LITERALS;
+    let mut edits = Vec::new();
+    let mut dest_urls = Vec::new();
+    let mut nesting = 0;
+    for (event, range) in Parser::new_ext(input, options).into_offset_iter() {
+        match event {
+            Event::Start(Tag::Link {
+                link_type,
+                dest_url,
+                ..
+            }) => {
+                nesting += 1;
+                let source = &input[range.clone()];
+                let replacement = match link_type {
+                    _ if nesting > 1 => continue,
+                    LinkType::AutolinkLiteral if *dest_url != *source => {
+                        alloc::format!("[{}]({})", source, dest_url)
+                    }
+                    LinkType::AutolinkLiteral | LinkType::EmailLiteral => {
+                        alloc::format!("<{}>", source)
+                    }
+                    _ => continue,
+                };
+                edits.push(TextEdit::new(range, replacement));
+                dest_urls.push(dest_url);
+            }
+            Event::Start(Tag::Image { .. }) => nesting += 1,
+            Event::End(TagEnd::Link | TagEnd::Image) => nesting -= 1,
+            _ => {}
+        }
+    }
+
+    // Keep only the edits whose replacement parses as the same link in the
+    // fixed document.
+    let fixed = apply_edits(input, &edits);
+    let links: HashMap<Range<usize>, CowStr<'_>> = Parser::new_ext(&fixed, options)
+        .into_offset_iter()
+        .filter_map(|(event, range)| match event {
+            Event::Start(Tag::Link { dest_url, .. }) => Some((range, dest_url)),
+            _ => None,
+        })
+        .collect();
+    let mut shift = 0isize;
+    edits
+        .into_iter()
+        .zip(dest_urls)
+        .filter(|(edit, dest_url)| {
+            let start = edit.range.start.wrapping_add_signed(shift);
+            shift += edit.replacement.len() as isize - edit.range.len() as isize;
+            links.get(&(start..start + edit.replacement.len())) == Some(dest_url)
+        })
+        .map(|(edit, _)| edit)
+        .collect()
+}
+
+/// Returns an edit that fixes `diagnostic`, if one is known for its kind.
+///
+/// An unclosed code fence gets a matching closing fence where its container
+/// ends, and a duplicate reference definition is removed. `input` must be the
+/// source the diagnostic was reported for.
+///
+/// ```
+/// # use pulldown_cmark::{utils::{apply_edits, fix_diagnostic}, Options, Parser};
+/// let input = "> ~~~~\n> code\n\nafter\n";
+/// let mut parser = Parser::new_ext(input, Options::ENABLE_DIAGNOSTICS);
+/// parser.by_ref().for_each(drop);
+/// let edits: Vec<_> = parser
+///     .diagnostics()
+///     .iter()
+///     .filter_map(|diagnostic| fix_diagnostic(input, diagnostic))
+///     .collect();
+/// assert_eq!(apply_edits(input, &edits), "> ~~~~\n> code\n> ~~~~\n\nafter\n");
+/// ```
+pub fn fix_diagnostic(input: &str, diagnostic: &Diagnostic) -> Option<TextEdit> {
+    let span = diagnostic.span.clone();
+    let line_start = input[..span.start].rfind('\n').map_or(0, |ix| ix + 1);
+    let prefix = &input[line_start..span.start];
+    match diagnostic.kind {
+        DiagnosticKind::UnclosedCodeFence => {
+            let opening = input[span.start..].trim_start_matches(' ');
+            let fence_char = opening.chars().next()?;
+            let fence_len = opening.len() - opening.trim_start_matches(fence_char).len();
+            let mut closing = String::new();
+            if span.end > 0 && input.as_bytes()[span.end - 1] != b'\n' {
+                closing.push('\n');
+            }
+            // Keep the block quote markers of the opening line and indent past
+            // any list markers.
+            closing.extend(prefix.chars().map(|c| match c {
+                '>' | '\t' => c,
+                _ => ' ',
+            }));
+            closing.extend(core::iter::repeat(fence_char).take(fence_len));
+            closing.push('\n');
+            Some(TextEdit::new(span.end..span.end, closing))
+        }
+        DiagnosticKind::DuplicateReferenceDefinition => {
+            // A definition alone on its line is removed with the line.
+            let range = if prefix.chars().all(|c| matches!(c, ' ' | '\t' | '>')) {
+                line_span(input, span)
+            } else {
+                span
+            };
+            Some(TextEdit::new(range, ""))
+        }
+        _ => None,
+    }
+}
+
+/// The contents of a table, as returned by [`extract_tables`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct TableData {
+    /// The plain text of the header cells.
+    pub headers: Vec<String>,
+    /// The plain text of the cells of each body row.
+    pub rows: Vec<Vec<String>>,
+    /// The alignment of each column.
+    pub alignments: Vec<Alignment>,
+    /// The whole table in the markdown source.
+    pub span: Range<usize>,
+}
+
+impl TableData {
+    /// Formats the header and rows as CSV with `\n` line endings.
+    ///
+    /// Fields containing a comma, a quote or a line break are quoted, with
+    /// quotes doubled, as described in RFC 4180.
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::new();
+        for row in core::iter::once(&self.headers).chain(&self.rows) {
+            for (i, cell) in row.iter().enumerate() {
+                if i > 0 {
+                    csv.push(',');
+                }
+                if cell.contains([',', '"', '\n', '\r']) {
+                    csv.push('"');
+                    csv.push_str(&cell.replace('"', "\"\""));
+                    csv.push('"');
+                } else {
+                    csv.push_str(cell);
+                }
+            }
+            csv.push('\n');
+        }
+        csv
+    }
+}
+
+/// Returns the header, body rows and column alignments of every table in the
+/// document, in document order.
+///
+/// Cells hold the plain text of their contents, including inline code and
+/// math; line breaks become spaces. Tables are only recognized with
+/// [`Options::ENABLE_TABLES`].
+///
+/// ```
+/// # use pulldown_cmark::{utils::extract_tables, Alignment, Options};
+/// let input = "| Name | Score |\n|:-----|------:|\n| *Ann* | 10 |\n| Bob, Jr. | `7` |\n";
+/// let tables = extract_tables(input, Options::ENABLE_TABLES);
+/// assert_eq!(tables[0].headers, ["Name", "Score"]);
+/// assert_eq!(tables[0].rows, [["Ann", "10"], ["Bob, Jr.", "7"]]);
+/// assert_eq!(tables[0].alignments, [Alignment::Left, Alignment::Right]);
+/// assert_eq!(tables[0].to_csv(), "Name,Score\nAnn,10\n\"Bob, Jr.\",7\n");
+/// ```
+pub fn extract_tables(input: &str, options: Options) -> Vec<TableData> {
+    let mut tables = Vec::new();
+    let mut table: Option<TableData> = None;
+    let mut row = Vec::new();
+    let mut cell: Option<String> = None;
+    for (event, range) in Parser::new_ext(input, options).into_offset_iter() {
+        match event {
+            Event::Start(Tag::Table(alignments)) => {
+                table = Some(TableData {
+                    headers: Vec::new(),
+                    rows: Vec::new(),
+                    alignments,
+                    span: range,
+                });
+            }
+            Event::End(TagEnd::Table) => tables.extend(table.take()),
+            Event::Start(Tag::TableCell(_)) => cell = Some(String::new()),
+            Event::End(TagEnd::TableCell) => row.extend(cell.take()),
+            Event::End(TagEnd::TableHead) => {
+                if let Some(table) = &mut table {
+                    table.headers = mem::take(&mut row);
+                }
+            }
+            Event::End(TagEnd::TableRow) => {
+                if let Some(table) = &mut table {
+                    table.rows.push(mem::take(&mut row));
+                }
+            }
+            Event::Text(text)
+            | Event::Code(text, _)
+            | Event::InlineMath(text, _)
+            | Event::DisplayMath(text, _) => {
+                if let Some(cell) = &mut cell {
+                    cell.push_str(&text);
+                }
+            }
+            Event::SoftBreak | Event::HardBreak(_) => {
+                if let Some(cell) = &mut cell {
+                    cell.push(' ');
+                }
+            }
+            _ => {}
+        }
+    }
+    tables
+}
+
+/// A code block, as returned by [`extract_code_blocks`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CodeBlockInfo<'a> {
+    /// The language of a fenced block: the first word of its info string, up to
+    /// a space or a comma. Empty for indented blocks and fences without one.
+    pub language: String,
+    /// The whole info string of a fenced block, such as `rust,ignore`.
+    pub info: CowStr<'a>,
+    /// The code, with a trailing newline after each line.
+    pub code: String,
+    /// The whole block in the markdown source, including any fences.
+    pub span: Range<usize>,
+}
+
+/// Returns the fenced and indented code blocks of the document in document
+/// order, for example to run or extract code examples.
+///
+/// With `lang_filter`, only fenced blocks whose language matches it
+/// (ignoring ASCII case) are returned.
+///
+/// ```
+/// # use pulldown_cmark::{utils::extract_code_blocks, Options};
+/// let input = "```rust,ignore\nlet x = 1;\n```\n\n    indented\n\n~~~ python\nprint()\n~~~\n";
+/// let blocks = extract_code_blocks(input, Options::empty(), None);
+/// assert_eq!(blocks.len(), 3);
+/// assert_eq!(blocks[0].language, "rust");
+/// assert_eq!(blocks[0].info.as_ref(), "rust,ignore");
+/// assert_eq!(blocks[1].code, "indented\n");
+/// let python = extract_code_blocks(input, Options::empty(), Some("Python"));
+/// assert_eq!(python[0].code, "print()\n");
+/// ```
+pub fn extract_code_blocks<'a>(
+    input: &'a str,
+    options: Options,
+    lang_filter: Option<&str>,
+) -> Vec<CodeBlockInfo<'a>> {
+    let mut blocks = Vec::new();
+    let mut current: Option<CodeBlockInfo<'a>> = None;
+    for (event, range) in Parser::new_ext(input, options).into_offset_iter() {
+        match event {
+            Event::Start(Tag::CodeBlock(kind)) => {
+                let info = match kind {
+                    CodeBlockKind::Fenced(info) => info,
+                    CodeBlockKind::Indented => "".into(),
+                };
+                current = Some(CodeBlockInfo {
+                    language: code_block_language(&info).to_owned(),
+                    info,
+                    code: String::new(),
+                    span: range,
+                });
+            }
+            Event::Text(text) => {
+                if let Some(block) = &mut current {
+                    block.code.push_str(&text);
+                }
+            }
+            Event::End(TagEnd::CodeBlock) => {
+                let Some(block) = current.take() else {
+                    continue;
+                };
+                let matches_filter = lang_filter.map_or(true, |lang| {
+                    !block.language.is_empty() && block.language.eq_ignore_ascii_case(lang)
+                });
+                if matches_filter {
+                    blocks.push(block);
+                }
+            }
+            _ => {}
+        }
+    }
+    blocks
+}
+
+/// The first word of a fenced code block's info string, up to a space or a comma.
+fn code_block_language(info: &str) -> &str {
+    info.split([' ', '\t', ',']).next().unwrap_or("")
+}
+
+/// Element counts returned by [`element_census`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ElementCensus {
+    /// The number of elements per tag, such as `TagEnd::Heading(HeadingLevel::H2)`
+    /// for second level headings or `TagEnd::List(true)` for ordered lists.
+    pub tags: BTreeMap<TagEnd, usize>,
+    /// The number of code blocks per language, as in [`CodeBlockInfo::language`].
+    /// Indented blocks and fences without a language are counted under `""`.
+    pub code_blocks: BTreeMap<String, usize>,
+    /// The number of links per link type. Images are not included.
+    pub links: BTreeMap<LinkType, usize>,
+}
+
+impl ElementCensus {
+    /// The number of elements with the given tag.
+    pub fn count(&self, tag: TagEnd) -> usize {
+        self.tags.get(&tag).copied().unwrap_or(0)
+    }
+
+    /// The number of headings of the given level.
+    pub fn headings(&self, level: HeadingLevel) -> usize {
+        self.count(TagEnd::Heading(level))
+    }
+}
+
+/// Counts the elements of a document by kind: every tag, code blocks by
+/// language and links by type, for corpus analysis and style dashboards.
+///
+/// ```
+/// # use pulldown_cmark::{utils::element_census, HeadingLevel, LinkType, Options, TagEnd};
+/// let input = "# A\n\n## B\n\n## C [x](/x) <https://y.org>\n\n```rust\nfn main() {}\n```\n";
+/// let census = element_census(input, Options::empty());
+/// assert_eq!(census.headings(HeadingLevel::H2), 2);
+/// assert_eq!(census.count(TagEnd::CodeBlock), 1);
+/// assert_eq!(census.code_blocks["rust"], 1);
+/// assert_eq!(census.links[&LinkType::Autolink], 1);
+/// ```
+pub fn element_census(input: &str, options: Options) -> ElementCensus {
+    let mut census = ElementCensus::default();
+    for event in Parser::new_ext(input, options) {
+        let Event::Start(tag) = event else {
+            continue;
+        };
+        match &tag {
+            Tag::CodeBlock(kind) => {
+                let language = match kind {
+                    CodeBlockKind::Fenced(info) => code_block_language(info),
+                    CodeBlockKind::Indented => "",
+                };
+                *census.code_blocks.entry(language.to_owned()).or_default() += 1;
+            }
+            Tag::Link { link_type, .. } => *census.links.entry(*link_type).or_default() += 1,
+            _ => {}
+        }
+        *census.tags.entry(tag.to_end()).or_default() += 1;
+    }
+    census
+}
+
+/// The kind of a [`DocumentSymbol`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SymbolKind {
+    Heading(HeadingLevel),
+    /// A fenced code block with a language.
+    CodeBlock,
+    FootnoteDefinition,
+    LinkReferenceDefinition,
+}
+
+/// An outline entry returned by [`document_symbols`], shaped like the
+/// `DocumentSymbol` of the Language Server Protocol.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DocumentSymbol {
+    /// The heading text, code block language or definition label.
+    pub name: String,
+    /// The destination of a link reference definition.
+    pub detail: Option<String>,
+    pub kind: SymbolKind,
+    /// The whole element. For a heading this is its section, which ends at
+    /// the next heading of the same or a higher level.
+    pub range: Range<usize>,
+    /// The part to reveal when the symbol is selected: the heading text, the
+    /// code block language or the bracketed label.
+    pub selection_range: Range<usize>,
+    /// The symbols inside a heading's section.
+    pub children: Vec<DocumentSymbol>,
+}
+
+/// Returns the outline of a document for editors: headings nested by level,
+/// with the fenced code blocks, footnote definitions and link reference
+/// definitions of each section as children.
+///
+/// ```
+/// # use pulldown_cmark::{utils::{document_symbols, SymbolKind}, HeadingLevel, Options};
+/// let input = "# Guide\n\n## Install\n\n```sh\ncargo add pulldown-cmark\n```\n\n[docs]: https://docs.rs\n";
+/// let symbols = document_symbols(input, Options::empty());
+/// assert_eq!(symbols.len(), 1);
+/// assert_eq!(symbols[0].name, "Guide");
+/// assert_eq!(symbols[0].range, 0..input.len());
+///
+/// let install = &symbols[0].children[0];
+/// assert_eq!(install.kind, SymbolKind::Heading(HeadingLevel::H2));
+/// assert_eq!(&input[install.selection_range.clone()], "Install");
+/// assert_eq!(install.children[0].name, "sh");
+/// assert_eq!(install.children[1].detail.as_deref(), Some("https://docs.rs"));
+/// ```
+pub fn document_symbols(input: &str, options: Options) -> Vec<DocumentSymbol> {
+    let symbol = |name: &str, kind, range: Range<usize>, selection_range| DocumentSymbol {
+        name: name.to_owned(),
+        detail: None,
+        kind,
+        range,
+        selection_range,
+        children: Vec::new(),
+    };
+    let mut symbols = Vec::new();
+    let mut heading: Option<(DocumentSymbol, Option<Range<usize>>)> = None;
+    let mut iter = Parser::new_ext(input, options).into_offset_iter();
+    for (event, range) in &mut iter {
+        match event {
+            Event::Start(Tag::Heading { level, .. }) => {
+                heading = Some((symbol("", SymbolKind::Heading(level), range, 0..0), None));
+                continue;
+            }
+            Event::End(TagEnd::Heading(_)) => {
+                if let Some((mut symbol, selection)) = heading.take() {
+                    symbol.selection_range = selection.unwrap_or_else(|| symbol.range.clone());
+                    symbols.push(symbol);
+                }
+                continue;
+            }
+            _ => {}
+        }
+        if let Some((symbol, selection)) = &mut heading {
+            if let Event::Text(text) | Event::Code(text, _) = &event {
+                symbol.name.push_str(text);
+            }
+            *selection = Some(match selection.take() {
+                Some(selection) => selection.start.min(range.start)..selection.end.max(range.end),
+                None => range,
+            });
+            continue;
+        }
+        match event {
+            Event::Start(Tag::CodeBlock(CodeBlockKind::Fenced(info))) => {
+                let language = code_block_language(&info);
+                if language.is_empty() {
+                    continue;
+                }
+                let first_line = &input[range.clone()];
+                let first_line = &first_line[..first_line.find('\n').unwrap_or(first_line.len())];
+                let start = range.start + first_line.find(language).unwrap_or(0);
+                let selection_range = start..start + language.len();
+                symbols.push(symbol(
+                    language,
+                    SymbolKind::CodeBlock,
+                    range,
+                    selection_range,
+                ));
+            }
+            Event::Start(Tag::FootnoteDefinition(label)) => {
+                let selection_range =
+                    range.start..range.start + bracketed_len(&input[range.clone()]);
+                symbols.push(symbol(
+                    &label,
+                    SymbolKind::FootnoteDefinition,
+                    range,
+                    selection_range,
+                ));
+            }
+            _ => {}
+        }
+    }
+    for (label, def) in iter.reference_definitions().iter() {
+        let span = def.span.clone();
+        let selection_range = span.start..span.start + bracketed_len(&input[span.clone()]);
+        let mut definition = symbol(
+            label,
+            SymbolKind::LinkReferenceDefinition,
+            span,
+            selection_range,
+        );
+        definition.detail = Some(def.dest.to_string());
+        symbols.push(definition);
+    }
+    symbols.sort_by_key(|symbol| symbol.range.start);
+
+    // A heading's section ends where the next heading of the same or a higher
+    // level starts.
+    let mut open: Vec<usize> = Vec::new();
+    for ix in 0..symbols.len() {
+        let SymbolKind::Heading(level) = symbols[ix].kind else {
+            continue;
+        };
+        while let Some(&top) = open.last() {
+            match symbols[top].kind {
+                SymbolKind::Heading(top_level) if top_level >= level => {
+                    symbols[top].range.end = symbols[ix].range.start;
+                    open.pop();
+                }
+                _ => break,
+            }
+        }
+        open.push(ix);
+    }
+    for ix in open {
+        symbols[ix].range.end = input.len();
+    }
+
+    fn close(stack: &mut Vec<DocumentSymbol>, roots: &mut Vec<DocumentSymbol>) {
+        if let Some(done) = stack.pop() {
+            match stack.last_mut() {
+                Some(parent) => parent.children.push(done),
+                None => roots.push(done),
+            }
+        }
+    }
+    let mut roots = Vec::new();
+    let mut stack: Vec<DocumentSymbol> = Vec::new();
+    for symbol in symbols {
+        while stack
+            .last()
+            .is_some_and(|top| symbol.range.start >= top.range.end)
+        {
+            close(&mut stack, &mut roots);
+        }
+        if let SymbolKind::Heading(_) = symbol.kind {
+            stack.push(symbol);
+        } else if let Some(parent) = stack.last_mut() {
+            parent.children.push(symbol);
+        } else {
+            roots.push(symbol);
+        }
+    }
+    while !stack.is_empty() {
+        close(&mut stack, &mut roots);
+    }
+    roots
+}
+
+/// Returns the elements containing the byte `offset`, from the outermost
+/// block down to the innermost element, each with its span.
+///
+/// Spans are half-open, so an offset just past the end of an element is not
+/// inside it. The result is empty if the offset is outside every element,
+/// for example on a blank line between paragraphs.
+///
+/// ```
+/// # use pulldown_cmark::{utils::node_at, Options, Tag};
+/// let input = "> Some *emphasized* text\n";
+/// let path = node_at(input, Options::empty(), input.find("ph").unwrap());
+/// let tags: Vec<_> = path.iter().map(|(tag, _)| tag.clone()).collect();
+/// let quote = Tag::BlockQuote { kind: None, folded: None, title: None };
+/// assert_eq!(tags, [quote, Tag::Paragraph(None), Tag::Emphasis]);
+/// assert_eq!(&input[path[2].1.clone()], "*emphasized*");
+/// ```
+pub fn node_at(input: &str, options: Options, offset: usize) -> Vec<(Tag<'_>, Range<usize>)> {
+    let mut path = Vec::new();
+    let mut depth = 0;
+    for (event, range) in Parser::new_ext(input, options).into_offset_iter() {
+        if range.start > offset {
+            break;
+        }
+        match event {
+            Event::Start(tag) => {
+                // Only descend into children of the innermost match so far.
+                if path.len() == depth && range.contains(&offset) {
+                    path.push((tag, range));
+                }
+                depth += 1;
+            }
+            Event::End(_) => {
+                depth -= 1;
+                if depth < path.len() {
+                    break;
+                }
+            }
+            _ => {}
+        }
+    }
+    path
+}
+
+/// What the cursor is placed in, as reported by [`completion_context`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CompletionKind {
+    /// The destination of an inline link or image, `[text](here)`.
+    LinkDestination,
+    /// The label of a full reference link, `[text][here]`.
+    ReferenceLabel,
+    /// The info string of a code fence, usually a language.
+    CodeFenceInfo,
+    /// The target of a wikilink, `[[here]]`, up to any `#` or `|`.
+    WikilinkTarget,
+    /// The label of a footnote reference, `[^here]`.
+    FootnoteLabel,
+}
+
+/// Where completions apply, as returned by [`completion_context`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CompletionContext<'a> {
+    pub kind: CompletionKind,
+    /// The text between the start of the completed item and the cursor.
+    pub prefix: &'a str,
+    /// The span a chosen completion replaces: from the start of the item to
+    /// its closing delimiter or the end of the line.
+    pub range: Range<usize>,
+}
+
+/// Reports whether the cursor at byte `offset` is inside a link destination,
+/// reference label, code fence info string, wikilink target or footnote
+/// label, so that an editor can offer matching completions.
+///
+/// Links are usually incomplete while they are typed, so apart from the code
+/// fence info string, the item is found by looking back from the cursor on
+/// its line. Nothing is reported inside code and HTML.
+///
+/// ```
+/// # use pulldown_cmark::{utils::{completion_context, CompletionKind}, Options};
+/// let input = "See [the guide](./docs/gu";
+/// let context = completion_context(input, Options::empty(), input.len()).unwrap();
+/// assert_eq!(context.kind, CompletionKind::LinkDestination);
+/// assert_eq!(context.prefix, "./docs/gu");
+///
+/// let input = "```ru\n";
+/// let context = completion_context(input, Options::empty(), 5).unwrap();
+/// assert_eq!(context.kind, CompletionKind::CodeFenceInfo);
+/// assert_eq!(context.prefix, "ru");
+/// ```
+pub fn completion_context(
+    input: &str,
+    options: Options,
+    offset: usize,
+) -> Option<CompletionContext<'_>> {
+    let line_start = input[..offset].rfind('\n').map_or(0, |ix| ix + 1);
+    let line_end = input[offset..]
+        .find('\n')
+        .map_or(input.len(), |ix| offset + ix);
+
+    for (event, range) in Parser::new_ext(input, options).into_offset_iter() {
+        if range.start > offset {
+            break;
+        }
+        match event {
+            // The end is inclusive, so that typing at the end of an unclosed
+            // fence still counts.
+            Event::Start(Tag::CodeBlock(kind)) if offset <= range.end => {
+                let CodeBlockKind::Fenced(_) = kind else {
+                    return None;
+                };
+                let fence_line = &input[range.start..];
+                let fence_line = &fence_line[..fence_line.find('\n').unwrap_or(fence_line.len())];
+                let indent = fence_line.len() - fence_line.trim_start_matches(' ').len();
+                let fence = fence_line[indent..]
+                    .bytes()
+                    .take_while(|&b| b == b'`' || b == b'~')
+                    .count();
+                let info_start = range.start + indent + fence;
+                let info_end = range.start + fence_line.len();
+                if !(info_start..=info_end).contains(&offset) {
+                    return None;
+                }
+                return Some(CompletionContext {
+                    kind: CompletionKind::CodeFenceInfo,
+                    prefix: &input[info_start..offset],
+                    range: info_start..info_end,
+                });
+            }
+            Event::Code(_, _)
+            | Event::Html(_)
+            | Event::InlineHtml(_)
+            | Event::InlineMath(..)
+            | Event::DisplayMath(..)
+                if range.contains(&offset) =>
+            {
+                return None
+            }
+            _ => {}
+        }
+    }
+
+    let before = &input[line_start..offset];
+    let candidates = [
+        (
+            "[[",
+            CompletionKind::WikilinkTarget,
+            "]|#",
+            options.contains(Options::ENABLE_WIKILINKS),
+        ),
+        (
+            "[^",
+            CompletionKind::FootnoteLabel,
+            "]",
+            options.contains(Options::ENABLE_FOOTNOTES),
+        ),
+        ("](", CompletionKind::LinkDestination, ") \t", true),
+        ("][", CompletionKind::ReferenceLabel, "]", true),
+    ];
+    let (start, kind, stops) = candidates
+        .into_iter()
+        .filter(|&(.., enabled)| enabled)
+        .filter_map(|(opener, kind, stops, _)| {
+            let start = before.rfind(opener)? + opener.len();
+            let open = !before[start..].contains(|c| stops.contains(c));
+            open.then_some((line_start + start, kind, stops))
+        })
+        .max_by_key(|&(start, ..)| start)?;
+    let end = input[offset..line_end]
+        .find(|c| stops.contains(c))
+        .map_or(line_end, |ix| offset + ix);
+    Some(CompletionContext {
+        kind,
+        prefix: &input[start..offset],
+        range: start..end,
+    })
+}
+
+/// A heading's section, as returned by [`section`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Section<'a> {
+    pub level: HeadingLevel,
+    /// The heading's anchor: its custom id or the slug of its text,
+    /// deduplicated like in [`check_anchors`].
+    pub anchor: String,
+    /// The heading itself.
+    pub heading: Range<usize>,
+    /// The heading and everything up to the next heading of the same or a
+    /// higher level, including subsections.
+    pub span: Range<usize>,
+    /// The markdown between the heading and the end of the section.
+    pub content: &'a str,
+}
+
+/// Finds the section owned by a heading, given its anchor (with or without a
+/// leading `#`) or, failing that, its exact text.
+///
+/// This is the boundary logic for transcluding sections: a section ends at
+/// the next heading of the same or a higher level, or at the end of the
+/// document.
+///
+/// ```
+/// # use pulldown_cmark::{utils::section, Options};
+/// let input = "# Guide\n\n## Install\n\nRun it.\n\n### Linux\n\nApt.\n\n## Usage\n";
+/// let install = section(input, Options::empty(), "#install").unwrap();
+/// assert_eq!(install.content, "\nRun it.\n\n### Linux\n\nApt.\n\n");
+/// assert_eq!(section(input, Options::empty(), "Usage").unwrap().anchor, "usage");
+/// ```
+pub fn section<'a>(input: &'a str, options: Options, target: &str) -> Option<Section<'a>> {
+    let mut headings = Vec::new();
+    let mut slugger = Slugger::new();
+    let mut heading = None;
+    for (event, range) in Parser::new_ext(input, options).into_offset_iter() {
+        match event {
+            Event::Start(Tag::Heading { level, id, .. }) => {
+                heading = Some((level, id, String::new(), range))
+            }
+            Event::Text(text) | Event::Code(text, _) => {
+                if let Some((.., title, _)) = &mut heading {
+                    title.push_str(&text);
+                }
+            }
+            Event::End(TagEnd::Heading(_)) => {
+                let Some((level, id, title, range)) = heading.take() else {
+                    continue;
+                };
+                let anchor = match id {
+                    Some(id) => slugger.unique(&id),
+                    None => slugger.slug(&title),
+                };
+                headings.push((level, anchor, title, range));
+            }
+            _ => {}
+        }
+    }
+
+    let anchor = target.strip_prefix('#').unwrap_or(target);
+    let ix = headings
+        .iter()
+        .position(|(_, a, ..)| a == anchor)
+        .or_else(|| headings.iter().position(|(.., title, _)| title == target))?;
+    let (level, anchor, _, heading) = headings[ix].clone();
+    let end = headings[ix + 1..]
+        .iter()
+        .find(|(next_level, ..)| *next_level <= level)
+        .map_or(input.len(), |(.., range)| range.start);
+    Some(Section {
+        level,
+        anchor,
+        span: heading.start..end,
+        content: &input[heading.end..end],
+        heading,
+    })
+}
+
+/// Returns the length of the bracketed label that `text` starts with,
+/// including the brackets, or the length of `text` if it is not closed.
+fn bracketed_len(text: &str) -> usize {
+    let bytes = text.as_bytes();
+    let mut ix = 1;
+    while ix < bytes.len() {
+        match bytes[ix] {
+            b'\\' => ix += 2,
+            b']' => return ix + 1,
+            _ => ix += 1,
+        }
+    }
+    text.len()
+}
+
+/// One document of an input split by [`split_documents`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DocumentPart<'a> {
+    /// The markdown of the document, without the separator lines.
+    pub source: &'a str,
+    /// Where `source` is in the whole input.
+    pub range: Range<usize>,
+}
+
+impl<'a> DocumentPart<'a> {
+    /// Parses the document on its own. Offsets are relative to `source`.
+    pub fn parser(&self, options: Options) -> Parser<'a> {
+        Parser::new_ext(self.source, options)
+    }
+
+    /// Parses the document on its own, with offsets relative to the whole
+    /// input.
+    pub fn offset_iter(
+        &self,
+        options: Options,
+    ) -> impl Iterator<Item = (Event<'a>, Range<usize>)> + 'a {
+        let start = self.range.start;
+        self.parser(options)
+            .into_offset_iter()
+            .map(move |(event, range)| (event, range.start + start..range.end + start))
+    }
+}
+
+/// Splits an input holding several documents, such as a slide deck or
+/// concatenated notes, at the lines consisting only of `marker`.
+///
+/// Marp-style decks use `"---"`. Marker lines inside code blocks, HTML blocks
+/// and a metadata block at the start of the input do not split, so a deck can
+/// start with front matter when a metadata block option is enabled. Outside of those, a marker line
+/// always splits, even where it would underline a setext heading. Trailing
+/// whitespace after the marker is ignored. Each document is parsed on its
+/// own, so link reference definitions do not carry over between documents.
+///
+/// ```
+/// # use pulldown_cmark::{utils::split_documents, Event, Options};
+/// let input = "# One\n\n```\n---\n```\n---\n# Two\n";
+/// let documents = split_documents(input, Options::empty(), "---");
+/// assert_eq!(documents.len(), 2);
+/// assert_eq!(documents[1].source, "# Two\n");
+/// let (_, heading) = documents[1].offset_iter(Options::empty()).next().unwrap();
+/// assert_eq!(&input[heading], "# Two\n");
+/// ```
+pub fn split_documents<'a>(
+    input: &'a str,
+    options: Options,
+    marker: &str,
+) -> Vec<DocumentPart<'a>> {
+    let mut opaque = Vec::new();
+    for (event, range) in Parser::new_ext(input, options).into_offset_iter() {
+        match event {
+            Event::Start(Tag::CodeBlock(_) | Tag::HtmlBlock) => opaque.push(range),
+            // only front matter, other metadata blocks would swallow separators
+            Event::Start(Tag::MetadataBlock(_)) if range.start == 0 => opaque.push(range),
+            _ => {}
+        }
+    }
+
+    let mut documents = Vec::new();
+    let mut opaque = opaque.into_iter().peekable();
+    let mut start = 0;
+    let mut line_start = 0;
+    for line in input.split_inclusive('\n') {
+        let line_end = line_start + line.len();
+        while opaque.next_if(|range| range.end <= line_start).is_some() {}
+        let in_block = opaque.peek().is_some_and(|range| range.start <= line_start);
+        if !marker.is_empty() && line.trim_end() == marker && !in_block {
+            documents.push(DocumentPart {
+                source: &input[start..line_start],
+                range: start..line_start,
+            });
+            start = line_end;
+        }
+        line_start = line_end;
+    }
+    documents.push(DocumentPart {
+        source: &input[start..],
+        range: start..input.len(),
+    });
+    documents
+}
+
+/// How [`DecodedInput`] handles bytes that are not valid UTF-8.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum InvalidUtf8 {
+    /// Replaces each invalid sequence with U+FFFD REPLACEMENT CHARACTER, like
+    /// `String::from_utf8_lossy`.
+    #[default]
+    Replace,
+    /// Removes invalid sequences.
+    Remove,
+}
+
+/// Markdown decoded from raw bytes, such as a request body, that maps offsets
+/// in the decoded text back to the original byte buffer.
+///
+/// Valid UTF-8 is borrowed as is. A leading byte order mark is left out of
+/// the text, and CRLF line endings need no normalization, so only the byte
+/// order mark and invalid sequences make offsets diverge.
+///
+/// ```
+/// # use pulldown_cmark::{utils::{DecodedInput, InvalidUtf8}, Event, Options};
+/// let bytes = b"\xEF\xBB\xBFcaf\xE9 *ol\xE9*\r\n";
+/// let input = DecodedInput::new(bytes, InvalidUtf8::Replace);
+/// assert_eq!(input.text(), "caf\u{FFFD} *ol\u{FFFD}*\r\n");
+/// let (event, range) = input.offset_iter(Options::empty()).nth(3).unwrap();
+/// assert_eq!(event, Event::Text("ol\u{FFFD}".into()));
+/// assert_eq!(&bytes[range], b"ol\xE9");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DecodedInput<'a> {
+    text: CowStr<'a>,
+    /// The length of the skipped byte order mark.
+    bom_len: usize,
+    /// The decoded and the original range of each invalid sequence, in order.
+    replacements: Vec<(Range<usize>, Range<usize>)>,
+}
+
+impl<'a> DecodedInput<'a> {
+    /// Decodes `bytes`, skipping a leading UTF-8 byte order mark and handling
+    /// invalid sequences as given by `invalid`.
+    ///
+    /// The parser borrows its text, so parse [`text`](Self::text) with
+    /// [`Parser::new_ext`](crate::Parser::new_ext), or use
+    /// [`offset_iter`](Self::offset_iter) to get offsets into `bytes`.
+    ///
+    /// ```
+    /// use pulldown_cmark::{utils::{DecodedInput, InvalidUtf8}, Event, Options, Parser, Tag};
+    ///
+    /// let bytes = b"\xEF\xBB\xBF# Caf\xE9\r\n";
+    /// let input = DecodedInput::new(bytes, InvalidUtf8::Replace);
+    /// let events: Vec<_> = Parser::new_ext(input.text(), Options::empty()).collect();
+    /// assert_eq!(events[1], Event::Text("Caf\u{FFFD}".into()));
+    /// let events: Vec<_> = input.offset_iter(Options::empty()).collect();
+    /// assert!(matches!(events[0].0, Event::Start(Tag::Heading { .. })));
+    /// assert_eq!(&bytes[events[0].1.clone()], b"# Caf\xE9\r\n");
+    /// ```
+    pub fn new(bytes: &'a [u8], invalid: InvalidUtf8) -> Self {
+        let bom_len = if bytes.starts_with(b"\xEF\xBB\xBF") {
+            3
+        } else {
+            0
+        };
+        let mut text = String::new();
+        let mut replacements = Vec::new();
+        let mut offset = bom_len;
+        loop {
+            let rest = &bytes[offset..];
+            let error = match core::str::from_utf8(rest) {
+                Ok(valid) if replacements.is_empty() => {
+                    return DecodedInput {
+                        text: valid.into(),
+                        bom_len,
+                        replacements,
+                    };
+                }
+                Ok(valid) => {
+                    text.push_str(valid);
+                    break;
+                }
+                Err(error) => error,
+            };
+            let valid_len = error.valid_up_to();
+            // the prefix was just validated
+            text.push_str(core::str::from_utf8(&rest[..valid_len]).unwrap());
+            let invalid_len = error.error_len().unwrap_or(rest.len() - valid_len);
+            let decoded_start = text.len();
+            if invalid == InvalidUtf8::Replace {
+                text.push(char::REPLACEMENT_CHARACTER);
+            }
+            let original_start = offset + valid_len;
+            replacements.push((
+                decoded_start..text.len(),
+                original_start..original_start + invalid_len,
+            ));
+            offset = original_start + invalid_len;
+        }
+        DecodedInput {
+            text: text.into(),
+            bom_len,
+            replacements,
+        }
+    }
+
+    /// The decoded text.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Whether the input had invalid sequences, so that the text differs from
+    /// the bytes.
+    pub fn is_lossy(&self) -> bool {
+        !self.replacements.is_empty()
+    }
+
+    /// Maps an offset in the decoded text to the original bytes. Offsets
+    /// inside a replacement character map to the start of the invalid
+    /// sequence.
+    pub fn original_offset(&self, offset: usize) -> usize {
+        self.map_offset(offset, false)
+    }
+
+    /// Maps a range in the decoded text to the original bytes. The range
+    /// does not include removed sequences at its end.
+    pub fn original_range(&self, range: Range<usize>) -> Range<usize> {
+        let end = self.map_offset(range.end, true);
+        self.map_offset(range.start, false).min(end)..end
+    }
+
+    fn map_offset(&self, offset: usize, is_end: bool) -> usize {
+        // The last sequence that starts before the offset. Ends stop before
+        // sequences starting at the offset, starts skip past them.
+        let count = self.replacements.partition_point(|(decoded, _)| {
+            decoded.start < offset || !is_end && decoded.start == offset
+        });
+        match count.checked_sub(1).map(|ix| &self.replacements[ix]) {
+            Some((decoded, original)) if offset < decoded.end => original.start,
+            Some((decoded, original)) => offset - decoded.end + original.end,
+            None => offset + self.bom_len,
+        }
+    }
+
+    /// Parses the decoded text. Offsets are relative to [`text`](Self::text).
+    pub fn parser(&self, options: Options) -> Parser<'_> {
+        Parser::new_ext(&self.text, options)
+    }
+
+    /// Parses the decoded text, with offsets in the original bytes.
+    pub fn offset_iter(
+        &self,
+        options: Options,
+    ) -> impl Iterator<Item = (Event<'_>, Range<usize>)> + '_ {
+        self.parser(options)
+            .into_offset_iter()
+            .map(move |(event, range)| (event, self.original_range(range)))
+    }
+}
+
+/// Where a range of an [`InputMap`] comes from.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SourceSpan<'a> {
+    /// The index of the source, in the order the sources were pushed.
+    pub index: usize,
+    /// The name the source was pushed with, usually a file path.
+    pub name: &'a str,
+    /// The range in the source's own text.
+    pub range: Range<usize>,
+}
+
+/// A virtual document assembled from several sources, such as book chapters
+/// or expanded includes, that maps offsets back to the source they came from.
+///
+/// The document is parsed as a whole, so link reference definitions in one
+/// source apply to the others. A newline is inserted between two sources when
+/// the first does not end with one; it counts as the end of that source.
+///
+/// ```
+/// # use pulldown_cmark::{utils::InputMap, Event, Options};
+/// let mut input = InputMap::new();
+/// input.push("intro.md", "# Intro\n\nSee [docs].\n");
+/// input.push("links.md", "[docs]: https://docs.rs");
+/// let (event, span) = input.offset_iter(Options::empty()).nth(6).unwrap();
+/// assert_eq!(event, Event::Text("docs".into()));
+/// let span = span.unwrap();
+/// assert_eq!(span.name, "intro.md");
+/// assert_eq!(span.range, 14..18);
+/// assert_eq!(input.locate(input.text().len()), Some((1, 23)));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct InputMap {
+    text: String,
+    /// The name and the range in `text` of each source, in order.
+    sources: Vec<(String, Range<usize>)>,
+}
+
+impl InputMap {
+    /// Creates an empty document.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends a source to the document.
+    pub fn push(&mut self, name: impl Into<String>, text: &str) {
+        if !self.text.is_empty() && !self.text.ends_with('\n') {
+            self.text.push('\n');
+        }
+        let start = self.text.len();
+        self.text.push_str(text);
+        self.sources.push((name.into(), start..self.text.len()));
+    }
+
+    /// The assembled document.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// The number of sources.
+    pub fn len(&self) -> usize {
+        self.sources.len()
+    }
+
+    /// Whether no source was pushed.
+    pub fn is_empty(&self) -> bool {
+        self.sources.is_empty()
+    }
+
+    /// The name of the source at `index`.
+    pub fn name(&self, index: usize) -> Option<&str> {
+        self.sources.get(index).map(|(name, _)| name.as_str())
+    }
+
+    /// Where the source at `index` is in the document.
+    pub fn source_range(&self, index: usize) -> Option<Range<usize>> {
+        self.sources.get(index).map(|(_, range)| range.clone())
+    }
+
+    /// Maps an offset in the document to the index of its source and the
+    /// offset in that source. Returns `None` past the end of the document or
+    /// when there are no sources.
+    pub fn locate(&self, offset: usize) -> Option<(usize, usize)> {
+        if offset > self.text.len() {
+            return None;
+        }
+        // the source starting at or before the offset, the inserted newline
+        // belongs to the source before it
+        let index = self
+            .sources
+            .partition_point(|(_, range)| range.start <= offset)
+            .checked_sub(1)?;
+        let range = &self.sources[index].1;
+        Some((index, offset.min(range.end) - range.start))
+    }
+
+    /// Maps a range in the document to its source. A range spanning several
+    /// sources, such as a list continuing into the next chapter, is cut at
+    /// the end of the source it starts in.
+    pub fn source_span(&self, range: Range<usize>) -> Option<SourceSpan<'_>> {
+        let (index, start) = self.locate(range.start)?;
+        let (name, source) = &self.sources[index];
+        let end = (range.end.min(source.end) - source.start).max(start);
+        Some(SourceSpan {
+            index,
+            name,
+            range: start..end,
+        })
+    }
+
+    /// Parses the document. Offsets are relative to [`text`](Self::text).
+    pub fn parser(&self, options: Options) -> Parser<'_> {
+        Parser::new_ext(&self.text, options)
+    }
+
+    /// Parses the document, with each event's range mapped to its source.
+    pub fn offset_iter(
+        &self,
+        options: Options,
+    ) -> impl Iterator<Item = (Event<'_>, Option<SourceSpan<'_>>)> + '_ {
+        self.parser(options)
+            .into_offset_iter()
+            .map(move |(event, range)| (event, self.source_span(range)))
+    }
+}
+
+/// What a [`FoldingRange`] folds.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FoldingKind {
+    /// A heading and its section, up to the next heading of the same or a
+    /// higher level.
+    Section,
+    List,
+    CodeBlock,
+    BlockQuote,
+    FrontMatter,
+}
+
+/// A foldable region returned by [`folding_ranges`]. Lines are counted from
+/// zero and both ends are inclusive, as in the Language Server Protocol.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FoldingRange {
+    pub start_line: usize,
+    pub end_line: usize,
+    pub kind: FoldingKind,
+}
+
+/// Returns the regions of a document an editor can fold: heading sections,
+/// lists, code blocks, block quotes and front matter. Regions that fit on a
+/// single line are left out, and sections do not include the blank lines
+/// before the next heading.
+///
+/// ```
+/// # use pulldown_cmark::{utils::{folding_ranges, FoldingKind}, Options};
+/// let input = "# Title\n\n- a\n- b\n\n```\ncode\n```\n";
+/// let ranges: Vec<_> = folding_ranges(input, Options::empty())
+///     .into_iter()
+///     .map(|range| (range.start_line, range.end_line, range.kind))
+///     .collect();
+/// assert_eq!(
+///     ranges,
+///     [
+///         (0, 7, FoldingKind::Section),
+///         (2, 3, FoldingKind::List),
+///         (5, 7, FoldingKind::CodeBlock),
+///     ]
+/// );
+/// ```
+pub fn folding_ranges(input: &str, options: Options) -> Vec<FoldingRange> {
+    let line_starts: Vec<usize> = core::iter::once(0)
+        .chain(input.match_indices('\n').map(|(ix, _)| ix + 1))
+        .collect();
+    let line_of = |offset: usize| line_starts.partition_point(|&start| start <= offset) - 1;
+
+    let mut regions = Vec::new();
+    fn sections(symbols: &[DocumentSymbol], regions: &mut Vec<(Range<usize>, FoldingKind)>) {
+        for symbol in symbols {
+            if let SymbolKind::Heading(_) = symbol.kind {
+                regions.push((symbol.range.clone(), FoldingKind::Section));
+                sections(&symbol.children, regions);
+            }
+        }
+    }
+    sections(&document_symbols(input, options), &mut regions);
+    for (event, range) in Parser::new_ext(input, options).into_offset_iter() {
+        let kind = match event {
+            Event::Start(Tag::List(_)) => FoldingKind::List,
+            Event::Start(Tag::CodeBlock(_)) => FoldingKind::CodeBlock,
+            Event::Start(Tag::BlockQuote { .. }) => FoldingKind::BlockQuote,
+            Event::Start(Tag::MetadataBlock(_)) => FoldingKind::FrontMatter,
+            _ => continue,
+        };
+        regions.push((range, kind));
+    }
+
+    let mut ranges: Vec<_> = regions
+        .into_iter()
+        .filter_map(|(range, kind)| {
+            let text = input[range.clone()].trim_end();
+            if text.is_empty() {
+                return None;
+            }
+            let start_line = line_of(range.start);
+            let end_line = line_of(range.start + text.len() - 1);
+            (end_line > start_line).then_some(FoldingRange {
+                start_line,
+                end_line,
+                kind,
+            })
+        })
+        .collect();
+    ranges.sort_by_key(|range| (range.start_line, core::cmp::Reverse(range.end_line)));
+    ranges
+}
+
+/// A task list item, as returned in [`TaskProgress::items`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TaskItem {
+    pub checked: bool,
+    /// The plain text of the item, without nested lists.
+    pub text: String,
+    /// The whole list item in the markdown source.
+    pub span: Range<usize>,
+}
+
+/// The task list items of a document, as returned by [`task_progress`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TaskProgress {
+    /// The number of task items.
+    pub total: usize,
+    /// The number of checked task items.
+    pub checked: usize,
+    /// Every task item in document order, including nested ones.
+    pub items: Vec<TaskItem>,
+}
+
+/// Counts the checked and unchecked task list items of the document and returns
+/// their text and spans. Task lists are only recognized with
+/// [`Options::ENABLE_TASKLISTS`].
+///
+/// ```
+/// # use pulldown_cmark::{utils::task_progress, Options};
+/// let input = "- [x] Write *docs*\n- [ ] Release\n  - [x] Tag\n- Not a task\n";
+/// let progress = task_progress(input, Options::ENABLE_TASKLISTS);
+/// assert_eq!((progress.checked, progress.total), (2, 3));
+/// assert_eq!(progress.items[0].text, "Write docs");
+/// assert_eq!(progress.items[1].text, "Release");
+/// assert_eq!(&input[progress.items[2].span.clone()], "- [x] Tag\n");
+/// ```
+pub fn task_progress(input: &str, options: Options) -> TaskProgress {
+    let mut progress = TaskProgress::default();
+    // The open list items, with the index of their task item if they are tasks.
+    let mut items: Vec<(Range<usize>, Option<usize>)> = Vec::new();
+    for (event, range) in Parser::new_ext(input, options).into_offset_iter() {
+        let current = items.last().and_then(|(_, task)| *task);
+        match event {
+            Event::Start(Tag::Item(..)) => items.push((range, None)),
+            Event::End(TagEnd::Item) => {
+                if let Some(ix) = current {
+                    let text = &mut progress.items[ix].text;
+                    text.truncate(text.trim_end().len());
+                }
+                items.pop();
+            }
+            Event::TaskListMarker(checked) => {
+                if let Some((span, task)) = items.last_mut() {
+                    *task = Some(progress.items.len());
+                    progress.items.push(TaskItem {
+                        checked,
+                        text: String::new(),
+                        span: span.clone(),
+                    });
+                    progress.total += 1;
+                    progress.checked += checked as usize;
+                }
+            }
+            Event::Text(text) | Event::Code(text, _) => {
+                if let Some(ix) = current {
+                    progress.items[ix].text.push_str(&text);
+                }
+            }
+            Event::SoftBreak | Event::HardBreak(_) | Event::End(TagEnd::Paragraph) => {
+                if let Some(ix) = current {
+                    progress.items[ix].text.push(' ');
+                }
+            }
+            _ => {}
+        }
+    }
+    progress
+}
+
+/// A problem found by [`check_anchors`]. `document` and `target` are indices
+/// into the checked document set.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AnchorIssue {
+    /// A heading whose anchor is already taken by an earlier heading, so it was
+    /// given a suffixed anchor and links to `anchor` go to the earlier one.
+    DuplicateAnchor {
+        document: usize,
+        anchor: String,
+        /// The heading in the markdown source.
+        span: Range<usize>,
+        /// The earlier heading with the same anchor.
+        first: Range<usize>,
+    },
+    /// A link to a fragment that no heading or block id of the target document
+    /// has.
+    BrokenFragment {
+        document: usize,
+        target: usize,
+        fragment: String,
+        /// The link in the markdown source.
+        span: Range<usize>,
+    },
+}
+
+impl AnchorIssue {
+    fn position(&self) -> (usize, usize) {
+        match self {
+            Self::DuplicateAnchor { document, span, .. }
+            | Self::BrokenFragment { document, span, .. } => (*document, span.start),
+        }
+    }
+}
+
+/// The heading anchors and fragment links of a document.
+struct DocumentAnchors {
+    anchors: Vec<String>,
+    links: Vec<(CowStr<'static>, Range<usize>)>,
+}
+
+/// Generates the heading anchors of a set of documents and reports duplicate
+/// anchors and links to fragments that do not exist, for example to lint a
+/// documentation site.
+///
+/// `documents` holds the path and source of each document. A link like
+/// `other.md#setup` is resolved against the path of the linking document with
+/// [`resolve_url`] and checked if it points at a document of the set; links to
+/// other documents are ignored. Explicit heading ids are used as they are,
+/// other anchors are slugified from the heading text. With
+/// [`Options::ENABLE_BLOCK_IDS`], a fragment like `#^quote` is checked against
+/// the block ids of the target document. Issues are sorted by document and
+/// position.
+///
+/// ```
+/// # use pulldown_cmark::{utils::{check_anchors, AnchorIssue}, Options};
+/// let documents = [
+///     ("docs/intro.md", "# Setup\n\nSee [usage](usage.md#usage) and [setup](#set-up).\n"),
+///     ("docs/usage.md", "# Usage\n\n## Usage\n"),
+/// ];
+/// let issues = check_anchors(&documents, Options::empty());
+/// assert_eq!(issues, [
+///     AnchorIssue::BrokenFragment { document: 0, target: 0, fragment: "set-up".into(), span: 41..57 },
+///     AnchorIssue::DuplicateAnchor { document: 1, anchor: "usage".into(), span: 9..18, first: 0..8 },
+/// ]);
+/// ```
+pub fn check_anchors(documents: &[(&str, &str)], options: Options) -> Vec<AnchorIssue> {
+    let mut issues = Vec::new();
+    let mut parsed = Vec::with_capacity(documents.len());
+    for (document, (_, source)) in documents.iter().enumerate() {
+        let mut slugger = Slugger::new();
+        let mut first_spans: HashMap<String, Range<usize>> = HashMap::new();
+        let mut anchors = Vec::new();
+        let mut links = Vec::new();
+        let mut heading: Option<(Option<CowStr<'_>>, String, Range<usize>)> = None;
+        for (event, range) in Parser::new_ext(source, options).into_offset_iter() {
+            match event {
+                Event::Start(Tag::Heading { id, .. }) => heading = Some((id, String::new(), range)),
+                Event::Text(text) | Event::Code(text, _) => {
+                    if let Some((_, title, _)) = &mut heading {
+                        title.push_str(&text);
+                    }
+                }
+                Event::End(TagEnd::Heading(_)) => {
+                    let Some((id, title, span)) = heading.take() else {
+                        continue;
+                    };
+                    let base = match id {
+                        Some(id) => id.into_string(),
+                        None => slugify(&title),
+                    };
+                    let anchor = slugger.unique(&base);
+                    match first_spans.get(&base) {
+                        Some(first) if anchor != base => {
+                            issues.push(AnchorIssue::DuplicateAnchor {
+                                document,
+                                anchor: base,
+                                span: span.clone(),
+                                first: first.clone(),
+                            });
+                        }
+                        _ => {
+                            first_spans.entry(base).or_insert_with(|| span.clone());
+                        }
+                    }
+                    first_spans.entry(anchor.clone()).or_insert(span);
+                    anchors.push(anchor);
+                }
+                Event::Start(Tag::Paragraph(Some(id)) | Tag::Item(_, Some(id))) => {
+                    anchors.push(format!("^{id}"));
+                }
+                Event::Start(Tag::Link {
+                    link_type,
+                    dest_url,
+                    ..
+                }) if !link_type.is_email() && dest_url.contains('#') => {
+                    links.push((dest_url.into_string().into(), range));
+                }
+                _ => {}
+            }
+        }
+        parsed.push(DocumentAnchors { anchors, links });
+    }
+
+    for (document, (path, _)) in documents.iter().enumerate() {
+        for (dest_url, span) in &parsed[document].links {
+            let (target_path, fragment) = dest_url.split_once('#').unwrap_or((dest_url, ""));
+            let target = if target_path.is_empty() {
+                Some(document)
+            } else if url_scheme(target_path).is_some() {
+                None
+            } else {
+                let resolved = resolve_url(path, target_path);
+                documents
+                    .iter()
+                    .position(|(path, _)| *path == resolved.as_ref())
+            };
+            let Some(target) = target else {
+                continue;
+            };
+            if !fragment.is_empty() && !parsed[target].anchors.iter().any(|a| a == fragment) {
+                issues.push(AnchorIssue::BrokenFragment {
+                    document,
+                    target,
+                    fragment: fragment.to_owned(),
+                    span: span.clone(),
+                });
+            }
+        }
+    }
+    issues.sort_by_key(AnchorIssue::position);
+    issues
+}
+
+/// A `#tag` found by [`extract_tags`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HashtagInfo<'a> {
+    /// The tag name, without the `#`.
+    pub name: CowStr<'a>,
+    /// The tag in the markdown source, including the `#`.
+    pub span: Range<usize>,
+}
+
+/// Returns every `#tag` of the document in document order, for example to
+/// build a note graph.
+///
+/// [`Options::ENABLE_HASHTAGS`] is always enabled, so tags are recognized with
+/// the same rules as [`Event::Hashtag`]; `#` inside code, URLs and headings
+/// markers is not a tag.
+///
+/// ```
+/// # use pulldown_cmark::{utils::extract_tags, Options};
+/// let input = "# Notes #draft\n\nSee `#not-a-tag` and #project/alpha, issue #42.\n";
+/// let tags = extract_tags(input, Options::empty());
+/// let names: Vec<_> = tags.iter().map(|tag| tag.name.as_ref()).collect();
+/// assert_eq!(names, ["draft", "project/alpha"]);
+/// assert_eq!(&input[tags[1].span.clone()], "#project/alpha");
+/// ```
+pub fn extract_tags(input: &str, options: Options) -> Vec<HashtagInfo<'_>> {
+    Parser::new_ext(input, options | Options::ENABLE_HASHTAGS)
+        .into_offset_iter()
+        .filter_map(|(event, span)| match event {
+            Event::Hashtag(name) => Some(HashtagInfo { name, span }),
+            _ => None,
+        })
+        .collect()
+}
+
+/// A `^block-id` found by [`extract_block_ids`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BlockIdInfo<'a> {
+    /// The identifier, without the `^`.
+    pub id: CowStr<'a>,
+    /// The identifier in the markdown source, including the `^`.
+    pub span: Range<usize>,
+    /// The block the identifier belongs to, such as a paragraph or list item.
+    pub block: Range<usize>,
+}
+
+/// Returns every `^block-id` of the document in document order together with
+/// the block it identifies, for example to resolve `[[note#^id]]` block
+/// transclusions.
+///
+/// [`Options::ENABLE_BLOCK_IDS`] is always enabled.
+///
+/// ```
+/// # use pulldown_cmark::{utils::extract_block_ids, Options};
+/// let input = "Intro\n\n> Quoted text ^quote\n\n- item ^item-1\n";
+/// let ids = extract_block_ids(input, Options::empty());
+/// assert_eq!(ids[0].id.as_ref(), "quote");
+/// assert_eq!(&input[ids[0].block.clone()], "Quoted text ^quote\n");
+/// assert_eq!(&input[ids[1].block.clone()], "- item ^item-1\n");
+/// assert_eq!(&input[ids[1].span.clone()], "^item-1");
+/// ```
+pub fn extract_block_ids(input: &str, options: Options) -> Vec<BlockIdInfo<'_>> {
+    let mut ids = Vec::new();
+    for (event, block) in
+        Parser::new_ext(input, options | Options::ENABLE_BLOCK_IDS).into_offset_iter()
+    {
+        if let Event::Start(Tag::Paragraph(Some(id)) | Tag::Item(_, Some(id))) = event {
+            // The identifier is the first `^id` of the block that ends its line.
+            let marker = format!("^{id}");
+            let start = input[block.clone()]
+                .match_indices(marker.as_str())
+                .map(|(ix, _)| block.start + ix)
+                .find(|&ix| {
+                    let rest = &input[ix + marker.len()..];
+                    let line = &rest[..rest.find('\n').unwrap_or(rest.len())];
+                    line.trim().is_empty()
+                })
+                .unwrap_or(block.start);
+            ids.push(BlockIdInfo {
+                id,
+                span: start..start + marker.len(),
+                block,
+            });
+        }
+    }
+    ids
+}
+
+/// Returns the extensions among `options` that the document actually uses,
+/// judged from the events they produce.
+///
+/// Useful to tell which flags matter for a given input when debugging option
+/// interactions. [`Options::ENABLE_SMART_PUNCTUATION`] counts as used when it
+/// replaced at least one character. Flags without an observable effect on the
+/// events, such as [`Options::ENABLE_DIAGNOSTICS`], are never reported.
+///
+/// ```
+/// # use pulldown_cmark::{utils::used_extensions, Options};
+/// let options = Options::ENABLE_TABLES | Options::ENABLE_MATH | Options::ENABLE_TASKLISTS;
+/// let used = used_extensions("- [x] $x^2$\n", options);
+/// assert_eq!(used, Options::ENABLE_MATH | Options::ENABLE_TASKLISTS);
+/// ```
+pub fn used_extensions(input: &str, options: Options) -> Options {
+    let mut used = Options::empty();
+    for (event, span) in Parser::new_ext(input, options).into_offset_iter() {
+        used |= match event {
+            Event::Start(Tag::Table(_)) => Options::ENABLE_TABLES,
+            Event::Start(Tag::Strikethrough) => Options::ENABLE_STRIKETHROUGH,
+            Event::Start(Tag::Superscript) => Options::ENABLE_SUPERSCRIPT,
+            Event::Start(Tag::Subscript) => Options::ENABLE_SUBSCRIPT,
+            Event::Start(Tag::DefinitionList) => Options::ENABLE_DEFINITION_LIST,
+            Event::Start(Tag::ContainerBlock(..)) => Options::ENABLE_CONTAINER_EXTENSIONS,
+            Event::Start(Tag::FootnoteDefinition(_)) | Event::FootnoteReference(_) => {
+                options & (Options::ENABLE_FOOTNOTES | Options::ENABLE_OLD_FOOTNOTES)
+            }
+            Event::Start(Tag::MetadataBlock(MetadataBlockKind::YamlStyle)) => {
+                Options::ENABLE_YAML_STYLE_METADATA_BLOCKS
+            }
+            Event::Start(Tag::MetadataBlock(MetadataBlockKind::PlusesStyle)) => {
+                Options::ENABLE_PLUSES_DELIMITED_METADATA_BLOCKS
+            }
+            Event::Start(Tag::Heading { id, attributes, .. })
+                if id.is_some() || attributes.is_some() =>
+            {
+                Options::ENABLE_HEADING_ATTRIBUTES
+            }
+            Event::Start(Tag::BlockQuote { kind: Some(_), .. }) => Options::ENABLE_GFM,
+            Event::Start(Tag::TableFoot) => Options::ENABLE_TABLE_FOOTERS,
+            Event::Start(Tag::Link {
+                link_type: LinkType::WikiLink { .. },
+                ..
+            }) => Options::ENABLE_WIKILINKS,
+            Event::Start(Tag::Link {
+                link_type: LinkType::AutolinkLiteral | LinkType::EmailLiteral,
+                ..
+            }) => Options::ENABLE_AUTOLINK_LITERALS,
+            Event::InlineMath(_, MathSyntax::AsciiMath)
+            | Event::DisplayMath(_, MathSyntax::AsciiMath) => {
+                Options::ENABLE_MATH | Options::ENABLE_ASCIIMATH
+            }
+            Event::InlineMath(..) | Event::DisplayMath(..) => Options::ENABLE_MATH,
+            Event::TaskListMarker(_) => Options::ENABLE_TASKLISTS,
+            Event::Hashtag(_) => Options::ENABLE_HASHTAGS,
+            Event::Start(Tag::Paragraph(Some(_)) | Tag::Item(_, Some(_))) => {
+                Options::ENABLE_BLOCK_IDS
+            }
+            Event::Text(text)
+                if options.contains(Options::ENABLE_SMART_PUNCTUATION)
+                    && text.chars().any(|c| {
+                        matches!(c, '‘' | '’' | '“' | '”' | '–' | '—' | '…')
+                            && !input[span.clone()].contains(c)
+                    }) =>
+            {
+                Options::ENABLE_SMART_PUNCTUATION
+            }
+            _ => Options::empty(),
+        };
+    }
+    used & options
+}
+
+/// Returns `true` for the tags of inline elements, around which whitespace is
+/// significant.
+pub(crate) fn is_inline_tag(tag: &TagEnd) -> bool {
+    matches!(
+        tag,
+        TagEnd::Emphasis
+            | TagEnd::Strong
+            | TagEnd::Strikethrough
+            | TagEnd::Superscript
+            | TagEnd::Subscript
+            | TagEnd::Link
+            | TagEnd::Image
+    )
+}
+
+/// Merges text events, collapses runs of ASCII whitespace (including soft
+/// breaks) to a single space and trims whitespace at block boundaries. Text in
+/// code and metadata blocks is only merged.
+fn normalize_events<'a>(events: impl IntoIterator<Item = Event<'a>>) -> Vec<Event<'a>> {
+    fn flush<'a>(text: &mut String, verbatim: bool, at_boundary: bool, out: &mut Vec<Event<'a>>) {
+        if verbatim {
+            if !text.is_empty() {
+                out.push(Event::Text(mem::take(text).into()));
+            }
+            return;
+        }
+        let mut collapsed = String::with_capacity(text.len());
+        for c in text.chars() {
+            if !c.is_ascii_whitespace() {
+                collapsed.push(c);
+            } else if !collapsed.ends_with(' ') {
+                collapsed.push(' ');
+            }
+        }
+        text.clear();
+        let block_start = match out.last() {
+            None | Some(Event::HardBreak(_)) | Some(Event::Rule) => true,
+            Some(Event::Start(tag)) => !is_inline_tag(&tag.to_end()),
+            Some(Event::End(tag)) => !is_inline_tag(tag),
+            _ => false,
+        };
+        let mut collapsed = collapsed.as_str();
+        if block_start {
+            collapsed = collapsed.trim_start_matches(' ');
+        }
+        if at_boundary {
+            collapsed = collapsed.trim_end_matches(' ');
+        }
+        if !collapsed.is_empty() {
+            out.push(Event::Text(collapsed.to_owned().into()));
+        }
+    }
+
+    let mut out = Vec::new();
+    let mut text = String::new();
+    let mut verbatim = 0usize;
+    for event in events {
+        let at_boundary = match &event {
+            Event::Text(s) => {
+                text.push_str(s);
+                continue;
+            }
+            Event::SoftBreak if verbatim == 0 => {
+                text.push(' ');
+                continue;
+            }
+            Event::HardBreak(_) | Event::Rule => true,
+            Event::Start(tag) => !is_inline_tag(&tag.to_end()),
+            Event::End(tag) => !is_inline_tag(tag),
+            _ => false,
+        };
+        flush(&mut text, verbatim > 0, at_boundary, &mut out);
+        match &event {
+            Event::Start(Tag::CodeBlock(_) | Tag::MetadataBlock(_)) => verbatim += 1,
+            Event::End(TagEnd::CodeBlock | TagEnd::MetadataBlock(_)) => verbatim -= 1,
+            _ => {}
+        }
+        out.push(event);
+    }
+    flush(&mut text, verbatim > 0, true, &mut out);
+    out
+}
+
+/// Compares two event streams, ignoring differences that do not change the
+/// meaning of the document: how text is split into [`Event::Text`] events,
+/// soft breaks versus spaces, runs of whitespace and whitespace at the start
+/// and end of blocks.
+///
+/// Whitespace stays significant inside code blocks, metadata blocks, inline
+/// code and HTML. This is meant for golden tests and for checking that
+/// serialized markdown round-trips.
+///
+/// ```
+/// # use pulldown_cmark::{utils::events_equivalent, Parser};
+/// let a = Parser::new("Hello\n  *big*   world");
+/// let b = Parser::new("Hello *big* world\n");
+/// assert!(events_equivalent(a, b));
+///
+/// let c = Parser::new("Hello *big*world");
+/// assert!(!events_equivalent(Parser::new("Hello *big* world"), c));
+/// ```
+pub fn events_equivalent<'a, 'b>(
+    a: impl IntoIterator<Item = Event<'a>>,
+    b: impl IntoIterator<Item = Event<'b>>,
+) -> bool {
+    normalize_events(a) == normalize_events(b)
+}
+
+/// Deduplicates strings that repeat across a document, such as code block
+/// languages, container names, classes and attributes, link destinations and
+/// reference ids, and footnote labels.
+///
+/// This is also the string storage of [`ParseContext`](crate::context::ParseContext).
+///
+/// Events that outlive their source text (see [`Event::into_static`]) own a
+/// separate copy of each of these strings. Collect them first with
+/// [`insert_event`](Self::insert_event), then rewrite the events with
+/// [`intern_event`](Self::intern_event) so that every occurrence borrows the
+/// single copy held by the interner.
+///
+/// ```
+/// # use pulldown_cmark::{utils::StringInterner, Event, Parser};
+/// let input = "```a-rather-long-language-name\n```\n\n```a-rather-long-language-name\n```\n";
+/// let events: Vec<Event<'static>> = Parser::new(input).map(Event::into_static).collect();
+///
+/// let mut interner = StringInterner::new();
+/// events.iter().for_each(|event| interner.insert_event(event));
+/// assert_eq!(interner.len(), 1);
+///
+/// let events: Vec<Event<'_>> = events
+///     .into_iter()
+///     .map(|event| interner.intern_event(event))
+///     .collect();
+/// assert!(events.iter().all(Event::is_borrowed));
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct StringInterner {
+    strings: Vec<Box<str>>,
+    /// The last symbol of each string hash, so that each string is stored once.
+    symbols: HashMap<u64, Symbol>,
+    /// The previous symbol with the same hash as each symbol.
+    collisions: Vec<Option<Symbol>>,
+}
+
+impl StringInterner {
+    /// Creates an empty interner.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the number of distinct strings held.
+    pub fn len(&self) -> usize {
+        self.strings.len()
+    }
+
+    /// Returns whether no strings are held.
+    pub fn is_empty(&self) -> bool {
+        self.strings.is_empty()
+    }
+
+    /// Adds a string, unless an equal one is already held, and returns its
+    /// [`Symbol`]. Equal strings always get the same symbol.
+    pub fn insert(&mut self, s: &str) -> Symbol {
+        let hash = self.symbols.hasher().hash_one(s);
+        if let Some(symbol) = self.find(hash, s) {
+            return symbol;
+        }
+        let symbol = Symbol(self.strings.len() as u32);
+        self.strings.push(s.into());
+        self.collisions.push(self.symbols.insert(hash, symbol));
+        symbol
+    }
+
+    fn find(&self, hash: u64, s: &str) -> Option<Symbol> {
+        let mut next = self.symbols.get(&hash).copied();
+        while let Some(symbol) = next {
+            if self.resolve(symbol) == s {
+                return Some(symbol);
+            }
+            next = self.collisions[symbol.0 as usize];
+        }
+        None
+    }
+
+    /// Returns the held string of `symbol`.
+    ///
+    /// # Panics
+    ///
+    /// If `symbol` was not returned by this interner.
+    pub fn resolve(&self, symbol: Symbol) -> &str {
+        &self.strings[symbol.0 as usize]
+    }
+
+    /// Adds the repeated kinds of strings found in `event`.
+    pub fn insert_event(&mut self, event: &Event<'_>) {
+        // Keep in sync with `intern_event`.
+        match event {
+            Event::Start(Tag::CodeBlock(CodeBlockKind::Fenced(info))) => {
+                self.insert(info);
+            }
+            Event::Start(Tag::Heading {
+                attributes: Some(attributes),
+                ..
+            }) => {
+                self.insert_attributes(&attributes.classes, &attributes.attrs);
+            }
+            Event::Start(Tag::Link { dest_url, id, .. }) => {
+                self.insert(dest_url);
+                if !id.is_empty() {
+                    self.insert(id);
+                }
+            }
+            Event::Start(Tag::Image {
+                dest_url, extras, ..
+            }) => {
+                self.insert(dest_url);
+                if let Some(extras) = extras {
+                    if !extras.id.is_empty() {
+                        self.insert(&extras.id);
+                    }
+                    let attributes = &extras.attributes;
+                    self.insert_attributes(&attributes.classes, &attributes.attrs);
+                }
+            }
+            Event::Start(Tag::ContainerBlock(_, name, attributes)) => {
+                if !name.is_empty() {
+                    self.insert(name);
+                }
+                if let Some(attributes) = attributes {
+                    self.insert_attributes(&attributes.classes, &attributes.attrs);
+                }
+            }
+            Event::Start(Tag::FootnoteDefinition(label)) | Event::FootnoteReference(label) => {
+                self.insert(label);
+            }
+            _ => {}
+        }
+    }
+
+    fn insert_attributes(
+        &mut self,
+        classes: &[CowStr<'_>],
+        attrs: &[(CowStr<'_>, Option<CowStr<'_>>)],
+    ) {
+        for class in classes {
+            self.insert(class);
+        }
+        for (key, value) in attrs {
+            self.insert(key);
+            if let Some(value) = value {
+                self.insert(value);
+            }
+        }
+    }
+
+    /// Returns a borrow of the held copy of `s`, or `s` itself if it was
+    /// never inserted.
+    pub fn intern<'i>(&'i self, s: CowStr<'i>) -> CowStr<'i> {
+        let hash = self.symbols.hasher().hash_one(&*s);
+        match self.find(hash, &s) {
+            Some(symbol) => CowStr::Borrowed(self.resolve(symbol)),
+            None => s,
+        }
+    }
+
+    fn intern_in_place<'i>(&'i self, s: &mut CowStr<'i>) {
+        *s = self.intern(mem::replace(s, CowStr::Borrowed("")));
+    }
+
+    fn intern_attributes<'i>(
+        &'i self,
+        classes: &mut [CowStr<'i>],
+        attrs: &mut [(CowStr<'i>, Option<CowStr<'i>>)],
+    ) {
+        for class in classes {
+            self.intern_in_place(class);
+        }
+        for (key, value) in attrs {
+            self.intern_in_place(key);
+            if let Some(value) = value {
+                self.intern_in_place(value);
+            }
+        }
+    }
+
+    /// Replaces the strings of `event` that [`insert_event`](Self::insert_event)
+    /// collects with borrows of the held copies.
+    pub fn intern_event<'i>(&'i self, mut event: Event<'i>) -> Event<'i> {
+        match &mut event {
+            Event::Start(Tag::CodeBlock(CodeBlockKind::Fenced(info))) => self.intern_in_place(info),
+            Event::Start(Tag::Heading {
+                attributes: Some(attributes),
+                ..
+            }) => {
+                self.intern_attributes(&mut attributes.classes, &mut attributes.attrs);
+            }
+            Event::Start(Tag::Link { dest_url, id, .. }) => {
+                self.intern_in_place(dest_url);
+                self.intern_in_place(id);
+            }
+            Event::Start(Tag::Image {
+                dest_url, extras, ..
+            }) => {
+                self.intern_in_place(dest_url);
+                if let Some(extras) = extras {
+                    self.intern_in_place(&mut extras.id);
+                    let attributes = &mut extras.attributes;
+                    self.intern_attributes(&mut attributes.classes, &mut attributes.attrs);
+                }
+            }
+            Event::Start(Tag::ContainerBlock(_, name, attributes)) => {
+                self.intern_in_place(name);
+                if let Some(attributes) = attributes {
+                    self.intern_attributes(&mut attributes.classes, &mut attributes.attrs);
+                }
+            }
+            Event::Start(Tag::FootnoteDefinition(label)) | Event::FootnoteReference(label) => {
+                self.intern_in_place(label)
+            }
+            _ => {}
+        }
+        event
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use alloc::vec::Vec;
+
+    use super::*;
+    use crate::Parser;
+
+    #[test]
+    fn text_merge_stream_indent() {
+        let source = r#"
+    first line
+    second line
+"#;
+        let parser = TextMergeStream::new(Parser::new(source));
+        let text_events: Vec<_> = parser.filter(|e| matches!(e, Event::Text(_))).collect();
+        assert_eq!(
+            text_events,
+            [Event::Text("first line\nsecond line\n".into())]
+        );
+    }
+
+    #[test]
+    fn text_merge_with_offset_indent() {
+        let source = r#"
+    first line
+    second line
+"#;
+        let parser = TextMergeWithOffset::new(Parser::new(source).into_offset_iter());
+        let text_events: Vec<_> = parser
+            .filter(|e| matches!(e, (Event::Text(_), _)))
+            .collect();
+        assert_eq!(
+            text_events,
+            [(Event::Text("first line\nsecond line\n".into()), 5..32)]
+        );
+    }
+
+    #[test]
+    fn text_merge_empty_is_discarded() {
+        let events = [
+            Event::Rule,
+            Event::Text("".into()),
+            Event::Text("".into()),
+            Event::Rule,
+        ];
+        let result: Vec<_> = TextMergeStream::new(events.into_iter()).collect();
+        assert_eq!(result, [Event::Rule, Event::Rule]);
+    }
+
+    #[test]
+    fn text_merge_html_disabled_by_default() {
+        let source = "<div>\n<p>\n\n*a* <b><i>x</i></b>";
+        let parser = TextMergeStream::new(Parser::new(source));
+        let html_events = parser
+            .filter(|e| matches!(e, Event::Html(_) | Event::InlineHtml(_)))
+            .count();
+        assert_eq!(html_events, 6);
+    }
+
+    #[test]
+    fn text_merge_with_offset_html() {
+        let source = "<div>\n<p>\n\n*a* <b><i>x</i></b>";
+        let parser =
+            TextMergeWithOffset::new(Parser::new(source).into_offset_iter()).merge_html(true);
+        let html_events: Vec<_> = parser
+            .filter(|e| matches!(e, (Event::Html(_) | Event::InlineHtml(_), _)))
+            .collect();
+        assert_eq!(
+            html_events,
+            [
+                (Event::Html("<div>\n<p>\n".into()), 0..10),
+                (Event::InlineHtml("<b><i>".into()), 15..21),
+                (Event::InlineHtml("</i></b>".into()), 22..30),
+            ]
+        );
+    }
+
+    #[test]
+    fn slugify_github() {
+        assert_eq!(slugify("Hello World"), "hello-world");
+        assert_eq!(slugify("API & SDK"), "api--sdk");
+        assert_eq!(slugify("foo_bar-baz"), "foo_bar-baz");
+        assert_eq!(slugify("🚀 Launch!"), "-launch");
+        assert_eq!(slugify("`code` (v1.2)"), "code-v12");
+        assert_eq!(slugify("你好 世界"), "你好-世界");
+        assert_eq!(slugify("Ünïcödé"), "ünïcödé");
+        assert_eq!(slugify("e\u{301}"), "e\u{301}");
+    }
+
+    #[test]
+    fn slugger_dedup() {
+        let mut slugger = Slugger::new();
+        assert_eq!(slugger.slug("A"), "a");
+        assert_eq!(slugger.slug("a-1"), "a-1");
+        assert_eq!(slugger.slug("A"), "a-2");
+        assert_eq!(slugger.unique("a"), "a-3");
+        slugger.reset();
+        assert_eq!(slugger.slug("A"), "a");
+    }
+
+    #[test]
+    fn extract_links_kinds() {
+        let input = "[a *b* ![img](i.png)][ref] [[Wiki]] <me@example.com>\n\n[ref]: /ref 't'\n";
+        let links = extract_links(input, Options::ENABLE_WIKILINKS);
+        let summary: Vec<_> = links
+            .iter()
+            .map(|link| (link.link_type, link.dest_url.as_ref(), link.text.as_str()))
+            .collect();
+        assert_eq!(
+            summary,
+            [
+                (LinkType::Reference, "/ref", "a b img"),
+                (LinkType::WikiLink { has_pothole: false }, "Wiki", "Wiki"),
+                (LinkType::Email, "me@example.com", "me@example.com"),
+            ]
+        );
+        assert_eq!(links[0].title, "t".into());
+        assert_eq!(&input[links[0].span.clone()], "[a *b* ![img](i.png)][ref]");
+    }
+
+    #[test]
+    fn summary_skips_non_paragraph_content() {
+        let input = "---\ntitle: T\n---\n\n## Heading\n\n<div>block</div>\n\n> Quoted `code` <b>x</b>\n\nNext";
+        let options = Options::ENABLE_YAML_STYLE_METADATA_BLOCKS;
+        assert_eq!(summary(input, options, 100), "Quoted code x");
+        assert_eq!(summary("", options, 10), "");
+        assert_eq!(summary("![only](image.png)", options, 10), "");
+    }
+
+    #[test]
+    fn summary_truncation() {
+        assert_eq!(summary("one two three", Options::empty(), 8), "one two…");
+        assert_eq!(summary("one two three", Options::empty(), 7), "one…");
+        assert_eq!(summary("abcdefgh", Options::empty(), 5), "abcd…");
+        assert_eq!(summary("中文的摘要文本", Options::empty(), 5), "中文的摘…");
+        assert_eq!(summary("exact", Options::empty(), 5), "exact");
+        assert_eq!(summary("exact", Options::empty(), 0), "");
+        for max_len in 0..16 {
+            let s = summary("one two three four", Options::empty(), max_len);
+            assert!(s.chars().count() <= max_len, "{:?} for {}", s, max_len);
+        }
+    }
+
+    #[test]
+    fn extract_title_sources() {
+        let yaml = Options::ENABLE_YAML_STYLE_METADATA_BLOCKS;
+        let toml = Options::ENABLE_PLUSES_DELIMITED_METADATA_BLOCKS;
+
+        // A heading wins over the front matter.
+        let input = "---\ntitle: Meta\n---\n\nSetext *title*\n=============\n\n# Second\n";
+        let title = extract_title(input, yaml).unwrap();
+        assert_eq!(title.text, "Setext title");
+        assert_eq!(&input[title.span], "Setext *title*\n=============\n");
+
+        let input = "+++\nsubtitle = \"no\"\ntitle = 'TOML'\n+++\n";
+        let title = extract_title(input, toml).unwrap();
+        assert_eq!(title.text, "TOML");
+        assert_eq!(&input[title.span], "'TOML'");
+
+        // Nested keys and disabled metadata blocks are ignored.
+        assert_eq!(extract_title("---\nauthor:\n  title: X\n---\n", yaml), None);
+        assert_eq!(
+            extract_title("---\ntitle: X\n---\n", Options::empty()),
+            None
+        );
+    }
+
+    #[test]
+    fn escape_markdown_round_trip() {
+        let inputs = [
+            "*a* _b_ `c` [d](e) <f> ![g](h) ~~i~~ $j$ ^k^ &amp; a | b {#id}",
+            "# heading\n> quote\n- item\n+ item\n10) item\n===\nline \\\n",
+            "2024. A year",
+            "[^note] [[wiki]] https://example.com",
+            "me@example.com tel:123 www.example.com",
+            "TEL:123 WWW.example.com Www.example.com",
+            "    indented\n\tline\ntrailing  \nbreak",
+            ": definition\n::: note\ntext",
+        ];
+        let options = Options::all() - Options::ENABLE_OLD_FOOTNOTES;
+        for input in inputs {
+            let escaped = escape_markdown(input);
+            let events: Vec<_> = TextMergeStream::new(Parser::new_ext(&escaped, options))
+                .filter(|event| !matches!(event, Event::SoftBreak))
+                .collect();
+            let text: String = events
+                .iter()
+                .filter_map(|event| match event {
+                    Event::Text(text) => Some(text.as_ref()),
+                    Event::Start(Tag::Paragraph(_)) | Event::End(TagEnd::Paragraph) => None,
+                    _ => panic!("unexpected event {:?} for {:?}", event, input),
+                })
+                .collect();
+            assert_eq!(text, input.replace('\n', ""));
+        }
+    }
+
+    #[test]
+    fn sanitize_url_policy() {
+        let policy = UrlPolicy::default();
+        for rejected in [
+            "javascript:alert(1)",
+            "  JAVASCRIPT:alert(1)",
+            "java\tscript:alert(1)",
+            "\u{1}javascript:alert(1)",
+            "vbscript:msgbox",
+            "data:image/png;base64,AAAA",
+        ] {
+            assert_eq!(sanitize_url(rejected, &policy), None, "{:?}", rejected);
+        }
+        for (url, expected) in [
+            ("https://example.com", "https://example.com"),
+            ("Mailto:a@b.c", "Mailto:a@b.c"),
+            ("/path#frag", "/path#frag"),
+            ("javascript%3Aalert(1)", "javascript%3Aalert(1)"),
+            ("?q=100%", "?q=100%25"),
+            ("/%c3%a9/é", "/%C3%A9/%C3%A9"),
+            ("/a\"b<c>", "/a%22b%3Cc%3E"),
+        ] {
+            assert_eq!(sanitize_url(url, &policy).as_deref(), Some(expected));
+        }
+        assert!(matches!(
+            sanitize_url("https://example.com", &policy),
+            Some(CowStr::Borrowed(_))
+        ));
+
+        let strict = UrlPolicy {
+            allowed_schemes: vec!["https".to_owned()],
+            allow_relative: false,
+        };
+        assert_eq!(sanitize_url("/path", &strict), None);
+        assert_eq!(sanitize_url("http://example.com", &strict), None);
+        assert!(sanitize_url("data:,x", &strict.allow_scheme("DATA")).is_some());
+    }
+
+    #[test]
+    fn replace_text_skips_code() {
+        let source = "---\ntitle: :x:\n---\n\n:x: [*:x:*](:x:) `:x:`\n\n```\n:x:\n```\n";
+        let options = Options::ENABLE_YAML_STYLE_METADATA_BLOCKS;
+        let events: Vec<_> = replace_text(
+            Parser::new_ext(source, options),
+            |text| text.find(":x:").map(|ix| ix..ix + 3),
+            |_| Event::InlineHtml("&#x274C;".into()),
+        )
+        .collect();
+        let replaced = events
+            .iter()
+            .filter(|event| matches!(event, Event::InlineHtml(_)))
+            .count();
+        assert_eq!(replaced, 2);
+        assert!(events.contains(&Event::Text("title: :x:\n".into())));
+        assert!(events.contains(&Event::Text(":x:\n".into())));
+        assert!(events.contains(&Event::Code(":x:".into(), Default::default())));
+        assert!(events.contains(&Event::Text(" ".into())));
+        let depth: isize = events
+            .iter()
+            .map(|event| match event {
+                Event::Start(_) => 1,
+                Event::End(_) => -1,
+                _ => 0,
+            })
+            .sum();
+        assert_eq!(depth, 0);
+    }
+
+    #[test]
+    fn diff_events_structure() {
+        let old = "# Title\n\n- a\n- b\n\n[link](/old)\n\nGone.\n";
+        let new = "# Title\n\n- a\n- c\n- b\n\n[link](/new)\n";
+        let diffs = diff_events(old, new, Options::empty());
+        assert_eq!(
+            diffs,
+            [
+                NodeDiff::Inserted { new_span: 13..17 },
+                NodeDiff::Changed {
+                    old_span: 18..30,
+                    new_span: 22..34
+                },
+                NodeDiff::Removed { old_span: 32..38 },
+            ]
+        );
+        assert_eq!(&new[13..17], "- c\n");
+        assert!(diff_events(old, old, Options::empty()).is_empty());
+        // Reflowing a paragraph does not change its structure.
+        assert!(diff_events("a\nb", "a\nb\n", Options::empty()).is_empty());
+    }
+
+    #[test]
+    fn reference_link_conversion() {
+        let input = "[a](/x) [b](/y 'T') [c](/x) [d][1] [e](</x y>)\n\n[1]: /one\n";
+        let converted = to_reference_links(input, Options::empty());
+        assert_eq!(
+            converted,
+            "[a][2] [b][3] [c][2] [d][1] [e][4]\n\n[1]: /one\n\n[2]: /x\n[3]: /y 'T'\n[4]: </x y>\n"
+        );
+        let reused = to_reference_links("[a](/one)\n\n[Home]: /one\n", Options::empty());
+        assert_eq!(reused, "[a][Home]\n\n[Home]: /one\n");
+
+        let inlined = to_inline_links(&converted, Options::empty());
+        assert_eq!(
+            inlined,
+            "[a](/x) [b](/y 'T') [c](/x) [d](/one) [e](</x y>)\n\n\n"
+        );
+        let links = |text: &str| -> Vec<_> {
+            extract_links(text, Options::empty())
+                .into_iter()
+                .map(|link| (link.dest_url.into_string(), link.title.into_string()))
+                .collect()
+        };
+        assert_eq!(links(&converted), links(input));
+        assert_eq!(links(&inlined), links(input));
+
+        // An unclosed fence is closed before the definitions.
+        let fenced = to_reference_links("[a](/x)\n```\ncode", Options::empty());
+        assert_eq!(fenced, "[a][1]\n```\ncode\n```\n\n[1]: /x\n");
+        assert_eq!(links(&fenced), [("/x".to_owned(), String::new())]);
+        let closed = to_reference_links("[a](/x)\n~~~~\n~~~\n~~~~", Options::empty());
+        assert_eq!(closed, "[a][1]\n~~~~\n~~~\n~~~~\n\n[1]: /x\n");
+
+        // The definition used by the nested image is kept.
+        let nested = "[![img][i]][l]\n\n[i]: /i.png\n[l]: /l\n";
+        assert_eq!(
+            to_inline_links(nested, Options::empty()),
+            "[![img][i]](/l)\n\n[i]: /i.png\n"
+        );
+    }
+
+    #[test]
+    fn extract_tables_cells() {
+        let input = "Intro\n\n| a | b |\n|---|:-:|\n| x \\| y | \"q\" |\n| [l](/u) | $m$ |\n\n| one |\n|-----|\n";
+        let options = Options::ENABLE_TABLES | Options::ENABLE_MATH;
+        let tables = extract_tables(input, options);
+        assert_eq!(tables.len(), 2);
+        assert_eq!(tables[0].headers, ["a", "b"]);
+        assert_eq!(tables[0].rows, [["x | y", "\"q\""], ["l", "m"]]);
+        assert_eq!(tables[0].alignments, [Alignment::None, Alignment::Center]);
+        assert!(input[tables[0].span.clone()].starts_with("| a |"));
+        assert_eq!(tables[0].to_csv(), "a,b\nx | y,\"\"\"q\"\"\"\nl,m\n");
+        assert_eq!(tables[1].headers, ["one"]);
+        assert!(tables[1].rows.is_empty());
+        assert!(extract_tables(input, Options::empty()).is_empty());
+    }
+
+    #[test]
+    fn extract_code_blocks_nested() {
+        let input = "- item\n\n  ```sh\n  echo 1\n  echo 2\n  ```\n\n> ```\n> plain\n> ```\n";
+        let blocks = extract_code_blocks(input, Options::empty(), None);
+        assert_eq!(blocks.len(), 2);
+        assert_eq!(blocks[0].language, "sh");
+        assert_eq!(blocks[0].code, "echo 1\necho 2\n");
+        assert_eq!(
+            &input[blocks[0].span.clone()],
+            "```sh\n  echo 1\n  echo 2\n  ```"
+        );
+        assert_eq!(blocks[1].language, "");
+        assert_eq!(blocks[1].code, "plain\n");
+        assert!(extract_code_blocks(input, Options::empty(), Some("")).is_empty());
+        assert_eq!(
+            extract_code_blocks(input, Options::empty(), Some("SH")).len(),
+            1
+        );
+    }
+
+    #[test]
+    fn task_progress_items() {
+        let input = "1. [ ] first\n   line\n\n   second paragraph\n2. plain\n\n> - [X] quoted\n";
+        let progress = task_progress(input, Options::ENABLE_TASKLISTS);
+        assert_eq!(progress.total, 2);
+        assert_eq!(progress.checked, 1);
+        assert_eq!(progress.items[0].text, "first line second paragraph");
+        assert!(!progress.items[0].checked);
+        assert_eq!(progress.items[1].text, "quoted");
+        assert!(progress.items[1].checked);
+        assert_eq!(
+            task_progress(input, Options::empty()),
+            TaskProgress::default()
+        );
+    }
+
+    #[test]
+    fn check_anchors_links() {
+        let documents = [
+            (
+                "a/index.md",
+                "# Intro {#top}\n## Intro\n## Intro\n[ok](#intro-1) [ok](../b.md#b) [bad](../b.md#c)\n",
+            ),
+            (
+                "b.md",
+                "# B\n[ext](https://x.org/#z) [other](c.md#z) [ok](a/index.md#top) [bad](#top)\n",
+            ),
+        ];
+        let options = Options::ENABLE_HEADING_ATTRIBUTES;
+        let issues = check_anchors(&documents, options);
+        let summary: Vec<_> = issues
+            .iter()
+            .map(|issue| match issue {
+                AnchorIssue::DuplicateAnchor {
+                    document, anchor, ..
+                } => (*document, None, anchor.as_str()),
+                AnchorIssue::BrokenFragment {
+                    document,
+                    target,
+                    fragment,
+                    ..
+                } => (*document, Some(*target), fragment.as_str()),
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            [(0, None, "intro"), (0, Some(1), "c"), (1, Some(1), "top")]
+        );
+    }
+
+    #[test]
+    fn check_anchors_block_ids() {
+        let documents = [
+            (
+                "a.md",
+                "Quote ^quote\n\nSee [[b#^gone]] [[a#^quote]] [x](#^quote)\n",
+            ),
+            ("b", "- item ^kept\n"),
+        ];
+        let options = Options::ENABLE_BLOCK_IDS | Options::ENABLE_WIKILINKS;
+        let issues = check_anchors(&documents, options);
+        assert!(matches!(
+            issues.as_slice(),
+            [AnchorIssue::BrokenFragment { document: 0, target: 1, fragment, .. }] if fragment == "^gone"
+        ));
+        assert_eq!(
+            extract_block_ids(documents[1].1, Options::empty())[0]
+                .id
+                .as_ref(),
+            "kept"
+        );
+    }
+
+    #[test]
+    fn split_documents_markers() {
+        let input = "---\ntitle: deck\n---\n\n# One\n---  \ntwo\n---\n\n---\n";
+        let options = Options::ENABLE_YAML_STYLE_METADATA_BLOCKS;
+        let documents = split_documents(input, options, "---");
+        let sources: Vec<_> = documents.iter().map(|d| d.source).collect();
+        assert_eq!(
+            sources,
+            ["---\ntitle: deck\n---\n\n# One\n", "two\n", "\n", ""]
+        );
+
+        let input = "a\n<div>\n---\n</div>\n\n---\nb";
+        let documents = split_documents(input, Options::empty(), "---");
+        assert_eq!(documents.len(), 2);
+        let spans: Vec<_> = documents[0]
+            .offset_iter(Options::empty())
+            .map(|(_, range)| &input[range])
+            .collect();
+        assert_eq!(spans[3], "<div>\n---\n</div>\n");
+        assert_eq!(documents[1].range, 24..25);
+        assert_eq!(split_documents(input, Options::empty(), "").len(), 1);
+    }
+
+    #[test]
+    fn events_equivalent_ignores_whitespace() {
+        let fragmented = [
+            Event::Start(Tag::Paragraph(None)),
+            Event::Text("  foo".into()),
+            Event::Text(" \t".into()),
+            Event::SoftBreak,
+            Event::Text("bar ".into()),
+            Event::End(TagEnd::Paragraph),
+        ];
+        assert!(events_equivalent(fragmented, Parser::new("foo bar")));
+        assert!(!events_equivalent(
+            Parser::new("foo bar"),
+            Parser::new("foobar")
+        ));
+        assert!(events_equivalent(
+            Parser::new("- a\n-   b\n"),
+            Parser::new("- a\n- b")
+        ));
+        assert!(!events_equivalent(
+            Parser::new("```\na  b\n```"),
+            Parser::new("```\na b\n```")
+        ));
+        assert!(!events_equivalent(
+            Parser::new("a `x  y`"),
+            Parser::new("a `x y`")
+        ));
+    }
+
+    #[test]
+    fn string_interner_shares_copies() {
+        let input = "\
+# One {.section-heading}
+# Two {.section-heading}
+
+[first][shared-reference] [second][shared-reference] [^long-footnote-label]
+
+[shared-reference]: https://example.com/a/long/destination
+[^long-footnote-label]: Note.
+
+::: note {.section-heading}
+![a](a.png){.section-heading}
+:::
+";
+        let options = Options::ENABLE_HEADING_ATTRIBUTES
+            | Options::ENABLE_FOOTNOTES
+            | Options::ENABLE_CONTAINER_EXTENSIONS
+            | Options::ENABLE_IMAGE_ATTRIBUTES;
+        let events: Vec<Event<'static>> = Parser::new_ext(input, options)
+            .map(Event::into_static)
+            .collect();
+        let mut interner = StringInterner::new();
+        for event in &events {
+            interner.insert_event(event);
+        }
+        // The class, the urls, the reference id, the footnote label and the
+        // container name.
+        assert_eq!(interner.len(), 6);
+
+        let interned: Vec<_> = events
+            .iter()
+            .cloned()
+            .map(|event| interner.intern_event(event))
+            .collect();
+        assert_eq!(interned, events);
+        let urls: Vec<_> = interned
+            .iter()
+            .filter_map(|event| match event {
+                Event::Start(Tag::Link { dest_url, .. }) => Some(dest_url.as_ptr()),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(urls.len(), 2);
+        assert_eq!(urls[0], urls[1]);
+        let classes: Vec<_> = interned
+            .iter()
+            .filter_map(|event| match event {
+                Event::Start(
+                    Tag::Heading {
+                        attributes: Some(attributes),
+                        ..
+                    }
+                    | Tag::ContainerBlock(_, _, Some(attributes)),
+                ) => Some(attributes.classes[0].as_ptr()),
+                Event::Start(Tag::Image {
+                    extras: Some(extras),
+                    ..
+                }) => Some(extras.attributes.classes[0].as_ptr()),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(classes.len(), 4);
+        assert!(classes.iter().all(|&class| class == classes[0]));
+    }
+
+    #[test]
+    fn document_symbols_nesting() {
+        let input = "\
+Intro[^note]
+
+# One
+
+## One A
+
+```rust,ignore
+fn main() {}
+```
+
+### Deep
+
+## One B
+
+[^note]: A footnote.
+
+# Two
+
+[Ref \\] label]: /url
+";
+        let options = Options::ENABLE_FOOTNOTES;
+        let symbols = document_symbols(input, options);
+        let outline: Vec<_> = symbols
+            .iter()
+            .map(|s| (s.name.as_str(), s.children.len()))
+            .collect();
+        assert_eq!(outline, [("One", 2), ("Two", 1)]);
+
+        let one = &symbols[0];
+        assert_eq!(
+            &input[one.range.clone()],
+            &input[input.find("# One").unwrap()..input.find("# Two").unwrap()]
+        );
+        let one_a = &one.children[0];
+        assert_eq!(one_a.name, "One A");
+        assert_eq!(one_a.children[0].kind, SymbolKind::CodeBlock);
+        assert_eq!(one_a.children[0].name, "rust");
+        assert_eq!(&input[one_a.children[0].selection_range.clone()], "rust");
+        assert_eq!(one_a.children[1].name, "Deep");
+        let note = &one.children[1].children[0];
+        assert_eq!(note.kind, SymbolKind::FootnoteDefinition);
+        assert_eq!(&input[note.selection_range.clone()], "[^note]");
+
+        let reference = &symbols[1].children[0];
+        assert_eq!(reference.kind, SymbolKind::LinkReferenceDefinition);
+        assert_eq!(&input[reference.selection_range.clone()], "[Ref \\] label]");
+        assert_eq!(reference.detail.as_deref(), Some("/url"));
+    }
+
+    #[test]
+    fn line_groups() {
+        let input = "> quote\n> more\n\n```\na\n\nb\n```\n- x\n";
+        let lines: Vec<_> = LineGroups::new(input, Parser::new(input).into_offset_iter()).collect();
+        let numbers: Vec<_> = lines.iter().map(|(line, _)| *line).collect();
+        assert_eq!(numbers, [0, 1, 3, 4, 5, 6, 7, 8]);
+        assert_eq!(lines[3].1, [Event::Text("a\n".into())]);
+        assert_eq!(
+            lines[1].1,
+            [
+                Event::Text("more".into()),
+                Event::End(TagEnd::Paragraph),
+                Event::End(TagEnd::BlockQuote(None)),
+            ]
+        );
+        assert_eq!(lines[5].1, [Event::Text("b\n".into())]);
+        assert_eq!(lines[6].1, [Event::End(TagEnd::CodeBlock)]);
+        assert_eq!(lines[7].1.len(), 5);
+
+        let all: Vec<_> = lines.into_iter().flat_map(|(_, events)| events).collect();
+        let merged: Vec<_> = TextMergeStream::new(all.into_iter()).collect();
+        assert_eq!(merged, Parser::new(input).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn fix_edits() {
+        let input = "Go to www.example.com/a_(b), <https://x.io> or https://y.io\n";
+        let edits = fix_bare_urls(input, Options::empty());
+        assert_eq!(
+            apply_edits(input, &edits),
+            "Go to [www.example.com/a_(b)](http://www.example.com/a_(b)), <https://x.io> or <https://y.io>\n"
+        );
+        for input in ["Run `curl https://x.com/a` now", "[see www.x.com](/u)"] {
+            assert_eq!(fix_bare_urls(input, Options::empty()), []);
+        }
+        // `>` would end the autolink early
+        assert_eq!(fix_bare_urls("x https://a.b/c>d", Options::empty()), []);
+
+        let input = "[a]: /one\n> [a]: /two\n\n- ```rust\n  code";
+        let mut parser = Parser::new_ext(input, Options::ENABLE_DIAGNOSTICS);
+        parser.by_ref().for_each(drop);
+        let edits: Vec<_> = parser
+            .diagnostics()
+            .iter()
+            .filter_map(|diagnostic| fix_diagnostic(input, diagnostic))
+            .collect();
+        assert_eq!(edits.len(), 2);
+        let fixed = apply_edits(input, &edits);
+        assert_eq!(fixed, "[a]: /one\n\n- ```rust\n  code\n  ```\n");
+        let mut parser = Parser::new_ext(&fixed, Options::ENABLE_DIAGNOSTICS);
+        parser.by_ref().for_each(drop);
+        assert!(parser.diagnostics().is_empty());
+    }
+
+    #[test]
+    fn folding_ranges_kinds() {
+        let input = "\
+---
+title: x
+---
+
+# One
+
+> quote
+> more
+
+## Sub
+
+1. a
+   - nested
+   - items
+2. b
+
+
+# Two
+single line
+";
+        let options = Options::ENABLE_YAML_STYLE_METADATA_BLOCKS;
+        let ranges: Vec<_> = folding_ranges(input, options)
+            .into_iter()
+            .map(|range| (range.start_line, range.end_line, range.kind))
+            .collect();
+        assert_eq!(
+            ranges,
+            [
+                (0, 2, FoldingKind::FrontMatter),
+                (4, 14, FoldingKind::Section),
+                (6, 7, FoldingKind::BlockQuote),
+                (9, 14, FoldingKind::Section),
+                (11, 14, FoldingKind::List),
+                (12, 13, FoldingKind::List),
+                (17, 18, FoldingKind::Section),
+            ]
+        );
+    }
+
+    #[test]
+    fn node_at_paths() {
+        let input = "# Title\n\n- item [link *em*](/url)\n\n  second\n\nafter\n";
+        let tags = |offset| -> Vec<TagEnd> {
+            node_at(input, Options::empty(), offset)
+                .iter()
+                .map(|(tag, _)| tag.to_end())
+                .collect()
+        };
+        assert_eq!(tags(2), [TagEnd::Heading(HeadingLevel::H1)]);
+        assert_eq!(tags(8), []);
+        assert_eq!(
+            tags(input.find("*em*").unwrap() + 1),
+            [
+                TagEnd::List(false),
+                TagEnd::Item,
+                TagEnd::Paragraph,
+                TagEnd::Link,
+                TagEnd::Emphasis
+            ]
+        );
+        assert_eq!(
+            tags(input.find("second").unwrap()),
+            [TagEnd::List(false), TagEnd::Item, TagEnd::Paragraph]
+        );
+        assert_eq!(tags(input.find("after").unwrap()), [TagEnd::Paragraph]);
+        assert_eq!(tags(input.len()), []);
+
+        let path = node_at(input, Options::empty(), input.find("link").unwrap());
+        assert_eq!(&input[path[3].1.clone()], "[link *em*](/url)");
+    }
+
+    #[test]
+    fn completion_context_kinds() {
+        fn at<'a>(input: &'a str, cursor: &str) -> Option<(CompletionKind, &'a str, &'a str)> {
+            let options = Options::ENABLE_WIKILINKS | Options::ENABLE_FOOTNOTES;
+            let offset = input.find(cursor).unwrap();
+            completion_context(input, options, offset)
+                .map(|context| (context.kind, context.prefix, &input[context.range]))
+        }
+        assert_eq!(
+            at("a [x](img/pic.png) b", ".png"),
+            Some((CompletionKind::LinkDestination, "img/pic", "img/pic.png"))
+        );
+        assert_eq!(at("a [x](img \"title\") b", "tle"), None);
+        assert_eq!(
+            at("a [x][ref-la", "la"),
+            Some((CompletionKind::ReferenceLabel, "ref-", "ref-la"))
+        );
+        assert_eq!(
+            at("see [[Some Page#head|alias]]", "Page"),
+            Some((CompletionKind::WikilinkTarget, "Some ", "Some Page"))
+        );
+        assert_eq!(at("see [[Some Page#head|alias]]", "ead"), None);
+        assert_eq!(
+            at("note[^fo]", "o]"),
+            Some((CompletionKind::FootnoteLabel, "f", "fo"))
+        );
+        assert_eq!(
+            at("text\n\n  ~~~ rust,ignore\n  code\n  ~~~\n", "st,"),
+            Some((CompletionKind::CodeFenceInfo, " ru", " rust,ignore"))
+        );
+        assert_eq!(at("```\n[x](in code\n```\n", "code"), None);
+        assert_eq!(at("`[x](in code` span", "code"), None);
+        assert_eq!(at("plain text", "text"), None);
+    }
+
+    #[test]
+    fn section_boundaries() {
+        let input = "\
+Preamble
+
+# Intro
+
+## Notes
+
+Text.
+
+## Notes
+
+Other.
+
+# Next {#custom}
+
+Setext
+------
+
+Body.
+";
+        let options = Options::ENABLE_HEADING_ATTRIBUTES;
+        let intro = section(input, options, "intro").unwrap();
+        assert_eq!(intro.level, HeadingLevel::H1);
+        assert_eq!(&input[intro.heading.clone()], "# Intro\n");
+        assert_eq!(
+            &input[intro.span],
+            "# Intro\n\n## Notes\n\nText.\n\n## Notes\n\nOther.\n\n"
+        );
+        assert_eq!(
+            section(input, options, "notes").unwrap().content,
+            "\nText.\n\n"
+        );
+        assert_eq!(
+            section(input, options, "#notes-1").unwrap().content,
+            "\nOther.\n\n"
+        );
+        let next = section(input, options, "custom").unwrap();
+        assert_eq!(next.span.end, input.len());
+        assert_eq!(
+            section(input, options, "Setext").unwrap().content,
+            "\nBody.\n"
+        );
+        assert_eq!(section(input, options, "Next").unwrap().anchor, "custom");
+        assert!(section(input, options, "missing").is_none());
+    }
+
+    #[test]
+    fn element_census_counts() {
+        let input = "\
+# Title
+
+    indented
+
+- [a][a]
+- [b]
+
+1. ![img](i.png)
+
+~~~python extra
+x = 1
+~~~
+
+```
+plain
+```
+
+[a]: /a
+[b]: /b
+";
+        let census = element_census(input, Options::empty());
+        assert_eq!(census.headings(HeadingLevel::H1), 1);
+        assert_eq!(census.headings(HeadingLevel::H2), 0);
+        assert_eq!(census.count(TagEnd::List(false)), 1);
+        assert_eq!(census.count(TagEnd::List(true)), 1);
+        assert_eq!(census.count(TagEnd::Item), 3);
+        assert_eq!(census.count(TagEnd::Image), 1);
+        assert_eq!(
+            census.code_blocks.into_iter().collect::<Vec<_>>(),
+            [("".to_owned(), 2), ("python".to_owned(), 1)]
+        );
+        assert_eq!(
+            census.links.into_iter().collect::<Vec<_>>(),
+            [(LinkType::Reference, 1), (LinkType::Shortcut, 1)]
+        );
+    }
+
+    #[test]
+    fn used_extensions_detection() {
+        let all = Options::all();
+        assert_eq!(used_extensions("plain *text*\n", all), Options::empty());
+        // Smart punctuation only counts when it changed something.
+        assert_eq!(used_extensions("it’s\n", all), Options::empty());
+        assert_eq!(
+            used_extensions("it's\n", all),
+            Options::ENABLE_SMART_PUNCTUATION
+        );
+        let footnotes = "a[^1]\n\n[^1]: b\n";
+        assert_eq!(
+            used_extensions(footnotes, Options::ENABLE_OLD_FOOTNOTES),
+            Options::ENABLE_OLD_FOOTNOTES
+        );
+        assert_eq!(
+            used_extensions("# A {#a}\n\n> [!NOTE]\n> b\n", all),
+            Options::ENABLE_HEADING_ATTRIBUTES | Options::ENABLE_GFM
+        );
+        assert_eq!(
+            used_extensions("# A {#a}\n", Options::empty()),
+            Options::empty()
+        );
+        assert_eq!(
+            used_extensions("<https://a.b> www.c.d\n", all),
+            Options::ENABLE_AUTOLINK_LITERALS
+        );
+        assert_eq!(used_extensions("<https://a.b>\n", all), Options::empty());
+    }
+
+    #[test]
+    fn decoded_input_offsets() {
+        let bytes = b"a\xFF\xFEb\n\n# c\xC3";
+        let input = DecodedInput::new(bytes, InvalidUtf8::Remove);
+        assert_eq!(input.text(), "ab\n\n# c");
+        assert!(input.is_lossy());
+        assert_eq!(input.original_range(1..2), 3..4);
+        let spans: Vec<_> = input
+            .offset_iter(Options::empty())
+            .map(|(_, range)| &bytes[range])
+            .collect();
+        // the removed sequence at the end is not part of the heading
+        assert_eq!(spans[3..], [&b"# c"[..], b"c", b"# c"]);
+
+        let input = DecodedInput::new(b"plain", InvalidUtf8::Replace);
+        assert!(!input.is_lossy());
+        assert_eq!(input.original_offset(5), 5);
+
+        let bytes = b"\xEF\xBB\xBF# a\xFF\xFE\xFDb";
+        let input = DecodedInput::new(bytes, InvalidUtf8::Remove);
+        assert_eq!(input.text(), "# ab");
+        assert!(input.is_lossy());
+        assert_eq!(input.original_range(0..4), 3..10);
+        assert_eq!(input.original_range(3..3), 6..6);
+        assert_eq!(input.original_offset(3), 9);
+        let input = DecodedInput::new(bytes, InvalidUtf8::Replace);
+        assert_eq!(input.original_offset(4), 6);
+        assert_eq!(input.original_offset(10), 8);
+        assert_eq!(input.original_offset(12), 9);
+    }
+
+    #[test]
+    fn input_map_sources() {
+        let mut input = InputMap::new();
+        assert_eq!(input.locate(0), None);
+        input.push("a.md", "- one");
+        input.push("b.md", "- two\n");
+        assert_eq!(input.text(), "- one\n- two\n");
+        assert_eq!(input.len(), 2);
+        assert_eq!(input.name(1), Some("b.md"));
+        assert_eq!(input.source_range(1), Some(6..12));
+        // the inserted newline belongs to the first source
+        assert_eq!(input.locate(5), Some((0, 5)));
+        assert_eq!(input.locate(6), Some((1, 0)));
+        assert_eq!(input.locate(13), None);
+
+        let spans: Vec<_> = input
+            .offset_iter(Options::empty())
+            .map(|(_, span)| span.unwrap())
+            .collect();
+        // the list continues into the second source and is cut at the first
+        assert_eq!((spans[0].name, spans[0].range.clone()), ("a.md", 0..5));
+        let item = &spans[spans.len() - 4];
+        assert_eq!((item.index, item.range.clone()), (1, 0..6));
+    }
+
+    #[test]
+    fn read_front_matter_matches_parser() {
+        let options = Options::ENABLE_YAML_STYLE_METADATA_BLOCKS
+            | Options::ENABLE_PLUSES_DELIMITED_METADATA_BLOCKS;
+        for input in [
+            "---\ntitle: a\ndate: b\n...   \n# Body\n",
+            "+++\r\ntitle = \"a\"\r\n+++\r\n",
+            "---\na: b\n\n---",
+        ] {
+            let (kind, front_matter, span) = read_front_matter(input).unwrap();
+            let mut events = Parser::new_ext(input, options).into_offset_iter();
+            let (start, range) = events.next().unwrap();
+            assert_eq!(start, Event::Start(Tag::MetadataBlock(kind)));
+            assert_eq!(range, span);
+            let text: String = events
+                .map_while(|(event, _)| match event {
+                    Event::Text(text) => Some(text.into_string()),
+                    _ => None,
+                })
+                .collect();
+            assert_eq!(text, front_matter.replace("\r\n", "\n"));
+        }
+        assert!(read_front_matter("\n---\na: b\n---\n").is_none());
+        assert!(read_front_matter("---\n\na: b\n---\n").is_none());
+        assert!(read_front_matter("---\na: b\n").is_none());
+    }
+
+    #[test]
+    fn find_in_text_skips_markup() {
+        let input = "# Needle\n\nA need\nle and \\*needle\\*.\n\n```\nneedle\n```\n\n\
+                     ![needle](needle.png) [needle](needle) <b title=needle>x</b>\n\n- nee\n- dle\n";
+        let found: Vec<_> = find_in_text(input, Options::empty(), "needle")
+            .into_iter()
+            .map(|span| &input[span])
+            .collect();
+        assert_eq!(found, ["needle", "needle"]);
+        let span = find_in_text(input, Options::empty(), "need le")[0].clone();
+        assert_eq!(&input[span], "need\nle");
+        let found = find_in_text(input, Options::empty(), "*needle*");
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0], 25..34);
+        assert!(find_in_text(input, Options::empty(), "").is_empty());
+    }
+
+    #[test]
+    fn truncate_markdown_balances_events() {
+        let render = |input: &str, max_chars| {
+            let mut output = String::new();
+            crate::html::push_html(
+                &mut output,
+                truncate_markdown(input, Options::empty(), max_chars).into_iter(),
+            );
+            output
+        };
+        let input = "> 1. ![alt text](a.png) one *two [three](x)*
+
+- four";
+        assert_eq!(
+            render(input, 12),
+            "<blockquote>\n<ol class=\"list-decimal list-inside list\">\n<li>\
+             <img src=\"a.png\" alt=\"alt text\" /> one <em>two <a class=\"link\" href=\"x\">thr…</a></em></li>\n\
+             </ol>\n</blockquote>\n"
+        );
+        // The limit falls between blocks: the next block is not started.
+        assert_eq!(
+            render(input, 14),
+            "<blockquote>\n<ol class=\"list-decimal list-inside list\">\n<li>\
+             <img src=\"a.png\" alt=\"alt text\" /> one <em>two <a class=\"link\" href=\"x\">three…</a></em></li>\n\
+             </ol>\n</blockquote>\n"
+        );
+        let whole: Vec<_> = Parser::new(input).collect();
+        assert_eq!(truncate_markdown(input, Options::empty(), 18), whole);
     }
 }
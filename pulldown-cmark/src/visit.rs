@@ -0,0 +1,410 @@
+//! 访问者（Visitor）
+//!
+//! 通过实现 [`Visitor`] trait 中的 enter/exit 方法来编写分析逻辑（链接检查、lint 等），
+//! 遍历由 [`walk_events`]、[`walk_offset_events`] 或 [`walk_ast`] 驱动，无需手写庞大的 `match` 循环。
+//!
+//! ```rust
+//! use core::ops::Range;
+//! use pulldown_cmark::{visit::{walk_events, Flow, Visitor}, CowStr, LinkType, Parser};
+//!
+//! #[derive(Default)]
+//! struct LinkCollector(Vec<String>);
+//!
+//! impl<'a> Visitor<'a> for LinkCollector {
+//!     fn enter_link(
+//!         &mut self,
+//!         _link_type: LinkType,
+//!         dest_url: &CowStr<'a>,
+//!         _title: &CowStr<'a>,
+//!         _span: Range<usize>,
+//!     ) -> Flow {
+//!         self.0.push(dest_url.to_string());
+//!         Flow::SkipChildren
+//!     }
+//! }
+//!
+//! let mut collector = LinkCollector::default();
+//! walk_events(Parser::new("[a](https://a.com) and [b](/b)"), &mut collector);
+//! assert_eq!(collector.0, ["https://a.com", "/b"]);
+//! ```
+
+use alloc::vec::Vec;
+use core::ops::Range;
+
+use crate::{
+    ast::{Ast, Node, NodeKind},
+    Alignment, BlockQuoteKind, CodeBlockKind, CowStr, Event, HeadingLevel, LinkType, Tag,
+};
+
+/// 控制遍历流程。
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Flow {
+    /// 继续遍历子节点。
+    Continue,
+    /// 跳过当前标签的子节点，但仍会调用对应的 exit 方法。
+    SkipChildren,
+    /// 立即停止整个遍历。
+    Stop,
+}
+
+/// 事件树的访问者。
+///
+/// 所有方法都有默认实现，只需覆盖关心的部分。
+/// [`enter`](Visitor::enter) 和 [`exit`](Visitor::exit) 默认分派到各个标签对应的方法，
+/// 没有专门方法的标签分派到 [`enter_other`](Visitor::enter_other) / [`exit_other`](Visitor::exit_other)；
+/// 叶子事件同理分派到 `visit_*` 方法。
+///
+/// 不带偏移遍历时，`span` 总是 `0..0`。
+#[allow(unused_variables)]
+pub trait Visitor<'a> {
+    /// 进入任意标签。
+    fn enter(&mut self, tag: &Tag<'a>, span: Range<usize>) -> Flow {
+        match tag {
+            Tag::Paragraph(_) => self.enter_paragraph(span),
+            Tag::Heading { level, id, .. } => self.enter_heading(*level, id.as_ref(), span),
+            Tag::BlockQuote { kind, .. } => self.enter_block_quote(*kind, span),
+            Tag::CodeBlock(kind) => self.enter_code_block(kind, span),
+            Tag::List(start) => self.enter_list(*start, span),
+            Tag::Item(..) => self.enter_item(span),
+            Tag::FootnoteDefinition(label) => self.enter_footnote_definition(label, span),
+            Tag::Table(alignments) => self.enter_table(alignments, span),
+            Tag::Emphasis => self.enter_emphasis(span),
+            Tag::Strong => self.enter_strong(span),
+            Tag::Link {
+                link_type,
+                dest_url,
+                title,
+                ..
+            } => self.enter_link(*link_type, dest_url, title, span),
+            Tag::Image {
+                link_type,
+                dest_url,
+                title,
+                ..
+            } => self.enter_image(*link_type, dest_url, title, span),
+            tag => self.enter_other(tag, span),
+        }
+    }
+
+    /// 离开任意标签。
+    fn exit(&mut self, tag: &Tag<'a>, span: Range<usize>) {
+        match tag {
+            Tag::Paragraph(_) => self.exit_paragraph(span),
+            Tag::Heading { level, id, .. } => self.exit_heading(*level, id.as_ref(), span),
+            Tag::BlockQuote { kind, .. } => self.exit_block_quote(*kind, span),
+            Tag::CodeBlock(kind) => self.exit_code_block(kind, span),
+            Tag::List(start) => self.exit_list(*start, span),
+            Tag::Item(..) => self.exit_item(span),
+            Tag::FootnoteDefinition(label) => self.exit_footnote_definition(label, span),
+            Tag::Table(alignments) => self.exit_table(alignments, span),
+            Tag::Emphasis => self.exit_emphasis(span),
+            Tag::Strong => self.exit_strong(span),
+            Tag::Link {
+                link_type,
+                dest_url,
+                title,
+                ..
+            } => self.exit_link(*link_type, dest_url, title, span),
+            Tag::Image {
+                link_type,
+                dest_url,
+                title,
+                ..
+            } => self.exit_image(*link_type, dest_url, title, span),
+            tag => self.exit_other(tag, span),
+        }
+    }
+
+    /// 访问任意叶子事件（不会是 `Start` 或 `End`）。
+    fn visit(&mut self, event: &Event<'a>, span: Range<usize>) -> Flow {
+        match event {
+            Event::Text(text) => self.visit_text(text, span),
+            Event::Code(code, _) => self.visit_code(code, span),
+            Event::Html(html) | Event::InlineHtml(html) => self.visit_html(html, span),
+            Event::FootnoteReference(label) => self.visit_footnote_reference(label, span),
+            event => self.visit_other(event, span),
+        }
+    }
+
+    fn enter_paragraph(&mut self, span: Range<usize>) -> Flow {
+        Flow::Continue
+    }
+    fn exit_paragraph(&mut self, span: Range<usize>) {}
+
+    fn enter_heading(
+        &mut self,
+        level: HeadingLevel,
+        id: Option<&CowStr<'a>>,
+        span: Range<usize>,
+    ) -> Flow {
+        Flow::Continue
+    }
+    fn exit_heading(&mut self, level: HeadingLevel, id: Option<&CowStr<'a>>, span: Range<usize>) {}
+
+    fn enter_block_quote(&mut self, kind: Option<BlockQuoteKind>, span: Range<usize>) -> Flow {
+        Flow::Continue
+    }
+    fn exit_block_quote(&mut self, kind: Option<BlockQuoteKind>, span: Range<usize>) {}
+
+    fn enter_code_block(&mut self, kind: &CodeBlockKind<'a>, span: Range<usize>) -> Flow {
+        Flow::Continue
+    }
+    fn exit_code_block(&mut self, kind: &CodeBlockKind<'a>, span: Range<usize>) {}
+
+    fn enter_list(&mut self, start: Option<u64>, span: Range<usize>) -> Flow {
+        Flow::Continue
+    }
+    fn exit_list(&mut self, start: Option<u64>, span: Range<usize>) {}
+
+    fn enter_item(&mut self, span: Range<usize>) -> Flow {
+        Flow::Continue
+    }
+    fn exit_item(&mut self, span: Range<usize>) {}
+
+    fn enter_footnote_definition(&mut self, label: &CowStr<'a>, span: Range<usize>) -> Flow {
+        Flow::Continue
+    }
+    fn exit_footnote_definition(&mut self, label: &CowStr<'a>, span: Range<usize>) {}
+
+    fn enter_table(&mut self, alignments: &[Alignment], span: Range<usize>) -> Flow {
+        Flow::Continue
+    }
+    fn exit_table(&mut self, alignments: &[Alignment], span: Range<usize>) {}
+
+    fn enter_emphasis(&mut self, span: Range<usize>) -> Flow {
+        Flow::Continue
+    }
+    fn exit_emphasis(&mut self, span: Range<usize>) {}
+
+    fn enter_strong(&mut self, span: Range<usize>) -> Flow {
+        Flow::Continue
+    }
+    fn exit_strong(&mut self, span: Range<usize>) {}
+
+    fn enter_link(
+        &mut self,
+        link_type: LinkType,
+        dest_url: &CowStr<'a>,
+        title: &CowStr<'a>,
+        span: Range<usize>,
+    ) -> Flow {
+        Flow::Continue
+    }
+    fn exit_link(
+        &mut self,
+        link_type: LinkType,
+        dest_url: &CowStr<'a>,
+        title: &CowStr<'a>,
+        span: Range<usize>,
+    ) {
+    }
+
+    fn enter_image(
+        &mut self,
+        link_type: LinkType,
+        dest_url: &CowStr<'a>,
+        title: &CowStr<'a>,
+        span: Range<usize>,
+    ) -> Flow {
+        Flow::Continue
+    }
+    fn exit_image(
+        &mut self,
+        link_type: LinkType,
+        dest_url: &CowStr<'a>,
+        title: &CowStr<'a>,
+        span: Range<usize>,
+    ) {
+    }
+
+    /// 没有专门方法的标签。
+    fn enter_other(&mut self, tag: &Tag<'a>, span: Range<usize>) -> Flow {
+        Flow::Continue
+    }
+    /// 没有专门方法的标签。
+    fn exit_other(&mut self, tag: &Tag<'a>, span: Range<usize>) {}
+
+    fn visit_text(&mut self, text: &CowStr<'a>, span: Range<usize>) -> Flow {
+        Flow::Continue
+    }
+    fn visit_code(&mut self, code: &CowStr<'a>, span: Range<usize>) -> Flow {
+        Flow::Continue
+    }
+    /// `Html` 和 `InlineHtml` 事件。
+    fn visit_html(&mut self, html: &CowStr<'a>, span: Range<usize>) -> Flow {
+        Flow::Continue
+    }
+    fn visit_footnote_reference(&mut self, label: &CowStr<'a>, span: Range<usize>) -> Flow {
+        Flow::Continue
+    }
+    /// 没有专门方法的叶子事件。
+    fn visit_other(&mut self, event: &Event<'a>, span: Range<usize>) -> Flow {
+        Flow::Continue
+    }
+}
+
+/// 使用访问者遍历事件流。返回 `false` 表示遍历被 [`Flow::Stop`] 中止。
+pub fn walk_events<'a, I, V>(events: I, visitor: &mut V) -> bool
+where
+    I: IntoIterator<Item = Event<'a>>,
+    V: Visitor<'a> + ?Sized,
+{
+    walk_offset_events(events.into_iter().map(|event| (event, 0..0)), visitor)
+}
+
+/// 使用访问者遍历带偏移的事件流，例如 [`OffsetIter`](crate::OffsetIter)。
+/// 返回 `false` 表示遍历被 [`Flow::Stop`] 中止。
+pub fn walk_offset_events<'a, I, V>(events: I, visitor: &mut V) -> bool
+where
+    I: IntoIterator<Item = (Event<'a>, Range<usize>)>,
+    V: Visitor<'a> + ?Sized,
+{
+    let mut stack: Vec<(Tag<'a>, Range<usize>)> = Vec::new();
+    // 处于被跳过子树中的嵌套深度
+    let mut skip_depth = 0usize;
+    for (event, range) in events {
+        match event {
+            Event::Start(tag) => {
+                if skip_depth > 0 {
+                    skip_depth += 1;
+                    continue;
+                }
+                match visitor.enter(&tag, range.clone()) {
+                    Flow::Continue => {}
+                    Flow::SkipChildren => skip_depth = 1,
+                    Flow::Stop => return false,
+                }
+                stack.push((tag, range));
+            }
+            Event::End(_) => {
+                if skip_depth > 1 {
+                    skip_depth -= 1;
+                    continue;
+                }
+                skip_depth = 0;
+                if let Some((tag, range)) = stack.pop() {
+                    visitor.exit(&tag, range);
+                }
+            }
+            event => {
+                if skip_depth == 0 && visitor.visit(&event, range) == Flow::Stop {
+                    return false;
+                }
+            }
+        }
+    }
+    true
+}
+
+/// 使用访问者遍历 [`Ast`]。返回 `false` 表示遍历被 [`Flow::Stop`] 中止。
+pub fn walk_ast<'a, V>(ast: &Ast<'a>, visitor: &mut V) -> bool
+where
+    V: Visitor<'a> + ?Sized,
+{
+    ast.children.iter().all(|node| walk_node(node, visitor))
+}
+
+fn walk_node<'a, V>(node: &Node<'a>, visitor: &mut V) -> bool
+where
+    V: Visitor<'a> + ?Sized,
+{
+    match &node.kind {
+        NodeKind::Tag(tag) => {
+            match visitor.enter(tag, node.span.clone()) {
+                Flow::Continue => {
+                    if !node.children.iter().all(|child| walk_node(child, visitor)) {
+                        return false;
+                    }
+                }
+                Flow::SkipChildren => {}
+                Flow::Stop => return false,
+            }
+            visitor.exit(tag, node.span.clone());
+            true
+        }
+        NodeKind::Leaf(event) => visitor.visit(event, node.span.clone()) != Flow::Stop,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Options, Parser};
+    use alloc::{string::String, vec::Vec};
+
+    #[derive(Default)]
+    struct Trace(Vec<String>);
+
+    impl<'a> Visitor<'a> for Trace {
+        fn enter_heading(
+            &mut self,
+            level: HeadingLevel,
+            _id: Option<&CowStr<'a>>,
+            _span: Range<usize>,
+        ) -> Flow {
+            self.0.push(format!("enter {}", level));
+            Flow::Continue
+        }
+        fn exit_heading(
+            &mut self,
+            level: HeadingLevel,
+            _id: Option<&CowStr<'a>>,
+            _span: Range<usize>,
+        ) {
+            self.0.push(format!("exit {}", level));
+        }
+        fn enter_code_block(&mut self, _kind: &CodeBlockKind<'a>, _span: Range<usize>) -> Flow {
+            self.0.push("code".into());
+            Flow::SkipChildren
+        }
+        fn visit_text(&mut self, text: &CowStr<'a>, span: Range<usize>) -> Flow {
+            self.0.push(format!("{}@{:?}", text, span));
+            if text.as_ref() == "stop" {
+                Flow::Stop
+            } else {
+                Flow::Continue
+            }
+        }
+    }
+
+    #[test]
+    fn test_walk_events() {
+        let text = "# a\n\n```\nskipped\n```\n\nb\n\nstop\n\nnever";
+        let mut trace = Trace::default();
+        assert!(!walk_offset_events(
+            Parser::new(text).into_offset_iter(),
+            &mut trace
+        ));
+        assert_eq!(
+            trace.0,
+            [
+                "enter h1",
+                "a@2..3",
+                "exit h1",
+                "code",
+                "b@22..23",
+                "stop@25..29"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_walk_ast_matches_stream() {
+        let text = "## *x*\n\n```\ny\n```\n\n- z";
+        let mut from_stream = Trace::default();
+        assert!(walk_events(
+            Parser::new_ext(text, Options::empty()),
+            &mut from_stream
+        ));
+        let mut from_ast = Trace::default();
+        assert!(walk_ast(
+            &Ast::from_events(Parser::new(text)),
+            &mut from_ast
+        ));
+        assert_eq!(from_stream.0, from_ast.0);
+        assert_eq!(
+            from_ast.0,
+            ["enter h2", "x@0..0", "exit h2", "code", "z@0..0"]
+        );
+    }
+}
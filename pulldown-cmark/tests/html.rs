@@ -1,7 +1,13 @@
 // Tests for HTML spec.
 #![cfg(feature = "html")]
 
-use pulldown_cmark::{html, BrokenLink, Options, Parser};
+use std::{cell::RefCell, fmt, rc::Rc};
+
+use pulldown_cmark::{
+    html, Alignment, AutolinkKinds, AutolinkTrimming, BlockQuoteKind, BrokenLink, CowStr,
+    DialectConfig, Event, LinkType, MathDelimiters, MathSyntax, Options, Parser, ParserCallbacks,
+    Tag, TagEnd, UrlPolicy,
+};
 
 #[test]
 fn html_test_1() {
@@ -356,3 +362,967 @@ fn issue_819() {
         assert_eq!(expected, s.trim_end_matches('\n'));
     }
 }
+
+#[test]
+fn hashtags() {
+    let original = "#tag at start, #nested/tag-1 and #2024 #_x\n\n`#code` [link](#anchor) a#b \\#escaped *#em*\n\n- #item\n";
+    let expected = r##"<p><span class="hashtag">#tag</span> at start, <span class="hashtag">#nested/tag-1</span> and #2024 <span class="hashtag">#_x</span></p>
+<p><kbd class="kbd">#code</kbd> <a class="link" href="#anchor">link</a> a#b #escaped <em>#em</em></p>
+<ul class="list-disc list-inside list">
+<li><span class="hashtag">#item</span></li>
+</ul>
+"##;
+
+    let mut s = String::new();
+    html::push_html(&mut s, Parser::new_ext(original, Options::ENABLE_HASHTAGS));
+    assert_eq!(expected, s);
+}
+
+#[test]
+fn hashtags_do_not_affect_headings() {
+    let original = "# Title #tag\n\n## Attrs { #id }\n";
+    let expected = r##"<h1>Title <span class="hashtag">#tag</span></h1>
+<h2 id="id">Attrs</h2>
+"##;
+
+    let mut s = String::new();
+    let options = Options::ENABLE_HASHTAGS | Options::ENABLE_HEADING_ATTRIBUTES;
+    html::push_html(&mut s, Parser::new_ext(original, options));
+    assert_eq!(expected, s);
+}
+
+#[test]
+fn block_ids() {
+    let original = "some paragraph ^quote-of-the-day\n\nnot ^last\nline\n\n- item ^item-1\n- a^b ^c!\n- outer ^outer\n  - inner ^inner\n\n1. loose ^loose\n\n   second\n\nSee [[note#^quote-of-the-day]].\n";
+    let expected = r##"<p id="^quote-of-the-day">some paragraph</p>
+<p>not ^last
+line</p>
+<ul class="list-disc list-inside list">
+<li id="^item-1">item</li>
+<li>a^b ^c!</li>
+<li id="^outer">outer
+<ul class="list-disc list-inside list">
+<li id="^inner">inner</li>
+</ul>
+</li>
+</ul>
+<ol class="list-decimal list-inside list">
+<li>
+<p id="^loose">loose</p>
+<p>second</p>
+</li>
+</ol>
+<p>See <a class="link" href="note#^quote-of-the-day">note#^quote-of-the-day</a>.</p>
+"##;
+
+    let mut s = String::new();
+    let options = Options::ENABLE_BLOCK_IDS | Options::ENABLE_WIKILINKS;
+    html::push_html(&mut s, Parser::new_ext(original, options));
+    assert_eq!(expected, s);
+}
+
+#[test]
+fn foldable_callouts() {
+    let original = "> [!IMPORTANT]- Read *this*\n> Folded body\n\n> [!IMPORTANT]+\n> Open body\n\n> [!IMPORTANT] Heads up\n> Body\n\n> [!IMPORTANT]nope\n";
+    let expected = r##"<details class="markdown-alert-important">
+<summary>❗
+Read *this*</summary>
+<p>Folded body</p>
+</details>
+<details class="markdown-alert-important" open>
+<summary>❗
+Important</summary>
+<p>Open body</p>
+</details>
+<blockquote class="markdown-alert-important">
+❗
+<p class="markdown-alert-title">Heads up</p>
+<p>Body</p>
+</blockquote>
+<blockquote>
+<p>[!IMPORTANT]nope</p>
+</blockquote>
+"##;
+
+    let mut s = String::new();
+    html::push_html(&mut s, Parser::new_ext(original, Options::ENABLE_GFM));
+    assert_eq!(expected, s);
+
+    let first = Parser::new_ext(original, Options::ENABLE_GFM).next();
+    assert_eq!(
+        first,
+        Some(Event::Start(Tag::BlockQuote {
+            kind: Some(BlockQuoteKind::Important),
+            folded: Some(true),
+            title: Some("Read *this*".into()),
+        }))
+    );
+}
+
+#[test]
+fn code_block_hook() {
+    let original = "```rust,ignore\n#![allow(x)]\n    # hidden\n#\nlet x = 1;\n```\n\n```python\n# comment\n```\n";
+    let expected = r##"<pre><code class="language-rust,ignore">#![allow(x)]
+let x = 1;
+</code></pre>
+<pre><code class="language-python"># comment
+</code></pre>
+"##;
+    let config = html::HtmlConfig {
+        code_block_hook: Some(html::hide_rust_lines),
+        ..Default::default()
+    };
+    let mut s = String::new();
+    html::push_html_with_config(&mut s, Parser::new(original), config);
+    assert_eq!(expected, s);
+
+    // the events keep the hidden lines
+    let text: String = Parser::new(original)
+        .filter_map(|event| match event {
+            Event::Text(text) => Some(text.into_string()),
+            _ => None,
+        })
+        .collect();
+    assert!(text.contains("    # hidden\n"));
+
+    fn upper<'s>(lang: &str, code: &'s str) -> CowStr<'s> {
+        if lang.is_empty() {
+            code.to_uppercase().into()
+        } else {
+            code.into()
+        }
+    }
+    let config = html::HtmlConfig {
+        code_block_hook: Some(upper),
+        ..Default::default()
+    };
+    let mut s = String::new();
+    html::push_html_with_config(&mut s, Parser::new("    a <b>\n"), config);
+    assert_eq!("<pre><code>A &lt;B&gt;\n</code></pre>\n", s);
+}
+
+#[test]
+fn render_without_lookahead() {
+    struct Shared(Rc<RefCell<String>>);
+
+    impl fmt::Write for Shared {
+        fn write_str(&mut self, s: &str) -> fmt::Result {
+            self.0.borrow_mut().push_str(s);
+            Ok(())
+        }
+    }
+
+    let mut events = vec![
+        Event::Start(Tag::Table(vec![Alignment::None, Alignment::Right])),
+        Event::Start(Tag::TableHead),
+    ];
+    for row in 0..3 {
+        if row > 0 {
+            events.push(Event::Start(Tag::TableRow));
+        }
+        for col in 0..2 {
+            events.push(Event::Start(Tag::TableCell(None)));
+            events.push(Event::Text(format!("cell {row} {col}").into()));
+            events.push(Event::End(TagEnd::TableCell));
+        }
+        events.push(Event::End(if row == 0 {
+            TagEnd::TableHead
+        } else {
+            TagEnd::TableRow
+        }));
+    }
+    events.extend([
+        Event::End(TagEnd::Table),
+        Event::Start(Tag::Paragraph(None)),
+        Event::FootnoteReference("note".into()),
+        Event::End(TagEnd::Paragraph),
+        Event::Start(Tag::FootnoteDefinition("note".into())),
+        Event::Text("definition".into()),
+        Event::End(TagEnd::FootnoteDefinition),
+    ]);
+
+    // Every text event must be written out before the next event is pulled.
+    let out = Rc::new(RefCell::new(String::new()));
+    let mut last_text: Option<CowStr> = None;
+    let iter = events.into_iter().inspect(|event| {
+        if let Some(text) = last_text.take() {
+            assert!(
+                out.borrow().contains(&*text),
+                "{text:?} was not written yet"
+            );
+        }
+        if let Event::Text(text) = event {
+            last_text = Some(text.clone());
+        }
+    });
+    html::write_html_fmt(Shared(out.clone()), iter).unwrap();
+    assert!(out.borrow().contains("definition"));
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn parallel_html_matches_sequential() {
+    let inputs = [
+        "# Title\n\ntext[^b] and[^a]\n\n[^a]: first\n\n[^b]: second[^a]\n",
+        "| a | b |\n|---|---|\n| c | d |\n\n> quote\n\n- item\n\n---\n",
+        "<div>\nhtml\n</div>\nafter\n\n![alt *em*](/img.png)\n",
+        "---\ntitle: front\n---\n\ntext\n",
+        "",
+    ];
+    for input in inputs {
+        let events: Vec<_> = Parser::new_ext(input, Options::all()).collect();
+        let mut parallel = String::new();
+        html::push_html_parallel(&mut parallel, &events, &Default::default());
+        let mut sequential = String::new();
+        html::push_html(&mut sequential, events.into_iter());
+        assert_eq!(parallel, sequential, "{input:?}");
+    }
+}
+
+#[test]
+fn html_stream_matches_push_html() {
+    let input = "# Title\n\ntext[^b] and[^a]\n\n[^a]: first\n\n| a | b |\n|---|---|\n| c | d |\n\n[^b]: second\n"
+        .repeat(20);
+    for placement in [
+        html::FootnotePlacement::InPlace,
+        html::FootnotePlacement::EndOfDocument,
+    ] {
+        let config = html::HtmlConfig {
+            footnote_placement: placement,
+            ..Default::default()
+        };
+        let mut expected = String::new();
+        html::push_html_with_config(
+            &mut expected,
+            Parser::new_ext(&input, Options::all()),
+            config.clone(),
+        );
+        for chunk_size in [0, 64, usize::MAX] {
+            let chunks: Vec<String> =
+                html::HtmlStream::new(Parser::new_ext(&input, Options::all()), config.clone())
+                    .chunk_size(chunk_size)
+                    .collect();
+            assert!(chunks.iter().all(|chunk| !chunk.is_empty()));
+            assert_eq!(chunks.concat(), expected);
+        }
+    }
+    assert_eq!(
+        html::HtmlStream::new(Parser::new(""), Default::default()).count(),
+        0
+    );
+}
+
+#[test]
+fn source_map_round_trips() {
+    let original = "Intro\n\n- one ![alt *x*](/i.png)\n- two\n\n```\ncode\n```\n";
+    let mut s = String::from("<!-- prefix -->");
+    let map = html::push_html_with_source_map(
+        &mut s,
+        Parser::new(original).into_offset_iter(),
+        Default::default(),
+    );
+
+    let mut expected = String::from("<!-- prefix -->");
+    html::push_html(&mut expected, Parser::new(original));
+    assert_eq!(s, expected);
+
+    // Elements cover their children, and offsets are positions in `s`.
+    let item = original.find("- one").unwrap();
+    let item_html = &s[map.output_range(item).unwrap()];
+    assert!(item_html.starts_with("<li>one <img"));
+    assert!(item_html.ends_with("</li>\n"));
+    let two = original.find("two").unwrap();
+    assert_eq!(&s[map.output_range(two).unwrap()], "two");
+    let image = original.find('!').unwrap();
+    let image_html = map.output_range(image).unwrap();
+    assert!(s[image_html.clone()].starts_with("<img"));
+    assert_eq!(
+        map.source_range(image_html.start),
+        Some(image..original.find(')').unwrap() + 1)
+    );
+    let code = original.find("code").unwrap();
+    assert_eq!(&s[map.output_range(code).unwrap()], "code\n");
+    assert!(map.source_range(0).is_none());
+}
+
+#[test]
+fn source_map_code_block_hook() {
+    let original = "```rust\n# fn main() {\nlet x = 1;\n# }\n```\n\nafter\n";
+    let config = || html::HtmlConfig {
+        code_block_hook: Some(html::hide_rust_lines),
+        ..Default::default()
+    };
+    let mut s = String::new();
+    let map =
+        html::push_html_with_source_map(&mut s, Parser::new(original).into_offset_iter(), config());
+
+    let mut expected = String::new();
+    html::push_html_with_config(&mut expected, Parser::new(original), config());
+    assert_eq!(s, expected);
+
+    // The buffered text maps to the code written when the block ends.
+    let code = original.find("let").unwrap();
+    assert_eq!(&s[map.output_range(code).unwrap()], "let x = 1;\n");
+    let block = map.output_range(0).unwrap();
+    assert!(s[block].ends_with("let x = 1;\n</code></pre>\n"));
+    let after = original.find("after").unwrap();
+    assert_eq!(&s[map.output_range(after).unwrap()], "after");
+}
+
+#[test]
+fn debug_report_formats() {
+    let original = "> quote <b>\n\n- [ ] task\n";
+    let options = Options::ENABLE_TASKLISTS | Options::ENABLE_TABLES;
+
+    let report = html::debug_report(original, options, html::ReportFormat::Html);
+    assert!(report.starts_with("<!DOCTYPE html>\n"));
+    assert!(report.contains("<h2>Options</h2>\n<pre>tables,tasklists</pre>"));
+    assert!(report.contains("<h2>Extensions used</h2>\n<pre>tasklists</pre>"));
+    assert!(report.contains("<td>0..12</td><td><code>Start(BlockQuote"));
+    // Inline HTML is escaped everywhere, the rendered section is a sandboxed iframe.
+    assert!(report.contains("<code>InlineHtml(Borrowed(&quot;&lt;b&gt;&quot;))</code>"));
+    assert!(report.contains("<pre>&lt;blockquote&gt;\n&lt;p&gt;quote &lt;b&gt;"));
+    assert!(report.contains(
+        "<iframe class=\"rendered\" sandbox srcdoc=\"&lt;blockquote&gt;\n&lt;p&gt;quote &lt;b&gt;"
+    ));
+    assert!(!report.contains("<b>"));
+
+    let report = html::debug_report(original, options, html::ReportFormat::Json);
+    assert!(report.starts_with(r#"{"options":"tables,tasklists","extensions":["tasklists"],"#));
+    assert!(report.contains(r#"{"event":"TaskListMarker(false)","span":[15,18]}"#));
+    let tree = &report[report.find(r#""tree":"#).unwrap()..report.find(r#","html":"#).unwrap()];
+    assert_eq!(
+        tree,
+        concat!(
+            r#""tree":[{"tag":"BlockQuote { kind: None, folded: None, title: None }","span":[0,12],"#,
+            r#""children":[{"tag":"Paragraph(None)","span":[2,12],"children":[]}]},"#,
+            r#"{"tag":"List(None)","span":[13,24],"children":[{"tag":"Item(None, None)","span":[13,24],"#,
+            r#""children":[]}]}]"#
+        )
+    );
+    assert!(report.contains(r#""html":"<blockquote>\n<p>quote <b></p>\n</blockquote>\n"#));
+}
+
+#[test]
+fn footnote_placement() {
+    let original = "a[^1]\n\n> b[^2]\n>\n> [^2]: in quote\n>\n> c\n\n[^1]: top\n\nd\n";
+    let render = |placement| {
+        let config = html::HtmlConfig {
+            footnote_placement: placement,
+            ..Default::default()
+        };
+        let mut s = String::new();
+        html::push_html_with_config(
+            &mut s,
+            Parser::new_ext(original, Options::ENABLE_FOOTNOTES),
+            config,
+        );
+        s
+    };
+    let def1 = "<div class=\"footnote-definition\" id=\"1\"><sup class=\"footnote-definition-label\">1</sup>\n<p>top</p>\n</div>\n";
+    let def2 = "<div class=\"footnote-definition\" id=\"2\"><sup class=\"footnote-definition-label\">2</sup>\n<p>in quote</p>\n</div>\n";
+    let a = "<p>a<sup class=\"footnote-reference\"><a href=\"#1\">1</a></sup></p>\n";
+    let b = "<p>b<sup class=\"footnote-reference\"><a href=\"#2\">2</a></sup></p>\n";
+
+    assert_eq!(
+        render(html::FootnotePlacement::InPlace),
+        format!("{a}<blockquote>\n{b}{def2}<p>c</p>\n</blockquote>\n{def1}<p>d</p>\n")
+    );
+    assert_eq!(
+        render(html::FootnotePlacement::EndOfParentBlock),
+        format!("{a}<blockquote>\n{b}<p>c</p>\n{def2}</blockquote>\n<p>d</p>\n{def1}")
+    );
+    assert_eq!(
+        render(html::FootnotePlacement::EndOfDocument),
+        format!(
+            "{a}<blockquote>\n{b}<p>c</p>\n</blockquote>\n<p>d</p>\n\
+             <section class=\"footnotes\">\n{def2}{def1}</section>\n"
+        )
+    );
+}
+
+#[test]
+fn footnote_label_hook() {
+    let original = "a[^note]\n\n[^note]: text\n";
+    let config = html::HtmlConfig {
+        footnote_label: Some(html::footnote_label_name),
+        ..Default::default()
+    };
+    let mut s = String::new();
+    html::push_html_with_config(
+        &mut s,
+        Parser::new_ext(original, Options::ENABLE_FOOTNOTES),
+        config,
+    );
+    assert_eq!(
+        s,
+        "<p>a<sup class=\"footnote-reference\"><a href=\"#note\">note</a></sup></p>\n\
+         <div class=\"footnote-definition\" id=\"note\"><sup class=\"footnote-definition-label\">note</sup>\n\
+         <p>text</p>\n</div>\n"
+    );
+}
+
+#[test]
+fn link_rel_policy() {
+    let original = "[a](/x) [b](#top) [c](https://Docs.Example.com:8080/y) [d](//cdn.org/z) \
+                    [e](mailto:me@x.org) <me@x.org> <https://other.org> [f](tel:123)";
+    let config = html::HtmlConfig {
+        link_rel: html::LinkRelPolicy {
+            internal: Some("bookmark".into()),
+            external: Some("nofollow sponsored".into()),
+            mailto: Some("noopener".into()),
+            autolink: None,
+            internal_hosts: vec!["docs.example.com".into()],
+        },
+        ..Default::default()
+    };
+    let mut s = String::new();
+    html::push_html_with_config(&mut s, Parser::new(original), config);
+    assert_eq!(
+        s,
+        "<p><a class=\"link\" href=\"/x\" rel=\"bookmark\">a</a> \
+         <a class=\"link\" href=\"#top\" rel=\"bookmark\">b</a> \
+         <a class=\"link\" href=\"https://Docs.Example.com:8080/y\" rel=\"bookmark\">c</a> \
+         <a class=\"link\" href=\"//cdn.org/z\" rel=\"nofollow sponsored\">d</a> \
+         <a class=\"link\" href=\"mailto:me@x.org\" rel=\"noopener\">e</a> \
+         <a class=\"link link-warning\" href=\"mailto:me@x.org\" rel=\"noopener\">me@x.org</a> \
+         <a class=\"link\" href=\"https://other.org\" rel=\"nofollow sponsored\">https://other.org</a> \
+         <a class=\"link\" href=\"tel:123\" rel=\"nofollow sponsored\">f</a></p>\n"
+    );
+}
+
+#[test]
+fn raw_html_policy() {
+    let original = "<div>\n<iframe src=\"x\"></iframe>\n</div>\n\na <b>b</b> <Script>c</script>\n";
+    let render = |policy| {
+        let config = html::HtmlConfig {
+            raw_html: policy,
+            ..Default::default()
+        };
+        let mut s = String::new();
+        html::push_html_with_config(&mut s, Parser::new(original), config);
+        s
+    };
+    assert_eq!(
+        render(html::RawHtmlPolicy::Passthrough),
+        "<div>\n<iframe src=\"x\"></iframe>\n</div>\n<p>a <b>b</b> <Script>c</script></p>\n"
+    );
+    assert_eq!(
+        render(html::RawHtmlPolicy::Tagfilter),
+        "<div>\n&lt;iframe src=\"x\">&lt;/iframe>\n</div>\n<p>a <b>b</b> &lt;Script>c&lt;/script></p>\n"
+    );
+    assert_eq!(
+        render(html::RawHtmlPolicy::EscapeAll),
+        "&lt;div&gt;\n&lt;iframe src=\"x\"&gt;&lt;/iframe&gt;\n&lt;/div&gt;\n\
+         <p>a &lt;b&gt;b&lt;/b&gt; &lt;Script&gt;c&lt;/script&gt;</p>\n"
+    );
+    assert_eq!(render(html::RawHtmlPolicy::Drop), "<p>a b c</p>\n");
+}
+
+#[test]
+fn table_footers() {
+    let original = "| Item | Price |\n|------|------:|\n| Pear | 0.80 |\n|------|------:|\n| Total | 0.80 |\n\n> | a |\n> |---|\n> | b |\n> |---|\n";
+    let expected = "<table class=\"table table-zebra w-full\">\n\
+                    <thead><tr><th>Item</th><th style=\"text-align: right\">Price</th></tr></thead><tbody>\n\
+                    <tr><td>Pear</td><td style=\"text-align: right\">0.80</td></tr>\n\
+                    </tbody><tfoot>\n\
+                    <tr><td>Total</td><td style=\"text-align: right\">0.80</td></tr>\n\
+                    </tfoot>\n\
+                    </table>\n\
+                    <blockquote>\n\
+                    <table class=\"table table-zebra w-full\">\n\
+                    <thead><tr><th>a</th></tr></thead><tbody>\n\
+                    <tr><td>b</td></tr>\n\
+                    </tbody></table>\n\
+                    </blockquote>\n";
+
+    let mut s = String::new();
+    let options = Options::ENABLE_TABLES | Options::ENABLE_TABLE_FOOTERS;
+    html::push_html(&mut s, Parser::new_ext(original, options));
+    assert_eq!(expected, s);
+
+    // without the option the delimiter row is a regular row
+    let count = |options| {
+        Parser::new_ext(original, options)
+            .filter(|event| matches!(event, Event::Start(Tag::TableRow)))
+            .count()
+    };
+    assert_eq!(count(options), 3);
+    assert_eq!(count(Options::ENABLE_TABLES), 5);
+}
+
+#[test]
+fn table_cell_alignment() {
+    let original =
+        "|:Item | Amount |\n|------|-------:|\n| Pear | 12 |\n| n/a  |:none:|\n| :smile: | \\:|\n";
+    let expected = "<table class=\"table table-zebra w-full\">\n\
+                    <thead><tr><th style=\"text-align: left\">Item</th><th style=\"text-align: right\">Amount</th></tr></thead><tbody>\n\
+                    <tr><td>Pear</td><td style=\"text-align: right\">12</td></tr>\n\
+                    <tr><td>n/a</td><td style=\"text-align: center\">none</td></tr>\n\
+                    <tr><td>:smile:</td><td style=\"text-align: right\">:</td></tr>\n\
+                    </tbody></table>\n";
+
+    let mut s = String::new();
+    let options = Options::ENABLE_TABLES | Options::ENABLE_CELL_ALIGNMENT;
+    html::push_html(&mut s, Parser::new_ext(original, options));
+    assert_eq!(expected, s);
+
+    let alignments: Vec<_> = Parser::new_ext(original, options)
+        .filter_map(|event| match event {
+            Event::Start(Tag::TableCell(alignment)) => Some(alignment),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(
+        alignments,
+        [
+            Some(Alignment::Left),
+            None,
+            None,
+            None,
+            None,
+            Some(Alignment::Center),
+            None,
+            None
+        ]
+    );
+    assert!(Parser::new_ext(original, Options::ENABLE_TABLES)
+        .all(|event| !matches!(event, Event::Start(Tag::TableCell(Some(_))))));
+}
+
+#[test]
+fn multiline_table_cells() {
+    let original = "| Step | Notes |\n|------|-------|\n| 1 | Unpack | \\\n| | and *check* |\n| 2 | done \\ |\n";
+    let expected = "<table class=\"table table-zebra w-full\">\n\
+                    <thead><tr><th>Step</th><th>Notes</th></tr></thead><tbody>\n\
+                    <tr><td>1</td><td>Unpack<br />\nand <em>check</em></td></tr>\n\
+                    <tr><td>2</td><td>done \\</td></tr>\n\
+                    </tbody></table>\n";
+
+    let mut s = String::new();
+    let options = Options::ENABLE_TABLES | Options::ENABLE_MULTILINE_TABLE_CELLS;
+    html::push_html(&mut s, Parser::new_ext(original, options));
+    assert_eq!(expected, s);
+
+    let count = |options| {
+        Parser::new_ext(original, options)
+            .filter(|event| matches!(event, Event::Start(Tag::TableRow)))
+            .count()
+    };
+    assert_eq!(count(options), 2);
+    assert_eq!(count(Options::ENABLE_TABLES), 3);
+}
+
+#[test]
+fn multiline_table_cells_spans_and_delimiter_rows() {
+    let original = "| a | b |\n|---|---|\n| `x | \\\n| y` | z |\n| w | \\\n|-|-|\n";
+    let expected = "<table class=\"table table-zebra w-full\">\n\
+                    <thead><tr><th>a</th><th>b</th></tr></thead><tbody>\n\
+                    <tr><td><kbd class=\"kbd\">x y</kbd></td><td>z</td></tr>\n\
+                    <tr><td>w</td><td></td></tr>\n\
+                    <tr><td>-</td><td>-</td></tr>\n\
+                    </tbody></table>\n";
+
+    let mut s = String::new();
+    let options = Options::ENABLE_TABLES | Options::ENABLE_MULTILINE_TABLE_CELLS;
+    html::push_html(&mut s, Parser::new_ext(original, options));
+    assert_eq!(expected, s);
+
+    let original = "| a |\n|---|\n| $x | \\\n| y$ |\n";
+    let math =
+        Parser::new_ext(original, options | Options::ENABLE_MATH).find_map(|event| match event {
+            Event::InlineMath(math, _) => Some(math),
+            _ => None,
+        });
+    assert_eq!(math.as_deref(), Some("x\ny"));
+}
+
+#[test]
+fn table_cell_blocks() {
+    let original = "| a | b |\n|---|---|\n| x<br>y | <ul> <li>*one*</li><li>two</li> </ul> |\n| <li>c | <ul><li>d</li>e</ul> |\n";
+    let expected = "<table class=\"table table-zebra w-full\">\n\
+                    <thead><tr><th>a</th><th>b</th></tr></thead><tbody>\n\
+                    <tr><td>x<br />\ny</td><td>\n\
+                    <ul class=\"list-disc list-inside list\">\n\
+                    <li><em>one</em></li>\n<li>two</li>\n</ul>\n</td></tr>\n\
+                    <tr><td><li>c</td><td><ul><li>d</li>e</ul></td></tr>\n\
+                    </tbody></table>\n";
+
+    let mut s = String::new();
+    let options = Options::ENABLE_TABLES | Options::ENABLE_TABLE_CELL_BLOCKS;
+    html::push_html(&mut s, Parser::new_ext(original, options));
+    assert_eq!(expected, s);
+
+    let lists = Parser::new_ext(original, options)
+        .filter(|event| matches!(event, Event::Start(Tag::List(None))))
+        .count();
+    assert_eq!(lists, 1);
+    assert!(!Parser::new_ext(original, Options::ENABLE_TABLES)
+        .any(|event| matches!(event, Event::HardBreak(_) | Event::Start(Tag::List(_)))));
+}
+
+#[test]
+fn asciimath_syntax() {
+    let original = "a $`x/y`$ $c$\n\n```asciimath\nx\n```\n";
+    let mut s = String::new();
+    let options = Options::ENABLE_MATH | Options::ENABLE_ASCIIMATH;
+    html::push_html(&mut s, Parser::new_ext(original, options));
+    assert!(s.contains("<span class=\"math math-inline asciimath\">x/y</span>"));
+    assert!(s.contains("<span class=\"math math-inline\">c</span>"));
+    assert!(s.contains("<span class=\"math math-display asciimath\">x</span>"));
+
+    let mut s = String::new();
+    html::push_html(&mut s, Parser::new_ext(original, Options::ENABLE_MATH));
+    assert!(s.contains("<span class=\"math math-inline\">`x/y`</span>"));
+    assert!(!s.contains("math-display"));
+}
+
+#[test]
+fn bracket_math_delimiters() {
+    let original = "\\(x\\) and $y$ \\\\(z\\)\n\n\\[a\\]\n\n\\[b\\[ \\(c\\]\n";
+    let mut s = String::new();
+    let parser = Parser::builder()
+        .options(Options::ENABLE_MATH)
+        .math_delimiters(MathDelimiters::Brackets)
+        .build(original);
+    html::push_html(&mut s, parser);
+    assert_eq!(
+        s,
+        "<p><span class=\"math math-inline\">x</span> and $y$ \\(z)</p>\n\
+         <p><span class=\"math math-display\">a</span></p>\n\
+         <p>[b[ (c]</p>\n"
+    );
+
+    let dialect = DialectConfig {
+        options: Options::ENABLE_MATH,
+        math_delimiters: MathDelimiters::Brackets,
+        ..DialectConfig::default()
+    };
+    assert!(dialect
+        .builder()
+        .build("\\(x\\)")
+        .any(|event| event == Event::InlineMath("x".into(), MathSyntax::Tex)));
+}
+
+#[test]
+fn autolink_literals() {
+    let original =
+        "Visit www.commonmark.org/help, (https://en.wikipedia.org/wiki/Rust_(language)).\n\
+                    *http://a.b/c?q=1&amp;* x:http://no.link `http://c.d` https://x_y.z\n";
+    let expected = "<p>Visit <a class=\"link\" href=\"http://www.commonmark.org/help\">www.commonmark.org/help</a>, \
+                    (<a class=\"link\" href=\"https://en.wikipedia.org/wiki/Rust_(language)\">https://en.wikipedia.org/wiki/Rust_(language)</a>).\n\
+                    <em><a class=\"link\" href=\"http://a.b/c?q=1\">http://a.b/c?q=1</a>&amp;</em> x:http://no.link \
+                    <kbd class=\"kbd\">http://c.d</kbd> https://x_y.z</p>\n";
+
+    let mut s = String::new();
+    html::push_html(
+        &mut s,
+        Parser::new_ext(original, Options::ENABLE_AUTOLINK_LITERALS),
+    );
+    assert_eq!(expected, s);
+
+    let dests = |trimming| {
+        Parser::builder()
+            .enable(Options::ENABLE_AUTOLINK_LITERALS)
+            .autolink_trimming(trimming)
+            .build("say https://a.b/c\"! www.d.e/(f)")
+            .filter_map(|event| match event {
+                Event::Start(Tag::Link { dest_url, .. }) => Some(dest_url.into_string()),
+                _ => None,
+            })
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(
+        dests(AutolinkTrimming::gfm()),
+        ["https://a.b/c\"", "http://www.d.e/(f)"]
+    );
+    assert_eq!(
+        dests(AutolinkTrimming::gfm() | AutolinkTrimming::QUOTES | AutolinkTrimming::PARENTHESES),
+        ["https://a.b/c", "http://www.d.e/(f"]
+    );
+}
+
+#[test]
+fn autolink_literals_in_code_and_link_text() {
+    let original = "`see https://x.com` and [see www.x.com](/u)\n";
+    let expected = "<p><kbd class=\"kbd\">see https://x.com</kbd> and \
+                    <a class=\"link\" href=\"/u\">see www.x.com</a></p>\n";
+
+    let mut s = String::new();
+    html::push_html(
+        &mut s,
+        Parser::new_ext(original, Options::ENABLE_AUTOLINK_LITERALS),
+    );
+    assert_eq!(expected, s);
+}
+
+#[test]
+fn email_and_tel_autolink_literals() {
+    let original = "Mail foo.bar+baz@example.com. or mailto:a@b.co, call tel:+1-201-555-0123.\n\
+                    not@localhost x@y.z_ tel:none\n";
+    let expected = "<p>Mail <a class=\"link link-warning\" href=\"mailto:foo.bar+baz@example.com\">foo.bar+baz@example.com</a>. \
+                    or <a class=\"link\" href=\"mailto:a@b.co\">mailto:a@b.co</a>, \
+                    call <a class=\"link\" href=\"tel:+1-201-555-0123\">tel:+1-201-555-0123</a>.\n\
+                    not@localhost x@y.z_ tel:none</p>\n";
+
+    let mut s = String::new();
+    let parser = Parser::builder()
+        .enable(Options::ENABLE_AUTOLINK_LITERALS)
+        .autolink_kinds(AutolinkKinds::all())
+        .build(original);
+    html::push_html(&mut s, parser);
+    assert_eq!(expected, s);
+
+    struct OnlyExample;
+    impl<'input> ParserCallbacks<'input> for OnlyExample {
+        fn allow_autolink_literal(&mut self, _link_type: LinkType, dest_url: &str) -> bool {
+            dest_url.ends_with("example.com")
+        }
+    }
+    let links: Vec<_> =
+        Parser::new_with_callbacks(original, Options::ENABLE_AUTOLINK_LITERALS, OnlyExample)
+            .filter_map(|event| match event {
+                Event::Start(Tag::Link {
+                    link_type,
+                    dest_url,
+                    ..
+                }) => Some((link_type, dest_url)),
+                _ => None,
+            })
+            .collect();
+    assert_eq!(
+        links,
+        [(LinkType::EmailLiteral, "foo.bar+baz@example.com".into())]
+    );
+}
+
+#[test]
+fn image_attributes() {
+    let original = "![a](a.png){#hero .wide srcset=\"a@2x.png 2x\" sizes=50vw onload=alert(1)}\n";
+    let config = html::HtmlConfig {
+        image_attributes: vec!["class".into(), "id".into(), "SRCSET".into(), "sizes".into()],
+        ..Default::default()
+    };
+
+    let mut s = String::new();
+    let parser = Parser::new_ext(original, Options::ENABLE_IMAGE_ATTRIBUTES);
+    html::push_html_with_config(&mut s, parser, config);
+    assert_eq!(
+        "<p><img src=\"a.png\" alt=\"a\" class=\"wide\" id=\"hero\" srcset=\"a@2x.png 2x\" sizes=\"50vw\" /></p>\n",
+        s
+    );
+
+    let mut s = String::new();
+    html::push_html(
+        &mut s,
+        Parser::new_ext(original, Options::ENABLE_IMAGE_ATTRIBUTES),
+    );
+    assert_eq!("<p><img src=\"a.png\" alt=\"a\" /></p>\n", s);
+
+    let mut s = String::new();
+    html::push_html(&mut s, Parser::new(original));
+    assert_eq!(
+        "<p><img src=\"a.png\" alt=\"a\" />{#hero .wide srcset=\"a@2x.png 2x\" sizes=50vw onload=alert(1)}</p>\n",
+        s
+    );
+}
+
+#[test]
+fn image_attributes_cannot_bypass_src() {
+    let original = "![a](a.png \"t\"){src=x.png ALT=x title=y srcset=\"javascript:alert(1) 1x, b@2x.png 2x\" srcset=c.png}\n";
+    let config = html::HtmlConfig {
+        url_policy: Some(UrlPolicy::default()),
+        image_attributes: ["src", "alt", "title", "srcset"].map(String::from).to_vec(),
+        ..Default::default()
+    };
+
+    let mut s = String::new();
+    let parser = Parser::new_ext(original, Options::ENABLE_IMAGE_ATTRIBUTES);
+    html::push_html_with_config(&mut s, parser, config);
+    assert_eq!(
+        "<p><img src=\"a.png\" alt=\"a\" title=\"t\" srcset=\"b@2x.png 2x\" /></p>\n",
+        s
+    );
+}
+
+#[test]
+fn id_prefix() {
+    let original = "# Intro {#intro}\n\nSee [intro](#intro)[^n] and [site](https://a.b/#intro).\n\n[^n]: Note\n\n::: note {#box}\nx\n:::\n";
+    let expected = "<h1 id=\"doc-intro\">Intro</h1>\n\
+                    <p>See <a class=\"link\" href=\"#doc-intro\">intro</a>\
+                    <sup class=\"footnote-reference\"><a href=\"#doc-n\">1</a></sup> \
+                    and <a class=\"link\" href=\"https://a.b/#intro\">site</a>.</p>\n\
+                    <div class=\"footnote-definition\" id=\"doc-n\"><sup class=\"footnote-definition-label\">1</sup>\n\
+                    <p>Note</p>\n\
+                    </div>\n\
+                    <div class=\"note\" id=\"doc-box\">\n<p>x</p>\n</div>\n";
+
+    let config = html::HtmlConfig {
+        id_prefix: "doc-".into(),
+        ..Default::default()
+    };
+    let mut s = String::new();
+    let parser = Parser::new_ext(
+        original,
+        Options::ENABLE_HEADING_ATTRIBUTES
+            | Options::ENABLE_FOOTNOTES
+            | Options::ENABLE_CONTAINER_EXTENSIONS,
+    );
+    html::push_html_with_config(&mut s, parser, config);
+    assert_eq!(expected, s);
+}
+
+#[test]
+fn container_attributes() {
+    let original = "::: note {#box .wide data-x=1 onclick=alert(1) title=t}\nx\n:::\n";
+    let render = |config| {
+        let mut s = String::new();
+        let parser = Parser::new_ext(original, Options::ENABLE_CONTAINER_EXTENSIONS);
+        html::push_html_with_config(&mut s, parser, config);
+        s
+    };
+
+    assert_eq!(
+        "<div class=\"note wide\" id=\"box\" data-x=\"1\">\n<p>x</p>\n</div>\n",
+        render(html::HtmlConfig::default())
+    );
+    assert_eq!(
+        "<div class=\"note wide\" id=\"box\" data-x=\"1\" title=\"t\">\n<p>x</p>\n</div>\n",
+        render(html::HtmlConfig {
+            container_attributes: vec!["TITLE".into()],
+            ..Default::default()
+        })
+    );
+}
+
+#[test]
+fn container_closes_open_list() {
+    let original = ":::: note\n- item\n\n::: spoiler Sum\ncontent\n:::\n::::\n";
+    let expected = "<div class=\"note\">\n\
+                    <ul class=\"list-disc list-inside list\">\n\
+                    <li>item</li>\n\
+                    </ul>\n\
+                    <details class=\"collapse collapse-plus border\">\
+                    <summary class=\"collapse-title font-semibold\">Sum</summary>\
+                    <div class=\"collapse-content text-sm\">\n\
+                    <p>content</p>\n\
+                    </div></details>\n\
+                    </div>\n";
+    let mut s = String::new();
+    html::push_html(
+        &mut s,
+        Parser::new_ext(original, Options::ENABLE_CONTAINER_EXTENSIONS),
+    );
+    assert_eq!(expected, s);
+
+    let original = "- item\n::: note\ncontent\n:::\n";
+    let expected = "<ul class=\"list-disc list-inside list\">\n\
+                    <li>item</li>\n\
+                    </ul>\n\
+                    <div class=\"note\">\n\
+                    <p>content</p>\n\
+                    </div>\n";
+    let mut s = String::new();
+    html::push_html(
+        &mut s,
+        Parser::new_ext(original, Options::ENABLE_CONTAINER_EXTENSIONS),
+    );
+    assert_eq!(expected, s);
+}
+
+#[test]
+fn list_item_values() {
+    let original = "3. a\n3. b\n   - c\n10) d\n";
+
+    let numbers: Vec<_> = Parser::new(original)
+        .filter_map(|event| match event {
+            Event::Start(Tag::Item(number, _)) => Some(number),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(numbers, [Some(3), Some(3), None, Some(10)]);
+
+    let expected = "<ol class=\"list-decimal list-inside list\" start=\"3\">\n\
+                    <li value=\"3\">a</li>\n\
+                    <li value=\"3\">b\n\
+                    <ul class=\"list-disc list-inside list\">\n\
+                    <li>c</li>\n\
+                    </ul>\n\
+                    </li>\n\
+                    </ol>\n\
+                    <ol class=\"list-decimal list-inside list\" start=\"10\">\n\
+                    <li value=\"10\">d</li>\n\
+                    </ol>\n";
+    let config = html::HtmlConfig {
+        list_item_values: true,
+        ..Default::default()
+    };
+    let mut s = String::new();
+    html::push_html_with_config(&mut s, Parser::new(original), config);
+    assert_eq!(expected, s);
+}
+
+#[test]
+fn canonical_line_endings() {
+    let lf = "# Title\n\n`co\nde` <span\nclass=\"x\">a</span>\n\n<div>\nb\n</div>\n\n```\nc\n```\n";
+    let crlf = lf.replace('\n', "\r\n");
+    let render = |markdown: &str, canonical| {
+        let config = html::HtmlConfig {
+            canonical,
+            ..Default::default()
+        };
+        let mut s = String::new();
+        html::push_html_with_config(&mut s, Parser::new_ext(markdown, Options::all()), config);
+        s
+    };
+
+    let expected = render(lf, false);
+    assert!(expected.contains("<kbd class=\"kbd\">co de</kbd>"));
+    assert_eq!(expected, render(lf, true));
+    assert_eq!(expected, render(&crlf, true));
+    assert_ne!(expected, render(&crlf, false));
+}
+
+#[test]
+fn autolink_kinds_and_scheme() {
+    let original =
+        "<https://a.com/x> <mailto:me@a.com> <me@a.com> https://b.com www.c.com d@e.com\n";
+    let link_types: Vec<_> = Parser::new_ext(original, Options::ENABLE_AUTOLINK_LITERALS)
+        .filter_map(|event| match event {
+            Event::Start(Tag::Link { link_type, .. }) => Some(link_type),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(
+        link_types,
+        [
+            LinkType::Autolink,
+            LinkType::Autolink,
+            LinkType::Email,
+            LinkType::AutolinkLiteral,
+            LinkType::AutolinkLiteral,
+            LinkType::EmailLiteral,
+        ]
+    );
+    assert!(link_types.iter().all(|link_type| link_type.is_autolink()));
+
+    let config = html::HtmlConfig {
+        hide_autolink_scheme: true,
+        ..Default::default()
+    };
+    let mut s = String::new();
+    html::push_html_with_config(
+        &mut s,
+        Parser::new_ext(original, Options::ENABLE_AUTOLINK_LITERALS),
+        config,
+    );
+    assert_eq!(
+        s,
+        "<p><a class=\"link\" href=\"https://a.com/x\">a.com/x</a> \
+         <a class=\"link\" href=\"mailto:me@a.com\">me@a.com</a> \
+         <a class=\"link link-warning\" href=\"mailto:me@a.com\">me@a.com</a> \
+         <a class=\"link\" href=\"https://b.com\">b.com</a> \
+         <a class=\"link\" href=\"http://www.c.com\">www.c.com</a> \
+         <a class=\"link link-warning\" href=\"mailto:d@e.com\">d@e.com</a></p>\n"
+    );
+}
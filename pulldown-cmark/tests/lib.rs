@@ -1,70 +1,32 @@
 #![cfg(feature = "html")]
 
-use pulldown_cmark::{Options, Parser};
+use pulldown_cmark::{
+    testing::{self, TestCase},
+    Options,
+};
 
 #[rustfmt::skip]
 mod suite;
 
 #[inline(never)]
-pub fn test_markdown_html(
-    input: &str,
-    output: &str,
-    smart_punct: bool,
-    metadata_blocks: bool,
-    old_footnotes: bool,
-    subscript: bool,
-    wikilinks: bool,
-    deflists: bool,
-    container_extensions: bool,
-) {
-    let mut s = String::new();
-
-    let mut opts = Options::empty();
-    opts.insert(Options::ENABLE_MATH);
-    opts.insert(Options::ENABLE_TABLES);
-    opts.insert(Options::ENABLE_STRIKETHROUGH);
-    opts.insert(Options::ENABLE_SUPERSCRIPT);
-    if wikilinks {
-        opts.insert(Options::ENABLE_WIKILINKS);
-    }
-    if subscript {
-        opts.insert(Options::ENABLE_SUBSCRIPT);
-    }
-    opts.insert(Options::ENABLE_TASKLISTS);
-    opts.insert(Options::ENABLE_GFM);
-    if old_footnotes {
-        opts.insert(Options::ENABLE_OLD_FOOTNOTES);
-    } else {
-        opts.insert(Options::ENABLE_FOOTNOTES);
-    }
-    if metadata_blocks {
-        opts.insert(Options::ENABLE_YAML_STYLE_METADATA_BLOCKS);
-        opts.insert(Options::ENABLE_PLUSES_DELIMITED_METADATA_BLOCKS);
-    }
-    if smart_punct {
-        opts.insert(Options::ENABLE_SMART_PUNCTUATION);
-    }
-    opts.insert(Options::ENABLE_HEADING_ATTRIBUTES);
-    if deflists {
-        opts.insert(Options::ENABLE_DEFINITION_LIST);
-    }
-    if container_extensions {
-        opts.insert(Options::ENABLE_CONTAINER_EXTENSIONS);
-    }
-
-    let p = Parser::new_ext(input, opts);
-    pulldown_cmark::html::push_html(&mut s, p);
+pub fn test_markdown_html(input: &str, output: &str, variant: &str) {
+    let case = TestCase {
+        number: 0,
+        line: 0,
+        markdown: input.into(),
+        html: output.into(),
+        variant: variant.into(),
+    };
+    let opts = Options::ENABLE_MATH
+        | Options::ENABLE_TABLES
+        | Options::ENABLE_STRIKETHROUGH
+        | Options::ENABLE_SUPERSCRIPT
+        | Options::ENABLE_TASKLISTS
+        | Options::ENABLE_GFM
+        | Options::ENABLE_FOOTNOTES
+        | Options::ENABLE_HEADING_ATTRIBUTES;
+    let s = testing::render_html(input, case.options(opts));
 
     // normalizing the HTML using html5ever may hide actual errors
-    // assert_eq!(html_standardize(output), html_standardize(&s));
-    assert_eq!(html_standardize(output), html_standardize(&s));
-}
-
-fn html_standardize(s: &str) -> String {
-    s.replace("<br>", "<br />")
-        .replace("<br/>", "<br />")
-        .replace("<hr>", "<hr />")
-        .replace("<hr/>", "<hr />")
-        // permit extra or missing line breaks only between tags
-        .replace(">\n<", "><")
+    assert_eq!(testing::normalize_html(output), testing::normalize_html(&s));
 }
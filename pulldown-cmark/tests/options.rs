@@ -0,0 +1,86 @@
+use pulldown_cmark::Options;
+
+#[test]
+fn options_display_uses_flag_names() {
+    let options = Options::ENABLE_TABLES | Options::ENABLE_FOOTNOTES | Options::ENABLE_GFM;
+    assert_eq!(options.to_string(), "tables,footnotes,gfm");
+    assert_eq!(Options::empty().to_string(), "");
+    assert_eq!(Options::ENABLE_OLD_FOOTNOTES.to_string(), "old_footnotes");
+}
+
+#[test]
+fn options_from_str() {
+    assert_eq!(
+        " tables, footnotes ,gfm,".parse::<Options>(),
+        Ok(Options::ENABLE_TABLES | Options::ENABLE_FOOTNOTES | Options::ENABLE_GFM)
+    );
+    assert_eq!("".parse::<Options>(), Ok(Options::empty()));
+    let err = "tables,typo".parse::<Options>().unwrap_err();
+    assert_eq!(err.to_string(), "unknown option name `typo`");
+}
+
+#[test]
+fn options_round_trip() {
+    let all = Options::all();
+    assert_eq!(all.to_string().parse::<Options>(), Ok(all));
+    let old = Options::ENABLE_OLD_FOOTNOTES | Options::ENABLE_MATH;
+    assert_eq!(old.to_string().parse::<Options>(), Ok(old));
+}
+
+#[test]
+fn options_presets() {
+    assert_eq!(Options::commonmark(), Options::empty());
+    assert_eq!(
+        Options::gfm().to_string(),
+        "tables,footnotes,strikethrough,tasklists,gfm"
+    );
+    assert!(Options::obsidian().contains(Options::gfm() | Options::ENABLE_WIKILINKS));
+
+    let all = Options::all_extensions();
+    assert!(all.contains(Options::ENABLE_FOOTNOTES | Options::ENABLE_CONTAINER_EXTENSIONS));
+    assert!(!all.contains(Options::ENABLE_OLD_FOOTNOTES));
+    assert!(!all.contains(Options::ENABLE_DIAGNOSTICS));
+}
+
+#[test]
+fn front_matter_overrides_options() {
+    use pulldown_cmark::{Event, Parser, ParserCallbacks};
+
+    struct Overrides<'a>(&'a mut Vec<String>);
+    impl<'input> ParserCallbacks<'input> for Overrides<'_> {
+        fn front_matter_override(
+            &mut self,
+            key: &str,
+            value: &str,
+            mut options: Options,
+        ) -> Options {
+            self.0.push(format!("{key}={value}"));
+            match key {
+                "tables" => options.set(Options::ENABLE_TABLES, value == "true"),
+                "footnotes" => options.set(Options::ENABLE_FOOTNOTES, value == "true"),
+                _ => {}
+            }
+            options
+        }
+    }
+
+    let markdown =
+        "---\nmarkdown:\n  tables: false\n  footnotes: true\n  theme: dark\n---\n| a |\n|---|\n";
+    let options = Options::ENABLE_YAML_STYLE_METADATA_BLOCKS | Options::ENABLE_TABLES;
+    let mut keys = Vec::new();
+    let parser = Parser::new_with_callbacks(markdown, options, Overrides(&mut keys));
+    assert!(!parser
+        .into_iter()
+        .any(|event| matches!(event, Event::Start(pulldown_cmark::Tag::Table(_)))));
+    assert_eq!(keys, ["tables=false", "footnotes=true", "theme=dark"]);
+
+    // disabled features stay disabled
+    let parser = Parser::builder()
+        .options(options)
+        .disable(pulldown_cmark::DisabledFeatures::FOOTNOTES)
+        .callbacks(Overrides(&mut keys))
+        .build("---\nmarkdown: { footnotes: true }\n---\n[^a]\n\n[^a]: b\n");
+    assert!(!parser
+        .into_iter()
+        .any(|event| matches!(event, Event::FootnoteReference(_))));
+}
@@ -2,7 +2,9 @@
 mod tests {
     use std::convert::TryInto;
 
-    use pulldown_cmark::CowStr;
+    use pulldown_cmark::{
+        html::RawHtmlPolicy, CowStr, DialectConfig, DisabledFeatures, Options, SmartPunctuation,
+    };
 
     #[test]
     fn escaped() {
@@ -54,6 +56,15 @@ mod tests {
         }
     }
 
+    #[test]
+    fn cow_str_json_borrows_when_possible() {
+        let decoded: CowStr = serde_json::from_str("\"no escapes in this string\"").unwrap();
+        assert!(matches!(decoded, CowStr::Borrowed(_)));
+        let decoded: CowStr = serde_json::from_str("\"an \\\"escaped\\\" string\"").unwrap();
+        assert!(!matches!(decoded, CowStr::Borrowed(_)));
+        assert_eq!(decoded.as_ref(), "an \"escaped\" string");
+    }
+
     #[test]
     fn str_to_cow_str_json() {
         let str = "a borrowed str";
@@ -83,4 +94,88 @@ mod tests {
         assert_eq!(decoded_str.as_ref(), str);
         assert_eq!(decoded_string.as_ref(), string);
     }
+
+    #[test]
+    fn options_json() {
+        let options = Options::ENABLE_TABLES | Options::ENABLE_MATH;
+        let encoded = serde_json::to_string(&options).unwrap();
+        assert_eq!(encoded, "\"tables,math\"");
+        assert_eq!(serde_json::from_str::<Options>(&encoded).unwrap(), options);
+        assert_eq!(
+            serde_json::from_str::<Options>("[\"tables\", \"math\"]").unwrap(),
+            options
+        );
+        assert!(serde_json::from_str::<Options>("\"tables,typo\"").is_err());
+    }
+
+    #[test]
+    fn options_bincode() {
+        let options = Options::ENABLE_OLD_FOOTNOTES | Options::ENABLE_GFM;
+        let encoded = bincode::serialize(&options).unwrap();
+        let decoded: Options = bincode::deserialize(&encoded).unwrap();
+        assert_eq!(decoded, options);
+    }
+
+    #[test]
+    fn dialect_config_json() {
+        let dialect: DialectConfig = serde_json::from_str(
+            r#"{
+                "options": "tables,smart_punctuation",
+                "smart_punctuation": "DASHES | SYMBOLS",
+                "disabled": "RAW_HTML",
+                "work_budget": 1000,
+                "raw_html": "Tagfilter"
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(
+            dialect,
+            DialectConfig {
+                options: Options::ENABLE_TABLES | Options::ENABLE_SMART_PUNCTUATION,
+                smart_punctuation: Some(SmartPunctuation::DASHES | SmartPunctuation::SYMBOLS),
+                disabled: DisabledFeatures::RAW_HTML,
+                work_budget: Some(1000),
+                raw_html: RawHtmlPolicy::Tagfilter,
+                ..Default::default()
+            }
+        );
+        let encoded = serde_json::to_string(&dialect).unwrap();
+        assert_eq!(
+            serde_json::from_str::<DialectConfig>(&encoded).unwrap(),
+            dialect
+        );
+        assert_eq!(
+            serde_json::from_str::<DialectConfig>("{}").unwrap(),
+            DialectConfig::default()
+        );
+    }
+
+    #[test]
+    fn event_log_round_trip() {
+        use pulldown_cmark::{
+            html,
+            transform::{EventLog, Recorder},
+            Parser,
+        };
+
+        let markdown = "# Title\n\n- [link](https://example.com \"t\")\n";
+        let mut recorder = Recorder::new(Parser::new(markdown).into_offset_iter());
+        let mut expected = String::new();
+        html::push_html(&mut expected, recorder.by_ref().map(|(event, _)| event));
+        let log = recorder.into_log();
+
+        let encoded = serde_json::to_string(&log).unwrap();
+        let decoded: EventLog = serde_json::from_str(&encoded).unwrap();
+        assert_eq!(decoded, log);
+        let mut s = String::new();
+        html::push_html(&mut s, decoded.replay());
+        assert_eq!(s, expected);
+
+        let encoded = bincode::serialize(&log).unwrap();
+        let decoded: EventLog = bincode::deserialize(&encoded).unwrap();
+        assert_eq!(
+            decoded.replay().into_offset_iter().collect::<Vec<_>>(),
+            Parser::new(markdown).into_offset_iter().collect::<Vec<_>>()
+        );
+    }
 }
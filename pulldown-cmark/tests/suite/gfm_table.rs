@@ -25,7 +25,7 @@ fn gfm_table_test_1() {
 </table>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -50,7 +50,7 @@ bar | baz
 </table>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -77,7 +77,7 @@ fn gfm_table_test_3() {
 </table>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -106,7 +106,7 @@ fn gfm_table_test_4() {
 </blockquote>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -139,7 +139,7 @@ bar
 <p>bar</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -153,7 +153,7 @@ fn gfm_table_test_6() {
 | bar |</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -183,7 +183,7 @@ fn gfm_table_test_7() {
 </table>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -202,7 +202,7 @@ fn gfm_table_test_8() {
 </table>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -229,5 +229,5 @@ fn gfm_table_test_9() {
 </table>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
@@ -20,7 +20,7 @@ multiple! {.myclass1 myattr #myh3 otherattr=value .myclass2}
 <h2 id="myh3" class="myclass1 myclass2" myattr="" otherattr="value">multiple!</h2>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -36,7 +36,7 @@ fn heading_attrs_test_2() {
 <h3 id="myh3" class="myclass1 myclass2" myattr="" otherattr="value">multiple!</h3>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -53,7 +53,7 @@ fn heading_attrs_test_3() {
 <h4>non-attribute-block {#id4}</h4>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -65,7 +65,7 @@ fn heading_attrs_test_4() {
 <h2 id="myid2">tabs</h2>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -77,7 +77,7 @@ nextline
 <p>nextline</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -99,7 +99,7 @@ nextline {.class}
 <p>](https://example.com/) {#myid3}</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -114,7 +114,7 @@ cont
 </h1>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -133,7 +133,7 @@ fn heading_attrs_test_8() {
 }</h1>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -145,7 +145,7 @@ fn heading_attrs_test_9() {
 <h2 id="id2">recommended style with spaces</h2>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -159,7 +159,7 @@ fn heading_attrs_test_10() {
 <h3 class="myclass">H3</h3>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -171,7 +171,7 @@ fn heading_attrs_test_11() {
 <h2 class="class1#id2.class2">H2</h2>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -183,7 +183,7 @@ fn heading_attrs_test_12() {
 <h2>H2 {#id2</h2>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -195,7 +195,7 @@ fn heading_attrs_test_13() {
 <h2>H2 #id2}</h2>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -207,7 +207,7 @@ fn heading_attrs_test_14() {
 <h2>H2 {#id2} <!-- hello --></h2>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -225,7 +225,7 @@ fn heading_attrs_test_15() {
 <h5 id="id5"><a href="uri">text</a></h5>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -235,7 +235,7 @@ fn heading_attrs_test_16() {
     let expected = r##"<h1 id="last">H1</h1>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -245,7 +245,7 @@ fn heading_attrs_test_17() {
     let expected = r##"<h1 class="z a zz">H1</h1>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -255,7 +255,7 @@ fn heading_attrs_test_18() {
     let expected = r##"<h1 class="a a a">H1</h1>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -267,7 +267,7 @@ fn heading_attrs_test_19() {
 <h2 id="m" class="z a">H2</h2>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -279,7 +279,7 @@ fn heading_attrs_test_20() {
 <h2 id="myid" class="myclass" unknown="" this#is.ignored="" attr="value">H2</h2>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -289,7 +289,7 @@ fn heading_attrs_test_21() {
     let expected = r##"<h1 myattr="value" other_attr="">Header</h1>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -299,7 +299,7 @@ fn heading_attrs_test_22() {
     let expected = r##"<h4 id="id" class="class1" myattr="" other_attr="false">Header</h4>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -311,7 +311,7 @@ fn heading_attrs_test_23() {
 <h2 class="bar">H2 {.foo</h2>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -321,7 +321,7 @@ fn heading_attrs_test_24() {
     let expected = r##"<h1>H1 {.foo}bar}</h1>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -331,7 +331,7 @@ fn heading_attrs_test_25() {
     let expected = r##"<h1>H1 {<i>foo</i>}</h1>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -341,7 +341,7 @@ fn heading_attrs_test_26() {
     let expected = r##"<h1>H1 {.foo}</h1>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -354,7 +354,7 @@ fn heading_attrs_test_27() {
 .bar}</h1>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -368,7 +368,7 @@ fn heading_attrs_test_28() {
 <h2>H2 {}</h2>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -378,7 +378,7 @@ fn heading_attrs_test_29() {
     let expected = r##"<h2>H2 {}</h2>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -396,7 +396,7 @@ newline can be used for setext heading {
 }</h2>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -410,7 +410,7 @@ fn heading_attrs_test_31() {
 <h3>stray backslash at the end is preserved \</h3>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -428,7 +428,7 @@ stray backslash at the end is preserved \
 <h2>stray backslash at the end is preserved \</h2>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -442,7 +442,7 @@ fn heading_attrs_test_33() {
 <h3 id="foo**bar**baz">H3</h3>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -461,7 +461,7 @@ H2-2 {#foo**bar**baz}
 <h2 id="foo**bar**baz">H2-2</h2>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -475,7 +475,7 @@ fn heading_attrs_test_35() {
 <h3 class="a&quot;b&#39;c&amp;d">H3</h3>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -487,7 +487,7 @@ fn heading_attrs_test_36() {
 <h2>H2</h2>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -499,7 +499,7 @@ fn heading_attrs_test_37() {
 <h1 class="foo bar">H1</h1>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -518,7 +518,7 @@ fn heading_attrs_test_38() {
 <p>#{}</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -545,7 +545,7 @@ fn heading_attrs_test_39() {
 <h2>{}</h2>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -565,7 +565,7 @@ fn heading_attrs_test_40() {
 <h3 id="vt">vertical tab</h3>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -580,7 +580,7 @@ fn heading_attrs_test_41() {
 <h1 id="vt.myclass">vertical tab (U+000B)</h1>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -592,5 +592,5 @@ fn heading_attrs_test_42() {
 <h2 id="ideographic-space　.myclass">IDEOGRAPHIC SPACE (U+3000)</h2>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
@@ -12,7 +12,7 @@ another_field: 0
 "##;
     let expected = r##""##;
 
-    test_markdown_html(original, expected, false, true, false, false, false, false, false);
+    test_markdown_html(original, expected, "metadata_blocks");
 }
 
 #[test]
@@ -26,7 +26,7 @@ another_field: 0
 another_field: 0</p>
 "##;
 
-    test_markdown_html(original, expected, false, true, false, false, false, false, false);
+    test_markdown_html(original, expected, "metadata_blocks");
 }
 
 #[test]
@@ -38,7 +38,7 @@ fn metadata_blocks_test_3() {
 <hr>
 "##;
 
-    test_markdown_html(original, expected, false, true, false, false, false, false, false);
+    test_markdown_html(original, expected, "metadata_blocks");
 }
 
 #[test]
@@ -54,7 +54,7 @@ another_field: 0
 another_field: 0</h2>
 "##;
 
-    test_markdown_html(original, expected, false, true, false, false, false, false, false);
+    test_markdown_html(original, expected, "metadata_blocks");
 }
 
 #[test]
@@ -70,7 +70,7 @@ another_field: 0
 another_field: 0</h2>
 "##;
 
-    test_markdown_html(original, expected, false, true, false, false, false, false, false);
+    test_markdown_html(original, expected, "metadata_blocks");
 }
 
 #[test]
@@ -85,7 +85,7 @@ another_field: 0
     let expected = r##"<p>My paragraph here.</p>
 "##;
 
-    test_markdown_html(original, expected, false, true, false, false, false, false, false);
+    test_markdown_html(original, expected, "metadata_blocks");
 }
 
 #[test]
@@ -105,7 +105,7 @@ another_field: 0
 another_field: 0</h2>
 "##;
 
-    test_markdown_html(original, expected, false, true, false, false, false, false, false);
+    test_markdown_html(original, expected, "metadata_blocks");
 }
 
 #[test]
@@ -126,7 +126,7 @@ another_field: 0
 ---a</p>
 "##;
 
-    test_markdown_html(original, expected, false, true, false, false, false, false, false);
+    test_markdown_html(original, expected, "metadata_blocks");
 }
 
 #[test]
@@ -138,7 +138,7 @@ another_field: 0
 "##;
     let expected = r##""##;
 
-    test_markdown_html(original, expected, false, true, false, false, false, false, false);
+    test_markdown_html(original, expected, "metadata_blocks");
 }
 
 #[test]
@@ -150,7 +150,7 @@ another_field: 0
 "##;
     let expected = r##""##;
 
-    test_markdown_html(original, expected, false, true, false, false, false, false, false);
+    test_markdown_html(original, expected, "metadata_blocks");
 }
 
 #[test]
@@ -165,7 +165,7 @@ Things
 </code></pre>
 "##;
 
-    test_markdown_html(original, expected, false, true, false, false, false, false, false);
+    test_markdown_html(original, expected, "metadata_blocks");
 }
 
 #[test]
@@ -177,5 +177,5 @@ fn metadata_blocks_test_12() {
 "##;
     let expected = r##""##;
 
-    test_markdown_html(original, expected, false, true, false, false, false, false, false);
+    test_markdown_html(original, expected, "metadata_blocks");
 }
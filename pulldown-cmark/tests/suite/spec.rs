@@ -11,7 +11,7 @@ fn spec_test_1() {
 </code></pre>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -22,7 +22,7 @@ fn spec_test_2() {
 </code></pre>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -35,7 +35,7 @@ fn spec_test_3() {
 </code></pre>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -52,7 +52,7 @@ fn spec_test_4() {
 </ul>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -70,7 +70,7 @@ fn spec_test_5() {
 </ul>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -83,7 +83,7 @@ fn spec_test_6() {
 </blockquote>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -98,7 +98,7 @@ fn spec_test_7() {
 </ul>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -111,7 +111,7 @@ bar
 </code></pre>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -133,7 +133,7 @@ fn spec_test_9() {
 </ul>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -143,7 +143,7 @@ fn spec_test_10() {
     let expected = r##"<h1>Foo</h1>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -153,7 +153,7 @@ fn spec_test_11() {
     let expected = r##"<hr />
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -163,7 +163,7 @@ fn spec_test_12() {
     let expected = r##"<p>!"#$%&amp;'()*+,-./:;&lt;=&gt;?@[\]^_`{|}~</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -173,7 +173,7 @@ fn spec_test_13() {
     let expected = r##"<p>\	\A\a\ \3\φ\«</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -199,7 +199,7 @@ fn spec_test_14() {
 &amp;ouml; not a character entity</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -209,7 +209,7 @@ fn spec_test_15() {
     let expected = r##"<p>\<em>emphasis</em></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -221,7 +221,7 @@ bar
 bar</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -231,7 +231,7 @@ fn spec_test_17() {
     let expected = r##"<p><code>\[\`</code></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -242,7 +242,7 @@ fn spec_test_18() {
 </code></pre>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -255,7 +255,7 @@ fn spec_test_19() {
 </code></pre>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -265,7 +265,7 @@ fn spec_test_20() {
     let expected = r##"<p><a href="https://example.com?find=%5C*">https://example.com?find=\*</a></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -275,7 +275,7 @@ fn spec_test_21() {
     let expected = r##"<a href="/bar\/)">
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -285,7 +285,7 @@ fn spec_test_22() {
     let expected = r##"<p><a href="/bar*" title="ti*tle">foo</a></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -297,7 +297,7 @@ fn spec_test_23() {
     let expected = r##"<p><a href="/bar*" title="ti*tle">foo</a></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -310,7 +310,7 @@ foo
 </code></pre>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -324,7 +324,7 @@ fn spec_test_25() {
 ∲ ≧̸</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -334,7 +334,7 @@ fn spec_test_26() {
     let expected = r##"<p># Ӓ Ϡ �</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -344,7 +344,7 @@ fn spec_test_27() {
     let expected = r##"<p>" ആ ಫ</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -360,7 +360,7 @@ fn spec_test_28() {
 &amp;ThisIsNotDefined; &amp;hi?;</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -370,7 +370,7 @@ fn spec_test_29() {
     let expected = r##"<p>&amp;copy</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -380,7 +380,7 @@ fn spec_test_30() {
     let expected = r##"<p>&amp;MadeUpEntity;</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -390,7 +390,7 @@ fn spec_test_31() {
     let expected = r##"<a href="&ouml;&ouml;.html">
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -400,7 +400,7 @@ fn spec_test_32() {
     let expected = r##"<p><a href="/f%C3%B6%C3%B6" title="föö">foo</a></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -412,7 +412,7 @@ fn spec_test_33() {
     let expected = r##"<p><a href="/f%C3%B6%C3%B6" title="föö">foo</a></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -425,7 +425,7 @@ foo
 </code></pre>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -435,7 +435,7 @@ fn spec_test_35() {
     let expected = r##"<p><code>f&amp;ouml;&amp;ouml;</code></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -446,7 +446,7 @@ fn spec_test_36() {
 </code></pre>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -458,7 +458,7 @@ fn spec_test_37() {
 <em>foo</em></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -473,7 +473,7 @@ fn spec_test_38() {
 </ul>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -485,7 +485,7 @@ fn spec_test_39() {
 bar</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -495,7 +495,7 @@ fn spec_test_40() {
     let expected = r##"<p>	foo</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -505,7 +505,7 @@ fn spec_test_41() {
     let expected = r##"<p>[a](url "tit")</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -519,7 +519,7 @@ fn spec_test_42() {
 </ul>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -533,7 +533,7 @@ ___
 <hr />
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -543,7 +543,7 @@ fn spec_test_44() {
     let expected = r##"<p>+++</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -553,7 +553,7 @@ fn spec_test_45() {
     let expected = r##"<p>===</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -567,7 +567,7 @@ __
 __</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -581,7 +581,7 @@ fn spec_test_47() {
 <hr />
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -592,7 +592,7 @@ fn spec_test_48() {
 </code></pre>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -604,7 +604,7 @@ fn spec_test_49() {
 ***</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -614,7 +614,7 @@ fn spec_test_50() {
     let expected = r##"<hr />
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -624,7 +624,7 @@ fn spec_test_51() {
     let expected = r##"<hr />
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -634,7 +634,7 @@ fn spec_test_52() {
     let expected = r##"<hr />
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -644,7 +644,7 @@ fn spec_test_53() {
     let expected = r##"<hr />
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -654,7 +654,7 @@ fn spec_test_54() {
     let expected = r##"<hr />
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -670,7 +670,7 @@ a------
 <p>---a---</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -680,7 +680,7 @@ fn spec_test_56() {
     let expected = r##"<p><em>-</em></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -698,7 +698,7 @@ fn spec_test_57() {
 </ul>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -712,7 +712,7 @@ bar
 <p>bar</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -725,7 +725,7 @@ bar
 <p>bar</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -743,7 +743,7 @@ fn spec_test_60() {
 </ul>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -759,7 +759,7 @@ fn spec_test_61() {
 </ul>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -779,7 +779,7 @@ fn spec_test_62() {
 <h6>foo</h6>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -789,7 +789,7 @@ fn spec_test_63() {
     let expected = r##"<p>####### foo</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -802,7 +802,7 @@ fn spec_test_64() {
 <p>#hashtag</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -812,7 +812,7 @@ fn spec_test_65() {
     let expected = r##"<p>## foo</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -822,7 +822,7 @@ fn spec_test_66() {
     let expected = r##"<h1>foo <em>bar</em> *baz*</h1>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -832,7 +832,7 @@ fn spec_test_67() {
     let expected = r##"<h1>foo</h1>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -846,7 +846,7 @@ fn spec_test_68() {
 <h1>foo</h1>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -857,7 +857,7 @@ fn spec_test_69() {
 </code></pre>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -869,7 +869,7 @@ fn spec_test_70() {
 # bar</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -881,7 +881,7 @@ fn spec_test_71() {
 <h3>bar</h3>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -893,7 +893,7 @@ fn spec_test_72() {
 <h5>foo</h5>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -903,7 +903,7 @@ fn spec_test_73() {
     let expected = r##"<h3>foo</h3>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -913,7 +913,7 @@ fn spec_test_74() {
     let expected = r##"<h3>foo ### b</h3>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -923,7 +923,7 @@ fn spec_test_75() {
     let expected = r##"<h1>foo#</h1>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -937,7 +937,7 @@ fn spec_test_76() {
 <h1>foo #</h1>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -951,7 +951,7 @@ fn spec_test_77() {
 <hr />
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -965,7 +965,7 @@ Bar foo
 <p>Bar foo</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -979,7 +979,7 @@ fn spec_test_79() {
 <h3></h3>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -994,7 +994,7 @@ Foo *bar*
 <h2>Foo <em>bar</em></h2>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -1007,7 +1007,7 @@ baz*
 baz</em></h1>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -1020,7 +1020,7 @@ baz*
 baz</em></h1>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -1035,7 +1035,7 @@ Foo
 <h1>Foo</h1>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -1054,7 +1054,7 @@ fn spec_test_84() {
 <h1>Foo</h1>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -1073,7 +1073,7 @@ Foo
 <hr />
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -1084,7 +1084,7 @@ fn spec_test_86() {
     let expected = r##"<h2>Foo</h2>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -1096,7 +1096,7 @@ fn spec_test_87() {
 ---</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -1113,7 +1113,7 @@ Foo
 <hr />
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -1124,7 +1124,7 @@ fn spec_test_89() {
     let expected = r##"<h2>Foo</h2>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -1135,7 +1135,7 @@ fn spec_test_90() {
     let expected = r##"<h2>Foo\</h2>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -1154,7 +1154,7 @@ of dashes"/>
 <p>of dashes"/&gt;</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -1168,7 +1168,7 @@ fn spec_test_92() {
 <hr />
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -1184,7 +1184,7 @@ bar
 </blockquote>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -1198,7 +1198,7 @@ fn spec_test_94() {
 <hr />
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -1211,7 +1211,7 @@ Bar
 Bar</h2>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -1229,7 +1229,7 @@ Baz
 <p>Baz</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -1240,7 +1240,7 @@ fn spec_test_97() {
     let expected = r##"<p>====</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -1252,7 +1252,7 @@ fn spec_test_98() {
 <hr />
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -1266,7 +1266,7 @@ fn spec_test_99() {
 <hr />
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -1279,7 +1279,7 @@ fn spec_test_100() {
 <hr />
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -1293,7 +1293,7 @@ fn spec_test_101() {
 <hr />
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -1304,7 +1304,7 @@ fn spec_test_102() {
     let expected = r##"<h2>&gt; foo</h2>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -1320,7 +1320,7 @@ baz
 <p>baz</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -1338,7 +1338,7 @@ bar</p>
 <p>baz</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -1354,7 +1354,7 @@ bar</p>
 <p>baz</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -1370,7 +1370,7 @@ bar
 baz</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -1383,7 +1383,7 @@ fn spec_test_107() {
 </code></pre>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -1400,7 +1400,7 @@ fn spec_test_108() {
 </ul>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -1419,7 +1419,7 @@ fn spec_test_109() {
 </ol>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -1436,7 +1436,7 @@ fn spec_test_110() {
 </code></pre>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -1459,7 +1459,7 @@ chunk3
 </code></pre>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -1474,7 +1474,7 @@ fn spec_test_112() {
 </code></pre>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -1487,7 +1487,7 @@ fn spec_test_113() {
 bar</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -1500,7 +1500,7 @@ bar
 <p>bar</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -1521,7 +1521,7 @@ Heading
 <hr />
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -1534,7 +1534,7 @@ bar
 </code></pre>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -1549,7 +1549,7 @@ fn spec_test_117() {
 </code></pre>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -1560,7 +1560,7 @@ fn spec_test_118() {
 </code></pre>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -1575,7 +1575,7 @@ fn spec_test_119() {
 </code></pre>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -1590,7 +1590,7 @@ fn spec_test_120() {
 </code></pre>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -1602,7 +1602,7 @@ foo
     let expected = r##"<p><code>foo</code></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -1617,7 +1617,7 @@ aaa
 </code></pre>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -1632,7 +1632,7 @@ aaa
 </code></pre>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -1647,7 +1647,7 @@ aaa
 </code></pre>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -1662,7 +1662,7 @@ aaa
 </code></pre>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -1672,7 +1672,7 @@ fn spec_test_126() {
     let expected = r##"<pre><code></code></pre>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -1688,7 +1688,7 @@ aaa
 </code></pre>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -1705,7 +1705,7 @@ bbb
 <p>bbb</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -1720,7 +1720,7 @@ fn spec_test_129() {
 </code></pre>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -1731,7 +1731,7 @@ fn spec_test_130() {
     let expected = r##"<pre><code></code></pre>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -1746,7 +1746,7 @@ aaa
 </code></pre>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -1763,7 +1763,7 @@ aaa
 </code></pre>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -1780,7 +1780,7 @@ aaa
 </code></pre>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -1795,7 +1795,7 @@ aaa
 </code></pre>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -1808,7 +1808,7 @@ aaa
 </code></pre>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -1821,7 +1821,7 @@ aaa
 </code></pre>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -1835,7 +1835,7 @@ aaa
 </code></pre>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -1847,7 +1847,7 @@ aaa
 aaa</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -1861,7 +1861,7 @@ aaa
 </code></pre>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -1878,7 +1878,7 @@ baz
 <p>baz</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -1896,7 +1896,7 @@ bar
 <h1>baz</h1>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -1913,7 +1913,7 @@ end
 </code></pre>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -1930,7 +1930,7 @@ end
 </code></pre>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -1941,7 +1941,7 @@ fn spec_test_144() {
     let expected = r##"<pre><code class="language-;"></code></pre>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -1953,7 +1953,7 @@ foo
 foo</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -1966,7 +1966,7 @@ foo
 </code></pre>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -1979,7 +1979,7 @@ fn spec_test_147() {
 </code></pre>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -2000,7 +2000,7 @@ _world_.
 </td></tr></table>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -2025,7 +2025,7 @@ okay.
 <p>okay.</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -2039,7 +2039,7 @@ fn spec_test_150() {
          <foo><a>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -2051,7 +2051,7 @@ fn spec_test_151() {
 *foo*
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -2067,7 +2067,7 @@ fn spec_test_152() {
 </DIV>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -2081,7 +2081,7 @@ fn spec_test_153() {
 </div>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -2095,7 +2095,7 @@ fn spec_test_154() {
 </div>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -2110,7 +2110,7 @@ fn spec_test_155() {
 <p><em>bar</em></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -2122,7 +2122,7 @@ fn spec_test_156() {
 *hi*
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -2134,7 +2134,7 @@ foo
 foo
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -2146,7 +2146,7 @@ fn spec_test_158() {
 *foo*
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -2156,7 +2156,7 @@ fn spec_test_159() {
     let expected = r##"<div><a href="bar">*foo*</a></div>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -2170,7 +2170,7 @@ foo
 </td></tr></table>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -2186,7 +2186,7 @@ int x = 33;
 ```
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -2200,7 +2200,7 @@ fn spec_test_162() {
 </a>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -2214,7 +2214,7 @@ fn spec_test_163() {
 </Warning>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -2228,7 +2228,7 @@ fn spec_test_164() {
 </i>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -2240,7 +2240,7 @@ fn spec_test_165() {
 *bar*
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -2254,7 +2254,7 @@ fn spec_test_166() {
 </del>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -2270,7 +2270,7 @@ fn spec_test_167() {
 </del>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -2280,7 +2280,7 @@ fn spec_test_168() {
     let expected = r##"<p><del><em>foo</em></del></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -2302,7 +2302,7 @@ main = print $ parseTags tags
 <p>okay</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -2322,7 +2322,7 @@ document.getElementById("demo").innerHTML = "Hello JavaScript!";
 <p>okay</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -2344,7 +2344,7 @@ _bar_
 </textarea>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -2366,7 +2366,7 @@ p {color:blue;}
 <p>okay</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -2382,7 +2382,7 @@ foo
 foo
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -2399,7 +2399,7 @@ foo
 <p>bar</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -2415,7 +2415,7 @@ fn spec_test_175() {
 </ul>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -2427,7 +2427,7 @@ fn spec_test_176() {
 <p><em>foo</em></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -2439,7 +2439,7 @@ fn spec_test_177() {
 <p><em>baz</em></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -2453,7 +2453,7 @@ foo
 </script>1. *bar*
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -2471,7 +2471,7 @@ bar
 <p>okay</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -2491,7 +2491,7 @@ okay
 <p>okay</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -2501,7 +2501,7 @@ fn spec_test_181() {
     let expected = r##"<!DOCTYPE html>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -2535,7 +2535,7 @@ function matchwo(a,b)
 <p>okay</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -2549,7 +2549,7 @@ fn spec_test_183() {
 </code></pre>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -2563,7 +2563,7 @@ fn spec_test_184() {
 </code></pre>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -2579,7 +2579,7 @@ bar
 </div>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -2595,7 +2595,7 @@ bar
 *foo*
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -2609,7 +2609,7 @@ baz
 baz</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -2625,7 +2625,7 @@ fn spec_test_188() {
 </div>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -2639,7 +2639,7 @@ fn spec_test_189() {
 </div>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -2665,7 +2665,7 @@ Hi
 </table>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -2692,7 +2692,7 @@ fn spec_test_191() {
 </table>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -2704,7 +2704,7 @@ fn spec_test_192() {
     let expected = r##"<p><a href="/url" title="title">foo</a></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -2718,7 +2718,7 @@ fn spec_test_193() {
     let expected = r##"<p><a href="/url" title="the title">foo</a></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -2730,7 +2730,7 @@ fn spec_test_194() {
     let expected = r##"<p><a href="my_(url)" title="title (with parens)">Foo*bar]</a></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -2744,7 +2744,7 @@ fn spec_test_195() {
     let expected = r##"<p><a href="my%20url" title="title">Foo bar</a></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -2764,7 +2764,7 @@ line2
 ">foo</a></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -2780,7 +2780,7 @@ with blank line'
 <p>[foo]</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -2793,7 +2793,7 @@ fn spec_test_198() {
     let expected = r##"<p><a href="/url">foo</a></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -2806,7 +2806,7 @@ fn spec_test_199() {
 <p>[foo]</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -2818,7 +2818,7 @@ fn spec_test_200() {
     let expected = r##"<p><a href="">foo</a></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -2831,7 +2831,7 @@ fn spec_test_201() {
 <p>[foo]</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -2843,7 +2843,7 @@ fn spec_test_202() {
     let expected = r##"<p><a href="/url%5Cbar*baz" title="foo&quot;bar\baz">foo</a></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -2855,7 +2855,7 @@ fn spec_test_203() {
     let expected = r##"<p><a href="url">foo</a></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -2868,7 +2868,7 @@ fn spec_test_204() {
     let expected = r##"<p><a href="first">foo</a></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -2880,7 +2880,7 @@ fn spec_test_205() {
     let expected = r##"<p><a href="/url">Foo</a></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -2892,7 +2892,7 @@ fn spec_test_206() {
     let expected = r##"<p><a href="/%CF%86%CE%BF%CF%85">αγω</a></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -2901,7 +2901,7 @@ fn spec_test_207() {
 "##;
     let expected = r##""##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -2914,7 +2914,7 @@ bar
     let expected = r##"<p>bar</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -2924,7 +2924,7 @@ fn spec_test_209() {
     let expected = r##"<p>[foo]: /url "title" ok</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -2935,7 +2935,7 @@ fn spec_test_210() {
     let expected = r##"<p>"title" ok</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -2949,7 +2949,7 @@ fn spec_test_211() {
 <p>[foo]</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -2965,7 +2965,7 @@ fn spec_test_212() {
 <p>[foo]</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -2980,7 +2980,7 @@ fn spec_test_213() {
 <p>[bar]</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -2995,7 +2995,7 @@ fn spec_test_214() {
 </blockquote>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -3009,7 +3009,7 @@ bar
 <p><a href="/url">foo</a></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -3022,7 +3022,7 @@ fn spec_test_216() {
 <a href="/url">foo</a></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -3041,7 +3041,7 @@ fn spec_test_217() {
 <a href="/baz-url">baz</a></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -3055,7 +3055,7 @@ fn spec_test_218() {
 </blockquote>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -3068,7 +3068,7 @@ bbb
 <p>bbb</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -3085,7 +3085,7 @@ bbb</p>
 ddd</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -3099,7 +3099,7 @@ bbb
 <p>bbb</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -3111,7 +3111,7 @@ fn spec_test_222() {
 bbb</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -3125,7 +3125,7 @@ bbb
 ccc</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -3137,7 +3137,7 @@ bbb
 bbb</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -3150,7 +3150,7 @@ bbb
 <p>bbb</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -3162,7 +3162,7 @@ bbb
 bbb</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -3180,7 +3180,7 @@ aaa
 <h1>aaa</h1>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -3196,7 +3196,7 @@ baz</p>
 </blockquote>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -3212,7 +3212,7 @@ baz</p>
 </blockquote>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -3228,7 +3228,7 @@ baz</p>
 </blockquote>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -3243,7 +3243,7 @@ fn spec_test_231() {
 </code></pre>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -3259,7 +3259,7 @@ baz</p>
 </blockquote>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -3275,7 +3275,7 @@ foo</p>
 </blockquote>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -3289,7 +3289,7 @@ fn spec_test_234() {
 <hr />
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -3307,7 +3307,7 @@ fn spec_test_235() {
 </ul>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -3323,7 +3323,7 @@ fn spec_test_236() {
 </code></pre>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -3339,7 +3339,7 @@ foo
 <pre><code></code></pre>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -3353,7 +3353,7 @@ fn spec_test_238() {
 </blockquote>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -3364,7 +3364,7 @@ fn spec_test_239() {
 </blockquote>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -3377,7 +3377,7 @@ fn spec_test_240() {
 </blockquote>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -3391,7 +3391,7 @@ fn spec_test_241() {
 </blockquote>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -3408,7 +3408,7 @@ fn spec_test_242() {
 </blockquote>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -3422,7 +3422,7 @@ bar</p>
 </blockquote>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -3437,7 +3437,7 @@ fn spec_test_244() {
 </blockquote>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -3451,7 +3451,7 @@ fn spec_test_245() {
 </blockquote>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -3469,7 +3469,7 @@ fn spec_test_246() {
 </blockquote>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -3483,7 +3483,7 @@ baz</p>
 </blockquote>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -3498,7 +3498,7 @@ baz
 <p>baz</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -3513,7 +3513,7 @@ baz
 <p>baz</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -3531,7 +3531,7 @@ bar</p>
 </blockquote>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -3551,7 +3551,7 @@ baz</p>
 </blockquote>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -3569,7 +3569,7 @@ fn spec_test_252() {
 </blockquote>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -3590,7 +3590,7 @@ with two lines.</p>
 </blockquote>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -3615,7 +3615,7 @@ with two lines.</p>
 </ol>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -3630,7 +3630,7 @@ fn spec_test_255() {
 <p>two</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -3647,7 +3647,7 @@ fn spec_test_256() {
 </ul>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -3663,7 +3663,7 @@ fn spec_test_257() {
 </code></pre>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -3680,7 +3680,7 @@ fn spec_test_258() {
 </ul>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -3701,7 +3701,7 @@ fn spec_test_259() {
 </blockquote>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -3720,7 +3720,7 @@ fn spec_test_260() {
 </blockquote>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -3733,7 +3733,7 @@ fn spec_test_261() {
 <p>2.two</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -3751,7 +3751,7 @@ fn spec_test_262() {
 </ul>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -3779,7 +3779,7 @@ fn spec_test_263() {
 </ol>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -3803,7 +3803,7 @@ baz
 </ul>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -3815,7 +3815,7 @@ fn spec_test_265() {
 </ol>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -3825,7 +3825,7 @@ fn spec_test_266() {
     let expected = r##"<p>1234567890. not ok</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -3837,7 +3837,7 @@ fn spec_test_267() {
 </ol>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -3849,7 +3849,7 @@ fn spec_test_268() {
 </ol>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -3859,7 +3859,7 @@ fn spec_test_269() {
     let expected = r##"<p>-1. not ok</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -3877,7 +3877,7 @@ fn spec_test_270() {
 </ul>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -3895,7 +3895,7 @@ fn spec_test_271() {
 </ol>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -3913,7 +3913,7 @@ paragraph
 </code></pre>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -3935,7 +3935,7 @@ fn spec_test_273() {
 </ol>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -3957,7 +3957,7 @@ fn spec_test_274() {
 </ol>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -3970,7 +3970,7 @@ bar
 <p>bar</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -3985,7 +3985,7 @@ fn spec_test_276() {
 <p>bar</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -4002,7 +4002,7 @@ fn spec_test_277() {
 </ul>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -4029,7 +4029,7 @@ fn spec_test_278() {
 </ul>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -4042,7 +4042,7 @@ fn spec_test_279() {
 </ul>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -4057,7 +4057,7 @@ fn spec_test_280() {
 <p>foo</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -4073,7 +4073,7 @@ fn spec_test_281() {
 </ul>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -4089,7 +4089,7 @@ fn spec_test_282() {
 </ul>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -4105,7 +4105,7 @@ fn spec_test_283() {
 </ol>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -4117,7 +4117,7 @@ fn spec_test_284() {
 </ul>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -4134,7 +4134,7 @@ foo
 1.</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -4159,7 +4159,7 @@ with two lines.</p>
 </ol>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -4184,7 +4184,7 @@ with two lines.</p>
 </ol>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -4209,7 +4209,7 @@ with two lines.</p>
 </ol>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -4230,7 +4230,7 @@ fn spec_test_289() {
 </code></pre>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -4255,7 +4255,7 @@ with two lines.</p>
 </ol>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -4269,7 +4269,7 @@ with two lines.</li>
 </ol>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -4289,7 +4289,7 @@ continued here.</p>
 </blockquote>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -4309,7 +4309,7 @@ continued here.</p>
 </blockquote>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -4336,7 +4336,7 @@ fn spec_test_294() {
 </ul>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -4354,7 +4354,7 @@ fn spec_test_295() {
 </ul>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -4371,7 +4371,7 @@ fn spec_test_296() {
 </ol>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -4387,7 +4387,7 @@ fn spec_test_297() {
 </ul>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -4403,7 +4403,7 @@ fn spec_test_298() {
 </ul>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -4423,7 +4423,7 @@ fn spec_test_299() {
 </ol>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -4443,7 +4443,7 @@ baz</li>
 </ul>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -4461,7 +4461,7 @@ fn spec_test_301() {
 </ul>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -4479,7 +4479,7 @@ fn spec_test_302() {
 </ol>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -4495,7 +4495,7 @@ fn spec_test_303() {
 </ul>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -4507,7 +4507,7 @@ fn spec_test_304() {
 14.  The number of doors is 6.</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -4521,7 +4521,7 @@ fn spec_test_305() {
 </ol>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -4546,7 +4546,7 @@ fn spec_test_306() {
 </ul>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -4574,7 +4574,7 @@ fn spec_test_307() {
 </ul>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -4598,7 +4598,7 @@ fn spec_test_308() {
 </ul>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -4627,7 +4627,7 @@ fn spec_test_309() {
 </code></pre>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -4651,7 +4651,7 @@ fn spec_test_310() {
 </ul>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -4675,7 +4675,7 @@ fn spec_test_311() {
 </ol>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -4695,7 +4695,7 @@ fn spec_test_312() {
 </ul>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -4718,7 +4718,7 @@ fn spec_test_313() {
 </code></pre>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -4741,7 +4741,7 @@ fn spec_test_314() {
 </ul>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -4762,7 +4762,7 @@ fn spec_test_315() {
 </ul>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -4787,7 +4787,7 @@ fn spec_test_316() {
 </ul>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -4811,7 +4811,7 @@ fn spec_test_317() {
 </ul>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -4836,7 +4836,7 @@ fn spec_test_318() {
 </ul>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -4860,7 +4860,7 @@ fn spec_test_319() {
 </ul>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -4880,7 +4880,7 @@ fn spec_test_320() {
 </ul>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -4904,7 +4904,7 @@ fn spec_test_321() {
 </ul>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -4916,7 +4916,7 @@ fn spec_test_322() {
 </ul>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -4933,7 +4933,7 @@ fn spec_test_323() {
 </ul>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -4953,7 +4953,7 @@ fn spec_test_324() {
 </ol>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -4974,7 +4974,7 @@ fn spec_test_325() {
 </ul>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -5005,7 +5005,7 @@ fn spec_test_326() {
 </ul>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -5015,7 +5015,7 @@ fn spec_test_327() {
     let expected = r##"<p><code>hi</code>lo`</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -5025,7 +5025,7 @@ fn spec_test_328() {
     let expected = r##"<p><code>foo</code></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -5035,7 +5035,7 @@ fn spec_test_329() {
     let expected = r##"<p><code>foo ` bar</code></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -5045,7 +5045,7 @@ fn spec_test_330() {
     let expected = r##"<p><code>``</code></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -5055,7 +5055,7 @@ fn spec_test_331() {
     let expected = r##"<p><code> `` </code></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -5065,7 +5065,7 @@ fn spec_test_332() {
     let expected = r##"<p><code> a</code></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -5075,7 +5075,7 @@ fn spec_test_333() {
     let expected = r##"<p><code> b </code></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -5087,7 +5087,7 @@ fn spec_test_334() {
 <code>  </code></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -5101,7 +5101,7 @@ baz
     let expected = r##"<p><code>foo bar   baz</code></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -5113,7 +5113,7 @@ foo
     let expected = r##"<p><code>foo </code></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -5124,7 +5124,7 @@ baz`
     let expected = r##"<p><code>foo   bar  baz</code></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -5134,7 +5134,7 @@ fn spec_test_338() {
     let expected = r##"<p><code>foo\</code>bar`</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -5144,7 +5144,7 @@ fn spec_test_339() {
     let expected = r##"<p><code>foo`bar</code></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -5154,7 +5154,7 @@ fn spec_test_340() {
     let expected = r##"<p><code>foo `` bar</code></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -5164,7 +5164,7 @@ fn spec_test_341() {
     let expected = r##"<p>*foo<code>*</code></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -5174,7 +5174,7 @@ fn spec_test_342() {
     let expected = r##"<p>[not a <code>link](/foo</code>)</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -5184,7 +5184,7 @@ fn spec_test_343() {
     let expected = r##"<p><code>&lt;a href="</code>"&gt;`</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -5194,7 +5194,7 @@ fn spec_test_344() {
     let expected = r##"<p><a href="`">`</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -5204,7 +5204,7 @@ fn spec_test_345() {
     let expected = r##"<p><code>&lt;https://foo.bar.</code>baz&gt;`</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -5214,7 +5214,7 @@ fn spec_test_346() {
     let expected = r##"<p><a href="https://foo.bar.%60baz">https://foo.bar.`baz</a>`</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -5224,7 +5224,7 @@ fn spec_test_347() {
     let expected = r##"<p>```foo``</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -5234,7 +5234,7 @@ fn spec_test_348() {
     let expected = r##"<p>`foo</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -5244,7 +5244,7 @@ fn spec_test_349() {
     let expected = r##"<p>`foo<code>bar</code></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -5254,7 +5254,7 @@ fn spec_test_350() {
     let expected = r##"<p><em>foo bar</em></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -5264,7 +5264,7 @@ fn spec_test_351() {
     let expected = r##"<p>a * foo bar*</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -5274,7 +5274,7 @@ fn spec_test_352() {
     let expected = r##"<p>a*"foo"*</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -5284,7 +5284,7 @@ fn spec_test_353() {
     let expected = r##"<p>* a *</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -5300,7 +5300,7 @@ fn spec_test_354() {
 <p>*€*charlie.</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -5310,7 +5310,7 @@ fn spec_test_355() {
     let expected = r##"<p>foo<em>bar</em></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -5320,7 +5320,7 @@ fn spec_test_356() {
     let expected = r##"<p>5<em>6</em>78</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -5330,7 +5330,7 @@ fn spec_test_357() {
     let expected = r##"<p><em>foo bar</em></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -5340,7 +5340,7 @@ fn spec_test_358() {
     let expected = r##"<p>_ foo bar_</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -5350,7 +5350,7 @@ fn spec_test_359() {
     let expected = r##"<p>a_"foo"_</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -5360,7 +5360,7 @@ fn spec_test_360() {
     let expected = r##"<p>foo_bar_</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -5370,7 +5370,7 @@ fn spec_test_361() {
     let expected = r##"<p>5_6_78</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -5380,7 +5380,7 @@ fn spec_test_362() {
     let expected = r##"<p>пристаням_стремятся_</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -5390,7 +5390,7 @@ fn spec_test_363() {
     let expected = r##"<p>aa_"bb"_cc</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -5400,7 +5400,7 @@ fn spec_test_364() {
     let expected = r##"<p>foo-<em>(bar)</em></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -5410,7 +5410,7 @@ fn spec_test_365() {
     let expected = r##"<p>_foo*</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -5420,7 +5420,7 @@ fn spec_test_366() {
     let expected = r##"<p>*foo bar *</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -5432,7 +5432,7 @@ fn spec_test_367() {
 *</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -5442,7 +5442,7 @@ fn spec_test_368() {
     let expected = r##"<p>*(*foo)</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -5452,7 +5452,7 @@ fn spec_test_369() {
     let expected = r##"<p><em>(<em>foo</em>)</em></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -5462,7 +5462,7 @@ fn spec_test_370() {
     let expected = r##"<p><em>foo</em>bar</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -5472,7 +5472,7 @@ fn spec_test_371() {
     let expected = r##"<p>_foo bar _</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -5482,7 +5482,7 @@ fn spec_test_372() {
     let expected = r##"<p>_(_foo)</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -5492,7 +5492,7 @@ fn spec_test_373() {
     let expected = r##"<p><em>(<em>foo</em>)</em></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -5502,7 +5502,7 @@ fn spec_test_374() {
     let expected = r##"<p>_foo_bar</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -5512,7 +5512,7 @@ fn spec_test_375() {
     let expected = r##"<p>_пристаням_стремятся</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -5522,7 +5522,7 @@ fn spec_test_376() {
     let expected = r##"<p><em>foo_bar_baz</em></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -5532,7 +5532,7 @@ fn spec_test_377() {
     let expected = r##"<p><em>(bar)</em>.</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -5542,7 +5542,7 @@ fn spec_test_378() {
     let expected = r##"<p><strong>foo bar</strong></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -5552,7 +5552,7 @@ fn spec_test_379() {
     let expected = r##"<p>** foo bar**</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -5562,7 +5562,7 @@ fn spec_test_380() {
     let expected = r##"<p>a**"foo"**</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -5572,7 +5572,7 @@ fn spec_test_381() {
     let expected = r##"<p>foo<strong>bar</strong></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -5582,7 +5582,7 @@ fn spec_test_382() {
     let expected = r##"<p><strong>foo bar</strong></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -5592,7 +5592,7 @@ fn spec_test_383() {
     let expected = r##"<p>__ foo bar__</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -5604,7 +5604,7 @@ foo bar__
 foo bar__</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -5614,7 +5614,7 @@ fn spec_test_385() {
     let expected = r##"<p>a__"foo"__</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -5624,7 +5624,7 @@ fn spec_test_386() {
     let expected = r##"<p>foo__bar__</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -5634,7 +5634,7 @@ fn spec_test_387() {
     let expected = r##"<p>5__6__78</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -5644,7 +5644,7 @@ fn spec_test_388() {
     let expected = r##"<p>пристаням__стремятся__</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -5654,7 +5654,7 @@ fn spec_test_389() {
     let expected = r##"<p><strong>foo, <strong>bar</strong>, baz</strong></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -5664,7 +5664,7 @@ fn spec_test_390() {
     let expected = r##"<p>foo-<strong>(bar)</strong></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -5674,7 +5674,7 @@ fn spec_test_391() {
     let expected = r##"<p>**foo bar **</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -5684,7 +5684,7 @@ fn spec_test_392() {
     let expected = r##"<p>**(**foo)</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -5694,7 +5694,7 @@ fn spec_test_393() {
     let expected = r##"<p><em>(<strong>foo</strong>)</em></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -5706,7 +5706,7 @@ fn spec_test_394() {
 <em>Asclepias physocarpa</em>)</strong></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -5716,7 +5716,7 @@ fn spec_test_395() {
     let expected = r##"<p><strong>foo "<em>bar</em>" foo</strong></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -5726,7 +5726,7 @@ fn spec_test_396() {
     let expected = r##"<p><strong>foo</strong>bar</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -5736,7 +5736,7 @@ fn spec_test_397() {
     let expected = r##"<p>__foo bar __</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -5746,7 +5746,7 @@ fn spec_test_398() {
     let expected = r##"<p>__(__foo)</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -5756,7 +5756,7 @@ fn spec_test_399() {
     let expected = r##"<p><em>(<strong>foo</strong>)</em></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -5766,7 +5766,7 @@ fn spec_test_400() {
     let expected = r##"<p>__foo__bar</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -5776,7 +5776,7 @@ fn spec_test_401() {
     let expected = r##"<p>__пристаням__стремятся</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -5786,7 +5786,7 @@ fn spec_test_402() {
     let expected = r##"<p><strong>foo__bar__baz</strong></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -5796,7 +5796,7 @@ fn spec_test_403() {
     let expected = r##"<p><strong>(bar)</strong>.</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -5806,7 +5806,7 @@ fn spec_test_404() {
     let expected = r##"<p><em>foo <a href="/url">bar</a></em></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -5818,7 +5818,7 @@ bar*
 bar</em></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -5828,7 +5828,7 @@ fn spec_test_406() {
     let expected = r##"<p><em>foo <strong>bar</strong> baz</em></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -5838,7 +5838,7 @@ fn spec_test_407() {
     let expected = r##"<p><em>foo <em>bar</em> baz</em></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -5848,7 +5848,7 @@ fn spec_test_408() {
     let expected = r##"<p><em><em>foo</em> bar</em></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -5858,7 +5858,7 @@ fn spec_test_409() {
     let expected = r##"<p><em>foo <em>bar</em></em></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -5868,7 +5868,7 @@ fn spec_test_410() {
     let expected = r##"<p><em>foo <strong>bar</strong> baz</em></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -5878,7 +5878,7 @@ fn spec_test_411() {
     let expected = r##"<p><em>foo<strong>bar</strong>baz</em></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -5888,7 +5888,7 @@ fn spec_test_412() {
     let expected = r##"<p><em>foo**bar</em></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -5898,7 +5898,7 @@ fn spec_test_413() {
     let expected = r##"<p><em><strong>foo</strong> bar</em></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -5908,7 +5908,7 @@ fn spec_test_414() {
     let expected = r##"<p><em>foo <strong>bar</strong></em></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -5918,7 +5918,7 @@ fn spec_test_415() {
     let expected = r##"<p><em>foo<strong>bar</strong></em></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -5928,7 +5928,7 @@ fn spec_test_416() {
     let expected = r##"<p>foo<em><strong>bar</strong></em>baz</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -5938,7 +5938,7 @@ fn spec_test_417() {
     let expected = r##"<p>foo<strong><strong><strong>bar</strong></strong></strong>***baz</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -5948,7 +5948,7 @@ fn spec_test_418() {
     let expected = r##"<p><em>foo <strong>bar <em>baz</em> bim</strong> bop</em></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -5958,7 +5958,7 @@ fn spec_test_419() {
     let expected = r##"<p><em>foo <a href="/url"><em>bar</em></a></em></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -5968,7 +5968,7 @@ fn spec_test_420() {
     let expected = r##"<p>** is not an empty emphasis</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -5978,7 +5978,7 @@ fn spec_test_421() {
     let expected = r##"<p>**** is not an empty strong emphasis</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -5988,7 +5988,7 @@ fn spec_test_422() {
     let expected = r##"<p><strong>foo <a href="/url">bar</a></strong></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -6000,7 +6000,7 @@ bar**
 bar</strong></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -6010,7 +6010,7 @@ fn spec_test_424() {
     let expected = r##"<p><strong>foo <em>bar</em> baz</strong></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -6020,7 +6020,7 @@ fn spec_test_425() {
     let expected = r##"<p><strong>foo <strong>bar</strong> baz</strong></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -6030,7 +6030,7 @@ fn spec_test_426() {
     let expected = r##"<p><strong><strong>foo</strong> bar</strong></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -6040,7 +6040,7 @@ fn spec_test_427() {
     let expected = r##"<p><strong>foo <strong>bar</strong></strong></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -6050,7 +6050,7 @@ fn spec_test_428() {
     let expected = r##"<p><strong>foo <em>bar</em> baz</strong></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -6060,7 +6060,7 @@ fn spec_test_429() {
     let expected = r##"<p><strong>foo<em>bar</em>baz</strong></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -6070,7 +6070,7 @@ fn spec_test_430() {
     let expected = r##"<p><strong><em>foo</em> bar</strong></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -6080,7 +6080,7 @@ fn spec_test_431() {
     let expected = r##"<p><strong>foo <em>bar</em></strong></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -6092,7 +6092,7 @@ bim* bop**
 bim</em> bop</strong></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -6102,7 +6102,7 @@ fn spec_test_433() {
     let expected = r##"<p><strong>foo <a href="/url"><em>bar</em></a></strong></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -6112,7 +6112,7 @@ fn spec_test_434() {
     let expected = r##"<p>__ is not an empty emphasis</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -6122,7 +6122,7 @@ fn spec_test_435() {
     let expected = r##"<p>____ is not an empty strong emphasis</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -6132,7 +6132,7 @@ fn spec_test_436() {
     let expected = r##"<p>foo ***</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -6142,7 +6142,7 @@ fn spec_test_437() {
     let expected = r##"<p>foo <em>*</em></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -6152,7 +6152,7 @@ fn spec_test_438() {
     let expected = r##"<p>foo <em>_</em></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -6162,7 +6162,7 @@ fn spec_test_439() {
     let expected = r##"<p>foo *****</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -6172,7 +6172,7 @@ fn spec_test_440() {
     let expected = r##"<p>foo <strong>*</strong></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -6182,7 +6182,7 @@ fn spec_test_441() {
     let expected = r##"<p>foo <strong>_</strong></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -6192,7 +6192,7 @@ fn spec_test_442() {
     let expected = r##"<p>*<em>foo</em></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -6202,7 +6202,7 @@ fn spec_test_443() {
     let expected = r##"<p><em>foo</em>*</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -6212,7 +6212,7 @@ fn spec_test_444() {
     let expected = r##"<p>*<strong>foo</strong></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -6222,7 +6222,7 @@ fn spec_test_445() {
     let expected = r##"<p>***<em>foo</em></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -6232,7 +6232,7 @@ fn spec_test_446() {
     let expected = r##"<p><strong>foo</strong>*</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -6242,7 +6242,7 @@ fn spec_test_447() {
     let expected = r##"<p><em>foo</em>***</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -6252,7 +6252,7 @@ fn spec_test_448() {
     let expected = r##"<p>foo ___</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -6262,7 +6262,7 @@ fn spec_test_449() {
     let expected = r##"<p>foo <em>_</em></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -6272,7 +6272,7 @@ fn spec_test_450() {
     let expected = r##"<p>foo <em>*</em></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -6282,7 +6282,7 @@ fn spec_test_451() {
     let expected = r##"<p>foo _____</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -6292,7 +6292,7 @@ fn spec_test_452() {
     let expected = r##"<p>foo <strong>_</strong></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -6302,7 +6302,7 @@ fn spec_test_453() {
     let expected = r##"<p>foo <strong>*</strong></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -6312,7 +6312,7 @@ fn spec_test_454() {
     let expected = r##"<p>_<em>foo</em></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -6322,7 +6322,7 @@ fn spec_test_455() {
     let expected = r##"<p><em>foo</em>_</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -6332,7 +6332,7 @@ fn spec_test_456() {
     let expected = r##"<p>_<strong>foo</strong></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -6342,7 +6342,7 @@ fn spec_test_457() {
     let expected = r##"<p>___<em>foo</em></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -6352,7 +6352,7 @@ fn spec_test_458() {
     let expected = r##"<p><strong>foo</strong>_</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -6362,7 +6362,7 @@ fn spec_test_459() {
     let expected = r##"<p><em>foo</em>___</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -6372,7 +6372,7 @@ fn spec_test_460() {
     let expected = r##"<p><strong>foo</strong></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -6382,7 +6382,7 @@ fn spec_test_461() {
     let expected = r##"<p><em><em>foo</em></em></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -6392,7 +6392,7 @@ fn spec_test_462() {
     let expected = r##"<p><strong>foo</strong></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -6402,7 +6402,7 @@ fn spec_test_463() {
     let expected = r##"<p><em><em>foo</em></em></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -6412,7 +6412,7 @@ fn spec_test_464() {
     let expected = r##"<p><strong><strong>foo</strong></strong></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -6422,7 +6422,7 @@ fn spec_test_465() {
     let expected = r##"<p><strong><strong>foo</strong></strong></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -6432,7 +6432,7 @@ fn spec_test_466() {
     let expected = r##"<p><strong><strong><strong>foo</strong></strong></strong></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -6442,7 +6442,7 @@ fn spec_test_467() {
     let expected = r##"<p><em><strong>foo</strong></em></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -6452,7 +6452,7 @@ fn spec_test_468() {
     let expected = r##"<p><em><strong><strong>foo</strong></strong></em></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -6462,7 +6462,7 @@ fn spec_test_469() {
     let expected = r##"<p><em>foo _bar</em> baz_</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -6472,7 +6472,7 @@ fn spec_test_470() {
     let expected = r##"<p><em>foo <strong>bar *baz bim</strong> bam</em></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -6482,7 +6482,7 @@ fn spec_test_471() {
     let expected = r##"<p>**foo <strong>bar baz</strong></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -6492,7 +6492,7 @@ fn spec_test_472() {
     let expected = r##"<p>*foo <em>bar baz</em></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -6502,7 +6502,7 @@ fn spec_test_473() {
     let expected = r##"<p>*<a href="/url">bar*</a></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -6512,7 +6512,7 @@ fn spec_test_474() {
     let expected = r##"<p>_foo <a href="/url">bar_</a></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -6522,7 +6522,7 @@ fn spec_test_475() {
     let expected = r##"<p>*<img src="foo" title="*"/></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -6532,7 +6532,7 @@ fn spec_test_476() {
     let expected = r##"<p>**<a href="**"></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -6542,7 +6542,7 @@ fn spec_test_477() {
     let expected = r##"<p>__<a href="__"></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -6552,7 +6552,7 @@ fn spec_test_478() {
     let expected = r##"<p><em>a <code>*</code></em></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -6562,7 +6562,7 @@ fn spec_test_479() {
     let expected = r##"<p><em>a <code>_</code></em></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -6572,7 +6572,7 @@ fn spec_test_480() {
     let expected = r##"<p>**a<a href="https://foo.bar/?q=**">https://foo.bar/?q=**</a></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -6582,7 +6582,7 @@ fn spec_test_481() {
     let expected = r##"<p>__a<a href="https://foo.bar/?q=__">https://foo.bar/?q=__</a></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -6592,7 +6592,7 @@ fn spec_test_482() {
     let expected = r##"<p><a href="/uri" title="title">link</a></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -6602,7 +6602,7 @@ fn spec_test_483() {
     let expected = r##"<p><a href="/uri">link</a></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -6612,7 +6612,7 @@ fn spec_test_484() {
     let expected = r##"<p><a href="./target.md"></a></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -6622,7 +6622,7 @@ fn spec_test_485() {
     let expected = r##"<p><a href="">link</a></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -6632,7 +6632,7 @@ fn spec_test_486() {
     let expected = r##"<p><a href="">link</a></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -6642,7 +6642,7 @@ fn spec_test_487() {
     let expected = r##"<p><a href=""></a></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -6652,7 +6652,7 @@ fn spec_test_488() {
     let expected = r##"<p>[link](/my uri)</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -6662,7 +6662,7 @@ fn spec_test_489() {
     let expected = r##"<p><a href="/my%20uri">link</a></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -6674,7 +6674,7 @@ bar)
 bar)</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -6686,7 +6686,7 @@ bar>)
 bar>)</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -6696,7 +6696,7 @@ fn spec_test_492() {
     let expected = r##"<p><a href="b)c">a</a></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -6706,7 +6706,7 @@ fn spec_test_493() {
     let expected = r##"<p>[link](&lt;foo&gt;)</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -6720,7 +6720,7 @@ fn spec_test_494() {
 [a](<b>c)</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -6730,7 +6730,7 @@ fn spec_test_495() {
     let expected = r##"<p><a href="(foo)">link</a></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -6740,7 +6740,7 @@ fn spec_test_496() {
     let expected = r##"<p><a href="foo(and(bar))">link</a></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -6750,7 +6750,7 @@ fn spec_test_497() {
     let expected = r##"<p>[link](foo(and(bar))</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -6760,7 +6760,7 @@ fn spec_test_498() {
     let expected = r##"<p><a href="foo(and(bar)">link</a></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -6770,7 +6770,7 @@ fn spec_test_499() {
     let expected = r##"<p><a href="foo(and(bar)">link</a></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -6780,7 +6780,7 @@ fn spec_test_500() {
     let expected = r##"<p><a href="foo):">link</a></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -6796,7 +6796,7 @@ fn spec_test_501() {
 <p><a href="https://example.com?foo=3#frag">link</a></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -6806,7 +6806,7 @@ fn spec_test_502() {
     let expected = r##"<p><a href="foo%5Cbar">link</a></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -6816,7 +6816,7 @@ fn spec_test_503() {
     let expected = r##"<p><a href="foo%20b%C3%A4">link</a></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -6826,7 +6826,7 @@ fn spec_test_504() {
     let expected = r##"<p><a href="%22title%22">link</a></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -6840,7 +6840,7 @@ fn spec_test_505() {
 <a href="/url" title="title">link</a></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -6850,7 +6850,7 @@ fn spec_test_506() {
     let expected = r##"<p><a href="/url" title="title &quot;&quot;">link</a></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -6860,7 +6860,7 @@ fn spec_test_507() {
     let expected = r##"<p><a href="/url%C2%A0%22title%22">link</a></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -6870,7 +6870,7 @@ fn spec_test_508() {
     let expected = r##"<p>[link](/url "title "and" title")</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -6880,7 +6880,7 @@ fn spec_test_509() {
     let expected = r##"<p><a href="/url" title="title &quot;and&quot; title">link</a></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -6891,7 +6891,7 @@ fn spec_test_510() {
     let expected = r##"<p><a href="/uri" title="title">link</a></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -6901,7 +6901,7 @@ fn spec_test_511() {
     let expected = r##"<p>[link] (/uri)</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -6911,7 +6911,7 @@ fn spec_test_512() {
     let expected = r##"<p><a href="/uri">link [foo [bar]]</a></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -6921,7 +6921,7 @@ fn spec_test_513() {
     let expected = r##"<p>[link] bar](/uri)</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -6931,7 +6931,7 @@ fn spec_test_514() {
     let expected = r##"<p>[link <a href="/uri">bar</a></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -6941,7 +6941,7 @@ fn spec_test_515() {
     let expected = r##"<p><a href="/uri">link [bar</a></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -6951,7 +6951,7 @@ fn spec_test_516() {
     let expected = r##"<p><a href="/uri">link <em>foo <strong>bar</strong> <code>#</code></em></a></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -6961,7 +6961,7 @@ fn spec_test_517() {
     let expected = r##"<p><a href="/uri"><img src="moon.jpg" alt="moon" /></a></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -6971,7 +6971,7 @@ fn spec_test_518() {
     let expected = r##"<p>[foo <a href="/uri">bar</a>](/uri)</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -6981,7 +6981,7 @@ fn spec_test_519() {
     let expected = r##"<p>[foo <em>[bar <a href="/uri">baz</a>](/uri)</em>](/uri)</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -6991,7 +6991,7 @@ fn spec_test_520() {
     let expected = r##"<p><img src="uri3" alt="[foo](uri2)" /></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -7001,7 +7001,7 @@ fn spec_test_521() {
     let expected = r##"<p>*<a href="/uri">foo*</a></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -7011,7 +7011,7 @@ fn spec_test_522() {
     let expected = r##"<p><a href="baz*">foo *bar</a></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -7021,7 +7021,7 @@ fn spec_test_523() {
     let expected = r##"<p><em>foo [bar</em> baz]</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -7031,7 +7031,7 @@ fn spec_test_524() {
     let expected = r##"<p>[foo <bar attr="](baz)"></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -7041,7 +7041,7 @@ fn spec_test_525() {
     let expected = r##"<p>[foo<code>](/uri)</code></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -7051,7 +7051,7 @@ fn spec_test_526() {
     let expected = r##"<p>[foo<a href="https://example.com/?search=%5D(uri)">https://example.com/?search=](uri)</a></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -7063,7 +7063,7 @@ fn spec_test_527() {
     let expected = r##"<p><a href="/url" title="title">foo</a></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -7075,7 +7075,7 @@ fn spec_test_528() {
     let expected = r##"<p><a href="/uri">link [foo [bar]]</a></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -7087,7 +7087,7 @@ fn spec_test_529() {
     let expected = r##"<p><a href="/uri">link [bar</a></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -7099,7 +7099,7 @@ fn spec_test_530() {
     let expected = r##"<p><a href="/uri">link <em>foo <strong>bar</strong> <code>#</code></em></a></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -7111,7 +7111,7 @@ fn spec_test_531() {
     let expected = r##"<p><a href="/uri"><img src="moon.jpg" alt="moon" /></a></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -7123,7 +7123,7 @@ fn spec_test_532() {
     let expected = r##"<p>[foo <a href="/uri">bar</a>]<a href="/uri">ref</a></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -7135,7 +7135,7 @@ fn spec_test_533() {
     let expected = r##"<p>[foo <em>bar <a href="/uri">baz</a></em>]<a href="/uri">ref</a></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -7147,7 +7147,7 @@ fn spec_test_534() {
     let expected = r##"<p>*<a href="/uri">foo*</a></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -7159,7 +7159,7 @@ fn spec_test_535() {
     let expected = r##"<p><a href="/uri">foo *bar</a>*</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -7171,7 +7171,7 @@ fn spec_test_536() {
     let expected = r##"<p>[foo <bar attr="][ref]"></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -7183,7 +7183,7 @@ fn spec_test_537() {
     let expected = r##"<p>[foo<code>][ref]</code></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -7195,7 +7195,7 @@ fn spec_test_538() {
     let expected = r##"<p>[foo<a href="https://example.com/?search=%5D%5Bref%5D">https://example.com/?search=][ref]</a></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -7207,7 +7207,7 @@ fn spec_test_539() {
     let expected = r##"<p><a href="/url" title="title">foo</a></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -7219,7 +7219,7 @@ fn spec_test_540() {
     let expected = r##"<p><a href="/url">ẞ</a></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -7232,7 +7232,7 @@ fn spec_test_541() {
     let expected = r##"<p><a href="/url">Baz</a></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -7244,7 +7244,7 @@ fn spec_test_542() {
     let expected = r##"<p>[foo] <a href="/url" title="title">bar</a></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -7258,7 +7258,7 @@ fn spec_test_543() {
 <a href="/url" title="title">bar</a></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -7272,7 +7272,7 @@ fn spec_test_544() {
     let expected = r##"<p><a href="/url1">bar</a></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -7284,7 +7284,7 @@ fn spec_test_545() {
     let expected = r##"<p>[bar][foo!]</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -7297,7 +7297,7 @@ fn spec_test_546() {
 <p>[ref[]: /uri</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -7310,7 +7310,7 @@ fn spec_test_547() {
 <p>[ref[bar]]: /uri</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -7323,7 +7323,7 @@ fn spec_test_548() {
 <p>[[[foo]]]: /url</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -7335,7 +7335,7 @@ fn spec_test_549() {
     let expected = r##"<p><a href="/uri">foo</a></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -7347,7 +7347,7 @@ fn spec_test_550() {
     let expected = r##"<p><a href="/uri">bar\</a></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -7360,7 +7360,7 @@ fn spec_test_551() {
 <p>[]: /uri</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -7377,7 +7377,7 @@ fn spec_test_552() {
 ]: /uri</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -7389,7 +7389,7 @@ fn spec_test_553() {
     let expected = r##"<p><a href="/url" title="title">foo</a></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -7401,7 +7401,7 @@ fn spec_test_554() {
     let expected = r##"<p><a href="/url" title="title"><em>foo</em> bar</a></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -7413,7 +7413,7 @@ fn spec_test_555() {
     let expected = r##"<p><a href="/url" title="title">Foo</a></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -7427,7 +7427,7 @@ fn spec_test_556() {
 []</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -7439,7 +7439,7 @@ fn spec_test_557() {
     let expected = r##"<p><a href="/url" title="title">foo</a></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -7451,7 +7451,7 @@ fn spec_test_558() {
     let expected = r##"<p><a href="/url" title="title"><em>foo</em> bar</a></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -7463,7 +7463,7 @@ fn spec_test_559() {
     let expected = r##"<p>[<a href="/url" title="title"><em>foo</em> bar</a>]</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -7475,7 +7475,7 @@ fn spec_test_560() {
     let expected = r##"<p>[[bar <a href="/url">foo</a></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -7487,7 +7487,7 @@ fn spec_test_561() {
     let expected = r##"<p><a href="/url" title="title">Foo</a></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -7499,7 +7499,7 @@ fn spec_test_562() {
     let expected = r##"<p><a href="/url">foo</a> bar</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -7511,7 +7511,7 @@ fn spec_test_563() {
     let expected = r##"<p>[foo]</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -7523,7 +7523,7 @@ fn spec_test_564() {
     let expected = r##"<p>*<a href="/url">foo*</a></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -7536,7 +7536,7 @@ fn spec_test_565() {
     let expected = r##"<p><a href="/url2">foo</a></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -7548,7 +7548,7 @@ fn spec_test_566() {
     let expected = r##"<p><a href="/url1">foo</a></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -7560,7 +7560,7 @@ fn spec_test_567() {
     let expected = r##"<p><a href="">foo</a></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -7572,7 +7572,7 @@ fn spec_test_568() {
     let expected = r##"<p><a href="/url1">foo</a>(not a link)</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -7584,7 +7584,7 @@ fn spec_test_569() {
     let expected = r##"<p>[foo]<a href="/url">bar</a></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -7597,7 +7597,7 @@ fn spec_test_570() {
     let expected = r##"<p><a href="/url2">foo</a><a href="/url1">baz</a></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -7610,7 +7610,7 @@ fn spec_test_571() {
     let expected = r##"<p>[foo]<a href="/url1">bar</a></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -7620,7 +7620,7 @@ fn spec_test_572() {
     let expected = r##"<p><img src="/url" alt="foo" title="title" /></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -7632,7 +7632,7 @@ fn spec_test_573() {
     let expected = r##"<p><img src="train.jpg" alt="foo bar" title="train &amp; tracks" /></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -7642,7 +7642,7 @@ fn spec_test_574() {
     let expected = r##"<p><img src="/url2" alt="foo bar" /></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -7652,7 +7652,7 @@ fn spec_test_575() {
     let expected = r##"<p><img src="/url2" alt="foo bar" /></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -7664,7 +7664,7 @@ fn spec_test_576() {
     let expected = r##"<p><img src="train.jpg" alt="foo bar" title="train &amp; tracks" /></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -7676,7 +7676,7 @@ fn spec_test_577() {
     let expected = r##"<p><img src="train.jpg" alt="foo bar" title="train &amp; tracks" /></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -7686,7 +7686,7 @@ fn spec_test_578() {
     let expected = r##"<p><img src="train.jpg" alt="foo" /></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -7696,7 +7696,7 @@ fn spec_test_579() {
     let expected = r##"<p>My <img src="/path/to/train.jpg" alt="foo bar" title="title" /></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -7706,7 +7706,7 @@ fn spec_test_580() {
     let expected = r##"<p><img src="url" alt="foo" /></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -7716,7 +7716,7 @@ fn spec_test_581() {
     let expected = r##"<p><img src="/url" alt="" /></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -7728,7 +7728,7 @@ fn spec_test_582() {
     let expected = r##"<p><img src="/url" alt="foo" /></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -7740,7 +7740,7 @@ fn spec_test_583() {
     let expected = r##"<p><img src="/url" alt="foo" /></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -7752,7 +7752,7 @@ fn spec_test_584() {
     let expected = r##"<p><img src="/url" alt="foo" title="title" /></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -7764,7 +7764,7 @@ fn spec_test_585() {
     let expected = r##"<p><img src="/url" alt="foo bar" title="title" /></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -7776,7 +7776,7 @@ fn spec_test_586() {
     let expected = r##"<p><img src="/url" alt="Foo" title="title" /></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -7790,7 +7790,7 @@ fn spec_test_587() {
 []</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -7802,7 +7802,7 @@ fn spec_test_588() {
     let expected = r##"<p><img src="/url" alt="foo" title="title" /></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -7814,7 +7814,7 @@ fn spec_test_589() {
     let expected = r##"<p><img src="/url" alt="foo bar" title="title" /></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -7827,7 +7827,7 @@ fn spec_test_590() {
 <p>[[foo]]: /url "title"</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -7839,7 +7839,7 @@ fn spec_test_591() {
     let expected = r##"<p><img src="/url" alt="Foo" title="title" /></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -7851,7 +7851,7 @@ fn spec_test_592() {
     let expected = r##"<p>![foo]</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -7863,7 +7863,7 @@ fn spec_test_593() {
     let expected = r##"<p>!<a href="/url" title="title">foo</a></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -7873,7 +7873,7 @@ fn spec_test_594() {
     let expected = r##"<p><a href="http://foo.bar.baz">http://foo.bar.baz</a></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -7883,7 +7883,7 @@ fn spec_test_595() {
     let expected = r##"<p><a href="https://foo.bar.baz/test?q=hello&amp;id=22&amp;boolean">https://foo.bar.baz/test?q=hello&amp;id=22&amp;boolean</a></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -7893,7 +7893,7 @@ fn spec_test_596() {
     let expected = r##"<p><a href="irc://foo.bar:2233/baz">irc://foo.bar:2233/baz</a></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -7903,7 +7903,7 @@ fn spec_test_597() {
     let expected = r##"<p><a href="MAILTO:FOO@BAR.BAZ">MAILTO:FOO@BAR.BAZ</a></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -7913,7 +7913,7 @@ fn spec_test_598() {
     let expected = r##"<p><a href="a+b+c:d">a+b+c:d</a></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -7923,7 +7923,7 @@ fn spec_test_599() {
     let expected = r##"<p><a href="made-up-scheme://foo,bar">made-up-scheme://foo,bar</a></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -7933,7 +7933,7 @@ fn spec_test_600() {
     let expected = r##"<p><a href="https://../">https://../</a></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -7943,7 +7943,7 @@ fn spec_test_601() {
     let expected = r##"<p><a href="localhost:5001/foo">localhost:5001/foo</a></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -7953,7 +7953,7 @@ fn spec_test_602() {
     let expected = r##"<p>&lt;https://foo.bar/baz bim&gt;</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -7963,7 +7963,7 @@ fn spec_test_603() {
     let expected = r##"<p><a href="https://example.com/%5C%5B%5C">https://example.com/\[\</a></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -7973,7 +7973,7 @@ fn spec_test_604() {
     let expected = r##"<p><a href="mailto:foo@bar.example.com">foo@bar.example.com</a></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -7983,7 +7983,7 @@ fn spec_test_605() {
     let expected = r##"<p><a href="mailto:foo+special@Bar.baz-bar0.com">foo+special@Bar.baz-bar0.com</a></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -7993,7 +7993,7 @@ fn spec_test_606() {
     let expected = r##"<p>&lt;foo+@bar.example.com&gt;</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -8003,7 +8003,7 @@ fn spec_test_607() {
     let expected = r##"<p>&lt;&gt;</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -8013,7 +8013,7 @@ fn spec_test_608() {
     let expected = r##"<p>&lt; https://foo.bar &gt;</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -8023,7 +8023,7 @@ fn spec_test_609() {
     let expected = r##"<p>&lt;m:abc&gt;</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -8033,7 +8033,7 @@ fn spec_test_610() {
     let expected = r##"<p>&lt;foo.bar.baz&gt;</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -8043,7 +8043,7 @@ fn spec_test_611() {
     let expected = r##"<p>https://example.com</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -8053,7 +8053,7 @@ fn spec_test_612() {
     let expected = r##"<p>foo@bar.example.com</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -8063,7 +8063,7 @@ fn spec_test_613() {
     let expected = r##"<p><a><bab><c2c></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -8073,7 +8073,7 @@ fn spec_test_614() {
     let expected = r##"<p><a/><b2/></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -8085,7 +8085,7 @@ data="foo" >
 data="foo" ></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -8097,7 +8097,7 @@ _boolean zoop:33=zoop:33 />
 _boolean zoop:33=zoop:33 /></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -8107,7 +8107,7 @@ fn spec_test_617() {
     let expected = r##"<p>Foo <responsive-image src="foo.jpg" /></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -8117,7 +8117,7 @@ fn spec_test_618() {
     let expected = r##"<p>&lt;33&gt; &lt;__&gt;</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -8127,7 +8127,7 @@ fn spec_test_619() {
     let expected = r##"<p>&lt;a h*#ref="hi"&gt;</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -8137,7 +8137,7 @@ fn spec_test_620() {
     let expected = r##"<p>&lt;a href="hi'&gt; &lt;a href=hi'&gt;</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -8153,7 +8153,7 @@ foo&gt;&lt;bar/ &gt;
 bim!bop /&gt;</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -8163,7 +8163,7 @@ fn spec_test_622() {
     let expected = r##"<p>&lt;a href='bar'title=title&gt;</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -8173,7 +8173,7 @@ fn spec_test_623() {
     let expected = r##"<p></a></foo ></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -8183,7 +8183,7 @@ fn spec_test_624() {
     let expected = r##"<p>&lt;/a href="foo"&gt;</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -8195,7 +8195,7 @@ comment - with hyphens -->
 comment - with hyphens --></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -8208,7 +8208,7 @@ foo <!---> foo -->
 <p>foo <!---> foo --&gt;</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -8218,7 +8218,7 @@ fn spec_test_627() {
     let expected = r##"<p>foo <?php echo $a; ?></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -8228,7 +8228,7 @@ fn spec_test_628() {
     let expected = r##"<p>foo <!ELEMENT br EMPTY></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -8238,7 +8238,7 @@ fn spec_test_629() {
     let expected = r##"<p>foo <![CDATA[>&<]]></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -8248,7 +8248,7 @@ fn spec_test_630() {
     let expected = r##"<p>foo <a href="&ouml;"></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -8258,7 +8258,7 @@ fn spec_test_631() {
     let expected = r##"<p>foo <a href="\*"></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -8268,7 +8268,7 @@ fn spec_test_632() {
     let expected = r##"<p>&lt;a href="""&gt;</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -8280,7 +8280,7 @@ baz
 baz</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -8292,7 +8292,7 @@ baz
 baz</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -8304,7 +8304,7 @@ baz
 baz</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -8316,7 +8316,7 @@ fn spec_test_636() {
 bar</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -8328,7 +8328,7 @@ fn spec_test_637() {
 bar</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -8340,7 +8340,7 @@ bar*
 bar</em></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -8352,7 +8352,7 @@ bar*
 bar</em></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -8363,7 +8363,7 @@ span`
     let expected = r##"<p><code>code   span</code></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -8374,7 +8374,7 @@ span`
     let expected = r##"<p><code>code\ span</code></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -8386,7 +8386,7 @@ bar">
 bar"></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -8398,7 +8398,7 @@ bar">
 bar"></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -8408,7 +8408,7 @@ fn spec_test_644() {
     let expected = r##"<p>foo\</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -8418,7 +8418,7 @@ fn spec_test_645() {
     let expected = r##"<p>foo</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -8428,7 +8428,7 @@ fn spec_test_646() {
     let expected = r##"<h3>foo\</h3>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -8438,7 +8438,7 @@ fn spec_test_647() {
     let expected = r##"<h3>foo</h3>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -8450,7 +8450,7 @@ baz
 baz</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -8462,7 +8462,7 @@ fn spec_test_649() {
 baz</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -8472,7 +8472,7 @@ fn spec_test_650() {
     let expected = r##"<p>hello $.;'there</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -8482,7 +8482,7 @@ fn spec_test_651() {
     let expected = r##"<p>Foo χρῆν</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -8492,5 +8492,5 @@ fn spec_test_652() {
     let expected = r##"<p>Multiple     spaces</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
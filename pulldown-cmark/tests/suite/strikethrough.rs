@@ -10,7 +10,7 @@ fn strikethrough_test_1() {
     let expected = r##"<p><del>This is <em>stricken out</em></del></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -20,7 +20,7 @@ fn strikethrough_test_2() {
     let expected = r##"<p><del>This is ~~stricken</del></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -30,7 +30,7 @@ fn strikethrough_test_3() {
     let expected = r##"<p>This<del>is</del>stricken</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -40,7 +40,7 @@ fn strikethrough_test_4() {
     let expected = r##"<p><del>This</del>is<del>stricken</del></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -50,7 +50,7 @@ fn strikethrough_test_5() {
     let expected = r##"<p>Here I strike out an exclamation point<del>!</del>.</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -60,7 +60,7 @@ fn strikethrough_test_6() {
     let expected = r##"<p><del>This is stricken out</del></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -70,7 +70,7 @@ fn strikethrough_test_7() {
     let expected = r##"<p><del>This is ~stricken</del></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -80,7 +80,7 @@ fn strikethrough_test_8() {
     let expected = r##"<p>This~is~nothing</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -90,7 +90,7 @@ fn strikethrough_test_9() {
     let expected = r##"<p><del>This~is~nothing</del></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -100,7 +100,7 @@ fn strikethrough_test_10() {
     let expected = r##"<p>Here I fail to strike out an exclamation point~!~.</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -110,7 +110,7 @@ fn strikethrough_test_11() {
     let expected = r##"<p>Here I fail to strike out a tilde ~~~.</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -120,7 +120,7 @@ fn strikethrough_test_12() {
     let expected = r##"<p>Here I fail to match up ~~tildes~.</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -130,7 +130,7 @@ fn strikethrough_test_13() {
     let expected = r##"<p>Here I fail to match up ~tildes~~.</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -140,7 +140,7 @@ fn strikethrough_test_14() {
     let expected = r##"<p><del>This ~is stricken.</del></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
 }
 
 #[test]
@@ -150,5 +150,15 @@ fn strikethrough_test_15() {
     let expected = r##"<p><del>This ~~is stricken.</del></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, false, false, false);
+    test_markdown_html(original, expected, "");
+}
+
+#[test]
+fn strikethrough_test_16() {
+    let original = r##"~This ~~is stricken~ but this is not~~
+"##;
+    let expected = r##"<p><del>This ~~is stricken</del> but this is not~~</p>
+"##;
+
+    test_markdown_html(original, expected, "");
 }
@@ -10,7 +10,7 @@ fn super_sub_test_1() {
     let expected = r##"<p><sup>This is super</sup> <sub>This is sub</sub></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, true, false, false, false);
+    test_markdown_html(original, expected, "super_sub");
 }
 
 #[test]
@@ -20,7 +20,7 @@ fn super_sub_test_2() {
     let expected = r##"<p><sub>This is stricken out</sub></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, true, false, false, false);
+    test_markdown_html(original, expected, "super_sub");
 }
 
 #[test]
@@ -30,7 +30,7 @@ fn super_sub_test_3() {
     let expected = r##"<p><sub>This is ~stricken</sub></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, true, false, false, false);
+    test_markdown_html(original, expected, "super_sub");
 }
 
 #[test]
@@ -40,7 +40,7 @@ fn super_sub_test_4() {
     let expected = r##"<p><sub>This</sub>is<sub>nothing</sub></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, true, false, false, false);
+    test_markdown_html(original, expected, "super_sub");
 }
 
 #[test]
@@ -50,7 +50,7 @@ fn super_sub_test_5() {
     let expected = r##"<p><sub>This ~~is not stricken.</sub></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, true, false, false, false);
+    test_markdown_html(original, expected, "super_sub");
 }
 
 #[test]
@@ -60,7 +60,7 @@ fn super_sub_test_6() {
     let expected = r##"<p><del>This ~is</del> stricken.~</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, true, false, false, false);
+    test_markdown_html(original, expected, "super_sub");
 }
 
 #[test]
@@ -70,7 +70,7 @@ fn super_sub_test_7() {
     let expected = r##"<p><sub>This ~~is stricken</sub> but this is not~~</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, true, false, false, false);
+    test_markdown_html(original, expected, "super_sub");
 }
 
 #[test]
@@ -83,7 +83,7 @@ y=x^2^a+xb+c
 <p>y=x<sup>2</sup>a+xb+c</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, true, false, false, false);
+    test_markdown_html(original, expected, "super_sub");
 }
 
 #[test]
@@ -96,7 +96,7 @@ fn super_sub_test_9() {
 <p>^bar^^</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, true, false, false, false);
+    test_markdown_html(original, expected, "super_sub");
 }
 
 #[test]
@@ -109,5 +109,5 @@ fn super_sub_test_10() {
 <p><em>foo_</em>_bar*</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, true, false, false, false);
+    test_markdown_html(original, expected, "super_sub");
 }
@@ -10,7 +10,7 @@ fn wikilinks_test_1() {
     let expected = r##"<p>This is a <a href="WikiLink">WikiLink</a>.</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, true, false, false);
+    test_markdown_html(original, expected, "wikilinks");
 }
 
 #[test]
@@ -20,7 +20,7 @@ fn wikilinks_test_2() {
     let expected = r##"<p>This is a <a href="Main/WikiLink">Main/WikiLink</a>.</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, true, false, false);
+    test_markdown_html(original, expected, "wikilinks");
 }
 
 #[test]
@@ -32,7 +32,7 @@ fn wikilinks_test_3() {
     let expected = r##"<p>This is <a href="Ambiguous">Ambiguous</a>.</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, true, false, false);
+    test_markdown_html(original, expected, "wikilinks");
 }
 
 #[test]
@@ -49,7 +49,7 @@ fn wikilinks_test_4() {
 </p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, true, false, false);
+    test_markdown_html(original, expected, "wikilinks");
 }
 
 #[test]
@@ -59,7 +59,7 @@ fn wikilinks_test_5() {
     let expected = r##"<p>This is [also <a href="Ambiguous">Ambiguous</a>](https://example.com/).</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, true, false, false);
+    test_markdown_html(original, expected, "wikilinks");
 }
 
 #[test]
@@ -72,7 +72,7 @@ fn wikilinks_test_6() {
 <p><a href="https://example.org/">https://example.org/</a></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, true, false, false);
+    test_markdown_html(original, expected, "wikilinks");
 }
 
 #[test]
@@ -82,7 +82,7 @@ fn wikilinks_test_7() {
     let expected = r##"<p>This is <a href="WikiLink">a pothole</a>.</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, true, false, false);
+    test_markdown_html(original, expected, "wikilinks");
 }
 
 #[test]
@@ -92,7 +92,7 @@ fn wikilinks_test_8() {
     let expected = r##"<p>This is a <a href="WikiLink/In/A/Directory">WikiLink</a>.</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, true, false, false);
+    test_markdown_html(original, expected, "wikilinks");
 }
 
 #[test]
@@ -102,7 +102,7 @@ fn wikilinks_test_9() {
     let expected = r##"<p>This is <a href="WikiLink">a <strong>strong</strong> pothole</a>.</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, true, false, false);
+    test_markdown_html(original, expected, "wikilinks");
 }
 
 #[test]
@@ -117,7 +117,7 @@ fn wikilinks_test_10() {
 </p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, true, false, false);
+    test_markdown_html(original, expected, "wikilinks");
 }
 
 #[test]
@@ -127,7 +127,7 @@ fn wikilinks_test_11() {
     let expected = r##"<p>[[WikiLink|<a href="Fish">Fish</a>]]</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, true, false, false);
+    test_markdown_html(original, expected, "wikilinks");
 }
 
 #[test]
@@ -137,7 +137,7 @@ fn wikilinks_test_12() {
     let expected = r##"<p>[[WikiLink|<a href="cat.html">cat</a>]]</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, true, false, false);
+    test_markdown_html(original, expected, "wikilinks");
 }
 
 #[test]
@@ -152,7 +152,7 @@ fn wikilinks_test_13() {
 </p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, true, false, false);
+    test_markdown_html(original, expected, "wikilinks");
 }
 
 #[test]
@@ -162,7 +162,7 @@ fn wikilinks_test_14() {
     let expected = r##"<p><img src="dog.png" alt="a cute dog" /></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, true, false, false);
+    test_markdown_html(original, expected, "wikilinks");
 }
 
 #[test]
@@ -172,7 +172,7 @@ fn wikilinks_test_15() {
     let expected = r##"<p>]] [[]] [[|]] [[|Symbol]] [[</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, true, false, false);
+    test_markdown_html(original, expected, "wikilinks");
 }
 
 #[test]
@@ -182,7 +182,7 @@ fn wikilinks_test_16() {
     let expected = r##"<p><a href="%5B%5Burl%5D%5D">inline link</a></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, true, false, false);
+    test_markdown_html(original, expected, "wikilinks");
 }
 
 #[test]
@@ -192,7 +192,7 @@ fn wikilinks_test_17() {
     let expected = r##"<p><a href="%5B%5Burl">inline link</a>]]</p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, true, false, false);
+    test_markdown_html(original, expected, "wikilinks");
 }
 
 #[test]
@@ -202,7 +202,7 @@ fn wikilinks_test_18() {
     let expected = r##"<p><code>[[code]]</code></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, true, false, false);
+    test_markdown_html(original, expected, "wikilinks");
 }
 
 #[test]
@@ -212,7 +212,7 @@ fn wikilinks_test_19() {
     let expected = r##"<p>emphasis **cross <a href="over**%20here">over** here</a></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, true, false, false);
+    test_markdown_html(original, expected, "wikilinks");
 }
 
 #[test]
@@ -222,7 +222,7 @@ fn wikilinks_test_20() {
     let expected = r##"<p><a href="first%5C">second</a></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, true, false, false);
+    test_markdown_html(original, expected, "wikilinks");
 }
 
 #[test]
@@ -232,5 +232,5 @@ fn wikilinks_test_21() {
     let expected = r##"<p><a href="first&amp;#33;second">first&amp;#33;second</a></p>
 "##;
 
-    test_markdown_html(original, expected, false, false, false, false, true, false, false);
+    test_markdown_html(original, expected, "wikilinks");
 }
@@ -26,12 +26,121 @@
 import json
 import sys
 
-def main(args):
-    with open(args[1]) as json_file:
-        jsondata = json.load(json_file)
-    entities = [entity[1:-1] for entity in jsondata.keys() if entity.endswith(';')]
-    entities.sort()
-    print(f"""// Copyright 2015 Google Inc. All rights reserved.
+# Entities kept with the `minimal-entities` feature: the XML entities and the
+# most common typographic ones.
+MINIMAL = """
+AMP GT LT QUOT amp apos gt lt quot
+nbsp ensp emsp thinsp shy zwj zwnj
+copy reg trade hellip mdash ndash lsquo rsquo sbquo ldquo rdquo bdquo
+laquo raquo lsaquo rsaquo middot bull dagger Dagger permil prime Prime
+times divide minus plusmn deg micro para sect cent pound yen euro curren
+larr rarr uarr darr harr le ge ne asymp infin
+""".split()
+
+# Entities per bucket of the perfect hash, on average.
+LAMBDA = 5
+
+MASK32 = 2 ** 32 - 1
+
+
+def fnv1a(name, key):
+    h = 0xcbf29ce484222325 ^ key
+    for b in name.encode('ascii'):
+        h = ((h ^ b) * 0x100000001b3) % 2 ** 64
+    return h
+
+
+def perfect_hash(entities):
+    """Hash and displace: returns the hash key, the displacements of each
+    bucket and the entities in slot order."""
+    for key in range(2 ** 16):
+        result = try_perfect_hash(entities, key)
+        if result:
+            return (key,) + result
+    assert False
+
+
+def try_perfect_hash(entities, key):
+    n = len(entities)
+    buckets = [[] for _ in range((n + LAMBDA - 1) // LAMBDA)]
+    for e in entities:
+        h = fnv1a(e, key)
+        buckets[(h >> 32) % len(buckets)].append((h & MASK32, h >> 32, e))
+    displacements = [(0, 0)] * len(buckets)
+    slots = [None] * n
+    for ix in sorted(range(len(buckets)), key=lambda ix: -len(buckets[ix])):
+        bucket = buckets[ix]
+        if not bucket:
+            continue
+        found = False
+        for d1 in range(n):
+            for d2 in range(n):
+                placed = [((d2 + f1 * d1 + f2) & MASK32) % n for f1, f2, _ in bucket]
+                if len(set(placed)) == len(placed) and all(slots[s] is None for s in placed):
+                    for s, (_, _, e) in zip(placed, bucket):
+                        slots[s] = e
+                    displacements[ix] = (d1, d2)
+                    found = True
+                    break
+            if found:
+                break
+        if not found:
+            return None
+    assert all(max(d) < 2 ** 16 for d in displacements)
+    return displacements, slots
+
+
+def table(jsondata, entities, indent):
+    key, displacements, entities = perfect_hash(entities)
+    names = []
+    values = []
+    entries = []
+    name_end = 0
+    value_end = 0
+    for e in entities:
+        codepoints = jsondata['&' + e + ';']["codepoints"]
+        names.append(e)
+        values.append(''.join([r'\u{%04X}' % cp for cp in codepoints]))
+        name_end += len(e)
+        value_end += len(''.join(map(chr, codepoints)).encode('utf-8'))
+        entries.append((name_end, value_end, e))
+    assert name_end < 2 ** 16 and value_end < 2 ** 16
+
+    out = []
+    out.append(f"{indent}/// Mixed into the hash, chosen so that every entity gets its own slot.")
+    out.append(f"{indent}pub(super) const KEY: u64 = {key};")
+    out.append("")
+    out.append(f"{indent}/// Entity names in slot order, concatenated.")
+    out.append(f"{indent}pub(super) static NAMES: &[u8] = b\"\\")
+    line = ''.join(names)
+    for i in range(0, len(line), 80):
+        out.append(f"{indent}    {line[i:i + 80]}\\")
+    out[-1] = out[-1][:-1] + '";'
+    out.append("")
+    out.append(f"{indent}/// Entity expansions, in the same order as the names.")
+    out.append(f"{indent}pub(super) static VALUES: &str = \"\\")
+    for i in range(0, len(values), 8):
+        out.append(f"{indent}    {''.join(values[i:i + 8])}\\")
+    out[-1] = out[-1][:-1] + '";'
+    out.append("")
+    out.append(f"{indent}/// End offsets of each entity in `NAMES` and `VALUES`; the start offsets are")
+    out.append(f"{indent}/// the end offsets of the previous entity.")
+    out.append(f"{indent}pub(super) static ENTRIES: [(u16, u16); {len(entries)}] = [")
+    items = [(f"({name_end}, {value_end}),", e) for name_end, value_end, e in entries]
+    width = max(len(item) for item, _ in items)
+    for item, e in items:
+        out.append(f"{indent}    {item.ljust(width)} // {e}")
+    out.append(f"{indent}];")
+    out.append("")
+    out.append(f"{indent}/// Displacements of each bucket of the perfect hash.")
+    out.append(f"{indent}pub(super) static DISPLACEMENTS: [(u16, u16); {len(displacements)}] = [")
+    for d1, d2 in displacements:
+        out.append(f"{indent}    ({d1}, {d2}),")
+    out.append(f"{indent}];")
+    return '\n'.join(out)
+
+
+LICENSE = """// Copyright 2015 Google Inc. All rights reserved.
 //
 // Permission is hereby granted, free of charge, to any person obtaining a copy
 // of this software and associated documentation files (the "Software"), to deal
@@ -51,24 +160,72 @@ def main(args):
 // OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
 // THE SOFTWARE.
 
+"""
+
+
+def main(args):
+    with open(args[1]) as json_file:
+        jsondata = json.load(json_file)
+    entities = [entity[1:-1] for entity in jsondata.keys() if entity.endswith(';')]
+    entities.sort()
+    minimal = sorted(MINIMAL)
+    assert all(e in entities for e in minimal)
+    print(f"""LICENSE
 //! Expansions of HTML5 entities
+//!
+//! Entities are found through a minimal perfect hash, generated together with
+//! the table, so a lookup hashes the name once and compares a single entry.
+//! Names and expansions are stored as two concatenated strings indexed by
+//! 16-bit offsets, which keeps the table small and free of relocations.
+//!
+//! With the `minimal-entities` feature only the XML entities and the most
+//! common typographic ones are recognized.
 
 // Autogenerated by mk_entities.py
 
-const ENTITIES: [(&[u8], &str); {len(entities)}] = [""")
+#[cfg(not(feature = "minimal-entities"))]
+mod table {{
+{table(jsondata, entities, "    ")}
+}}
 
-    for e in entities:
-        codepoints = jsondata['&' + e + ';']["codepoints"];
-        s = ''.join([r'\u{%04X}' % cp for cp in codepoints])
-        print(f"    (b\"{e}\", \"{s}\"),")
-    print("""];
-
-pub(crate) fn get_entity(bytes: &[u8]) -> Option<&'static str> {
-    ENTITIES
-        .binary_search_by_key(&bytes, |&(key, _value)| key)
-        .ok()
-        .map(|i| ENTITIES[i].1)
-}
-""")
-
-main(sys.argv)
\ No newline at end of file
+#[cfg(feature = "minimal-entities")]
+mod table {{
+{table(jsondata, minimal, "    ")}
+}}
+
+use table::{{DISPLACEMENTS, ENTRIES, KEY, NAMES, VALUES}};
+
+fn entry(i: usize) -> (&'static [u8], &'static str) {{
+    let (name_start, value_start) = match i.checked_sub(1) {{
+        Some(prev) => ENTRIES[prev],
+        None => (0, 0),
+    }};
+    let (name_end, value_end) = ENTRIES[i];
+    (
+        &NAMES[name_start as usize..name_end as usize],
+        &VALUES[value_start as usize..value_end as usize],
+    )
+}}
+
+/// 64-bit FNV-1a with `KEY` mixed into the offset basis, as in the generator.
+fn hash(bytes: &[u8]) -> u64 {{
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325 ^ KEY, |h, &b| {{
+        (h ^ u64::from(b)).wrapping_mul(0x0000_0100_0000_01b3)
+    }})
+}}
+
+pub(crate) fn get_entity(bytes: &[u8]) -> Option<&'static str> {{
+    let h = hash(bytes);
+    let (f1, f2) = (h as u32, (h >> 32) as u32);
+    let (d1, d2) = DISPLACEMENTS[f2 as usize % DISPLACEMENTS.len()];
+    let slot = u32::from(d2)
+        .wrapping_add(f1.wrapping_mul(u32::from(d1)))
+        .wrapping_add(f2) as usize
+        % ENTRIES.len();
+    let (name, value) = entry(slot);
+    (name == bytes).then_some(value)
+}}
+""".replace("LICENSE\n", LICENSE), end="")
+
+
+main(sys.argv)
@@ -39,67 +39,72 @@ def main(args):
         spl = line.split(';')
         if spl[2] in ('Pc', 'Pd', 'Pe', 'Pf', 'Pi', 'Po', 'Ps', 'Sm', 'Sc', 'Sk', 'So'):
             punct.add(int(spl[0], 16))
-    pshift = list(set((cp // 16 for cp in punct if cp >= 128)))
-    pshift.sort()
-    bits = [get_bits(high, punct) for high in pshift]
-    print("""// Copyright 2015 Google Inc. All rights reserved.
-//
-// Permission is hereby granted, free of charge, to any person obtaining a copy
-// of this software and associated documentation files (the "Software"), to deal
-// in the Software without restriction, including without limitation the rights
-// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
-// copies of the Software, and to permit persons to whom the Software is
-// furnished to do so, subject to the following conditions:
-//
-// The above copyright notice and this permission notice shall be included in
-// all copies or substantial portions of the Software.
-//
-// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
-// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
-// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
-// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
-// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
-// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
-// THE SOFTWARE.
-
-//! CommonMark punctuation set based on spec and Unicode properties.
+    # The spec defines ASCII punctuation explicitly, Unicode categories apply
+    # to the rest.
+    punct = ascii_set | set(cp for cp in punct if cp >= 128)
+
+    # Two-level table: code points are split into pages of 256, and every
+    # distinct page is stored once as sixteen 16-bit masks.
+    n_pages = max(punct) // 256 + 1
+    blocks = [(0,) * 16]
+    pages = []
+    for page in range(n_pages):
+        block = tuple(get_bits(page * 16 + i, punct) for i in range(16))
+        if block not in blocks:
+            blocks.append(block)
+        pages.append(blocks.index(block))
+    assert len(blocks) <= 256
+
+    print("""LICENSE//! CommonMark punctuation set based on spec and Unicode properties.
 
 // Autogenerated by mk_puncttable.py
 
-const PUNCT_MASKS_ASCII: [u16; 8] = [""")
+const PUNCT_MASKS_ASCII: [u16; 8] = [""".replace("LICENSE", LICENSE))
     for x in range(8):
         y = get_bits(x, ascii_set)
         print('    0x%04x, // U+%04X...U+%04X' % (y, x * 16, x * 16 + 15))
     print("""];
 
-const PUNCT_TAB: [u16; %i] = [""" % len(pshift))
-    for x in pshift:
-        print('    %-5s // U+%04X...U+%04X' % (str(x)+",", x * 16, x * 16 + 15))
+/// Index into `PUNCT_BLOCKS` for every page of 256 code points.
+#[rustfmt::skip]
+const PUNCT_PAGES: [u8; %i] = [""" % len(pages))
+    for i in range(0, len(pages), 16):
+        row = ' '.join('%i,' % b for b in pages[i:i + 16])
+        print('    %s // U+%04X...U+%04X' % (row, i * 256, min(i + 16, len(pages)) * 256 - 1))
     print("""];
 
-const PUNCT_MASKS: [u16; %i] = [""" % len(pshift))
-    for i, y in enumerate(bits):
-        x = pshift[i]
-        print('    0x%04x, // U+%04X...U+%04X' % (y, x * 16, x * 16 + 15))
+/// Punctuation in a page of 256 code points, as sixteen 16-bit masks.
+#[rustfmt::skip]
+const PUNCT_BLOCKS: [[u16; 16]; %i] = [""" % len(blocks))
+    for block in blocks:
+        print('    [')
+        for i in range(0, 16, 8):
+            print('        %s' % ' '.join('0x%04x,' % m for m in block[i:i + 8]))
+        print('    ],')
     print("""];
 
+const fn ascii_punctuation_table() -> [bool; 256] {
+    let mut table = [false; 256];
+    let mut c = 0;
+    while c < 128 {
+        table[c] = (PUNCT_MASKS_ASCII[c / 16] & (1 << (c & 15))) != 0;
+        c += 1;
+    }
+    table
+}
+
+static ASCII_PUNCTUATION: [bool; 256] = ascii_punctuation_table();
+
 pub(crate) fn is_ascii_punctuation(c: u8) -> bool {
-    c < 128 && (PUNCT_MASKS_ASCII[(c / 16) as usize] & (1 << (c & 15))) != 0
+    ASCII_PUNCTUATION[c as usize]
 }
 
 pub(crate) fn is_punctuation(c: char) -> bool {
     let cp = c as u32;
-    if cp < 128 {
-        return is_ascii_punctuation(cp as u8);
-    }
-    if cp > 0x%04X {
+    let Some(&block) = PUNCT_PAGES.get((cp >> 8) as usize) else {
         return false;
-    }
-    let high = (cp / 16) as u16;
-    match PUNCT_TAB.binary_search(&high) {
-        Ok(index) => (PUNCT_MASKS[index] & (1 << (cp & 15))) != 0,
-        _ => false,
-    }
+    };
+    (PUNCT_BLOCKS[block as usize][((cp >> 4) & 15) as usize] & (1 << (cp & 15))) != 0
 }
 
 #[cfg(test)]
@@ -127,7 +132,32 @@ mod tests {
         assert!(is_punctuation('\\u{FF65}'));
         assert!(is_punctuation('\\u{1BC9F}'));
         assert!(!is_punctuation('\\u{1BCA0}'));
+        assert!(is_punctuation('\\u{%04X}'));
+        assert!(!is_punctuation('\\u{%04X}'));
+        assert!(!is_punctuation(char::MAX));
     }
-}""" % max(punct))
+}""" % (max(punct), max(punct) + 1))
+
+LICENSE = """// Copyright 2015 Google Inc. All rights reserved.
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+// THE SOFTWARE.
+
+"""
 
 main(sys.argv)